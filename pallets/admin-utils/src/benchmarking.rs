@@ -240,5 +240,27 @@ mod benchmarks {
 		_(RawOrigin::Root, 1u16/*netuid*/, true/*enabled*/)/*set_commit_reveal_weights_enabled*/;
     }
 
+    #[benchmark]
+    fn sudo_set_stake_lock_boosts() {
+        // The longest schedule, every tier is validated before the write.
+        let boosts: Vec<(u64, u32)> = (1..=pallet_subtensor::MAX_STAKE_LOCK_BOOSTS as u64)
+            .map(|tier| {
+                (
+                    tier * 1_000,
+                    pallet_subtensor::EMISSION_MULTIPLIER_BPS + tier as u32,
+                )
+            })
+            .collect();
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, boosts)/*sudo_set_stake_lock_boosts*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_root_max_uids() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 128u16/*max_uids*/)/*sudo_set_root_max_uids*/;
+    }
+
     //impl_benchmark_test_suite!(AdminUtils, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -1,9 +1,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 pub mod weights;
 pub use weights::WeightInfo;
 
+use alloc::vec::Vec;
+use pallet_subtensor::{OwnerHyperparam, TransactionType};
 use sp_runtime::DispatchError;
 use sp_runtime::{traits::Member, RuntimeAppPublic};
 
@@ -69,6 +73,30 @@ pub mod pallet {
         MaxValidatorsLargerThanMaxUIds,
         /// The maximum number of subnet validators must be more than the current number of UIDs already in the subnet.
         MaxAllowedUIdsLessThanCurrentUIds,
+        /// The activity cutoff is below the minimum a subnet owner can set.
+        ActivityCutoffTooLow,
+        /// The proportional stake fee is above 10_000 basis points.
+        StakeFeeBpsTooHigh,
+        /// The maximum number of subnet miner uids must not exceed the maximum number of allowed UIDs in the subnet.
+        MaxMinerUidsLargerThanMaxUIds,
+        /// Weight setting on the subnet was paused too recently to be paused again.
+        WeightsPauseOnCooldown,
+        /// The value is outside the bounds within which the subnet owner can set the hyperparameter.
+        HyperparamOutsideOwnerBounds,
+        /// The subnet owner set the hyperparameter too recently to set it again.
+        OwnerHyperparamRateLimitExceeded,
+        /// The network lock cost multiplier is below 10_000 basis points, which would lower the lock cost on registration.
+        LockCostMultiplierTooLow,
+        /// The minimum stake to register is above the bound a subnet can set it to.
+        MinStakeToRegisterAboveMax,
+        /// The emission multiplier is above MAX_EMISSION_MULTIPLIER basis points.
+        EmissionMultiplierTooHigh,
+        /// The cap on the take of delegates on a subnet is below the minimum delegate take.
+        MaxTakePerSubnetTooLow,
+        /// The stake lock boosts are too many, out of bounds or not ascending.
+        InvalidStakeLockBoosts,
+        /// The weights precision is zero or above 16 bits.
+        InvalidWeightsPrecision,
     }
 
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -238,7 +266,8 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the adjustment interval for a subnet.
-        /// It is only callable by the root account, not changeable by the subnet owner.
+        /// It is only callable by the root account or subnet owner.
+        /// The subnet owner can only set it within its owner bounds, once per rate limit.
         /// The extrinsic will call the Subtensor pallet to set the adjustment interval.
         #[pallet::call_index(8)]
         #[pallet::weight(T::WeightInfo::sudo_set_adjustment_interval())]
@@ -247,12 +276,12 @@ pub mod pallet {
             netuid: u16,
             adjustment_interval: u16,
         ) -> DispatchResult {
-            ensure_root(origin)?;
-
-            ensure!(
-                T::Subtensor::if_subnet_exist(netuid),
-                Error::<T>::SubnetDoesNotExist
-            );
+            Self::ensure_owner_can_set_hyperparam(
+                origin,
+                netuid,
+                OwnerHyperparam::AdjustmentInterval,
+                adjustment_interval,
+            )?;
             T::Subtensor::set_adjustment_interval(netuid, adjustment_interval);
             log::info!(
                 "AdjustmentIntervalSet( netuid: {:?} adjustment_interval: {:?} ) ",
@@ -319,6 +348,7 @@ pub mod pallet {
 
         /// The extrinsic sets the immunity period for a subnet.
         /// It is only callable by the root account or subnet owner.
+        /// The subnet owner can only set it up to the max immunity period, once per rate limit.
         /// The extrinsic will call the Subtensor pallet to set the immunity period.
         #[pallet::call_index(13)]
         #[pallet::weight(T::WeightInfo::sudo_set_immunity_period())]
@@ -327,11 +357,12 @@ pub mod pallet {
             netuid: u16,
             immunity_period: u16,
         ) -> DispatchResult {
-            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
-            ensure!(
-                T::Subtensor::if_subnet_exist(netuid),
-                Error::<T>::SubnetDoesNotExist
-            );
+            Self::ensure_owner_can_set_hyperparam(
+                origin,
+                netuid,
+                OwnerHyperparam::ImmunityPeriod,
+                immunity_period,
+            )?;
 
             T::Subtensor::set_immunity_period(netuid, immunity_period);
             log::info!(
@@ -412,6 +443,33 @@ pub mod pallet {
             Ok(())
         }
 
+        /// The extrinsic sets the largest share of the active stake, out of u16::MAX, that one
+        /// validator of a subnet weighs in its epoch with. The excess of a larger validator is
+        /// spread over the others.
+        /// It is only callable by the root account or subnet owner.
+        /// The subnet owner can only set it within its owner bounds, once per rate limit.
+        #[pallet::call_index(112)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_stake_weight_fraction(
+            origin: OriginFor<T>,
+            netuid: u16,
+            fraction: u16,
+        ) -> DispatchResult {
+            Self::ensure_owner_can_set_hyperparam(
+                origin,
+                netuid,
+                OwnerHyperparam::MaxStakeWeightFraction,
+                fraction,
+            )?;
+            T::Subtensor::set_max_stake_weight_fraction(netuid, fraction);
+            log::info!(
+                "MaxStakeWeightFractionSet( netuid: {:?} fraction: {:?} ) ",
+                netuid,
+                fraction
+            );
+            Ok(())
+        }
+
         /// The extrinsic sets the rho for a subnet.
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the rho.
@@ -431,6 +489,7 @@ pub mod pallet {
 
         /// The extrinsic sets the activity cutoff for a subnet.
         /// It is only callable by the root account or subnet owner.
+        /// The subnet owner cannot set it below the minimum activity cutoff.
         /// The extrinsic will call the Subtensor pallet to set the activity cutoff.
         #[pallet::call_index(18)]
         #[pallet::weight(T::WeightInfo::sudo_set_activity_cutoff())]
@@ -439,12 +498,17 @@ pub mod pallet {
             netuid: u16,
             activity_cutoff: u16,
         ) -> DispatchResult {
+            let is_root = ensure_root(origin.clone()).is_ok();
             T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
 
             ensure!(
                 T::Subtensor::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            ensure!(
+                is_root || activity_cutoff >= T::Subtensor::get_min_activity_cutoff(),
+                Error::<T>::ActivityCutoffTooLow
+            );
             T::Subtensor::set_activity_cutoff(netuid, activity_cutoff);
             log::info!(
                 "ActivityCutoffSet( netuid: {:?} activity_cutoff: {:?} ) ",
@@ -506,7 +570,8 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the target registrations per interval for a subnet.
-        /// It is only callable by the root account.
+        /// It is only callable by the root account or subnet owner.
+        /// The subnet owner can only set it within its owner bounds, once per rate limit.
         /// The extrinsic will call the Subtensor pallet to set the target registrations per interval.
         #[pallet::call_index(21)]
         #[pallet::weight(T::WeightInfo::sudo_set_target_registrations_per_interval())]
@@ -515,12 +580,12 @@ pub mod pallet {
             netuid: u16,
             target_registrations_per_interval: u16,
         ) -> DispatchResult {
-            ensure_root(origin)?;
-
-            ensure!(
-                T::Subtensor::if_subnet_exist(netuid),
-                Error::<T>::SubnetDoesNotExist
-            );
+            Self::ensure_owner_can_set_hyperparam(
+                origin,
+                netuid,
+                OwnerHyperparam::TargetRegistrationsPerInterval,
+                target_registrations_per_interval,
+            )?;
             T::Subtensor::set_target_registrations_per_interval(
                 netuid,
                 target_registrations_per_interval,
@@ -662,7 +727,8 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the maximum registrations per block for a subnet.
-        /// It is only callable by the root account.
+        /// It is only callable by the root account or subnet owner.
+        /// The subnet owner can only set it within its owner bounds, once per rate limit.
         /// The extrinsic will call the Subtensor pallet to set the maximum registrations per block.
         #[pallet::call_index(27)]
         #[pallet::weight(T::WeightInfo::sudo_set_max_registrations_per_block())]
@@ -671,12 +737,12 @@ pub mod pallet {
             netuid: u16,
             max_registrations_per_block: u16,
         ) -> DispatchResult {
-            ensure_root(origin)?;
-
-            ensure!(
-                T::Subtensor::if_subnet_exist(netuid),
-                Error::<T>::SubnetDoesNotExist
-            );
+            Self::ensure_owner_can_set_hyperparam(
+                origin,
+                netuid,
+                OwnerHyperparam::MaxRegistrationsPerBlock,
+                max_registrations_per_block,
+            )?;
             T::Subtensor::set_max_registrations_per_block(netuid, max_registrations_per_block);
             log::info!(
                 "MaxRegistrationsPerBlock( netuid: {:?} max_registrations_per_block: {:?} ) ",
@@ -730,16 +796,13 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the tempo for a subnet.
-        /// It is only callable by the root account.
+        /// It is only callable by the root account or subnet owner.
+        /// The subnet owner can only set it within its owner bounds, once per rate limit.
         /// The extrinsic will call the Subtensor pallet to set the tempo.
         #[pallet::call_index(30)]
         #[pallet::weight(T::WeightInfo::sudo_set_tempo())]
         pub fn sudo_set_tempo(origin: OriginFor<T>, netuid: u16, tempo: u16) -> DispatchResult {
-            ensure_root(origin)?;
-            ensure!(
-                T::Subtensor::if_subnet_exist(netuid),
-                Error::<T>::SubnetDoesNotExist
-            );
+            Self::ensure_owner_can_set_hyperparam(origin, netuid, OwnerHyperparam::Tempo, tempo)?;
             T::Subtensor::set_tempo(netuid, tempo);
             log::info!("TempoSet( netuid: {:?} tempo: {:?} ) ", netuid, tempo);
             Ok(())
@@ -807,21 +870,27 @@ pub mod pallet {
             Ok(())
         }
 
-        /// The extrinsic sets the subnet limit for the network.
+        /// The extrinsic announces a new subnet limit for the network.
         /// It is only callable by the root account.
-        /// The extrinsic will call the Subtensor pallet to set the subnet limit.
+        /// The extrinsic will call the Subtensor pallet to schedule the subnet limit, which is
+        /// applied once the subnet limit change delay has passed.
         #[pallet::call_index(37)]
         #[pallet::weight((
 			Weight::from_parts(14_000_000, 0)
+				.saturating_add(T::DbWeight::get().reads(1))
 				.saturating_add(T::DbWeight::get().writes(1)),
 			DispatchClass::Operational,
 			Pays::No
 		))]
         pub fn sudo_set_subnet_limit(origin: OriginFor<T>, max_subnets: u16) -> DispatchResult {
             ensure_root(origin)?;
-            T::Subtensor::set_subnet_limit(max_subnets);
+            let apply_block = T::Subtensor::set_subnet_limit(max_subnets);
 
-            log::info!("SubnetLimit( max_subnets: {:?} ) ", max_subnets);
+            log::info!(
+                "SubnetLimitChangeScheduled( max_subnets: {:?}, apply_block: {:?} ) ",
+                max_subnets,
+                apply_block
+            );
 
             Ok(())
         }
@@ -1035,106 +1104,1296 @@ pub mod pallet {
             T::Subtensor::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
             T::Subtensor::do_set_alpha_values(origin, netuid, alpha_low, alpha_high)
         }
-    }
-}
 
-impl<T: Config> sp_runtime::BoundToRuntimeAppPublic for Pallet<T> {
-    type Public = T::AuthorityId;
-}
+        /// The extrinsic sets the maximum number of root network validators.
+        /// It is only callable by the root account.
+        /// The root network size is independent of the subnet MaxAllowedUids settings.
+        #[pallet::call_index(52)]
+        #[pallet::weight((T::WeightInfo::sudo_set_root_max_uids(), DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_root_max_uids(origin: OriginFor<T>, max_uids: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                T::Subtensor::get_subnetwork_n(T::Subtensor::get_root_netuid()) < max_uids,
+                Error::<T>::MaxAllowedUIdsLessThanCurrentUIds
+            );
+            T::Subtensor::set_max_root_validators(max_uids);
+            log::info!("RootMaxUidsSet( max_uids: {:?} ) ", max_uids);
+            Ok(())
+        }
 
-// Interfaces to interact with other pallets
-use sp_runtime::BoundedVec;
+        /// The extrinsic sets the balance kept back for fees on coldkeys.
+        /// It is only callable by the root account.
+        /// The reserve is left by `stake_all_minus_fees` and by coldkeys that opted to keep it.
+        #[pallet::call_index(53)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_fee_reserve(origin: OriginFor<T>, fee_reserve: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_fee_reserve(fee_reserve);
+            log::info!("FeeReserveSet( fee_reserve: {:?} ) ", fee_reserve);
+            Ok(())
+        }
 
-pub trait AuraInterface<AuthorityId, MaxAuthorities> {
-    fn change_authorities(new: BoundedVec<AuthorityId, MaxAuthorities>);
-}
+        /// The extrinsic sets the number of blocks between announcing and applying a subnet limit.
+        /// It is only callable by the root account.
+        /// Limits that are already announced keep their application block.
+        #[pallet::call_index(54)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_limit_change_delay(
+            origin: OriginFor<T>,
+            delay: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_subnet_limit_change_delay(delay);
+            log::info!("SubnetLimitChangeDelaySet( delay: {:?} ) ", delay);
+            Ok(())
+        }
 
-impl<A, M> AuraInterface<A, M> for () {
-    fn change_authorities(_: BoundedVec<A, M>) {}
-}
+        /// The extrinsic sets the maximum length of a subnet maintenance window.
+        /// It is only callable by the root account.
+        /// Windows that are already scheduled are kept.
+        #[pallet::call_index(55)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_maintenance_duration(
+            origin: OriginFor<T>,
+            duration: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_max_maintenance_duration(duration);
+            log::info!("MaxMaintenanceDurationSet( duration: {:?} ) ", duration);
+            Ok(())
+        }
 
-///////////////////////////////////////////
+        /// The extrinsic sets the maximum size of a miner commitment on a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// The size is capped at the subtensor pallet's MAX_COMMITMENT_BYTES.
+        #[pallet::call_index(56)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_commitment_size(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_commitment_size: u32,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            T::Subtensor::set_max_commitment_size(netuid, max_commitment_size);
+            log::info!(
+                "MaxCommitmentSizeSet( netuid: {:?} max_commitment_size: {:?} ) ",
+                netuid,
+                max_commitment_size
+            );
+            Ok(())
+        }
 
-pub trait SubtensorInterface<AccountId, Balance, RuntimeOrigin> {
-    fn set_min_delegate_take(take: u16);
-    fn set_max_delegate_take(take: u16);
-    fn set_tx_rate_limit(rate_limit: u64);
-    fn set_tx_delegate_take_rate_limit(rate_limit: u64);
+        /// The extrinsic sets the number of blocks between commitments of a hotkey on a subnet.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(57)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_commitment_rate_limit(
+            origin: OriginFor<T>,
+            netuid: u16,
+            commitment_rate_limit: u64,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            T::Subtensor::set_commitment_rate_limit(netuid, commitment_rate_limit);
+            log::info!(
+                "CommitmentRateLimitSet( netuid: {:?} commitment_rate_limit: {:?} ) ",
+                netuid,
+                commitment_rate_limit
+            );
+            Ok(())
+        }
 
-    fn set_serving_rate_limit(netuid: u16, rate_limit: u64);
+        /// The extrinsic sets the lowest activity cutoff a subnet owner can set.
+        /// It is only callable by the root account.
+        #[pallet::call_index(58)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_activity_cutoff(
+            origin: OriginFor<T>,
+            min_activity_cutoff: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_min_activity_cutoff(min_activity_cutoff);
+            log::info!(
+                "MinActivityCutoffSet( min_activity_cutoff: {:?} ) ",
+                min_activity_cutoff
+            );
+            Ok(())
+        }
 
-    fn set_max_burn(netuid: u16, max_burn: u64);
-    fn set_min_burn(netuid: u16, min_burn: u64);
-    fn set_burn(netuid: u16, burn: u64);
+        /// The extrinsic sets the maximum PoW registrations per block for a subnet.
+        /// It is only callable by the root account.
+        /// The registrations also count towards the subnet's max registrations per block.
+        #[pallet::call_index(59)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_pow_registrations_per_block(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_registrations_per_block: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
 
-    fn set_max_difficulty(netuid: u16, max_diff: u64);
-    fn set_min_difficulty(netuid: u16, min_diff: u64);
-    fn set_difficulty(netuid: u16, diff: u64);
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            T::Subtensor::set_max_pow_registrations_per_block(netuid, max_registrations_per_block);
+            log::info!(
+                "MaxPowRegistrationsPerBlock( netuid: {:?} max_registrations_per_block: {:?} ) ",
+                netuid,
+                max_registrations_per_block
+            );
+            Ok(())
+        }
 
-    fn set_weights_rate_limit(netuid: u16, rate_limit: u64);
+        /// The extrinsic sets the maximum burned registrations per block for a subnet.
+        /// It is only callable by the root account.
+        /// The registrations also count towards the subnet's max registrations per block.
+        #[pallet::call_index(60)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_burn_registrations_per_block(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_registrations_per_block: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
 
-    fn set_weights_version_key(netuid: u16, version: u64);
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            T::Subtensor::set_max_burn_registrations_per_block(netuid, max_registrations_per_block);
+            log::info!(
+                "MaxBurnRegistrationsPerBlock( netuid: {:?} max_registrations_per_block: {:?} ) ",
+                netuid,
+                max_registrations_per_block
+            );
+            Ok(())
+        }
 
-    fn set_bonds_moving_average(netuid: u16, moving_average: u64);
+        /// The extrinsic reserves a free netuid and opens an auction for it.
+        /// It is only callable by the root account.
+        /// The netuid is skipped by sequential network registration until it is claimed or released.
+        #[pallet::call_index(61)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_reserve_netuid(
+            origin: OriginFor<T>,
+            netuid: u16,
+            min_bid: u64,
+            duration: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::reserve_netuid(netuid, min_bid, duration)
+        }
 
-    fn set_max_allowed_validators(netuid: u16, max_validators: u16);
+        /// The extrinsic releases a reserved netuid, refunding its highest bid.
+        /// It is only callable by the root account.
+        #[pallet::call_index(62)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_release_netuid(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::release_netuid(netuid)
+        }
 
-    fn get_root_netuid() -> u16;
-    fn if_subnet_exist(netuid: u16) -> bool;
-    fn create_account_if_non_existent(coldkey: &AccountId, hotkey: &AccountId);
-    fn coldkey_owns_hotkey(coldkey: &AccountId, hotkey: &AccountId) -> bool;
-    fn increase_stake_on_coldkey_hotkey_account(
-        coldkey: &AccountId,
-        hotkey: &AccountId,
-        increment: u64,
-    );
-    fn add_balance_to_coldkey_account(coldkey: &AccountId, amount: Balance);
-    fn get_current_block_as_u64() -> u64;
-    fn get_subnetwork_n(netuid: u16) -> u16;
-    fn get_max_allowed_uids(netuid: u16) -> u16;
-    fn append_neuron(netuid: u16, new_hotkey: &AccountId, block_number: u64);
-    fn get_neuron_to_prune(netuid: u16) -> u16;
-    fn replace_neuron(netuid: u16, uid_to_replace: u16, new_hotkey: &AccountId, block_number: u64);
-    fn set_total_issuance(total_issuance: u64);
-    fn set_network_immunity_period(net_immunity_period: u64);
-    fn set_network_min_lock(net_min_lock: u64);
-    fn set_rao_recycled(netuid: u16, rao_recycled: u64);
-    fn set_subnet_limit(limit: u16);
-    fn is_hotkey_registered_on_network(netuid: u16, hotkey: &AccountId) -> bool;
-    fn set_lock_reduction_interval(interval: u64);
-    fn set_tempo(netuid: u16, tempo: u16);
-    fn set_subnet_owner_cut(subnet_owner_cut: u16);
-    fn set_network_rate_limit(limit: u64);
-    fn set_max_registrations_per_block(netuid: u16, max_registrations_per_block: u16);
-    fn set_adjustment_alpha(netuid: u16, adjustment_alpha: u64);
-    fn set_target_registrations_per_interval(netuid: u16, target_registrations_per_interval: u16);
-    fn set_network_pow_registration_allowed(netuid: u16, registration_allowed: bool);
-    fn set_network_registration_allowed(netuid: u16, registration_allowed: bool);
-    fn set_activity_cutoff(netuid: u16, activity_cutoff: u16);
-    fn ensure_subnet_owner_or_root(o: RuntimeOrigin, netuid: u16) -> Result<(), DispatchError>;
-    fn set_rho(netuid: u16, rho: u16);
-    fn set_kappa(netuid: u16, kappa: u16);
-    fn set_max_allowed_uids(netuid: u16, max_allowed: u16);
-    fn set_min_allowed_weights(netuid: u16, min_allowed_weights: u16);
-    fn set_immunity_period(netuid: u16, immunity_period: u16);
-    fn set_max_weight_limit(netuid: u16, max_weight_limit: u16);
-    fn set_scaling_law_power(netuid: u16, scaling_law_power: u16);
-    fn set_validator_prune_len(netuid: u16, validator_prune_len: u64);
-    fn set_adjustment_interval(netuid: u16, adjustment_interval: u16);
-    fn set_weights_set_rate_limit(netuid: u16, weights_set_rate_limit: u64);
-    fn init_new_network(netuid: u16, tempo: u16);
-    fn set_weights_min_stake(min_stake: u64);
-    fn get_nominator_min_required_stake() -> u64;
-    fn set_nominator_min_required_stake(min_stake: u64);
-    fn clear_small_nominations();
-    fn set_target_stakes_per_interval(target_stakes_per_interval: u64);
-    fn set_commit_reveal_weights_interval(netuid: u16, interval: u64);
-    fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool);
-    fn set_liquid_alpha_enabled(netuid: u16, enabled: bool);
-    fn do_set_alpha_values(
-        origin: RuntimeOrigin,
-        netuid: u16,
-        alpha_low: u16,
-        alpha_high: u16,
+        /// The extrinsic bans a hotkey from registering and receiving stake.
+        /// It is only callable by the root account.
+        /// The stake on the hotkey is returned to the staking coldkeys over the following blocks.
+        #[pallet::call_index(63)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_ban_hotkey(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::ban_hotkey(&hotkey)
+        }
+
+        /// The extrinsic lifts the ban on a hotkey.
+        /// It is only callable by the root account.
+        /// The stake not yet returned to the staking coldkeys stays on the hotkey.
+        #[pallet::call_index(113)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_unban_hotkey(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::unban_hotkey(&hotkey)
+        }
+
+        /// The extrinsic sets the number of stakes of banned hotkeys returned per block.
+        /// It is only callable by the root account.
+        #[pallet::call_index(64)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_stake_wind_down_chunk_size(
+            origin: OriginFor<T>,
+            chunk_size: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_stake_wind_down_chunk_size(chunk_size);
+            log::info!("StakeWindDownChunkSizeSet( chunk_size: {:?} ) ", chunk_size);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks after which an axon that is not served again
+        /// is stale and left out of the active axon views of a subnet. Zero disables expiry.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(65)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_axon_ttl_blocks(
+            origin: OriginFor<T>,
+            netuid: u16,
+            axon_ttl_blocks: u64,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            T::Subtensor::set_axon_ttl_blocks(netuid, axon_ttl_blocks);
+            log::info!(
+                "AxonTTLBlocksSet( netuid: {:?} axon_ttl_blocks: {:?} ) ",
+                netuid,
+                axon_ttl_blocks
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the utilization below which subnets have part of their emission
+        /// burned, as a fraction of u16::MAX.
+        /// It is only callable by the root account.
+        #[pallet::call_index(66)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_utilization_floor(
+            origin: OriginFor<T>,
+            utilization_floor: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_utilization_floor(utilization_floor);
+            log::info!(
+                "UtilizationFloorSet( utilization_floor: {:?} ) ",
+                utilization_floor
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the fraction of emission, out of u16::MAX, burned for subnets
+        /// below the utilization floor.
+        /// It is only callable by the root account.
+        #[pallet::call_index(67)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_low_utilization_burn(
+            origin: OriginFor<T>,
+            low_utilization_burn: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_low_utilization_burn(low_utilization_burn);
+            log::info!(
+                "LowUtilizationBurnSet( low_utilization_burn: {:?} ) ",
+                low_utilization_burn
+            );
+            Ok(())
+        }
+
+        /// The extrinsic schedules the max allowed uids of a subnet to grow by `step` every
+        /// `interval` blocks until they reach `target`, replacing any growth already scheduled.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(68)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_schedule_max_allowed_uids_growth(
+            origin: OriginFor<T>,
+            netuid: u16,
+            step: u16,
+            interval: u64,
+            target: u16,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            T::Subtensor::schedule_max_allowed_uids_growth(netuid, step, interval, target)
+        }
+
+        /// The extrinsic cancels the scheduled growth of the max allowed uids of a subnet.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(69)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_cancel_max_allowed_uids_growth(
+            origin: OriginFor<T>,
+            netuid: u16,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            T::Subtensor::cancel_max_allowed_uids_growth(netuid)
+        }
+
+        /// The extrinsic removes at most `limit` LoadedEmission and LastUpdate entries of
+        /// netuids without any uids, so that state bloat can be reclaimed in chunks.
+        /// It is only callable by the root account.
+        #[pallet::call_index(70)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_prune_orphaned_storage(origin: OriginFor<T>, limit: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            let pruned = T::Subtensor::prune_orphaned_storage(limit);
+            log::info!(
+                "OrphanedStoragePruned( limit: {:?} pruned: {:?} ) ",
+                limit,
+                pruned
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks between payouts of the emission accrued by
+        /// each hotkey. Zero pays hotkey emission out as soon as it is drained.
+        /// It is only callable by the root account.
+        #[pallet::call_index(71)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_hotkey_emission_tempo(
+            origin: OriginFor<T>,
+            hotkey_emission_tempo: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_hotkey_emission_tempo(hotkey_emission_tempo);
+            log::info!(
+                "HotkeyEmissionTempoSet( hotkey_emission_tempo: {:?} ) ",
+                hotkey_emission_tempo
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the stake of a subnet's permitted validators that
+        /// pauses its epochs when they signal an emergency.
+        /// It is only callable by the root account.
+        #[pallet::call_index(72)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_epoch_pause_threshold(
+            origin: OriginFor<T>,
+            threshold: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_epoch_pause_threshold(threshold);
+            log::info!("EpochPauseThresholdSet( threshold: {:?} ) ", threshold);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a subnet's epochs are paused for once its
+        /// validators signal an emergency.
+        /// It is only callable by the root account.
+        #[pallet::call_index(73)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_epoch_pause_duration(
+            origin: OriginFor<T>,
+            duration: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_epoch_pause_duration(duration);
+            log::info!("EpochPauseDurationSet( duration: {:?} ) ", duration);
+            Ok(())
+        }
+
+        /// The extrinsic sets the fee charged on adding and removing stake, a flat amount plus
+        /// `bps` basis points of the amount.
+        /// It is only callable by the root account.
+        #[pallet::call_index(74)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_stake_fee(origin: OriginFor<T>, flat: u64, bps: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(bps <= 10_000, Error::<T>::StakeFeeBpsTooHigh);
+            T::Subtensor::set_stake_fee(flat, bps);
+            log::info!("StakeFeeSet( flat: {:?} bps: {:?} ) ", flat, bps);
+            Ok(())
+        }
+
+        /// The extrinsic sets the amount below which staking and unstaking are exempt from the
+        /// stake fee.
+        /// It is only callable by the root account.
+        #[pallet::call_index(75)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_stake_fee_dust_threshold(
+            origin: OriginFor<T>,
+            threshold: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_stake_fee_dust_threshold(threshold);
+            log::info!("StakeFeeDustThresholdSet( threshold: {:?} ) ", threshold);
+            Ok(())
+        }
+
+        /// The extrinsic sets the account receiving stake fees. Without one, stake fees are
+        /// recycled out of the total issuance.
+        /// It is only callable by the root account.
+        #[pallet::call_index(76)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_stake_fee_treasury(
+            origin: OriginFor<T>,
+            treasury: Option<T::AccountId>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_stake_fee_treasury(treasury.clone());
+            log::info!("StakeFeeTreasurySet( treasury: {:?} ) ", treasury);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of weight rows checked per block after the min allowed
+        /// weights of a subnet is raised.
+        /// It is only callable by the root account.
+        #[pallet::call_index(77)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_weights_sweep_chunk_size(
+            origin: OriginFor<T>,
+            chunk_size: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_weights_sweep_chunk_size(chunk_size);
+            log::info!("WeightsSweepChunkSizeSet( chunk_size: {:?} ) ", chunk_size);
+            Ok(())
+        }
+
+        /// The extrinsic sets the maximum number of uids without a validator permit on a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// Registrations beyond the cap prune a miner even when the subnet has free uids.
+        #[pallet::call_index(78)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_miner_uids(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_miner_uids: u16,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                max_miner_uids <= T::Subtensor::get_max_allowed_uids(netuid),
+                Error::<T>::MaxMinerUidsLargerThanMaxUIds
+            );
+
+            T::Subtensor::set_max_miner_uids(netuid, max_miner_uids);
+            log::info!(
+                "MaxMinerUidsSet( netuid: {:?} max_miner_uids: {:?} ) ",
+                netuid,
+                max_miner_uids
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the work per block of the root epoch, counted as stakes read plus
+        /// subnets of each root weight row. A larger root epoch continues in the next blocks.
+        /// It is only callable by the root account.
+        #[pallet::call_index(79)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_root_epoch_block_budget(
+            origin: OriginFor<T>,
+            budget: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_root_epoch_block_budget(budget);
+            log::info!("RootEpochBlockBudgetSet( budget: {:?} ) ", budget);
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the server emission, over u16::MAX, that every uid
+        /// in its first epoch on the subnet receives at least. Zero turns it off.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(80)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_bootstrap_incentive_floor(
+            origin: OriginFor<T>,
+            netuid: u16,
+            floor: u16,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            T::Subtensor::set_bootstrap_incentive_floor(netuid, floor);
+            log::info!(
+                "BootstrapIncentiveFloorSet( netuid: {:?} floor: {:?} ) ",
+                netuid,
+                floor
+            );
+            Ok(())
+        }
+
+        /// The extrinsic pauses weight setting on the subnet for MaxWeightsPauseDuration blocks,
+        /// or ends the pause. Epochs keep running on the existing weights while it is paused.
+        /// A subnet cannot be paused again until MaxWeightsPauseDuration blocks after its last
+        /// pause ended. It is only callable by the root account or subnet owner.
+        #[pallet::call_index(81)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_weights_paused(
+            origin: OriginFor<T>,
+            netuid: u16,
+            paused: bool,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                !paused || T::Subtensor::can_pause_weights(netuid),
+                Error::<T>::WeightsPauseOnCooldown
+            );
+
+            T::Subtensor::set_weights_paused(netuid, paused);
+            log::info!(
+                "WeightsPausedSet( netuid: {:?} paused: {:?} ) ",
+                netuid,
+                paused
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a pause of weight setting lasts, which is
+        /// also the cooldown before a subnet can be paused again.
+        /// It is only callable by the root account.
+        #[pallet::call_index(82)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_weights_pause_duration(
+            origin: OriginFor<T>,
+            duration: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_max_weights_pause_duration(duration);
+            log::info!("MaxWeightsPauseDurationSet( duration: {:?} ) ", duration);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a subnet owner waits between two updates
+        /// of the same hyperparameter.
+        /// It is only callable by the root account.
+        #[pallet::call_index(83)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_owner_hyperparam_rate_limit(
+            origin: OriginFor<T>,
+            rate_limit: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_owner_hyperparam_rate_limit(rate_limit);
+            log::info!(
+                "OwnerHyperparamRateLimitSet( rate_limit: {:?} ) ",
+                rate_limit
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the longest immunity period a subnet owner can set.
+        /// It is only callable by the root account.
+        #[pallet::call_index(111)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_immunity_period(
+            origin: OriginFor<T>,
+            max_immunity_period: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_max_immunity_period(max_immunity_period);
+            log::info!(
+                "MaxImmunityPeriodSet( max_immunity_period: {:?} ) ",
+                max_immunity_period
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the factor in basis points the network lock cost is raised by on
+        /// each network registration, 20_000 doubling it. It must be at least 10_000.
+        /// It is only callable by the root account.
+        #[pallet::call_index(84)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_network_lock_cost_multiplier(
+            origin: OriginFor<T>,
+            multiplier: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(multiplier >= 10_000, Error::<T>::LockCostMultiplierTooLow);
+            T::Subtensor::set_network_lock_cost_multiplier(multiplier);
+            log::info!(
+                "NetworkLockCostMultiplierSet( multiplier: {:?} ) ",
+                multiplier
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a subnet referendum is open for votes.
+        /// It is only callable by the root account.
+        #[pallet::call_index(85)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_referendum_duration(origin: OriginFor<T>, duration: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_referendum_duration(duration);
+            log::info!("ReferendumDurationSet( duration: {:?} ) ", duration);
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the stake of a subnet's permitted validators that
+        /// must vote for a subnet referendum for it to pass.
+        /// It is only callable by the root account.
+        #[pallet::call_index(86)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_referendum_threshold(
+            origin: OriginFor<T>,
+            threshold: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_referendum_threshold(threshold);
+            log::info!("ReferendumThresholdSet( threshold: {:?} ) ", threshold);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a removed netuid is held back from
+        /// reassignment, 0 letting a new network take a pruned netuid immediately.
+        /// It is only callable by the root account.
+        #[pallet::call_index(87)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_netuid_quarantine_period(
+            origin: OriginFor<T>,
+            period: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_netuid_quarantine_period(period);
+            log::info!("NetuidQuarantinePeriodSet( period: {:?} ) ", period);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks the stake behind a reachable axon
+        /// attestation halves in when scoring the reachability of an axon.
+        /// It is only callable by the root account.
+        #[pallet::call_index(88)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_axon_reachability_half_life(
+            origin: OriginFor<T>,
+            half_life: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_axon_reachability_half_life(half_life);
+            log::info!("AxonReachabilityHalfLifeSet( half_life: {:?} ) ", half_life);
+            Ok(())
+        }
+
+        /// The extrinsic sets the stake a hotkey must already hold to register on a subnet,
+        /// up to the bound set by root.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(89)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_stake_to_register(
+            origin: OriginFor<T>,
+            netuid: u16,
+            min_stake: u64,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                min_stake <= T::Subtensor::get_max_min_stake_to_register(),
+                Error::<T>::MinStakeToRegisterAboveMax
+            );
+
+            T::Subtensor::set_min_stake_to_register(netuid, min_stake);
+            log::info!(
+                "MinStakeToRegisterSet( netuid: {:?} min_stake: {:?} ) ",
+                netuid,
+                min_stake
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the bound on the stake a subnet can require of registering hotkeys.
+        /// It is only callable by the root account.
+        #[pallet::call_index(90)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_min_stake_to_register(
+            origin: OriginFor<T>,
+            max_min_stake: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_max_min_stake_to_register(max_min_stake);
+            log::info!(
+                "MaxMinStakeToRegisterSet( max_min_stake: {:?} ) ",
+                max_min_stake
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks before a change of child keys applies.
+        /// It is only callable by the root account.
+        #[pallet::call_index(91)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_pending_child_key_cooldown(
+            origin: OriginFor<T>,
+            cooldown: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_pending_child_key_cooldown(cooldown);
+            log::info!("PendingChildKeyCooldownSet( cooldown: {:?} ) ", cooldown);
+            Ok(())
+        }
+
+        /// The extrinsic clears the bonds of a subnet, used before changing its bonds
+        /// parameters far from their current values. The rows are cleared in chunks across
+        /// blocks and the epochs of the subnet wait until they all are.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(92)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_reset_bonds(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            T::Subtensor::schedule_bonds_reset(netuid);
+            log::info!("BondsResetStarted( netuid: {:?} ) ", netuid);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of bonds rows cleared per block by a bonds reset.
+        /// It is only callable by the root account.
+        #[pallet::call_index(93)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_bonds_reset_chunk_size(
+            origin: OriginFor<T>,
+            chunk_size: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_bonds_reset_chunk_size(chunk_size);
+            log::info!("BondsResetChunkSizeSet( chunk_size: {:?} ) ", chunk_size);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a scheduled coldkey swap waits for
+        /// arbitration before it is performed.
+        /// It is only callable by the root account.
+        #[pallet::call_index(94)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_arbitration_period(origin: OriginFor<T>, period: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_arbitration_period(period);
+            log::info!("ArbitrationPeriodSet( period: {:?} ) ", period);
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the stake of a delegate its nominators need
+        /// to cap its take.
+        /// It is only callable by the root account.
+        #[pallet::call_index(95)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_take_cap_threshold(origin: OriginFor<T>, threshold: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_take_cap_threshold(threshold);
+            log::info!("TakeCapThresholdSet( threshold: {:?} ) ", threshold);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a cap voted on the take of a delegate runs.
+        /// It is only callable by the root account.
+        #[pallet::call_index(96)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_take_cap_duration(origin: OriginFor<T>, duration: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_take_cap_duration(duration);
+            log::info!("TakeCapDurationSet( duration: {:?} ) ", duration);
+            Ok(())
+        }
+
+        /// The extrinsic sets the deposit held for a coldkey or subnet identity.
+        /// It is only callable by the root account.
+        #[pallet::call_index(97)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_identity_deposit(origin: OriginFor<T>, deposit: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_identity_deposit(deposit);
+            log::info!("IdentityDepositSet( deposit: {:?} ) ", deposit);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks call metrics are counted over.
+        /// It is only callable by the root account.
+        #[pallet::call_index(98)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_call_metrics_era_length(
+            origin: OriginFor<T>,
+            era_length: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_call_metrics_era_length(era_length);
+            log::info!("CallMetricsEraLengthSet( era_length: {:?} ) ", era_length);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a coldkey waits between faucet calls.
+        /// It is only callable by the root account.
+        #[pallet::call_index(99)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_faucet_rate_limit(
+            origin: OriginFor<T>,
+            faucet_rate_limit: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_faucet_rate_limit(faucet_rate_limit);
+            log::info!(
+                "FaucetRateLimitSet( faucet_rate_limit: {:?} ) ",
+                faucet_rate_limit
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets root weights safe mode. When enabled, all-zero root weights are
+        /// rejected, as are root weights reaching fewer than `min_spread` subnets from hotkeys
+        /// with more than `stake_threshold` stake.
+        /// It is only callable by the root account.
+        #[pallet::call_index(100)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_root_weights_safe_mode(
+            origin: OriginFor<T>,
+            enabled: bool,
+            stake_threshold: u64,
+            min_spread: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_root_weights_safe_mode(enabled, stake_threshold, min_spread);
+            log::info!(
+                "RootWeightsSafeModeSet( enabled: {:?}, stake_threshold: {:?}, min_spread: {:?} ) ",
+                enabled,
+                stake_threshold,
+                min_spread
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the cap on the share of root stake, over u16::MAX, each root
+        /// validator ranks subnets with. u16::MAX leaves stakes uncapped.
+        /// It is only callable by the root account.
+        #[pallet::call_index(101)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_root_stake_cap(origin: OriginFor<T>, cap: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_root_stake_cap(cap);
+            log::info!("RootStakeCapSet( cap: {:?} ) ", cap);
+            Ok(())
+        }
+
+        /// The extrinsic sets the refund of `unregister`: the share of the registration burn,
+        /// over u16::MAX, refunded within `window` blocks of a burned registration. A zero
+        /// window disables refunds.
+        /// It is only callable by the root account.
+        #[pallet::call_index(102)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_unregister_refund(
+            origin: OriginFor<T>,
+            window: u64,
+            share: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_unregister_refund(window, share);
+            log::info!(
+                "UnregisterRefundSet( window: {:?}, share: {:?} ) ",
+                window,
+                share
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the factor in basis points the emission values set by the root
+        /// epoch for a subnet are scaled by, 10_000 leaving them unchanged. It is at most
+        /// MAX_EMISSION_MULTIPLIER, for testnets and staged launches.
+        /// It is only callable by the root account.
+        #[pallet::call_index(103)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_emission_multiplier(
+            origin: OriginFor<T>,
+            netuid: u16,
+            multiplier: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                multiplier <= pallet_subtensor::MAX_EMISSION_MULTIPLIER,
+                Error::<T>::EmissionMultiplierTooHigh
+            );
+            T::Subtensor::set_emission_multiplier(netuid, multiplier);
+            log::info!(
+                "EmissionMultiplierSet( netuid: {:?}, multiplier: {:?} ) ",
+                netuid,
+                multiplier
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the cap on the take of delegates for the emission earned on a
+        /// subnet, letting it guarantee nominators lower fees. It is at least the minimum
+        /// delegate take, and a cap at or above the maximum delegate take lifts it.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(104)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_take_per_subnet(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_take: u16,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                max_take >= T::Subtensor::get_min_delegate_take(),
+                Error::<T>::MaxTakePerSubnetTooLow
+            );
+            T::Subtensor::set_max_take_per_subnet(netuid, max_take);
+            log::info!(
+                "MaxTakePerSubnetSet( netuid: {:?}, max_take: {:?} ) ",
+                netuid,
+                max_take
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks an account waits between two transactions
+        /// of the type, 0 lifting the limit.
+        /// It is only callable by the root account.
+        #[pallet::call_index(105)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_transaction_type_rate_limit(
+            origin: OriginFor<T>,
+            tx_type: TransactionType,
+            rate_limit: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_transaction_type_rate_limit(tx_type, rate_limit);
+            log::info!(
+                "TransactionTypeRateLimitSet( tx_type: {:?}, rate_limit: {:?} ) ",
+                tx_type,
+                rate_limit
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the dividend multipliers of locked stake, as ( lock_blocks,
+        /// multiplier ) tiers in basis points. A lock gets the multiplier of the longest tier
+        /// its duration reaches, tiers must grow in both duration and multiplier.
+        /// It is only callable by the root account.
+        #[pallet::call_index(106)]
+        #[pallet::weight((T::WeightInfo::sudo_set_stake_lock_boosts(), DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_stake_lock_boosts(
+            origin: OriginFor<T>,
+            boosts: Vec<(u64, u32)>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                boosts.len() <= pallet_subtensor::MAX_STAKE_LOCK_BOOSTS
+                    && boosts.iter().all(|(lock_blocks, multiplier)| {
+                        *lock_blocks <= pallet_subtensor::MAX_STAKE_LOCK_BLOCKS
+                            && *multiplier >= pallet_subtensor::EMISSION_MULTIPLIER_BPS
+                            && *multiplier <= pallet_subtensor::MAX_STAKE_LOCK_BOOST
+                    })
+                    && boosts.windows(2).all(|pair| match pair {
+                        [(blocks_a, multiplier_a), (blocks_b, multiplier_b)] => {
+                            blocks_a < blocks_b && multiplier_a <= multiplier_b
+                        }
+                        _ => true,
+                    }),
+                Error::<T>::InvalidStakeLockBoosts
+            );
+            T::Subtensor::set_stake_lock_boosts(boosts.clone());
+            log::info!("StakeLockBoostsSet( boosts: {:?} ) ", boosts);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks removed stake waits before it can be
+        /// claimed, 0 paying it out at once.
+        /// It is only callable by the root account.
+        #[pallet::call_index(107)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_unstake_cooldown(origin: OriginFor<T>, cooldown: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_unstake_cooldown(cooldown);
+            log::info!("UnstakeCooldownSet( cooldown: {:?} ) ", cooldown);
+            Ok(())
+        }
+
+        /// The extrinsic starts moving a subnet and all its storage to a free netuid.
+        /// It is only callable by the root account.
+        /// The subnet is offline until its storage has moved, over as many blocks as that takes.
+        #[pallet::call_index(108)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_migrate_subnet(
+            origin: OriginFor<T>,
+            old_netuid: u16,
+            new_netuid: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::migrate_subnet(old_netuid, new_netuid)
+        }
+
+        /// The extrinsic sets the bits each weight of a subnet is stored with, rounding weights
+        /// to fewer values to shrink the weight rows of large subnets.
+        /// It is only callable by the root account or subnet owner.
+        /// The existing weight rows are rewritten with the new precision over the following
+        /// blocks.
+        #[pallet::call_index(109)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_weights_precision(
+            origin: OriginFor<T>,
+            netuid: u16,
+            precision: u8,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                precision > 0 && precision <= pallet_subtensor::weights_row::FULL_WEIGHTS_PRECISION,
+                Error::<T>::InvalidWeightsPrecision
+            );
+            T::Subtensor::set_weights_precision(netuid, precision);
+            log::info!(
+                "WeightsPrecisionSet( netuid: {:?} precision: {:?} ) ",
+                netuid,
+                precision
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the take reduction of underperforming delegates. A delegate whose
+        /// validator trust stays below `trust_threshold` for `tempos` root tempos in a row has
+        /// its take reduced to `take`, restored once its trust stays at or above the threshold
+        /// for as many tempos. A zero threshold turns the reduction off.
+        /// It is only callable by the root account.
+        #[pallet::call_index(110)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_delegate_underperformance(
+            origin: OriginFor<T>,
+            trust_threshold: u16,
+            tempos: u16,
+            take: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_delegate_underperformance(trust_threshold, tempos, take);
+            log::info!(
+                "DelegateUnderperformanceSet( trust_threshold: {:?}, tempos: {:?}, take: {:?} ) ",
+                trust_threshold,
+                tempos,
+                take
+            );
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Ensures the origin is root or the owner of the subnet, which must exist. The subnet
+        /// owner can only set `param` within its owner bounds, and once per
+        /// OwnerHyperparamRateLimit blocks.
+        fn ensure_owner_can_set_hyperparam(
+            origin: OriginFor<T>,
+            netuid: u16,
+            param: OwnerHyperparam,
+            value: u16,
+        ) -> DispatchResult {
+            let is_root = ensure_root(origin.clone()).is_ok();
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            if is_root {
+                return Ok(());
+            }
+
+            let (min, max) = T::Subtensor::get_owner_hyperparam_bounds(netuid, param);
+            ensure!(
+                (min..=max).contains(&value),
+                Error::<T>::HyperparamOutsideOwnerBounds
+            );
+            ensure!(
+                T::Subtensor::passes_owner_hyperparam_rate_limit(netuid, param),
+                Error::<T>::OwnerHyperparamRateLimitExceeded
+            );
+            T::Subtensor::set_last_owner_hyperparam_update(netuid, param);
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> sp_runtime::BoundToRuntimeAppPublic for Pallet<T> {
+    type Public = T::AuthorityId;
+}
+
+// Interfaces to interact with other pallets
+use sp_runtime::BoundedVec;
+
+pub trait AuraInterface<AuthorityId, MaxAuthorities> {
+    fn change_authorities(new: BoundedVec<AuthorityId, MaxAuthorities>);
+}
+
+impl<A, M> AuraInterface<A, M> for () {
+    fn change_authorities(_: BoundedVec<A, M>) {}
+}
+
+///////////////////////////////////////////
+
+pub trait SubtensorInterface<AccountId, Balance, RuntimeOrigin> {
+    fn set_min_delegate_take(take: u16);
+    fn set_max_delegate_take(take: u16);
+    fn set_tx_rate_limit(rate_limit: u64);
+    fn set_tx_delegate_take_rate_limit(rate_limit: u64);
+
+    fn set_serving_rate_limit(netuid: u16, rate_limit: u64);
+
+    fn set_max_burn(netuid: u16, max_burn: u64);
+    fn set_min_burn(netuid: u16, min_burn: u64);
+    fn set_burn(netuid: u16, burn: u64);
+
+    fn set_max_difficulty(netuid: u16, max_diff: u64);
+    fn set_min_difficulty(netuid: u16, min_diff: u64);
+    fn set_difficulty(netuid: u16, diff: u64);
+
+    fn set_weights_rate_limit(netuid: u16, rate_limit: u64);
+
+    fn set_weights_version_key(netuid: u16, version: u64);
+
+    fn set_bonds_moving_average(netuid: u16, moving_average: u64);
+
+    fn set_max_allowed_validators(netuid: u16, max_validators: u16);
+
+    fn get_root_netuid() -> u16;
+    fn if_subnet_exist(netuid: u16) -> bool;
+    fn create_account_if_non_existent(coldkey: &AccountId, hotkey: &AccountId);
+    fn coldkey_owns_hotkey(coldkey: &AccountId, hotkey: &AccountId) -> bool;
+    fn increase_stake_on_coldkey_hotkey_account(
+        coldkey: &AccountId,
+        hotkey: &AccountId,
+        increment: u64,
+    );
+    fn add_balance_to_coldkey_account(coldkey: &AccountId, amount: Balance);
+    fn get_current_block_as_u64() -> u64;
+    fn get_subnetwork_n(netuid: u16) -> u16;
+    fn get_max_allowed_uids(netuid: u16) -> u16;
+    fn append_neuron(netuid: u16, new_hotkey: &AccountId, block_number: u64);
+    fn get_neuron_to_prune(netuid: u16) -> u16;
+    fn replace_neuron(netuid: u16, uid_to_replace: u16, new_hotkey: &AccountId, block_number: u64);
+    fn set_total_issuance(total_issuance: u64);
+    fn set_network_immunity_period(net_immunity_period: u64);
+    fn set_network_min_lock(net_min_lock: u64);
+    fn set_rao_recycled(netuid: u16, rao_recycled: u64);
+    fn set_subnet_limit(limit: u16) -> u64;
+    fn set_subnet_limit_change_delay(delay: u64);
+    fn set_max_maintenance_duration(duration: u64);
+    fn set_max_commitment_size(netuid: u16, max_commitment_size: u32);
+    fn set_commitment_rate_limit(netuid: u16, commitment_rate_limit: u64);
+    fn is_hotkey_registered_on_network(netuid: u16, hotkey: &AccountId) -> bool;
+    fn set_lock_reduction_interval(interval: u64);
+    fn set_tempo(netuid: u16, tempo: u16);
+    fn set_subnet_owner_cut(subnet_owner_cut: u16);
+    fn set_network_rate_limit(limit: u64);
+    fn set_max_registrations_per_block(netuid: u16, max_registrations_per_block: u16);
+    fn set_max_pow_registrations_per_block(netuid: u16, max_registrations_per_block: u16);
+    fn set_max_burn_registrations_per_block(netuid: u16, max_registrations_per_block: u16);
+    fn set_adjustment_alpha(netuid: u16, adjustment_alpha: u64);
+    fn set_target_registrations_per_interval(netuid: u16, target_registrations_per_interval: u16);
+    fn set_network_pow_registration_allowed(netuid: u16, registration_allowed: bool);
+    fn set_network_registration_allowed(netuid: u16, registration_allowed: bool);
+    fn set_activity_cutoff(netuid: u16, activity_cutoff: u16);
+    fn get_min_activity_cutoff() -> u16;
+    fn set_min_activity_cutoff(min_activity_cutoff: u16);
+    fn ensure_subnet_owner_or_root(o: RuntimeOrigin, netuid: u16) -> Result<(), DispatchError>;
+    fn set_rho(netuid: u16, rho: u16);
+    fn set_kappa(netuid: u16, kappa: u16);
+    fn set_max_stake_weight_fraction(netuid: u16, fraction: u16);
+    fn set_max_allowed_uids(netuid: u16, max_allowed: u16);
+    fn set_max_root_validators(max_validators: u16);
+    fn set_min_allowed_weights(netuid: u16, min_allowed_weights: u16);
+    fn set_immunity_period(netuid: u16, immunity_period: u16);
+    fn set_max_weight_limit(netuid: u16, max_weight_limit: u16);
+    fn set_scaling_law_power(netuid: u16, scaling_law_power: u16);
+    fn set_validator_prune_len(netuid: u16, validator_prune_len: u64);
+    fn set_adjustment_interval(netuid: u16, adjustment_interval: u16);
+    fn set_weights_set_rate_limit(netuid: u16, weights_set_rate_limit: u64);
+    fn init_new_network(netuid: u16, tempo: u16);
+    fn set_weights_min_stake(min_stake: u64);
+    fn get_nominator_min_required_stake() -> u64;
+    fn set_nominator_min_required_stake(min_stake: u64);
+    fn set_fee_reserve(fee_reserve: u64);
+    fn clear_small_nominations();
+    fn set_target_stakes_per_interval(target_stakes_per_interval: u64);
+    fn set_commit_reveal_weights_interval(netuid: u16, interval: u64);
+    fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool);
+    fn set_liquid_alpha_enabled(netuid: u16, enabled: bool);
+    fn do_set_alpha_values(
+        origin: RuntimeOrigin,
+        netuid: u16,
+        alpha_low: u16,
+        alpha_high: u16,
+    ) -> Result<(), DispatchError>;
+    fn reserve_netuid(netuid: u16, min_bid: u64, duration: u64) -> Result<(), DispatchError>;
+    fn release_netuid(netuid: u16) -> Result<(), DispatchError>;
+    fn ban_hotkey(hotkey: &AccountId) -> Result<(), DispatchError>;
+    fn unban_hotkey(hotkey: &AccountId) -> Result<(), DispatchError>;
+    fn set_stake_wind_down_chunk_size(chunk_size: u16);
+    fn set_axon_ttl_blocks(netuid: u16, axon_ttl_blocks: u64);
+    fn set_utilization_floor(utilization_floor: u16);
+    fn set_low_utilization_burn(low_utilization_burn: u16);
+    fn schedule_max_allowed_uids_growth(
+        netuid: u16,
+        step: u16,
+        interval: u64,
+        target: u16,
     ) -> Result<(), DispatchError>;
+    fn cancel_max_allowed_uids_growth(netuid: u16) -> Result<(), DispatchError>;
+    fn prune_orphaned_storage(limit: u32) -> u32;
+    fn set_hotkey_emission_tempo(hotkey_emission_tempo: u64);
+    fn set_epoch_pause_threshold(threshold: u16);
+    fn set_epoch_pause_duration(duration: u64);
+    fn set_stake_fee(flat: u64, bps: u16);
+    fn set_stake_fee_dust_threshold(threshold: u64);
+    fn set_stake_fee_treasury(treasury: Option<AccountId>);
+    fn set_weights_sweep_chunk_size(chunk_size: u16);
+    fn set_max_miner_uids(netuid: u16, max_miner_uids: u16);
+    fn set_root_epoch_block_budget(budget: u32);
+    fn set_bootstrap_incentive_floor(netuid: u16, floor: u16);
+    fn can_pause_weights(netuid: u16) -> bool;
+    fn set_weights_paused(netuid: u16, paused: bool);
+    fn set_max_weights_pause_duration(duration: u64);
+    fn get_owner_hyperparam_bounds(netuid: u16, param: OwnerHyperparam) -> (u16, u16);
+    fn passes_owner_hyperparam_rate_limit(netuid: u16, param: OwnerHyperparam) -> bool;
+    fn set_last_owner_hyperparam_update(netuid: u16, param: OwnerHyperparam);
+    fn set_owner_hyperparam_rate_limit(rate_limit: u64);
+    fn set_max_immunity_period(max_immunity_period: u16);
+    fn set_network_lock_cost_multiplier(multiplier: u32);
+    fn set_referendum_duration(duration: u64);
+    fn set_referendum_threshold(threshold: u16);
+    fn set_netuid_quarantine_period(period: u64);
+    fn set_axon_reachability_half_life(half_life: u64);
+    fn set_min_stake_to_register(netuid: u16, min_stake: u64);
+    fn get_max_min_stake_to_register() -> u64;
+    fn set_max_min_stake_to_register(max_min_stake: u64);
+    fn set_pending_child_key_cooldown(cooldown: u64);
+    fn schedule_bonds_reset(netuid: u16);
+    fn set_bonds_reset_chunk_size(chunk_size: u16);
+    fn set_arbitration_period(period: u64);
+    fn set_take_cap_threshold(threshold: u16);
+    fn set_take_cap_duration(duration: u64);
+    fn set_identity_deposit(deposit: u64);
+    fn set_call_metrics_era_length(era_length: u64);
+    fn set_faucet_rate_limit(faucet_rate_limit: u64);
+    fn set_root_weights_safe_mode(enabled: bool, stake_threshold: u64, min_spread: u16);
+    fn set_root_stake_cap(cap: u16);
+    fn set_unregister_refund(window: u64, share: u16);
+    fn set_emission_multiplier(netuid: u16, multiplier: u32);
+    fn get_min_delegate_take() -> u16;
+    fn set_max_take_per_subnet(netuid: u16, max_take: u16);
+    fn set_transaction_type_rate_limit(tx_type: TransactionType, rate_limit: u64);
+    fn set_stake_lock_boosts(boosts: Vec<(u64, u32)>);
+    fn set_unstake_cooldown(cooldown: u64);
+    fn migrate_subnet(old_netuid: u16, new_netuid: u16) -> Result<(), DispatchError>;
+    fn set_weights_precision(netuid: u16, precision: u8);
+    fn set_delegate_underperformance(trust_threshold: u16, tempos: u16, take: u16);
 }
@@ -62,6 +62,8 @@ pub trait WeightInfo {
 	fn sudo_set_tempo() -> Weight;
 	fn sudo_set_commit_reveal_weights_interval() -> Weight;
 	fn sudo_set_commit_reveal_weights_enabled() -> Weight;
+	fn sudo_set_stake_lock_boosts() -> Weight;
+	fn sudo_set_root_max_uids() -> Weight;
 	
 }
 
@@ -432,6 +434,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	fn sudo_set_stake_lock_boosts() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1024`
+		//  Estimated: `1024`
+		// Minimum execution time: 18_360_000 picoseconds.
+		Weight::from_parts(19_140_000, 1024)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_root_max_uids() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1098`
+		//  Estimated: `4563`
+		// Minimum execution time: 21_870_000 picoseconds.
+		Weight::from_parts(22_510_000, 4563)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -806,4 +825,27 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	fn sudo_set_stake_lock_boosts() -> Weight {
+		// -- Extrinsic Time --
+		// Model:
+		// Time ~=    19.14
+		//               µs
+		// Reads = 0
+		// Writes = 1
+		// Recorded proof Size = 0
+		Weight::from_parts(19_140_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_root_max_uids() -> Weight {
+		// -- Extrinsic Time --
+		// Model:
+		// Time ~=    22.51
+		//               µs
+		// Reads = 1
+		// Writes = 1
+		// Recorded proof Size = 0
+		Weight::from_parts(22_510_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
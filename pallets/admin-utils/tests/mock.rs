@@ -115,6 +115,38 @@ parameter_types! {
     pub const InitialAlphaLow: u16 = 45875; // Represents 0.7 as per the production default
     pub const InitialLiquidAlphaOn: bool = false; // Default value for LiquidAlphaOn
     pub const InitialBaseDifficulty: u64 = 10_000; // Base difficulty
+    pub const InitialRootTempo: u16 = 100;
+    pub const InitialRootMaxUids: u16 = 64;
+    pub const NewNetworkTempo: u16 = 360;
+    pub const NewNetworkMaxAllowedUids: u16 = 256;
+    pub const NewNetworkMaxAllowedValidators: u16 = 64;
+    pub const NewNetworkMinAllowedWeights: u16 = 1;
+    pub const NewNetworkAdjustmentInterval: u16 = 360;
+    pub const NewNetworkTargetRegistrationsPerInterval: u16 = 1;
+    pub const NewNetworkAdjustmentAlpha: u64 = 17_893_341_751_498_265_066; // 0.97
+    pub const NewNetworkImmunityPeriod: u16 = 5000;
+    pub const NewNetworkMinBurn: u64 = 1;
+}
+
+/// A signature in tests: the signing account and the payload it signed.
+#[derive(codec::Encode, codec::Decode, scale_info::TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct TestKeySignature(pub U256, pub Vec<u8>);
+
+/// The signer of a `TestKeySignature`.
+pub struct TestKeySigner(pub U256);
+
+impl sp_runtime::traits::IdentifyAccount for TestKeySigner {
+    type AccountId = U256;
+    fn into_account(self) -> U256 {
+        self.0
+    }
+}
+
+impl sp_runtime::traits::Verify for TestKeySignature {
+    type Signer = TestKeySigner;
+    fn verify<L: sp_runtime::traits::Lazy<[u8]>>(&self, mut msg: L, signer: &U256) -> bool {
+        self.0 == *signer && msg.get() == self.1.as_slice()
+    }
 }
 
 impl pallet_subtensor::Config for Test {
@@ -125,6 +157,10 @@ impl pallet_subtensor::Config for Test {
     type CouncilOrigin = EnsureNever<AccountId>;
     type SenateMembers = ();
     type TriumvirateInterface = ();
+    type OnSubtensorEvent = ();
+    type KeySignature = TestKeySignature;
+    type KeySigner = TestKeySigner;
+    type WeightInfo = ();
 
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -171,6 +207,17 @@ impl pallet_subtensor::Config for Test {
     type AlphaLow = InitialAlphaLow;
     type LiquidAlphaOn = InitialLiquidAlphaOn;
     type InitialBaseDifficulty = InitialBaseDifficulty;
+    type InitialRootTempo = InitialRootTempo;
+    type InitialRootMaxUids = InitialRootMaxUids;
+    type NewNetworkTempo = NewNetworkTempo;
+    type NewNetworkMaxAllowedUids = NewNetworkMaxAllowedUids;
+    type NewNetworkMaxAllowedValidators = NewNetworkMaxAllowedValidators;
+    type NewNetworkMinAllowedWeights = NewNetworkMinAllowedWeights;
+    type NewNetworkAdjustmentInterval = NewNetworkAdjustmentInterval;
+    type NewNetworkTargetRegistrationsPerInterval = NewNetworkTargetRegistrationsPerInterval;
+    type NewNetworkAdjustmentAlpha = NewNetworkAdjustmentAlpha;
+    type NewNetworkImmunityPeriod = NewNetworkImmunityPeriod;
+    type NewNetworkMinBurn = NewNetworkMinBurn;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -343,8 +390,24 @@ impl pallet_admin_utils::SubtensorInterface<AccountId, Balance, RuntimeOrigin> f
         SubtensorModule::set_network_min_lock(net_min_lock);
     }
 
-    fn set_subnet_limit(limit: u16) {
-        SubtensorModule::set_max_subnets(limit);
+    fn set_subnet_limit(limit: u16) -> u64 {
+        SubtensorModule::schedule_max_subnets(limit)
+    }
+
+    fn set_subnet_limit_change_delay(delay: u64) {
+        SubtensorModule::set_subnet_limit_change_delay(delay);
+    }
+
+    fn set_max_maintenance_duration(duration: u64) {
+        SubtensorModule::set_max_maintenance_duration(duration);
+    }
+
+    fn set_max_commitment_size(netuid: u16, max_commitment_size: u32) {
+        SubtensorModule::set_max_commitment_size(netuid, max_commitment_size);
+    }
+
+    fn set_commitment_rate_limit(netuid: u16, commitment_rate_limit: u64) {
+        SubtensorModule::set_commitment_rate_limit(netuid, commitment_rate_limit);
     }
 
     fn set_lock_reduction_interval(interval: u64) {
@@ -367,6 +430,14 @@ impl pallet_admin_utils::SubtensorInterface<AccountId, Balance, RuntimeOrigin> f
         SubtensorModule::set_max_registrations_per_block(netuid, max_registrations_per_block);
     }
 
+    fn set_max_pow_registrations_per_block(netuid: u16, max_registrations_per_block: u16) {
+        SubtensorModule::set_max_pow_registrations_per_block(netuid, max_registrations_per_block);
+    }
+
+    fn set_max_burn_registrations_per_block(netuid: u16, max_registrations_per_block: u16) {
+        SubtensorModule::set_max_burn_registrations_per_block(netuid, max_registrations_per_block);
+    }
+
     fn set_adjustment_alpha(netuid: u16, adjustment_alpha: u64) {
         SubtensorModule::set_adjustment_alpha(netuid, adjustment_alpha);
     }
@@ -390,6 +461,14 @@ impl pallet_admin_utils::SubtensorInterface<AccountId, Balance, RuntimeOrigin> f
         SubtensorModule::set_activity_cutoff(netuid, activity_cutoff);
     }
 
+    fn get_min_activity_cutoff() -> u16 {
+        SubtensorModule::get_min_activity_cutoff()
+    }
+
+    fn set_min_activity_cutoff(min_activity_cutoff: u16) {
+        SubtensorModule::set_min_activity_cutoff(min_activity_cutoff);
+    }
+
     fn ensure_subnet_owner_or_root(o: RuntimeOrigin, netuid: u16) -> Result<(), DispatchError> {
         SubtensorModule::ensure_subnet_owner_or_root(o, netuid)
     }
@@ -402,10 +481,22 @@ impl pallet_admin_utils::SubtensorInterface<AccountId, Balance, RuntimeOrigin> f
         SubtensorModule::set_kappa(netuid, kappa);
     }
 
+    fn set_max_stake_weight_fraction(netuid: u16, fraction: u16) {
+        SubtensorModule::set_max_stake_weight_fraction(netuid, fraction);
+    }
+
     fn set_max_allowed_uids(netuid: u16, max_allowed: u16) {
         SubtensorModule::set_max_allowed_uids(netuid, max_allowed);
     }
 
+    fn set_max_root_validators(max_validators: u16) {
+        SubtensorModule::set_max_root_validators(max_validators);
+    }
+
+    fn set_fee_reserve(fee_reserve: u64) {
+        SubtensorModule::set_fee_reserve(fee_reserve);
+    }
+
     fn set_min_allowed_weights(netuid: u16, min_allowed_weights: u16) {
         SubtensorModule::set_min_allowed_weights(netuid, min_allowed_weights);
     }
@@ -459,7 +550,7 @@ impl pallet_admin_utils::SubtensorInterface<AccountId, Balance, RuntimeOrigin> f
     }
 
     fn clear_small_nominations() {
-        SubtensorModule::clear_small_nominations();
+        SubtensorModule::clear_all_small_nominations();
     }
 
     fn set_target_stakes_per_interval(target_stakes_per_interval: u64) {
@@ -485,6 +576,252 @@ impl pallet_admin_utils::SubtensorInterface<AccountId, Balance, RuntimeOrigin> f
     ) -> Result<(), DispatchError> {
         SubtensorModule::do_set_alpha_values(origin, netuid, alpha_low, alpha_high)
     }
+
+    fn reserve_netuid(netuid: u16, min_bid: u64, duration: u64) -> Result<(), DispatchError> {
+        SubtensorModule::do_reserve_netuid(netuid, min_bid, duration)
+    }
+
+    fn release_netuid(netuid: u16) -> Result<(), DispatchError> {
+        SubtensorModule::do_release_netuid(netuid)
+    }
+
+    fn ban_hotkey(hotkey: &AccountId) -> Result<(), DispatchError> {
+        SubtensorModule::do_ban_hotkey(hotkey)
+    }
+
+    fn unban_hotkey(hotkey: &AccountId) -> Result<(), DispatchError> {
+        SubtensorModule::do_unban_hotkey(hotkey)
+    }
+
+    fn set_stake_wind_down_chunk_size(chunk_size: u16) {
+        SubtensorModule::set_stake_wind_down_chunk_size(chunk_size);
+    }
+
+    fn set_axon_ttl_blocks(netuid: u16, axon_ttl_blocks: u64) {
+        SubtensorModule::set_axon_ttl_blocks(netuid, axon_ttl_blocks);
+    }
+
+    fn set_utilization_floor(utilization_floor: u16) {
+        SubtensorModule::set_utilization_floor(utilization_floor);
+    }
+
+    fn set_low_utilization_burn(low_utilization_burn: u16) {
+        SubtensorModule::set_low_utilization_burn(low_utilization_burn);
+    }
+
+    fn schedule_max_allowed_uids_growth(
+        netuid: u16,
+        step: u16,
+        interval: u64,
+        target: u16,
+    ) -> Result<(), DispatchError> {
+        SubtensorModule::do_schedule_max_allowed_uids_growth(netuid, step, interval, target)
+    }
+
+    fn cancel_max_allowed_uids_growth(netuid: u16) -> Result<(), DispatchError> {
+        SubtensorModule::do_cancel_max_allowed_uids_growth(netuid)
+    }
+
+    fn prune_orphaned_storage(limit: u32) -> u32 {
+        SubtensorModule::do_prune_orphaned_storage(limit)
+    }
+
+    fn set_hotkey_emission_tempo(hotkey_emission_tempo: u64) {
+        SubtensorModule::set_hotkey_emission_tempo(hotkey_emission_tempo);
+    }
+
+    fn set_epoch_pause_threshold(threshold: u16) {
+        SubtensorModule::set_epoch_pause_threshold(threshold);
+    }
+
+    fn set_epoch_pause_duration(duration: u64) {
+        SubtensorModule::set_epoch_pause_duration(duration);
+    }
+
+    fn set_stake_fee(flat: u64, bps: u16) {
+        SubtensorModule::set_stake_fee(flat, bps);
+    }
+
+    fn set_stake_fee_dust_threshold(threshold: u64) {
+        SubtensorModule::set_stake_fee_dust_threshold(threshold);
+    }
+
+    fn set_stake_fee_treasury(treasury: Option<AccountId>) {
+        SubtensorModule::set_stake_fee_treasury(treasury);
+    }
+
+    fn set_weights_sweep_chunk_size(chunk_size: u16) {
+        SubtensorModule::set_weights_sweep_chunk_size(chunk_size);
+    }
+
+    fn set_max_miner_uids(netuid: u16, max_miner_uids: u16) {
+        SubtensorModule::set_max_miner_uids(netuid, max_miner_uids);
+    }
+
+    fn set_root_epoch_block_budget(budget: u32) {
+        SubtensorModule::set_root_epoch_block_budget(budget);
+    }
+
+    fn set_bootstrap_incentive_floor(netuid: u16, floor: u16) {
+        SubtensorModule::set_bootstrap_incentive_floor(netuid, floor);
+    }
+
+    fn can_pause_weights(netuid: u16) -> bool {
+        SubtensorModule::can_pause_weights(netuid)
+    }
+
+    fn set_weights_paused(netuid: u16, paused: bool) {
+        SubtensorModule::set_weights_paused(netuid, paused);
+    }
+
+    fn set_max_weights_pause_duration(duration: u64) {
+        SubtensorModule::set_max_weights_pause_duration(duration);
+    }
+
+    fn get_owner_hyperparam_bounds(
+        netuid: u16,
+        param: pallet_subtensor::OwnerHyperparam,
+    ) -> (u16, u16) {
+        SubtensorModule::get_owner_hyperparam_bounds(netuid, param)
+    }
+
+    fn passes_owner_hyperparam_rate_limit(
+        netuid: u16,
+        param: pallet_subtensor::OwnerHyperparam,
+    ) -> bool {
+        SubtensorModule::passes_owner_hyperparam_rate_limit(netuid, param)
+    }
+
+    fn set_last_owner_hyperparam_update(netuid: u16, param: pallet_subtensor::OwnerHyperparam) {
+        SubtensorModule::set_last_owner_hyperparam_update(netuid, param);
+    }
+
+    fn set_owner_hyperparam_rate_limit(rate_limit: u64) {
+        SubtensorModule::set_owner_hyperparam_rate_limit(rate_limit);
+    }
+
+    fn set_max_immunity_period(max_immunity_period: u16) {
+        SubtensorModule::set_max_immunity_period(max_immunity_period);
+    }
+
+    fn set_network_lock_cost_multiplier(multiplier: u32) {
+        SubtensorModule::set_network_lock_cost_multiplier(multiplier);
+    }
+
+    fn set_referendum_duration(duration: u64) {
+        SubtensorModule::set_referendum_duration(duration);
+    }
+
+    fn set_referendum_threshold(threshold: u16) {
+        SubtensorModule::set_referendum_threshold(threshold);
+    }
+
+    fn set_netuid_quarantine_period(period: u64) {
+        SubtensorModule::set_netuid_quarantine_period(period);
+    }
+
+    fn set_axon_reachability_half_life(half_life: u64) {
+        SubtensorModule::set_axon_reachability_half_life(half_life);
+    }
+
+    fn set_min_stake_to_register(netuid: u16, min_stake: u64) {
+        SubtensorModule::set_min_stake_to_register(netuid, min_stake);
+    }
+
+    fn get_max_min_stake_to_register() -> u64 {
+        SubtensorModule::get_max_min_stake_to_register()
+    }
+
+    fn set_max_min_stake_to_register(max_min_stake: u64) {
+        SubtensorModule::set_max_min_stake_to_register(max_min_stake);
+    }
+
+    fn set_pending_child_key_cooldown(cooldown: u64) {
+        SubtensorModule::set_pending_child_key_cooldown(cooldown);
+    }
+
+    fn schedule_bonds_reset(netuid: u16) {
+        SubtensorModule::schedule_bonds_reset(netuid);
+    }
+
+    fn set_bonds_reset_chunk_size(chunk_size: u16) {
+        SubtensorModule::set_bonds_reset_chunk_size(chunk_size);
+    }
+
+    fn set_arbitration_period(period: u64) {
+        SubtensorModule::set_arbitration_period(period);
+    }
+
+    fn set_take_cap_threshold(threshold: u16) {
+        SubtensorModule::set_take_cap_threshold(threshold);
+    }
+
+    fn set_take_cap_duration(duration: u64) {
+        SubtensorModule::set_take_cap_duration(duration);
+    }
+
+    fn set_identity_deposit(deposit: u64) {
+        SubtensorModule::set_identity_deposit(deposit);
+    }
+
+    fn set_call_metrics_era_length(era_length: u64) {
+        SubtensorModule::set_call_metrics_era_length(era_length);
+    }
+
+    fn set_faucet_rate_limit(faucet_rate_limit: u64) {
+        SubtensorModule::set_faucet_rate_limit(faucet_rate_limit);
+    }
+
+    fn set_root_weights_safe_mode(enabled: bool, stake_threshold: u64, min_spread: u16) {
+        SubtensorModule::set_root_weights_safe_mode(enabled, stake_threshold, min_spread);
+    }
+
+    fn set_root_stake_cap(cap: u16) {
+        SubtensorModule::set_root_stake_cap(cap);
+    }
+
+    fn set_unregister_refund(window: u64, share: u16) {
+        SubtensorModule::set_unregister_refund(window, share);
+    }
+
+    fn set_emission_multiplier(netuid: u16, multiplier: u32) {
+        SubtensorModule::set_emission_multiplier(netuid, multiplier);
+    }
+
+    fn get_min_delegate_take() -> u16 {
+        SubtensorModule::get_min_delegate_take()
+    }
+
+    fn set_max_take_per_subnet(netuid: u16, max_take: u16) {
+        SubtensorModule::set_max_take_per_subnet(netuid, max_take);
+    }
+
+    fn set_transaction_type_rate_limit(
+        tx_type: pallet_subtensor::TransactionType,
+        rate_limit: u64,
+    ) {
+        SubtensorModule::set_transaction_type_rate_limit(tx_type, rate_limit);
+    }
+
+    fn set_stake_lock_boosts(boosts: Vec<(u64, u32)>) {
+        SubtensorModule::set_stake_lock_boosts(boosts);
+    }
+
+    fn set_unstake_cooldown(cooldown: u64) {
+        SubtensorModule::set_unstake_cooldown(cooldown);
+    }
+
+    fn migrate_subnet(old_netuid: u16, new_netuid: u16) -> Result<(), DispatchError> {
+        SubtensorModule::do_start_subnet_migration(old_netuid, new_netuid)
+    }
+
+    fn set_weights_precision(netuid: u16, precision: u8) {
+        SubtensorModule::set_weights_precision(netuid, precision);
+    }
+
+    fn set_delegate_underperformance(trust_threshold: u16, tempos: u16, take: u16) {
+        SubtensorModule::set_delegate_underperformance(trust_threshold, tempos, take);
+    }
 }
 
 impl pallet_admin_utils::Config for Test {
@@ -2,6 +2,7 @@ use frame_support::sp_runtime::DispatchError;
 use frame_support::{
     assert_err, assert_ok,
     dispatch::{DispatchClass, GetDispatchInfo, Pays},
+    weights::Weight,
 };
 use frame_system::Config;
 use pallet_admin_utils::Error;
@@ -433,6 +434,37 @@ fn test_sudo_set_max_allowed_uids() {
     });
 }
 
+#[test]
+fn test_sudo_set_root_max_uids() {
+    new_test_ext().execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid = SubtensorModule::get_root_netuid();
+        let to_be_set: u16 = 32;
+        let init_value: u16 = SubtensorModule::get_max_root_validators();
+        assert_eq!(
+            AdminUtils::sudo_set_root_max_uids(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_max_root_validators(), init_value);
+        assert_ok!(AdminUtils::sudo_set_root_max_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_root_validators(), to_be_set);
+
+        // Resizing subnet uids through the generic setter leaves the root network alone.
+        assert_ok!(AdminUtils::sudo_set_max_allowed_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            root_netuid,
+            8
+        ));
+        assert_eq!(SubtensorModule::get_max_root_validators(), to_be_set);
+    });
+}
+
 #[test]
 fn test_sudo_set_and_decrease_max_allowed_uids() {
     new_test_ext().execute_with(|| {
@@ -503,6 +535,43 @@ fn test_sudo_set_kappa() {
     });
 }
 
+#[test]
+fn test_sudo_set_kappa_by_subnet_owner_hotkey() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        let operator = U256::from(11);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_kappa(
+                <<Test as Config>::RuntimeOrigin>::signed(operator),
+                netuid,
+                10
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(SubtensorModule::set_subnet_owner_hotkey(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            operator
+        ));
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::signed(operator),
+            netuid,
+            10
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), 10);
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            20
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), 20);
+    });
+}
+
 #[test]
 fn test_sudo_set_rho() {
     new_test_ext().execute_with(|| {
@@ -569,6 +638,252 @@ fn test_sudo_set_activity_cutoff() {
     });
 }
 
+#[test]
+fn test_sudo_set_activity_cutoff_by_owner_respects_min() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let min_activity_cutoff = SubtensorModule::get_min_activity_cutoff();
+
+        assert_eq!(
+            AdminUtils::sudo_set_activity_cutoff(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                min_activity_cutoff - 1
+            ),
+            Err(Error::<Test>::ActivityCutoffTooLow.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_activity_cutoff(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            min_activity_cutoff
+        ));
+        assert_eq!(
+            SubtensorModule::get_activity_cutoff(netuid),
+            min_activity_cutoff
+        );
+
+        // Lowering the minimum lets the owner go lower.
+        assert_ok!(AdminUtils::sudo_set_min_activity_cutoff(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_ok!(AdminUtils::sudo_set_activity_cutoff(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            100
+        ));
+        assert_eq!(SubtensorModule::get_activity_cutoff(netuid), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_min_activity_cutoff() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = 100;
+        let init_value: u16 = SubtensorModule::get_min_activity_cutoff();
+        assert_eq!(
+            AdminUtils::sudo_set_min_activity_cutoff(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_min_activity_cutoff(), init_value);
+        assert_ok!(AdminUtils::sudo_set_min_activity_cutoff(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_min_activity_cutoff(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_registrations_per_block_by_route() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u16 = 2;
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_max_pow_registrations_per_block(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_burn_registrations_per_block(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            SubtensorModule::get_max_pow_registrations_per_block(netuid),
+            u16::MAX
+        );
+        assert_eq!(
+            SubtensorModule::get_max_burn_registrations_per_block(netuid),
+            u16::MAX
+        );
+        assert_ok!(AdminUtils::sudo_set_max_pow_registrations_per_block(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_ok!(AdminUtils::sudo_set_max_burn_registrations_per_block(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set + 1
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_pow_registrations_per_block(netuid),
+            to_be_set
+        );
+        assert_eq!(
+            SubtensorModule::get_max_burn_registrations_per_block(netuid),
+            to_be_set + 1
+        );
+    });
+}
+
+#[test]
+fn test_sudo_reserve_and_release_netuid() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 7;
+        assert_eq!(
+            AdminUtils::sudo_reserve_netuid(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                1_000,
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_reserve_netuid(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            1_000,
+            100
+        ));
+        assert_eq!(
+            SubtensorModule::get_reserved_netuid(netuid),
+            Some((1_000, 100))
+        );
+        assert_eq!(
+            AdminUtils::sudo_reserve_netuid(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                1_000,
+                100
+            ),
+            Err(SubtensorError::<Test>::NetuidAlreadyInUse.into())
+        );
+        assert_ok!(AdminUtils::sudo_release_netuid(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid
+        ));
+        assert!(!SubtensorModule::is_netuid_reserved(netuid));
+        assert_eq!(
+            AdminUtils::sudo_release_netuid(<<Test as Config>::RuntimeOrigin>::root(), netuid),
+            Err(SubtensorError::<Test>::NetuidNotReserved.into())
+        );
+    });
+}
+
+#[test]
+fn test_sudo_ban_hotkey() {
+    new_test_ext().execute_with(|| {
+        let hotkey = U256::from(1);
+        assert_eq!(
+            AdminUtils::sudo_ban_hotkey(<<Test as Config>::RuntimeOrigin>::signed(hotkey), hotkey),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_ban_hotkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            hotkey
+        ));
+        assert!(SubtensorModule::is_hotkey_banned(&hotkey));
+        assert_eq!(
+            AdminUtils::sudo_ban_hotkey(<<Test as Config>::RuntimeOrigin>::root(), hotkey),
+            Err(SubtensorError::<Test>::HotkeyAlreadyBanned.into())
+        );
+
+        assert_eq!(
+            AdminUtils::sudo_unban_hotkey(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                hotkey
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_unban_hotkey(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            hotkey
+        ));
+        assert!(!SubtensorModule::is_hotkey_banned(&hotkey));
+        assert_eq!(
+            AdminUtils::sudo_unban_hotkey(<<Test as Config>::RuntimeOrigin>::root(), hotkey),
+            Err(SubtensorError::<Test>::HotkeyNotBanned.into())
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_stake_wind_down_chunk_size() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = 7;
+        assert_eq!(
+            AdminUtils::sudo_set_stake_wind_down_chunk_size(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_stake_wind_down_chunk_size(), 64);
+        assert_ok!(AdminUtils::sudo_set_stake_wind_down_chunk_size(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_stake_wind_down_chunk_size(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_axon_ttl_blocks() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u64 = 100;
+        add_network(netuid, 10);
+        assert_eq!(SubtensorModule::get_axon_ttl_blocks(netuid), 0);
+        assert_eq!(
+            AdminUtils::sudo_set_axon_ttl_blocks(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_axon_ttl_blocks(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_axon_ttl_blocks(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_axon_ttl_blocks(netuid), to_be_set);
+    });
+}
+
 #[test]
 fn test_sudo_set_target_registrations_per_interval() {
     new_test_ext().execute_with(|| {
@@ -819,10 +1134,68 @@ fn test_sudo_set_subnet_limit() {
             <<Test as Config>::RuntimeOrigin>::root(),
             to_be_set
         ));
+        // The limit is only announced, it applies after the change delay.
+        assert_eq!(SubtensorModule::get_max_subnets(), init_value);
+        let apply_block = SubtensorModule::get_current_block_as_u64()
+            + SubtensorModule::get_subnet_limit_change_delay();
+        assert_eq!(
+            pallet_subtensor::ScheduledSubnetLimits::<Test>::get(apply_block),
+            Some(to_be_set)
+        );
+        SubtensorModule::apply_scheduled_subnet_limit(apply_block);
         assert_eq!(SubtensorModule::get_max_subnets(), to_be_set);
     });
 }
 
+#[test]
+fn test_sudo_set_subnet_limit_change_delay() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 0;
+        let init_value: u64 = SubtensorModule::get_subnet_limit_change_delay();
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_limit_change_delay(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_subnet_limit_change_delay(), init_value);
+        assert_ok!(AdminUtils::sudo_set_subnet_limit_change_delay(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_subnet_limit_change_delay(), to_be_set);
+
+        // Without a delay a new limit applies immediately.
+        assert_ok!(AdminUtils::sudo_set_subnet_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            7
+        ));
+        assert_eq!(SubtensorModule::get_max_subnets(), 7);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_maintenance_duration() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 100;
+        let init_value: u64 = SubtensorModule::get_max_maintenance_duration();
+        assert_eq!(
+            AdminUtils::sudo_set_max_maintenance_duration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_max_maintenance_duration(), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_maintenance_duration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_maintenance_duration(), to_be_set);
+    });
+}
+
 #[test]
 fn test_sudo_set_network_lock_reduction_interval() {
     new_test_ext().execute_with(|| {
@@ -1361,3 +1734,1326 @@ fn test_sudo_get_set_alpha() {
         ));
     });
 }
+
+#[test]
+fn test_sudo_set_fee_reserve() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 5_000_000;
+        let init_value: u64 = SubtensorModule::get_fee_reserve();
+        assert_eq!(
+            AdminUtils::sudo_set_fee_reserve(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_fee_reserve(), init_value);
+        assert_ok!(AdminUtils::sudo_set_fee_reserve(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_fee_reserve(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_commitment_size() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u32 = 256;
+        add_network(netuid, 10);
+        let init_value: u32 = SubtensorModule::get_max_commitment_size(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_max_commitment_size(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_commitment_size(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_max_commitment_size(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_commitment_size(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_commitment_size(netuid), to_be_set);
+
+        // The limit is capped at the pallet's hard maximum.
+        assert_ok!(AdminUtils::sudo_set_max_commitment_size(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            u32::MAX
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_commitment_size(netuid),
+            pallet_subtensor::MAX_COMMITMENT_BYTES
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_commitment_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u64 = 10;
+        add_network(netuid, 10);
+        let init_value: u64 = SubtensorModule::get_commitment_rate_limit(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_commitment_rate_limit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_commitment_rate_limit(netuid),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_commitment_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_commitment_rate_limit(netuid),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_utilization_floor_and_low_utilization_burn() {
+    new_test_ext().execute_with(|| {
+        let floor: u16 = u16::MAX / 4;
+        let burn: u16 = u16::MAX / 2;
+        assert_eq!(
+            AdminUtils::sudo_set_utilization_floor(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                floor
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_low_utilization_burn(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                burn
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_utilization_floor(), 0);
+        assert_eq!(SubtensorModule::get_low_utilization_burn(), 0);
+        assert_ok!(AdminUtils::sudo_set_utilization_floor(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            floor
+        ));
+        assert_ok!(AdminUtils::sudo_set_low_utilization_burn(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            burn
+        ));
+        assert_eq!(SubtensorModule::get_utilization_floor(), floor);
+        assert_eq!(SubtensorModule::get_low_utilization_burn(), burn);
+    });
+}
+
+#[test]
+fn test_sudo_schedule_and_cancel_max_allowed_uids_growth() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        SubtensorModule::set_max_allowed_uids(netuid, 64);
+        assert_eq!(
+            AdminUtils::sudo_schedule_max_allowed_uids_growth(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                64,
+                100,
+                256
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_schedule_max_allowed_uids_growth(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                64,
+                100,
+                64
+            ),
+            Err(SubtensorError::<Test>::InvalidMaxAllowedUidsGrowth.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_schedule_max_allowed_uids_growth(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                64,
+                100,
+                256
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_schedule_max_allowed_uids_growth(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            64,
+            100,
+            256
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_allowed_uids_growth(netuid),
+            Some((64, 100, 256, 101))
+        );
+
+        assert_ok!(AdminUtils::sudo_cancel_max_allowed_uids_growth(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid
+        ));
+        assert_eq!(SubtensorModule::get_max_allowed_uids_growth(netuid), None);
+        assert_eq!(
+            AdminUtils::sudo_cancel_max_allowed_uids_growth(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid
+            ),
+            Err(SubtensorError::<Test>::NoMaxAllowedUidsGrowth.into())
+        );
+    });
+}
+
+#[test]
+fn test_sudo_prune_orphaned_storage() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        SubtensorModule::insert_loaded_emission(netuid, vec![]);
+        assert_eq!(
+            AdminUtils::sudo_prune_orphaned_storage(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                10
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert!(SubtensorModule::get_loaded_emission_tuples(netuid).is_some());
+
+        // The subnet has no uids, so its loaded emission is orphaned.
+        assert_ok!(AdminUtils::sudo_prune_orphaned_storage(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            10
+        ));
+        assert!(SubtensorModule::get_loaded_emission_tuples(netuid).is_none());
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::OrphanedStoragePruned(
+            1,
+        )));
+    });
+}
+
+#[test]
+fn test_sudo_set_hotkey_emission_tempo() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 360;
+        assert_eq!(
+            AdminUtils::sudo_set_hotkey_emission_tempo(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_hotkey_emission_tempo(), 0);
+        assert_ok!(AdminUtils::sudo_set_hotkey_emission_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_emission_tempo(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_epoch_pause_threshold_and_duration() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_epoch_pause_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                u16::MAX
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_epoch_pause_duration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_epoch_pause_threshold(), 43_690);
+        assert_eq!(SubtensorModule::get_epoch_pause_duration(), 7200);
+
+        assert_ok!(AdminUtils::sudo_set_epoch_pause_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            u16::MAX
+        ));
+        assert_ok!(AdminUtils::sudo_set_epoch_pause_duration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_epoch_pause_threshold(), u16::MAX);
+        assert_eq!(SubtensorModule::get_epoch_pause_duration(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_stake_fee() {
+    new_test_ext().execute_with(|| {
+        let treasury = U256::from(77);
+        assert_eq!(
+            AdminUtils::sudo_set_stake_fee(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100,
+                50
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_stake_fee(<<Test as Config>::RuntimeOrigin>::root(), 100, 10_001),
+            Err(Error::<Test>::StakeFeeBpsTooHigh.into())
+        );
+        assert_eq!(SubtensorModule::get_stake_fee(10_000), 0);
+
+        assert_ok!(AdminUtils::sudo_set_stake_fee(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100,
+            50
+        ));
+        assert_ok!(AdminUtils::sudo_set_stake_fee_dust_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            1_000
+        ));
+        assert_ok!(AdminUtils::sudo_set_stake_fee_treasury(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Some(treasury)
+        ));
+        assert_eq!(SubtensorModule::get_stake_fee(999), 0);
+        assert_eq!(SubtensorModule::get_stake_fee(10_000), 150);
+        assert_eq!(SubtensorModule::get_stake_fee_treasury(), Some(treasury));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::StakeFeeTreasurySet(
+            Some(treasury),
+        )));
+    });
+}
+
+#[test]
+fn test_sudo_set_weights_sweep_chunk_size() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = 7;
+        assert_eq!(
+            AdminUtils::sudo_set_weights_sweep_chunk_size(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_weights_sweep_chunk_size(), 64);
+        assert_ok!(AdminUtils::sudo_set_weights_sweep_chunk_size(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_weights_sweep_chunk_size(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_miner_uids() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u16 = 10;
+        add_network(netuid, 10);
+        let init_value: u16 = SubtensorModule::get_max_miner_uids(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_max_miner_uids(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_miner_uids(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_miner_uids(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                SubtensorModule::get_max_allowed_uids(netuid) + 1
+            ),
+            Err(Error::<Test>::MaxMinerUidsLargerThanMaxUIds.into())
+        );
+        assert_eq!(SubtensorModule::get_max_miner_uids(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_miner_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_miner_uids(netuid), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_root_epoch_block_budget() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u32 = 1_000;
+        assert_eq!(
+            AdminUtils::sudo_set_root_epoch_block_budget(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_root_epoch_block_budget(), 65_536);
+        assert_ok!(AdminUtils::sudo_set_root_epoch_block_budget(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_root_epoch_block_budget(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_bootstrap_incentive_floor() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u16 = u16::MAX / 20;
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_bootstrap_incentive_floor(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_bootstrap_incentive_floor(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_bootstrap_incentive_floor(netuid), 0);
+        assert_ok!(AdminUtils::sudo_set_bootstrap_incentive_floor(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_bootstrap_incentive_floor(netuid),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_weights_paused() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_weights_paused(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_weights_paused(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_max_weights_pause_duration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            10
+        ));
+        assert_eq!(SubtensorModule::get_max_weights_pause_duration(), 10);
+
+        assert_ok!(AdminUtils::sudo_set_weights_paused(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert_eq!(SubtensorModule::get_weights_paused_until(netuid), Some(11));
+        assert_ok!(AdminUtils::sudo_set_weights_paused(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            false
+        ));
+        assert_eq!(SubtensorModule::get_weights_paused_until(netuid), None);
+
+        // A new pause has to wait out the cooldown after the last one ended.
+        assert_eq!(
+            AdminUtils::sudo_set_weights_paused(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                true
+            ),
+            Err(Error::<Test>::WeightsPauseOnCooldown.into())
+        );
+        run_to_block(11);
+        assert_ok!(AdminUtils::sudo_set_weights_paused(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+    });
+}
+
+#[test]
+fn test_sudo_set_tempo_by_owner_respects_bounds_and_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_owner_hyperparam_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, 5),
+            Err(Error::<Test>::HyperparamOutsideOwnerBounds.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            360
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 360);
+
+        // The owner waits out the rate limit, root does not.
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                100
+            ),
+            Err(Error::<Test>::OwnerHyperparamRateLimitExceeded.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            5
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 5);
+
+        // Other hyperparameters are rate limited on their own.
+        assert_ok!(AdminUtils::sudo_set_max_registrations_per_block(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            4
+        ));
+        assert_eq!(SubtensorModule::get_max_registrations_per_block(netuid), 4);
+
+        run_to_block(101);
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            100
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_network_lock_cost_multiplier() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_network_lock_cost_multiplier(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                30_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_network_lock_cost_multiplier(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                9_999
+            ),
+            Err(Error::<Test>::LockCostMultiplierTooLow.into())
+        );
+        assert_eq!(SubtensorModule::get_network_lock_cost_multiplier(), 20_000);
+        assert_ok!(AdminUtils::sudo_set_network_lock_cost_multiplier(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            30_000
+        ));
+        assert_eq!(SubtensorModule::get_network_lock_cost_multiplier(), 30_000);
+    });
+}
+
+#[test]
+fn test_sudo_set_referendum_duration_and_threshold() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_referendum_duration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_referendum_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                u16::MAX
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_referendum_duration(), 7200);
+        assert_eq!(SubtensorModule::get_referendum_threshold(), 43_690);
+
+        assert_ok!(AdminUtils::sudo_set_referendum_duration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_ok!(AdminUtils::sudo_set_referendum_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            u16::MAX
+        ));
+        assert_eq!(SubtensorModule::get_referendum_duration(), 100);
+        assert_eq!(SubtensorModule::get_referendum_threshold(), u16::MAX);
+    });
+}
+
+#[test]
+fn test_sudo_set_netuid_quarantine_period() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_netuid_quarantine_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_netuid_quarantine_period(), 0);
+        assert_ok!(AdminUtils::sudo_set_netuid_quarantine_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_netuid_quarantine_period(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_axon_reachability_half_life() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_axon_reachability_half_life(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_axon_reachability_half_life(), 7200);
+        assert_ok!(AdminUtils::sudo_set_axon_reachability_half_life(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_axon_reachability_half_life(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_min_stake_to_register() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_min_stake_to_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_min_stake_to_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_min_stake_to_register(netuid), 0);
+
+        assert_ok!(AdminUtils::sudo_set_max_min_stake_to_register(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_max_min_stake_to_register(), 100);
+        assert_eq!(
+            AdminUtils::sudo_set_min_stake_to_register(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                101
+            ),
+            Err(Error::<Test>::MinStakeToRegisterAboveMax.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_min_stake_to_register(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                100
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_min_stake_to_register(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            100
+        ));
+        assert_eq!(SubtensorModule::get_min_stake_to_register(netuid), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_pending_child_key_cooldown() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_pending_child_key_cooldown(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_pending_child_key_cooldown(), 7200);
+        assert_ok!(AdminUtils::sudo_set_pending_child_key_cooldown(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_pending_child_key_cooldown(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_reset_bonds() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_reset_bonds(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_bonds_reset_chunk_size(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                10
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_reset_bonds(<<Test as Config>::RuntimeOrigin>::root(), netuid + 1),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert!(!SubtensorModule::is_bonds_reset_in_progress(netuid));
+        assert_eq!(SubtensorModule::get_bonds_reset_chunk_size(), 64);
+
+        assert_ok!(AdminUtils::sudo_reset_bonds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid
+        ));
+        assert_ok!(AdminUtils::sudo_set_bonds_reset_chunk_size(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            10
+        ));
+        assert_eq!(SubtensorModule::get_bonds_reset_cursor(netuid), Some(0));
+        assert_eq!(SubtensorModule::get_bonds_reset_chunk_size(), 10);
+    });
+}
+
+#[test]
+fn test_sudo_set_arbitration_period() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_arbitration_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_arbitration_period(), 7200 * 3);
+        assert_ok!(AdminUtils::sudo_set_arbitration_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_arbitration_period(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_take_cap_threshold_and_duration() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_take_cap_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_take_cap_threshold(), 32_768);
+        assert_ok!(AdminUtils::sudo_set_take_cap_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_take_cap_threshold(), 100);
+
+        assert_eq!(
+            AdminUtils::sudo_set_take_cap_duration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_take_cap_duration(), 7200 * 7);
+        assert_ok!(AdminUtils::sudo_set_take_cap_duration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_take_cap_duration(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_identity_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_identity_deposit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_identity_deposit(), 100_000_000);
+        assert_ok!(AdminUtils::sudo_set_identity_deposit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_identity_deposit(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_call_metrics_era_length() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_call_metrics_era_length(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_call_metrics_era_length(), 7200);
+        assert_ok!(AdminUtils::sudo_set_call_metrics_era_length(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_call_metrics_era_length(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_faucet_rate_limit() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_faucet_rate_limit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_faucet_rate_limit(), 7200);
+        assert_ok!(AdminUtils::sudo_set_faucet_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_faucet_rate_limit(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_set_root_weights_safe_mode() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_root_weights_safe_mode(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                true,
+                1000,
+                3
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_root_weights_safe_mode(), (false, 0, 2));
+        assert_ok!(AdminUtils::sudo_set_root_weights_safe_mode(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            true,
+            1000,
+            3
+        ));
+        assert_eq!(
+            SubtensorModule::get_root_weights_safe_mode(),
+            (true, 1000, 3)
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_root_stake_cap() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_root_stake_cap(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                u16::MAX / 2
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_root_stake_cap(), u16::MAX);
+        assert_ok!(AdminUtils::sudo_set_root_stake_cap(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            u16::MAX / 2
+        ));
+        assert_eq!(SubtensorModule::get_root_stake_cap(), u16::MAX / 2);
+    });
+}
+
+#[test]
+fn test_sudo_set_unregister_refund() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_unregister_refund(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100,
+                u16::MAX / 2
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_unregister_refund_window(), 0);
+        assert_ok!(AdminUtils::sudo_set_unregister_refund(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100,
+            u16::MAX / 2
+        ));
+        assert_eq!(SubtensorModule::get_unregister_refund_window(), 100);
+        assert_eq!(SubtensorModule::get_unregister_refund_share(), u16::MAX / 2);
+    });
+}
+
+#[test]
+fn test_sudo_set_emission_multiplier() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_emission_multiplier(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                5_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_emission_multiplier(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                5_000
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_emission_multiplier(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                pallet_subtensor::MAX_EMISSION_MULTIPLIER + 1
+            ),
+            Err(Error::<Test>::EmissionMultiplierTooHigh.into())
+        );
+        assert_eq!(SubtensorModule::get_emission_multiplier(netuid), 10_000);
+        assert_ok!(AdminUtils::sudo_set_emission_multiplier(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            pallet_subtensor::MAX_EMISSION_MULTIPLIER
+        ));
+        assert_eq!(
+            SubtensorModule::get_emission_multiplier(netuid),
+            pallet_subtensor::MAX_EMISSION_MULTIPLIER
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_max_take_per_subnet() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let min_take = SubtensorModule::get_min_delegate_take();
+        let max_take = SubtensorModule::get_max_delegate_take();
+
+        assert_eq!(
+            AdminUtils::sudo_set_max_take_per_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                min_take
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_take_per_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                min_take - 1
+            ),
+            Err(Error::<Test>::MaxTakePerSubnetTooLow.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_max_take_per_subnet(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            min_take
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_take_per_subnet(netuid),
+            Some(min_take)
+        );
+
+        // A cap at the maximum delegate take lifts it.
+        assert_ok!(AdminUtils::sudo_set_max_take_per_subnet(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            max_take
+        ));
+        assert_eq!(SubtensorModule::get_max_take_per_subnet(netuid), None);
+    });
+}
+
+#[test]
+fn test_sudo_set_transaction_type_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let tx_type = pallet_subtensor::TransactionType::Serve;
+        assert_eq!(SubtensorModule::get_transaction_type_rate_limit(tx_type), 0);
+
+        assert_eq!(
+            AdminUtils::sudo_set_transaction_type_rate_limit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                tx_type,
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_transaction_type_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            tx_type,
+            100
+        ));
+        assert_eq!(
+            SubtensorModule::get_transaction_type_rate_limit(tx_type),
+            100
+        );
+        assert_eq!(
+            SubtensorModule::get_transaction_type_rate_limit(
+                pallet_subtensor::TransactionType::RegisterNetwork
+            ),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_stake_lock_boosts() {
+    new_test_ext().execute_with(|| {
+        let boosts = vec![(7_200, 12_000), (72_000, 20_000)];
+        assert_eq!(
+            AdminUtils::sudo_set_stake_lock_boosts(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                boosts.clone()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        for invalid in [
+            vec![(72_000, 20_000), (7_200, 12_000)],
+            vec![(7_200, 20_000), (72_000, 12_000)],
+            vec![(7_200, 9_999)],
+            vec![(7_200, pallet_subtensor::MAX_STAKE_LOCK_BOOST + 1)],
+            vec![(pallet_subtensor::MAX_STAKE_LOCK_BLOCKS + 1, 12_000)],
+        ] {
+            assert_eq!(
+                AdminUtils::sudo_set_stake_lock_boosts(
+                    <<Test as Config>::RuntimeOrigin>::root(),
+                    invalid
+                ),
+                Err(Error::<Test>::InvalidStakeLockBoosts.into())
+            );
+        }
+        assert_ok!(AdminUtils::sudo_set_stake_lock_boosts(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            boosts.clone()
+        ));
+        assert_eq!(pallet_subtensor::StakeLockBoosts::<Test>::get(), boosts);
+    });
+}
+
+#[test]
+fn test_sudo_set_unstake_cooldown() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(SubtensorModule::get_unstake_cooldown(), 0);
+        assert_eq!(
+            AdminUtils::sudo_set_unstake_cooldown(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_unstake_cooldown(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_unstake_cooldown(), 100);
+    });
+}
+
+#[test]
+fn test_sudo_migrate_subnet() {
+    new_test_ext().execute_with(|| {
+        let old_netuid: u16 = 1;
+        let new_netuid: u16 = 5;
+        add_network(old_netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_migrate_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                old_netuid,
+                new_netuid
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_migrate_subnet(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            old_netuid,
+            new_netuid
+        ));
+        assert!(!SubtensorModule::if_subnet_exist(old_netuid));
+        assert!(SubtensorModule::is_subnet_migrating(new_netuid));
+
+        SubtensorModule::advance_subnet_migration(Weight::MAX);
+        assert!(SubtensorModule::if_subnet_exist(new_netuid));
+        assert_eq!(SubtensorModule::get_tempo(new_netuid), 10);
+        assert_eq!(SubtensorModule::get_subnet_migration(), None);
+    });
+}
+
+#[test]
+fn test_sudo_set_weights_precision() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert_eq!(SubtensorModule::get_weights_precision(netuid), 16);
+        assert_eq!(
+            AdminUtils::sudo_set_weights_precision(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                8
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_weights_precision(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                8
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        for precision in [0, 17] {
+            assert_eq!(
+                AdminUtils::sudo_set_weights_precision(
+                    <<Test as Config>::RuntimeOrigin>::root(),
+                    netuid,
+                    precision
+                ),
+                Err(Error::<Test>::InvalidWeightsPrecision.into())
+            );
+        }
+        assert_ok!(AdminUtils::sudo_set_weights_precision(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            8
+        ));
+        assert_eq!(SubtensorModule::get_weights_precision(netuid), 8);
+    });
+}
+
+#[test]
+fn test_sudo_set_delegate_underperformance() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_delegate_underperformance(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                30_000,
+                2,
+                1_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_delegate_underperformance(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            30_000,
+            2,
+            1_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_delegate_underperformance(),
+            (30_000, 2, 1_000)
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_immunity_period_by_owner_respects_max() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_eq!(
+            AdminUtils::sudo_set_max_immunity_period(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_max_immunity_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(SubtensorModule::get_max_immunity_period(), 100);
+        assert_ok!(AdminUtils::sudo_set_owner_hyperparam_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+
+        assert_eq!(
+            AdminUtils::sudo_set_immunity_period(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                101
+            ),
+            Err(Error::<Test>::HyperparamOutsideOwnerBounds.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_immunity_period(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            100
+        ));
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 100);
+
+        // The owner waits out the rate limit, root is bound by neither.
+        assert_eq!(
+            AdminUtils::sudo_set_immunity_period(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                50
+            ),
+            Err(Error::<Test>::OwnerHyperparamRateLimitExceeded.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_immunity_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            5000
+        ));
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 5000);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_stake_weight_fraction() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_owner_hyperparam_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_stake_weight_fraction(netuid),
+            u16::MAX
+        );
+
+        assert_eq!(
+            AdminUtils::sudo_set_max_stake_weight_fraction(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(11)),
+                netuid,
+                32_768
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_stake_weight_fraction(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                32_768
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_stake_weight_fraction(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                1_000
+            ),
+            Err(Error::<Test>::HyperparamOutsideOwnerBounds.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_max_stake_weight_fraction(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            32_768
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_stake_weight_fraction(netuid),
+            32_768
+        );
+
+        // The owner waits out the rate limit, root is bound by neither.
+        assert_eq!(
+            AdminUtils::sudo_set_max_stake_weight_fraction(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                u16::MAX
+            ),
+            Err(Error::<Test>::OwnerHyperparamRateLimitExceeded.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_max_stake_weight_fraction(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            1_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_stake_weight_fraction(netuid),
+            1_000
+        );
+    });
+}
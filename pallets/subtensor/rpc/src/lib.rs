@@ -12,7 +12,7 @@ use std::sync::Arc;
 use sp_api::ProvideRuntimeApi;
 
 pub use subtensor_custom_rpc_runtime_api::{
-    DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, SubnetInfoRuntimeApi,
+    DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, StakeInfoRuntimeApi, SubnetInfoRuntimeApi,
     SubnetRegistrationRuntimeApi, SubtensorRuntimeApi,
 };
 
@@ -41,19 +41,114 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_neurons(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "neuronInfo_getNeuron")]
     fn get_neuron(&self, netuid: u16, uid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getAxons")]
+    fn get_axons(&self, netuid: u16, uid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronsByIp")]
+    fn get_neurons_by_ip(&self, ip: u128, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronCertificate")]
+    fn get_neuron_certificate(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronsPaginated")]
+    fn get_neurons_paginated(
+        &self,
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronsLitePaginated")]
+    fn get_neurons_lite_paginated(
+        &self,
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "subnetInfo_getSubnetInfo")]
     fn get_subnet_info(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetsInfo")]
     fn get_subnets_info(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getSubnetInfoV2")]
+    fn get_subnet_info_v2(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getSubnetsInfoV2")]
+    fn get_subnets_info_v2(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetHyperparams")]
     fn get_subnet_hyperparams(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getWeightsSparse")]
+    fn get_weights_sparse(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getBondsSparse")]
+    fn get_bonds_sparse(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getSubnetRecycleInfo")]
+    fn get_subnet_recycle_info(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_dumpSubnetState")]
+    fn dump_subnet_state(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getEpochSnapshots")]
+    fn get_epoch_snapshots(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getEvictionLog")]
+    fn get_eviction_log(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getAllSubnetNetuids")]
+    fn get_all_subnet_netuids(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getEvmKeyAssociations")]
+    fn get_evm_key_associations(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getRootWeights")]
+    fn get_root_weights(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getRootEmissionInfo")]
+    fn get_root_emission_info(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_simulateRootEpoch")]
+    fn simulate_root_epoch(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getPowStats")]
+    fn get_pow_stats(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getEmissionProof")]
+    fn get_emission_proof(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getAdjustmentInfo")]
+    fn get_adjustment_info(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getAllEpochSchedules")]
+    fn get_all_epoch_schedules(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "stakeInfo_getStakeInfoForColdkey")]
+    fn get_stake_info_for_coldkey(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getStakeInfoForColdkeys")]
+    fn get_stake_info_for_coldkeys(
+        &self,
+        coldkey_account_vecs: Vec<Vec<u8>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "subnetInfo_getLockCost")]
     fn get_network_lock_cost(&self, at: Option<BlockHash>) -> RpcResult<u64>;
+    #[method(name = "subnetInfo_projectLockCost")]
+    fn project_network_burn_cost(&self, blocks_ahead: u64, at: Option<BlockHash>)
+        -> RpcResult<u64>;
 
     #[method(name = "subtensor_getEpoch")]
     fn get_epoch(&self, netuid: u16, maybe_return_incentives: Option<bool>, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_simulateRootWeightChange")]
+    fn simulate_root_weight_change(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getStorageStats")]
+    fn get_storage_stats(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getTopValidators")]
+    fn get_top_validators(&self, netuid: u16, k: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getRootVoters")]
+    fn get_root_voters(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getCallMetrics")]
+    fn get_call_metrics(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getWeightsMinStake")]
+    fn get_weights_min_stake(&self, at: Option<BlockHash>) -> RpcResult<u64>;
 }
 
 pub struct SubtensorCustom<C, P> {
@@ -101,6 +196,7 @@ where
     C::Api: DelegateInfoRuntimeApi<Block>,
     C::Api: NeuronInfoRuntimeApi<Block>,
     C::Api: SubnetInfoRuntimeApi<Block>,
+    C::Api: StakeInfoRuntimeApi<Block>,
     C::Api: SubnetRegistrationRuntimeApi<Block>,
     C::Api: SubtensorRuntimeApi<Block>
 {
@@ -187,6 +283,75 @@ where
             .map_err(|e| Error::RuntimeError(format!("Unable to get neuron info: {:?}", e)).into())
     }
 
+    fn get_axons(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_axons(at, netuid, uid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get axons: {:?}", e)).into())
+    }
+
+    fn get_neurons_by_ip(
+        &self,
+        ip: u128,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neurons_by_ip(at, ip).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons by ip: {:?}", e)).into()
+        })
+    }
+
+    fn get_neuron_certificate(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neuron_certificate(at, netuid, uid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neuron certificate: {:?}", e)).into()
+        })
+    }
+
+    fn get_neurons_paginated(
+        &self,
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neurons_paginated(at, netuid, start_uid, limit)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neurons info: {:?}", e)).into())
+    }
+
+    fn get_neurons_lite_paginated(
+        &self,
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neurons_lite_paginated(at, netuid, start_uid, limit).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e)).into()
+        })
+    }
+
     fn get_subnet_info(
         &self,
         netuid: u16,
@@ -211,6 +376,179 @@ where
             .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)).into())
     }
 
+    fn get_weights_sparse(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_weights_sparse(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get weights: {:?}", e)).into())
+    }
+
+    fn get_bonds_sparse(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_bonds_sparse(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get bonds: {:?}", e)).into())
+    }
+
+    fn get_subnet_recycle_info(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnet_recycle_info(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnet recycle info: {:?}", e)).into()
+        })
+    }
+
+    fn dump_subnet_state(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.dump_subnet_state(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to dump subnet state: {:?}", e)).into()
+        })
+    }
+
+    fn get_epoch_snapshots(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_epoch_snapshots(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get epoch snapshots: {:?}", e)).into()
+        })
+    }
+
+    fn get_eviction_log(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_eviction_log(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get eviction log: {:?}", e)).into()
+        })
+    }
+
+    fn get_all_subnet_netuids(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_all_subnet_netuids(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnet netuids: {:?}", e)).into()
+        })
+    }
+
+    fn get_evm_key_associations(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_evm_key_associations(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get evm key associations: {:?}", e)).into()
+        })
+    }
+
+    fn get_root_weights(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_root_weights(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get root weights: {:?}", e)).into()
+        })
+    }
+
+    fn get_root_emission_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_root_emission_info(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get root emission info: {:?}", e)).into()
+        })
+    }
+
+    fn simulate_root_epoch(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.simulate_root_epoch(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to simulate root epoch: {:?}", e)).into()
+        })
+    }
+
+    fn get_pow_stats(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_pow_stats(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get pow stats: {:?}", e)).into()
+        })
+    }
+
+    fn get_emission_proof(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_emission_proof(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get emission proof: {:?}", e)).into()
+        })
+    }
+
+    fn get_adjustment_info(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_adjustment_info(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get adjustment info: {:?}", e)).into()
+        })
+    }
+
+    fn get_all_epoch_schedules(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_all_epoch_schedules(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get epoch schedules: {:?}", e)).into()
+        })
+    }
+
     fn get_subnets_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
@@ -219,6 +557,54 @@ where
             .map_err(|e| Error::RuntimeError(format!("Unable to get subnets info: {:?}", e)).into())
     }
 
+    fn get_subnet_info_v2(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnet_info_v2(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnet info v2: {:?}", e)).into()
+        })
+    }
+
+    fn get_subnets_info_v2(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnets_info_v2(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnets info v2: {:?}", e)).into()
+        })
+    }
+
+    fn get_stake_info_for_coldkey(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_stake_info_for_coldkey(at, coldkey_account_vec).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get stake info: {:?}", e)).into()
+        })
+    }
+
+    fn get_stake_info_for_coldkeys(
+        &self,
+        coldkey_account_vecs: Vec<Vec<u8>>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_stake_info_for_coldkeys(at, coldkey_account_vecs).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get stake info: {:?}", e)).into()
+        })
+    }
+
     fn get_network_lock_cost(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u64> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
@@ -228,6 +614,19 @@ where
         })
     }
 
+    fn project_network_burn_cost(
+        &self,
+        blocks_ahead: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.project_network_burn_cost(at, blocks_ahead).map_err(|e| {
+            Error::RuntimeError(format!("Unable to project subnet lock cost: {:?}", e)).into()
+        })
+    }
+
     fn get_epoch(&self, netuid: u16, maybe_return_incentives: Option<bool>, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
@@ -236,4 +635,70 @@ where
             Error::RuntimeError(format!("Unable to get epoch: {:?}", e)).into()
         })
     }
+
+    fn simulate_root_weight_change(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.simulate_root_weight_change(at, hotkey_account_vec, uids, values)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to simulate root weights: {:?}", e)).into()
+            })
+    }
+
+    fn get_storage_stats(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_storage_stats(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get storage stats: {:?}", e)).into()
+        })
+    }
+
+    fn get_top_validators(
+        &self,
+        netuid: u16,
+        k: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_top_validators(at, netuid, k).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get top validators: {:?}", e)).into()
+        })
+    }
+
+    fn get_root_voters(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_root_voters(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get root voters: {:?}", e)).into()
+        })
+    }
+
+    fn get_call_metrics(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_call_metrics(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get call metrics: {:?}", e)).into()
+        })
+    }
+
+    fn get_weights_min_stake(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_weights_min_stake(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get weights min stake: {:?}", e)).into()
+        })
+    }
 }
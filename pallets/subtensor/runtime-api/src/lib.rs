@@ -3,12 +3,16 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
-// src/neuron_info.rs, src/subnet_info.rs, and src/delegate_info.rs
+// src/neuron_info.rs, src/subnet_info.rs, src/delegate_info.rs, src/rate_limit_info.rs,
+// src/runtime_features.rs, src/root_info.rs, src/storage_stats.rs, src/call_metrics.rs,
+// src/adjustment_info.rs and src/validator_set.rs
 sp_api::decl_runtime_apis! {
     pub trait DelegateInfoRuntimeApi {
         fn get_delegates() -> Vec<u8>;
         fn get_delegate( delegate_account_vec: Vec<u8> ) -> Vec<u8>;
         fn get_delegated( delegatee_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_top_validators(netuid: u16, k: u16) -> Vec<u8>;
+        fn get_root_voters() -> Vec<u8>;
     }
 
     pub trait NeuronInfoRuntimeApi {
@@ -16,17 +20,58 @@ sp_api::decl_runtime_apis! {
         fn get_neuron(netuid: u16, uid: u16) -> Vec<u8>;
         fn get_neurons_lite(netuid: u16) -> Vec<u8>;
         fn get_neuron_lite(netuid: u16, uid: u16) -> Vec<u8>;
+        fn get_axons(netuid: u16, uid: u16) -> Vec<u8>;
+        fn get_neurons_by_ip(ip: u128) -> Vec<u8>;
+        fn get_neuron_certificate(netuid: u16, uid: u16) -> Vec<u8>;
+        fn get_neurons_paginated(netuid: u16, start_uid: u16, limit: u16) -> Vec<u8>;
+        fn get_neurons_lite_paginated(netuid: u16, start_uid: u16, limit: u16) -> Vec<u8>;
     }
 
     pub trait SubnetInfoRuntimeApi {
         fn get_subnet_info(netuid: u16) -> Vec<u8>;
         fn get_subnets_info() -> Vec<u8>;
+        fn get_subnet_info_v2(netuid: u16) -> Vec<u8>;
+        fn get_subnets_info_v2() -> Vec<u8>;
         fn get_subnet_hyperparams(netuid: u16) -> Vec<u8>;
+        fn get_weights_sparse(netuid: u16) -> Vec<u8>;
+        fn get_bonds_sparse(netuid: u16) -> Vec<u8>;
+        fn get_subnet_recycle_info(netuid: u16) -> Vec<u8>;
+        fn dump_subnet_state(netuid: u16) -> Vec<u8>;
+        fn get_epoch_snapshots(netuid: u16) -> Vec<u8>;
+        fn get_eviction_log(netuid: u16) -> Vec<u8>;
+        fn get_all_subnet_netuids() -> Vec<u8>;
+        fn get_evm_key_associations(netuid: u16) -> Vec<u8>;
+        fn get_root_weights() -> Vec<u8>;
+        fn get_root_emission_info() -> Vec<u8>;
+        fn simulate_root_epoch() -> Vec<u8>;
+        fn get_pow_stats(netuid: u16) -> Vec<u8>;
+        fn get_emission_proof(netuid: u16) -> Vec<u8>;
+        fn get_adjustment_info(netuid: u16) -> Vec<u8>;
+        fn get_all_epoch_schedules() -> Vec<u8>;
+        fn project_network_burn_cost(blocks_ahead: u64) -> u64;
+        fn simulate_root_weight_change( hotkey_account_vec: Vec<u8>, uids: Vec<u16>, values: Vec<u16> ) -> Vec<u8>;
+        fn get_storage_stats() -> Vec<u8>;
+        fn get_call_metrics() -> Vec<u8>;
     }
 
     pub trait StakeInfoRuntimeApi {
         fn get_stake_info_for_coldkey( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
         fn get_stake_info_for_coldkeys( coldkey_account_vecs: Vec<Vec<u8>> ) -> Vec<u8>;
+        fn get_coldkey_emission_summary( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_stake_distribution( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_stake_fee( amount: u64 ) -> u64;
+        fn get_pending_unstakes( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_coldkey_value( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_weights_min_stake() -> u64;
+    }
+
+    pub trait RateLimitInfoRuntimeApi {
+        fn get_rate_limits( hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_next_weight_set_block( netuid: u16, hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
+    }
+
+    pub trait RuntimeFeaturesRuntimeApi {
+        fn get_runtime_features() -> Vec<u8>;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
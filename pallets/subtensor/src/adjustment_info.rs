@@ -0,0 +1,56 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+/// Where a subnet stands in its registration adjustment interval, for miners scheduling
+/// registrations around the next burn and difficulty adjustment.
+#[freeze_struct("2fb71c946a0379ba")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct AdjustmentInfo {
+    pub netuid: Compact<u16>,
+    pub adjustment_interval: Compact<u16>,
+    pub last_adjustment_block: Compact<u64>,
+    /// Blocks until block_step next adjusts the burn and difficulty, 0 when it does in the
+    /// coming block.
+    pub blocks_until_next_adjustment: Compact<u64>,
+    pub registrations_this_interval: Compact<u16>,
+    pub pow_registrations_this_interval: Compact<u16>,
+    pub burn_registrations_this_interval: Compact<u16>,
+    pub target_registrations_per_interval: Compact<u16>,
+    pub burn: Compact<u64>,
+    pub difficulty: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Blocks until the registration terms of `netuid` are next adjusted. block_step adjusts
+    /// them at the start of the first block at least AdjustmentInterval blocks after the last
+    /// adjustment.
+    pub fn blocks_until_next_adjustment(netuid: u16) -> u64 {
+        let next_adjustment_block = Self::get_last_adjustment_block(netuid)
+            .saturating_add(u64::from(Self::get_adjustment_interval(netuid)));
+        next_adjustment_block.saturating_sub(Self::get_current_block_as_u64().saturating_add(1))
+    }
+
+    /// Returns the adjustment interval state of `netuid`, None if the subnet does not exist.
+    pub fn get_adjustment_info(netuid: u16) -> Option<AdjustmentInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        Some(AdjustmentInfo {
+            netuid: netuid.into(),
+            adjustment_interval: Self::get_adjustment_interval(netuid).into(),
+            last_adjustment_block: Self::get_last_adjustment_block(netuid).into(),
+            blocks_until_next_adjustment: Self::blocks_until_next_adjustment(netuid).into(),
+            registrations_this_interval: Self::get_registrations_this_interval(netuid).into(),
+            pow_registrations_this_interval: Self::get_pow_registrations_this_interval(netuid)
+                .into(),
+            burn_registrations_this_interval: Self::get_burn_registrations_this_interval(netuid)
+                .into(),
+            target_registrations_per_interval: Self::get_target_registrations_per_interval(netuid)
+                .into(),
+            burn: Self::get_burn_as_u64(netuid).into(),
+            difficulty: Self::get_difficulty_as_u64(netuid).into(),
+        })
+    }
+}
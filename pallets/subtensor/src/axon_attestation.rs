@@ -0,0 +1,123 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic attest_axon.
+    ///
+    /// Records whether the calling validator could reach the axon of `uid` on `netuid`,
+    /// replacing its previous attestation of the uid. Attestations are weighed by stake in
+    /// `get_axon_reachability`, only while the attesting hotkey holds a permit.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey has no uid on the subnet.
+    ///
+    /// * 'NeuronNoValidatorPermit':
+    ///     - The hotkey's uid has no validator permit.
+    ///
+    /// * 'AttestedUidNotFound':
+    ///     - The attested uid does not exist on the subnet.
+    ///
+    pub fn do_attest_axon(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        uid: u16,
+        reachable: bool,
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        let validator_uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)
+            .map_err(|_| Error::<T>::HotKeyNotRegisteredInSubNet)?;
+        ensure!(
+            Self::get_validator_permit_for_uid(netuid, validator_uid),
+            Error::<T>::NeuronNoValidatorPermit
+        );
+        ensure!(
+            uid < Self::get_subnetwork_n(netuid),
+            Error::<T>::AttestedUidNotFound
+        );
+
+        let current_block = Self::get_current_block_as_u64();
+        AxonAttestations::<T>::mutate(netuid, uid, |attestations| {
+            // Drop the previous attestation of the validator along with those of hotkeys
+            // that no longer hold a permit on the subnet.
+            attestations.retain(|(attester, _, _)| {
+                *attester != hotkey && Self::is_permitted_validator(netuid, attester)
+            });
+            attestations.push((hotkey.clone(), reachable, current_block));
+        });
+
+        log::info!(
+            "AxonAttested( netuid:{:?}, uid:{:?}, hotkey:{:?}, reachable:{:?} )",
+            netuid,
+            uid,
+            hotkey,
+            reachable
+        );
+        Self::deposit_subnet_event(
+            &[netuid],
+            Event::AxonAttested(netuid, uid, hotkey, reachable),
+        );
+        Ok(())
+    }
+
+    /// Returns the reachability score of the axon of `uid` on `netuid`, out of u16::MAX: the
+    /// share of the stake of the permitted validators attesting it that found it reachable.
+    /// The stake behind each reachable attestation is halved every AxonReachabilityHalfLife
+    /// blocks since it was made, so the score of an axon nobody attests again decays to zero.
+    pub fn get_axon_reachability(netuid: u16, uid: u16) -> u16 {
+        let current_block = Self::get_current_block_as_u64();
+        let half_life = Self::get_axon_reachability_half_life().max(1);
+        let mut reachable_stake: u128 = 0;
+        let mut total_stake: u128 = 0;
+        for (attester, reachable, block) in AxonAttestations::<T>::get(netuid, uid) {
+            if !Self::is_permitted_validator(netuid, &attester) {
+                continue;
+            }
+            let stake = u128::from(Self::get_total_stake_for_hotkey(&attester));
+            total_stake = total_stake.saturating_add(stake);
+            if reachable {
+                let halvings = current_block
+                    .saturating_sub(block)
+                    .saturating_div(half_life);
+                let decayed = u32::try_from(halvings)
+                    .ok()
+                    .and_then(|halvings| stake.checked_shr(halvings))
+                    .unwrap_or(0);
+                reachable_stake = reachable_stake.saturating_add(decayed);
+            }
+        }
+        if total_stake == 0 {
+            return 0;
+        }
+        let score = reachable_stake
+            .saturating_mul(u128::from(u16::MAX))
+            .checked_div(total_stake)
+            .unwrap_or(0);
+        u16::try_from(score).unwrap_or(u16::MAX)
+    }
+
+    /// Returns the attestations of the axon of `uid` on `netuid`: the attesting hotkey,
+    /// whether it found the axon reachable and the block it attested at.
+    pub fn get_axon_attestations(netuid: u16, uid: u16) -> Vec<(T::AccountId, bool, u64)> {
+        AxonAttestations::<T>::get(netuid, uid)
+    }
+
+    fn is_permitted_validator(netuid: u16, hotkey: &T::AccountId) -> bool {
+        Self::get_uid_for_net_and_hotkey(netuid, hotkey)
+            .is_ok_and(|uid| Self::get_validator_permit_for_uid(netuid, uid))
+    }
+
+    pub fn get_axon_reachability_half_life() -> u64 {
+        AxonReachabilityHalfLife::<T>::get()
+    }
+    pub fn set_axon_reachability_half_life(half_life: u64) {
+        AxonReachabilityHalfLife::<T>::put(half_life);
+        Self::deposit_event(Event::AxonReachabilityHalfLifeSet(half_life));
+    }
+}
@@ -0,0 +1,77 @@
+//! Synthetic subnet state for benchmarking the epochs across network sizes.
+#![allow(clippy::arithmetic_side_effects)]
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::account;
+use sp_std::vec;
+
+impl<T: Config> Pallet<T> {
+    /// Creates `netuid` with `n` staked neurons. The first `validators` neurons hold a
+    /// validator permit, and set weights and bonds on `k` neurons spread over the subnet, so
+    /// `k == n` gives dense weight rows.
+    pub fn populate_synthetic_subnet(netuid: u16, n: u16, validators: u16, k: u16) {
+        let n = n.max(1);
+        let validators = validators.min(n);
+        let k = k.clamp(1, n);
+        let block_number = Self::get_current_block_as_u64();
+
+        Self::init_new_network(netuid, 1);
+        Self::set_max_allowed_uids(netuid, n);
+        Self::set_max_allowed_validators(netuid, validators);
+
+        for uid in 0..n {
+            let hotkey: T::AccountId = account("hotkey", uid.into(), netuid.into());
+            let coldkey: T::AccountId = account("coldkey", uid.into(), netuid.into());
+            Self::append_neuron(netuid, &hotkey, block_number);
+            Self::create_account_if_non_existent(&coldkey, &hotkey);
+            Self::increase_stake_on_coldkey_hotkey_account(
+                &coldkey,
+                &hotkey,
+                1_000_000_000 * (u64::from(uid) + 1),
+            );
+        }
+
+        let step = n / k;
+        for uid in 0..validators {
+            Self::set_validator_permit_for_uid(netuid, uid, true);
+            let row: Vec<(u16, u16)> = (0..k)
+                .map(|j| ((uid + j * step) % n, u16::MAX / (j + 1)))
+                .collect();
+            Weights::<T>::insert(netuid, uid, WeightsRow::from_entries(&row));
+            Bonds::<T>::insert(netuid, uid, row);
+        }
+    }
+
+    /// Creates the root network with `validators` staked root validators and `subnets`
+    /// subnets, each root validator weighting `weighted` of them, so `weighted == subnets`
+    /// gives dense root weight rows. The root tempo is 1, so the root epoch runs on even
+    /// blocks.
+    pub fn populate_synthetic_root(validators: u16, subnets: u16, weighted: u16) {
+        let weighted = weighted.clamp(1, subnets.max(1));
+        let root_netuid = Self::get_root_netuid();
+        let block_number = Self::get_current_block_as_u64();
+
+        Self::init_new_network(root_netuid, 1);
+        Self::set_max_allowed_uids(root_netuid, validators.max(1));
+        for netuid in 1..=subnets {
+            Self::init_new_network(netuid, 1);
+        }
+
+        for uid in 0..validators {
+            let hotkey: T::AccountId = account("root_hotkey", uid.into(), 0);
+            let coldkey: T::AccountId = account("root_coldkey", uid.into(), 0);
+            Self::append_neuron(root_netuid, &hotkey, block_number);
+            Self::create_account_if_non_existent(&coldkey, &hotkey);
+            Self::increase_stake_on_coldkey_hotkey_account(
+                &coldkey,
+                &hotkey,
+                1_000_000_000 * (u64::from(uid) + 1),
+            );
+            let row: Vec<(u16, u16)> = (0..weighted)
+                .map(|j| (1 + (uid + j) % subnets.max(1), u16::MAX / (j + 1 + uid % 3)))
+                .collect();
+            Weights::<T>::insert(root_netuid, uid, WeightsRow::from(row));
+        }
+    }
+}
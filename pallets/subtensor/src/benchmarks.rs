@@ -429,4 +429,78 @@ reveal_weights {
 
   }: reveal_weights(RawOrigin::Signed(hotkey.clone()), netuid, uids, weight_values, salt, version_key)
 
+  benchmark_epoch {
+    // n neurons, of which 64 validators each weight k of them.
+    let n in 16 .. 4096;
+    let k in 1 .. 256;
+    let netuid: u16 = 1;
+    Subtensor::<T>::populate_synthetic_subnet(netuid, n as u16, 64, k as u16);
+  }: {
+    Subtensor::<T>::epoch(netuid, None);
+  }
+
+  benchmark_epoch_dense {
+    let n in 16 .. 1024;
+    let netuid: u16 = 1;
+    Subtensor::<T>::populate_synthetic_subnet(netuid, n as u16, 64, n as u16);
+  }: {
+    Subtensor::<T>::epoch_dense(netuid, 1_000_000_000);
+  }
+
+  benchmark_commit_epoch_output {
+    // The end-of-epoch writes alone for n neurons, every bonds row recomputed.
+    let n in 16 .. 4096;
+    let netuid: u16 = 1;
+    Subtensor::<T>::populate_synthetic_subnet(netuid, n as u16, 64, 1);
+    let mut output = epoch::EpochOutput::with_capacity(n as usize);
+    for uid in 0..n {
+      output.active.push(true);
+      output.emission.push(uid as u64);
+      output.rank.push(uid as u16);
+      output.trust.push(uid as u16);
+      output.consensus.push(uid as u16);
+      output.incentive.push(uid as u16);
+      output.dividends.push(uid as u16);
+      output.pruning_scores.push(uid as u16);
+      output.validator_trust.push(uid as u16);
+      output.validator_permit.push(uid < 64);
+      output.bonds.push(Some(vec![(0, uid as u16)]));
+    }
+  }: {
+    Subtensor::<T>::commit_epoch_output(netuid, output);
+  }
+
+  benchmark_root_epoch {
+    // n root validators weighting k subnets.
+    let n in 1 .. 64;
+    let k in 1 .. 32;
+    Subtensor::<T>::populate_synthetic_root(n as u16, k as u16, k as u16);
+  }: {
+    assert_ok!(Subtensor::<T>::root_epoch(0));
+  }
+
+  benchmark_root_epoch_sparse {
+    // n root validators weighting 4 each of 256 subnets.
+    let n in 1 .. 64;
+    Subtensor::<T>::populate_synthetic_root(n as u16, 256, 4);
+  }: {
+    assert_ok!(Subtensor::<T>::root_epoch(0));
+  }
+
+  benchmark_root_epoch_wide {
+    // 64 root validators weighting 4 each of k subnets, the cost should not grow with n x k.
+    let k in 32 .. 1024;
+    Subtensor::<T>::populate_synthetic_root(64, k as u16, 4);
+  }: {
+    assert_ok!(Subtensor::<T>::root_epoch(0));
+  }
+
+  benchmark_compute_root_emission_values_wide {
+    // The root emission math of the simulation APIs at 64 root validators and k subnets.
+    let k in 32 .. 1024;
+    Subtensor::<T>::populate_synthetic_root(64, k as u16, 4);
+    let weights = Subtensor::<T>::get_root_weights_sparse();
+  }: {
+    assert_ok!(Subtensor::<T>::compute_root_emission_values(weights));
+  }
 }
@@ -1,31 +1,76 @@
 use super::*;
+use crate::epoch::EpochInput;
 use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::storage::IterableStorageMap;
+use frame_support::weights::Weight;
 use sp_runtime::Saturating;
 use substrate_fixed::types::I110F18;
 use substrate_fixed::types::I64F64;
 use substrate_fixed::types::I96F32;
 
 impl<T: Config> Pallet<T> {
-    /// Executes the necessary operations for each block.
-    pub fn block_step() -> Result<(), &'static str> {
+    /// Executes the necessary operations for each block. Returns the weight used by the steps
+    /// whose cost depends on the state they process, on top of the fixed block step weight.
+    pub fn block_step() -> Result<Weight, &'static str> {
         let block_number: u64 = Self::get_current_block_as_u64();
+        let mut weight = Weight::zero();
         log::debug!("block_step for block: {:?} ", block_number);
+        // --- 0. Apply an announced subnet limit change.
+        Self::apply_scheduled_subnet_limit(block_number);
         // --- 1. Adjust difficulties.
         Self::adjust_registration_terms_for_networks();
-        // --- 2. Calculate per-subnet emissions
+        // --- 2. Calculate per-subnet emissions, scale them by the emission multipliers, burn
+        // part of them for underused subnets, record them in the emission history and commit
+        // to them for proofs.
+        weight.saturating_accrue(Self::root_epoch_weight(block_number));
         match Self::root_epoch(block_number) {
-            Ok(_) => (),
+            Ok(_) => {
+                Self::apply_emission_multipliers();
+                Self::burn_emission_of_underused_subnets();
+                Self::record_emission_history();
+                Self::record_root_epoch_commitment(block_number);
+            }
             Err(e) => {
                 log::trace!("Error while running root epoch: {:?}", e);
             }
         }
         // --- 3. Drains emission tuples ( hotkey, amount ).
         Self::drain_emission(block_number);
+        // --- 3.1 Pays out the emission accrued by hotkeys on their own cadence.
+        weight.saturating_accrue(Self::drain_hotkey_emission(block_number));
+        // --- 3.2 Pauses the epochs of subnets whose validators signal an emergency.
+        weight.saturating_accrue(Self::apply_epoch_pause_signals(block_number));
+        // --- 3.3 Applies the child keys whose cooldown ended.
+        weight.saturating_accrue(Self::apply_pending_child_keys(block_number));
+        // --- 3.4 Clears the bonds of the subnets being reset, a chunk per block.
+        weight.saturating_accrue(Self::reset_bonds_in_chunks());
+        // --- 3.5 Caps the takes of delegates as voted by their nominators.
+        weight.saturating_accrue(Self::tally_delegate_take_caps(block_number));
+        // --- 3.6 Reduces the takes of underperforming delegates, restoring them on recovery.
+        weight.saturating_accrue(Self::check_delegate_underperformance(block_number));
         // --- 4. Generates emission tuples from epoch functions.
-        Self::generate_emission(block_number);
+        weight.saturating_accrue(Self::generate_emission(block_number));
+        // --- 5. Returns the stake of banned hotkeys.
+        weight.saturating_accrue(Self::wind_down_banned_hotkeys());
+        // --- 6. Grows the max allowed uids on schedule.
+        weight.saturating_accrue(Self::grow_max_allowed_uids(block_number));
+        // --- 7. Clears the weights set below a raised MinAllowedWeights.
+        weight.saturating_accrue(Self::sweep_nonconforming_weights());
+        // --- 8. Alerts the coldkeys subscribed to a stake threshold that has been crossed.
+        weight.saturating_accrue(Self::check_stake_thresholds());
+        // --- 9. Closes the subnet referenda that ended, applying the changes they passed.
+        weight.saturating_accrue(Self::close_ended_subnet_referenda(block_number));
+        // --- 9.1 Starts a new call metrics era once the current one has run its length.
+        weight.saturating_accrue(Self::roll_call_metrics_era(block_number));
+        // --- 9.2 Ends the stake locks that ran their length.
+        weight.saturating_accrue(Self::expire_stake_locks(block_number));
+        // --- 9.3 Releases the auctioned netuids their winners did not claim in time.
+        weight.saturating_accrue(Self::expire_netuid_reservations(block_number));
+        // --- 10. Checks the storage invariants on testnets.
+        #[cfg(feature = "strict-invariants")]
+        Self::assert_invariants(block_number);
         // Return ok.
-        Ok(())
+        Ok(weight)
     }
 
     #[allow(clippy::arithmetic_side_effects)]
@@ -92,29 +137,40 @@ impl<T: Config> Pallet<T> {
     pub fn drain_emission(_: u64) {
         // --- 1. We iterate across each network.
         for (netuid, _) in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
+            // A migrating subnet's emission is drained once it is back under its new netuid.
+            if Self::is_subnet_migrating(netuid) {
+                continue;
+            }
             let Some(tuples_to_drain) = Self::get_loaded_emission_tuples(netuid) else {
                 // There are no tuples to emit.
                 continue;
             };
             let mut total_emitted: u64 = 0;
             for (hotkey, server_amount, validator_amount) in tuples_to_drain.iter() {
-                Self::emit_inflation_through_hotkey_account(
+                total_emitted.saturating_accrue(Self::accrue_hotkey_emission(
                     hotkey,
+                    netuid,
                     *server_amount,
                     *validator_amount,
-                );
-                total_emitted.saturating_accrue((*server_amount).saturating_add(*validator_amount));
+                ));
             }
-            LoadedEmission::<T>::remove(netuid);
+            Self::remove_loaded_emission(netuid);
             TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(total_emitted));
         }
     }
 
     /// Iterates through networks queues more emission onto their pending storage.
     /// If a network has no blocks left until tempo, we run the epoch function and generate
-    /// more token emission tuples for later draining onto accounts.
+    /// more token emission tuples for later draining onto accounts. Returns the weight of the
+    /// epochs run, each as benchmarked for the size of its subnet and the length of its
+    /// longest weight row.
     ///
-    pub fn generate_emission(block_number: u64) {
+    pub fn generate_emission(block_number: u64) -> Weight {
+        // Subnets at tempo with the emission they drain and their epoch inputs. Epochs only
+        // read their own subnet, so they are computed together once every input is loaded.
+        let mut epochs: Vec<(u16, u64)> = Vec::new();
+        let mut epoch_inputs: Vec<EpochInput<T::AccountId>> = Vec::new();
+
         // --- 1. Iterate across each network and add pending emission into stash.
         for (netuid, tempo) in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
             // Skip the root network or subnets with registrations turned off
@@ -122,49 +178,23 @@ impl<T: Config> Pallet<T> {
                 // Root emission or subnet emission is burned
                 continue;
             }
-
-            // --- 2. Queue the emission due to this network.
-            let mut new_queued_emission: u64 = Self::get_subnet_emission_value(netuid);
-            if !Self::is_registration_allowed(netuid) {
-                new_queued_emission = 0; // No emission for this network if registration is off.
-            }
-
-            log::debug!(
-                "generate_emission for netuid: {:?} with tempo: {:?} and emission: {:?}",
-                netuid,
-                tempo,
-                new_queued_emission,
-            );
-
-            let subnet_has_owner = SubnetOwner::<T>::contains_key(netuid);
-            let mut remaining = I96F32::from_num(new_queued_emission);
-            if subnet_has_owner {
-                let cut = remaining
-                    .saturating_mul(I96F32::from_num(Self::get_subnet_owner_cut()))
-                    .saturating_div(I96F32::from_num(u16::MAX));
-
-                remaining = remaining.saturating_sub(cut);
-
-                Self::add_balance_to_coldkey_account(
-                    &Self::get_subnet_owner(netuid),
-                    cut.to_num::<u64>(),
-                );
-
-                // We are creating tokens here from the coinbase.
-                Self::coinbase(cut.to_num::<u64>());
+            // A migrating subnet neither accrues emission nor runs epochs while its neurons,
+            // weights and bonds are split between the two netuids.
+            if Self::is_subnet_migrating(netuid) {
+                continue;
             }
-            // --- 5. Add remaining amount to the network's pending emission.
-            PendingEmission::<T>::mutate(netuid, |queued| {
-                queued.saturating_accrue(remaining.to_num::<u64>())
-            });
-            log::debug!(
-                "netuid_i: {:?} queued_emission: +{:?} ",
-                netuid,
-                new_queued_emission
-            );
 
-            // --- 6. Check to see if this network has reached tempo.
-            if Self::blocks_until_next_epoch(netuid, tempo, block_number) != 0 {
+            // --- 2. Queue the emission due to this network every block.
+            Self::accumulate_pending_emission(netuid);
+
+            // --- 6. Check to see if this network has reached tempo. Subnets under maintenance,
+            // paused by their validators or with their bonds being reset skip their epochs and
+            // keep accruing pending emission until the window closes.
+            if Self::blocks_until_next_epoch(netuid, tempo, block_number) != 0
+                || Self::is_in_maintenance(netuid, block_number)
+                || Self::is_epoch_paused(netuid, block_number)
+                || Self::is_bonds_reset_in_progress(netuid)
+            {
                 // --- 3.1 No epoch, increase blocks since last step and continue,
                 Self::set_blocks_since_last_step(
                     netuid,
@@ -178,9 +208,30 @@ impl<T: Config> Pallet<T> {
             let emission_to_drain: u64 = PendingEmission::<T>::get(netuid);
             PendingEmission::<T>::insert(netuid, 0);
 
-            // --- 8. Run the epoch mechanism and return emission tuples for hotkeys in the network.
+            epochs.push((netuid, emission_to_drain));
+            epoch_inputs.push(Self::load_epoch_input(netuid));
+        }
+
+        let mut weight = Weight::zero();
+        for input in epoch_inputs.iter() {
+            let longest_row = input.weights.iter().map(Vec::len).max().unwrap_or_default();
+            weight.saturating_accrue(T::WeightInfo::benchmark_epoch(
+                u32::from(input.n),
+                u32::try_from(longest_row).unwrap_or(u32::MAX),
+            ));
+        }
+
+        // --- 8. Run the epoch mechanism of every network at tempo. The math runs off storage,
+        // the results are written back sequentially in the order the networks were visited.
+        let epoch_results = Self::compute_epochs(epoch_inputs);
+        for ((netuid, emission_to_drain), (epoch_info, epoch_output)) in
+            epochs.into_iter().zip(epoch_results)
+        {
+            if let Some(epoch_output) = epoch_output {
+                Self::commit_epoch_output(netuid, epoch_output);
+            }
             let emission_tuples_this_block: Vec<(T::AccountId, u64, u64)> =
-                Self::epoch(netuid, None).as_emission().unwrap();
+                epoch_info.as_emission().unwrap_or_default();
             log::debug!(
                 "netuid_i: {:?} emission_to_drain: {:?} ",
                 netuid,
@@ -196,20 +247,70 @@ impl<T: Config> Pallet<T> {
                 continue;
             } // Saftey check.
 
-            // --- 10. Sink the emission tuples onto the already loaded.
+            // --- 10. Guarantee the uids in their first epoch the subnet's bootstrap incentive,
+            // then sink the emission tuples onto the already loaded.
             let mut concat_emission_tuples: Vec<(T::AccountId, u64, u64)> =
                 emission_tuples_this_block.clone();
+            Self::apply_bootstrap_incentive(netuid, &mut concat_emission_tuples);
             if let Some(mut current_emission_tuples) = Self::get_loaded_emission_tuples(netuid) {
                 // 10.a We already have loaded emission tuples, so we concat the new ones.
                 concat_emission_tuples.append(&mut current_emission_tuples);
             }
-            LoadedEmission::<T>::insert(netuid, concat_emission_tuples);
+            Self::insert_loaded_emission(netuid, concat_emission_tuples);
 
             // --- 11 Set counters.
             Self::set_blocks_since_last_step(netuid, 0);
             Self::set_last_mechanism_step_block(netuid, block_number);
+            EpochIndex::<T>::mutate(netuid, |index| *index = index.saturating_add(1));
+
+            // --- 12. Warn the uid that will be pruned next once its scores are settled.
+            Self::warn_imminent_pruning(netuid, block_number);
+
+            // --- 13. Anchor the resulting economic state for off-chain reconstruction.
+            Self::record_epoch_snapshot(netuid, block_number);
+
+            // --- 14. Notify the pallets following subtensor.
+            T::OnSubtensorEvent::on_epoch_completed(netuid, block_number);
+        }
+        weight
+    }
+
+    /// Adds the emission the root epoch set for `netuid` to its PendingEmission, after paying
+    /// the owner cut, and returns the amount queued. The pending emission builds up block by
+    /// block until the epoch of the subnet drains it. Subnets with registration turned off
+    /// receive no emission.
+    pub fn accumulate_pending_emission(netuid: u16) -> u64 {
+        let mut new_queued_emission: u64 = Self::get_subnet_emission_value(netuid);
+        if !Self::is_registration_allowed(netuid) {
+            new_queued_emission = 0; // No emission for this network if registration is off.
+        }
+
+        let mut remaining = I96F32::from_num(new_queued_emission);
+        if SubnetOwner::<T>::contains_key(netuid) {
+            let cut = Self::get_subnet_owner_emission_cut(netuid, remaining);
+
+            remaining = remaining.saturating_sub(cut);
+
+            Self::add_balance_to_coldkey_account(
+                &Self::get_subnet_owner(netuid),
+                cut.to_num::<u64>(),
+            );
+
+            // We are creating tokens here from the coinbase.
+            Self::coinbase(cut.to_num::<u64>());
         }
+
+        let queued: u64 = remaining.to_num::<u64>();
+        PendingEmission::<T>::mutate(netuid, |pending| pending.saturating_accrue(queued));
+        log::debug!(
+            "netuid_i: {:?} emission: {:?} queued_emission: +{:?} ",
+            netuid,
+            new_queued_emission,
+            queued
+        );
+        queued
     }
+
     /// Distributes token inflation through the hotkey based on emission. The call ensures that the inflation
     /// is distributed onto the accounts in proportion of the stake delegated minus the take. This function
     /// is called after an epoch to distribute the newly minted stake according to delegation.
@@ -218,36 +319,61 @@ impl<T: Config> Pallet<T> {
         hotkey: &T::AccountId,
         server_emission: u64,
         validator_emission: u64,
+    ) {
+        Self::emit_inflation_through_hotkey_account_at_take(
+            hotkey,
+            Self::get_effective_delegate_take(hotkey),
+            server_emission,
+            validator_emission,
+        );
+    }
+
+    /// Distributes token inflation through the hotkey as `emit_inflation_through_hotkey_account`
+    /// does, a delegate hotkey taking `take` of the validator emission.
+    pub fn emit_inflation_through_hotkey_account_at_take(
+        hotkey: &T::AccountId,
+        take: u16,
+        server_emission: u64,
+        validator_emission: u64,
     ) {
         // --- 1. Check if the hotkey is a delegate. If not, we simply pass the stake through to the
         // coldkey - hotkey account as normal.
+        let owning_coldkey: T::AccountId = Self::get_owning_coldkey_for_hotkey(hotkey);
         if !Self::hotkey_is_delegate(hotkey) {
             Self::increase_stake_on_hotkey_account(
                 hotkey,
                 server_emission.saturating_add(validator_emission),
             );
+            Self::record_coldkey_emission(&owning_coldkey, server_emission, validator_emission, 0);
             return;
         }
         // Then this is a delegate, we distribute validator_emission, then server_emission.
 
         // --- 2. The hotkey is a delegate. We first distribute a proportion of the validator_emission to the hotkey
         // directly as a function of its 'take'
-        let total_hotkey_stake: u64 = Self::get_total_stake_for_hotkey(hotkey);
-        let delegate_take: u64 =
-            Self::calculate_delegate_proportional_take(hotkey, validator_emission);
+        let delegate_take: u64 = Self::calculate_take_of_emission(take, validator_emission);
         let validator_emission_minus_take: u64 = validator_emission.saturating_sub(delegate_take);
         let mut remaining_validator_emission: u64 = validator_emission_minus_take;
 
-        // 3. -- The remaining emission goes to the owners in proportion to the stake delegated.
-        for (owning_coldkey_i, stake_i) in
+        // 3. -- The remaining emission goes to the owners in proportion to the stake delegated,
+        // locked stake weighing by the boost of its lock.
+        let weighted_stakes: Vec<(T::AccountId, u64)> =
             <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix(
                 hotkey,
             )
-        {
+            .map(|(coldkey, stake)| {
+                let weighted = Self::get_dividend_weighted_stake(hotkey, &coldkey, stake);
+                (coldkey, weighted)
+            })
+            .collect();
+        let total_weighted_stake: u64 = weighted_stakes
+            .iter()
+            .fold(0u64, |total, (_, weighted)| total.saturating_add(*weighted));
+        for (owning_coldkey_i, weighted_stake_i) in weighted_stakes {
             // --- 4. The emission proportion is remaining_emission * ( stake / total_stake ).
             let stake_proportion: u64 = Self::calculate_stake_proportional_emission(
-                stake_i,
-                total_hotkey_stake,
+                weighted_stake_i,
+                total_weighted_stake,
                 validator_emission_minus_take,
             );
             Self::increase_stake_on_coldkey_hotkey_account(
@@ -255,6 +381,7 @@ impl<T: Config> Pallet<T> {
                 hotkey,
                 stake_proportion,
             );
+            Self::record_coldkey_emission(&owning_coldkey_i, 0, stake_proportion, 0);
             log::debug!(
                 "owning_coldkey_i: {:?} hotkey: {:?} emission: +{:?} ",
                 owning_coldkey_i,
@@ -275,6 +402,29 @@ impl<T: Config> Pallet<T> {
         // The server emission is distributed in-full to the delegate owner.
         // We do this after 4. for the same reason as above.
         Self::increase_stake_on_hotkey_account(hotkey, server_emission);
+        Self::record_coldkey_emission(
+            &owning_coldkey,
+            server_emission,
+            remaining_validator_emission,
+            delegate_take,
+        );
+    }
+
+    /// Adds emission received by `coldkey` to its lifetime totals.
+    fn record_coldkey_emission(
+        coldkey: &T::AccountId,
+        incentive: u64,
+        dividends: u64,
+        delegate_take: u64,
+    ) {
+        if incentive == 0 && dividends == 0 && delegate_take == 0 {
+            return;
+        }
+        ColdkeyEmissionTotals::<T>::mutate(coldkey, |emission| {
+            emission.incentive.saturating_accrue(incentive);
+            emission.dividends.saturating_accrue(dividends);
+            emission.delegate_take.saturating_accrue(delegate_take);
+        });
     }
 
     /// Increases the stake on the cold - hot pairing by increment while also incrementing other counters.
@@ -290,11 +440,11 @@ impl<T: Config> Pallet<T> {
             hotkey,
             TotalHotkeyStake::<T>::get(hotkey).saturating_add(increment),
         );
-        Stake::<T>::insert(
-            hotkey,
-            coldkey,
-            Stake::<T>::get(hotkey, coldkey).saturating_add(increment),
-        );
+        let old_stake: u64 = Stake::<T>::get(hotkey, coldkey);
+        let new_stake: u64 = old_stake.saturating_add(increment);
+        Stake::<T>::insert(hotkey, coldkey, new_stake);
+        Self::update_delegate_indexes(hotkey, coldkey, old_stake, new_stake);
+        T::OnSubtensorEvent::on_stake_changed(hotkey, coldkey, new_stake);
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_add(increment));
     }
 
@@ -310,12 +460,13 @@ impl<T: Config> Pallet<T> {
             hotkey,
             TotalHotkeyStake::<T>::get(hotkey).saturating_sub(decrement),
         );
-        Stake::<T>::insert(
-            hotkey,
-            coldkey,
-            Stake::<T>::get(hotkey, coldkey).saturating_sub(decrement),
-        );
+        let old_stake: u64 = Stake::<T>::get(hotkey, coldkey);
+        let new_stake: u64 = old_stake.saturating_sub(decrement);
+        Stake::<T>::insert(hotkey, coldkey, new_stake);
+        Self::update_delegate_indexes(hotkey, coldkey, old_stake, new_stake);
+        T::OnSubtensorEvent::on_stake_changed(hotkey, coldkey, new_stake);
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_sub(decrement));
+        Self::queue_stake_alert_check(hotkey);
     }
 
     /// Returns emission awarded to a hotkey as a function of its proportion of the total stake.
@@ -339,22 +490,27 @@ impl<T: Config> Pallet<T> {
     ///
     pub fn calculate_delegate_proportional_take(hotkey: &T::AccountId, emission: u64) -> u64 {
         if Self::hotkey_is_delegate(hotkey) {
-            let take_proportion: I64F64 = I64F64::from_num(Delegates::<T>::get(hotkey))
-                .saturating_div(I64F64::from_num(u16::MAX));
-            let take_emission: I64F64 = take_proportion.saturating_mul(I64F64::from_num(emission));
-            take_emission.to_num::<u64>()
+            Self::calculate_take_of_emission(Self::get_effective_delegate_take(hotkey), emission)
         } else {
             0
         }
     }
 
+    /// Returns the share of `emission` a delegate at `take` keeps.
+    fn calculate_take_of_emission(take: u16, emission: u64) -> u64 {
+        let take_proportion: I64F64 =
+            I64F64::from_num(take).saturating_div(I64F64::from_num(u16::MAX));
+        let take_emission: I64F64 = take_proportion.saturating_mul(I64F64::from_num(emission));
+        take_emission.to_num::<u64>()
+    }
+
     /// Adjusts the network difficulties/burns of every active network. Resetting state parameters.
     ///
     pub fn adjust_registration_terms_for_networks() {
         log::debug!("adjust_registration_terms_for_networks");
 
         // --- 1. Iterate through each network.
-        for (netuid, _) in <NetworksAdded<T> as IterableStorageMap<u16, bool>>::iter() {
+        for netuid in Self::get_all_subnet_netuids() {
             // --- 2. Pull counters for network difficulty.
             let last_adjustment_block: u64 = Self::get_last_adjustment_block(netuid);
             let adjustment_interval: u16 = Self::get_adjustment_interval(netuid);
@@ -382,116 +538,58 @@ impl<T: Config> Pallet<T> {
                     Self::get_burn_registrations_this_interval(netuid);
                 let target_registrations_this_interval: u16 =
                     Self::get_target_registrations_per_interval(netuid);
-                // --- 5. Adjust burn + pow
-                // There are six cases to consider. A, B, C, D, E, F
-                if registrations_this_interval > target_registrations_this_interval {
-                    #[allow(clippy::comparison_chain)]
-                    if pow_registrations_this_interval > burn_registrations_this_interval {
-                        // A. There are too many registrations this interval and most of them are pow registrations
-                        // this triggers an increase in the pow difficulty.
-                        // pow_difficulty ++
-                        Self::set_difficulty(
-                            netuid,
-                            Self::upgraded_difficulty(
-                                netuid,
-                                current_difficulty,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
-                    } else if pow_registrations_this_interval < burn_registrations_this_interval {
-                        // B. There are too many registrations this interval and most of them are burn registrations
-                        // this triggers an increase in the burn cost.
-                        // burn_cost ++
-                        Self::set_burn(
-                            netuid,
-                            Self::upgraded_burn(
-                                netuid,
-                                current_burn,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
-                    } else {
-                        // F. There are too many registrations this interval and the pow and burn registrations are equal
-                        // this triggers an increase in the burn cost and pow difficulty
-                        // burn_cost ++
-                        Self::set_burn(
-                            netuid,
-                            Self::upgraded_burn(
-                                netuid,
-                                current_burn,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
-                        // pow_difficulty ++
-                        Self::set_difficulty(
-                            netuid,
-                            Self::upgraded_difficulty(
-                                netuid,
-                                current_difficulty,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
-                    }
-                } else {
-                    // Not enough registrations this interval.
-                    #[allow(clippy::comparison_chain)]
-                    if pow_registrations_this_interval > burn_registrations_this_interval {
-                        // C. There are not enough registrations this interval and most of them are pow registrations
-                        // this triggers a decrease in the burn cost
-                        // burn_cost --
-                        Self::set_burn(
-                            netuid,
-                            Self::upgraded_burn(
-                                netuid,
-                                current_burn,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
-                    } else if pow_registrations_this_interval < burn_registrations_this_interval {
-                        // D. There are not enough registrations this interval and most of them are burn registrations
-                        // this triggers a decrease in the pow difficulty
-                        // pow_difficulty --
-                        Self::set_difficulty(
-                            netuid,
-                            Self::upgraded_difficulty(
-                                netuid,
-                                current_difficulty,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
-                    } else {
-                        // E. There are not enough registrations this interval and the pow and burn registrations are equal
-                        // this triggers a decrease in the burn cost and pow difficulty
-                        // burn_cost --
-                        Self::set_burn(
-                            netuid,
-                            Self::upgraded_burn(
-                                netuid,
-                                current_burn,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
-                        // pow_difficulty --
-                        Self::set_difficulty(
-                            netuid,
-                            Self::upgraded_difficulty(
-                                netuid,
-                                current_difficulty,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
-                    }
+                let over_target: bool =
+                    registrations_this_interval > target_registrations_this_interval;
+
+                // --- 5. Adjust burn.
+                // With the BURN_EMA flag the burn follows the total registration pressure every
+                // interval, however the registrations split between pow and burn. Otherwise it
+                // rises when there are too many registrations and burn registrations are at least
+                // as common as pow ones, and falls when there are too few and pow registrations
+                // are at least as common as burn ones.
+                if Self::get_burn_ema_enabled(netuid)
+                    || (over_target
+                        && pow_registrations_this_interval <= burn_registrations_this_interval)
+                    || (!over_target
+                        && pow_registrations_this_interval >= burn_registrations_this_interval)
+                {
+                    let new_burn: u64 = Self::upgraded_burn(
+                        netuid,
+                        current_burn,
+                        registrations_this_interval,
+                        target_registrations_this_interval,
+                    );
+                    Self::set_burn(netuid, new_burn);
+                    Self::deposit_subnet_event(
+                        &[netuid],
+                        Event::BurnAdjusted(netuid, current_burn, new_burn),
+                    );
+                }
+
+                // --- 6. Adjust pow.
+                // Difficulty rises when there are too many registrations and pow registrations are
+                // at least as common as burn ones, and falls when there are too few and burn
+                // registrations are at least as common as pow ones.
+                if (over_target
+                    && pow_registrations_this_interval >= burn_registrations_this_interval)
+                    || (!over_target
+                        && pow_registrations_this_interval <= burn_registrations_this_interval)
+                {
+                    let new_difficulty: u64 = Self::upgraded_difficulty(
+                        netuid,
+                        current_difficulty,
+                        registrations_this_interval,
+                        target_registrations_this_interval,
+                    );
+                    Self::set_difficulty(netuid, new_difficulty);
+                    Self::deposit_subnet_event(
+                        &[netuid],
+                        Event::DifficultyAdjusted(netuid, current_difficulty, new_difficulty),
+                    );
                 }
 
-                // --- 6. Drain all counters for this network for this interval.
+                // --- 7. Drain all counters for this network for this interval.
+                Self::close_pow_stats_interval(netuid, pow_registrations_this_interval);
                 Self::set_last_adjustment_block(netuid, current_block);
                 Self::set_registrations_this_interval(netuid, 0);
                 Self::set_pow_registrations_this_interval(netuid, 0);
@@ -500,21 +598,24 @@ impl<T: Config> Pallet<T> {
                 log::debug!("interval not reached.");
             }
 
-            // --- 7. Drain block registrations for each network. Needed for registration rate limits.
+            // --- 8. Drain block registrations for each network. Needed for registration rate limits.
             Self::set_registrations_this_block(netuid, 0);
+            POWRegistrationsThisBlock::<T>::remove(netuid);
+            BurnRegistrationsThisBlock::<T>::remove(netuid);
         }
     }
 
-    /// Calculates the upgraded difficulty by multiplying the current difficulty by the ratio ( reg_actual + reg_target / reg_target + reg_target )
-    /// We use I110F18 to avoid any overflows on u64. Also min_difficulty and max_difficulty bound the range.
+    /// Moves `current_value` towards `current_value * ( reg_actual + reg_target ) / ( reg_target + reg_target )`
+    /// through an exponential moving average weighted by the subnet's AdjustmentAlpha.
+    /// Shared by the burn and pow difficulty adjustments. We use I110F18 to avoid any overflows on u64.
     ///
-    pub fn upgraded_difficulty(
+    pub fn registration_pressure_ema(
         netuid: u16,
-        current_difficulty: u64,
+        current_value: u64,
         registrations_this_interval: u16,
         target_registrations_per_interval: u16,
-    ) -> u64 {
-        let updated_difficulty: I110F18 = I110F18::from_num(current_difficulty)
+    ) -> I110F18 {
+        let updated_value: I110F18 = I110F18::from_num(current_value)
             .saturating_mul(I110F18::from_num(
                 registrations_this_interval.saturating_add(target_registrations_per_interval),
             ))
@@ -523,13 +624,30 @@ impl<T: Config> Pallet<T> {
             ));
         let alpha: I110F18 = I110F18::from_num(Self::get_adjustment_alpha(netuid))
             .saturating_div(I110F18::from_num(u64::MAX));
-        let next_value: I110F18 = alpha
-            .saturating_mul(I110F18::from_num(current_difficulty))
+        alpha
+            .saturating_mul(I110F18::from_num(current_value))
             .saturating_add(
                 I110F18::from_num(1.0)
                     .saturating_sub(alpha)
-                    .saturating_mul(updated_difficulty),
-            );
+                    .saturating_mul(updated_value),
+            )
+    }
+
+    /// Calculates the upgraded difficulty from the registration pressure EMA.
+    /// Also min_difficulty and max_difficulty bound the range.
+    ///
+    pub fn upgraded_difficulty(
+        netuid: u16,
+        current_difficulty: u64,
+        registrations_this_interval: u16,
+        target_registrations_per_interval: u16,
+    ) -> u64 {
+        let next_value: I110F18 = Self::registration_pressure_ema(
+            netuid,
+            current_difficulty,
+            registrations_this_interval,
+            target_registrations_per_interval,
+        );
         if next_value >= I110F18::from_num(Self::get_max_difficulty(netuid)) {
             Self::get_max_difficulty(netuid)
         } else if next_value <= I110F18::from_num(Self::get_min_difficulty(netuid)) {
@@ -539,8 +657,8 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    /// Calculates the upgraded burn by multiplying the current burn by the ratio ( reg_actual + reg_target / reg_target + reg_target )
-    /// We use I110F18 to avoid any overflows on u64. Also min_burn and max_burn bound the range.
+    /// Calculates the upgraded burn from the registration pressure EMA.
+    /// Also min_burn and max_burn bound the range.
     ///
     pub fn upgraded_burn(
         netuid: u16,
@@ -548,22 +666,12 @@ impl<T: Config> Pallet<T> {
         registrations_this_interval: u16,
         target_registrations_per_interval: u16,
     ) -> u64 {
-        let updated_burn: I110F18 = I110F18::from_num(current_burn)
-            .saturating_mul(I110F18::from_num(
-                registrations_this_interval.saturating_add(target_registrations_per_interval),
-            ))
-            .saturating_div(I110F18::from_num(
-                target_registrations_per_interval.saturating_add(target_registrations_per_interval),
-            ));
-        let alpha: I110F18 = I110F18::from_num(Self::get_adjustment_alpha(netuid))
-            .saturating_div(I110F18::from_num(u64::MAX));
-        let next_value: I110F18 = alpha
-            .saturating_mul(I110F18::from_num(current_burn))
-            .saturating_add(
-                I110F18::from_num(1.0)
-                    .saturating_sub(alpha)
-                    .saturating_mul(updated_burn),
-            );
+        let next_value: I110F18 = Self::registration_pressure_ema(
+            netuid,
+            current_burn,
+            registrations_this_interval,
+            target_registrations_per_interval,
+        );
         if next_value >= I110F18::from_num(Self::get_max_burn_as_u64(netuid)) {
             Self::get_max_burn_as_u64(netuid)
         } else if next_value <= I110F18::from_num(Self::get_min_burn_as_u64(netuid)) {
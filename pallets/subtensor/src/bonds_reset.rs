@@ -0,0 +1,78 @@
+use super::*;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// Starts clearing the bonds of `netuid` from its first uid, restarting any reset already
+    /// running. The epochs of the subnet are held back until its bonds are cleared, so no
+    /// epoch sees a matrix only partly reset.
+    pub fn schedule_bonds_reset(netuid: u16) {
+        BondsResetCursor::<T>::insert(netuid, 0);
+        log::info!("BondsResetStarted( netuid:{:?} )", netuid);
+        Self::deposit_subnet_event(&[netuid], Event::BondsResetStarted(netuid));
+    }
+
+    /// Removes at most BondsResetChunkSize bonds rows per block from the subnets being reset.
+    /// Subnets are reset one after another, each resuming at its cursor and reporting the uid
+    /// it reached. Returns the weight used.
+    pub fn reset_bonds_in_chunks() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let mut budget: u16 = Self::get_bonds_reset_chunk_size().max(1);
+        let resets: Vec<(u16, u16)> = BondsResetCursor::<T>::iter().collect();
+        weight.saturating_accrue(
+            T::DbWeight::get().reads(u64::try_from(resets.len()).unwrap_or(u64::MAX)),
+        );
+
+        for (netuid, cursor) in resets {
+            if budget == 0 {
+                break;
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+            // The reset resumes once the subnet is back under its new netuid.
+            if Self::is_subnet_migrating(netuid) {
+                continue;
+            }
+            let subnetwork_n = Self::get_subnetwork_n(netuid);
+            let end = cursor.saturating_add(budget).min(subnetwork_n);
+
+            for uid in cursor..end {
+                Bonds::<T>::remove(netuid, uid);
+            }
+            weight.saturating_accrue(
+                T::DbWeight::get().writes(u64::from(end.saturating_sub(cursor))),
+            );
+            budget = budget.saturating_sub(end.saturating_sub(cursor));
+
+            if end >= subnetwork_n {
+                BondsResetCursor::<T>::remove(netuid);
+                log::info!("BondsResetCompleted( netuid:{:?} )", netuid);
+                Self::deposit_subnet_event(&[netuid], Event::BondsResetCompleted(netuid));
+            } else {
+                BondsResetCursor::<T>::insert(netuid, end);
+                log::info!(
+                    "BondsResetProgress( netuid:{:?}, next_uid:{:?} )",
+                    netuid,
+                    end
+                );
+                Self::deposit_subnet_event(&[netuid], Event::BondsResetProgress(netuid, end));
+            }
+        }
+        weight
+    }
+
+    /// Whether the bonds of `netuid` are being reset, holding back its epochs.
+    pub fn is_bonds_reset_in_progress(netuid: u16) -> bool {
+        BondsResetCursor::<T>::contains_key(netuid)
+    }
+
+    pub fn get_bonds_reset_cursor(netuid: u16) -> Option<u16> {
+        BondsResetCursor::<T>::get(netuid)
+    }
+
+    pub fn get_bonds_reset_chunk_size() -> u16 {
+        BondsResetChunkSize::<T>::get()
+    }
+    pub fn set_bonds_reset_chunk_size(chunk_size: u16) {
+        BondsResetChunkSize::<T>::put(chunk_size);
+        Self::deposit_event(Event::BondsResetChunkSizeSet(chunk_size));
+    }
+}
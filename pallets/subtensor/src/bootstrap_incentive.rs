@@ -0,0 +1,95 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Raises the server emission of every uid registered since the previous epoch of
+    /// `netuid` to at least BootstrapIncentiveFloor of the epoch's total server emission, so
+    /// new miners earn before validators have weighed them. The top-ups are taken from the
+    /// server emission of the other uids, pro-rata to it, and are scaled down when it does
+    /// not cover them. Runs before LastMechansimStepBlock moves to the current epoch, so
+    /// uids registered in the block of the previous epoch, after it ran, count as new.
+    pub fn apply_bootstrap_incentive(
+        netuid: u16,
+        emission_tuples: &mut [(T::AccountId, u64, u64)],
+    ) {
+        let floor = Self::get_bootstrap_incentive_floor(netuid);
+        if floor == 0 {
+            return;
+        }
+        let last_epoch_block = Self::get_last_mechanism_step_block(netuid);
+        let is_new: Vec<bool> = emission_tuples
+            .iter()
+            .map(|(hotkey, _, _)| {
+                Self::get_uid_for_net_and_hotkey(netuid, hotkey).is_ok_and(|uid| {
+                    Self::get_neuron_block_at_registration(netuid, uid) >= last_epoch_block
+                })
+            })
+            .collect();
+
+        let total_server: u128 = emission_tuples
+            .iter()
+            .map(|(_, server, _)| u128::from(*server))
+            .sum();
+        let floor_emission: u128 = total_server
+            .saturating_mul(u128::from(floor))
+            .checked_div(u128::from(u16::MAX))
+            .unwrap_or(0);
+        let mut needed: u128 = 0;
+        let mut available: u128 = 0;
+        for ((_, server, _), new) in emission_tuples.iter().zip(&is_new) {
+            if *new {
+                needed = needed.saturating_add(floor_emission.saturating_sub(u128::from(*server)));
+            } else {
+                available = available.saturating_add(u128::from(*server));
+            }
+        }
+        let funding = needed.min(available);
+        if funding == 0 {
+            return;
+        }
+
+        // Take the funding from the established uids, rounding each share down.
+        let mut funded: u128 = 0;
+        for ((_, server, _), new) in emission_tuples.iter_mut().zip(&is_new) {
+            if *new {
+                continue;
+            }
+            let share: u128 = u128::from(*server)
+                .saturating_mul(funding)
+                .checked_div(available)
+                .unwrap_or(0);
+            *server = server.saturating_sub(u64::try_from(share).unwrap_or(u64::MAX));
+            funded = funded.saturating_add(share);
+        }
+
+        // Pay the top-ups out of what was funded, never more.
+        for ((hotkey, server, _), new) in emission_tuples.iter_mut().zip(&is_new) {
+            if !*new {
+                continue;
+            }
+            let top_up: u128 = floor_emission
+                .saturating_sub(u128::from(*server))
+                .saturating_mul(funded)
+                .checked_div(needed)
+                .unwrap_or(0);
+            if top_up == 0 {
+                continue;
+            }
+            let top_up = u64::try_from(top_up).unwrap_or(u64::MAX);
+            *server = server.saturating_add(top_up);
+            log::debug!(
+                "BootstrapIncentivePaid( netuid:{:?}, hotkey:{:?}, top_up:{:?} )",
+                netuid,
+                hotkey,
+                top_up
+            );
+        }
+    }
+
+    pub fn get_bootstrap_incentive_floor(netuid: u16) -> u16 {
+        BootstrapIncentiveFloor::<T>::get(netuid)
+    }
+    pub fn set_bootstrap_incentive_floor(netuid: u16, floor: u16) {
+        BootstrapIncentiveFloor::<T>::insert(netuid, floor);
+        Self::deposit_event(Event::BootstrapIncentiveFloorSet(netuid, floor));
+    }
+}
@@ -0,0 +1,129 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::traits::{GetCallIndex, GetCallName, PalletInfoAccess};
+use frame_support::weights::Weight;
+use sp_runtime::ModuleError;
+extern crate alloc;
+use codec::Compact;
+
+/// Calls made to a dispatchable of the pallet through signed extrinsics in the current call
+/// metrics era, and how many of them failed.
+#[freeze_struct("3e28c41fc5717091")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct CallMetrics {
+    pub call_index: Compact<u8>,
+    pub call_name: Vec<u8>,
+    pub calls: Compact<u64>,
+    pub failures: Compact<u64>,
+    /// Failures by the stable code of their error, 0 for errors not raised by this pallet.
+    pub failures_by_error: Vec<(Compact<u16>, Compact<u64>)>,
+}
+
+/// Call metrics of every dispatchable called since `era_start`.
+#[freeze_struct("754e5e7dfb8b38b4")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct CallMetricsReport {
+    pub era_start: Compact<u64>,
+    pub era_length: Compact<u64>,
+    pub calls: Vec<CallMetrics>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Counts a dispatch of `call_index` and, when it failed, its error. Returns the weight
+    /// used, which the dispatch did not account for.
+    pub fn record_call_metrics(call_index: u8, result: &DispatchResult) -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        CallCounts::<T>::mutate(call_index, |(calls, failures)| {
+            *calls = calls.saturating_add(1);
+            if result.is_err() {
+                *failures = failures.saturating_add(1);
+            }
+        });
+        if let Err(error) = result {
+            let error_code = Self::get_dispatch_error_code(error);
+            CallFailuresByError::<T>::mutate(call_index, error_code, |failures| {
+                *failures = failures.saturating_add(1)
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+        weight
+    }
+
+    /// The stable code of `error` when it is an `Error` of this pallet, 0 otherwise.
+    fn get_dispatch_error_code(error: &DispatchError) -> u16 {
+        match error {
+            DispatchError::Module(ModuleError { index, error, .. })
+                if usize::from(*index) == <Pallet<T> as PalletInfoAccess>::index() =>
+            {
+                Error::<T>::decode(&mut error.as_slice())
+                    .map(|error| error.error_code())
+                    .unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Clears the call metrics once CallMetricsEraLength blocks have passed since the era
+    /// started. A length of 0 keeps counting indefinitely. Returns the weight used.
+    pub fn roll_call_metrics_era(block_number: u64) -> Weight {
+        let mut weight = T::DbWeight::get().reads(2);
+        let era_length = Self::get_call_metrics_era_length();
+        if era_length == 0
+            || block_number.saturating_sub(CallMetricsEraStart::<T>::get()) < era_length
+        {
+            return weight;
+        }
+        let counts = CallCounts::<T>::clear(u32::MAX, None).unique;
+        let failures = CallFailuresByError::<T>::clear(u32::MAX, None).unique;
+        CallMetricsEraStart::<T>::put(block_number);
+        weight.saturating_accrue(
+            T::DbWeight::get().writes(
+                u64::from(counts)
+                    .saturating_add(u64::from(failures))
+                    .saturating_add(1),
+            ),
+        );
+        weight
+    }
+
+    pub fn get_call_metrics() -> CallMetricsReport {
+        let names = Call::<T>::get_call_names();
+        let mut calls: Vec<CallMetrics> = CallCounts::<T>::iter()
+            .map(|(call_index, (calls, failures))| {
+                let call_name = Call::<T>::get_call_indices()
+                    .iter()
+                    .position(|index| *index == call_index)
+                    .and_then(|position| names.get(position))
+                    .map(|name| name.as_bytes().to_vec())
+                    .unwrap_or_default();
+                let mut failures_by_error: Vec<(Compact<u16>, Compact<u64>)> =
+                    CallFailuresByError::<T>::iter_prefix(call_index)
+                        .map(|(error_code, failures)| (error_code.into(), failures.into()))
+                        .collect();
+                failures_by_error.sort_by_key(|(error_code, _)| error_code.0);
+                CallMetrics {
+                    call_index: call_index.into(),
+                    call_name,
+                    calls: calls.into(),
+                    failures: failures.into(),
+                    failures_by_error,
+                }
+            })
+            .collect();
+        calls.sort_by_key(|metrics| metrics.call_index.0);
+
+        CallMetricsReport {
+            era_start: CallMetricsEraStart::<T>::get().into(),
+            era_length: Self::get_call_metrics_era_length().into(),
+            calls,
+        }
+    }
+
+    pub fn get_call_metrics_era_length() -> u64 {
+        CallMetricsEraLength::<T>::get()
+    }
+    pub fn set_call_metrics_era_length(era_length: u64) {
+        CallMetricsEraLength::<T>::put(era_length);
+        Self::deposit_event(Event::CallMetricsEraLengthSet(era_length));
+    }
+}
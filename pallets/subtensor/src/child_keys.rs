@@ -0,0 +1,232 @@
+use super::*;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_children.
+    ///
+    /// Schedules `children` to replace the child keys of `hotkey` on `netuid` once
+    /// PendingChildKeyCooldown blocks pass. Each child is attributed its proportion, out of
+    /// u64::MAX, of the stake of the parent on the subnet. An empty list revokes the children.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey is not owned by the calling coldkey.
+    ///
+    /// * 'TooManyChildren':
+    ///     - More than MAX_CHILDREN children are given.
+    ///
+    /// * 'InvalidChild':
+    ///     - The hotkey is given as its own child.
+    ///
+    /// * 'DuplicateChild':
+    ///     - A child is given more than once.
+    ///
+    /// * 'ChildProportionsOverflow':
+    ///     - The proportions of the children add up to more than u64::MAX.
+    ///
+    /// * 'TooManyParents':
+    ///     - A child already has MAX_PARENTS other parents on the subnet.
+    ///
+    pub fn do_set_children(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+        children: Vec<(u64, T::AccountId)>,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            children.len() <= MAX_CHILDREN as usize,
+            Error::<T>::TooManyChildren
+        );
+        let mut total_proportion: u64 = 0;
+        for (i, (proportion, child)) in children.iter().enumerate() {
+            ensure!(*child != hotkey, Error::<T>::InvalidChild);
+            ensure!(
+                !children.iter().take(i).any(|(_, other)| other == child),
+                Error::<T>::DuplicateChild
+            );
+            total_proportion = total_proportion
+                .checked_add(*proportion)
+                .ok_or(Error::<T>::ChildProportionsOverflow)?;
+            let parents = ParentKeys::<T>::get(netuid, child);
+            ensure!(
+                parents.len() < MAX_PARENTS as usize
+                    || parents.iter().any(|(_, parent)| *parent == hotkey),
+                Error::<T>::TooManyParents
+            );
+        }
+
+        let cooldown_block =
+            Self::get_current_block_as_u64().saturating_add(Self::get_pending_child_key_cooldown());
+        PendingChildKeys::<T>::insert(netuid, &hotkey, (children, cooldown_block));
+        Self::schedule_pending_child_keys(netuid, &hotkey, cooldown_block);
+
+        log::info!(
+            "ChildKeysPending( hotkey:{:?}, netuid:{:?}, cooldown_block:{:?} )",
+            hotkey,
+            netuid,
+            cooldown_block
+        );
+        Self::deposit_subnet_event(
+            &[netuid],
+            Event::ChildKeysPending(hotkey, netuid, cooldown_block),
+        );
+        Ok(())
+    }
+
+    /// Indexes the pending child keys of `hotkey` on `netuid` under the block they apply at,
+    /// the first block after this one that `cooldown_block` is reached by.
+    pub(crate) fn schedule_pending_child_keys(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        cooldown_block: u64,
+    ) {
+        let apply_block = cooldown_block.max(Self::get_current_block_as_u64().saturating_add(1));
+        PendingChildKeysByBlock::<T>::insert(apply_block, (netuid, hotkey.clone()), ());
+    }
+
+    /// Applies the pending child keys indexed under `block_number`. Entries replaced by a
+    /// later set_children, or dropped with their subnet, are skipped, and those of a
+    /// migrating subnet wait for the next block. Returns the weight used.
+    pub fn apply_pending_child_keys(block_number: u64) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let scheduled: Vec<(u16, T::AccountId)> =
+            PendingChildKeysByBlock::<T>::drain_prefix(block_number)
+                .map(|(key, ())| key)
+                .collect();
+        let mut ready: Vec<(u16, T::AccountId, Vec<(u64, T::AccountId)>)> =
+            Vec::with_capacity(scheduled.len());
+        for (netuid, hotkey) in scheduled {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+            match PendingChildKeys::<T>::get(netuid, &hotkey) {
+                Some((_, cooldown_block)) if cooldown_block > block_number => {}
+                Some(_) if Self::is_subnet_migrating(netuid) => {
+                    weight.saturating_accrue(T::DbWeight::get().writes(1));
+                    PendingChildKeysByBlock::<T>::insert(
+                        block_number.saturating_add(1),
+                        (netuid, hotkey),
+                        (),
+                    );
+                }
+                Some((children, _)) => ready.push((netuid, hotkey, children)),
+                None => {}
+            }
+        }
+        for (netuid, hotkey, children) in ready {
+            // The old and new children each have their parents updated.
+            let children_n = u64::try_from(children.len())
+                .unwrap_or(u64::MAX)
+                .saturating_add(u64::from(MAX_CHILDREN));
+            weight.saturating_accrue(
+                T::DbWeight::get()
+                    .reads_writes(children_n.saturating_add(1), children_n.saturating_add(2)),
+            );
+            PendingChildKeys::<T>::remove(netuid, &hotkey);
+            Self::set_child_keys(netuid, &hotkey, children);
+
+            log::info!("ChildKeysSet( hotkey:{:?}, netuid:{:?} )", hotkey, netuid);
+            Self::deposit_subnet_event(&[netuid], Event::ChildKeysSet(hotkey, netuid));
+        }
+        weight
+    }
+
+    /// Replaces the children of `parent` on `netuid`, keeping ParentKeys in step. A child
+    /// which reached MAX_PARENTS since the children were set is left out.
+    fn set_child_keys(netuid: u16, parent: &T::AccountId, children: Vec<(u64, T::AccountId)>) {
+        for (_, old_child) in ChildKeys::<T>::take(netuid, parent) {
+            ParentKeys::<T>::mutate_exists(netuid, &old_child, |parents| {
+                if let Some(list) = parents {
+                    list.retain(|(_, other)| other != parent);
+                    if list.is_empty() {
+                        *parents = None;
+                    }
+                }
+            });
+        }
+        let mut linked: Vec<(u64, T::AccountId)> = Vec::with_capacity(children.len());
+        for (proportion, child) in children {
+            let pushed = ParentKeys::<T>::mutate(netuid, &child, |parents| {
+                parents.try_push((proportion, parent.clone())).is_ok()
+            });
+            if pushed {
+                linked.push((proportion, child));
+            } else {
+                log::warn!(
+                    "ChildKeyDropped( hotkey:{:?}, netuid:{:?}, child:{:?} ): too many parents",
+                    parent,
+                    netuid,
+                    child
+                );
+            }
+        }
+        if !linked.is_empty() {
+            ChildKeys::<T>::insert(netuid, parent, linked);
+        }
+    }
+
+    /// Returns the stake attributed to `hotkey` on `netuid`: its total stake less the
+    /// proportions it passes to its children, plus the proportions its parents pass to it
+    /// of their total stake. Parents pass on only the stake they hold themselves, so stake
+    /// moves one level down and never back through a cycle of keys.
+    pub fn get_stake_for_hotkey_on_subnet(hotkey: &T::AccountId, netuid: u16) -> u64 {
+        let own_stake = u128::from(Self::get_total_stake_for_hotkey(hotkey));
+        let given: u128 =
+            ChildKeys::<T>::get(netuid, hotkey)
+                .iter()
+                .fold(0, |total, (proportion, _)| {
+                    total.saturating_add(Self::stake_proportion(own_stake, *proportion))
+                });
+        let received: u128 =
+            ParentKeys::<T>::get(netuid, hotkey)
+                .iter()
+                .fold(0, |total, (proportion, parent)| {
+                    let parent_stake = u128::from(Self::get_total_stake_for_hotkey(parent));
+                    total.saturating_add(Self::stake_proportion(parent_stake, *proportion))
+                });
+        u64::try_from(own_stake.saturating_sub(given).saturating_add(received)).unwrap_or(u64::MAX)
+    }
+
+    fn stake_proportion(stake: u128, proportion: u64) -> u128 {
+        stake
+            .saturating_mul(u128::from(proportion))
+            .checked_div(u128::from(u64::MAX))
+            .unwrap_or(0)
+    }
+
+    /// Returns the children of `hotkey` on `netuid` with their proportions.
+    pub fn get_child_keys(hotkey: &T::AccountId, netuid: u16) -> Vec<(u64, T::AccountId)> {
+        ChildKeys::<T>::get(netuid, hotkey)
+    }
+
+    /// Returns the parents of `hotkey` on `netuid` with the proportions they pass to it.
+    pub fn get_parent_keys(hotkey: &T::AccountId, netuid: u16) -> Vec<(u64, T::AccountId)> {
+        ParentKeys::<T>::get(netuid, hotkey).into_inner()
+    }
+
+    /// Returns the children pending for `hotkey` on `netuid` and the block they apply at.
+    pub fn get_pending_child_keys(
+        hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> Option<(Vec<(u64, T::AccountId)>, u64)> {
+        PendingChildKeys::<T>::get(netuid, hotkey)
+    }
+
+    pub fn get_pending_child_key_cooldown() -> u64 {
+        PendingChildKeyCooldown::<T>::get()
+    }
+    pub fn set_pending_child_key_cooldown(cooldown: u64) {
+        PendingChildKeyCooldown::<T>::put(cooldown);
+        Self::deposit_event(Event::PendingChildKeyCooldownSet(cooldown));
+    }
+}
@@ -7,33 +7,76 @@ extern crate alloc;
 use codec::Compact;
 use sp_core::hexdisplay::AsBytesRef;
 
-#[freeze_struct("5752e4c650a83e0d")]
+#[freeze_struct("fe5794d6b173d2b0")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct DelegateInfo<T: Config> {
     delegate_ss58: T::AccountId,
     take: Compact<u16>,
-    nominators: Vec<(T::AccountId, Compact<u64>)>, // map of nominator_ss58 to stake amount
+    nominators: Vec<(T::AccountId, Compact<u64>)>, // map of nominator_ss58 to stake amount, only listed by get_delegate
+    nominator_count: Compact<u32>,                 // Number of coldkeys with stake on this delegate
+    total_delegated: Compact<u64>, // Stake on this delegate from coldkeys other than its owner
     owner_ss58: T::AccountId,
-    registrations: Vec<Compact<u16>>, // Vec of netuid this delegate is registered on
+    registrations: Vec<DelegateRegistration>, // The subnets this delegate is registered on
+    registration_symbols: Vec<Vec<u8>>, // Token symbol of each subnet in registrations, empty if unset
     validator_permits: Vec<Compact<u16>>, // Vec of netuid this delegate has validator permit on
     return_per_1000: Compact<u64>, // Delegators current daily return per 1000 TAO staked minus take fee
     total_daily_return: Compact<u64>, // Delegators current daily return
 }
 
+/// A subnet a delegate is registered on, telling the delegates validating there from the ones
+/// only registered.
+#[freeze_struct("400a64ddd95b3d82")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct DelegateRegistration {
+    pub netuid: Compact<u16>,
+    /// Whether the delegate holds a validator permit on the subnet.
+    pub validator_permit: bool,
+    pub rank: Compact<u16>,
+    /// Emission of the delegate in the last epoch of the subnet.
+    pub emission: Compact<u64>,
+}
+
 impl<T: Config> Pallet<T> {
-    fn get_delegate_by_existing_account(delegate: AccountIdOf<T>) -> DelegateInfo<T> {
-        let mut nominators = Vec::<(T::AccountId, Compact<u64>)>::new();
+    /// Returns the subnets `delegate` is registered on with its validator permit, rank and
+    /// emission on each.
+    pub fn get_delegate_registrations(delegate: &T::AccountId) -> Vec<DelegateRegistration> {
+        Self::get_registered_networks_for_hotkey(delegate)
+            .into_iter()
+            .filter_map(|netuid| {
+                let uid = Self::get_uid_for_net_and_hotkey(netuid, delegate).ok()?;
+                Some(DelegateRegistration {
+                    netuid: netuid.into(),
+                    validator_permit: Self::get_validator_permit_for_uid(netuid, uid),
+                    rank: Self::get_rank_for_uid(netuid, uid).into(),
+                    emission: Self::get_emission_for_uid(netuid, uid).into(),
+                })
+            })
+            .collect()
+    }
 
-        for (nominator, stake) in
-            <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix(
-                delegate.clone(),
-            )
-        {
-            if stake == 0 {
-                continue;
+    /// Assembles the info of `delegate` from the delegate indexes. The nominators are only
+    /// listed `with_nominators`, which iterates the stake of the delegate, so that listing
+    /// every delegate stays O(delegates).
+    fn get_delegate_by_existing_account(
+        delegate: AccountIdOf<T>,
+        with_nominators: bool,
+    ) -> DelegateInfo<T> {
+        let nominator_count: u32 = Self::get_delegate_nominator_count(&delegate);
+        let mut nominators = Vec::<(T::AccountId, Compact<u64>)>::new();
+        if with_nominators {
+            nominators.reserve(nominator_count as usize);
+            for (nominator, stake) in <Stake<T> as IterableStorageDoubleMap<
+                T::AccountId,
+                T::AccountId,
+                u64,
+            >>::iter_prefix(delegate.clone())
+            {
+                if stake == 0 {
+                    continue;
+                }
+                // Only add nominators with stake
+                nominators.push((nominator.clone(), stake.into()));
             }
-            // Only add nominators with stake
-            nominators.push((nominator.clone(), stake.into()));
         }
 
         let registrations = Self::get_registered_networks_for_hotkey(&delegate.clone());
@@ -57,8 +100,9 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        let delegate_registrations = Self::get_delegate_registrations(&delegate);
         let owner = Self::get_owning_coldkey_for_hotkey(&delegate.clone());
-        let take: Compact<u16> = <Delegates<T>>::get(delegate.clone()).into();
+        let take: Compact<u16> = Self::get_effective_delegate_take(&delegate).into();
 
         let total_stake: U64F64 = Self::get_total_stake_for_hotkey(&delegate.clone()).into();
 
@@ -74,8 +118,14 @@ impl<T: Config> Pallet<T> {
             delegate_ss58: delegate.clone(),
             take,
             nominators,
+            nominator_count: nominator_count.into(),
+            total_delegated: Self::get_total_hotkey_delegated_stake(&delegate).into(),
             owner_ss58: owner.clone(),
-            registrations: registrations.iter().map(|x| x.into()).collect(),
+            registration_symbols: delegate_registrations
+                .iter()
+                .map(|registration| Self::get_subnet_symbol(registration.netuid.0))
+                .collect(),
+            registrations: delegate_registrations,
             validator_permits,
             return_per_1000: U64F64::to_num::<u64>(return_per_1000).into(),
             total_daily_return: U64F64::to_num::<u64>(emissions_per_day).into(),
@@ -94,16 +144,16 @@ impl<T: Config> Pallet<T> {
             return None;
         }
 
-        let delegate_info = Self::get_delegate_by_existing_account(delegate.clone());
+        let delegate_info = Self::get_delegate_by_existing_account(delegate.clone(), true);
         Some(delegate_info)
     }
 
-    /// get all delegates info from storage
+    /// get all delegates info from storage, without their nominators
     ///
     pub fn get_delegates() -> Vec<DelegateInfo<T>> {
         let mut delegates = Vec::<DelegateInfo<T>>::new();
         for delegate in <Delegates<T> as IterableStorageMap<T::AccountId, u16>>::iter_keys() {
-            let delegate_info = Self::get_delegate_by_existing_account(delegate.clone());
+            let delegate_info = Self::get_delegate_by_existing_account(delegate.clone(), false);
             delegates.push(delegate_info);
         }
 
@@ -118,14 +168,18 @@ impl<T: Config> Pallet<T> {
         };
 
         let mut delegates: Vec<(DelegateInfo<T>, Compact<u64>)> = Vec::new();
-        for delegate in <Delegates<T> as IterableStorageMap<T::AccountId, u16>>::iter_keys() {
+        // Only the hotkeys this account stakes to can be delegates it has stake on.
+        for delegate in Self::get_all_staked_hotkeys(&delegatee) {
+            if !Self::hotkey_is_delegate(&delegate) {
+                continue;
+            }
             let staked_to_this_delegatee =
                 Self::get_stake_for_coldkey_and_hotkey(&delegatee.clone(), &delegate.clone());
             if staked_to_this_delegatee == 0 {
                 continue; // No stake to this delegate
             }
             // Staked to this delegate, so add to list
-            let delegate_info = Self::get_delegate_by_existing_account(delegate.clone());
+            let delegate_info = Self::get_delegate_by_existing_account(delegate.clone(), false);
             delegates.push((delegate_info, staked_to_this_delegatee.into()));
         }
 
@@ -136,16 +190,10 @@ impl<T: Config> Pallet<T> {
         let mut total_delegated = 0u64;
 
         // Get all hotkeys associated with this coldkey
-        let hotkeys = StakingHotkeys::<T>::get(coldkey);
+        let hotkeys = Self::get_all_staked_hotkeys(coldkey);
 
         for hotkey in hotkeys {
-            let owner = Owner::<T>::get(&hotkey);
-
-            for (delegator, stake) in Stake::<T>::iter_prefix(&hotkey) {
-                if delegator != owner {
-                    total_delegated = total_delegated.saturating_add(stake);
-                }
-            }
+            total_delegated = total_delegated.saturating_add(DelegateTotalStake::<T>::get(&hotkey));
         }
 
         log::info!(
@@ -158,16 +206,59 @@ impl<T: Config> Pallet<T> {
 
     // Helper function to get total delegated stake for a hotkey
     pub fn get_total_hotkey_delegated_stake(hotkey: &T::AccountId) -> u64 {
-        let mut total_delegated = 0u64;
+        DelegateTotalStake::<T>::get(hotkey)
+    }
+
+    // Helper function to get the number of coldkeys with stake on a hotkey
+    pub fn get_delegate_nominator_count(hotkey: &T::AccountId) -> u32 {
+        DelegateNominatorCount::<T>::get(hotkey)
+    }
 
-        // Iterate through all delegators for this hotkey
-        for (delegator, stake) in Stake::<T>::iter_prefix(hotkey) {
-            if delegator != Self::get_coldkey_for_hotkey(hotkey) {
-                total_delegated = total_delegated.saturating_add(stake);
+    /// Updates the delegate indexes of `hotkey` after the stake of `coldkey` on it moved from
+    /// `old_stake` to `new_stake`. Must be called by every path that writes to `Stake`.
+    pub fn update_delegate_indexes(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        old_stake: u64,
+        new_stake: u64,
+    ) {
+        if old_stake == 0 && new_stake > 0 {
+            DelegateNominatorCount::<T>::mutate(hotkey, |count| count.saturating_inc());
+        } else if old_stake > 0 && new_stake == 0 {
+            DelegateNominatorCount::<T>::mutate(hotkey, |count| count.saturating_dec());
+        }
+
+        if old_stake != new_stake && !Self::coldkey_owns_hotkey(coldkey, hotkey) {
+            DelegateTotalStake::<T>::mutate(hotkey, |total| {
+                *total = total.saturating_sub(old_stake).saturating_add(new_stake)
+            });
+        }
+    }
+
+    /// Recomputes the delegate indexes of `hotkey` from the `Stake` map.
+    ///
+    /// Used where stake or ownership is moved wholesale, such as key swaps and migrations.
+    /// Returns the number of storage reads performed.
+    pub fn refresh_delegate_indexes(hotkey: &T::AccountId) -> u64 {
+        let owner = Owner::<T>::get(hotkey);
+        let mut reads: u64 = 1;
+        let mut nominator_count: u32 = 0;
+        let mut delegated_stake: u64 = 0;
+
+        for (coldkey, stake) in Stake::<T>::iter_prefix(hotkey) {
+            reads.saturating_inc();
+            if stake == 0 {
+                continue;
+            }
+            nominator_count.saturating_inc();
+            if coldkey != owner {
+                delegated_stake = delegated_stake.saturating_add(stake);
             }
         }
 
-        total_delegated
+        DelegateNominatorCount::<T>::insert(hotkey, nominator_count);
+        DelegateTotalStake::<T>::insert(hotkey, delegated_stake);
+        reads
     }
 
     // Helper function to get the coldkey associated with a hotkey
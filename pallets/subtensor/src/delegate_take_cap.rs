@@ -0,0 +1,249 @@
+use super::*;
+use frame_support::storage::StoragePrefixedMap;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic vote_delegate_take_cap.
+    ///
+    /// Records the take the calling nominator votes to cap `hotkey` at, replacing its
+    /// previous vote, or withdraws its vote when `cap` is None. Votes are weighed by the
+    /// stake the nominator holds on the delegate when they are tallied.
+    ///
+    /// # Raises:
+    /// * 'HotKeyNotDelegate':
+    ///     - The hotkey is not a delegate.
+    ///
+    /// * 'NoStakeOnDelegate':
+    ///     - The coldkey holds no stake on the delegate.
+    ///
+    /// * 'DelegateTakeTooLow':
+    ///     - The cap is below the minimum delegate take.
+    ///
+    /// * 'DelegateTakeTooHigh':
+    ///     - The cap is above the maximum delegate take.
+    ///
+    pub fn do_vote_delegate_take_cap(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        cap: Option<u16>,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::hotkey_is_delegate(&hotkey),
+            Error::<T>::HotKeyNotDelegate
+        );
+
+        let Some(cap) = cap else {
+            TakeCapVotes::<T>::remove(&hotkey, &coldkey);
+            log::info!(
+                "TakeCapVoteWithdrawn( hotkey:{:?}, coldkey:{:?} )",
+                hotkey,
+                coldkey
+            );
+            Self::deposit_event(Event::TakeCapVoteWithdrawn(hotkey, coldkey));
+            return Ok(());
+        };
+
+        ensure!(
+            Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey) > 0,
+            Error::<T>::NoStakeOnDelegate
+        );
+        ensure!(
+            cap >= Self::get_min_delegate_take(),
+            Error::<T>::DelegateTakeTooLow
+        );
+        ensure!(
+            cap <= Self::get_max_delegate_take(),
+            Error::<T>::DelegateTakeTooHigh
+        );
+
+        TakeCapVotes::<T>::insert(&hotkey, &coldkey, cap);
+
+        log::info!(
+            "TakeCapVoted( hotkey:{:?}, coldkey:{:?}, cap:{:?} )",
+            hotkey,
+            coldkey,
+            cap
+        );
+        Self::deposit_event(Event::TakeCapVoted(hotkey, coldkey, cap));
+        Ok(())
+    }
+
+    /// Tallies the take cap votes on every delegate from the tempo boundaries of the root
+    /// network on, at most MAX_DELEGATES_CHECKED_PER_BLOCK delegates per block. Caps that ran
+    /// for TakeCapDuration blocks are lifted first. A delegate is capped at the lowest take
+    /// that nominators holding TakeCapThreshold of its stake voted for or below, and the votes
+    /// behind a cap are cleared so it lapses unless the nominators vote again. Returns the
+    /// weight used.
+    pub fn tally_delegate_take_caps(block_number: u64) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let Some(cursor) =
+            TakeCapTallyCursor::<T>::get().or_else(|| Self::start_delegates_pass(block_number))
+        else {
+            return weight;
+        };
+
+        let (delegates, next_cursor) = Self::next_delegates_chunk(cursor);
+        match next_cursor {
+            Some(cursor) => TakeCapTallyCursor::<T>::put(cursor),
+            None => TakeCapTallyCursor::<T>::kill(),
+        }
+        weight.saturating_accrue(
+            T::DbWeight::get().reads_writes(u64::try_from(delegates.len()).unwrap_or(u64::MAX), 1),
+        );
+
+        let threshold = u128::from(Self::get_take_cap_threshold());
+        for hotkey in delegates {
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
+            if DelegateTakeCap::<T>::get(&hotkey)
+                .is_some_and(|(_, expires_at)| block_number >= expires_at)
+            {
+                DelegateTakeCap::<T>::remove(&hotkey);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+                log::info!("DelegateTakeCapExpired( hotkey:{:?} )", hotkey);
+                Self::deposit_event(Event::DelegateTakeCapExpired(hotkey.clone()));
+            }
+
+            let total = u128::from(Self::get_total_stake_for_hotkey(&hotkey));
+            let mut votes: Vec<(u16, u128)> = TakeCapVotes::<T>::iter_prefix(&hotkey)
+                .map(|(coldkey, cap)| {
+                    let stake = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+                    (cap, u128::from(stake))
+                })
+                .collect();
+            if votes.is_empty() {
+                continue;
+            }
+            weight.saturating_accrue(
+                T::DbWeight::get().reads(
+                    u64::try_from(votes.len())
+                        .unwrap_or(u64::MAX)
+                        .saturating_mul(2),
+                ),
+            );
+            votes.sort_by_key(|(cap, _)| *cap);
+
+            // A nominator voting for a cap also backs any higher one.
+            let mut backing: u128 = 0;
+            let passed = votes.iter().find_map(|(cap, stake)| {
+                backing = backing.saturating_add(*stake);
+                (total > 0
+                    && backing.saturating_mul(u128::from(u16::MAX))
+                        >= threshold.saturating_mul(total))
+                .then_some(*cap)
+            });
+            let Some(cap) = passed else {
+                continue;
+            };
+
+            let _ = TakeCapVotes::<T>::clear_prefix(&hotkey, u32::MAX, None);
+            let expires_at = block_number.saturating_add(Self::get_take_cap_duration());
+            DelegateTakeCap::<T>::insert(&hotkey, (cap, expires_at));
+            weight.saturating_accrue(
+                T::DbWeight::get().writes(
+                    u64::try_from(votes.len())
+                        .unwrap_or(u64::MAX)
+                        .saturating_add(1),
+                ),
+            );
+
+            log::info!(
+                "DelegateTakeCapped( hotkey:{:?}, cap:{:?}, expires_at:{:?} )",
+                hotkey,
+                cap,
+                expires_at
+            );
+            Self::deposit_event(Event::DelegateTakeCapped(hotkey, cap, expires_at));
+        }
+        weight
+    }
+
+    /// Starts a pass over the delegates at the tempo boundaries of the root network,
+    /// returning the raw key the pass starts after.
+    pub fn start_delegates_pass(block_number: u64) -> Option<Vec<u8>> {
+        let root_netuid = Self::get_root_netuid();
+        (Self::blocks_until_next_epoch(root_netuid, Self::get_tempo(root_netuid), block_number)
+            == 0)
+            .then(|| Delegates::<T>::final_prefix().to_vec())
+    }
+
+    /// Returns at most MAX_DELEGATES_CHECKED_PER_BLOCK delegates after the raw key `cursor`,
+    /// and the raw key to resume after, None once the delegates are all read.
+    pub fn next_delegates_chunk(cursor: Vec<u8>) -> (Vec<T::AccountId>, Option<Vec<u8>>) {
+        let mut keys = Delegates::<T>::iter_keys_from(cursor);
+        let limit = usize::try_from(MAX_DELEGATES_CHECKED_PER_BLOCK).unwrap_or(usize::MAX);
+        let delegates: Vec<T::AccountId> = keys.by_ref().take(limit).collect();
+        let next_cursor = (delegates.len() >= limit).then(|| keys.last_raw_key().to_vec());
+        (delegates, next_cursor)
+    }
+
+    /// Returns the take applied to the emission of `hotkey`: its take, lowered to the cap its
+    /// nominators voted while the cap runs, and to UnderperformanceTake while it is reduced
+    /// for underperformance.
+    pub fn get_effective_delegate_take(hotkey: &T::AccountId) -> u16 {
+        let take = Self::get_hotkey_take(hotkey);
+        let take = match DelegateTakeCap::<T>::get(hotkey) {
+            Some((cap, expires_at)) if Self::get_current_block_as_u64() < expires_at => {
+                take.min(cap)
+            }
+            _ => take,
+        };
+        if Self::is_delegate_take_reduced(hotkey) {
+            take.min(UnderperformanceTake::<T>::get())
+        } else {
+            take
+        }
+    }
+
+    /// Returns the take applied to the emission `hotkey` earns on `netuid`: its effective take,
+    /// lowered to the cap the subnet owner set on the subnet.
+    pub fn get_delegate_take_on_subnet(hotkey: &T::AccountId, netuid: u16) -> u16 {
+        let take = Self::get_effective_delegate_take(hotkey);
+        match Self::get_max_take_per_subnet(netuid) {
+            Some(max_take) => take.min(max_take),
+            None => take,
+        }
+    }
+
+    /// Returns the cap on the take of delegates for the emission earned on `netuid`.
+    pub fn get_max_take_per_subnet(netuid: u16) -> Option<u16> {
+        MaxTakePerSubnet::<T>::get(netuid)
+    }
+
+    /// Sets the cap on the take of delegates for the emission earned on `netuid`. A cap at or
+    /// above the maximum delegate take lifts it.
+    pub fn set_max_take_per_subnet(netuid: u16, max_take: u16) {
+        if max_take >= Self::get_max_delegate_take() {
+            MaxTakePerSubnet::<T>::remove(netuid);
+        } else {
+            MaxTakePerSubnet::<T>::insert(netuid, max_take);
+        }
+        Self::deposit_subnet_event(&[netuid], Event::MaxTakePerSubnetSet(netuid, max_take));
+    }
+
+    /// Returns the cap on the take of `hotkey` and the block it runs until.
+    pub fn get_delegate_take_cap(hotkey: &T::AccountId) -> Option<(u16, u64)> {
+        DelegateTakeCap::<T>::get(hotkey)
+    }
+
+    /// Returns the cap `coldkey` votes to hold the take of `hotkey` to.
+    pub fn get_take_cap_vote(hotkey: &T::AccountId, coldkey: &T::AccountId) -> Option<u16> {
+        TakeCapVotes::<T>::get(hotkey, coldkey)
+    }
+
+    pub fn get_take_cap_threshold() -> u16 {
+        TakeCapThreshold::<T>::get()
+    }
+    pub fn set_take_cap_threshold(threshold: u16) {
+        TakeCapThreshold::<T>::put(threshold);
+        Self::deposit_event(Event::TakeCapThresholdSet(threshold));
+    }
+
+    pub fn get_take_cap_duration() -> u64 {
+        TakeCapDuration::<T>::get()
+    }
+    pub fn set_take_cap_duration(duration: u64) {
+        TakeCapDuration::<T>::put(duration);
+        Self::deposit_event(Event::TakeCapDurationSet(duration));
+    }
+}
@@ -0,0 +1,124 @@
+use super::*;
+use frame_support::storage::StoragePrefixedMap;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// Checks the validator trust of every delegate from the tempo boundaries of the root
+    /// network on, at most MAX_DELEGATES_CHECKED_PER_BLOCK delegates per block, while
+    /// UnderperformanceTrustThreshold is set. The take of a delegate whose trust stays below
+    /// the threshold for UnderperformanceTempos root tempos in a row is reduced to
+    /// UnderperformanceTake, and restored once its trust stays at or above the threshold for
+    /// as many tempos in a row. Delegates registered on no subnet are skipped. Returns the
+    /// weight used.
+    pub fn check_delegate_underperformance(block_number: u64) -> Weight {
+        let mut weight = T::DbWeight::get().reads(2);
+        let threshold = UnderperformanceTrustThreshold::<T>::get();
+        if threshold == 0 {
+            DelegateUnderperformanceCursor::<T>::kill();
+            return weight;
+        }
+        let Some(cursor) = DelegateUnderperformanceCursor::<T>::get()
+            .or_else(|| Self::start_delegates_pass(block_number))
+        else {
+            return weight;
+        };
+
+        let tempos = UnderperformanceTempos::<T>::get().max(1);
+        let (delegates, next_cursor) = Self::next_delegates_chunk(cursor);
+        match next_cursor {
+            Some(cursor) => DelegateUnderperformanceCursor::<T>::put(cursor),
+            None => DelegateUnderperformanceCursor::<T>::kill(),
+        }
+        weight.saturating_accrue(
+            T::DbWeight::get().reads_writes(u64::try_from(delegates.len()).unwrap_or(u64::MAX), 1),
+        );
+
+        for hotkey in delegates {
+            // The trust is read from the uid and the validator trust on each subnet.
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(8, 1));
+            let Some(trust) = Self::get_delegate_validator_trust(&hotkey) else {
+                continue;
+            };
+            let (reduced, streak) = DelegateUnderperformance::<T>::get(&hotkey).unwrap_or_default();
+
+            // The streak counts the tempos in a row the trust is on the other side of the
+            // threshold than the take, so a single tempo doesn't flip it.
+            let crossed = if reduced {
+                trust >= threshold
+            } else {
+                trust < threshold
+            };
+            let streak = if crossed { streak.saturating_add(1) } else { 0 };
+            if streak < tempos {
+                if reduced || streak > 0 {
+                    DelegateUnderperformance::<T>::insert(&hotkey, (reduced, streak));
+                } else {
+                    DelegateUnderperformance::<T>::remove(&hotkey);
+                }
+                continue;
+            }
+
+            if reduced {
+                DelegateUnderperformance::<T>::remove(&hotkey);
+                log::info!("DelegateTakeRestored( hotkey:{:?} )", hotkey);
+                Self::deposit_event(Event::DelegateTakeRestored(hotkey));
+            } else {
+                DelegateUnderperformance::<T>::insert(&hotkey, (true, 0));
+                let take = UnderperformanceTake::<T>::get();
+                log::info!(
+                    "DelegateTakeReduced( hotkey:{:?}, take:{:?} )",
+                    hotkey,
+                    take
+                );
+                Self::deposit_event(Event::DelegateTakeReduced(hotkey, take));
+            }
+        }
+        weight
+    }
+
+    /// Returns the mean validator trust of `hotkey` over the subnets it is registered on,
+    /// other than the root network, or nothing if there are none.
+    pub fn get_delegate_validator_trust(hotkey: &T::AccountId) -> Option<u16> {
+        let root_netuid = Self::get_root_netuid();
+        let trusts: Vec<u64> = Self::get_registered_networks_for_hotkey(hotkey)
+            .into_iter()
+            .filter(|netuid| *netuid != root_netuid)
+            .filter_map(|netuid| {
+                let uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey).ok()?;
+                Some(u64::from(Self::get_validator_trust_for_uid(netuid, uid)))
+            })
+            .collect();
+        let count = u64::try_from(trusts.len()).unwrap_or(u64::MAX);
+        let mean = trusts
+            .iter()
+            .fold(0u64, |total, trust| total.saturating_add(*trust))
+            .checked_div(count)?;
+        Some(u16::try_from(mean).unwrap_or(u16::MAX))
+    }
+
+    /// Returns whether the take of `hotkey` is reduced for underperformance.
+    pub fn is_delegate_take_reduced(hotkey: &T::AccountId) -> bool {
+        UnderperformanceTrustThreshold::<T>::get() != 0
+            && DelegateUnderperformance::<T>::get(hotkey).is_some_and(|(reduced, _)| reduced)
+    }
+
+    /// Returns the validator trust threshold, the root tempos in a row and the reduced take
+    /// of the take reduction of underperforming delegates.
+    pub fn get_delegate_underperformance() -> (u16, u16, u16) {
+        (
+            UnderperformanceTrustThreshold::<T>::get(),
+            UnderperformanceTempos::<T>::get(),
+            UnderperformanceTake::<T>::get(),
+        )
+    }
+    pub fn set_delegate_underperformance(trust_threshold: u16, tempos: u16, take: u16) {
+        UnderperformanceTrustThreshold::<T>::put(trust_threshold);
+        UnderperformanceTempos::<T>::put(tempos);
+        UnderperformanceTake::<T>::put(take);
+        Self::deposit_event(Event::DelegateUnderperformanceSet(
+            trust_threshold,
+            tempos,
+            take,
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+extern crate alloc;
+use codec::Compact;
+
+/// Proof that the emission of a subnet is a leaf of the RootEpochCommitment made at `block`.
+#[freeze_struct("d8d8bc9a5a42b648")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct EmissionProof {
+    pub netuid: Compact<u16>,
+    pub emission: Compact<u64>,
+    pub block: Compact<u64>,
+    pub root: H256,
+    /// Siblings from the leaf up to the root, each flagged true when it is the left node of
+    /// its pair. Levels where the node is carried up unpaired have no entry.
+    pub path: Vec<(H256, bool)>,
+}
+
+impl EmissionProof {
+    /// Whether folding the leaf of the proof along its path gives its root.
+    pub fn verify(&self) -> bool {
+        let leaf = (self.netuid.0, self.emission.0);
+        let node = self
+            .path
+            .iter()
+            .fold(blake2_256(&leaf.encode()), |node, (sibling, is_left)| {
+                if *is_left {
+                    blake2_256(&[sibling.as_bytes(), node.as_slice()].concat())
+                } else {
+                    blake2_256(&[node.as_slice(), sibling.as_bytes()].concat())
+                }
+            });
+        H256::from(node) == self.root
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Commits the emission values set by the root epoch at `block_number` to
+    /// RootEpochCommitment.
+    ///
+    /// Leaf `i` is the blake2-256 hash of the SCALE encoding of the `i`-th `(netuid, emission)`
+    /// in netuid order, as `(u16, u64)`, excluding the root network. The tree is built as for
+    /// `get_epoch_snapshot_root`.
+    pub fn record_root_epoch_commitment(block_number: u64) {
+        let leaves: Vec<[u8; 32]> = Self::get_emission_commitment_entries()
+            .iter()
+            .map(|leaf| blake2_256(&leaf.encode()))
+            .collect();
+        let root = Self::merkle_root(leaves);
+        RootEpochCommitment::<T>::put((block_number, root));
+        log::debug!(
+            "RootEpochCommitted( block:{:?}, root:{:?} )",
+            block_number,
+            root
+        );
+        Self::deposit_event(Event::RootEpochCommitted(block_number, root));
+    }
+
+    fn get_emission_commitment_entries() -> Vec<(u16, u64)> {
+        let mut netuids: Vec<u16> = Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter(|netuid| *netuid != Self::get_root_netuid())
+            .collect();
+        netuids.sort_unstable();
+        netuids
+            .into_iter()
+            .map(|netuid| (netuid, EmissionValues::<T>::get(netuid)))
+            .collect()
+    }
+
+    /// Returns the block of the last root epoch and the merkle root of the emission values it
+    /// set.
+    pub fn get_root_epoch_commitment() -> (u64, H256) {
+        RootEpochCommitment::<T>::get()
+    }
+
+    /// Returns the proof of the emission of `netuid` against RootEpochCommitment, None if the
+    /// subnet does not exist or its emission changed since the commitment was made.
+    pub fn get_emission_proof(netuid: u16) -> Option<EmissionProof> {
+        let entries = Self::get_emission_commitment_entries();
+        let mut index = entries.iter().position(|(entry, _)| *entry == netuid)?;
+        let emission = entries.get(index).map(|(_, emission)| *emission)?;
+
+        let mut level: Vec<[u8; 32]> = entries
+            .iter()
+            .map(|leaf| blake2_256(&leaf.encode()))
+            .collect();
+        let mut path: Vec<(H256, bool)> = Vec::new();
+        while level.len() > 1 {
+            let sibling = index ^ 1;
+            if let Some(node) = level.get(sibling) {
+                path.push((H256::from(*node), sibling < index));
+            }
+            level = Self::merkle_level(&level);
+            index = index.saturating_div(2);
+        }
+
+        let (block, root) = Self::get_root_epoch_commitment();
+        let proof = EmissionProof {
+            netuid: netuid.into(),
+            emission: emission.into(),
+            block: block.into(),
+            root,
+            path,
+        };
+        proof.verify().then_some(proof)
+    }
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Appends the emission value of every subnet set by the root epoch to its
+    /// EmissionHistory, dropping the oldest value once EMISSION_HISTORY_LENGTH are kept.
+    pub fn record_emission_history() {
+        for netuid in Self::get_all_subnet_netuids() {
+            if netuid == Self::get_root_netuid() {
+                continue;
+            }
+            let emission = EmissionValues::<T>::get(netuid);
+            EmissionHistory::<T>::mutate(netuid, |history| {
+                if history.len() >= EMISSION_HISTORY_LENGTH as usize {
+                    history.remove(0);
+                }
+                // Cannot fail, the oldest value was dropped above.
+                let _ = history.try_push(emission);
+            });
+        }
+    }
+
+    /// Returns the emission values of `netuid` set by the last root epochs, oldest first.
+    pub fn get_emission_history(netuid: u16) -> Vec<u64> {
+        EmissionHistory::<T>::get(netuid).into_inner()
+    }
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Scales the emission values set by the root epoch by the EmissionMultiplier of each
+    /// subnet, in basis points. Meant for testnets and staged launches, a subnet boosted above
+    /// 10_000 basis points receives more than its share of the block emission.
+    pub fn apply_emission_multipliers() {
+        for (netuid, multiplier) in EmissionMultiplier::<T>::iter() {
+            if multiplier == EMISSION_MULTIPLIER_BPS || !Self::if_subnet_exist(netuid) {
+                continue;
+            }
+            let scaled = Self::multiply_emission(EmissionValues::<T>::get(netuid), multiplier);
+            EmissionValues::<T>::insert(netuid, scaled);
+
+            log::debug!(
+                "SubnetEmissionMultiplied( netuid:{:?}, multiplier:{:?}, emission:{:?} )",
+                netuid,
+                multiplier,
+                scaled
+            );
+            Self::deposit_subnet_event(
+                &[netuid],
+                Event::SubnetEmissionMultiplied(netuid, multiplier, scaled),
+            );
+        }
+    }
+
+    /// Scales `emission` by `multiplier` basis points.
+    pub fn multiply_emission(emission: u64, multiplier: u32) -> u64 {
+        u128::from(emission)
+            .saturating_mul(u128::from(multiplier))
+            .checked_div(u128::from(EMISSION_MULTIPLIER_BPS))
+            .and_then(|scaled| u64::try_from(scaled).ok())
+            .unwrap_or(u64::MAX)
+    }
+
+    pub fn get_emission_multiplier(netuid: u16) -> u32 {
+        EmissionMultiplier::<T>::get(netuid)
+    }
+    pub fn set_emission_multiplier(netuid: u16, multiplier: u32) {
+        if multiplier == EMISSION_MULTIPLIER_BPS {
+            EmissionMultiplier::<T>::remove(netuid);
+        } else {
+            EmissionMultiplier::<T>::insert(netuid, multiplier);
+        }
+        Self::deposit_subnet_event(&[netuid], Event::EmissionMultiplierSet(netuid, multiplier));
+    }
+}
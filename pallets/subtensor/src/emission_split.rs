@@ -0,0 +1,98 @@
+use super::*;
+use substrate_fixed::types::{I32F32, I96F32};
+
+/// Basis points in a whole, the sum of the shares of an EmissionSplit.
+const EMISSION_SPLIT_BPS: u16 = 10_000;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_emission_split.
+    ///
+    /// Sets how the emission of `netuid` is split between its owner, its miners and its
+    /// validators, replacing the global SubnetOwnerCut and the even split of incentive and
+    /// dividends for this subnet.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'InvalidEmissionSplit':
+    ///     - The shares do not sum to 10_000 basis points.
+    ///
+    pub fn do_set_emission_split(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        split: EmissionSplit,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        let total = u32::from(split.owner_bps)
+            .saturating_add(u32::from(split.miners_bps))
+            .saturating_add(u32::from(split.validators_bps));
+        ensure!(
+            total == u32::from(EMISSION_SPLIT_BPS),
+            Error::<T>::InvalidEmissionSplit
+        );
+
+        SubnetEmissionSplit::<T>::insert(netuid, split);
+
+        log::info!("EmissionSplitSet( netuid:{:?}, split:{:?} )", netuid, split);
+        Self::deposit_event(Event::EmissionSplitSet(netuid, split));
+        Ok(())
+    }
+
+    /// The share of `emission` paid to the owner of `netuid`: its EmissionSplit owner share,
+    /// or the global SubnetOwnerCut until the owner sets a split.
+    pub fn get_subnet_owner_emission_cut(netuid: u16, emission: I96F32) -> I96F32 {
+        match SubnetEmissionSplit::<T>::get(netuid) {
+            Some(split) => emission
+                .saturating_mul(I96F32::from_num(split.owner_bps))
+                .saturating_div(I96F32::from_num(EMISSION_SPLIT_BPS)),
+            None => emission
+                .saturating_mul(I96F32::from_num(Self::get_subnet_owner_cut()))
+                .saturating_div(I96F32::from_num(u16::MAX)),
+        }
+    }
+
+    /// Rescales the server and validator emission of an epoch, each normalized to half of the
+    /// emission, to the miner and validator shares of `split`. The combined emission follows
+    /// them. Without a split the emission keeps the even split.
+    pub fn scale_to_emission_split(
+        split: Option<EmissionSplit>,
+        server_emission: &mut [I32F32],
+        validator_emission: &mut [I32F32],
+        combined_emission: &mut [I32F32],
+    ) {
+        let Some(split) = split else {
+            return;
+        };
+        let paid = split.miners_bps.saturating_add(split.validators_bps);
+        if paid == 0 {
+            return;
+        }
+        let paid = I32F32::from_num(paid);
+        let two = I32F32::from_num(2);
+        let server_factor = I32F32::from_num(split.miners_bps)
+            .saturating_mul(two)
+            .saturating_div(paid);
+        let validator_factor = I32F32::from_num(split.validators_bps)
+            .saturating_mul(two)
+            .saturating_div(paid);
+
+        for ((server, validator), combined) in server_emission
+            .iter_mut()
+            .zip(validator_emission.iter_mut())
+            .zip(combined_emission.iter_mut())
+        {
+            *server = server.saturating_mul(server_factor);
+            *validator = validator.saturating_mul(validator_factor);
+            *combined = server.saturating_add(*validator);
+        }
+    }
+
+    pub fn get_emission_split(netuid: u16) -> Option<EmissionSplit> {
+        SubnetEmissionSplit::<T>::get(netuid)
+    }
+}
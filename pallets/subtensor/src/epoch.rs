@@ -7,7 +7,7 @@ use substrate_fixed::types::{I32F32, I64F64, I96F32};
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub enum EpochInfo<T: Config> {
     Emission(Vec<(T::AccountId, u64, u64)>),
-    Incentive(Vec<I32F32>)
+    Incentive(Vec<I32F32>),
 }
 
 impl<T: Config> EpochInfo<T> {
@@ -16,7 +16,86 @@ impl<T: Config> EpochInfo<T> {
             EpochInfo::Emission(emission) => Some(emission.to_vec()),
             _ => None,
         }
-    
+    }
+}
+
+/// Epoch results in their storage representation, written back in a single pass by
+/// `commit_epoch_output`.
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct EpochOutput {
+    pub active: Vec<bool>,
+    pub emission: Vec<u64>,
+    pub rank: Vec<u16>,
+    pub trust: Vec<u16>,
+    pub consensus: Vec<u16>,
+    pub incentive: Vec<u16>,
+    pub dividends: Vec<u16>,
+    pub pruning_scores: Vec<u16>,
+    pub validator_trust: Vec<u16>,
+    pub validator_permit: Vec<bool>,
+    /// Bonds row per uid, `None` leaves the stored row untouched.
+    pub bonds: Vec<Option<Vec<(u16, u16)>>>,
+}
+
+/// Storage inputs of an epoch, loaded by `load_epoch_input` so that `compute_epoch` is pure.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EpochInput<AccountId> {
+    pub n: u16,
+    pub current_block: u64,
+    pub activity_cutoff: u64,
+    pub last_update: Vec<u64>,
+    pub block_at_registration: Vec<u64>,
+    pub hotkeys: Vec<(u16, AccountId)>,
+    /// Total stake per uid.
+    pub stake: Vec<u64>,
+    pub validator_permits: Vec<bool>,
+    pub max_allowed_validators: u16,
+    pub weights: Vec<Vec<(u16, I32F32)>>,
+    /// Whether the weight rows are stored sum-normalized to u16::MAX, in which case the
+    /// weights masked out of a row are not redistributed over the rest of it.
+    pub sum_normalized_weights: bool,
+    /// Whether the weights of each uid were set for an epoch other than this one, on subnets
+    /// with TARGET_EPOCH_WEIGHTS.
+    pub stale_weights: Vec<bool>,
+    /// Whether the subnet counts the weight a validator sets on itself, UNMASKED_SELF_WEIGHTS.
+    pub unmasked_self_weights: bool,
+    pub kappa: I32F32,
+    /// Largest share of the active stake one validator weighs in consensus with.
+    pub max_stake_weight_fraction: I32F32,
+    pub bonds: Vec<Vec<(u16, I32F32)>>,
+    pub ema_bonds_params: EmaBondsParams,
+    /// Whether validators losing their permit are cut from this epoch's dividends.
+    pub reset_bonds_on_permit_loss: bool,
+    pub rao_emission: u64,
+    /// The owner-set emission split, `None` splits incentive and dividends evenly.
+    pub emission_split: Option<EmissionSplit>,
+}
+
+/// Subnet hyperparameters used to compute the EMA of bonds.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EmaBondsParams {
+    pub liquid_alpha_enabled: bool,
+    pub alpha_low: I32F32,
+    pub alpha_high: I32F32,
+    pub bonds_moving_average: u64,
+}
+
+impl EpochOutput {
+    /// Creates an output with every vector pre-sized for `n` uids.
+    pub fn with_capacity(n: usize) -> Self {
+        Self {
+            active: Vec::with_capacity(n),
+            emission: Vec::with_capacity(n),
+            rank: Vec::with_capacity(n),
+            trust: Vec::with_capacity(n),
+            consensus: Vec::with_capacity(n),
+            incentive: Vec::with_capacity(n),
+            dividends: Vec::with_capacity(n),
+            pruning_scores: Vec::with_capacity(n),
+            validator_trust: Vec::with_capacity(n),
+            validator_permit: Vec::with_capacity(n),
+            bonds: Vec::with_capacity(n),
+        }
     }
 }
 
@@ -75,15 +154,14 @@ impl<T: Config> Pallet<T> {
         // == Stake ==
         // ===========
 
-        let hotkeys: Vec<(u16, T::AccountId)> =
-            <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(netuid)
-                .collect();
+        let hotkeys: Vec<(u16, T::AccountId)> = Self::get_epoch_hotkeys(netuid, n);
         log::trace!("hotkeys: {:?}", &hotkeys);
 
         // Access network stake as normalized vector.
         let mut stake_64: Vec<I64F64> = vec![I64F64::from_num(0.0); n as usize];
         for (uid_i, hotkey) in &hotkeys {
-            stake_64[*uid_i as usize] = I64F64::from_num(Self::get_total_stake_for_hotkey(hotkey));
+            stake_64[*uid_i as usize] =
+                I64F64::from_num(Self::get_stake_for_hotkey_on_subnet(hotkey, netuid));
         }
         inplace_normalize_64(&mut stake_64);
         let stake: Vec<I32F32> = vec_fixed64_to_fixed32(stake_64);
@@ -104,8 +182,19 @@ impl<T: Config> Pallet<T> {
         let max_allowed_validators: u16 = Self::get_max_allowed_validators(netuid);
         log::trace!("max_allowed_validators: {:?}", max_allowed_validators);
 
-        // Get new validator permits.
-        let new_validator_permits: Vec<bool> = is_topk(&stake, max_allowed_validators as usize);
+        // Get new validator permits, preferring older registrations when stake is tied.
+        if block_at_registration.len() != stake.len() {
+            log::error!(
+                "Block at registration has {:?} entries for {:?} uids, stake ties go to the lower uid",
+                block_at_registration.len(),
+                stake.len()
+            );
+        }
+        let new_validator_permits: Vec<bool> = is_topk_with_tiebreak(
+            &stake,
+            &block_at_registration,
+            max_allowed_validators as usize,
+        );
         log::trace!("new_validator_permits: {:?}", new_validator_permits);
 
         // ==================
@@ -122,6 +211,12 @@ impl<T: Config> Pallet<T> {
 
         // Normalize active stake.
         inplace_normalize(&mut active_stake);
+
+        // Clip the share of any single validator, spreading the excess over the others.
+        inplace_cap_normalized(
+            &mut active_stake,
+            Self::get_float_max_stake_weight_fraction(netuid),
+        );
         log::trace!("S:\n{:?}\n", &active_stake);
 
         // =============
@@ -136,16 +231,27 @@ impl<T: Config> Pallet<T> {
         inplace_mask_rows(&validator_forbids, &mut weights);
         log::trace!("W (permit): {:?}", &weights);
 
-        // Remove self-weight by masking diagonal.
-        inplace_mask_diag(&mut weights);
+        // Mask weights set for another epoch, they do not count towards this one.
+        inplace_mask_rows(&Self::get_stale_weights(netuid), &mut weights);
+        log::trace!("W (permit+epoch): {:?}", &weights);
+
+        // Remove self-weight by masking diagonal, unless the subnet counts self-weights.
+        if !Self::get_unmasked_self_weights(netuid) {
+            inplace_mask_diag(&mut weights);
+        }
         log::trace!("W (permit+diag):\n{:?}\n", &weights);
 
         // Mask outdated weights: remove weights referring to deregistered neurons.
         inplace_mask_matrix(&outdated, &mut weights);
         log::trace!("W (permit+diag+outdate):\n{:?}\n", &weights);
 
-        // Normalize remaining weights.
-        inplace_row_normalize(&mut weights);
+        // Normalize remaining weights. Sum-normalized rows are divided by u16::MAX, so a
+        // validator whose weights were partly masked keeps only the remaining share.
+        if Self::weights_sum_normalized(netuid) {
+            inplace_row_normalize_with_floor(&mut weights, I32F32::from_num(u16::MAX));
+        } else {
+            inplace_row_normalize(&mut weights);
+        }
         log::trace!("W (mask+norm):\n{:?}\n", &weights);
 
         // ================================
@@ -194,6 +300,15 @@ impl<T: Config> Pallet<T> {
         inplace_col_normalize(&mut ema_bonds); // sum_i b_ij = 1
         log::trace!("emaB:\n{:?}\n", &ema_bonds);
 
+        // Cut validators losing their permit from the dividends, their bonds are cleared below.
+        if Self::get_reset_bonds_on_permit_loss(netuid) {
+            let new_validator_forbids: Vec<bool> =
+                new_validator_permits.iter().map(|&b| !b).collect();
+            inplace_mask_rows(&new_validator_forbids, &mut ema_bonds);
+            inplace_col_normalize(&mut ema_bonds); // sum_i b_ij = 1
+            log::trace!("emaB (permit):\n{:?}\n", &ema_bonds);
+        }
+
         // Compute dividends: d_i = SUM(j) b_ij * inc_j
         let mut dividends: Vec<I32F32> = matmul_transpose(&ema_bonds, &incentive);
         inplace_normalize(&mut dividends);
@@ -221,6 +336,12 @@ impl<T: Config> Pallet<T> {
         inplace_normalize_using_sum(&mut normalized_server_emission, emission_sum);
         inplace_normalize_using_sum(&mut normalized_validator_emission, emission_sum);
         inplace_normalize(&mut normalized_combined_emission);
+        Self::scale_to_emission_split(
+            Self::get_emission_split(netuid),
+            &mut normalized_server_emission,
+            &mut normalized_validator_emission,
+            &mut normalized_combined_emission,
+        );
 
         // If emission is zero, replace emission with normalized stake.
         if emission_sum == I32F32::from(0) {
@@ -280,63 +401,41 @@ impl<T: Config> Pallet<T> {
         // ===================
         // == Value storage ==
         // ===================
-        let cloned_emission: Vec<u64> = combined_emission.clone();
-        let cloned_ranks: Vec<u16> = ranks
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_trust: Vec<u16> = trust
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_consensus: Vec<u16> = consensus
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_incentive: Vec<u16> = incentive
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_dividends: Vec<u16> = dividends
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_pruning_scores: Vec<u16> = vec_max_upscale_to_u16(&pruning_scores);
-        let cloned_validator_trust: Vec<u16> = validator_trust
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        Active::<T>::insert(netuid, active.clone());
-        Emission::<T>::insert(netuid, cloned_emission);
-        Rank::<T>::insert(netuid, cloned_ranks);
-        Trust::<T>::insert(netuid, cloned_trust);
-        Consensus::<T>::insert(netuid, cloned_consensus);
-        Incentive::<T>::insert(netuid, cloned_incentive);
-        Dividends::<T>::insert(netuid, cloned_dividends);
-        PruningScores::<T>::insert(netuid, cloned_pruning_scores);
-        ValidatorTrust::<T>::insert(netuid, cloned_validator_trust);
-        ValidatorPermit::<T>::insert(netuid, new_validator_permits.clone());
+        let mut output = EpochOutput::with_capacity(n as usize);
+        for i in 0..n as usize {
+            output.rank.push(fixed_proportion_to_u16(ranks[i]));
+            output.trust.push(fixed_proportion_to_u16(trust[i]));
+            output.consensus.push(fixed_proportion_to_u16(consensus[i]));
+            output.incentive.push(fixed_proportion_to_u16(incentive[i]));
+            output.dividends.push(fixed_proportion_to_u16(dividends[i]));
+            output
+                .validator_trust
+                .push(fixed_proportion_to_u16(validator_trust[i]));
+        }
+        output.active = active;
+        output.emission = combined_emission;
+        output.pruning_scores = vec_max_upscale_to_u16(&pruning_scores);
 
         // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
         inplace_col_max_upscale(&mut ema_bonds);
-        new_validator_permits
+        output.bonds = new_validator_permits
             .iter()
             .zip(validator_permits)
             .zip(ema_bonds)
-            .enumerate()
-            .for_each(|(i, ((new_permit, validator_permit), ema_bond))| {
+            .map(|((new_permit, validator_permit), ema_bond)| {
                 // Set bonds only if uid retains validator permit, otherwise clear bonds.
                 if *new_permit {
-                    let new_bonds_row: Vec<(u16, u16)> = (0..n)
-                        .zip(vec_fixed_proportions_to_u16(ema_bond.clone()))
-                        .collect();
-                    Bonds::<T>::insert(netuid, i as u16, new_bonds_row);
+                    Some((0..n).zip(vec_fixed_proportions_to_u16(ema_bond)).collect())
                 } else if validator_permit {
                     // Only overwrite the intersection.
-                    let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
-                    Bonds::<T>::insert(netuid, i as u16, new_empty_bonds_row);
+                    Some(vec![])
+                } else {
+                    None
                 }
-            });
+            })
+            .collect();
+        output.validator_permit = new_validator_permits;
+        Self::commit_epoch_output(netuid, output);
 
         hotkeys
             .into_iter()
@@ -357,32 +456,122 @@ impl<T: Config> Pallet<T> {
     ///  * 'netuid': ( u16 ):
     ///     - The network to distribute the emission onto.
     ///
-    ///  * 'rao_emission': ( u64 ):
-    ///     - The total emission for the epoch.
-    ///
-    ///  * 'debug' ( bool ):
-    ///     - Print debugging outputs.
+    ///  * 'maybe_return_incentives' ( Option<bool> ):
+    ///     - Return the incentives instead, without writing to storage.
     ///
-    #[allow(clippy::indexing_slicing)]
     pub fn epoch(netuid: u16, maybe_return_incentives: Option<bool>) -> EpochInfo<T> {
-        // Get subnetwork size.
+        let input = Self::load_epoch_input(netuid);
+        let (info, output) = Self::compute_epoch(input, maybe_return_incentives);
+        if let Some(output) = output {
+            Self::commit_epoch_output(netuid, output);
+        }
+        info
+    }
+
+    /// Computes the epochs of several subnets. With the `parallel-epochs` feature the epochs run
+    /// on a thread pool, results are returned in the order of `inputs` either way.
+    pub fn compute_epochs(
+        inputs: Vec<EpochInput<T::AccountId>>,
+    ) -> Vec<(EpochInfo<T>, Option<EpochOutput>)> {
+        #[cfg(feature = "parallel-epochs")]
+        {
+            use rayon::prelude::*;
+            inputs
+                .into_par_iter()
+                .map(|input| Self::compute_epoch(input, None))
+                .collect()
+        }
+        #[cfg(not(feature = "parallel-epochs"))]
+        {
+            inputs
+                .into_iter()
+                .map(|input| Self::compute_epoch(input, None))
+                .collect()
+        }
+    }
+
+    /// Returns the (uid, hotkey) pairs of `netuid` the epoch runs over, ignoring keys left at
+    /// uids past the `n` uids of the subnet.
+    pub fn get_epoch_hotkeys(netuid: u16, n: u16) -> Vec<(u16, T::AccountId)> {
+        Self::get_uid_hotkey_pairs(netuid)
+            .into_iter()
+            .filter(|(uid_i, _)| *uid_i < n)
+            .collect()
+    }
+
+    /// Reads everything the epoch of `netuid` depends on from storage.
+    pub fn load_epoch_input(netuid: u16) -> EpochInput<T::AccountId> {
         let n: u16 = Self::get_subnetwork_n(netuid);
+        let hotkeys: Vec<(u16, T::AccountId)> = Self::get_epoch_hotkeys(netuid, n);
+        let mut stake: Vec<u64> = vec![0; n as usize];
+        for (uid_i, hotkey) in &hotkeys {
+            if let Some(stake_i) = stake.get_mut(*uid_i as usize) {
+                *stake_i = Self::get_stake_for_hotkey_on_subnet(hotkey, netuid);
+            }
+        }
+
+        EpochInput {
+            n,
+            current_block: Self::get_current_block_as_u64(),
+            activity_cutoff: Self::get_activity_cutoff(netuid) as u64,
+            last_update: Self::get_last_update(netuid),
+            block_at_registration: Self::get_block_at_registration(netuid),
+            hotkeys,
+            stake,
+            validator_permits: Self::get_validator_permit(netuid),
+            max_allowed_validators: Self::get_max_allowed_validators(netuid),
+            weights: Self::get_weights_sparse(netuid),
+            sum_normalized_weights: Self::weights_sum_normalized(netuid),
+            stale_weights: Self::get_stale_weights(netuid),
+            unmasked_self_weights: Self::get_unmasked_self_weights(netuid),
+            kappa: Self::get_float_kappa(netuid),
+            max_stake_weight_fraction: Self::get_float_max_stake_weight_fraction(netuid),
+            bonds: Self::get_bonds_sparse(netuid),
+            ema_bonds_params: Self::get_ema_bonds_params(netuid),
+            reset_bonds_on_permit_loss: Self::get_reset_bonds_on_permit_loss(netuid),
+            rao_emission: PendingEmission::<T>::get(netuid),
+            emission_split: Self::get_emission_split(netuid),
+        }
+    }
+
+    /// Runs the epoch math over `input` without touching storage, so that the epochs of
+    /// different subnets can be computed independently. Returns the epoch info along with the
+    /// output to commit, which is `None` when only the incentives were asked for.
+    #[allow(clippy::indexing_slicing)]
+    pub fn compute_epoch(
+        input: EpochInput<T::AccountId>,
+        maybe_return_incentives: Option<bool>,
+    ) -> (EpochInfo<T>, Option<EpochOutput>) {
+        let EpochInput {
+            n,
+            current_block,
+            activity_cutoff,
+            last_update,
+            block_at_registration,
+            hotkeys,
+            stake: raw_stake,
+            validator_permits,
+            max_allowed_validators,
+            mut weights,
+            sum_normalized_weights,
+            stale_weights,
+            unmasked_self_weights,
+            kappa,
+            max_stake_weight_fraction,
+            mut bonds,
+            ema_bonds_params,
+            reset_bonds_on_permit_loss,
+            rao_emission,
+            emission_split,
+        } = input;
         log::trace!("Number of Neurons in Network: {:?}", n);
 
         // ======================
         // == Active & updated ==
         // ======================
 
-        // Get current block.
-        let current_block: u64 = Self::get_current_block_as_u64();
         log::trace!("current_block: {:?}", current_block);
-
-        // Get activity cutoff.
-        let activity_cutoff: u64 = Self::get_activity_cutoff(netuid) as u64;
         log::trace!("activity_cutoff: {:?}", activity_cutoff);
-
-        // Last update vector.
-        let last_update: Vec<u64> = Self::get_last_update(netuid);
         log::trace!("Last update: {:?}", &last_update);
 
         // Inactive mask.
@@ -396,23 +585,16 @@ impl<T: Config> Pallet<T> {
         let active: Vec<bool> = inactive.iter().map(|&b| !b).collect();
 
         // Block at registration vector (block when each neuron was most recently registered).
-        let block_at_registration: Vec<u64> = Self::get_block_at_registration(netuid);
         log::trace!("Block at registration: {:?}", &block_at_registration);
 
         // ===========
         // == Stake ==
         // ===========
 
-        let hotkeys: Vec<(u16, T::AccountId)> =
-            <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(netuid)
-                .collect();
         log::trace!("hotkeys: {:?}", &hotkeys);
 
         // Access network stake as normalized vector.
-        let mut stake_64: Vec<I64F64> = vec![I64F64::from_num(0.0); n as usize];
-        for (uid_i, hotkey) in &hotkeys {
-            stake_64[*uid_i as usize] = I64F64::from_num(Self::get_total_stake_for_hotkey(hotkey));
-        }
+        let mut stake_64: Vec<I64F64> = raw_stake.into_iter().map(I64F64::from_num).collect();
         log::trace!("Stake : {:?}", &stake_64);
         inplace_normalize_64(&mut stake_64);
         let stake: Vec<I32F32> = vec_fixed64_to_fixed32(stake_64);
@@ -423,19 +605,28 @@ impl<T: Config> Pallet<T> {
         // == Validator permits ==
         // =======================
 
-        // Get current validator permits.
-        let validator_permits: Vec<bool> = Self::get_validator_permit(netuid);
+        // Current validator permits.
         log::trace!("validator_permits: {:?}", validator_permits);
 
         // Logical negation of validator_permits.
         let validator_forbids: Vec<bool> = validator_permits.iter().map(|&b| !b).collect();
 
-        // Get max allowed validators.
-        let max_allowed_validators: u16 = Self::get_max_allowed_validators(netuid);
+        // Max allowed validators.
         log::trace!("max_allowed_validators: {:?}", max_allowed_validators);
 
-        // Get new validator permits.
-        let new_validator_permits: Vec<bool> = is_topk(&stake, max_allowed_validators as usize);
+        // Get new validator permits, preferring older registrations when stake is tied.
+        if block_at_registration.len() != stake.len() {
+            log::error!(
+                "Block at registration has {:?} entries for {:?} uids, stake ties go to the lower uid",
+                block_at_registration.len(),
+                stake.len()
+            );
+        }
+        let new_validator_permits: Vec<bool> = is_topk_with_tiebreak(
+            &stake,
+            &block_at_registration,
+            max_allowed_validators as usize,
+        );
         log::trace!("new_validator_permits: {:?}", new_validator_permits);
 
         // ==================
@@ -452,22 +643,30 @@ impl<T: Config> Pallet<T> {
 
         // Normalize active stake.
         inplace_normalize(&mut active_stake);
+
+        // Clip the share of any single validator, spreading the excess over the others.
+        inplace_cap_normalized(&mut active_stake, max_stake_weight_fraction);
         log::trace!("Active Stake:\n{:?}\n", &active_stake);
 
         // =============
         // == Weights ==
         // =============
 
-        // Access network weights row unnormalized.
-        let mut weights: Vec<Vec<(u16, I32F32)>> = Self::get_weights_sparse(netuid);
+        // Network weights row unnormalized.
         log::trace!("Weights: {:?}", &weights);
 
         // Mask weights that are not from permitted validators.
         weights = mask_rows_sparse(&validator_forbids, &weights);
         log::trace!("Weights (permit): {:?}", &weights);
 
-        // Remove self-weight by masking diagonal.
-        weights = mask_diag_sparse(&weights);
+        // Mask weights set for another epoch, they do not count towards this one.
+        weights = mask_rows_sparse(&stale_weights, &weights);
+        log::trace!("Weights (permit+epoch): {:?}", &weights);
+
+        // Remove self-weight by masking diagonal, unless the subnet counts self-weights.
+        if !unmasked_self_weights {
+            weights = mask_diag_sparse(&weights);
+        }
         log::trace!("Weights (permit+diag): {:?}", &weights);
 
         // Remove weights referring to deregistered neurons.
@@ -479,8 +678,13 @@ impl<T: Config> Pallet<T> {
         );
         log::trace!("Weights (permit+diag+outdate): {:?}", &weights);
 
-        // Normalize remaining weights.
-        inplace_row_normalize_sparse(&mut weights);
+        // Normalize remaining weights. Sum-normalized rows are divided by u16::MAX, so a
+        // validator whose weights were partly masked keeps only the remaining share.
+        if sum_normalized_weights {
+            inplace_row_normalize_sparse_with_floor(&mut weights, I32F32::from_num(u16::MAX));
+        } else {
+            inplace_row_normalize_sparse(&mut weights);
+        }
         log::trace!("Weights (mask+norm): {:?}", &weights);
 
         // ================================
@@ -492,7 +696,7 @@ impl<T: Config> Pallet<T> {
         log::trace!("Ranks (before): {:?}", &preranks);
 
         // Clip weights at majority consensus
-        let kappa: I32F32 = Self::get_float_kappa(netuid); // consensus majority ratio, e.g. 51%.
+        // kappa is the consensus majority ratio, e.g. 51%.
         let consensus: Vec<I32F32> = weighted_median_col_sparse(&active_stake, &weights, n, kappa);
         log::trace!("Consensus: {:?}", &consensus);
 
@@ -518,15 +722,14 @@ impl<T: Config> Pallet<T> {
         let incentive: Vec<I32F32> = ranks.clone();
         log::trace!("Incentive (=Rank): {:?}", &incentive);
         if maybe_return_incentives.unwrap_or(false) {
-            return EpochInfo::Incentive(incentive);
+            return (EpochInfo::Incentive(incentive), None);
         }
 
         // =========================
         // == Bonds and Dividends ==
         // =========================
 
-        // Access network bonds.
-        let mut bonds: Vec<Vec<(u16, I32F32)>> = Self::get_bonds_sparse(netuid);
+        // Network bonds.
         log::trace!("B: {:?}", &bonds);
 
         // Remove bonds referring to deregistered neurons.
@@ -551,12 +754,28 @@ impl<T: Config> Pallet<T> {
         log::trace!("ΔB (norm): {:?}", &bonds_delta);
 
         // Compute the Exponential Moving Average (EMA) of bonds.
-        let mut ema_bonds =
-            Self::compute_ema_bonds_sparse(netuid, consensus.clone(), bonds_delta, bonds);
+        let mut ema_bonds = Self::compute_ema_bonds_sparse(
+            &ema_bonds_params,
+            consensus.clone(),
+            bonds_delta,
+            bonds,
+        );
         // Normalize EMA bonds.
         inplace_col_normalize_sparse(&mut ema_bonds, n); // sum_i b_ij = 1
         log::trace!("Exponential Moving Average Bonds: {:?}", &ema_bonds);
 
+        // Cut validators losing their permit from the dividends, their bonds are cleared below.
+        if reset_bonds_on_permit_loss {
+            let new_validator_forbids: Vec<bool> =
+                new_validator_permits.iter().map(|&b| !b).collect();
+            ema_bonds = mask_rows_sparse(&new_validator_forbids, &ema_bonds);
+            inplace_col_normalize_sparse(&mut ema_bonds, n); // sum_i b_ij = 1
+            log::trace!(
+                "Exponential Moving Average Bonds (permit): {:?}",
+                &ema_bonds
+            );
+        }
+
         // Compute dividends: d_i = SUM(j) b_ij * inc_j.
         // range: I32F32(0, 1)
         let mut dividends: Vec<I32F32> = matmul_transpose_sparse(&ema_bonds, &incentive);
@@ -582,6 +801,12 @@ impl<T: Config> Pallet<T> {
         inplace_normalize_using_sum(&mut normalized_server_emission, emission_sum);
         inplace_normalize_using_sum(&mut normalized_validator_emission, emission_sum);
         inplace_normalize(&mut normalized_combined_emission);
+        Self::scale_to_emission_split(
+            emission_split,
+            &mut normalized_server_emission,
+            &mut normalized_validator_emission,
+            &mut normalized_combined_emission,
+        );
 
         // If emission is zero, replace emission with normalized stake.
         if emission_sum == I32F32::from(0) {
@@ -597,7 +822,6 @@ impl<T: Config> Pallet<T> {
         }
 
         // Compute rao based emission scores. range: I96F32(0, rao_emission)
-        let rao_emission = PendingEmission::<T>::get(netuid);
         let float_rao_emission: I96F32 = I96F32::from_num(rao_emission);
 
         let server_emission: Vec<I96F32> = normalized_server_emission
@@ -651,67 +875,48 @@ impl<T: Config> Pallet<T> {
         // ===================
         // == Value storage ==
         // ===================
-        let cloned_emission: Vec<u64> = combined_emission.clone();
-        let cloned_ranks: Vec<u16> = ranks
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_trust: Vec<u16> = trust
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_consensus: Vec<u16> = consensus
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_incentive: Vec<u16> = incentive
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_dividends: Vec<u16> = dividends
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_pruning_scores: Vec<u16> = vec_max_upscale_to_u16(&pruning_scores);
-        let cloned_validator_trust: Vec<u16> = validator_trust
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        Active::<T>::insert(netuid, active.clone());
-        Emission::<T>::insert(netuid, cloned_emission);
-        Rank::<T>::insert(netuid, cloned_ranks);
-        Trust::<T>::insert(netuid, cloned_trust);
-        Consensus::<T>::insert(netuid, cloned_consensus);
-        Incentive::<T>::insert(netuid, cloned_incentive);
-        Dividends::<T>::insert(netuid, cloned_dividends);
-        PruningScores::<T>::insert(netuid, cloned_pruning_scores);
-        ValidatorTrust::<T>::insert(netuid, cloned_validator_trust);
-        ValidatorPermit::<T>::insert(netuid, new_validator_permits.clone());
+        let mut output = EpochOutput::with_capacity(n as usize);
+        for i in 0..n as usize {
+            output.rank.push(fixed_proportion_to_u16(ranks[i]));
+            output.trust.push(fixed_proportion_to_u16(trust[i]));
+            output.consensus.push(fixed_proportion_to_u16(consensus[i]));
+            output.incentive.push(fixed_proportion_to_u16(incentive[i]));
+            output.dividends.push(fixed_proportion_to_u16(dividends[i]));
+            output
+                .validator_trust
+                .push(fixed_proportion_to_u16(validator_trust[i]));
+        }
+        output.active = active;
+        output.emission = combined_emission;
+        output.pruning_scores = vec_max_upscale_to_u16(&pruning_scores);
 
         // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
         inplace_col_max_upscale_sparse(&mut ema_bonds, n);
-        new_validator_permits
+        output.bonds = new_validator_permits
             .iter()
             .zip(validator_permits)
             .zip(ema_bonds)
-            .enumerate()
-            .for_each(|(i, ((new_permit, validator_permit), ema_bond))| {
+            .map(|((new_permit, validator_permit), ema_bond)| {
                 // Set bonds only if uid retains validator permit, otherwise clear bonds.
                 if *new_permit {
-                    let new_bonds_row: Vec<(u16, u16)> = ema_bond
-                        .iter()
-                        .map(|(j, value)| (*j, fixed_proportion_to_u16(*value)))
-                        .collect();
-                    Bonds::<T>::insert(netuid, i as u16, new_bonds_row);
+                    Some(
+                        ema_bond
+                            .iter()
+                            .map(|(j, value)| (*j, fixed_proportion_to_u16(*value)))
+                            .collect(),
+                    )
                 } else if validator_permit {
                     // Only overwrite the intersection.
-                    let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
-                    Bonds::<T>::insert(netuid, i as u16, new_empty_bonds_row);
+                    Some(vec![])
+                } else {
+                    None
                 }
-            });
+            })
+            .collect();
+        output.validator_permit = new_validator_permits;
 
         // Emission tuples ( hotkeys, server_emission, validator_emission )
-        EpochInfo::Emission(hotkeys
+        let emission_tuples = hotkeys
             .into_iter()
             .map(|(uid_i, hotkey)| {
                 (
@@ -720,7 +925,55 @@ impl<T: Config> Pallet<T> {
                     validator_emission[uid_i as usize],
                 )
             })
-            .collect())
+            .collect();
+        (EpochInfo::Emission(emission_tuples), Some(output))
+    }
+
+    /// Writes the epoch output for `netuid` back to storage in a single pass.
+    ///
+    /// Every vector is written blind, one write per item and one per recomputed bonds row,
+    /// without reading the previous value back first.
+    pub fn commit_epoch_output(netuid: u16, output: EpochOutput) {
+        let EpochOutput {
+            active,
+            emission,
+            rank,
+            trust,
+            consensus,
+            incentive,
+            dividends,
+            pruning_scores,
+            validator_trust,
+            validator_permit,
+            bonds,
+        } = output;
+
+        Active::<T>::insert(netuid, active);
+        Emission::<T>::insert(netuid, emission);
+        Rank::<T>::insert(netuid, rank);
+        Trust::<T>::insert(netuid, trust);
+        Consensus::<T>::insert(netuid, consensus);
+        Incentive::<T>::insert(netuid, incentive);
+        Dividends::<T>::insert(netuid, dividends);
+        PruningScores::<T>::insert(netuid, pruning_scores);
+        ValidatorTrust::<T>::insert(netuid, validator_trust);
+        ValidatorPermit::<T>::insert(netuid, validator_permit);
+
+        for (uid_i, row) in bonds.into_iter().enumerate() {
+            if let Some(row) = row {
+                Bonds::<T>::insert(netuid, uid_i as u16, row);
+            }
+        }
+    }
+
+    pub fn get_ema_bonds_params(netuid: u16) -> EmaBondsParams {
+        let (alpha_low, alpha_high) = Self::get_alpha_values_32(netuid);
+        EmaBondsParams {
+            liquid_alpha_enabled: Self::get_liquid_alpha_enabled(netuid),
+            alpha_low,
+            alpha_high,
+            bonds_moving_average: Self::get_bonds_moving_average(netuid),
+        }
     }
 
     pub fn get_float_rho(netuid: u16) -> I32F32 {
@@ -729,6 +982,10 @@ impl<T: Config> Pallet<T> {
     pub fn get_float_kappa(netuid: u16) -> I32F32 {
         I32F32::from_num(Self::get_kappa(netuid)).saturating_div(I32F32::from_num(u16::MAX))
     }
+    pub fn get_float_max_stake_weight_fraction(netuid: u16) -> I32F32 {
+        I32F32::from_num(Self::get_max_stake_weight_fraction(netuid))
+            .saturating_div(I32F32::from_num(u16::MAX))
+    }
 
     pub fn get_normalized_stake(netuid: u16) -> Vec<I32F32> {
         let n = Self::get_subnetwork_n(netuid);
@@ -761,10 +1018,14 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut weights: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
         for (uid_i, weights_i) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
-            for (uid_j, weight_ij) in weights_i.iter().filter(|(uid_j, _)| *uid_j < n as u16) {
+            for (uid_j, weight_ij) in weights_i
+                .entries()
+                .iter()
+                .filter(|(uid_j, _)| *uid_j < n as u16)
+            {
                 weights
                     .get_mut(uid_i as usize)
                     .expect("uid_i is filtered to be less than n; qed")
@@ -779,10 +1040,11 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut weights: Vec<Vec<I32F32>> = vec![vec![I32F32::from_num(0.0); n]; n];
         for (uid_i, weights_vec) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
             for (uid_j, weight_ij) in weights_vec
+                .entries()
                 .into_iter()
                 .filter(|(uid_j, _)| *uid_j < n as u16)
             {
@@ -1010,18 +1272,18 @@ impl<T: Config> Pallet<T> {
     /// # Args:
     /// * `bonds_delta` - A vector of bond deltas.
     /// * `bonds` - A vector of bonds.
-    /// * `netuid` - The network ID.
+    /// * `bonds_moving_average` - The bonds moving average of the network.
     ///
     /// # Returns:
     /// A vector of EMA bonds.
     pub fn compute_ema_bonds_normal_sparse(
         bonds_delta: &[Vec<(u16, I32F32)>],
         bonds: &[Vec<(u16, I32F32)>],
-        netuid: u16,
+        bonds_moving_average: u64,
     ) -> Vec<Vec<(u16, I32F32)>> {
-        // Retrieve the bonds moving average for the given network ID and scale it down.
-        let bonds_moving_average: I64F64 = I64F64::from_num(Self::get_bonds_moving_average(netuid))
-            .saturating_div(I64F64::from_num(1_000_000));
+        // Scale the bonds moving average down.
+        let bonds_moving_average: I64F64 =
+            I64F64::from_num(bonds_moving_average).saturating_div(I64F64::from_num(1_000_000));
 
         // Calculate the alpha value for the EMA calculation.
         // Alpha is derived by subtracting the scaled bonds moving average from 1.
@@ -1074,7 +1336,7 @@ impl<T: Config> Pallet<T> {
     /// Compute the Exponential Moving Average (EMA) of bonds based on the Liquid Alpha setting for a sparse matrix.
     ///
     /// # Args:
-    /// * `netuid` - The network ID.
+    /// * `params` - The EMA hyperparameters of the network.
     /// * `consensus` - A vector of consensus values.
     /// * `bonds_delta` - A vector of bond deltas.
     /// * `bonds` - A vector of bonds.
@@ -1082,14 +1344,14 @@ impl<T: Config> Pallet<T> {
     /// # Returns:
     /// A vector of EMA bonds.
     pub fn compute_ema_bonds_sparse(
-        netuid: u16,
+        params: &EmaBondsParams,
         consensus: Vec<I32F32>,
         bonds_delta: Vec<Vec<(u16, I32F32)>>,
         bonds: Vec<Vec<(u16, I32F32)>>,
     ) -> Vec<Vec<(u16, I32F32)>> {
         // Check if Liquid Alpha is enabled, consensus is not empty, and contains non-zero values.
         // This way we avoid the quantil function panic.
-        if LiquidAlphaOn::<T>::get(netuid)
+        if params.liquid_alpha_enabled
             && !consensus.is_empty()
             && consensus.iter().any(|&c| c != I32F32::from_num(0))
         {
@@ -1102,8 +1364,9 @@ impl<T: Config> Pallet<T> {
                 // if (consensus_high > consensus_low) || consensus_low != 0 {
                 log::trace!("Using Liquid Alpha");
 
-                // Get the high and low alpha values for the network.
-                let (alpha_low, alpha_high): (I32F32, I32F32) = Self::get_alpha_values_32(netuid);
+                // The high and low alpha values for the network.
+                let (alpha_low, alpha_high): (I32F32, I32F32) =
+                    (params.alpha_low, params.alpha_high);
                 log::trace!("alpha_low: {:?} alpha_high: {:?}", alpha_low, alpha_high);
 
                 // Calculate the logistic function parameters 'a' and 'b' based on alpha and consensus values.
@@ -1130,13 +1393,17 @@ impl<T: Config> Pallet<T> {
                 log::trace!("Using Bonds Moving Average");
 
                 // Compute the EMA of bonds using a normal alpha value.
-                Self::compute_ema_bonds_normal_sparse(&bonds_delta, &bonds, netuid)
+                Self::compute_ema_bonds_normal_sparse(
+                    &bonds_delta,
+                    &bonds,
+                    params.bonds_moving_average,
+                )
             }
         } else {
             log::trace!("Using Bonds Moving Average");
 
             // Compute the EMA of bonds using a normal alpha value.
-            Self::compute_ema_bonds_normal_sparse(&bonds_delta, &bonds, netuid)
+            Self::compute_ema_bonds_normal_sparse(&bonds_delta, &bonds, params.bonds_moving_average)
         }
     }
 
@@ -1157,7 +1424,7 @@ impl<T: Config> Pallet<T> {
         bonds: Vec<Vec<I32F32>>,
     ) -> Vec<Vec<I32F32>> {
         // Check if Liquid Alpha is enabled, consensus is not empty, and contains non-zero values.
-        if LiquidAlphaOn::<T>::get(netuid)
+        if Self::get_liquid_alpha_enabled(netuid)
             && !consensus.is_empty()
             && consensus.iter().any(|&c| c != I32F32::from_num(0))
         {
@@ -1241,6 +1508,7 @@ impl<T: Config> Pallet<T> {
             alpha_low,
             alpha_high,
         );
+        Self::deposit_event(Event::AlphaValuesSet(netuid, alpha_low, alpha_high));
         Ok(())
     }
 }
@@ -0,0 +1,143 @@
+use super::*;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic signal_epoch_pause.
+    ///
+    /// Records whether the calling validator wants the epochs of `netuid` paused. Signals are
+    /// weighed by stake in `apply_epoch_pause_signals`, only while the hotkey holds a permit.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey has no uid on the subnet.
+    ///
+    /// * 'NeuronNoValidatorPermit':
+    ///     - The hotkey's uid has no validator permit.
+    ///
+    pub fn do_signal_epoch_pause(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        pause: bool,
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)
+            .map_err(|_| Error::<T>::HotKeyNotRegisteredInSubNet)?;
+        ensure!(
+            Self::get_validator_permit_for_uid(netuid, uid),
+            Error::<T>::NeuronNoValidatorPermit
+        );
+
+        if pause {
+            EpochPauseSignals::<T>::insert(netuid, &hotkey, true);
+        } else {
+            EpochPauseSignals::<T>::remove(netuid, &hotkey);
+        }
+
+        log::info!(
+            "EpochPauseSignalled( netuid:{:?}, hotkey:{:?}, pause:{:?} )",
+            netuid,
+            hotkey,
+            pause
+        );
+        Self::deposit_subnet_event(&[netuid], Event::EpochPauseSignalled(netuid, hotkey, pause));
+        Ok(())
+    }
+
+    /// Pauses the epochs of every subnet whose signalling validators hold at least
+    /// EpochPauseThreshold of the stake of its permitted validators, for EpochPauseDuration
+    /// blocks, and ends the pauses that have run out. The signals are cleared once they pause
+    /// the subnet, so a pause must be signalled again to be extended. Returns the weight used.
+    pub fn apply_epoch_pause_signals(block_number: u64) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let threshold = u128::from(Self::get_epoch_pause_threshold());
+        for netuid in Self::get_all_subnet_netuids() {
+            weight.saturating_accrue(T::DbWeight::get().reads(3));
+            if let Some(until) = EpochPausedUntil::<T>::get(netuid) {
+                if block_number < until {
+                    continue;
+                }
+                EpochPausedUntil::<T>::remove(netuid);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+                log::info!("EpochPauseEnded( netuid:{:?} )", netuid);
+                Self::deposit_subnet_event(&[netuid], Event::EpochPauseEnded(netuid));
+            }
+            if EpochPauseSignals::<T>::iter_prefix(netuid).next().is_none() {
+                continue;
+            }
+
+            // The permit, stake and signal of every uid are read.
+            weight.saturating_accrue(
+                T::DbWeight::get()
+                    .reads(u64::from(Self::get_subnetwork_n(netuid)).saturating_mul(4)),
+            );
+            let (signalled, total) = Self::get_epoch_pause_stake(netuid);
+            if total == 0
+                || signalled.saturating_mul(u128::from(u16::MAX)) < threshold.saturating_mul(total)
+            {
+                continue;
+            }
+
+            let until = block_number.saturating_add(Self::get_epoch_pause_duration());
+            EpochPausedUntil::<T>::insert(netuid, until);
+            let removed = EpochPauseSignals::<T>::clear_prefix(netuid, u32::MAX, None).unique;
+            weight
+                .saturating_accrue(T::DbWeight::get().writes(u64::from(removed).saturating_add(1)));
+            log::info!("EpochPaused( netuid:{:?}, until:{:?} )", netuid, until);
+            Self::deposit_subnet_event(&[netuid], Event::EpochPaused(netuid, until));
+        }
+        weight
+    }
+
+    /// Returns the stake of the permitted validators of `netuid` that signal a pause, and the
+    /// stake of all its permitted validators.
+    pub fn get_epoch_pause_stake(netuid: u16) -> (u128, u128) {
+        let mut signalled: u128 = 0;
+        let mut total: u128 = 0;
+        for uid in 0..Self::get_subnetwork_n(netuid) {
+            if !Self::get_validator_permit_for_uid(netuid, uid) {
+                continue;
+            }
+            let stake = u128::from(Self::get_stake_for_uid_and_subnetwork(netuid, uid));
+            total = total.saturating_add(stake);
+            let signals = Keys::<T>::try_get(netuid, uid)
+                .map(|hotkey| EpochPauseSignals::<T>::get(netuid, hotkey))
+                .unwrap_or(false);
+            if signals {
+                signalled = signalled.saturating_add(stake);
+            }
+        }
+        (signalled, total)
+    }
+
+    /// Whether the validators of the subnet have paused its epochs at `block_number`.
+    pub fn is_epoch_paused(netuid: u16, block_number: u64) -> bool {
+        EpochPausedUntil::<T>::get(netuid).is_some_and(|until| block_number < until)
+    }
+
+    pub fn get_epoch_paused_until(netuid: u16) -> Option<u64> {
+        EpochPausedUntil::<T>::get(netuid)
+    }
+
+    pub fn get_epoch_pause_threshold() -> u16 {
+        EpochPauseThreshold::<T>::get()
+    }
+    pub fn set_epoch_pause_threshold(threshold: u16) {
+        EpochPauseThreshold::<T>::put(threshold);
+        Self::deposit_event(Event::EpochPauseThresholdSet(threshold));
+    }
+
+    pub fn get_epoch_pause_duration() -> u64 {
+        EpochPauseDuration::<T>::get()
+    }
+    pub fn set_epoch_pause_duration(duration: u64) {
+        EpochPauseDuration::<T>::put(duration);
+        Self::deposit_event(Event::EpochPauseDurationSet(duration));
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+/// When a subnet runs its epochs, for validators and indexers aligning their cycles with the
+/// epoch boundaries.
+#[freeze_struct("cca51f2fc53b78c9")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct EpochSchedule {
+    pub netuid: Compact<u16>,
+    pub tempo: Compact<u16>,
+    /// The epochs of the subnet run in the blocks whose number modulo `tempo + 1` equals the
+    /// phase, None for a subnet with a zero tempo, which never runs its epoch.
+    pub phase: Option<Compact<u64>>,
+    /// Blocks until the block running the next epoch, 1 when the coming block does. None for
+    /// a subnet with a zero tempo.
+    pub blocks_until_next_epoch: Option<Compact<u64>>,
+    /// Whether the subnet skips its epochs for now, being under maintenance, paused by its
+    /// validators or resetting its bonds.
+    pub epochs_skipped: bool,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the epoch schedule of every subnet but the root network, whose emission
+    /// schedule `get_root_emission_info` serves.
+    pub fn get_all_epoch_schedules() -> Vec<EpochSchedule> {
+        let root_netuid = Self::get_root_netuid();
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter(|netuid| *netuid != root_netuid)
+            .map(Self::get_epoch_schedule)
+            .collect()
+    }
+
+    fn get_epoch_schedule(netuid: u16) -> EpochSchedule {
+        let tempo = Self::get_tempo(netuid);
+        let current_block = Self::get_current_block_as_u64();
+        let next_block = current_block.saturating_add(1);
+        let (phase, blocks_until_next_epoch) = if tempo == 0 {
+            (None, None)
+        } else {
+            let blocks = Self::blocks_until_next_epoch(netuid, tempo, next_block).saturating_add(1);
+            let phase = current_block
+                .saturating_add(blocks)
+                .checked_rem(u64::from(tempo).saturating_add(1))
+                .unwrap_or_default();
+            (Some(phase.into()), Some(blocks.into()))
+        };
+        EpochSchedule {
+            netuid: netuid.into(),
+            tempo: tempo.into(),
+            phase,
+            blocks_until_next_epoch,
+            epochs_skipped: Self::is_in_maintenance(netuid, next_block)
+                || Self::is_epoch_paused(netuid, next_block)
+                || Self::is_bonds_reset_in_progress(netuid),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Off-chain simulation of the subnet epoch, for sweeping consensus parameters against
+//! captured subnet state with the same math the chain runs.
+#![cfg(feature = "std")]
+
+use super::*;
+use crate::epoch::{EmaBondsParams, EpochInput, EpochOutput};
+use crate::math::{inplace_normalize_64, is_topk_with_tiebreak, vec_fixed64_to_fixed32};
+use substrate_fixed::types::{I32F32, I64F64};
+
+/// Consensus parameters of a simulated epoch, in their hyperparameter representation.
+///
+/// Rho is not among them: it only shifts the trust of root validators, the subnet epoch does
+/// not read it.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EpochSimulationParams {
+    pub kappa: u16,
+    pub max_stake_weight_fraction: u16,
+    pub bonds_moving_average: u64,
+    pub liquid_alpha_enabled: bool,
+    pub alpha_low: u16,
+    pub alpha_high: u16,
+    pub max_allowed_validators: u16,
+    pub reset_bonds_on_permit_loss: bool,
+    pub rao_emission: u64,
+    pub emission_split: Option<EmissionSplit>,
+    /// Column max-upscaled bonds carried over from the previous epoch, such as the `bonds` of a
+    /// previous simulation. Empty for a subnet without bonds.
+    pub bonds: Vec<Vec<(u16, u16)>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Captures the consensus parameters and bonds of `netuid`, as a starting point for a sweep.
+    pub fn get_epoch_simulation_params(netuid: u16) -> EpochSimulationParams {
+        let (alpha_low, alpha_high) = Self::get_alpha_values(netuid);
+        let n = Self::get_subnetwork_n(netuid);
+        EpochSimulationParams {
+            kappa: Self::get_kappa(netuid),
+            max_stake_weight_fraction: Self::get_max_stake_weight_fraction(netuid),
+            bonds_moving_average: Self::get_bonds_moving_average(netuid),
+            liquid_alpha_enabled: Self::get_liquid_alpha_enabled(netuid),
+            alpha_low,
+            alpha_high,
+            max_allowed_validators: Self::get_max_allowed_validators(netuid),
+            reset_bonds_on_permit_loss: Self::get_reset_bonds_on_permit_loss(netuid),
+            rao_emission: PendingEmission::<T>::get(netuid),
+            emission_split: Self::get_emission_split(netuid),
+            bonds: (0..n).map(|uid| Bonds::<T>::get(netuid, uid)).collect(),
+        }
+    }
+
+    /// Runs the epoch of a subnet with one uid per entry of `stakes` under `params`, and returns
+    /// what the epoch would write back, without reading or writing storage.
+    ///
+    /// Rows of `weights` are indexed by uid as in the `Weights` map. Every uid is taken to be
+    /// active and to have set its weights after registering, and the validators are the
+    /// `max_allowed_validators` uids with the most stake, as once the epoch has settled.
+    pub fn simulate_epoch(
+        params: &EpochSimulationParams,
+        stakes: &[u64],
+        weights: &[Vec<(u16, u16)>],
+    ) -> EpochOutput {
+        let n = u16::try_from(stakes.len()).unwrap_or(u16::MAX);
+        let sparse = |rows: &[Vec<(u16, u16)>]| -> Vec<Vec<(u16, I32F32)>> {
+            (0..usize::from(n))
+                .map(|uid_i| {
+                    rows.get(uid_i)
+                        .map(|row| {
+                            row.iter()
+                                .filter(|(uid_j, _)| *uid_j < n)
+                                .map(|(uid_j, value)| (*uid_j, I32F32::from_num(*value)))
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                })
+                .collect()
+        };
+
+        let stake: Vec<u64> = stakes.iter().take(usize::from(n)).copied().collect();
+        let block_at_registration: Vec<u64> = vec![0; usize::from(n)];
+        let mut normalized_stake: Vec<I64F64> =
+            stake.iter().map(|s| I64F64::from_num(*s)).collect();
+        inplace_normalize_64(&mut normalized_stake);
+        let validator_permits = is_topk_with_tiebreak(
+            &vec_fixed64_to_fixed32(normalized_stake),
+            &block_at_registration,
+            usize::from(params.max_allowed_validators),
+        );
+
+        let unit = I32F32::from_num(u16::MAX);
+        let input = EpochInput {
+            n,
+            current_block: 1,
+            activity_cutoff: 1,
+            last_update: vec![1; usize::from(n)],
+            block_at_registration,
+            hotkeys: Vec::new(),
+            stake,
+            validator_permits,
+            max_allowed_validators: params.max_allowed_validators,
+            weights: sparse(weights),
+            sum_normalized_weights: false,
+            stale_weights: vec![false; usize::from(n)],
+            unmasked_self_weights: false,
+            kappa: I32F32::from_num(params.kappa).saturating_div(unit),
+            max_stake_weight_fraction: I32F32::from_num(params.max_stake_weight_fraction)
+                .saturating_div(unit),
+            bonds: sparse(&params.bonds),
+            ema_bonds_params: EmaBondsParams {
+                liquid_alpha_enabled: params.liquid_alpha_enabled,
+                alpha_low: I32F32::from_num(params.alpha_low).saturating_div(unit),
+                alpha_high: I32F32::from_num(params.alpha_high).saturating_div(unit),
+                bonds_moving_average: params.bonds_moving_average,
+            },
+            reset_bonds_on_permit_loss: params.reset_bonds_on_permit_loss,
+            rao_emission: params.rao_emission,
+            emission_split: params.emission_split,
+        };
+        Self::compute_epoch(input, None).1.unwrap_or_default()
+    }
+}
@@ -0,0 +1,71 @@
+use super::*;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+
+impl<T: Config> Pallet<T> {
+    /// Appends the snapshot root of `netuid` after its epoch at `block_number` to
+    /// EpochSnapshots, dropping the oldest snapshot once EPOCH_SNAPSHOT_HISTORY are kept.
+    pub fn record_epoch_snapshot(netuid: u16, block_number: u64) {
+        let root = Self::get_epoch_snapshot_root(netuid);
+        EpochSnapshots::<T>::mutate(netuid, |snapshots| {
+            if snapshots.len() >= EPOCH_SNAPSHOT_HISTORY {
+                snapshots.remove(0);
+            }
+            snapshots.push((block_number, root));
+        });
+    }
+
+    /// Returns the merkle root over the economic state of every uid of `netuid`.
+    ///
+    /// Leaf `uid` is the blake2-256 hash of the SCALE encoding of `(uid, stake, incentive,
+    /// dividends)`, as `(u16, u64, u16, u16)`. Each level hashes the concatenation of adjacent
+    /// pairs, carrying an unpaired last node up unchanged. A subnet without uids has a zero root.
+    pub fn get_epoch_snapshot_root(netuid: u16) -> H256 {
+        let incentive = Self::get_incentive(netuid);
+        let dividends = Self::get_dividends(netuid);
+        let leaves: Vec<[u8; 32]> = (0..Self::get_subnetwork_n(netuid))
+            .map(|uid| {
+                let stake = Self::get_stake_for_uid_and_subnetwork(netuid, uid);
+                let index = usize::from(uid);
+                let leaf = (
+                    uid,
+                    stake,
+                    incentive.get(index).copied().unwrap_or(0),
+                    dividends.get(index).copied().unwrap_or(0),
+                );
+                blake2_256(&leaf.encode())
+            })
+            .collect();
+        Self::merkle_root(leaves)
+    }
+
+    /// Returns the merkle root over `leaves`, zero when there are none.
+    pub fn merkle_root(leaves: Vec<[u8; 32]>) -> H256 {
+        let mut level = leaves;
+        while level.len() > 1 {
+            level = Self::merkle_level(&level);
+        }
+        level
+            .first()
+            .map(|root| H256::from(*root))
+            .unwrap_or_default()
+    }
+
+    /// Hashes the concatenation of adjacent pairs of `level`, carrying an unpaired last node
+    /// up unchanged.
+    pub fn merkle_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+        level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => blake2_256(&[left.as_slice(), right.as_slice()].concat()),
+                [node] => *node,
+                _ => [0; 32],
+            })
+            .collect()
+    }
+
+    /// The snapshots of `netuid` as `(block, root)`, oldest first.
+    pub fn get_epoch_snapshots(netuid: u16) -> Vec<(u64, H256)> {
+        EpochSnapshots::<T>::get(netuid)
+    }
+}
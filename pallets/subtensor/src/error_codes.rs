@@ -0,0 +1,238 @@
+use super::*;
+
+/// The groups of `Error` codes. A code is `group * 100` plus its number within the group.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ErrorGroup {
+    /// Subnet existence, ownership and subnet settings.
+    Subnet = 1,
+    /// Neuron registration and hotkey lookups.
+    Registration = 2,
+    /// Axon and prometheus serving.
+    Serving = 3,
+    /// Staking and delegation.
+    Staking = 4,
+    /// Setting, committing and revealing weights.
+    Weights = 5,
+    /// Rate limits on any call.
+    RateLimit = 6,
+    /// Hotkey and coldkey swaps.
+    Swap = 7,
+    /// The root network and the senate.
+    Root = 8,
+}
+
+impl ErrorGroup {
+    pub fn from_error_code(code: u16) -> Option<Self> {
+        match code.saturating_div(100) {
+            1 => Some(Self::Subnet),
+            2 => Some(Self::Registration),
+            3 => Some(Self::Serving),
+            4 => Some(Self::Staking),
+            5 => Some(Self::Weights),
+            6 => Some(Self::RateLimit),
+            7 => Some(Self::Swap),
+            8 => Some(Self::Root),
+            _ => None,
+        }
+    }
+}
+
+// Builds both directions of the mapping from one table, so a variant without a code does
+// not compile and a code given twice is an unreachable pattern.
+macro_rules! error_codes {
+    ($($variant:ident = $code:literal,)*) => {
+        impl<T: Config> Error<T> {
+            /// The stable numeric code of the error. Unlike the variant index, which follows the
+            /// declaration order of `Error`, codes never change across runtime upgrades.
+            pub fn error_code(&self) -> u16 {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::__Ignore(_, _) => unreachable!("`__Ignore` can never be constructed"),
+                }
+            }
+
+            /// The error with the given stable code, if any.
+            pub fn from_error_code(code: u16) -> Option<Self> {
+                match code {
+                    $($code => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            pub fn error_group(&self) -> Option<ErrorGroup> {
+                ErrorGroup::from_error_code(self.error_code())
+            }
+        }
+    };
+}
+
+// Codes are never reused or reassigned. New errors take the next free code of their group.
+error_codes! {
+    // --- Subnet
+    SubNetworkDoesNotExist = 101,
+    RootNetworkDoesNotExist = 102,
+    NotSubnetOwner = 103,
+    NotRootSubnet = 104,
+    AllNetworksInImmunity = 105,
+    InvalidSubnetFeatureFlags = 106,
+    MaintenanceWindowTooLong = 107,
+    MaintenanceWindowInPast = 108,
+    SubnetOwnerHotkeyNotSet = 109,
+    NetuidNotReserved = 110,
+    NetuidAlreadyInUse = 111,
+    NetuidAuctionEnded = 112,
+    NetuidAuctionNotEnded = 113,
+    NetuidBidTooLow = 114,
+    NotNetuidAuctionWinner = 115,
+    SubnetLimitReached = 116,
+    InvalidMaxAllowedUidsGrowth = 117,
+    NoMaxAllowedUidsGrowth = 118,
+    InvalidSubnetTokenMetadata = 119,
+    SubnetSymbolInUse = 120,
+    InvalidOwnerKeySignature = 121,
+    InvalidEmissionSplit = 122,
+    ReferendumAlreadyOpen = 123,
+    NoOpenReferendum = 124,
+    AlreadyVotedInReferendum = 125,
+    ReferendumValueOutOfBounds = 126,
+    NetuidQuarantined = 127,
+    NotPendingSubnetOwner = 128,
+    SubnetMigrationInProgress = 129,
+    CannotMigrateRootNetwork = 130,
+    TooManyReservedNetuids = 131,
+
+    // --- Registration
+    HotKeyAlreadyRegisteredInSubNet = 201,
+    TooManyRegistrationsThisBlock = 202,
+    TooManyRegistrationsThisInterval = 203,
+    SubNetRegistrationDisabled = 204,
+    InvalidWorkBlock = 205,
+    InvalidDifficulty = 206,
+    InvalidSeal = 207,
+    RegistrationNotPermittedOnRootSubnet = 208,
+    NoNeuronIdAvailable = 209,
+    FaucetDisabled = 210,
+    TransactorAccountShouldBeHotKey = 211,
+    HotKeyNotRegisteredInSubNet = 212,
+    HotKeyNotRegisteredInNetwork = 213,
+    HotKeyAccountNotExists = 214,
+    TooManyPowRegistrationsThisBlock = 215,
+    TooManyBurnRegistrationsThisBlock = 216,
+    InvalidEvmKeySignature = 217,
+    EvmKeySignatureExpired = 218,
+    NotEnoughStakeToRegister = 219,
+    RegistrationReservedForRole = 220,
+
+    // --- Serving
+    InvalidIpType = 301,
+    InvalidIpAddress = 302,
+    InvalidPort = 303,
+    InvalidAxonIndex = 304,
+    CommitmentTooLarge = 305,
+    AttestedUidNotFound = 306,
+    InvalidCertificate = 307,
+    InvalidIdentity = 308,
+    NotEnoughBalanceForIdentityDeposit = 309,
+
+    // --- Staking
+    NonAssociatedColdKey = 401,
+    HotKeyNotDelegateAndSignerNotOwnHotKey = 402,
+    StakeToWithdrawIsZero = 403,
+    NotEnoughStakeToWithdraw = 404,
+    NotEnoughBalanceToStake = 405,
+    BalanceWithdrawalError = 406,
+    ZeroBalanceAfterWithdrawn = 407,
+    HotKeyAlreadyDelegate = 408,
+    NomStakeBelowMinimumThreshold = 409,
+    DelegateTakeTooLow = 410,
+    DelegateTakeTooHigh = 411,
+    FeeReserveNotKept = 412,
+    RecycleAmountIsZero = 413,
+    NotEnoughBalanceToRecycle = 414,
+    HotkeyIsBanned = 415,
+    HotkeyAlreadyBanned = 416,
+    StakeToAddIsZero = 417,
+    TooManyStakeSubscriptions = 418,
+    StakeLockedInReferendum = 419,
+    TooManyChildren = 420,
+    InvalidChild = 421,
+    DuplicateChild = 422,
+    ChildProportionsOverflow = 423,
+    HotKeyNotDelegate = 424,
+    NoStakeOnDelegate = 425,
+    TooManyNominationsToClear = 426,
+    StakeIsLocked = 427,
+    InvalidStakeLockDuration = 428,
+    TooManyPendingWithdrawals = 429,
+    NothingToClaim = 430,
+    StakeBatchTooLarge = 431,
+    HotkeyNotBanned = 432,
+    TooManyParents = 433,
+
+    // --- Weights
+    NotEnoughStakeToSetWeights = 501,
+    NeuronNoValidatorPermit = 502,
+    WeightVecNotEqualSize = 503,
+    DuplicateUids = 504,
+    UidVecContainInvalidOne = 505,
+    WeightVecLengthIsLow = 506,
+    MaxWeightExceeded = 507,
+    IncorrectWeightVersionKey = 508,
+    UidsLengthExceedUidsInSubNet = 509,
+    CanNotSetRootNetworkWeights = 510,
+    WeightsCommitNotAllowed = 511,
+    NoWeightsCommitFound = 512,
+    InvalidRevealCommitTempo = 513,
+    InvalidRevealCommitHashNotMatch = 514,
+    CommitRevealEnabled = 515,
+    CommitRevealDisabled = 516,
+    LiquidAlphaDisabled = 517,
+    AlphaHighTooLow = 518,
+    AlphaLowOutOfRange = 519,
+    WeightsKeyInUse = 520,
+    WeightsKeyIsRegistered = 521,
+    WeightsPaused = 522,
+    WeightsTargetEpochRequired = 523,
+    InvalidWeightsTargetEpoch = 524,
+    WeightsBatchTooLarge = 525,
+    DegenerateRootWeights = 526,
+    RootWeightsSpreadTooLow = 527,
+    NoWeightSetter = 528,
+
+    // --- Rate limits
+    SettingWeightsTooFast = 601,
+    ServingRateLimitExceeded = 602,
+    NetworkTxRateLimitExceeded = 603,
+    DelegateTxRateLimitExceeded = 604,
+    HotKeySetTxRateLimitExceeded = 605,
+    StakeRateLimitExceeded = 606,
+    UnstakeRateLimitExceeded = 607,
+    ColdKeySwapTxRateLimitExceeded = 608,
+    CommitmentRateLimitExceeded = 609,
+    FaucetRateLimitExceeded = 610,
+    TransactionTypeRateLimitExceeded = 611,
+
+    // --- Swaps
+    NewHotKeyIsSameWithOld = 701,
+    NotEnoughBalanceToPaySwapHotKey = 702,
+    ColdKeyAlreadyAssociated = 703,
+    NewColdKeyIsSameWithOld = 704,
+    NotExistColdkey = 705,
+    NotEnoughBalanceToPaySwapColdKey = 706,
+    NoBalanceToTransfer = 707,
+    SameColdkey = 708,
+    ColdkeyIsInArbitration = 709,
+    DuplicateColdkey = 710,
+    ColdkeySwapError = 711,
+    InsufficientBalanceToPerformColdkeySwap = 712,
+    MaxColdkeyDestinationsReached = 713,
+    NoColdkeySwapScheduled = 714,
+    InvalidColdkeySwapDestination = 715,
+
+    // --- Root and senate
+    NotSenateMember = 801,
+    StakeTooLowForRoot = 802,
+    CouldNotJoinSenate = 803,
+    CanNotRecycleToRootNetwork = 804,
+    RootValidatorInImmunity = 805,
+}
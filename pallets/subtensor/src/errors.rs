@@ -4,6 +4,7 @@ use frame_support::pallet_macros::pallet_section;
 /// This can later be imported into the pallet using [`import_section`].
 #[pallet_section]
 mod errors {
+    /// Variants are only ever appended, and each one is given a stable code in `error_codes.rs`.
     #[pallet::error]
     pub enum Error<T> {
         /// The subnet does not exist.
@@ -98,7 +99,7 @@ mod errors {
         RegistrationNotPermittedOnRootSubnet,
         /// A hotkey with too little stake is attempting to join the root subnet.
         StakeTooLowForRoot,
-        /// All subnets are in the immunity period.
+        /// All subnets are in the immunity period or protected by their lock.
         AllNetworksInImmunity,
         /// Not enough balance to pay swapping hotkey.
         NotEnoughBalanceToPaySwapHotKey,
@@ -158,5 +159,162 @@ mod errors {
         InsufficientBalanceToPerformColdkeySwap,
         /// The maximum number of coldkey destinations has been reached
         MaxColdkeyDestinationsReached,
+        /// No subnet feature flags were given, or one of them is unknown.
+        InvalidSubnetFeatureFlags,
+        /// The axon index is past the end of the neuron's axon set, or at MAX_AXONS_PER_NEURON.
+        InvalidAxonIndex,
+        /// Removing the amount would leave the coldkey with less than the fee reserve it keeps.
+        FeeReserveNotKept,
+        /// The maintenance window is longer than MaxMaintenanceDuration.
+        MaintenanceWindowTooLong,
+        /// The maintenance window starts before the current block.
+        MaintenanceWindowInPast,
+        /// The commitment is larger than the subnet's MaxCommitmentSize.
+        CommitmentTooLarge,
+        /// The certificate is empty or longer than MAX_NEURON_CERTIFICATE_BYTES.
+        InvalidCertificate,
+        /// The hotkey has committed on the subnet within the CommitmentRateLimit.
+        CommitmentRateLimitExceeded,
+        /// The subnet has no operator hotkey to revoke.
+        SubnetOwnerHotkeyNotSet,
+        /// The amount to recycle or burn is zero.
+        RecycleAmountIsZero,
+        /// The coldkey's free balance is below the amount to recycle or burn.
+        NotEnoughBalanceToRecycle,
+        /// The root network has no emission pool to recycle into.
+        CanNotRecycleToRootNetwork,
+        /// Number of PoW registrations in this block exceeds the subnet's "max_pow_regs_per_block".
+        TooManyPowRegistrationsThisBlock,
+        /// Number of burned registrations in this block exceeds the subnet's "max_burn_regs_per_block".
+        TooManyBurnRegistrationsThisBlock,
+        /// The netuid is not reserved for an auction.
+        NetuidNotReserved,
+        /// The netuid is the root network, already has a subnet, is already reserved, or a
+        /// subnet is moving to or from it.
+        NetuidAlreadyInUse,
+        /// The auction for the netuid no longer accepts bids.
+        NetuidAuctionEnded,
+        /// The auction for the netuid has not ended yet.
+        NetuidAuctionNotEnded,
+        /// The bid is below the minimum bid or does not beat the highest bid.
+        NetuidBidTooLow,
+        /// The caller does not hold the highest bid on the netuid.
+        NotNetuidAuctionWinner,
+        /// The number of subnets is at the subnet limit.
+        SubnetLimitReached,
+        /// The hotkey is banned from registering and receiving stake.
+        HotkeyIsBanned,
+        /// The hotkey is already banned.
+        HotkeyAlreadyBanned,
+        /// The weights key already sets weights for another hotkey on the subnet.
+        WeightsKeyInUse,
+        /// The weights key is itself a hotkey registered on the subnet.
+        WeightsKeyIsRegistered,
+        /// The growth step or interval is zero, or the target is not above the current max allowed uids.
+        InvalidMaxAllowedUidsGrowth,
+        /// No growth of the max allowed uids is scheduled for the subnet.
+        NoMaxAllowedUidsGrowth,
+        /// The amount to stake is zero.
+        StakeToAddIsZero,
+        /// The subnet token symbol or name is too long or not valid UTF-8.
+        InvalidSubnetTokenMetadata,
+        /// Another subnet already uses the token symbol.
+        SubnetSymbolInUse,
+        /// The coldkey already subscribes to the maximum number of stake thresholds.
+        TooManyStakeSubscriptions,
+        /// The signature does not prove possession of the new subnet owner key.
+        InvalidOwnerKeySignature,
+        /// The signature does not prove possession of the EVM key being associated.
+        InvalidEvmKeySignature,
+        /// The block named in the EVM key signature is in the future or too old.
+        EvmKeySignatureExpired,
+        /// The shares of the emission split do not sum to 10_000 basis points.
+        InvalidEmissionSplit,
+        /// Weight setting on the subnet is paused.
+        WeightsPaused,
+        /// The subnet already has an open referendum.
+        ReferendumAlreadyOpen,
+        /// The subnet has no referendum open for votes.
+        NoOpenReferendum,
+        /// The hotkey already voted on the open referendum of the subnet.
+        AlreadyVotedInReferendum,
+        /// The proposed value is outside the bounds the subnet owner can set the hyperparameter to.
+        ReferendumValueOutOfBounds,
+        /// The unstake would take the hotkey's stake below the stake locked by its referendum votes.
+        StakeLockedInReferendum,
+        /// The netuid was removed too recently to be assigned again.
+        NetuidQuarantined,
+        /// The subnet requires weights to be set for its next epoch with `set_weights_for_epoch`.
+        WeightsTargetEpochRequired,
+        /// The weights target an epoch other than the next one of the subnet.
+        InvalidWeightsTargetEpoch,
+        /// The weights batch has more entries than MAX_WEIGHTS_BATCH_SIZE.
+        WeightsBatchTooLarge,
+        /// The attested uid does not exist on the subnet.
+        AttestedUidNotFound,
+        /// The hotkey holds less stake than the subnet requires to register.
+        NotEnoughStakeToRegister,
+        /// More than MAX_CHILDREN child keys are given.
+        TooManyChildren,
+        /// A hotkey cannot be its own child.
+        InvalidChild,
+        /// A child key is given more than once.
+        DuplicateChild,
+        /// The proportions of the child keys add up to more than u64::MAX.
+        ChildProportionsOverflow,
+        /// No coldkey swap is scheduled for the coldkey.
+        NoColdkeySwapScheduled,
+        /// The coldkey is not a scheduled swap destination of the coldkey.
+        InvalidColdkeySwapDestination,
+        /// The hotkey is not a delegate.
+        HotKeyNotDelegate,
+        /// The coldkey holds no stake on the delegate.
+        NoStakeOnDelegate,
+        /// The root validator with the lowest stake registered within the ImmunityPeriod of
+        /// the root network.
+        RootValidatorInImmunity,
+        /// A field of the identity is longer than its bound.
+        InvalidIdentity,
+        /// The coldkey cannot pay the IdentityDeposit.
+        NotEnoughBalanceForIdentityDeposit,
+        /// The coldkey used the faucet less than FaucetRateLimit blocks ago.
+        FaucetRateLimitExceeded,
+        /// Every root weight is zero while root weights safe mode is on.
+        DegenerateRootWeights,
+        /// The root weights reach fewer than RootWeightsMinSpread subnets while root weights
+        /// safe mode is on.
+        RootWeightsSpreadTooLow,
+        /// The caller is not the pending owner of the subnet.
+        NotPendingSubnetOwner,
+        /// More nominations are listed than MAX_NOMINATIONS_CLEARED_PER_CALL.
+        TooManyNominationsToClear,
+        /// The last registration of the block is left to the role the subnet is short of.
+        RegistrationReservedForRole,
+        /// The hotkey has no weight setter on the subnet.
+        NoWeightSetter,
+        /// The account sent a transaction of the type less than its TransactionTypeRateLimit
+        /// blocks ago.
+        TransactionTypeRateLimitExceeded,
+        /// The unstake would take the stake below the amount the coldkey locked on the hotkey, or
+        /// the hotkey unregistering holds locked stake.
+        StakeIsLocked,
+        /// The stake lock duration is zero or longer than MAX_STAKE_LOCK_BLOCKS.
+        InvalidStakeLockDuration,
+        /// The coldkey has MAX_PENDING_WITHDRAWALS withdrawals waiting to be claimed.
+        TooManyPendingWithdrawals,
+        /// None of the withdrawals of the coldkey finished its unstake cooldown.
+        NothingToClaim,
+        /// The batch has more than MAX_STAKE_BATCH_SIZE entries.
+        StakeBatchTooLarge,
+        /// A subnet is already moving to another netuid.
+        SubnetMigrationInProgress,
+        /// The root network can not move to another netuid.
+        CannotMigrateRootNetwork,
+        /// The hotkey is not banned.
+        HotkeyNotBanned,
+        /// MAX_RESERVED_NETUIDS netuids are already reserved for auctions.
+        TooManyReservedNetuids,
+        /// A child key already has MAX_PARENTS parents on the subnet.
+        TooManyParents,
     }
 }
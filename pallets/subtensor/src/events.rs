@@ -35,12 +35,18 @@ mod events {
         RegistrationPerIntervalSet(u16, u16),
         /// we set max registrations per block.
         MaxRegistrationsPerBlockSet(u16, u16),
+        /// we set max PoW registrations per block.
+        MaxPowRegistrationsPerBlockSet(u16, u16),
+        /// we set max burned registrations per block.
+        MaxBurnRegistrationsPerBlockSet(u16, u16),
         /// an activity cutoff is set for a subnet.
         ActivityCutoffSet(u16, u16),
         /// Rho value is set.
         RhoSet(u16, u16),
         /// Kappa is set for a subnet.
         KappaSet(u16, u16),
+        /// the feature flags of a subnet have changed, carries the resulting bitfield.
+        SubnetFeatureFlagsSet(u16, u32),
         /// minimum allowed weight is set for a subnet.
         MinAllowedWeightSet(u16, u16),
         /// the validator pruning length has been set.
@@ -111,6 +117,10 @@ mod events {
         NetworkMinLockCostSet(u64),
         /// the maximum number of subnets is set
         SubnetLimitSet(u16),
+        /// a new subnet limit has been announced, to be applied at the given block.
+        SubnetLimitChangeScheduled(u16, u64),
+        /// the maximum number of root network validators has been set.
+        RootMaxUidsSet(u16),
         /// the lock cost reduction is set
         NetworkLockCostReductionIntervalSet(u64),
         /// the take for a delegate is decreased.
@@ -171,5 +181,272 @@ mod events {
             /// The account ID of the coldkey
             coldkey: T::AccountId,
         },
+        /// the fee reserve kept back on coldkeys is set.
+        FeeReserveSet(u64),
+        /// a coldkey has set whether it keeps the fee reserve when staking or locking.
+        KeepFeeReserveSet(T::AccountId, bool),
+        /// a subnet owner has scheduled a maintenance window (netuid, start, duration); a zero duration clears it.
+        MaintenanceScheduled(u16, u64, u64),
+        /// the maximum length of a subnet maintenance window is set.
+        MaxMaintenanceDurationSet(u64),
+        /// a hotkey has set or cleared its commitment on a subnet.
+        CommitmentSet(u16, T::AccountId),
+        /// the maximum commitment size of a subnet is set.
+        MaxCommitmentSizeSet(u16, u32),
+        /// the commitment rate limit of a subnet is set.
+        CommitmentRateLimitSet(u16, u64),
+        /// a subnet owner has designated an operator hotkey for the subnet.
+        SubnetOwnerHotkeySet(u16, T::AccountId),
+        /// the operator hotkey of a subnet is revoked.
+        SubnetOwnerHotkeyRevoked(u16),
+        /// a coldkey has recycled tokens into the emission pool of a subnet (coldkey, netuid, amount).
+        TokensRecycled(T::AccountId, u16, u64),
+        /// a coldkey has burned tokens.
+        TokensBurned(T::AccountId, u64),
+        /// the lowest activity cutoff a subnet owner can set is set.
+        MinActivityCutoffSet(u16),
+        /// a netuid is reserved for an auction (netuid, min_bid, auction_end_block).
+        NetuidReserved(u16, u64, u64),
+        /// a reserved netuid is returned to sequential assignment.
+        NetuidReleased(u16),
+        /// a coldkey has placed the highest bid on a reserved netuid (netuid, coldkey, amount).
+        NetuidBidPlaced(u16, T::AccountId, u64),
+        /// an escrowed bid on a netuid is refunded to its bidder (netuid, coldkey, amount).
+        NetuidBidRefunded(u16, T::AccountId, u64),
+        /// the winner of a netuid auction has registered its network (netuid, coldkey, amount burned).
+        NetuidClaimed(u16, T::AccountId, u64),
+        /// a hotkey is banned by governance and its stake starts winding down.
+        HotkeyBanned(T::AccountId),
+        /// a ban on a hotkey is lifted, the stake not yet returned stays on the hotkey.
+        HotkeyUnbanned(T::AccountId),
+        /// stake on a banned hotkey is returned to its coldkey (hotkey, coldkey, amount).
+        BannedHotkeyStakeReturned(T::AccountId, T::AccountId, u64),
+        /// all stake on a banned hotkey has been returned.
+        BannedHotkeyWoundDown(T::AccountId),
+        /// the number of stakes of banned hotkeys returned per block is set.
+        StakeWindDownChunkSizeSet(u16),
+        /// the number of blocks after which unrefreshed axons of a subnet are stale is set.
+        AxonTTLBlocksSet(u16, u64),
+        /// a hotkey has authorized a weights key on a subnet (netuid, hotkey, key).
+        WeightsKeyAuthorized(u16, T::AccountId, T::AccountId),
+        /// a hotkey has revoked its weights key on a subnet.
+        WeightsKeyRevoked(u16, T::AccountId),
+        /// part of the emission of a subnet below the utilization floor is burned (netuid, utilization, amount burned).
+        SubnetEmissionBurned(u16, u16, u64),
+        /// the utilization below which subnets have emission burned is set.
+        UtilizationFloorSet(u16),
+        /// the fraction of emission burned for subnets below the utilization floor is set.
+        LowUtilizationBurnSet(u16),
+        /// growth of the max allowed uids of a subnet is scheduled (netuid, step, interval, target).
+        MaxAllowedUidsGrowthScheduled(u16, u16, u64, u16),
+        /// the scheduled growth of the max allowed uids of a subnet is cancelled.
+        MaxAllowedUidsGrowthCancelled(u16),
+        /// the max allowed uids of a subnet have reached the target of their scheduled growth.
+        MaxAllowedUidsGrowthCompleted(u16),
+        /// orphaned LoadedEmission and LastUpdate entries are pruned (number of entries).
+        OrphanedStoragePruned(u32),
+        /// the number of blocks between payouts of hotkey emission is set.
+        HotkeyEmissionTempoSet(u64),
+        /// a uid of a full subnet becomes its pruning candidate within a tempo (netuid, uid).
+        PruningImminent(u16, u16),
+        /// a permitted validator signals whether to pause the epochs of a subnet (netuid, hotkey, pause).
+        EpochPauseSignalled(u16, T::AccountId, bool),
+        /// the validators of a subnet pause its epochs (netuid, until block).
+        EpochPaused(u16, u64),
+        /// the pause of a subnet's epochs signalled by its validators ends.
+        EpochPauseEnded(u16),
+        /// the share of validator stake that pauses a subnet's epochs is set.
+        EpochPauseThresholdSet(u16),
+        /// the number of blocks validators pause a subnet's epochs for is set.
+        EpochPauseDurationSet(u64),
+        /// a fee is charged on adding or removing stake (coldkey, fee).
+        StakeFeeCharged(T::AccountId, u64),
+        /// the flat and proportional (basis points) parts of the stake fee are set.
+        StakeFeeSet(u64, u16),
+        /// the amount below which staking and unstaking are exempt from the fee is set.
+        StakeFeeDustThresholdSet(u64),
+        /// the account receiving stake fees is set, none recycles them.
+        StakeFeeTreasurySet(Option<T::AccountId>),
+        /// the token symbol and name of a subnet are set (netuid, symbol, token name).
+        SubnetTokenMetadataSet(u16, Vec<u8>, Vec<u8>),
+        /// the weights of a uid are cleared as they are below the raised min allowed weights (netuid, uid).
+        NonconformingWeightsCleared(u16, u16),
+        /// every weight row of a subnet has been checked against its min allowed weights.
+        WeightsSweepCompleted(u16),
+        /// the number of weight rows checked per block after a min allowed weights raise is set.
+        WeightsSweepChunkSizeSet(u16),
+        /// a coldkey subscribes to an alert on a hotkey's total stake, zero cancels (coldkey, hotkey, threshold).
+        StakeThresholdSubscribed(T::AccountId, T::AccountId, u64),
+        /// the total stake of a hotkey has fallen below a subscribed threshold (coldkey, hotkey, stake, threshold).
+        StakeThresholdBreached(T::AccountId, T::AccountId, u64, u64),
+        /// the maximum number of uids without a validator permit on a subnet is set.
+        MaxMinerUidsSet(u16, u16),
+        /// the ownership of a subnet is transferred to a new coldkey (netuid, old_coldkey, new_coldkey).
+        SubnetOwnerKeyRotated(u16, T::AccountId, T::AccountId),
+        /// the work per block of the root epoch is set.
+        RootEpochBlockBudgetSet(u32),
+        /// the share of the server emission guaranteed to uids in their first epoch on a subnet is set.
+        BootstrapIncentiveFloorSet(u16, u16),
+        /// an EVM address is associated with a hotkey on a subnet (netuid, hotkey, evm_address).
+        EvmKeyAssociated(u16, T::AccountId, H160),
+        /// the split of a subnet's emission between its owner, miners and validators is set.
+        EmissionSplitSet(u16, EmissionSplit),
+        /// weight setting on a subnet is paused until a block, or unpaused (netuid, paused_until).
+        WeightsPausedUntilSet(u16, Option<u64>),
+        /// the maximum number of blocks weight setting stays paused is set.
+        MaxWeightsPauseDurationSet(u64),
+        /// the number of blocks between two updates of a hyperparameter by a subnet owner is set.
+        OwnerHyperparamRateLimitSet(u64),
+        /// the factor in basis points the network lock cost is raised by on each registration is set.
+        NetworkLockCostMultiplierSet(u32),
+        /// a subnet owner puts a hyperparameter change to a vote (netuid, hyperparam, value, ends_at).
+        SubnetReferendumOpened(u16, OwnerHyperparam, u16, u64),
+        /// a validator votes on the referendum of a subnet (netuid, hotkey, approve, locked_stake).
+        SubnetReferendumVoted(u16, T::AccountId, bool, u64),
+        /// the referendum of a subnet closes, applying its change if it passed (netuid, passed).
+        SubnetReferendumClosed(u16, bool),
+        /// the number of blocks a subnet referendum is open for votes is set.
+        ReferendumDurationSet(u64),
+        /// the share of the permitted validators' stake that passes a subnet referendum is set.
+        ReferendumThresholdSet(u16),
+        /// the number of blocks a removed netuid is held back from reassignment is set.
+        NetuidQuarantinePeriodSet(u64),
+        /// a validator attests the reachability of a uid's axon (netuid, uid, hotkey, reachable).
+        AxonAttested(u16, u16, T::AccountId, bool),
+        /// the number of blocks the stake behind a reachable axon attestation halves in is set.
+        AxonReachabilityHalfLifeSet(u64),
+        /// the stake a hotkey must already hold to register on a subnet is set (netuid, min_stake).
+        MinStakeToRegisterSet(u16, u64),
+        /// the bound on the stake a subnet can require of registering hotkeys is set.
+        MaxMinStakeToRegisterSet(u64),
+        /// the children of a hotkey on a subnet are scheduled (hotkey, netuid, cooldown_block).
+        ChildKeysPending(T::AccountId, u16, u64),
+        /// the pending children of a hotkey on a subnet apply (hotkey, netuid).
+        ChildKeysSet(T::AccountId, u16),
+        /// the number of blocks before a change of child keys applies is set.
+        PendingChildKeyCooldownSet(u64),
+        /// a reset of the bonds of a subnet starts, holding back its epochs.
+        BondsResetStarted(u16),
+        /// a bonds reset clears a chunk of rows (netuid, next_uid).
+        BondsResetProgress(u16, u16),
+        /// the bonds of a subnet are cleared and its epochs resume.
+        BondsResetCompleted(u16),
+        /// the number of bonds rows cleared per block by a bonds reset is set.
+        BondsResetChunkSizeSet(u16),
+        /// the owner of a hotkey clears its serving info and weights on a subnet (netuid, hotkey).
+        NeuronCleared(u16, T::AccountId),
+        /// governance has cancelled the swap scheduled for a coldkey.
+        ColdkeySwapCancelled {
+            /// The account ID of the coldkey
+            old_coldkey: T::AccountId,
+        },
+        /// the arbitration period of scheduled coldkey swaps is set.
+        ArbitrationPeriodSet(u64),
+        /// a nominator votes to cap the take of a delegate (hotkey, coldkey, cap).
+        TakeCapVoted(T::AccountId, T::AccountId, u16),
+        /// a nominator withdraws its vote on the take of a delegate (hotkey, coldkey).
+        TakeCapVoteWithdrawn(T::AccountId, T::AccountId),
+        /// the take of a delegate is capped by vote of its nominators (hotkey, cap, expires_at).
+        DelegateTakeCapped(T::AccountId, u16, u64),
+        /// the cap voted on the take of a delegate lapses.
+        DelegateTakeCapExpired(T::AccountId),
+        /// the share of the stake of a delegate needed to cap its take is set.
+        TakeCapThresholdSet(u16),
+        /// the number of blocks a cap on the take of a delegate runs is set.
+        TakeCapDurationSet(u64),
+        /// the take of a delegate is reduced for underperformance (hotkey, take).
+        DelegateTakeReduced(T::AccountId, u16),
+        /// the take of a delegate reduced for underperformance is restored.
+        DelegateTakeRestored(T::AccountId),
+        /// the take reduction of underperforming delegates is set (trust_threshold, tempos, take).
+        DelegateUnderperformanceSet(u16, u16, u16),
+        /// the longest immunity period a subnet owner can set is set.
+        MaxImmunityPeriodSet(u16),
+        /// the largest share of the active stake one validator of a subnet weighs in its epoch
+        /// with is set (netuid, fraction).
+        MaxStakeWeightFractionSet(u16, u16),
+        /// an entry of a weights batch fails and is skipped (netuid, error).
+        WeightsBatchEntryFailed(u16, DispatchError),
+        /// the root epoch commits the emission values it set (block, root).
+        RootEpochCommitted(u64, H256),
+        /// a neuron sets the TLS certificate of its axon (netuid, hotkey).
+        NeuronCertificateSet(u16, T::AccountId),
+        /// a coldkey sets its identity (coldkey, deposit).
+        IdentitySet(T::AccountId, u64),
+        /// the identity of a coldkey is cleared and its deposit refunded (coldkey, deposit).
+        IdentityCleared(T::AccountId, u64),
+        /// a subnet owner sets the identity of the subnet (netuid, deposit).
+        SubnetIdentitySet(u16, u64),
+        /// the identity of a subnet is cleared and its deposit refunded (netuid, deposit).
+        SubnetIdentityCleared(u16, u64),
+        /// the deposit held for an identity is set.
+        IdentityDepositSet(u64),
+        /// the number of blocks call metrics are counted over is set.
+        CallMetricsEraLengthSet(u64),
+        /// block_step adjusts the pow difficulty of a subnet at the end of its adjustment interval (netuid, old, new).
+        DifficultyAdjusted(u16, u64, u64),
+        /// block_step adjusts the burn of a subnet at the end of its adjustment interval (netuid, old, new).
+        BurnAdjusted(u16, u64, u64),
+        /// the number of blocks a coldkey waits between faucet calls is set.
+        FaucetRateLimitSet(u64),
+        /// liquid alpha is enabled or disabled on a subnet (netuid, enabled).
+        LiquidAlphaEnabledSet(u16, bool),
+        /// the bounds of the liquid alpha of a subnet are set (netuid, alpha_low, alpha_high).
+        AlphaValuesSet(u16, u16, u16),
+        /// root weights safe mode is set (enabled, stake_threshold, min_spread).
+        RootWeightsSafeModeSet(bool, u64, u16),
+        /// the cap on the share of root stake a validator ranks subnets with is set.
+        RootStakeCapSet(u16),
+        /// a hotkey removes its own weight row on a subnet (netuid, uid, hotkey).
+        WeightsPurged(u16, u16, T::AccountId),
+        /// a neuron leaves a subnet, freeing its uid (netuid, uid, hotkey, refund).
+        NeuronUnregistered(u16, u16, T::AccountId, u64),
+        /// the last uid of a subnet moves into a slot freed by `unregister` (netuid, from, to).
+        NeuronUidMoved(u16, u16, u16),
+        /// the refund of `unregister` is set (window, share).
+        UnregisterRefundSet(u64, u16),
+        /// the emission multiplier of a subnet is set, in basis points (netuid, multiplier).
+        EmissionMultiplierSet(u16, u32),
+        /// the emission value of a subnet is scaled by its emission multiplier (netuid, multiplier, emission).
+        SubnetEmissionMultiplied(u16, u32, u64),
+        /// the owner of a subnet proposes a new owner (netuid, owner, new_owner).
+        SubnetOwnershipTransferProposed(u16, T::AccountId, T::AccountId),
+        /// the pending owner of a subnet takes it over (netuid, old_owner, new_owner).
+        SubnetOwnershipTransferred(u16, T::AccountId, T::AccountId),
+        /// a nomination below the minimum is returned to its nominator (hotkey, coldkey, amount).
+        SmallNominationCleared(T::AccountId, T::AccountId, u64),
+        /// a registrant declares the role it registered for (netuid, hotkey, role).
+        NeuronRoleDeclared(u16, T::AccountId, NeuronRole),
+        /// a hotkey lets another account set its weights (netuid, hotkey, signer).
+        WeightSetterAuthorized(u16, T::AccountId, T::AccountId),
+        /// a hotkey revokes its weight setter (netuid, hotkey).
+        WeightSetterRevoked(u16, T::AccountId),
+        /// the cap on the take of delegates for the emission earned on a subnet is set (netuid, max_take).
+        MaxTakePerSubnetSet(u16, u16),
+        /// the rate limit of a transaction type is set (transaction_type, rate_limit).
+        TransactionTypeRateLimitSet(TransactionType, u64),
+        /// a coldkey locked stake on a hotkey (coldkey, hotkey, amount, unlock_block).
+        StakeLocked(T::AccountId, T::AccountId, u64, u64),
+        /// a stake lock ended (coldkey, hotkey, amount).
+        StakeUnlocked(T::AccountId, T::AccountId, u64),
+        /// the dividend multipliers of locked stake are set (boosts).
+        StakeLockBoostsSet(Vec<(u64, u32)>),
+        /// the unstake cooldown is set (cooldown).
+        UnstakeCooldownSet(u64),
+        /// removed stake is held until the unstake cooldown ends (coldkey, hotkey, amount, unlock_block).
+        WithdrawalScheduled(T::AccountId, T::AccountId, u64, u64),
+        /// a coldkey claimed the removed stake whose cooldown ended (coldkey, amount).
+        UnstakeClaimed(T::AccountId, u64),
+        /// a subnet starts moving to another netuid (old_netuid, new_netuid).
+        SubnetMigrationStarted(u16, u16),
+        /// a migrating subnet moved more of its storage (old_netuid, new_netuid, stage, moved).
+        SubnetMigrationProgressed(u16, u16, u8, u64),
+        /// a subnet finished moving to another netuid (old_netuid, new_netuid).
+        SubnetMigrated(u16, u16),
+        /// the bits each weight of a subnet is stored with are set (netuid, precision).
+        WeightsPrecisionSet(u16, u8),
+        /// a reserved netuid was not claimed within NETUID_CLAIM_PERIOD blocks of the end of
+        /// its auction and returns to sequential assignment.
+        NetuidReservationExpired(u16),
     }
 }
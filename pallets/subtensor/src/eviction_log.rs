@@ -0,0 +1,33 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Appends the replacement of `evicted_hotkey` by `new_hotkey` on `netuid` at
+    /// `block_number` to EvictionLog, dropping the oldest entry once EVICTION_LOG_LENGTH are
+    /// kept.
+    pub fn record_eviction(
+        netuid: u16,
+        block_number: u64,
+        evicted_hotkey: &T::AccountId,
+        evicted_stake: u64,
+        new_hotkey: &T::AccountId,
+    ) {
+        EvictionLog::<T>::mutate(netuid, |log| {
+            if log.len() >= EVICTION_LOG_LENGTH as usize {
+                log.remove(0);
+            }
+            // Cannot fail, the oldest entry was dropped above.
+            let _ = log.try_push((
+                block_number,
+                evicted_hotkey.clone(),
+                evicted_stake,
+                new_hotkey.clone(),
+            ));
+        });
+    }
+
+    /// The neurons replaced on `netuid` as `(block, evicted hotkey, evicted stake, new
+    /// hotkey)`, oldest first.
+    pub fn get_eviction_log(netuid: u16) -> Vec<(u64, T::AccountId, u64, T::AccountId)> {
+        EvictionLog::<T>::get(netuid).into_inner()
+    }
+}
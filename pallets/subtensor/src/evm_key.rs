@@ -0,0 +1,115 @@
+use super::*;
+use sp_core::H160;
+use sp_io::hashing::keccak_256;
+
+/// Domain separator of the payload an EVM key signs to be associated with a hotkey.
+const EVM_KEY_ASSOCIATION_CONTEXT: &[u8] = b"subtensor:associate_evm_key";
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic associate_evm_key.
+    ///
+    /// Associates `evm_address` with the calling hotkey on `netuid`, replacing any previous
+    /// address. The EVM key signs `evm_key_association_digest` as an Ethereum personal
+    /// message, which names the hotkey, the subnet and a recent block so that the proof
+    /// cannot be replayed for another hotkey or long after it was made.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on the subnet.
+    ///
+    /// * 'EvmKeySignatureExpired':
+    ///     - The signed block is in the future or older than EVM_KEY_SIGNATURE_VALIDITY blocks.
+    ///
+    /// * 'InvalidEvmKeySignature':
+    ///     - The signature does not recover to the EVM address.
+    ///
+    pub fn do_associate_evm_key(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        evm_address: H160,
+        block_number: u64,
+        signature: [u8; 65],
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        let current_block = Self::get_current_block_as_u64();
+        ensure!(
+            block_number <= current_block
+                && current_block.saturating_sub(block_number) <= EVM_KEY_SIGNATURE_VALIDITY,
+            Error::<T>::EvmKeySignatureExpired
+        );
+
+        let digest = Self::evm_key_association_digest(netuid, &hotkey, block_number);
+        ensure!(
+            Self::recover_evm_address(&signature, &digest) == Some(evm_address),
+            Error::<T>::InvalidEvmKeySignature
+        );
+
+        AssociatedEvmAddress::<T>::insert(netuid, &hotkey, (evm_address, current_block));
+
+        log::info!(
+            "EvmKeyAssociated( netuid:{:?}, hotkey:{:?}, evm_address:{:?} )",
+            netuid,
+            hotkey,
+            evm_address
+        );
+        Self::deposit_event(Event::EvmKeyAssociated(netuid, hotkey, evm_address));
+        Ok(())
+    }
+
+    /// The digest the EVM key signs to be associated with `hotkey` on `netuid`: the keccak-256
+    /// of the SCALE payload, prefixed as an Ethereum personal message of 32 bytes and hashed
+    /// again, so wallets can produce it with `personal_sign`.
+    pub fn evm_key_association_digest(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        block_number: u64,
+    ) -> [u8; 32] {
+        let payload = (EVM_KEY_ASSOCIATION_CONTEXT, netuid, hotkey, block_number).encode();
+        let message = keccak_256(&payload);
+        keccak_256(
+            &[
+                b"\x19Ethereum Signed Message:\n32".as_slice(),
+                message.as_slice(),
+            ]
+            .concat(),
+        )
+    }
+
+    /// The EVM address whose key produced `signature` over `digest`. Accepts recovery ids
+    /// of 0/1 as well as the Ethereum 27/28.
+    pub fn recover_evm_address(signature: &[u8; 65], digest: &[u8; 32]) -> Option<H160> {
+        let mut signature = *signature;
+        if let Some(v) = signature.last_mut() {
+            if *v >= 27 {
+                *v = v.saturating_sub(27);
+            }
+        }
+        let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, digest).ok()?;
+        let hash = keccak_256(&public);
+        Some(H160::from_slice(hash.get(12..)?))
+    }
+
+    /// The EVM address associated with `hotkey` on `netuid`.
+    pub fn get_associated_evm_address(netuid: u16, hotkey: &T::AccountId) -> Option<H160> {
+        AssociatedEvmAddress::<T>::get(netuid, hotkey).map(|(evm_address, _)| evm_address)
+    }
+
+    /// Returns the EVM addresses associated with hotkeys registered on `netuid`, with the
+    /// block each association was made at.
+    pub fn get_evm_key_associations(netuid: u16) -> Vec<(T::AccountId, H160, u64)> {
+        AssociatedEvmAddress::<T>::iter_prefix(netuid)
+            .map(|(hotkey, (evm_address, block))| (hotkey, evm_address, block))
+            .collect()
+    }
+}
@@ -0,0 +1,139 @@
+use super::*;
+use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic sudo_ban_hotkey.
+    ///
+    /// Bans a hotkey from registering and receiving stake, deregisters it from every network
+    /// it holds a uid on so that it earns no more emission, removes it from the Senate, and
+    /// queues its stake to be returned to the staking coldkeys by `block_step`, at most
+    /// StakeWindDownChunkSize stakes per block. Until then the stake can be removed without
+    /// the unstake rate limit.
+    ///
+    /// # Raises:
+    /// * 'HotkeyAlreadyBanned':
+    ///     - The hotkey is already banned.
+    ///
+    pub fn do_ban_hotkey(hotkey: &T::AccountId) -> DispatchResult {
+        ensure!(
+            !Self::is_hotkey_banned(hotkey),
+            Error::<T>::HotkeyAlreadyBanned
+        );
+
+        BannedHotkeys::<T>::insert(hotkey, Self::get_current_block_as_u64());
+        HotkeysWindingDown::<T>::append(hotkey);
+
+        for netuid in Self::get_registered_networks_for_hotkey(hotkey) {
+            let Ok(uid) = Self::get_uid_for_net_and_hotkey(netuid, hotkey) else {
+                continue;
+            };
+            let moved_uid = Self::remove_neuron(netuid, uid);
+            Self::deposit_subnet_event(
+                &[netuid],
+                Event::NeuronUnregistered(netuid, uid, hotkey.clone(), 0),
+            );
+            if let Some(moved_uid) = moved_uid {
+                Self::deposit_subnet_event(
+                    &[netuid],
+                    Event::NeuronUidMoved(netuid, moved_uid, uid),
+                );
+            }
+        }
+        if T::SenateMembers::is_member(hotkey) {
+            T::SenateMembers::remove_member(hotkey).map_err(|e| e.error)?;
+        }
+
+        log::info!("HotkeyBanned( hotkey:{:?} )", hotkey);
+        Self::deposit_event(Event::HotkeyBanned(hotkey.clone()));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic sudo_unban_hotkey.
+    ///
+    /// Lifts the ban on a hotkey, which may register and receive stake again. The stake not
+    /// yet returned stays on the hotkey. The uids the hotkey held are not restored.
+    ///
+    /// # Raises:
+    /// * 'HotkeyNotBanned':
+    ///     - The hotkey is not banned.
+    ///
+    pub fn do_unban_hotkey(hotkey: &T::AccountId) -> DispatchResult {
+        ensure!(Self::is_hotkey_banned(hotkey), Error::<T>::HotkeyNotBanned);
+
+        BannedHotkeys::<T>::remove(hotkey);
+        HotkeysWindingDown::<T>::mutate(|winding_down| {
+            winding_down.retain(|winding_down_hotkey| winding_down_hotkey != hotkey)
+        });
+
+        log::info!("HotkeyUnbanned( hotkey:{:?} )", hotkey);
+        Self::deposit_event(Event::HotkeyUnbanned(hotkey.clone()));
+        Ok(())
+    }
+
+    pub fn is_hotkey_banned(hotkey: &T::AccountId) -> bool {
+        BannedHotkeys::<T>::contains_key(hotkey)
+    }
+
+    /// Returns the stake of banned hotkeys to the staking coldkeys, oldest ban first, and
+    /// removes a hotkey from the queue once it holds no more stake. Returns the weight used.
+    pub fn wind_down_banned_hotkeys() -> Weight {
+        let mut weight = T::DbWeight::get().reads(2);
+        let mut budget: usize = Self::get_stake_wind_down_chunk_size().into();
+        let mut winding_down = HotkeysWindingDown::<T>::get();
+        let mut done: usize = 0;
+
+        for hotkey in winding_down.iter() {
+            if budget == 0 {
+                break;
+            }
+            let stakes: Vec<(T::AccountId, u64)> = <Stake<T> as IterableStorageDoubleMap<
+                T::AccountId,
+                T::AccountId,
+                u64,
+            >>::iter_prefix(hotkey)
+            .take(budget.saturating_add(1))
+            .collect();
+            let finished = stakes.len() <= budget;
+
+            weight.saturating_accrue(
+                T::DbWeight::get().reads(u64::try_from(stakes.len()).unwrap_or(u64::MAX)),
+            );
+            for (coldkey, stake) in stakes.into_iter().take(budget) {
+                // The stake, totals and lock of the coldkey are updated and its balance credited.
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(6, 7));
+                Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, hotkey, stake);
+                StakeLocks::<T>::remove(hotkey, &coldkey);
+                Self::add_balance_to_coldkey_account(&coldkey, stake);
+                budget = budget.saturating_sub(1);
+                Self::deposit_event(Event::BannedHotkeyStakeReturned(
+                    hotkey.clone(),
+                    coldkey,
+                    stake,
+                ));
+            }
+
+            if !finished {
+                break;
+            }
+            done = done.saturating_add(1);
+            log::info!("BannedHotkeyWoundDown( hotkey:{:?} )", hotkey);
+            Self::deposit_event(Event::BannedHotkeyWoundDown(hotkey.clone()));
+        }
+
+        if done > 0 {
+            winding_down.drain(..done.min(winding_down.len()));
+            HotkeysWindingDown::<T>::put(winding_down);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight
+    }
+
+    pub fn get_stake_wind_down_chunk_size() -> u16 {
+        StakeWindDownChunkSize::<T>::get()
+    }
+    pub fn set_stake_wind_down_chunk_size(chunk_size: u16) {
+        StakeWindDownChunkSize::<T>::put(chunk_size);
+        Self::deposit_event(Event::StakeWindDownChunkSizeSet(chunk_size));
+    }
+}
@@ -0,0 +1,122 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_runtime::traits::Hash;
+
+impl<T: Config> Pallet<T> {
+    /// Pays the emission `hotkey` earned on `netuid` through to its nominators right away, or
+    /// accrues it in PendingHotkeyEmission until the hotkey's next payout block when
+    /// HotkeyEmissionTempo is set. Pending emission is paid out at the take of the hotkey, so
+    /// emission earned on a subnet capping the take below it is paid out right away. Returns
+    /// the amount paid out right away.
+    pub fn accrue_hotkey_emission(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        server_emission: u64,
+        validator_emission: u64,
+    ) -> u64 {
+        let take = Self::get_delegate_take_on_subnet(hotkey, netuid);
+        if Self::get_hotkey_emission_tempo() == 0
+            || take < Self::get_effective_delegate_take(hotkey)
+        {
+            Self::emit_inflation_through_hotkey_account_at_take(
+                hotkey,
+                take,
+                server_emission,
+                validator_emission,
+            );
+            return server_emission.saturating_add(validator_emission);
+        }
+
+        if !PendingHotkeyEmission::<T>::contains_key(hotkey) {
+            Self::schedule_hotkey_emission_payout(hotkey, Self::get_current_block_as_u64());
+        }
+        PendingHotkeyEmission::<T>::mutate(hotkey, |(server, validator)| {
+            *server = server.saturating_add(server_emission);
+            *validator = validator.saturating_add(validator_emission);
+        });
+        0
+    }
+
+    /// Pays out the pending emission of every hotkey whose payout block is `block_number`,
+    /// splitting the take from the nominators' share as `drain_emission` does. Only the hotkeys
+    /// bucketed under the block are read. Emission pending when the tempo is changed is paid
+    /// out at the block it was scheduled for. Returns the weight used.
+    pub fn drain_hotkey_emission(block_number: u64) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let due: Vec<T::AccountId> = HotkeyEmissionPayouts::<T>::drain_prefix(block_number)
+            .map(|(hotkey, ())| hotkey)
+            .collect();
+
+        let mut total_emitted: u64 = 0;
+        for hotkey in due {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            // The pending emission may have moved to a new hotkey since it was scheduled.
+            if !PendingHotkeyEmission::<T>::contains_key(&hotkey) {
+                continue;
+            }
+            let (server_emission, validator_emission) = PendingHotkeyEmission::<T>::take(&hotkey);
+            Self::emit_inflation_through_hotkey_account(
+                &hotkey,
+                server_emission,
+                validator_emission,
+            );
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(8, 6));
+            total_emitted = total_emitted
+                .saturating_add(server_emission)
+                .saturating_add(validator_emission);
+        }
+        if total_emitted > 0 {
+            TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(total_emitted));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+        weight
+    }
+
+    /// Buckets `hotkey` under its first payout block from `from_block` on, the next block when
+    /// no tempo is set.
+    pub fn schedule_hotkey_emission_payout(hotkey: &T::AccountId, from_block: u64) {
+        let tempo = Self::get_hotkey_emission_tempo();
+        let payout_block = if tempo == 0 {
+            from_block.saturating_add(1)
+        } else {
+            let phase = from_block
+                .wrapping_add(Self::get_hotkey_emission_offset(hotkey))
+                .checked_rem(tempo)
+                .unwrap_or(0);
+            let delay = tempo.saturating_sub(phase).checked_rem(tempo).unwrap_or(0);
+            from_block.saturating_add(delay)
+        };
+        HotkeyEmissionPayouts::<T>::insert(payout_block, hotkey, ());
+    }
+
+    /// Whether the pending emission of `hotkey` is paid out at `block_number`. Hotkeys are
+    /// paid every `tempo` blocks at an offset derived from the hotkey, so that payouts are
+    /// spread over the blocks of a tempo.
+    pub fn is_hotkey_emission_block(hotkey: &T::AccountId, block_number: u64, tempo: u64) -> bool {
+        block_number
+            .wrapping_add(Self::get_hotkey_emission_offset(hotkey))
+            .checked_rem(tempo)
+            == Some(0)
+    }
+
+    fn get_hotkey_emission_offset(hotkey: &T::AccountId) -> u64 {
+        let hash = T::Hashing::hash_of(hotkey);
+        hash.as_ref()
+            .get(..8)
+            .and_then(|bytes| <[u8; 8]>::try_from(bytes).ok())
+            .map(u64::from_le_bytes)
+            .unwrap_or(0)
+    }
+
+    pub fn get_pending_hotkey_emission(hotkey: &T::AccountId) -> (u64, u64) {
+        PendingHotkeyEmission::<T>::get(hotkey)
+    }
+
+    pub fn get_hotkey_emission_tempo() -> u64 {
+        HotkeyEmissionTempo::<T>::get()
+    }
+    pub fn set_hotkey_emission_tempo(tempo: u64) {
+        HotkeyEmissionTempo::<T>::put(tempo);
+        Self::deposit_event(Event::HotkeyEmissionTempoSet(tempo));
+    }
+}
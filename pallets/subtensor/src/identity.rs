@@ -0,0 +1,189 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_identity.
+    ///
+    /// The deposit is taken when the coldkey first sets an identity and kept as is while it
+    /// updates it, so changes to IdentityDeposit only apply to new identities.
+    ///
+    /// # Raises:
+    /// * 'InvalidIdentity':
+    ///     - A field of the identity is longer than its bound.
+    ///
+    /// * 'NotEnoughBalanceForIdentityDeposit':
+    ///     - The coldkey cannot pay the deposit.
+    ///
+    pub fn do_set_identity(origin: T::RuntimeOrigin, identity: ChainIdentity) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::is_valid_identity(&identity),
+            Error::<T>::InvalidIdentity
+        );
+
+        let held = Identities::<T>::get(&coldkey).map(|(_, deposit)| deposit);
+        if Self::is_empty_identity(&identity) {
+            if let Some(deposit) = held {
+                Identities::<T>::remove(&coldkey);
+                Self::add_balance_to_coldkey_account(&coldkey, deposit);
+                log::info!(
+                    "IdentityCleared( coldkey:{:?}, deposit:{:?} )",
+                    coldkey,
+                    deposit
+                );
+                Self::deposit_event(Event::IdentityCleared(coldkey, deposit));
+            }
+            return Ok(());
+        }
+
+        let deposit = match held {
+            Some(deposit) => deposit,
+            None => Self::take_identity_deposit(&coldkey)?,
+        };
+        Identities::<T>::insert(&coldkey, (identity, deposit));
+
+        log::info!(
+            "IdentitySet( coldkey:{:?}, deposit:{:?} )",
+            coldkey,
+            deposit
+        );
+        Self::deposit_event(Event::IdentitySet(coldkey, deposit));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic set_subnet_identity.
+    ///
+    /// The deposit is held from the owner that sets the identity. When the subnet changed
+    /// hands since, the previous owner is refunded and the deposit taken from the new one.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'NotSubnetOwner':
+    ///     - The caller is not the owner coldkey of the subnet.
+    ///
+    /// * 'InvalidIdentity':
+    ///     - A field of the identity is longer than its bound.
+    ///
+    /// * 'NotEnoughBalanceForIdentityDeposit':
+    ///     - The owner cannot pay the deposit.
+    ///
+    pub fn do_set_subnet_identity(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        identity: ChainIdentity,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            SubnetOwner::<T>::get(netuid) == coldkey,
+            Error::<T>::NotSubnetOwner
+        );
+        ensure!(
+            Self::is_valid_identity(&identity),
+            Error::<T>::InvalidIdentity
+        );
+
+        if Self::is_empty_identity(&identity) {
+            Self::clear_subnet_identity(netuid);
+            return Ok(());
+        }
+
+        let deposit = match SubnetIdentities::<T>::get(netuid) {
+            Some((_, depositor, deposit)) if depositor == coldkey => deposit,
+            Some((_, depositor, deposit)) => {
+                let taken = Self::take_identity_deposit(&coldkey)?;
+                Self::add_balance_to_coldkey_account(&depositor, deposit);
+                taken
+            }
+            None => Self::take_identity_deposit(&coldkey)?,
+        };
+        SubnetIdentities::<T>::insert(netuid, (identity, coldkey, deposit));
+
+        log::info!(
+            "SubnetIdentitySet( netuid:{:?}, deposit:{:?} )",
+            netuid,
+            deposit
+        );
+        Self::deposit_event(Event::SubnetIdentitySet(netuid, deposit));
+        Ok(())
+    }
+
+    /// Removes the identity of `netuid` and refunds its deposit to the owner that set it.
+    pub fn clear_subnet_identity(netuid: u16) {
+        if let Some((_, depositor, deposit)) = SubnetIdentities::<T>::take(netuid) {
+            Self::add_balance_to_coldkey_account(&depositor, deposit);
+            log::info!(
+                "SubnetIdentityCleared( netuid:{:?}, deposit:{:?} )",
+                netuid,
+                deposit
+            );
+            Self::deposit_event(Event::SubnetIdentityCleared(netuid, deposit));
+        }
+    }
+
+    /// Moves the identity of `old_coldkey` and its deposit to `new_coldkey`, along with the
+    /// deposits it holds for subnet identities. When the new coldkey has an identity of its
+    /// own, it is kept and the deposit of the old one refunded to it.
+    pub fn swap_identity_for_coldkey(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        weight: &mut Weight,
+    ) {
+        SubnetIdentities::<T>::translate_values(
+            |(identity, depositor, deposit): (ChainIdentity, T::AccountId, u64)| {
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+                if depositor == *old_coldkey {
+                    Some((identity, new_coldkey.clone(), deposit))
+                } else {
+                    Some((identity, depositor, deposit))
+                }
+            },
+        );
+
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        let Some((identity, deposit)) = Identities::<T>::take(old_coldkey) else {
+            return;
+        };
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        if Identities::<T>::contains_key(new_coldkey) {
+            Self::add_balance_to_coldkey_account(new_coldkey, deposit);
+        } else {
+            Identities::<T>::insert(new_coldkey, (identity, deposit));
+        }
+    }
+
+    fn take_identity_deposit(coldkey: &T::AccountId) -> Result<u64, DispatchError> {
+        let deposit = Self::get_identity_deposit();
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(coldkey, deposit),
+            Error::<T>::NotEnoughBalanceForIdentityDeposit
+        );
+        Self::remove_balance_from_coldkey_account(coldkey, deposit)
+    }
+
+    fn is_valid_identity(identity: &ChainIdentity) -> bool {
+        identity.name.len() <= MAX_IDENTITY_FIELD_BYTES
+            && identity.url.len() <= MAX_IDENTITY_FIELD_BYTES
+            && identity.image.len() <= MAX_IDENTITY_FIELD_BYTES
+            && identity.discord.len() <= MAX_IDENTITY_FIELD_BYTES
+            && identity.description.len() <= MAX_IDENTITY_DESCRIPTION_BYTES
+    }
+
+    fn is_empty_identity(identity: &ChainIdentity) -> bool {
+        *identity == ChainIdentity::default()
+    }
+
+    /// The identity of the coldkey, None when it has not set one.
+    pub fn get_identity(coldkey: &T::AccountId) -> Option<ChainIdentity> {
+        Identities::<T>::get(coldkey).map(|(identity, _)| identity)
+    }
+
+    /// The identity of the subnet, None when its owner has not set one.
+    pub fn get_subnet_identity(netuid: u16) -> Option<ChainIdentity> {
+        SubnetIdentities::<T>::get(netuid).map(|(identity, _, _)| identity)
+    }
+}
@@ -0,0 +1,96 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Checks the invariants the storage of the pallet holds after every block step,
+    /// returning the first one broken. Compiled in with the `strict-invariants` feature, for
+    /// testnets, where block_step runs it to catch corrupted state in the block it appears.
+    pub fn check_invariants() -> Result<(), &'static str> {
+        Self::check_uids_have_keys()?;
+        Self::check_emission_within_block_emission()?;
+        Self::check_weight_rows_within_subnet_size()?;
+        Self::check_total_hotkey_stake_matches_stake()?;
+        Ok(())
+    }
+
+    /// Runs `check_invariants` at the end of block_step, panicking on a broken invariant in
+    /// debug builds.
+    pub fn assert_invariants(block_number: u64) {
+        if let Err(e) = Self::check_invariants() {
+            log::error!(
+                "InvariantBroken( block_number:{:?}, invariant:{:?} )",
+                block_number,
+                e
+            );
+            #[cfg(debug_assertions)]
+            panic!("invariant broken at block {}: {}", block_number, e);
+        }
+    }
+
+    /// Every uid below the size of a subnet has a hotkey, which maps back to the uid.
+    fn check_uids_have_keys() -> Result<(), &'static str> {
+        for netuid in Self::get_all_subnet_netuids() {
+            for uid in 0..Self::get_subnetwork_n(netuid) {
+                let hotkey = Keys::<T>::try_get(netuid, uid).map_err(|_| "uid without a hotkey")?;
+                ensure!(
+                    Uids::<T>::get(netuid, &hotkey) == Some(uid),
+                    "hotkey not mapped back to its uid"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// The emission of the subnets adds up to no more than the block emission.
+    fn check_emission_within_block_emission() -> Result<(), &'static str> {
+        let block_emission = Self::get_block_emission()?;
+        let subnet_emission = Self::get_all_subnet_netuids()
+            .into_iter()
+            .fold(0u64, |total, netuid| {
+                total.saturating_add(EmissionValues::<T>::get(netuid))
+            });
+        ensure!(
+            subnet_emission <= block_emission,
+            "subnet emission exceeds the block emission"
+        );
+        Ok(())
+    }
+
+    /// No weight row of a subnet has more entries than the subnet has uids. Root weight rows
+    /// are over netuids and keep zeroed entries for removed subnets, so are not checked.
+    fn check_weight_rows_within_subnet_size() -> Result<(), &'static str> {
+        let root_netuid = Self::get_root_netuid();
+        for netuid in Self::get_all_subnet_netuids() {
+            if netuid == root_netuid {
+                continue;
+            }
+            let n = usize::from(Self::get_subnetwork_n(netuid));
+            for (_, row) in Weights::<T>::iter_prefix(netuid) {
+                ensure!(
+                    row.entries().len() <= n,
+                    "weight row longer than the subnet"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// TotalHotkeyStake, which the epochs read in place of summing Stake, equals the sum of
+    /// the stakes of each hotkey, and no hotkey with stake is missing from it.
+    fn check_total_hotkey_stake_matches_stake() -> Result<(), &'static str> {
+        for (hotkey, total) in TotalHotkeyStake::<T>::iter() {
+            let staked = Stake::<T>::iter_prefix_values(&hotkey)
+                .fold(0u64, |sum, stake| sum.saturating_add(stake));
+            ensure!(
+                total == staked,
+                "total hotkey stake differs from its stakes"
+            );
+        }
+        for (hotkey, _, stake) in Stake::<T>::iter() {
+            ensure!(
+                stake == 0 || TotalHotkeyStake::<T>::contains_key(&hotkey),
+                "staked hotkey without a total hotkey stake"
+            );
+        }
+        Ok(())
+    }
+}
@@ -4,7 +4,10 @@
 // Edit this file to define custom logic or remove it if it is not needed.
 // Learn more about FRAME and the core library of Substrate FRAME pallets:
 // <https://docs.substrate.io/reference/frame-pallets/>
+pub use netuid::NetUid;
 pub use pallet::*;
+pub use weight_info::WeightInfo;
+pub use weights_row::WeightsRow;
 
 use frame_system::{self as system, ensure_signed};
 
@@ -12,7 +15,7 @@ use frame_support::{
     dispatch::{self, DispatchInfo, DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo},
     ensure,
     pallet_macros::import_section,
-    traits::{tokens::fungible, IsSubType},
+    traits::{tokens::fungible, GetCallIndex, IsSubType},
 };
 
 use codec::{Decode, Encode};
@@ -30,34 +33,126 @@ use sp_std::marker::PhantomData;
 // ============================
 //	==== Benchmark Imports =====
 // ============================
+mod benchmark_load;
 mod benchmarks;
 
 // =========================
 //	==== Pallet Imports =====
 // =========================
+mod axon_attestation;
 mod block_step;
+mod bonds_reset;
+mod bootstrap_incentive;
+mod child_keys;
+mod delegate_take_cap;
+mod delegate_underperformance;
+mod emission_history;
+mod emission_multiplier;
+mod emission_split;
 pub mod epoch;
+mod epoch_pause;
+pub mod epoch_simulation;
+mod epoch_snapshot;
 mod errors;
 mod events;
+mod eviction_log;
+mod evm_key;
+mod hotkey_ban;
+mod hotkey_emission;
+#[cfg(feature = "identity")]
+mod identity;
+#[cfg(feature = "strict-invariants")]
+mod invariants;
 pub mod math;
+pub mod netuid;
+mod netuid_auction;
+mod owner_hyperparams;
+mod pending_withdrawals;
+mod recycle;
 mod registration;
 mod root;
+mod root_epoch_progress;
 mod serving;
+mod stake_alerts;
+mod stake_fee;
+mod stake_locks;
 mod staking;
+mod staking_pages;
+mod subnet_migration;
+mod subnet_ownership;
+mod subnet_referendum;
+mod subnet_token;
 mod swap;
+mod uid_growth;
 mod uids;
+mod utilization;
 mod utils;
+mod weight_setters;
 mod weights;
+mod weights_sweep;
 
+pub mod adjustment_info;
+pub mod call_metrics;
 pub mod delegate_info;
+pub mod emission_commitment;
+pub mod epoch_schedule;
+pub mod error_codes;
 pub mod neuron_info;
+pub mod pow_stats;
+pub mod rate_limit_info;
+pub mod root_info;
+pub mod runtime_features;
 pub mod stake_info;
+pub mod storage_stats;
+pub mod subnet_config_snapshot;
 pub mod subnet_info;
+pub mod subnet_state;
+pub mod validator_set;
+pub mod weight_info;
+pub mod weights_row;
 
 // apparently this is stabilized since rust 1.36
 extern crate alloc;
 pub mod migration;
 
+/// Bits of the per-subnet `SubnetFeatureFlags` bitfield.
+pub mod subnet_features {
+    /// Weights must be committed and then revealed rather than set directly.
+    pub const COMMIT_REVEAL_WEIGHTS: u32 = 1 << 0;
+    /// Bond EMAs use the consensus-driven liquid alpha instead of the fixed bonds moving average.
+    pub const LIQUID_ALPHA: u32 = 1 << 1;
+    /// Weights are stored sum-normalized to u16::MAX instead of max-upscaled, preserving
+    /// their magnitudes relative to other validators' rows.
+    pub const SUM_NORMALIZED_WEIGHTS: u32 = 1 << 2;
+    /// Validators that lose their permit in an epoch earn no dividends from their bonds in
+    /// that epoch, instead of one last payout before the bonds are cleared.
+    pub const RESET_BONDS_ON_PERMIT_LOSS: u32 = 1 << 3;
+    /// Weights must be set for the subnet's next epoch by index, and the epoch only counts
+    /// the weights set for it.
+    pub const TARGET_EPOCH_WEIGHTS: u32 = 1 << 4;
+    /// A hotkey re-registering after being pruned reclaims its previous uid when the slot is
+    /// free, so the uid stays a stable handle for the hotkey.
+    pub const PERSISTENT_UIDS: u32 = 1 << 5;
+    /// A registration on a full subnet replaces a neuron drawn at random among those past
+    /// their immunity period, rather than the one with the lowest pruning score.
+    pub const REGISTRATION_LOTTERY: u32 = 1 << 6;
+    /// The epoch counts the weight a validator sets on its own uid instead of masking it.
+    pub const UNMASKED_SELF_WEIGHTS: u32 = 1 << 7;
+    /// The burn follows the registration pressure every adjustment interval, however the
+    /// registrations split between pow and burn, instead of only when burn registrations lead.
+    pub const BURN_EMA: u32 = 1 << 8;
+    /// Every flag understood by this runtime.
+    pub const ALL: u32 = COMMIT_REVEAL_WEIGHTS
+        | LIQUID_ALPHA
+        | SUM_NORMALIZED_WEIGHTS
+        | RESET_BONDS_ON_PERMIT_LOSS
+        | TARGET_EPOCH_WEIGHTS
+        | PERSISTENT_UIDS
+        | REGISTRATION_LOTTERY
+        | UNMASKED_SELF_WEIGHTS
+        | BURN_EMA;
+}
+
 #[deny(missing_docs)]
 #[import_section(errors::errors)]
 #[import_section(events::events)]
@@ -70,8 +165,8 @@ pub mod pallet {
         traits::{tokens::fungible, UnfilteredDispatchable},
     };
     use frame_system::pallet_prelude::*;
-    use sp_core::H256;
-    use sp_runtime::traits::TrailingZeroInput;
+    use sp_core::{H160, H256};
+    use sp_runtime::traits::{IdentifyAccount, TrailingZeroInput, Verify};
     use sp_std::vec;
     use sp_std::vec::Vec;
 
@@ -84,11 +179,111 @@ pub mod pallet {
 
     /// Tracks version for migrations. Should be monotonic with respect to the
     /// order of migrations. (i.e. always increasing)
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
 
     /// Minimum balance required to perform a coldkey swap
     pub const MIN_BALANCE_TO_PERFORM_COLDKEY_SWAP: u64 = 100_000_000; // 0.1 TAO in RAO
 
+    /// Maximum number of coldkeys a coldkey can have swaps scheduled to.
+    pub const MAX_COLDKEY_DESTINATIONS: usize = 10;
+
+    /// Maximum number of netuids reserved for auctions at once.
+    pub const MAX_RESERVED_NETUIDS: usize = 32;
+
+    /// Blocks after the end of a netuid auction its winner has to claim the netuid, one day
+    /// at 12 second blocks. The reservation then expires and the winning bid is refunded.
+    pub const NETUID_CLAIM_PERIOD: u64 = 7_200;
+
+    /// Maximum number of axon endpoints a neuron can serve on a subnet, including the primary one.
+    pub const MAX_AXONS_PER_NEURON: u32 = 8;
+
+    /// Maximum size in bytes of a miner commitment, per-subnet limits are capped at this.
+    pub const MAX_COMMITMENT_BYTES: u32 = 1024;
+
+    /// Maximum size in bytes of the TLS public key or certificate fingerprint of a neuron.
+    pub const MAX_NEURON_CERTIFICATE_BYTES: u32 = 64;
+
+    /// Maximum size in bytes of each of the name, url, image and discord of an identity.
+    pub const MAX_IDENTITY_FIELD_BYTES: usize = 256;
+
+    /// Maximum size in bytes of the description of an identity.
+    pub const MAX_IDENTITY_DESCRIPTION_BYTES: usize = 1024;
+
+    /// Maximum size in bytes of a subnet's token symbol.
+    pub const MAX_SUBNET_SYMBOL_BYTES: u32 = 16;
+
+    /// Maximum size in bytes of a subnet's token name.
+    pub const MAX_SUBNET_TOKEN_NAME_BYTES: u32 = 64;
+
+    /// Maximum number of stake thresholds a coldkey can subscribe to at once.
+    pub const MAX_STAKE_SUBSCRIPTIONS_PER_COLDKEY: u32 = 8;
+
+    /// Maximum number of stake threshold subscriptions block_step checks in a block.
+    pub const MAX_STAKE_ALERT_CHECKS_PER_BLOCK: u32 = 64;
+
+    /// Maximum number of delegates the take cap tally and the underperformance check each
+    /// process in a block.
+    pub const MAX_DELEGATES_CHECKED_PER_BLOCK: u32 = 64;
+
+    /// Maximum number of child keys a hotkey can pass its stake on a subnet to.
+    pub const MAX_CHILDREN: u32 = 5;
+
+    /// Maximum number of parent keys passing stake to a hotkey on a subnet.
+    pub const MAX_PARENTS: u32 = 64;
+
+    /// Maximum number of subnets weights can be set on in one `batch_set_weights` call, or
+    /// purged from in one `purge_my_weights` call.
+    pub const MAX_WEIGHTS_BATCH_SIZE: u32 = 32;
+
+    /// Maximum number of nominations one `clear_small_nominations` call can list.
+    pub const MAX_NOMINATIONS_CLEARED_PER_CALL: u32 = 64;
+
+    /// Maximum number of neurons returned in one page of `get_neurons_paginated` or
+    /// `get_neurons_lite_paginated`.
+    pub const MAX_NEURONS_PAGE_SIZE: u16 = 256;
+
+    /// Number of epoch snapshots kept per subnet, older ones are dropped first.
+    pub const EPOCH_SNAPSHOT_HISTORY: usize = 32;
+
+    /// Number of root epoch emission values kept per subnet, older ones are dropped first.
+    pub const EMISSION_HISTORY_LENGTH: u32 = 64;
+
+    /// Number of replaced neurons kept in the eviction log of a subnet, older ones are
+    /// dropped first.
+    pub const EVICTION_LOG_LENGTH: u32 = 256;
+
+    /// Basis points of an EmissionMultiplier leaving the emission of a subnet unchanged.
+    pub const EMISSION_MULTIPLIER_BPS: u32 = 10_000;
+
+    /// Maximum EmissionMultiplier of a subnet, ten times its emission.
+    pub const MAX_EMISSION_MULTIPLIER: u32 = 100_000;
+
+    /// Number of adjustment interval PoW averages kept per subnet, older ones are dropped first.
+    pub const POW_STATS_INTERVALS: u32 = 32;
+
+    /// Number of blocks after the signed block within which an EVM key association proof is accepted.
+    pub const EVM_KEY_SIGNATURE_VALIDITY: u64 = 100;
+
+    /// Number of hotkeys in a page of the hotkeys a coldkey stakes to.
+    pub const STAKING_HOTKEYS_PAGE_SIZE: u32 = 64;
+
+    /// Longest a stake can be locked for, about two years at 12 second blocks.
+    pub const MAX_STAKE_LOCK_BLOCKS: u64 = 5_256_000;
+
+    /// Maximum number of ( lock_blocks, multiplier ) tiers in StakeLockBoosts.
+    pub const MAX_STAKE_LOCK_BOOSTS: usize = 8;
+
+    /// Maximum dividend multiplier of locked stake in basis points, five times its weight.
+    pub const MAX_STAKE_LOCK_BOOST: u32 = 50_000;
+
+    /// Maximum number of unclaimed withdrawals a coldkey can have waiting out the unstake
+    /// cooldown.
+    pub const MAX_PENDING_WITHDRAWALS: usize = 16;
+
+    /// Maximum number of ( hotkey, amount ) entries in add_stake_multiple and
+    /// remove_stake_multiple.
+    pub const MAX_STAKE_BATCH_SIZE: u32 = 32;
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -118,6 +313,18 @@ pub mod pallet {
         /// Interface to allow other pallets to control who can register identities
         type TriumvirateInterface: crate::CollectiveInterface<Self::AccountId, Self::Hash, u32>;
 
+        /// Hooks of other pallets following registrations, stake changes, epochs and subnets.
+        type OnSubtensorEvent: crate::OnSubtensorEvent<Self::AccountId>;
+
+        /// Signature by which a coldkey proves possession of its key.
+        type KeySignature: Parameter + Verify<Signer = Self::KeySigner>;
+
+        /// Signer of a KeySignature, identifying the account it signs for.
+        type KeySigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Weights of the epochs, scaling with the size of the subnets.
+        type WeightInfo: crate::WeightInfo;
+
         /// =================================
         /// ==== Initial Value Constants ====
         /// =================================
@@ -260,6 +467,48 @@ pub mod pallet {
         /// The base difficulty for proof of work for coldkey swaps
         #[pallet::constant]
         type InitialBaseDifficulty: Get<u64>;
+        /// Tempo of the root network when it is created.
+        #[pallet::constant]
+        type InitialRootTempo: Get<u16>;
+        /// Max uids of the root network, and of its validators, when it is created.
+        #[pallet::constant]
+        type InitialRootMaxUids: Get<u16>;
+        /// Tempo of a network registered by a coldkey.
+        #[pallet::constant]
+        type NewNetworkTempo: Get<u16>;
+        /// Max allowed uids of a new network.
+        #[pallet::constant]
+        type NewNetworkMaxAllowedUids: Get<u16>;
+        /// Max allowed validators of a new network.
+        #[pallet::constant]
+        type NewNetworkMaxAllowedValidators: Get<u16>;
+        /// Min allowed weights of a new network.
+        #[pallet::constant]
+        type NewNetworkMinAllowedWeights: Get<u16>;
+        /// Registration adjustment interval of a new network.
+        #[pallet::constant]
+        type NewNetworkAdjustmentInterval: Get<u16>;
+        /// Target registrations per interval of a new network.
+        #[pallet::constant]
+        type NewNetworkTargetRegistrationsPerInterval: Get<u16>;
+        /// Registration adjustment alpha of a new network.
+        #[pallet::constant]
+        type NewNetworkAdjustmentAlpha: Get<u64>;
+        /// Immunity period of a new network.
+        #[pallet::constant]
+        type NewNetworkImmunityPeriod: Get<u16>;
+        /// Min burn of a new network.
+        #[pallet::constant]
+        type NewNetworkMinBurn: Get<u64>;
+    }
+
+    #[pallet::extra_constants]
+    impl<T: Config> Pallet<T> {
+        /// The netuid of the root network.
+        #[pallet::constant_name(RootNetuid)]
+        pub fn root_netuid() -> u16 {
+            Self::get_root_netuid()
+        }
     }
 
     /// Alias for the account ID.
@@ -330,11 +579,41 @@ pub mod pallet {
     pub fn DefaultTargetStakesPerInterval<T: Config>() -> u64 {
         T::InitialTargetStakesPerInterval::get()
     }
+    /// Default number of stakes of banned hotkeys returned per block.
+    #[pallet::type_value]
+    pub fn DefaultStakeWindDownChunkSize<T: Config>() -> u16 {
+        64
+    }
+    /// Default number of weight rows checked per block after MinAllowedWeights is raised.
+    #[pallet::type_value]
+    pub fn DefaultWeightsSweepChunkSize<T: Config>() -> u16 {
+        64
+    }
+    /// Default number of bonds rows cleared per block by a bonds reset.
+    #[pallet::type_value]
+    pub fn DefaultBondsResetChunkSize<T: Config>() -> u16 {
+        64
+    }
+    /// Default work per block of the root epoch, stakes read plus root weights accumulated.
+    #[pallet::type_value]
+    pub fn DefaultRootEpochBlockBudget<T: Config>() -> u32 {
+        65_536
+    }
+    /// Default cap on the share of root stake of a validator, uncapped.
+    #[pallet::type_value]
+    pub fn DefaultRootStakeCap<T: Config>() -> u16 {
+        u16::MAX
+    }
     /// Default stake interval.
     #[pallet::type_value]
     pub fn DefaultStakeInterval<T: Config>() -> u64 {
         360
     }
+    /// Default balance kept back for fees, 0.001 TAO in RAO.
+    #[pallet::type_value]
+    pub fn DefaultFeeReserve<T: Config>() -> u64 {
+        1_000_000
+    }
 
     /// Default base difficulty for proof of work for coldkey swaps
     #[pallet::type_value]
@@ -360,12 +639,65 @@ pub mod pallet {
     pub type BaseDifficulty<T> = StorageValue<_, u64, ValueQuery, DefaultBaseDifficulty<T>>;
     #[pallet::storage] // --- ITEM (default_stake_interval)
     pub type StakeInterval<T> = StorageValue<_, u64, ValueQuery, DefaultStakeInterval<T>>;
+    #[pallet::storage] // --- ITEM ( fee_reserve )
+    pub type FeeReserve<T> = StorageValue<_, u64, ValueQuery, DefaultFeeReserve<T>>;
+    #[pallet::storage] // --- MAP ( cold ) --> keep_fee_reserve | Whether staking and network locks must leave the fee reserve on the coldkey.
+    pub type KeepFeeReserve<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( stake_fee_flat ) | Flat part of the fee charged on adding and removing stake.
+    pub type StakeFeeFlat<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( stake_fee_bps ) | Part of the fee proportional to the amount staked or unstaked, in basis points.
+    pub type StakeFeeBps<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( stake_fee_dust_threshold ) | Amounts below this are staked and unstaked without a fee.
+    pub type StakeFeeDustThreshold<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( stake_fee_treasury ) | Account receiving stake fees, which are recycled when unset.
+    pub type StakeFeeTreasury<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+    #[pallet::storage] // --- ITEM ( total_stake_fees ) | Stake fees charged since genesis.
+    pub type TotalStakeFees<T> = StorageValue<_, u64, ValueQuery>;
     #[pallet::storage] // --- MAP ( hot ) --> stake | Returns the total amount of stake under a hotkey.
     pub type TotalHotkeyStake<T: Config> =
         StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultAccountTake<T>>;
     #[pallet::storage] // --- MAP ( cold ) --> stake | Returns the total amount of stake under a coldkey.
     pub type TotalColdkeyStake<T: Config> =
         StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultAccountTake<T>>;
+    #[pallet::storage] // --- MAP ( cold ) --> emission | Lifetime emission received by a coldkey.
+    pub type ColdkeyEmissionTotals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ColdkeyEmission, ValueQuery>;
+    #[pallet::storage] // --- MAP ( hot ) --> ban_block | Hotkeys banned by governance from registering and receiving stake.
+    pub type BannedHotkeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+    #[pallet::storage] // --- ITEM ( hotkeys ) | Banned hotkeys whose stake is still being returned, oldest ban first.
+    pub type HotkeysWindingDown<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( hotkey_emission_tempo ) | Blocks between payouts of hotkey emission, 0 pays out every drain.
+    pub type HotkeyEmissionTempo<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- MAP ( hotkey ) --> ( server_emission, validator_emission ) | Emission accrued until the hotkey's next payout.
+    pub type PendingHotkeyEmission<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u64, u64), ValueQuery>;
+    #[pallet::storage] // --- DMAP ( payout_block, hotkey ) --> () | Hotkeys with pending emission, by the block paying it out.
+    pub type HotkeyEmissionPayouts<T: Config> =
+        StorageDoubleMap<_, Identity, u64, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+    #[pallet::storage] // --- ITEM ( stake_wind_down_chunk_size ) | Stakes of banned hotkeys returned per block.
+    pub type StakeWindDownChunkSize<T> =
+        StorageValue<_, u16, ValueQuery, DefaultStakeWindDownChunkSize<T>>;
+    #[pallet::storage] // --- ITEM ( weights_sweep_chunk_size ) | Weight rows checked per block by the sweep.
+    pub type WeightsSweepChunkSize<T> =
+        StorageValue<_, u16, ValueQuery, DefaultWeightsSweepChunkSize<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> next_uid | Next uid whose weights the sweep checks against MinAllowedWeights.
+    pub type WeightsSweepCursor<T> = StorageMap<_, Identity, u16, u16, OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> () | Subnets whose weight rows the sweep rewrites into their new representation.
+    pub type WeightsRescalePending<T> = StorageMap<_, Identity, u16, (), OptionQuery>;
+    #[pallet::storage] // --- ITEM ( bonds_reset_chunk_size ) | Bonds rows cleared per block by a bonds reset.
+    pub type BondsResetChunkSize<T> =
+        StorageValue<_, u16, ValueQuery, DefaultBondsResetChunkSize<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> next_uid | Next uid whose bonds the reset clears, the subnet's epochs wait while set.
+    pub type BondsResetCursor<T> = StorageMap<_, Identity, u16, u16, OptionQuery>;
+    #[pallet::storage] // --- ITEM ( root_epoch_block_budget ) | Stakes read plus root weights accumulated per block by the root epoch.
+    pub type RootEpochBlockBudget<T> =
+        StorageValue<_, u32, ValueQuery, DefaultRootEpochBlockBudget<T>>;
+    #[pallet::storage] // --- ITEM ( root_epoch_progress ) | The root epoch running over several blocks, None between epochs.
+    pub type RootEpochInProgress<T> = StorageValue<_, RootEpochProgress, OptionQuery>;
+    #[pallet::storage] // --- ITEM ( root_stake_cap ) | Share of the root stake, over u16::MAX, a validator ranks subnets with at most.
+    pub type RootStakeCap<T> = StorageValue<_, u16, ValueQuery, DefaultRootStakeCap<T>>;
     #[pallet::storage]
     ///  MAP (hot, cold) --> stake | Returns a tuple (u64: stakes, u64: block_number)
     pub type TotalHotkeyColdkeyStakesThisInterval<T: Config> = StorageDoubleMap<
@@ -384,12 +716,59 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( cold ) --> Vec<hot> | Returns the vector of hotkeys controlled by this coldkey.
     pub type OwnedHotkeys<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
-    #[pallet::storage] // --- DMAP ( cold ) --> Vec<hot> | Maps coldkey to hotkeys that stake to it
+    #[pallet::storage] // --- DMAP ( cold ) --> Vec<hot> | Deprecated, the hotkeys a coldkey stakes to before they were paged into StakingHotkeyPages.
     pub type StakingHotkeys<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( cold, page ) --> Vec<hot> | A page of the hotkeys a coldkey stakes to.
+    pub type StakingHotkeyPages<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        u32,
+        BoundedVec<T::AccountId, ConstU32<STAKING_HOTKEYS_PAGE_SIZE>>,
+        ValueQuery,
+    >;
+    #[pallet::storage] // --- MAP ( cold ) --> count | Number of StakingHotkeyPages of the coldkey.
+    pub type StakingHotkeyPageCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( cold, hot ) --> page | The StakingHotkeyPages page of the coldkey listing the hotkey.
+    pub type StakingHotkeyPage<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- DMAP ( hot, cold ) --> threshold | Alerts the coldkey once the hotkey's total stake falls below the threshold.
+    pub type StakeThresholdSubscriptions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- MAP ( cold ) --> count | Number of stake thresholds the coldkey subscribes to.
+    pub type StakeSubscriptionCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+    #[pallet::storage] // --- MAP ( hot ) --> () | Subscribed hotkeys whose total stake fell since their thresholds were last checked.
+    pub type StakeAlertHotkeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+    #[pallet::storage] // --- ITEM ( hotkey, raw_key ) | Subscription of the hotkey the threshold checks resume after.
+    pub type StakeAlertCursor<T: Config> = StorageValue<_, (T::AccountId, Vec<u8>), OptionQuery>;
     #[pallet::storage] // --- MAP ( hot ) --> take | Returns the hotkey delegation take. And signals that this key is open for delegation.
     pub type Delegates<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery, DefaultDefaultTake<T>>;
+    #[pallet::storage] // --- MAP ( hot ) --> count | Returns the number of coldkeys with a non-zero stake on a hotkey.
+    pub type DelegateNominatorCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+    #[pallet::storage] // --- MAP ( hot ) --> stake | Returns the stake on a hotkey from coldkeys other than its owner.
+    pub type DelegateTotalStake<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
     #[pallet::storage] // --- DMAP ( hot, cold ) --> stake | Returns the stake under a coldkey prefixed by hotkey.
     pub type Stake<T: Config> = StorageDoubleMap<
         _,
@@ -432,15 +811,6 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( u64 ) --> Vec<coldkeys_to_drain>  | Coldkeys to drain on the specific block.
     pub type ColdkeysToSwapAtBlock<T: Config> =
         StorageMap<_, Identity, u64, Vec<T::AccountId>, ValueQuery, EmptyAccounts<T>>;
-    /// -- ITEM (switches liquid alpha on)
-    #[pallet::type_value]
-    pub fn DefaultLiquidAlpha<T: Config>() -> bool {
-        false
-    }
-    #[pallet::storage] // --- MAP ( netuid ) --> Whether or not Liquid Alpha is enabled
-    pub type LiquidAlphaOn<T> =
-        StorageMap<_, Blake2_128Concat, u16, bool, ValueQuery, DefaultLiquidAlpha<T>>;
-
     /// =====================================
     /// ==== Difficulty / Registrations =====
     /// =====================================
@@ -490,14 +860,31 @@ pub mod pallet {
     pub fn DefaultMaxRegistrationsPerBlock<T: Config>() -> u16 {
         T::InitialMaxRegistrationsPerBlock::get()
     }
+    /// Default max PoW or burned registrations per block, leaving only the total limit.
+    #[pallet::type_value]
+    pub fn DefaultMaxRouteRegistrationsPerBlock<T: Config>() -> u16 {
+        u16::MAX
+    }
     /// Default RAO recycled for registration.
     #[pallet::type_value]
     pub fn DefaultRAORecycledForRegistration<T: Config>() -> u64 {
         T::InitialRAORecycledForRegistration::get()
     }
 
+    /// Default number of blocks a coldkey waits between faucet calls.
+    /// The limit is 1 day, assuming a block time of 12 seconds.
+    #[pallet::type_value]
+    pub fn DefaultFaucetRateLimit<T: Config>() -> u64 {
+        7200
+    }
+
     #[pallet::storage] // ---- StorageItem Global Used Work.
     pub type UsedWork<T: Config> = StorageMap<_, Identity, Vec<u8>, u64, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( faucet_rate_limit )
+    pub type FaucetRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultFaucetRateLimit<T>>;
+    #[pallet::storage] // --- MAP ( coldkey ) --> last_faucet_block
+    pub type LastFaucetBlock<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> Burn
     pub type Burn<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultBurn<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> Difficulty
@@ -521,9 +908,25 @@ pub mod pallet {
     #[pallet::storage] // --- ITEM( global_max_registrations_per_block )
     pub type MaxRegistrationsPerBlock<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxRegistrationsPerBlock<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> PoW registrations of this Block.
+    pub type POWRegistrationsThisBlock<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultRegistrationsThisBlock<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> Burned registrations of this Block.
+    pub type BurnRegistrationsThisBlock<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultRegistrationsThisBlock<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> max PoW registrations per block
+    pub type MaxPOWRegistrationsPerBlock<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxRouteRegistrationsPerBlock<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> max burned registrations per block
+    pub type MaxBurnRegistrationsPerBlock<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxRouteRegistrationsPerBlock<T>>;
     #[pallet::storage] // --- MAP ( netuid, global_RAO_recycled_for_registration )
     pub type RAORecycledForRegistration<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultRAORecycledForRegistration<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> RAO recycled into the subnet's emission pool with `recycle`.
+    pub type RAORecycledIntoEmission<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( total_rao_burned ) | RAO removed from the issuance with `burn`.
+    pub type TotalRAOBurned<T> = StorageValue<_, u64, ValueQuery>;
 
     /// ==============================
     /// ==== Subnetworks Storage =====
@@ -594,6 +997,11 @@ pub mod pallet {
     pub fn DefaultNetworkLockReductionInterval<T: Config>() -> u64 {
         T::InitialNetworkLockReductionInterval::get()
     }
+    /// Default factor the lock cost is raised by on each network registration, doubling it.
+    #[pallet::type_value]
+    pub fn DefaultNetworkLockCostMultiplier<T: Config>() -> u32 {
+        20_000
+    }
     /// Default value for subnet owner cut.
     #[pallet::type_value]
     pub fn DefaultSubnetOwnerCut<T: Config>() -> u16 {
@@ -616,6 +1024,24 @@ pub mod pallet {
 
     #[pallet::storage] // --- ITEM( maximum_number_of_networks )
     pub type SubnetLimit<T> = StorageValue<_, u16, ValueQuery, DefaultSubnetLimit<T>>;
+    /// Default delay before an announced subnet limit is applied.
+    /// The delay is 7 days, assuming a block time of 12 seconds.
+    #[pallet::type_value]
+    pub fn DefaultSubnetLimitChangeDelay<T: Config>() -> u64 {
+        7200 * 7
+    }
+    #[pallet::storage] // --- ITEM( subnet_limit_change_delay )
+    pub type SubnetLimitChangeDelay<T> =
+        StorageValue<_, u64, ValueQuery, DefaultSubnetLimitChangeDelay<T>>;
+    #[pallet::storage] // --- MAP ( block ) --> subnet_limit | The subnet limit announced to apply at a block.
+    pub type ScheduledSubnetLimits<T> = StorageMap<_, Identity, u64, u16, OptionQuery>;
+    /// Default value for the maximum number of root network validators.
+    #[pallet::type_value]
+    pub fn DefaultRootMaxUids<T: Config>() -> u16 {
+        T::InitialRootMaxUids::get()
+    }
+    #[pallet::storage] // --- ITEM( maximum_number_of_root_validators )
+    pub type RootMaxUids<T> = StorageValue<_, u16, ValueQuery, DefaultRootMaxUids<T>>;
     #[pallet::storage] // --- ITEM( total_number_of_existing_networks )
     pub type TotalNetworks<T> = StorageValue<_, u16, ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> subnetwork_n (Number of UIDs in the network).
@@ -624,7 +1050,9 @@ pub mod pallet {
     pub type NetworkModality<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultModality<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> network_is_added
     pub type NetworksAdded<T: Config> =
-        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultNeworksAdded<T>>;
+        StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultNeworksAdded<T>>;
+    #[pallet::storage] // --- ITEM( netuids ) | The netuids of NetworksAdded in ascending order, read once where all networks are visited.
+    pub type SubnetNetuids<T> = StorageValue<_, Vec<u16>, ValueQuery>;
     #[pallet::storage] // --- DMAP ( hotkey, netuid ) --> bool
     pub type IsNetworkMember<T: Config> = StorageDoubleMap<
         _,
@@ -662,10 +1090,24 @@ pub mod pallet {
     #[pallet::storage] // ITEM( network_lock_reduction_interval )
     pub type NetworkLockReductionInterval<T> =
         StorageValue<_, u64, ValueQuery, DefaultNetworkLockReductionInterval<T>>;
+    #[pallet::storage] // ITEM( network_lock_cost_multiplier ) | Factor in basis points the lock cost is raised by on each network registration.
+    pub type NetworkLockCostMultiplier<T> =
+        StorageValue<_, u32, ValueQuery, DefaultNetworkLockCostMultiplier<T>>;
     #[pallet::storage] // ITEM( subnet_owner_cut )
     pub type SubnetOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultSubnetOwnerCut<T>>;
     #[pallet::storage] // ITEM( network_rate_limit )
     pub type NetworkRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultNetworkRateLimit<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> ( min_bid, auction_end_block ) | Netuids skipped by sequential assignment.
+    pub type ReservedNetuids<T> = StorageMap<_, Identity, u16, (u64, u64), OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> ( bidder, escrowed_amount ) | Highest bid on a reserved netuid.
+    pub type NetuidBids<T: Config> = StorageMap<_, Identity, u16, (T::AccountId, u64), OptionQuery>;
+    #[pallet::storage] // ITEM( netuid_quarantine_period ) | Blocks a removed netuid is held back from reassignment, 0 reusing it immediately.
+    pub type NetuidQuarantinePeriod<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> ( removed_at_block, owner ) | Netuids removed and not registered again since.
+    pub type NetuidTombstones<T: Config> =
+        StorageMap<_, Identity, u16, (u64, T::AccountId), OptionQuery>;
+    #[pallet::storage] // --- ITEM ( subnet_migration ) | The subnet moving to another netuid, None when none is.
+    pub type SubnetMigrationInProgress<T> = StorageValue<_, SubnetMigration, OptionQuery>;
     #[pallet::storage] // ITEM( nominator_min_required_stake )
     pub type NominatorMinRequiredStake<T> =
         StorageValue<_, u64, ValueQuery, DefaultNominatorMinRequiredStake<T>>;
@@ -716,6 +1158,20 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> emission_values
     pub type EmissionValues<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultEmissionValues<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> utilization | Fraction of uids active within the activity cutoff, recorded after the root epoch.
+    pub type SubnetUtilization<T> = StorageMap<_, Identity, u16, u16, ValueQuery>;
+    /// Default emission multiplier of a subnet, leaving its emission unchanged.
+    #[pallet::type_value]
+    pub fn DefaultEmissionMultiplier<T: Config>() -> u32 {
+        EMISSION_MULTIPLIER_BPS
+    }
+    #[pallet::storage] // --- MAP ( netuid ) --> emission_multiplier | Factor in basis points the emission values set by the root epoch are scaled by.
+    pub type EmissionMultiplier<T> =
+        StorageMap<_, Identity, u16, u32, ValueQuery, DefaultEmissionMultiplier<T>>;
+    #[pallet::storage] // --- ITEM ( utilization_floor ) | Subnets below this utilization have part of their emission burned.
+    pub type UtilizationFloor<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( low_utilization_burn ) | Fraction of the emission burned for subnets below the utilization floor.
+    pub type LowUtilizationBurn<T> = StorageValue<_, u16, ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> pending_emission
     pub type PendingEmission<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultPendingEmission<T>>;
@@ -725,13 +1181,143 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> last_mechanism_step_block
     pub type LastMechansimStepBlock<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultLastMechanismStepBlock<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> epoch_index | Index of the subnet's next epoch, the number of epochs it ran.
+    pub type EpochIndex<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> subnet_owner
     pub type SubnetOwner<T: Config> =
         StorageMap<_, Identity, u16, T::AccountId, ValueQuery, DefaultSubnetOwner<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> subnet_owner_hotkey | Operator key that may act for the subnet owner.
+    pub type SubnetOwnerHotkey<T: Config> = StorageMap<_, Identity, u16, T::AccountId, OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> pending_owner | Coldkey the owner proposed to transfer the subnet to, until it accepts.
+    pub type PendingSubnetOwner<T: Config> =
+        StorageMap<_, Identity, u16, T::AccountId, OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> rotation_nonce | Number of times the subnet changed hands, signed into owner key rotations.
+    pub type SubnetOwnerRotationNonce<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> symbol | Token symbol of the subnet, unique across subnets.
+    pub type SubnetSymbol<T> = StorageMap<
+        _,
+        Identity,
+        u16,
+        BoundedVec<u8, ConstU32<MAX_SUBNET_SYMBOL_BYTES>>,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- MAP ( netuid ) --> token_name
+    pub type SubnetTokenName<T> = StorageMap<
+        _,
+        Identity,
+        u16,
+        BoundedVec<u8, ConstU32<MAX_SUBNET_TOKEN_NAME_BYTES>>,
+        OptionQuery,
+    >;
     #[pallet::storage] // --- MAP ( netuid ) --> subnet_locked
     pub type SubnetLocked<T: Config> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultSubnetLocked<T>>;
 
+    /// Lifetime emission received by a coldkey, split by its source.
+    #[freeze_struct("d0fc39ca8a5c0185")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ColdkeyEmission {
+        /// Mining incentive earned by the coldkey's hotkeys.
+        #[codec(compact)]
+        pub incentive: u64,
+        /// Validator dividends, earned as a validator or as a nominator.
+        #[codec(compact)]
+        pub dividends: u64,
+        /// Take kept from the nominators of the coldkey's delegates.
+        #[codec(compact)]
+        pub delegate_take: u64,
+    }
+
+    /// A root epoch spread over blocks, kept between them until its emission values are set.
+    /// Fixed point values are stored as the bits of their I64F64.
+    #[freeze_struct("da64781295a0df31")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct RootEpochProgress {
+        /// Subnets receiving emission, in `get_all_subnet_netuids` order.
+        pub netuids: Vec<u16>,
+        /// Number of root validators at the start of the epoch.
+        pub validators: u16,
+        /// Block emission at the start of the epoch.
+        pub block_emission: u64,
+        /// Total stake of each root uid collected so far.
+        pub stakes: Vec<u64>,
+        /// Root uid whose weights are accumulated next, once every stake is collected.
+        pub next_row: u16,
+        /// Rank of each subnet accumulated so far.
+        pub ranks: Vec<i128>,
+        /// Trust of each subnet accumulated so far.
+        pub trust: Vec<i128>,
+        /// Normalized stake of the rows accumulated so far.
+        pub total_stake: i128,
+    }
+
+    /// A subnet moving to another netuid over several blocks, kept between them until its
+    /// parameters move last.
+    #[freeze_struct("5d544b04b213d910")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct SubnetMigration {
+        /// The netuid the subnet moves from.
+        pub old_netuid: u16,
+        /// The netuid the subnet moves to.
+        pub new_netuid: u16,
+        /// The netuid-keyed double map moved next, in `move_subnet_migration_stage` order.
+        pub stage: u8,
+        /// Number of entries moved so far.
+        pub moved: u64,
+    }
+
+    /// How the emission of a subnet is split, in basis points summing to 10_000.
+    #[freeze_struct("e3f846c540c3ef7c")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct EmissionSplit {
+        /// Share paid to the subnet owner's coldkey.
+        pub owner_bps: u16,
+        /// Share paid to miners as incentive.
+        pub miners_bps: u16,
+        /// Share paid to validators and their nominators as dividends.
+        pub validators_bps: u16,
+    }
+
+    /// Subnet hyperparameters the subnet owner can set within bounds, each rate limited on
+    /// its own.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum OwnerHyperparam {
+        /// Blocks between the epochs of the subnet.
+        Tempo,
+        /// Blocks between the adjustments of the registration difficulty and burn.
+        AdjustmentInterval,
+        /// Registrations targeted per adjustment interval.
+        TargetRegistrationsPerInterval,
+        /// Registrations allowed in a block.
+        MaxRegistrationsPerBlock,
+        /// Blocks a new neuron of the subnet is protected from pruning.
+        ImmunityPeriod,
+        /// Largest share of the active stake one validator weighs in the epoch with.
+        MaxStakeWeightFraction,
+    }
+
+    /// The role a registrant declares it registers for.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum NeuronRole {
+        /// Serves the subnet and earns incentive.
+        Miner,
+        /// Sets weights and earns dividends, needs a validator permit.
+        Validator,
+    }
+
+    /// Transactions rate limited per signing account, each by its own TransactionTypeRateLimit.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum TransactionType {
+        /// Serving an axon or prometheus endpoint.
+        Serve,
+        /// Increasing or decreasing a delegate take.
+        SetDelegateTake,
+        /// Setting weights on the root network.
+        SetRootWeights,
+        /// Registering a network.
+        RegisterNetwork,
+    }
+
     /// =================================
     /// ==== Axon / Promo Endpoints =====
     /// =================================
@@ -779,6 +1365,22 @@ pub mod pallet {
         pub ip_type: u8,
     }
 
+    /// Data structure for the identity of a coldkey or subnet.
+    #[freeze_struct("591027721bd19597")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ChainIdentity {
+        /// Display name.
+        pub name: Vec<u8>,
+        /// Website url.
+        pub url: Vec<u8>,
+        /// Url of the logo or avatar.
+        pub image: Vec<u8>,
+        /// Discord handle or invite.
+        pub discord: Vec<u8>,
+        /// Free form description, at most MAX_IDENTITY_DESCRIPTION_BYTES.
+        pub description: Vec<u8>,
+    }
+
     /// Default value for rate limiting
     #[pallet::type_value]
     pub fn DefaultTxRateLimit<T: Config>() -> u64 {
@@ -806,6 +1408,19 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( key ) --> last_block
     pub(super) type LastTxBlockDelegateTake<T: Config> =
         StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultLastTxBlock<T>>;
+    #[pallet::storage] // --- MAP ( transaction_type ) --> rate_limit | Blocks an account waits between two transactions of the type, 0 for no limit.
+    pub type TransactionTypeRateLimit<T> =
+        StorageMap<_, Twox64Concat, TransactionType, u64, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( key, transaction_type ) --> last_block
+    pub type LastTransactionTypeBlock<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        TransactionType,
+        u64,
+        OptionQuery,
+    >;
 
     /// Default value for serving rate limit.
     #[pallet::type_value]
@@ -816,9 +1431,85 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> serving_rate_limit
     pub type ServingRateLimit<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultServingRateLimit<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> axon_ttl_blocks | Axons not served again within this many blocks are stale, 0 disables expiry.
+    pub type AxonTTLBlocks<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid, hotkey ) --> axon_info
     pub type Axons<T: Config> =
         StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, AxonInfoOf, OptionQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> certificate | TLS public key or certificate fingerprint the neuron's axon presents.
+    pub type NeuronCertificates<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u8, ConstU32<MAX_NEURON_CERTIFICATE_BYTES>>,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- MAP ( netuid, hotkey ) --> replica axon_infos, served at indices 1..MAX_AXONS_PER_NEURON
+    pub type AxonReplicas<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<AxonInfoOf, ConstU32<{ MAX_AXONS_PER_NEURON - 1 }>>,
+        ValueQuery,
+    >;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> block | Block the owner last cleared the neuron's serving info and weights at.
+    pub type LastNeuronClearBlock<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( ip, (netuid, hotkey) ) --> () | The neurons serving an axon on an ip.
+    pub type AxonIpIndex<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u128,
+        Blake2_128Concat,
+        (u16, T::AccountId),
+        (),
+        OptionQuery,
+    >;
+    /// Default value for the maximum size of a miner commitment.
+    #[pallet::type_value]
+    pub fn DefaultMaxCommitmentSize<T: Config>() -> u32 {
+        128
+    }
+    /// Default value for the commitment rate limit.
+    #[pallet::type_value]
+    pub fn DefaultCommitmentRateLimit<T: Config>() -> u64 {
+        100
+    }
+    #[pallet::storage] // --- MAP ( netuid ) --> max_commitment_size
+    pub type MaxCommitmentSize<T> =
+        StorageMap<_, Identity, u16, u32, ValueQuery, DefaultMaxCommitmentSize<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> commitment_rate_limit
+    pub type CommitmentRateLimit<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultCommitmentRateLimit<T>>;
+    #[cfg(feature = "commitments")]
+    #[pallet::storage] // --- MAP ( netuid, hotkey ) --> commitment
+    pub type Commitments<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u8, ConstU32<MAX_COMMITMENT_BYTES>>,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- MAP ( netuid, hotkey ) --> (evm_address, block_associated) | EVM key proven to belong to the hotkey's owner.
+    pub type AssociatedEvmAddress<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        (H160, u64),
+        OptionQuery,
+    >;
+    #[cfg(feature = "commitments")]
+    #[pallet::storage] // --- MAP ( netuid, hotkey ) --> last_commitment_block
+    pub type LastCommitmentBlock<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid, hotkey ) --> prometheus_info
     pub type Prometheus<T: Config> = StorageDoubleMap<
         _,
@@ -854,6 +1545,12 @@ pub mod pallet {
     pub fn DefaultKappa<T: Config>() -> u16 {
         T::InitialKappa::get()
     }
+    /// Default largest share of a subnet's active stake one validator weighs in its epoch with,
+    /// the whole of it.
+    #[pallet::type_value]
+    pub fn DefaultMaxStakeWeightFraction<T: Config>() -> u16 {
+        u16::MAX
+    }
     /// Default max allowed uids.
     #[pallet::type_value]
     pub fn DefaultMaxAllowedUids<T: Config>() -> u16 {
@@ -869,6 +1566,11 @@ pub mod pallet {
     pub fn DefaultActivityCutoff<T: Config>() -> u16 {
         T::InitialActivityCutoff::get()
     }
+    /// Default lowest activity cutoff a subnet owner can set, one default tempo.
+    #[pallet::type_value]
+    pub fn DefaultMinActivityCutoff<T: Config>() -> u16 {
+        360
+    }
     /// Default max weights limit.
     #[pallet::type_value]
     pub fn DefaultMaxWeightsLimit<T: Config>() -> u16 {
@@ -884,11 +1586,26 @@ pub mod pallet {
     pub fn DefaultMinAllowedWeights<T: Config>() -> u16 {
         T::InitialMinAllowedWeights::get()
     }
+    /// Default bits weights are stored with, their full precision.
+    #[pallet::type_value]
+    pub fn DefaultWeightsPrecision<T: Config>() -> u8 {
+        weights_row::FULL_WEIGHTS_PRECISION
+    }
     /// Default max allowed validators.
     #[pallet::type_value]
     pub fn DefaultMaxAllowedValidators<T: Config>() -> u16 {
         T::InitialMaxAllowedValidators::get()
     }
+    /// Default max miner uids, no cap beyond max allowed uids.
+    #[pallet::type_value]
+    pub fn DefaultMaxMinerUids<T: Config>() -> u16 {
+        u16::MAX
+    }
+    /// Default bound on the stake a subnet can require of registering hotkeys, 1000 TAO.
+    #[pallet::type_value]
+    pub fn DefaultMaxMinStakeToRegister<T: Config>() -> u64 {
+        1_000_000_000_000
+    }
     /// Default adjustment interval.
     #[pallet::type_value]
     pub fn DefaultAdjustmentInterval<T: Config>() -> u16 {
@@ -931,12 +1648,29 @@ pub mod pallet {
         (45875, 58982) // (alpha_low: 0.7, alpha_high: 0.9)
     }
 
+    /// Default number of subnets the root weights of a validator must reach in root weights
+    /// safe mode.
+    #[pallet::type_value]
+    pub fn DefaultRootWeightsMinSpread<T: Config>() -> u16 {
+        2
+    }
+
     #[pallet::storage] // ITEM( weights_min_stake )
     pub type WeightsMinStake<T> = StorageValue<_, u64, ValueQuery, DefaultWeightsMinStake<T>>;
+    #[pallet::storage] // --- ITEM ( root_weights_safe_mode ) | Whether degenerate root weights are rejected.
+    pub type RootWeightsSafeMode<T> = StorageValue<_, bool, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( root_weights_safe_mode_stake_threshold ) | Stake above which root weights must reach RootWeightsMinSpread subnets.
+    pub type RootWeightsSafeModeStakeThreshold<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( root_weights_min_spread ) | Subnets the root weights of a validator above the threshold must reach.
+    pub type RootWeightsMinSpread<T> =
+        StorageValue<_, u16, ValueQuery, DefaultRootWeightsMinSpread<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> Rho
     pub type Rho<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultRho<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> Kappa
     pub type Kappa<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultKappa<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> max_stake_weight_fraction | Largest share of the active stake one validator weighs in the epoch with.
+    pub type MaxStakeWeightFraction<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxStakeWeightFraction<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> uid, we use to record uids to prune at next epoch.
     pub type NeuronsToPruneAtNextEpoch<T: Config> = StorageMap<_, Identity, u16, u16, ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> registrations_this_interval
@@ -947,15 +1681,27 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> burn_registrations_this_interval
     pub type BurnRegistrationsThisInterval<T: Config> =
         StorageMap<_, Identity, u16, u16, ValueQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> difficulty_solved | Total difficulty solved by the PoW registrations of the subnet.
+    pub type PowDifficultySolved<T> = StorageMap<_, Identity, u16, u128, ValueQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> difficulty_solved_this_interval
+    pub type PowDifficultySolvedThisInterval<T> = StorageMap<_, Identity, u16, u128, ValueQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> Vec<average_difficulty> | Average difficulty solved per PoW registration in the last adjustment intervals, oldest first.
+    pub type PowIntervalAverages<T> =
+        StorageMap<_, Identity, u16, BoundedVec<u64, ConstU32<POW_STATS_INTERVALS>>, ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> max_allowed_uids
     pub type MaxAllowedUids<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxAllowedUids<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> ( step, interval, target, next_block ) | Scheduled growth of MaxAllowedUids.
+    pub type MaxAllowedUidsGrowth<T> =
+        StorageMap<_, Identity, u16, (u16, u64, u16, u64), OptionQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> immunity_period
     pub type ImmunityPeriod<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultImmunityPeriod<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> activity_cutoff
     pub type ActivityCutoff<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultActivityCutoff<T>>;
+    #[pallet::storage] // --- ITEM ( min_activity_cutoff )
+    pub type MinActivityCutoff<T> = StorageValue<_, u16, ValueQuery, DefaultMinActivityCutoff<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> max_weight_limit
     pub type MaxWeightsLimit<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxWeightsLimit<T>>;
@@ -965,9 +1711,24 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> min_allowed_weights
     pub type MinAllowedWeights<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMinAllowedWeights<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> weights_precision | Bits each weight of the subnet is stored with.
+    pub type WeightsPrecision<T> =
+        StorageMap<_, Identity, u16, u8, ValueQuery, DefaultWeightsPrecision<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> max_allowed_validators
     pub type MaxAllowedValidators<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxAllowedValidators<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> max_miner_uids | Cap on the uids without a validator permit, registrations prune a miner beyond it.
+    pub type MaxMinerUids<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxMinerUids<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> min_stake_to_register | Stake a hotkey must already hold to register on the subnet.
+    pub type MinStakeToRegister<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( max_min_stake_to_register ) | Bound on the MinStakeToRegister a subnet can set.
+    pub type MaxMinStakeToRegister<T> =
+        StorageValue<_, u64, ValueQuery, DefaultMaxMinStakeToRegister<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> bootstrap_incentive_floor | Share of the server emission, over u16::MAX, guaranteed to uids in their first epoch.
+    pub type BootstrapIncentiveFloor<T> = StorageMap<_, Identity, u16, u16, ValueQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> emission_split | Owner-set split of the subnet's emission, the global owner cut and an even split without one.
+    pub type SubnetEmissionSplit<T> = StorageMap<_, Identity, u16, EmissionSplit, OptionQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> adjustment_interval
     pub type AdjustmentInterval<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultAdjustmentInterval<T>>;
@@ -986,6 +1747,19 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> target_registrations_this_interval
     pub type TargetRegistrationsPerInterval<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultTargetRegistrationsPerInterval<T>>;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> uid | Uid the hotkey held when it was pruned from a subnet with PERSISTENT_UIDS.
+    pub type RetiredUids<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, u16, OptionQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> ( burn, block ) | Burn the hotkey paid to register on the subnet and the block it registered at.
+    pub type RegistrationBurn<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, (u64, u64), OptionQuery>;
+    #[pallet::storage] // --- ITEM ( unregister_refund_window ) | Blocks after a burned registration within which `unregister` refunds part of the burn, 0 disables refunds.
+    pub type UnregisterRefundWindow<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( unregister_refund_share ) | Share of the burn, over u16::MAX, `unregister` refunds within the refund window.
+    pub type UnregisterRefundShare<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> role | Role the hotkey declared when it registered on the subnet with `burned_register_with_role`.
+    pub type DeclaredRoles<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, NeuronRole, OptionQuery>;
     #[pallet::storage] // --- DMAP ( netuid, uid ) --> block_at_registration
     pub type BlockAtRegistration<T: Config> = StorageDoubleMap<
         _,
@@ -1016,6 +1790,52 @@ pub mod pallet {
         (H256, u64),
         OptionQuery,
     >;
+    #[pallet::storage] // --- DMAP ( netuid, uid ) --> metadata_hash | Provenance hash set with the uid's weights, e.g. the CID of an evaluation report.
+    pub type WeightsMetadataHash<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, H256, OptionQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, uid ) --> epoch_index | Epoch of the subnet the uid's weights were set for.
+    pub type WeightsTargetEpoch<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, u64, OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> Vec<( block, root )> | Merkle roots of the subnet's stakes, incentives and dividends after its last epochs.
+    pub type EpochSnapshots<T> = StorageMap<_, Identity, u16, Vec<(u64, H256)>, ValueQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> Vec<emission> | Emission values of the subnet set by the last root epochs, oldest first.
+    pub type EmissionHistory<T> = StorageMap<
+        _,
+        Identity,
+        u16,
+        BoundedVec<u64, ConstU32<EMISSION_HISTORY_LENGTH>>,
+        ValueQuery,
+    >;
+    #[pallet::storage] // --- MAP ( netuid ) --> Vec<( block, evicted_hotkey, evicted_stake, new_hotkey )> | Neurons of the subnet last replaced by registrations, oldest first.
+    pub type EvictionLog<T: Config> = StorageMap<
+        _,
+        Identity,
+        u16,
+        BoundedVec<(u64, T::AccountId, u64, T::AccountId), ConstU32<EVICTION_LOG_LENGTH>>,
+        ValueQuery,
+    >;
+    #[pallet::storage] // --- ITEM ( block, root ) | Merkle root of the ( netuid, emission ) pairs set by the last root epoch.
+    pub type RootEpochCommitment<T> = StorageValue<_, (u64, H256), ValueQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> weights_key | Key allowed to set weights for the hotkey.
+    pub type WeightsKeys<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- DMAP ( netuid, weights_key ) --> hotkey | The hotkey a weights key acts for.
+    pub type WeightsKeyHotkey<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
 
     /// Default value for weight commit reveal interval.
     #[pallet::type_value]
@@ -1027,58 +1847,321 @@ pub mod pallet {
     pub type WeightCommitRevealInterval<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultWeightCommitRevealInterval<T>>;
 
-    /// Default value for weight commit/reveal enabled.
+    /// Default value for subnet feature flags.
     #[pallet::type_value]
-    pub fn DefaultCommitRevealWeightsEnabled<T: Config>() -> bool {
-        false
+    pub fn DefaultSubnetFeatureFlags<T: Config>() -> u32 {
+        0
     }
-    // --- DMAP ( netuid ) --> interval
-    #[pallet::storage]
-    pub type CommitRevealWeightsEnabled<T> =
-        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultCommitRevealWeightsEnabled<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> flags | Bitfield of the features enabled on a subnet, see `subnet_features`.
+    pub type SubnetFeatureFlags<T> =
+        StorageMap<_, Identity, u16, u32, ValueQuery, DefaultSubnetFeatureFlags<T>>;
 
-    /// =======================================
-    /// ==== Subnetwork Consensus Storage  ====
-    /// =======================================
-
-    /// Value definition for vector of u16.
-    #[pallet::type_value]
-    pub fn EmptyU16Vec<T: Config>() -> Vec<u16> {
-        vec![]
-    }
-    /// Value definition for vector of u64.
+    /// Default maximum length of a subnet maintenance window, one day at 12 second blocks.
     #[pallet::type_value]
-    pub fn EmptyU64Vec<T: Config>() -> Vec<u64> {
-        vec![]
+    pub fn DefaultMaxMaintenanceDuration<T: Config>() -> u64 {
+        7200
     }
-    /// Value definition for vector of bool.
+    #[pallet::storage] // --- ITEM( max_maintenance_duration )
+    pub type MaxMaintenanceDuration<T> =
+        StorageValue<_, u64, ValueQuery, DefaultMaxMaintenanceDuration<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> (start, duration) | Blocks during which the subnet's epochs are paused.
+    pub type MaintenanceWindow<T> = StorageMap<_, Identity, u16, (u64, u64), OptionQuery>;
+
+    /// Default share of the stake of permitted validators that pauses a subnet's epochs, two thirds.
     #[pallet::type_value]
-    pub fn EmptyBoolVec<T: Config>() -> Vec<bool> {
-        vec![]
+    pub fn DefaultEpochPauseThreshold<T: Config>() -> u16 {
+        43_690
     }
-    /// Value definition for bonds with type vector of (u16, u16).
+    #[pallet::storage] // --- ITEM( epoch_pause_threshold )
+    pub type EpochPauseThreshold<T> =
+        StorageValue<_, u16, ValueQuery, DefaultEpochPauseThreshold<T>>;
+    /// Default number of blocks validators pause a subnet's epochs for, one day at 12 second blocks.
     #[pallet::type_value]
-    pub fn DefaultBonds<T: Config>() -> Vec<(u16, u16)> {
-        vec![]
+    pub fn DefaultEpochPauseDuration<T: Config>() -> u64 {
+        7200
     }
-    /// Value definition for weights with vector of (u16, u16).
+    #[pallet::storage] // --- ITEM( epoch_pause_duration )
+    pub type EpochPauseDuration<T> = StorageValue<_, u64, ValueQuery, DefaultEpochPauseDuration<T>>;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> signals | Permitted validators signalling to pause the subnet's epochs.
+    pub type EpochPauseSignals<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> block | Block until which the subnet's validators paused its epochs.
+    pub type EpochPausedUntil<T> = StorageMap<_, Identity, u16, u64, OptionQuery>;
+    /// Default number of blocks the stake behind a reachable axon attestation halves in, one day at 12 second blocks.
     #[pallet::type_value]
-    pub fn DefaultWeights<T: Config>() -> Vec<(u16, u16)> {
-        vec![]
+    pub fn DefaultAxonReachabilityHalfLife<T: Config>() -> u64 {
+        7200
     }
-    /// Default value for key with type T::AccountId derived from trailing zeroes.
+    #[pallet::storage] // --- ITEM( axon_reachability_half_life )
+    pub type AxonReachabilityHalfLife<T> =
+        StorageValue<_, u64, ValueQuery, DefaultAxonReachabilityHalfLife<T>>;
+    #[pallet::storage] // --- DMAP ( netuid, uid ) --> Vec<( hotkey, reachable, block )> | Attestations of permitted validators on the reachability of the uid's axon.
+    pub type AxonAttestations<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Identity,
+        u16,
+        Vec<(T::AccountId, bool, u64)>,
+        ValueQuery,
+    >;
+    /// Default number of blocks before a change of child keys applies, one day at 12 second blocks.
     #[pallet::type_value]
-    pub fn DefaultKey<T: Config>() -> T::AccountId {
-        T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
-            .expect("trailing zeroes always produce a valid account ID; qed")
+    pub fn DefaultPendingChildKeyCooldown<T: Config>() -> u64 {
+        7200
     }
-
+    #[pallet::storage] // --- ITEM( pending_child_key_cooldown )
+    pub type PendingChildKeyCooldown<T> =
+        StorageValue<_, u64, ValueQuery, DefaultPendingChildKeyCooldown<T>>;
+    #[pallet::storage] // --- DMAP ( netuid, parent ) --> Vec<( proportion, child )> | Children the hotkey passes a proportion of its stake on the subnet to, out of u64::MAX.
+    pub type ChildKeys<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<(u64, T::AccountId)>,
+        ValueQuery,
+    >;
+    #[pallet::storage] // --- DMAP ( netuid, child ) --> Vec<( proportion, parent )> | Parents passing the hotkey a proportion of their stake on the subnet.
+    pub type ParentKeys<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(u64, T::AccountId), ConstU32<MAX_PARENTS>>,
+        ValueQuery,
+    >;
+    #[pallet::storage] // --- DMAP ( netuid, parent ) --> ( Vec<( proportion, child )>, cooldown_block ) | Children replacing those of the hotkey once the block is reached.
+    pub type PendingChildKeys<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        (Vec<(u64, T::AccountId)>, u64),
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- DMAP ( apply_block, ( netuid, parent ) ) --> () | Pending child keys to apply at the block, so only those due are visited.
+    pub type PendingChildKeysByBlock<T: Config> =
+        StorageDoubleMap<_, Identity, u64, Blake2_128Concat, (u16, T::AccountId), (), OptionQuery>;
+    /// Default maximum number of blocks the weight setting of a subnet stays paused, one day at 12 second blocks.
+    #[pallet::type_value]
+    pub fn DefaultMaxWeightsPauseDuration<T: Config>() -> u64 {
+        7200
+    }
+    #[pallet::storage] // --- ITEM( max_weights_pause_duration )
+    pub type MaxWeightsPauseDuration<T> =
+        StorageValue<_, u64, ValueQuery, DefaultMaxWeightsPauseDuration<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> block | Block until which weight setting on the subnet is paused, kept afterwards to space out pauses.
+    pub type WeightsPausedUntil<T> = StorageMap<_, Identity, u16, u64, OptionQuery>;
+    /// Default number of blocks between two updates of a hyperparameter by a subnet owner.
+    #[pallet::type_value]
+    pub fn DefaultOwnerHyperparamRateLimit<T: Config>() -> u64 {
+        7200
+    }
+    #[pallet::storage] // --- ITEM( owner_hyperparam_rate_limit )
+    pub type OwnerHyperparamRateLimit<T> =
+        StorageValue<_, u64, ValueQuery, DefaultOwnerHyperparamRateLimit<T>>;
+    /// Default longest immunity period a subnet owner can set, one day at 12 second blocks.
+    #[pallet::type_value]
+    pub fn DefaultMaxImmunityPeriod<T: Config>() -> u16 {
+        7200
+    }
+    #[pallet::storage] // --- ITEM( max_immunity_period )
+    pub type MaxImmunityPeriod<T> = StorageValue<_, u16, ValueQuery, DefaultMaxImmunityPeriod<T>>;
+    #[pallet::storage] // --- DMAP ( netuid, hyperparam ) --> block | Block the subnet owner last set the hyperparameter at.
+    pub type LastOwnerHyperparamUpdate<T> =
+        StorageDoubleMap<_, Identity, u16, Twox64Concat, OwnerHyperparam, u64, OptionQuery>;
+    /// Default number of blocks a subnet referendum is open for votes, one day at 12 second blocks.
+    #[pallet::type_value]
+    pub fn DefaultReferendumDuration<T: Config>() -> u64 {
+        7200
+    }
+    #[pallet::storage] // --- ITEM( referendum_duration )
+    pub type ReferendumDuration<T> = StorageValue<_, u64, ValueQuery, DefaultReferendumDuration<T>>;
+    /// Default share of the stake of permitted validators that passes a subnet referendum, two thirds.
+    #[pallet::type_value]
+    pub fn DefaultReferendumThreshold<T: Config>() -> u16 {
+        43_690
+    }
+    #[pallet::storage] // --- ITEM( referendum_threshold )
+    pub type ReferendumThreshold<T> =
+        StorageValue<_, u16, ValueQuery, DefaultReferendumThreshold<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> (hyperparam, value, ends_at) | Hyperparameter change the subnet owner put to a vote of the subnet's validators.
+    pub type SubnetReferendum<T> =
+        StorageMap<_, Identity, u16, (OwnerHyperparam, u16, u64), OptionQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> (approve, stake) | Votes on the open referendum of the subnet, with the stake each locks.
+    pub type ReferendumVotes<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        (bool, u64),
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- MAP ( hotkey ) --> stake | Stake of the hotkey locked by its votes on open subnet referenda.
+    pub type ReferendumLockedStake<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Stake a coldkey locked on a hotkey in exchange for boosted dividends.
+    #[freeze_struct("39da6ee519df1eaf")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct StakeLock {
+        /// Stake that can't be removed until the unlock block.
+        pub amount: u64,
+        /// Block the stake was last locked at, the lock duration counts from it.
+        pub locked_at: u64,
+        /// First block the stake can be removed at.
+        pub unlock_block: u64,
+    }
+    #[pallet::storage] // --- DMAP ( hot, cold ) --> lock | Stake the coldkey locked on the hotkey.
+    pub type StakeLocks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        StakeLock,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- MAP ( block ) --> Vec<( hot, cold )> | Stake locks ending at the block, cleared by block_step.
+    pub type StakeLockExpiries<T: Config> =
+        StorageMap<_, Identity, u64, Vec<(T::AccountId, T::AccountId)>, ValueQuery>;
+    #[pallet::storage] // --- ITEM( stake_lock_boosts ) | ( lock_blocks, multiplier ) tiers in ascending order, the multiplier in basis points of the longest tier a lock reaches applies.
+    pub type StakeLockBoosts<T> = StorageValue<_, Vec<(u64, u32)>, ValueQuery>;
+    #[pallet::storage] // --- ITEM( unstake_cooldown ) | Blocks removed stake waits before it can be claimed, 0 to pay it out at once.
+    pub type UnstakeCooldown<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- MAP ( cold ) --> Vec<( hot, amount, unlock_block )> | Removed stake waiting out the unstake cooldown, claimed with claim_unstaked.
+    pub type PendingWithdrawals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<(T::AccountId, u64, u64)>, ValueQuery>;
+    /// Default share of the stake of a delegate its nominators need to cap its take, one half.
+    #[pallet::type_value]
+    pub fn DefaultTakeCapThreshold<T: Config>() -> u16 {
+        32_768
+    }
+    #[pallet::storage] // --- ITEM( take_cap_threshold )
+    pub type TakeCapThreshold<T> = StorageValue<_, u16, ValueQuery, DefaultTakeCapThreshold<T>>;
+    /// Default number of blocks a cap voted on the take of a delegate runs, one week at 12 second blocks.
+    #[pallet::type_value]
+    pub fn DefaultTakeCapDuration<T: Config>() -> u64 {
+        7200 * 7
+    }
+    #[pallet::storage] // --- ITEM( take_cap_duration )
+    pub type TakeCapDuration<T> = StorageValue<_, u64, ValueQuery, DefaultTakeCapDuration<T>>;
+    #[pallet::storage] // --- DMAP ( hotkey, coldkey ) --> cap | Take the nominator votes to cap the delegate at.
+    pub type TakeCapVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        u16,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- MAP ( hotkey ) --> (cap, expires_at) | Cap on the take of the delegate voted by its nominators.
+    pub type DelegateTakeCap<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u16, u64), OptionQuery>;
+    #[pallet::storage] // --- ITEM( underperformance_trust_threshold ) | Validator trust below which the take of a delegate is reduced, 0 turning the reduction off.
+    pub type UnderperformanceTrustThreshold<T> = StorageValue<_, u16, ValueQuery>;
+    /// Default number of root tempos in a row that reduce or restore the take of a delegate.
+    #[pallet::type_value]
+    pub fn DefaultUnderperformanceTempos<T: Config>() -> u16 {
+        3
+    }
+    #[pallet::storage] // --- ITEM( underperformance_tempos )
+    pub type UnderperformanceTempos<T> =
+        StorageValue<_, u16, ValueQuery, DefaultUnderperformanceTempos<T>>;
+    #[pallet::storage] // --- ITEM( underperformance_take ) | Take an underperforming delegate is reduced to.
+    pub type UnderperformanceTake<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage] // --- MAP ( hotkey ) --> (reduced, streak) | Whether the take of the delegate is reduced, and the root tempos in a row its trust has been on the other side of the threshold.
+    pub type DelegateUnderperformance<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (bool, u16), OptionQuery>;
+    #[pallet::storage] // --- ITEM ( raw_key ) | Delegate the running underperformance check resumes after.
+    pub type DelegateUnderperformanceCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
+    #[pallet::storage] // --- ITEM ( raw_key ) | Delegate the running take cap tally resumes after.
+    pub type TakeCapTallyCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> max_take | Cap the subnet owner sets on the take of delegates for the emission earned on the subnet.
+    pub type MaxTakePerSubnet<T> = StorageMap<_, Identity, u16, u16, OptionQuery>;
+    /// Default deposit held for an identity, 0.1 TAO.
+    #[pallet::type_value]
+    pub fn DefaultIdentityDeposit<T: Config>() -> u64 {
+        100_000_000
+    }
+    #[pallet::storage] // --- ITEM( identity_deposit )
+    pub type IdentityDeposit<T> = StorageValue<_, u64, ValueQuery, DefaultIdentityDeposit<T>>;
+    #[cfg(feature = "identity")]
+    #[pallet::storage] // --- MAP ( coldkey ) --> (identity, deposit) | Identity of the coldkey and the deposit held for it.
+    pub type Identities<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (ChainIdentity, u64), OptionQuery>;
+    #[cfg(feature = "identity")]
+    #[pallet::storage] // --- MAP ( netuid ) --> (identity, depositor, deposit) | Identity of the subnet and the deposit held for it from the owner that set it.
+    pub type SubnetIdentities<T: Config> =
+        StorageMap<_, Identity, u16, (ChainIdentity, T::AccountId, u64), OptionQuery>;
+    /// Default number of blocks call metrics are counted over, one day at 12 second blocks.
+    #[pallet::type_value]
+    pub fn DefaultCallMetricsEraLength<T: Config>() -> u64 {
+        7200
+    }
+    #[pallet::storage] // --- ITEM( call_metrics_era_length )
+    pub type CallMetricsEraLength<T> =
+        StorageValue<_, u64, ValueQuery, DefaultCallMetricsEraLength<T>>;
+    #[pallet::storage] // --- ITEM( call_metrics_era_start )
+    pub type CallMetricsEraStart<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- MAP ( call_index ) --> (calls, failures) | Signed dispatches of the call in the current call metrics era.
+    pub type CallCounts<T> = StorageMap<_, Identity, u8, (u64, u64), ValueQuery>;
+    #[pallet::storage] // --- DMAP ( call_index, error_code ) --> failures | Failed dispatches of the call in the current call metrics era by error.
+    pub type CallFailuresByError<T> =
+        StorageDoubleMap<_, Identity, u8, Identity, u16, u64, ValueQuery>;
+
+    /// =======================================
+    /// ==== Subnetwork Consensus Storage  ====
+    /// =======================================
+
+    /// Value definition for vector of u16.
+    #[pallet::type_value]
+    pub fn EmptyU16Vec<T: Config>() -> Vec<u16> {
+        vec![]
+    }
+    /// Value definition for vector of u64.
+    #[pallet::type_value]
+    pub fn EmptyU64Vec<T: Config>() -> Vec<u64> {
+        vec![]
+    }
+    /// Value definition for vector of bool.
+    #[pallet::type_value]
+    pub fn EmptyBoolVec<T: Config>() -> Vec<bool> {
+        vec![]
+    }
+    /// Value definition for bonds with type vector of (u16, u16).
+    #[pallet::type_value]
+    pub fn DefaultBonds<T: Config>() -> Vec<(u16, u16)> {
+        vec![]
+    }
+    /// Value definition for weights, an empty row.
+    #[pallet::type_value]
+    pub fn DefaultWeights<T: Config>() -> crate::WeightsRow {
+        crate::WeightsRow::default()
+    }
+    /// Default value for key with type T::AccountId derived from trailing zeroes.
+    #[pallet::type_value]
+    pub fn DefaultKey<T: Config>() -> T::AccountId {
+        T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
+            .expect("trailing zeroes always produce a valid account ID; qed")
+    }
+
     #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> uid
     pub type Uids<T: Config> =
         StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, u16, OptionQuery>;
     #[pallet::storage] // --- DMAP ( netuid, uid ) --> hotkey
     pub type Keys<T: Config> =
         StorageDoubleMap<_, Identity, u16, Identity, u16, T::AccountId, ValueQuery, DefaultKey<T>>;
+    #[pallet::storage] // --- ITEM ( loaded_emission_entries ) | Number of LoadedEmission entries, counted at write time.
+    pub type LoadedEmissionEntries<T> = StorageValue<_, u32, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( last_update_entries ) | Number of LastUpdate entries, counted at write time.
+    pub type LastUpdateEntries<T> = StorageValue<_, u32, ValueQuery>;
     #[pallet::storage] // --- DMAP ( netuid ) --> (hotkey, se, ve)
     pub type LoadedEmission<T: Config> =
         StorageMap<_, Identity, u16, Vec<(T::AccountId, u64, u64)>, OptionQuery>;
@@ -1117,14 +2200,14 @@ pub mod pallet {
     pub(super) type ValidatorPermit<T: Config> =
         StorageMap<_, Identity, u16, Vec<bool>, ValueQuery, EmptyBoolVec<T>>;
 
-    #[pallet::storage] // --- DMAP ( netuid, uid ) --> weights
+    #[pallet::storage] // --- DMAP ( netuid, uid ) --> weights | compressed row of (uid, weight)
     pub(super) type Weights<T: Config> = StorageDoubleMap<
         _,
         Identity,
         u16,
         Identity,
         u16,
-        Vec<(u16, u16)>,
+        crate::WeightsRow,
         ValueQuery,
         DefaultWeights<T>,
     >;
@@ -1144,12 +2227,51 @@ pub mod pallet {
     /// ==== Genesis =====
     /// ==================
 
+    /// Data structure for a subnet declared in genesis.
+    #[freeze_struct("b9782447cee65c9e")]
+    #[derive(
+        Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize,
+    )]
+    #[serde(rename_all = "camelCase")]
+    pub struct GenesisSubnet<AccountId> {
+        /// Netuid of the subnet.
+        pub netuid: u16,
+        /// Optional owner coldkey of the subnet.
+        pub owner: Option<AccountId>,
+        /// Subnet tempo.
+        pub tempo: u16,
+        /// Maximum number of uids on the subnet.
+        pub max_allowed_uids: u16,
+        /// Maximum number of validators on the subnet.
+        pub max_allowed_validators: u16,
+        /// Minimum number of weights a validator must set.
+        pub min_allowed_weights: u16,
+        /// Maximum weight a validator can set on a single uid.
+        pub max_weights_limit: u16,
+        /// Immunity period of newly registered neurons.
+        pub immunity_period: u16,
+        /// Initial PoW registration difficulty.
+        pub difficulty: u64,
+        /// Initial burn registration cost.
+        pub burn: u64,
+        /// Blocks between weight sets of a validator.
+        pub weights_set_rate_limit: u64,
+        /// Neurons registered in genesis as (hotkey, coldkey), uids follow this order.
+        pub neurons: Vec<(AccountId, AccountId)>,
+    }
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         /// Stakes record in genesis.
         pub stakes: Vec<(T::AccountId, Vec<(T::AccountId, (u64, u16))>)>,
         /// The total issued balance in genesis
         pub balances_issuance: u64,
+        /// Subnets created in genesis along with their hyperparameters and neurons.
+        pub subnets: Vec<GenesisSubnet<T::AccountId>>,
+        /// Stakes added in genesis as (coldkey, hotkey, stake).
+        pub initial_stakes: Vec<(T::AccountId, T::AccountId, u64)>,
+        /// Hotkeys made delegates in genesis as (hotkey, take).
+        pub delegates: Vec<(T::AccountId, u16)>,
     }
 
     impl<T: Config> Default for GenesisConfig<T> {
@@ -1157,6 +2279,9 @@ pub mod pallet {
             Self {
                 stakes: Default::default(),
                 balances_issuance: 0,
+                subnets: Default::default(),
+                initial_stakes: Default::default(),
+                delegates: Default::default(),
             }
         }
     }
@@ -1174,7 +2299,7 @@ pub mod pallet {
 
             // The functions for initializing new networks/setting defaults cannot be run directly from genesis functions like extrinsics would
             // --- Set this network uid to alive.
-            NetworksAdded::<T>::insert(netuid, true);
+            Pallet::<T>::add_subnet_netuid(netuid);
 
             // --- Fill tempo memory item.
             Tempo::<T>::insert(netuid, tempo);
@@ -1247,7 +2372,7 @@ pub mod pallet {
                     Consensus::<T>::mutate(netuid, |v| v.push(0));
                     Incentive::<T>::mutate(netuid, |v| v.push(0));
                     Dividends::<T>::mutate(netuid, |v| v.push(0));
-                    LastUpdate::<T>::mutate(netuid, |v| v.push(0));
+                    Pallet::<T>::append_last_update(netuid, 0);
                     PruningScores::<T>::mutate(netuid, |v| v.push(0));
                     ValidatorTrust::<T>::mutate(netuid, |v| v.push(0));
                     ValidatorPermit::<T>::mutate(netuid, |v| v.push(false));
@@ -1278,13 +2403,10 @@ pub mod pallet {
                     TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(*stake));
 
                     Stake::<T>::insert(hotkey.clone(), coldkey.clone(), stake);
+                    Pallet::<T>::update_delegate_indexes(hotkey, coldkey, 0, *stake);
 
-                    // Update StakingHotkeys map
-                    let mut staking_hotkeys = StakingHotkeys::<T>::get(coldkey);
-                    if !staking_hotkeys.contains(hotkey) {
-                        staking_hotkeys.push(hotkey.clone());
-                        StakingHotkeys::<T>::insert(coldkey, staking_hotkeys);
-                    }
+                    // Update StakingHotkeyPages
+                    Pallet::<T>::add_staking_hotkey(coldkey, hotkey);
 
                     next_uid = next_uid.checked_add(1).expect(
                         "should not have total number of hotkey accounts larger than u16::MAX",
@@ -1306,7 +2428,7 @@ pub mod pallet {
             let root_netuid: u16 = 0;
 
             // Set the root network as added.
-            NetworksAdded::<T>::insert(root_netuid, true);
+            Pallet::<T>::add_subnet_netuid(root_netuid);
 
             // Increment the number of total networks.
             TotalNetworks::<T>::mutate(|n| {
@@ -1318,10 +2440,11 @@ pub mod pallet {
             SubnetworkN::<T>::insert(root_netuid, 0);
 
             // Set the maximum number to the number of senate members.
-            MaxAllowedUids::<T>::insert(root_netuid, 64u16);
+            MaxAllowedUids::<T>::insert(root_netuid, T::InitialRootMaxUids::get());
+            RootMaxUids::<T>::put(T::InitialRootMaxUids::get());
 
             // Set the maximum number to the number of validators to all members.
-            MaxAllowedValidators::<T>::insert(root_netuid, 64u16);
+            MaxAllowedValidators::<T>::insert(root_netuid, T::InitialRootMaxUids::get());
 
             // Set the min allowed weights to zero, no weights restrictions.
             MinAllowedWeights::<T>::insert(root_netuid, 0);
@@ -1337,6 +2460,51 @@ pub mod pallet {
 
             // Set target registrations for validators as 1 per block.
             TargetRegistrationsPerInterval::<T>::insert(root_netuid, 1);
+
+            // Create the subnets declared in the chain spec.
+            for subnet in self.subnets.iter() {
+                let netuid = subnet.netuid;
+                assert!(
+                    !NetworksAdded::<T>::get(netuid),
+                    "genesis subnet {} is already added",
+                    netuid
+                );
+                assert!(
+                    subnet.neurons.len() <= subnet.max_allowed_uids as usize,
+                    "genesis subnet {} has more neurons than max_allowed_uids",
+                    netuid
+                );
+
+                Pallet::<T>::init_new_network(netuid, subnet.tempo);
+                Pallet::<T>::set_max_allowed_uids(netuid, subnet.max_allowed_uids);
+                Pallet::<T>::set_max_allowed_validators(netuid, subnet.max_allowed_validators);
+                Pallet::<T>::set_min_allowed_weights(netuid, subnet.min_allowed_weights);
+                Pallet::<T>::set_max_weight_limit(netuid, subnet.max_weights_limit);
+                Pallet::<T>::set_immunity_period(netuid, subnet.immunity_period);
+                Pallet::<T>::set_difficulty(netuid, subnet.difficulty);
+                Pallet::<T>::set_burn(netuid, subnet.burn);
+                Pallet::<T>::set_weights_set_rate_limit(netuid, subnet.weights_set_rate_limit);
+                if let Some(owner) = &subnet.owner {
+                    SubnetOwner::<T>::insert(netuid, owner.clone());
+                }
+
+                for (hotkey, coldkey) in subnet.neurons.iter() {
+                    Pallet::<T>::create_account_if_non_existent(coldkey, hotkey);
+                    Pallet::<T>::append_neuron(netuid, hotkey, 0);
+                }
+            }
+
+            // Add the initial stakes, these are minted on top of the balances issuance.
+            for (coldkey, hotkey, stake) in self.initial_stakes.iter() {
+                Pallet::<T>::create_account_if_non_existent(coldkey, hotkey);
+                Pallet::<T>::increase_stake_on_coldkey_hotkey_account(coldkey, hotkey, *stake);
+                TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(*stake));
+            }
+
+            // Make the declared hotkeys delegates.
+            for (hotkey, take) in self.delegates.iter() {
+                Pallet::<T>::delegate_hotkey(hotkey, *take);
+            }
         }
     }
 
@@ -1346,8 +2514,14 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_idle(_n: BlockNumberFor<T>, _remaining_weight: Weight) -> Weight {
-            Weight::zero()
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            // Move the storage of a subnet migrating to another netuid, as much as fits.
+            let weight = Self::advance_subnet_migration(remaining_weight);
+
+            // Page the StakingHotkeys left from before StakingHotkeyPages, a few coldkeys a block.
+            weight.saturating_add(migration::migrate_page_staking_hotkeys::<T>(
+                remaining_weight.saturating_sub(weight),
+            ))
         }
 
         fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
@@ -1369,12 +2543,13 @@ pub mod pallet {
             // Perform block step
             let block_step_result = Self::block_step();
             match block_step_result {
-                Ok(_) => {
+                Ok(step_weight) => {
                     log::debug!("Successfully ran block step.");
                     total_weight = total_weight.saturating_add(
                         Weight::from_parts(110_634_229_000_u64, 0)
                             .saturating_add(T::DbWeight::get().reads(8304_u64))
-                            .saturating_add(T::DbWeight::get().writes(110_u64)),
+                            .saturating_add(T::DbWeight::get().writes(110_u64))
+                            .saturating_add(step_weight),
                     );
                 }
                 Err(e) => {
@@ -1406,6 +2581,8 @@ pub mod pallet {
                 .saturating_add(migration::migrate_to_v2_fixed_total_stake::<T>())
                 // Doesn't check storage version. TODO: Remove after upgrade
                 .saturating_add(migration::migrate_create_root_network::<T>())
+                // List the added networks in SubnetNetuids, which later migrations read. Doesn't update storage vesion.
+                .saturating_add(migration::migrate_populate_subnet_netuids::<T>())
                 // Storage version v2 -> v3
                 .saturating_add(migration::migrate_transfer_ownership_to_foundation::<T>(
                     hex,
@@ -1416,10 +2593,26 @@ pub mod pallet {
                 .saturating_add(migration::migrate_delete_subnet_3::<T>())
                 // Doesn't check storage version. TODO: Remove after upgrade
                 .saturating_add(migration::migration5_total_issuance::<T>(false))
+                // Storage version v6 -> v7
+                .saturating_add(migration::migrate_compress_weights::<T>())
                 // Populate OwnedHotkeys map for coldkey swap. Doesn't update storage vesion.
                 .saturating_add(migration::migrate_populate_owned::<T>())
                 // Populate StakingHotkeys map for coldkey swap. Doesn't update storage vesion.
-                .saturating_add(migration::migrate_populate_staking_hotkeys::<T>());
+                .saturating_add(migration::migrate_populate_staking_hotkeys::<T>())
+                // Storage version v7 -> v8, populating the delegate nominator count and stake indexes.
+                .saturating_add(migration::migrate_populate_delegate_indexes::<T>())
+                // Fold the per-subnet feature booleans into SubnetFeatureFlags. Doesn't update storage vesion.
+                .saturating_add(migration::migrate_subnet_feature_flags::<T>())
+                // Move the root network size out of MaxAllowedUids. Doesn't update storage vesion.
+                .saturating_add(migration::migrate_root_max_uids::<T>())
+                // Clamp delegate takes set before MinTake was enforced. Doesn't update storage vesion.
+                .saturating_add(migration::migrate_clamp_delegate_takes::<T>())
+                // Index served axons by ip. Doesn't update storage vesion.
+                .saturating_add(migration::migrate_populate_axon_ip_index::<T>())
+                // Count LoadedEmission and LastUpdate entries. Doesn't update storage vesion.
+                .saturating_add(migration::migrate_count_storage_entries::<T>())
+                // Partition existing subnets into validator and miner slots. Doesn't update storage vesion.
+                .saturating_add(migration::migrate_init_max_miner_uids::<T>());
 
             weight
         }
@@ -1499,6 +2692,10 @@ pub mod pallet {
             weights: Vec<u16>,
             version_key: u64,
         ) -> DispatchResult {
+            ensure!(
+                !Self::get_target_epoch_weights(netuid),
+                Error::<T>::WeightsTargetEpochRequired
+            );
             if !Self::get_commit_reveal_weights_enabled(netuid) {
                 return Self::do_set_weights(origin, netuid, dests, weights, version_key);
             }
@@ -1534,7 +2731,8 @@ pub mod pallet {
             Self::do_commit_weights(origin, netuid, commit_hash)
         }
 
-        /// ---- Used to reveal the weights for a previously committed hash.
+        /// ---- Used to reveal the weights for a previously committed hash. On the root network
+        /// the revealed weights are the hotkey's root weights.
         ///
         /// # Args:
         /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
@@ -1580,20 +2778,195 @@ pub mod pallet {
             Self::do_reveal_weights(origin, netuid, uids, values, salt, version_key)
         }
 
+        /// ---- Sets the caller's weights as `set_weights` does, together with a hash of their
+        /// provenance such as the CID of the evaluation report behind them. The hash is exposed
+        /// in the uid's NeuronInfo until its weights are set again.
+        ///
         /// # Args:
         /// * `origin`: (<T as frame_system::Config>Origin):
-        /// 	- The caller, a hotkey who wishes to set their weights.
+        ///     - The caller, a hotkey who wishes to set their weights.
         ///
         /// * `netuid` (u16):
         /// 	- The network uid we are setting these weights on.
         ///
-        /// * `hotkey` (T::AccountId):
-        /// 	- The hotkey associated with the operation and the calling coldkey.
+        /// * `dests` (Vec<u16>):
+        /// 	- The edge endpoint for the weight, i.e. j for w_ij.
+        ///
+        /// * 'weights' (Vec<u16>):
+        /// 	- The u16 integer encoded weights.
+        ///
+        /// * 'version_key' ( u64 ):
+        /// 	- The network version key to check if the validator is up to date.
+        ///
+        /// * 'weights_metadata_hash' ( H256 ):
+        /// 	- The 32-byte hash stored alongside the weights.
+        ///
+        /// # Event:
+        /// * WeightsSet;
+        /// 	- On successfully setting the weights on chain.
+        ///
+        /// # Raises:
+        /// * 'CommitRevealEnabled':
+        /// 	- The subnet requires weights to be committed and revealed.
+        ///
+        /// * Any error raised by `set_weights`.
+        ///
+        #[pallet::call_index(88)]
+        #[pallet::weight((Weight::from_parts(22_060_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(4106))
+        .saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+        pub fn set_weights_with_metadata(
+            origin: OriginFor<T>,
+            netuid: u16,
+            dests: Vec<u16>,
+            weights: Vec<u16>,
+            version_key: u64,
+            weights_metadata_hash: H256,
+        ) -> DispatchResult {
+            ensure!(
+                !Self::get_commit_reveal_weights_enabled(netuid),
+                Error::<T>::CommitRevealEnabled
+            );
+            ensure!(
+                !Self::get_target_epoch_weights(netuid),
+                Error::<T>::WeightsTargetEpochRequired
+            );
+            Self::do_set_weights_with_metadata(
+                origin,
+                netuid,
+                dests,
+                weights,
+                version_key,
+                Some(weights_metadata_hash),
+            )
+        }
+
+        /// ---- Sets the caller's weights as `set_weights` does, for the epoch of the subnet
+        /// with index `target_epoch`. Subnets with TARGET_EPOCH_WEIGHTS only accept weights
+        /// through this call, and their epochs only count the weights set for them, so weights
+        /// evaluated for an epoch that already ran cannot carry into the next.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        ///     - The caller, a hotkey who wishes to set their weights.
+        ///
+        /// * `netuid` (u16):
+        /// 	- The network uid we are setting these weights on.
         ///
         /// * `dests` (Vec<u16>):
         /// 	- The edge endpoint for the weight, i.e. j for w_ij.
         ///
         /// * 'weights' (Vec<u16>):
+        /// 	- The u16 integer encoded weights.
+        ///
+        /// * 'version_key' ( u64 ):
+        /// 	- The network version key to check if the validator is up to date.
+        ///
+        /// * 'target_epoch' ( u64 ):
+        /// 	- The index of the epoch the weights evaluate, the subnet's next epoch.
+        ///
+        /// # Raises:
+        /// * 'CommitRevealEnabled':
+        /// 	- The subnet requires weights to be committed and revealed.
+        ///
+        /// * 'InvalidWeightsTargetEpoch':
+        /// 	- The target epoch is not the next epoch of the subnet.
+        ///
+        /// * Any error raised by `set_weights`.
+        ///
+        #[pallet::call_index(99)]
+        #[pallet::weight((Weight::from_parts(22_060_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(4107))
+        .saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+        pub fn set_weights_for_epoch(
+            origin: OriginFor<T>,
+            netuid: u16,
+            dests: Vec<u16>,
+            weights: Vec<u16>,
+            version_key: u64,
+            target_epoch: u64,
+        ) -> DispatchResult {
+            ensure!(
+                !Self::get_commit_reveal_weights_enabled(netuid),
+                Error::<T>::CommitRevealEnabled
+            );
+            Self::do_set_weights_for_epoch(
+                origin,
+                netuid,
+                dests,
+                weights,
+                version_key,
+                target_epoch,
+            )
+        }
+
+        /// ---- Reveals committed weights as `reveal_weights` does, together with the hash of
+        /// their provenance. The commit must be the hash of the revealed values with
+        /// `weights_metadata_hash` appended after the version key.
+        ///
+        /// # Args:
+        /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+        ///   - The signature of the revealing hotkey.
+        ///
+        /// * `netuid` (`u16`):
+        ///   - The u16 network identifier.
+        ///
+        /// * `uids` (`Vec<u16>`):
+        ///   - The uids for the weights being revealed.
+        ///
+        /// * `values` (`Vec<u16>`):
+        ///   - The values of the weights being revealed.
+        ///
+        /// * `salt` (`Vec<u16>`):
+        ///   - The salt used to generate the commit hash.
+        ///
+        /// * `version_key` (`u64`):
+        ///   - The network version key.
+        ///
+        /// * `weights_metadata_hash` (`H256`):
+        ///   - The 32-byte hash stored alongside the weights.
+        ///
+        /// # Raises:
+        /// * Any error raised by `reveal_weights`.
+        ///
+        #[pallet::call_index(89)]
+        #[pallet::weight((Weight::from_parts(103_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(11))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::No))]
+        pub fn reveal_weights_with_metadata(
+            origin: T::RuntimeOrigin,
+            netuid: u16,
+            uids: Vec<u16>,
+            values: Vec<u16>,
+            salt: Vec<u16>,
+            version_key: u64,
+            weights_metadata_hash: H256,
+        ) -> DispatchResult {
+            Self::do_reveal_weights_with_metadata(
+                origin,
+                netuid,
+                uids,
+                values,
+                salt,
+                version_key,
+                Some(weights_metadata_hash),
+            )
+        }
+
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The caller, a hotkey who wishes to set their weights.
+        ///
+        /// * `netuid` (NetUid):
+        /// 	- The network uid we are setting these weights on, the root network.
+        ///
+        /// * `hotkey` (T::AccountId):
+        /// 	- The hotkey associated with the operation and the calling coldkey.
+        ///
+        /// * `dests` (Vec<NetUid>):
+        /// 	- The subnets weighted, i.e. j for w_ij.
+        ///
+        /// * 'weights' (Vec<u16>):
         /// 	- The u16 integer encoded weights. Interpreted as rational
         /// 		values in the range [0,1]. They must sum to in32::MAX.
         ///
@@ -1608,7 +2981,7 @@ pub mod pallet {
         /// # Raises:
         ///
         /// * NonAssociatedColdKey;
-        /// 	- Attempting to set weights on a non-associated cold key.
+        /// 	- The caller is neither the coldkey of the hotkey nor its weight setter.
         ///
         /// * 'SubNetworkDoesNotExist':
         /// 	- Attempting to set weights on a non-existent network.
@@ -1640,19 +3013,33 @@ pub mod pallet {
         /// * 'MaxWeightExceeded':
         /// 	- Attempting to set weights with max value exceeding limit.
         ///
+        /// * 'CommitRevealEnabled':
+        /// 	- Root weights must be committed and revealed by the hotkey.
+        ///
         #[pallet::call_index(8)]
         #[pallet::weight((Weight::from_parts(10_151_000_000, 0)
 		.saturating_add(T::DbWeight::get().reads(4104))
 		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
         pub fn set_root_weights(
             origin: OriginFor<T>,
-            netuid: u16,
+            netuid: NetUid,
             hotkey: T::AccountId,
-            dests: Vec<u16>,
+            dests: Vec<NetUid>,
             weights: Vec<u16>,
             version_key: u64,
         ) -> DispatchResult {
-            Self::do_set_root_weights(origin, netuid, hotkey, dests, weights, version_key)
+            if !Self::get_commit_reveal_weights_enabled(netuid.inner()) {
+                return Self::do_set_root_weights(
+                    origin,
+                    netuid,
+                    hotkey,
+                    dests,
+                    weights,
+                    version_key,
+                );
+            }
+
+            Err(Error::<T>::CommitRevealEnabled.into())
         }
 
         /// --- Sets the key as a delegate.
@@ -1811,35 +3198,84 @@ pub mod pallet {
             Self::do_add_stake(origin, hotkey, amount_staked)
         }
 
-        /// Remove stake from the staking account. The call must be made
-        /// from the coldkey account attached to the neuron metadata. Only this key
-        /// has permission to make staking and unstaking requests.
+        /// --- Stakes the caller's whole free balance to a hotkey, except for the fee
+        /// reserve, so that the coldkey can still pay for later transactions.
         ///
         /// # Args:
-        /// * 'origin': (<T as frame_system::Config>Origin):
+        ///  * 'origin': (<T as frame_system::Config>Origin):
         /// 	- The signature of the caller's coldkey.
         ///
-        /// * 'hotkey' (T::AccountId):
+        ///  * 'hotkey' (T::AccountId):
         /// 	- The associated hotkey account.
         ///
-        /// * 'amount_unstaked' (u64):
-        /// 	- The amount of stake to be added to the hotkey staking account.
-        ///
         /// # Event:
-        /// * StakeRemoved;
-        /// 	- On the successfully removing stake from the hotkey account.
+        ///  * StakeAdded;
+        /// 	- On the successfully adding stake to a global account.
         ///
         /// # Raises:
-        /// * 'NotRegistered':
-        /// 	- Thrown if the account we are attempting to unstake from is non existent.
-        ///
-        /// * 'NonAssociatedColdKey':
-        /// 	- Thrown if the coldkey does not own the hotkey we are unstaking from.
+        ///  * 'NotEnoughBalanceToStake':
+        /// 	- The coldkey balance does not exceed the fee reserve.
         ///
-        /// * 'NotEnoughStakeToWithdraw':
-        /// 	- Thrown if there is not enough stake on the hotkey to withdwraw this amount.
+        ///  * Any error raised by `add_stake`.
         ///
-        #[pallet::call_index(3)]
+        #[pallet::call_index(75)]
+        #[pallet::weight((Weight::from_parts(124_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(11))
+		.saturating_add(T::DbWeight::get().writes(7)), DispatchClass::Normal, Pays::No))]
+        pub fn stake_all_minus_fees(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            Self::do_stake_all_minus_fees(origin, hotkey)
+        }
+
+        /// --- Sets whether `add_stake` and `register_network` must leave the fee reserve
+        /// on the caller's coldkey, failing instead of sweeping the balance.
+        ///
+        /// # Args:
+        ///  * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        ///  * 'keep' (bool):
+        /// 	- Whether the fee reserve is kept.
+        ///
+        /// # Event:
+        ///  * KeepFeeReserveSet;
+        /// 	- On successfully updating the setting.
+        ///
+        #[pallet::call_index(76)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn set_keep_fee_reserve(origin: OriginFor<T>, keep: bool) -> DispatchResult {
+            Self::do_set_keep_fee_reserve(origin, keep)
+        }
+
+        /// Remove stake from the staking account. The call must be made
+        /// from the coldkey account attached to the neuron metadata. Only this key
+        /// has permission to make staking and unstaking requests.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The associated hotkey account.
+        ///
+        /// * 'amount_unstaked' (u64):
+        /// 	- The amount of stake to be added to the hotkey staking account.
+        ///
+        /// # Event:
+        /// * StakeRemoved;
+        /// 	- On the successfully removing stake from the hotkey account.
+        ///
+        /// # Raises:
+        /// * 'NotRegistered':
+        /// 	- Thrown if the account we are attempting to unstake from is non existent.
+        ///
+        /// * 'NonAssociatedColdKey':
+        /// 	- Thrown if the coldkey does not own the hotkey we are unstaking from.
+        ///
+        /// * 'NotEnoughStakeToWithdraw':
+        /// 	- Thrown if there is not enough stake on the hotkey to withdwraw this amount.
+        ///
+        #[pallet::call_index(3)]
         #[pallet::weight((Weight::from_parts(111_000_000, 0)
 		.saturating_add(Weight::from_parts(0, 43991))
 		.saturating_add(T::DbWeight::get().reads(10))
@@ -1903,6 +3339,7 @@ pub mod pallet {
         /// * 'ServingRateLimitExceeded':
         /// 	- Attempting to set prometheus information withing the rate limit min.
         ///
+        #[cfg(feature = "serving")]
         #[pallet::call_index(4)]
         #[pallet::weight((Weight::from_parts(46_000_000, 0)
 		.saturating_add(T::DbWeight::get().reads(4))
@@ -1931,173 +3368,1809 @@ pub mod pallet {
             )
         }
 
-        /// ---- Set prometheus information for the neuron.
+        /// ---- Set prometheus information for the neuron.
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the calling hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The u16 network identifier.
+        ///
+        /// * 'version' (u16):
+        /// 	-  The bittensor version identifier.
+        ///
+        /// * 'ip' (u128):
+        /// 	- The prometheus ip information as a u128 encoded integer.
+        ///
+        /// * 'port' (u16):
+        /// 	- The prometheus port information as a u16 encoded integer.
+        ///
+        /// * 'ip_type' (u8):
+        /// 	- The ip type v4 or v6.
+        ///
+        #[cfg(feature = "serving")]
+        #[pallet::call_index(5)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn serve_prometheus(
+            origin: OriginFor<T>,
+            netuid: u16,
+            version: u32,
+            ip: u128,
+            port: u16,
+            ip_type: u8,
+        ) -> DispatchResult {
+            Self::do_serve_prometheus(origin, netuid, version, ip, port, ip_type)
+        }
+
+        /// ---- Registers a new neuron to the subnetwork.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the calling hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The u16 network identifier.
+        ///
+        /// * 'block_number' ( u64 ):
+        /// 	- Block hash used to prove work done.
+        ///
+        /// * 'nonce' ( u64 ):
+        /// 	- Positive integer nonce used in POW.
+        ///
+        /// * 'work' ( Vec<u8> ):
+        /// 	- Vector encoded bytes representing work done.
+        ///
+        /// * 'hotkey' ( T::AccountId ):
+        /// 	- Hotkey to be registered to the network.
+        ///
+        /// * 'coldkey' ( T::AccountId ):
+        /// 	- Associated coldkey account.
+        ///
+        /// # Event:
+        /// * NeuronRegistered;
+        /// 	- On successfully registering a uid to a neuron slot on a subnetwork.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- Attempting to register to a non existent network.
+        ///
+        /// * 'TooManyRegistrationsThisBlock':
+        /// 	- This registration exceeds the total allowed on this network this block.
+        ///
+        /// * 'TooManyPowRegistrationsThisBlock':
+        /// 	- This registration exceeds the PoW registrations allowed on this network this block.
+        ///
+        /// * 'HotKeyAlreadyRegisteredInSubNet':
+        /// 	- The hotkey is already registered on this network.
+        ///
+        /// * 'InvalidWorkBlock':
+        /// 	- The work has been performed on a stale, future, or non existent block.
+        ///
+        /// * 'InvalidDifficulty':
+        /// 	- The work does not match the difficulty.
+        ///
+        /// * 'InvalidSeal':
+        /// 	- The seal is incorrect.
+        ///
+        #[pallet::call_index(6)]
+        #[pallet::weight((Weight::from_parts(192_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(24))
+		.saturating_add(T::DbWeight::get().writes(22)), DispatchClass::Normal, Pays::No))]
+        pub fn register(
+            origin: OriginFor<T>,
+            netuid: u16,
+            block_number: u64,
+            nonce: u64,
+            work: Vec<u8>,
+            hotkey: T::AccountId,
+            coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_registration(origin, netuid, block_number, nonce, work, hotkey, coldkey)
+        }
+
+        /// Register the hotkey to root network
+        #[pallet::call_index(62)]
+        #[pallet::weight((Weight::from_parts(164_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(23))
+		.saturating_add(T::DbWeight::get().writes(20)), DispatchClass::Normal, Pays::No))]
+        pub fn root_register(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            Self::do_root_register(origin, hotkey)
+        }
+
+        /// ---- Stakes to a hotkey and registers it to the root network atomically, so that a
+        /// failed registration never leaves the stake behind and the new stake counts towards
+        /// replacing the lowest staked root validator.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the staking coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- A new hotkey, or one owned by the coldkey.
+        ///
+        /// * 'amount' (u64):
+        /// 	- The stake to add, in RAO.
+        ///
+        /// # Event:
+        /// * StakeAdded;
+        /// 	- On staking to the hotkey.
+        ///
+        /// * NeuronRegistered;
+        /// 	- On registering the hotkey to the root network.
+        ///
+        /// # Raises:
+        /// * 'StakeToAddIsZero':
+        /// 	- The amount is zero.
+        ///
+        /// * 'NonAssociatedColdKey':
+        /// 	- The hotkey is owned by another coldkey.
+        ///
+        /// * 'HotKeyAlreadyRegisteredInSubNet':
+        /// 	- The hotkey is already registered to the root network.
+        ///
+        /// * Any error raised by `add_stake` or `root_register`.
+        ///
+        #[pallet::call_index(86)]
+        #[pallet::weight((Weight::from_parts(288_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(33))
+		.saturating_add(T::DbWeight::get().writes(27)), DispatchClass::Normal, Pays::No))]
+        pub fn root_register_and_stake(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_root_register_and_stake(origin, hotkey, amount)
+        }
+
+        /// Attempt to adjust the senate membership to include a hotkey
+        #[pallet::call_index(63)]
+        #[pallet::weight((Weight::from_parts(0, 0)
+		.saturating_add(T::DbWeight::get().reads(0))
+		.saturating_add(T::DbWeight::get().writes(0)), DispatchClass::Normal, Pays::Yes))]
+        pub fn adjust_senate(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            Self::do_adjust_senate(origin, hotkey)
+        }
+
+        /// User register a new subnetwork via burning token
+        #[pallet::call_index(7)]
+        #[pallet::weight((Weight::from_parts(177_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(26))
+		.saturating_add(T::DbWeight::get().writes(24)), DispatchClass::Normal, Pays::No))]
+        pub fn burned_register(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_burned_registration(origin, netuid, hotkey)
+        }
+
+        /// Registers by burning TAO as `burned_register`, declaring the role the hotkey
+        /// registers for. When one registration is left in the block, it goes to the role the
+        /// subnet is short of, and such registrations are ordered first in the pool.
+        #[pallet::call_index(116)]
+        #[pallet::weight((Weight::from_parts(177_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(28))
+		.saturating_add(T::DbWeight::get().writes(25)), DispatchClass::Normal, Pays::No))]
+        pub fn burned_register_with_role(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            role: NeuronRole,
+        ) -> DispatchResult {
+            Self::do_burned_registration_with_role(origin, netuid, hotkey, Some(role))
+        }
+
+        /// The extrinsic for user to change its hotkey
+        #[pallet::call_index(70)]
+        #[pallet::weight((Weight::from_parts(1_940_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(272))
+		.saturating_add(T::DbWeight::get().writes(527)), DispatchClass::Operational, Pays::No))]
+        pub fn swap_hotkey(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            new_hotkey: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_swap_hotkey(origin, &hotkey, &new_hotkey)
+        }
+
+        /// The extrinsic for user to change the coldkey associated with their account.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - The origin of the call, must be signed by the old coldkey.
+        /// * `old_coldkey` - The current coldkey associated with the account.
+        /// * `new_coldkey` - The new coldkey to be associated with the account.
+        ///
+        /// # Returns
+        ///
+        /// Returns a `DispatchResultWithPostInfo` indicating success or failure of the operation.
+        ///
+        /// # Weight
+        ///
+        /// Weight is calculated based on the number of database reads and writes.
+        #[pallet::call_index(71)]
+        #[pallet::weight((Weight::from_parts(1_940_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(272))
+		.saturating_add(T::DbWeight::get().writes(527)), DispatchClass::Operational, Pays::No))]
+        pub fn swap_coldkey(
+            origin: OriginFor<T>,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_swap_coldkey(origin, &new_coldkey)
+        }
+        /// ---- Set or clear feature flags on a subnet.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The subnet owner's coldkey or operator hotkey, or root.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet to update.
+        ///
+        /// * 'flags' (u32):
+        /// 	- The `subnet_features` bits to change.
+        ///
+        /// * 'enabled' (bool):
+        /// 	- Whether the bits are set or cleared.
+        ///
+        /// # Event:
+        /// * SubnetFeatureFlagsSet;
+        /// 	- On successfully updating the flags.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'InvalidSubnetFeatureFlags':
+        /// 	- No flags were given, or an unknown flag was given.
+        ///
+        #[pallet::call_index(73)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn set_subnet_feature_flags(
+            origin: OriginFor<T>,
+            netuid: u16,
+            flags: u32,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::do_set_subnet_feature_flags(origin, netuid, flags, enabled)
+        }
+
+        /// ---- Schedules a maintenance window on a subnet. Epochs are skipped while the
+        /// window is open and the subnet's emission accrues to its pending emission, which
+        /// is drained by the first epoch after the window. Serving and registration continue.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The subnet owner's coldkey or operator hotkey, or root.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet to pause.
+        ///
+        /// * 'start' (u64):
+        /// 	- The first block of the window.
+        ///
+        /// * 'duration' (u64):
+        /// 	- The number of blocks in the window, zero clears the scheduled window.
+        ///
+        /// # Event:
+        /// * MaintenanceScheduled;
+        /// 	- On successfully scheduling the window.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'MaintenanceWindowTooLong':
+        /// 	- The duration is above MaxMaintenanceDuration.
+        ///
+        /// * 'MaintenanceWindowInPast':
+        /// 	- The window starts before the current block.
+        ///
+        #[pallet::call_index(77)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn schedule_maintenance(
+            origin: OriginFor<T>,
+            netuid: u16,
+            start: u64,
+            duration: u64,
+        ) -> DispatchResult {
+            Self::do_schedule_maintenance(origin, netuid, start, duration)
+        }
+
+        /// ---- Signals whether the calling validator wants the epochs of its subnet paused, for
+        /// an emergency such as a consensus attack. Once the signalling validators hold
+        /// EpochPauseThreshold of the stake of the subnet's permitted validators, block_step
+        /// pauses its epochs for EpochPauseDuration blocks. Pending emission keeps accruing and
+        /// is drained by the first epoch after the pause.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the validator's hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet to pause.
+        ///
+        /// * 'pause' (bool):
+        /// 	- Whether to signal a pause, false withdraws the signal.
+        ///
+        /// # Event:
+        /// * EpochPauseSignalled;
+        /// 	- On successfully recording the signal.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey has no uid on the subnet.
+        ///
+        /// * 'NeuronNoValidatorPermit':
+        /// 	- The hotkey's uid has no validator permit.
+        ///
+        #[pallet::call_index(87)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn signal_epoch_pause(
+            origin: OriginFor<T>,
+            netuid: u16,
+            pause: bool,
+        ) -> DispatchResult {
+            Self::do_signal_epoch_pause(origin, netuid, pause)
+        }
+
+        /// ---- Attests whether the calling validator could reach the axon of a uid, replacing
+        /// its previous attestation of the uid. The attestations of a subnet's permitted
+        /// validators add up to a reachability score per uid, weighed by stake and decayed
+        /// over AxonReachabilityHalfLife, exposed in NeuronInfo.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the validator's hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet of the attested uid.
+        ///
+        /// * 'uid' (u16):
+        /// 	- The uid whose axon was probed.
+        ///
+        /// * 'reachable' (bool):
+        /// 	- Whether the axon answered.
+        ///
+        /// # Event:
+        /// * AxonAttested;
+        /// 	- On successfully recording the attestation.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey has no uid on the subnet.
+        ///
+        /// * 'NeuronNoValidatorPermit':
+        /// 	- The hotkey's uid has no validator permit.
+        ///
+        /// * 'AttestedUidNotFound':
+        /// 	- The attested uid does not exist on the subnet.
+        ///
+        #[pallet::call_index(100)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn attest_axon(
+            origin: OriginFor<T>,
+            netuid: u16,
+            uid: u16,
+            reachable: bool,
+        ) -> DispatchResult {
+            Self::do_attest_axon(origin, netuid, uid, reachable)
+        }
+
+        /// ---- Sets the child keys of a hotkey on a subnet, each attributed a proportion of
+        /// the hotkey's stake there in the epochs, so a validator can split its operational
+        /// keys without moving stake. The children replace the current ones once
+        /// PendingChildKeyCooldown blocks pass; an empty list revokes them.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey owning the hotkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The parent hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet the children take the stake on.
+        ///
+        /// * 'children' (Vec<(u64, T::AccountId)>):
+        /// 	- The proportion of the stake, out of u64::MAX, and hotkey of each child.
+        ///
+        /// # Event:
+        /// * ChildKeysPending;
+        /// 	- On successfully scheduling the children.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'NonAssociatedColdKey':
+        /// 	- The hotkey is not owned by the calling coldkey.
+        ///
+        /// * 'TooManyChildren':
+        /// 	- More than MAX_CHILDREN children are given.
+        ///
+        /// * 'InvalidChild':
+        /// 	- The hotkey is given as its own child.
+        ///
+        /// * 'DuplicateChild':
+        /// 	- A child is given more than once.
+        ///
+        /// * 'ChildProportionsOverflow':
+        /// 	- The proportions of the children add up to more than u64::MAX.
+        ///
+        /// * 'TooManyParents':
+        /// 	- A child already has MAX_PARENTS other parents on the subnet.
+        ///
+        #[pallet::call_index(101)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3_u64.saturating_add(MAX_CHILDREN as u64)))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_children(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+            children: Vec<(u64, T::AccountId)>,
+        ) -> DispatchResult {
+            Self::do_set_children(origin, hotkey, netuid, children)
+        }
+
+        /// ---- Wipes the axons, prometheus endpoint and weight row of a neuron without
+        /// deregistering it, for an owner decommissioning its infrastructure. Rate limited by
+        /// the subnet's serving rate limit.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey owning the hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet the neuron is registered on.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey of the neuron.
+        ///
+        /// # Event:
+        /// * NeuronCleared;
+        /// 	- On successfully clearing the neuron.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'NonAssociatedColdKey':
+        /// 	- The hotkey is not owned by the calling coldkey.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey is not registered on the subnet.
+        ///
+        /// * 'ServingRateLimitExceeded':
+        /// 	- The neuron was cleared more recently than the subnet's serving rate limit.
+        ///
+        #[pallet::call_index(102)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(8)), DispatchClass::Normal, Pays::Yes))]
+        pub fn clear_neuron(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_clear_neuron(origin, netuid, hotkey)
+        }
+
+        /// ---- Schedules the swap of the calling coldkey to a new coldkey once the arbitration
+        /// period passes, migrating its balance, stake and subnet ownership. Scheduling a
+        /// second destination disputes the swap until governance arbitrates it.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey to swap, paying the key swap cost.
+        ///
+        /// * 'new_coldkey' (T::AccountId):
+        /// 	- The coldkey to swap to.
+        ///
+        #[pallet::call_index(103)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Operational, Pays::Yes))]
+        pub fn schedule_swap_coldkey(
+            origin: OriginFor<T>,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_schedule_swap_coldkey(origin, &new_coldkey)
+        }
+
+        /// ---- Resolves the swap scheduled for a coldkey, swapping it to one of its scheduled
+        /// destinations at once or cancelling it.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- Must be root.
+        ///
+        /// * 'old_coldkey' (T::AccountId):
+        /// 	- The coldkey the swap is scheduled for.
+        ///
+        /// * 'new_coldkey' (Option<T::AccountId>):
+        /// 	- The destination to swap to, None cancels the swap.
+        ///
+        #[pallet::call_index(104)]
+        #[pallet::weight((Weight::from_parts(1_940_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(272))
+		.saturating_add(T::DbWeight::get().writes(527)), DispatchClass::Operational, Pays::No))]
+        pub fn arbitrate_coldkey_swap(
+            origin: OriginFor<T>,
+            old_coldkey: T::AccountId,
+            new_coldkey: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_arbitrate_coldkey_swap(origin, &old_coldkey, new_coldkey)
+        }
+
+        /// ---- Votes to cap the take of a delegate the caller nominates. Once nominators
+        /// holding TakeCapThreshold of the delegate's stake vote for a cap or below, the take
+        /// is held to it for TakeCapDuration blocks from the next root tempo boundary.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the nominating coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The delegate to cap the take of.
+        ///
+        /// * 'cap' (Option<u16>):
+        /// 	- The take to cap the delegate at, within the delegate take bounds. None
+        /// 	withdraws the vote.
+        ///
+        #[pallet::call_index(105)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn vote_delegate_take_cap(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            cap: Option<u16>,
+        ) -> DispatchResult {
+            Self::do_vote_delegate_take_cap(origin, hotkey, cap)
+        }
+
+        /// ---- Sets weights on several subnets in one transaction. Each entry is checked and
+        /// applied as `set_weights` would on its own, so an entry that fails, for instance on
+        /// the rate limit of its subnet, leaves the others set and emits
+        /// WeightsBatchEntryFailed.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the calling hotkey.
+        ///
+        /// * 'batch' (Vec<(u16, Vec<u16>, Vec<u16>, u64)>):
+        /// 	- The (netuid, uids, values, version_key) of each subnet to set weights on.
+        ///
+        /// # Raises:
+        /// * 'WeightsBatchTooLarge':
+        /// 	- The batch has more than MAX_WEIGHTS_BATCH_SIZE entries.
+        ///
+        #[pallet::call_index(106)]
+        #[pallet::weight((Weight::from_parts(22_060_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(4106))
+        .saturating_add(T::DbWeight::get().writes(2))
+        .saturating_mul(batch.len() as u64), DispatchClass::Normal, Pays::No))]
+        pub fn batch_set_weights(
+            origin: OriginFor<T>,
+            batch: Vec<(u16, Vec<u16>, Vec<u16>, u64)>,
+        ) -> DispatchResult {
+            Self::do_batch_set_weights(origin, batch)
+        }
+
+        /// ---- Serves the primary axon as `serve_axon` does, along with the TLS public key or
+        /// certificate fingerprint clients dialing it verify the endpoint against.
+        ///
+        /// # Args:
+        /// * 'origin', 'netuid', 'version', 'ip', 'port', 'ip_type', 'protocol',
+        /// 'placeholder1', 'placeholder2':
+        /// 	- As for `serve_axon`.
+        ///
+        /// * 'certificate' (Vec<u8>):
+        /// 	- The TLS public key or certificate fingerprint of the axon.
+        ///
+        /// # Event:
+        /// * AxonServed;
+        /// 	- On successfully serving the axon info.
+        ///
+        /// * NeuronCertificateSet;
+        /// 	- On successfully storing the certificate.
+        ///
+        /// # Raises:
+        /// * 'InvalidCertificate':
+        /// 	- The certificate is empty or longer than MAX_NEURON_CERTIFICATE_BYTES.
+        ///
+        /// * Any error raised by `serve_axon`.
+        ///
+        #[cfg(feature = "serving")]
+        #[pallet::call_index(107)]
+        #[pallet::weight((Weight::from_parts(46_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn serve_axon_tls(
+            origin: OriginFor<T>,
+            netuid: u16,
+            version: u32,
+            ip: u128,
+            port: u16,
+            ip_type: u8,
+            protocol: u8,
+            placeholder1: u8,
+            placeholder2: u8,
+            certificate: Vec<u8>,
+        ) -> DispatchResult {
+            Self::do_serve_axon_tls(
+                origin,
+                netuid,
+                version,
+                ip,
+                port,
+                ip_type,
+                protocol,
+                placeholder1,
+                placeholder2,
+                certificate,
+            )
+        }
+
+        /// ---- Sets the identity of the calling coldkey, holding IdentityDeposit from its
+        /// balance while one is set. An identity with every field empty clears it and refunds
+        /// the deposit.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey.
+        ///
+        /// * 'identity' (ChainIdentity):
+        /// 	- The name, url, image, discord and description to publish.
+        ///
+        /// # Event:
+        /// * IdentitySet;
+        /// 	- On setting the identity.
+        ///
+        /// * IdentityCleared;
+        /// 	- On clearing the identity.
+        ///
+        /// # Raises:
+        /// * 'InvalidIdentity':
+        /// 	- A field is longer than its bound.
+        ///
+        /// * 'NotEnoughBalanceForIdentityDeposit':
+        /// 	- The coldkey cannot pay the deposit.
+        ///
+        #[cfg(feature = "identity")]
+        #[pallet::call_index(108)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_identity(origin: OriginFor<T>, identity: ChainIdentity) -> DispatchResult {
+            Self::do_set_identity(origin, identity)
+        }
+
+        /// ---- Sets the identity of a subnet, holding IdentityDeposit from the balance of
+        /// its owner while one is set. An identity with every field empty clears it and
+        /// refunds the deposit.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the subnet owner coldkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet to set the identity of.
+        ///
+        /// * 'identity' (ChainIdentity):
+        /// 	- The name, url, image, discord and description to publish.
+        ///
+        /// # Event:
+        /// * SubnetIdentitySet;
+        /// 	- On setting the identity.
+        ///
+        /// * SubnetIdentityCleared;
+        /// 	- On clearing the identity.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'NotSubnetOwner':
+        /// 	- The caller is not the owner of the subnet.
+        ///
+        /// * 'InvalidIdentity':
+        /// 	- A field is longer than its bound.
+        ///
+        /// * 'NotEnoughBalanceForIdentityDeposit':
+        /// 	- The owner cannot pay the deposit.
+        ///
+        #[cfg(feature = "identity")]
+        #[pallet::call_index(109)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_subnet_identity(
+            origin: OriginFor<T>,
+            netuid: u16,
+            identity: ChainIdentity,
+        ) -> DispatchResult {
+            Self::do_set_subnet_identity(origin, netuid, identity)
+        }
+
+        /// ---- Registers a hotkey to the root network by burning the Burn of the root
+        /// network, which is recycled into the emission. When the root network is full, the
+        /// validator with the lowest stake is replaced once past the ImmunityPeriod of the
+        /// root network, whatever the stake of the new hotkey.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the paying coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- A new hotkey, or one owned by the coldkey.
+        ///
+        /// # Event:
+        /// * NeuronRegistered;
+        /// 	- On registering the hotkey to the root network.
+        ///
+        /// # Raises:
+        /// * 'TooManyBurnRegistrationsThisBlock':
+        /// 	- The root network reached its burn registrations for the block.
+        ///
+        /// * 'RootValidatorInImmunity':
+        /// 	- The root network is full and the validator to replace is in immunity.
+        ///
+        /// * 'NotEnoughBalanceToStake':
+        /// 	- The coldkey cannot pay the burn.
+        ///
+        #[pallet::call_index(110)]
+        #[pallet::weight((Weight::from_parts(180_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(27))
+		.saturating_add(T::DbWeight::get().writes(24)), DispatchClass::Normal, Pays::No))]
+        pub fn burned_root_register(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            Self::do_burned_root_register(origin, hotkey)
+        }
+
+        /// ---- Removes the caller's own weight rows on several subnets, for a hotkey that no
+        /// longer validates them. Subnets the hotkey is not registered on or holds no weights
+        /// on are skipped.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the calling hotkey.
+        ///
+        /// * 'netuids' (Vec<u16>):
+        /// 	- The subnets to remove the weight rows of.
+        ///
+        /// # Event:
+        /// * WeightsPurged;
+        /// 	- For each weight row removed.
+        ///
+        /// # Raises:
+        /// * 'WeightsBatchTooLarge':
+        /// 	- More than MAX_WEIGHTS_BATCH_SIZE netuids are given.
+        ///
+        #[pallet::call_index(111)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(2))
+        .saturating_add(T::DbWeight::get().writes(3))
+        .saturating_mul(netuids.len() as u64), DispatchClass::Normal, Pays::Yes))]
+        pub fn purge_my_weights(origin: OriginFor<T>, netuids: Vec<u16>) -> DispatchResult {
+            Self::do_purge_my_weights(origin, netuids)
+        }
+
+        /// ---- Unregisters a neuron from a subnet, freeing its uid for a new registration
+        /// rather than holding it until pruned. The last uid of the subnet moves into the freed
+        /// slot. Within UnregisterRefundWindow blocks of a burned registration, the coldkey
+        /// gets back UnregisterRefundShare of the burn.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey owning the hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet to leave.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey of the neuron.
+        ///
+        /// # Event:
+        /// * NeuronUnregistered;
+        /// 	- On unregistering the neuron.
+        ///
+        /// * NeuronUidMoved;
+        /// 	- When the last uid of the subnet moves into the freed slot.
+        ///
+        /// # Raises:
+        /// * 'RegistrationNotPermittedOnRootSubnet':
+        /// 	- The subnet is the root network.
+        ///
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'NonAssociatedColdKey':
+        /// 	- The caller does not own the hotkey.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey holds no uid on the subnet.
+        ///
+        /// * 'StakeIsLocked':
+        /// 	- Stake is locked on the hotkey.
+        ///
+        #[pallet::call_index(112)]
+        #[pallet::weight((Pallet::<T>::unregister_weight(*netuid), DispatchClass::Normal, Pays::Yes))]
+        pub fn unregister(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_unregister(origin, netuid, hotkey)
+        }
+
+        /// ---- Returns the listed nominations below NominatorMinRequiredStake to their
+        /// nominator coldkeys, so dust nominations stop bloating the stake maps. Anyone can
+        /// call it.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- Any signed account.
+        ///
+        /// * 'nominations' (Vec<(T::AccountId, T::AccountId)>):
+        /// 	- The ( hotkey, coldkey ) of each nomination to check.
+        ///
+        /// # Event:
+        /// * SmallNominationCleared;
+        /// 	- For each nomination cleared.
+        ///
+        /// # Raises:
+        /// * 'TooManyNominationsToClear':
+        /// 	- More than MAX_NOMINATIONS_CLEARED_PER_CALL nominations are listed.
+        ///
+        #[pallet::call_index(115)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(4))
+        .saturating_add(T::DbWeight::get().writes(7))
+        .saturating_mul(nominations.len() as u64), DispatchClass::Normal, Pays::Yes))]
+        pub fn clear_small_nominations(
+            origin: OriginFor<T>,
+            nominations: Vec<(T::AccountId, T::AccountId)>,
+        ) -> DispatchResult {
+            Self::do_clear_small_nominations(origin, nominations)
+        }
+
+        /// ---- Subscribes the caller to an alert once the total stake of a hotkey falls below
+        /// an amount. block_step emits StakeThresholdBreached when it does and clears the
+        /// subscription.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the subscribing coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey whose total stake is watched.
+        ///
+        /// * 'amount' (u64):
+        /// 	- The threshold, zero cancels the subscription.
+        ///
+        /// # Event:
+        /// * StakeThresholdSubscribed;
+        /// 	- On successfully subscribing or cancelling.
+        ///
+        /// # Raises:
+        /// * 'HotKeyAccountNotExists':
+        /// 	- The hotkey has no account.
+        ///
+        /// * 'TooManyStakeSubscriptions':
+        /// 	- The coldkey already holds the maximum number of subscriptions.
+        ///
+        #[pallet::call_index(91)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn notify_if_stake_below(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_notify_if_stake_below(origin, hotkey, amount)
+        }
+
+        /// ---- Transfers the ownership of a subnet to a new coldkey, which proves it holds
+        /// its key by signing `subnet_owner_rotation_payload`. Only this subnet changes hands,
+        /// unlike a coldkey swap.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the current owner coldkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet changing hands.
+        ///
+        /// * 'new_coldkey' (T::AccountId):
+        /// 	- The coldkey becoming the owner.
+        ///
+        /// * 'signature' (T::KeySignature):
+        /// 	- The signature of the rotation payload by the new coldkey.
+        ///
+        /// # Event:
+        /// * SubnetOwnerKeyRotated;
+        /// 	- On successfully transferring the ownership.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'NotSubnetOwner':
+        /// 	- The caller is not the owner coldkey of the subnet.
+        ///
+        /// * 'ColdkeyIsInArbitration':
+        /// 	- The owner coldkey has a coldkey swap in arbitration.
+        ///
+        /// * 'SameColdkey':
+        /// 	- The new coldkey is the current owner.
+        ///
+        /// * 'InvalidOwnerKeySignature':
+        /// 	- The signature is not the new coldkey's over the rotation payload.
+        ///
+        #[pallet::call_index(92)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Operational, Pays::No))]
+        pub fn rotate_subnet_owner_key(
+            origin: OriginFor<T>,
+            netuid: u16,
+            new_coldkey: T::AccountId,
+            signature: T::KeySignature,
+        ) -> DispatchResult {
+            Self::do_rotate_subnet_owner_key(origin, netuid, new_coldkey, signature)
+        }
+
+        /// ---- Proposes a new owner coldkey for a subnet, who takes it over with
+        /// accept_subnet_ownership. A new proposal replaces the pending one.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the current owner coldkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet changing hands.
+        ///
+        /// * 'new_owner' (T::AccountId):
+        /// 	- The coldkey proposed as the owner.
+        ///
+        /// # Event:
+        /// * SubnetOwnershipTransferProposed;
+        /// 	- On successfully proposing the new owner.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'NotSubnetOwner':
+        /// 	- The caller is not the owner coldkey of the subnet.
+        ///
+        /// * 'ColdkeyIsInArbitration':
+        /// 	- The owner coldkey has a coldkey swap in arbitration.
+        ///
+        /// * 'SameColdkey':
+        /// 	- The new owner is the current owner.
+        ///
+        #[pallet::call_index(113)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn transfer_subnet_ownership(
+            origin: OriginFor<T>,
+            netuid: u16,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_transfer_subnet_ownership(origin, netuid, new_owner)
+        }
+
+        /// ---- Takes over the ownership of a subnet proposed to the caller with
+        /// transfer_subnet_ownership, along with its lock and owner cut.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the pending owner coldkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet changing hands.
+        ///
+        /// # Event:
+        /// * SubnetOwnershipTransferred;
+        /// 	- On successfully transferring the ownership.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'NotPendingSubnetOwner':
+        /// 	- The caller is not the pending owner of the subnet.
+        ///
+        /// * 'ColdkeyIsInArbitration':
+        /// 	- The current owner coldkey has a coldkey swap in arbitration.
+        ///
+        #[pallet::call_index(114)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn accept_subnet_ownership(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_accept_subnet_ownership(origin, netuid)
+        }
+
+        /// ---- Lets another account set the root weights of the calling hotkey, so the
+        /// hotkey can be kept cold while an operational key submits its weights.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+        /// 	- The hotkey authorizing the signer.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The root network.
+        ///
+        /// * 'signer' (T::AccountId):
+        /// 	- The account allowed to call set_root_weights for the hotkey.
+        ///
+        /// # Event:
+        /// * WeightSetterAuthorized;
+        /// 	- On successfully authorizing the signer.
+        ///
+        /// # Raises:
+        /// * 'NotRootSubnet':
+        /// 	- The netuid is not the root network.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey is not a root validator.
+        ///
+        #[pallet::call_index(117)]
+        #[pallet::weight((Weight::from_parts(12_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn authorize_weight_setter(
+            origin: OriginFor<T>,
+            netuid: u16,
+            signer: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_authorize_weight_setter(origin, netuid, signer)
+        }
+
+        /// ---- Revokes the weight setter of the calling hotkey.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+        /// 	- The hotkey revoking its weight setter.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The network the weight setter was authorized on.
+        ///
+        /// # Event:
+        /// * WeightSetterRevoked;
+        /// 	- On successfully revoking the weight setter.
+        ///
+        /// # Raises:
+        /// * 'NoWeightSetter':
+        /// 	- The hotkey has no weight setter on the network.
+        ///
+        #[pallet::call_index(118)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn revoke_weight_setter(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_revoke_weight_setter(origin, netuid)
+        }
+
+        /// --- Adds stake to a hotkey and locks it for a number of blocks. Until the lock
+        /// ends the stake can't be removed, and it weighs in the dividends of the hotkey by
+        /// the StakeLockBoosts multiplier of the lock duration.
+        ///
+        /// # Args:
+        ///  * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        ///  * 'hotkey' (T::AccountId):
+        /// 	- The associated hotkey account.
+        ///
+        ///  * 'amount_staked' (u64):
+        /// 	- The amount of stake to be added and locked.
+        ///
+        ///  * 'lock_blocks' (u64):
+        /// 	- The number of blocks the stake is locked for, at most MAX_STAKE_LOCK_BLOCKS.
+        ///
+        /// # Event:
+        ///  * StakeLocked;
+        /// 	- On successfully adding and locking the stake.
+        ///
+        /// # Raises:
+        ///  * 'InvalidStakeLockDuration':
+        /// 	- The lock duration is zero or too long.
+        ///
+        ///  * The errors raised by `add_stake`.
+        ///
+        #[pallet::call_index(119)]
+        #[pallet::weight((Weight::from_parts(134_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(12))
+		.saturating_add(T::DbWeight::get().writes(9)), DispatchClass::Normal, Pays::No))]
+        pub fn add_stake_locked(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            amount_staked: u64,
+            lock_blocks: u64,
+        ) -> DispatchResult {
+            Self::do_add_stake_locked(origin, hotkey, amount_staked, lock_blocks)
+        }
+
+        /// --- Credits the caller's coldkey with the stake it removed whose unstake cooldown
+        /// ended.
+        ///
+        /// # Args:
+        ///  * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// # Event:
+        ///  * UnstakeClaimed;
+        /// 	- On successfully claiming the removed stake.
+        ///
+        /// # Raises:
+        ///  * 'NothingToClaim':
+        /// 	- No removed stake of the coldkey finished its cooldown.
+        ///
+        #[pallet::call_index(120)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn claim_unstaked(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_claim_unstaked(origin)
+        }
+
+        /// --- Adds stake to several hotkeys in one transaction. Entries for the same hotkey
+        /// are summed and each hotkey is staked to as `add_stake` does. The batch is atomic,
+        /// an entry that fails fails the whole call.
+        ///
+        /// # Args:
+        ///  * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        ///  * 'stakes' (Vec<(T::AccountId, u64)>):
+        /// 	- The (hotkey, amount) of each stake to add.
+        ///
+        /// # Event:
+        ///  * StakeAdded;
+        /// 	- Once for each hotkey staked to.
+        ///
+        /// # Raises:
+        ///  * 'StakeBatchTooLarge':
+        /// 	- The batch has more than MAX_STAKE_BATCH_SIZE entries.
+        ///
+        ///  * 'NotEnoughBalanceToStake':
+        /// 	- The coldkey balance does not cover the total of the batch.
+        ///
+        ///  * The errors raised by `add_stake`.
+        ///
+        #[pallet::call_index(121)]
+        #[pallet::weight((Weight::from_parts(124_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(10))
+		.saturating_add(T::DbWeight::get().writes(7))
+		.saturating_mul(stakes.len() as u64), DispatchClass::Normal, Pays::No))]
+        pub fn add_stake_multiple(
+            origin: OriginFor<T>,
+            stakes: Vec<(T::AccountId, u64)>,
+        ) -> DispatchResult {
+            Self::do_add_stake_multiple(origin, stakes)
+        }
+
+        /// --- Removes stake from several hotkeys in one transaction. Entries for the same
+        /// hotkey are summed and each hotkey is unstaked from as `remove_stake` does. The
+        /// batch is atomic, an entry that fails fails the whole call.
+        ///
+        /// # Args:
+        ///  * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        ///  * 'stakes' (Vec<(T::AccountId, u64)>):
+        /// 	- The (hotkey, amount) of each stake to remove.
+        ///
+        /// # Event:
+        ///  * StakeRemoved;
+        /// 	- Once for each hotkey unstaked from.
+        ///
+        /// # Raises:
+        ///  * 'StakeBatchTooLarge':
+        /// 	- The batch has more than MAX_STAKE_BATCH_SIZE entries.
+        ///
+        ///  * The errors raised by `remove_stake`.
+        ///
+        #[pallet::call_index(122)]
+        #[pallet::weight((Weight::from_parts(111_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(10))
+		.saturating_add(T::DbWeight::get().writes(7))
+		.saturating_mul(stakes.len() as u64), DispatchClass::Normal, Pays::No))]
+        pub fn remove_stake_multiple(
+            origin: OriginFor<T>,
+            stakes: Vec<(T::AccountId, u64)>,
+        ) -> DispatchResult {
+            Self::do_remove_stake_multiple(origin, stakes)
+        }
+
+        /// ---- Sets the caller's commitment on a subnet, an arbitrary blob of miner metadata
+        /// such as a model or dataset hash.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet the commitment is made on.
+        ///
+        /// * 'commitment' (Vec<u8>):
+        /// 	- The committed bytes, an empty commitment clears the existing one.
+        ///
+        /// # Event:
+        /// * CommitmentSet;
+        /// 	- On successfully setting the commitment.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey is not registered on the subnet.
+        ///
+        /// * 'CommitmentTooLarge':
+        /// 	- The commitment is larger than the subnet's MaxCommitmentSize.
+        ///
+        /// * 'CommitmentRateLimitExceeded':
+        /// 	- The hotkey committed on the subnet within its CommitmentRateLimit.
+        ///
+        #[cfg(feature = "commitments")]
+        #[pallet::call_index(78)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn set_commitment(
+            origin: OriginFor<T>,
+            netuid: u16,
+            commitment: Vec<u8>,
+        ) -> DispatchResult {
+            Self::do_set_commitment(origin, netuid, commitment)
+        }
+
+        /// ---- Associates an EVM address with the caller's hotkey on a subnet, so that EVM and
+        /// ink! contracts can attribute actions of the EVM key to the neuron. The EVM key proves
+        /// possession by signing the association digest as an Ethereum personal message.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet the hotkey is registered on.
+        ///
+        /// * 'evm_address' (H160):
+        /// 	- The EVM address to associate, replacing any previous one.
+        ///
+        /// * 'block_number' (u64):
+        /// 	- The recent block named in the signed digest.
+        ///
+        /// * 'signature' ([u8; 65]):
+        /// 	- The EVM key's recoverable signature over `evm_key_association_digest`.
+        ///
+        /// # Event:
+        /// * EvmKeyAssociated;
+        /// 	- On successfully associating the EVM address.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey is not registered on the subnet.
+        ///
+        /// * 'EvmKeySignatureExpired':
+        /// 	- The signed block is in the future or older than EVM_KEY_SIGNATURE_VALIDITY blocks.
+        ///
+        /// * 'InvalidEvmKeySignature':
+        /// 	- The signature does not recover to the EVM address.
+        ///
+        #[pallet::call_index(93)]
+        #[pallet::weight((Weight::from_parts(60_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn associate_evm_key(
+            origin: OriginFor<T>,
+            netuid: u16,
+            evm_address: H160,
+            block_number: u64,
+            signature: [u8; 65],
+        ) -> DispatchResult {
+            Self::do_associate_evm_key(origin, netuid, evm_address, block_number, signature)
+        }
+
+        /// ---- Sets how the emission of a subnet is split between its owner, its miners and
+        /// its validators, in place of the global owner cut and the even split of incentive
+        /// and dividends.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The subnet owner, its operator hotkey, or root.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet whose emission is split.
+        ///
+        /// * 'split' (EmissionSplit):
+        /// 	- The owner, miner and validator shares in basis points.
+        ///
+        /// # Event:
+        /// * EmissionSplitSet;
+        /// 	- On successfully setting the split.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'InvalidEmissionSplit':
+        /// 	- The shares do not sum to 10_000 basis points.
+        ///
+        #[pallet::call_index(94)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn set_emission_split(
+            origin: OriginFor<T>,
+            netuid: u16,
+            split: EmissionSplit,
+        ) -> DispatchResult {
+            Self::do_set_emission_split(origin, netuid, split)
+        }
+
+        /// ---- Puts a change of an owner-settable hyperparameter of a subnet to a vote of its
+        /// permitted validators, weighed by stake. If the stake voting for it reaches
+        /// ReferendumThreshold of the stake of the permitted validators once the referendum
+        /// closes after ReferendumDuration blocks, block_step applies the change.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The subnet owner, its operator hotkey, or root.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet the referendum is held on.
+        ///
+        /// * 'param' (OwnerHyperparam):
+        /// 	- The hyperparameter to change.
+        ///
+        /// * 'value' (u16):
+        /// 	- The proposed value, within the bounds the owner can set it to.
+        ///
+        /// # Event:
+        /// * SubnetReferendumOpened;
+        /// 	- On successfully opening the referendum.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'ReferendumAlreadyOpen':
+        /// 	- The subnet already has an open referendum.
+        ///
+        /// * 'ReferendumValueOutOfBounds':
+        /// 	- The value is outside the owner's bounds for the hyperparameter.
+        ///
+        #[pallet::call_index(95)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn propose_subnet_hyperparam(
+            origin: OriginFor<T>,
+            netuid: u16,
+            param: OwnerHyperparam,
+            value: u16,
+        ) -> DispatchResult {
+            Self::do_propose_subnet_hyperparam(origin, netuid, param, value)
+        }
+
+        /// ---- Votes on the open referendum of a subnet as one of its permitted validators.
+        /// The vote is weighed by the total stake of the hotkey, which is locked until the
+        /// referendum closes: unstaking from the hotkey cannot take its stake below it.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the validator's hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet the referendum is held on.
+        ///
+        /// * 'approve' (bool):
+        /// 	- Whether the vote approves the change.
+        ///
+        /// # Event:
+        /// * SubnetReferendumVoted;
+        /// 	- On successfully recording the vote.
+        ///
+        /// # Raises:
+        /// * 'NoOpenReferendum':
+        /// 	- The subnet has no referendum open for votes.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey has no uid on the subnet.
+        ///
+        /// * 'NeuronNoValidatorPermit':
+        /// 	- The hotkey's uid has no validator permit.
+        ///
+        /// * 'AlreadyVotedInReferendum':
+        /// 	- The hotkey already voted on the referendum.
+        ///
+        #[pallet::call_index(98)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn vote_subnet_referendum(
+            origin: OriginFor<T>,
+            netuid: u16,
+            approve: bool,
+        ) -> DispatchResult {
+            Self::do_vote_subnet_referendum(origin, netuid, approve)
+        }
+
+        /// ---- Designates an operator hotkey that may call the owner-gated calls of a subnet,
+        /// so the owner coldkey can be kept offline. Replaces any previous operator.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The subnet owner's coldkey, or root.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet to operate.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The operator hotkey.
+        ///
+        /// # Event:
+        /// * SubnetOwnerHotkeySet;
+        /// 	- On successfully setting the operator.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        #[pallet::call_index(79)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn set_subnet_owner_hotkey(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_set_subnet_owner_hotkey(origin, netuid, hotkey)
+        }
+
+        /// ---- Revokes the operator hotkey of a subnet.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The subnet owner's coldkey, or root.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet whose operator is revoked.
+        ///
+        /// # Event:
+        /// * SubnetOwnerHotkeyRevoked;
+        /// 	- On successfully revoking the operator.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'SubnetOwnerHotkeyNotSet':
+        /// 	- The subnet has no operator hotkey.
+        ///
+        #[pallet::call_index(80)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn revoke_subnet_owner_hotkey(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_revoke_subnet_owner_hotkey(origin, netuid)
+        }
+
+        /// ---- Sets the token symbol and name a subnet is displayed with.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The subnet owner, its operator hotkey, or root.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet whose token is named.
+        ///
+        /// * 'symbol' (Vec<u8>):
+        /// 	- The UTF-8 token symbol, unique across subnets. An empty symbol clears it.
+        ///
+        /// * 'token_name' (Vec<u8>):
+        /// 	- The UTF-8 token name. An empty name clears it.
+        ///
+        /// # Event:
+        /// * SubnetTokenMetadataSet;
+        /// 	- On successfully setting the symbol and name.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'InvalidSubnetTokenMetadata':
+        /// 	- The symbol or name is too long or not valid UTF-8.
+        ///
+        /// * 'SubnetSymbolInUse':
+        /// 	- Another subnet already uses the symbol.
+        ///
+        #[pallet::call_index(90)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Operational, Pays::No))]
+        pub fn set_subnet_token_metadata(
+            origin: OriginFor<T>,
+            netuid: u16,
+            symbol: Vec<u8>,
+            token_name: Vec<u8>,
+        ) -> DispatchResult {
+            Self::do_set_subnet_token_metadata(origin, netuid, symbol, token_name)
+        }
+
+        /// ---- Recycles tokens from the caller's free balance into the emission pool of a
+        /// subnet, to be paid out by its next epoch.
+        ///
         /// # Args:
         /// * 'origin': (<T as frame_system::Config>Origin):
-        /// 	- The signature of the calling hotkey.
+        /// 	- The signature of the recycling coldkey.
+        ///
+        /// * 'amount' (u64):
+        /// 	- The amount of RAO to recycle.
         ///
         /// * 'netuid' (u16):
-        /// 	- The u16 network identifier.
+        /// 	- The subnet whose emission pool receives the tokens.
         ///
-        /// * 'version' (u16):
-        /// 	-  The bittensor version identifier.
+        /// # Event:
+        /// * TokensRecycled;
+        /// 	- On successfully recycling the tokens.
         ///
-        /// * 'ip' (u128):
-        /// 	- The prometheus ip information as a u128 encoded integer.
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
         ///
-        /// * 'port' (u16):
-        /// 	- The prometheus port information as a u16 encoded integer.
+        /// * 'CanNotRecycleToRootNetwork':
+        /// 	- The subnet is the root network.
         ///
-        /// * 'ip_type' (u8):
-        /// 	- The ip type v4 or v6.
+        /// * 'RecycleAmountIsZero':
+        /// 	- The amount is zero.
         ///
-        #[pallet::call_index(5)]
-        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+        /// * 'NotEnoughBalanceToRecycle':
+        /// 	- The coldkey's free balance is below the amount.
+        ///
+        #[pallet::call_index(81)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
 		.saturating_add(T::DbWeight::get().reads(4))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
-        pub fn serve_prometheus(
-            origin: OriginFor<T>,
-            netuid: u16,
-            version: u32,
-            ip: u128,
-            port: u16,
-            ip_type: u8,
-        ) -> DispatchResult {
-            Self::do_serve_prometheus(origin, netuid, version, ip, port, ip_type)
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn recycle(origin: OriginFor<T>, amount: u64, netuid: u16) -> DispatchResult {
+            Self::do_recycle(origin, amount, netuid)
         }
 
-        /// ---- Registers a new neuron to the subnetwork.
+        /// ---- Burns tokens from the caller's free balance, removing them from the issuance.
         ///
         /// # Args:
         /// * 'origin': (<T as frame_system::Config>Origin):
-        /// 	- The signature of the calling hotkey.
+        /// 	- The signature of the burning coldkey.
         ///
-        /// * 'netuid' (u16):
-        /// 	- The u16 network identifier.
+        /// * 'amount' (u64):
+        /// 	- The amount of RAO to burn.
         ///
-        /// * 'block_number' ( u64 ):
-        /// 	- Block hash used to prove work done.
+        /// # Event:
+        /// * TokensBurned;
+        /// 	- On successfully burning the tokens.
         ///
-        /// * 'nonce' ( u64 ):
-        /// 	- Positive integer nonce used in POW.
+        /// # Raises:
+        /// * 'RecycleAmountIsZero':
+        /// 	- The amount is zero.
         ///
-        /// * 'work' ( Vec<u8> ):
-        /// 	- Vector encoded bytes representing work done.
+        /// * 'NotEnoughBalanceToRecycle':
+        /// 	- The coldkey's free balance is below the amount.
         ///
-        /// * 'hotkey' ( T::AccountId ):
-        /// 	- Hotkey to be registered to the network.
+        #[pallet::call_index(82)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn burn(origin: OriginFor<T>, amount: u64) -> DispatchResult {
+            Self::do_burn(origin, amount)
+        }
+
+        /// ---- Authorizes a weights key to set, commit and reveal weights on a subnet for the
+        /// calling hotkey, so the hotkey itself can stay offline. Authorizing the hotkey
+        /// itself revokes the weights key.
         ///
-        /// * 'coldkey' ( T::AccountId ):
-        /// 	- Associated coldkey account.
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the registered hotkey.
         ///
-        /// # Event:
-        /// * NeuronRegistered;
-        /// 	- On successfully registering a uid to a neuron slot on a subnetwork.
+        /// * 'netuid' (u16):
+        /// 	- The subnet the key sets weights on.
         ///
-        /// # Raises:
-        /// * 'SubNetworkDoesNotExist':
-        /// 	- Attempting to register to a non existent network.
+        /// * 'key' (T::AccountId):
+        /// 	- The weights key.
         ///
-        /// * 'TooManyRegistrationsThisBlock':
-        /// 	- This registration exceeds the total allowed on this network this block.
+        /// # Event:
+        /// * WeightsKeyAuthorized;
+        /// 	- On authorizing the key.
         ///
-        /// * 'HotKeyAlreadyRegisteredInSubNet':
-        /// 	- The hotkey is already registered on this network.
+        /// * WeightsKeyRevoked;
+        /// 	- On revoking the authorization.
         ///
-        /// * 'InvalidWorkBlock':
-        /// 	- The work has been performed on a stale, future, or non existent block.
+        /// # Raises:
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey is not registered on the subnet.
         ///
-        /// * 'InvalidDifficulty':
-        /// 	- The work does not match the difficulty.
+        /// * 'WeightsKeyIsRegistered':
+        /// 	- The key is itself a hotkey registered on the subnet.
         ///
-        /// * 'InvalidSeal':
-        /// 	- The seal is incorrect.
+        /// * 'WeightsKeyInUse':
+        /// 	- The key already sets weights for another hotkey on the subnet.
         ///
-        #[pallet::call_index(6)]
-        #[pallet::weight((Weight::from_parts(192_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(24))
-		.saturating_add(T::DbWeight::get().writes(22)), DispatchClass::Normal, Pays::No))]
-        pub fn register(
+        #[pallet::call_index(85)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::No))]
+        pub fn authorize_weights_key(
             origin: OriginFor<T>,
             netuid: u16,
-            block_number: u64,
-            nonce: u64,
-            work: Vec<u8>,
-            hotkey: T::AccountId,
-            coldkey: T::AccountId,
+            key: T::AccountId,
         ) -> DispatchResult {
-            Self::do_registration(origin, netuid, block_number, nonce, work, hotkey, coldkey)
-        }
-
-        /// Register the hotkey to root network
-        #[pallet::call_index(62)]
-        #[pallet::weight((Weight::from_parts(164_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(23))
-		.saturating_add(T::DbWeight::get().writes(20)), DispatchClass::Normal, Pays::No))]
-        pub fn root_register(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
-            Self::do_root_register(origin, hotkey)
-        }
-
-        /// Attempt to adjust the senate membership to include a hotkey
-        #[pallet::call_index(63)]
-        #[pallet::weight((Weight::from_parts(0, 0)
-		.saturating_add(T::DbWeight::get().reads(0))
-		.saturating_add(T::DbWeight::get().writes(0)), DispatchClass::Normal, Pays::Yes))]
-        pub fn adjust_senate(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
-            Self::do_adjust_senate(origin, hotkey)
+            Self::do_authorize_weights_key(origin, netuid, key)
         }
 
-        /// User register a new subnetwork via burning token
-        #[pallet::call_index(7)]
-        #[pallet::weight((Weight::from_parts(177_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(26))
-		.saturating_add(T::DbWeight::get().writes(24)), DispatchClass::Normal, Pays::No))]
-        pub fn burned_register(
-            origin: OriginFor<T>,
-            netuid: u16,
-            hotkey: T::AccountId,
-        ) -> DispatchResult {
-            Self::do_burned_registration(origin, netuid, hotkey)
+        /// ---- Bids on a reserved netuid. The amount is held in escrow until the caller is
+        /// outbid, the netuid is released, or the winning bid is burned by `claim_netuid`.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the bidding coldkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The reserved netuid.
+        ///
+        /// * 'amount' (u64):
+        /// 	- The bid in RAO.
+        ///
+        /// # Event:
+        /// * NetuidBidPlaced;
+        /// 	- On successfully placing the bid.
+        ///
+        /// # Raises:
+        /// * 'NetuidNotReserved':
+        /// 	- The netuid is not up for auction.
+        ///
+        /// * 'NetuidAuctionEnded':
+        /// 	- The auction no longer accepts bids.
+        ///
+        /// * 'NetuidBidTooLow':
+        /// 	- The bid is below the minimum bid or does not beat the highest bid.
+        ///
+        /// * 'NotEnoughBalanceToStake':
+        /// 	- The coldkey cannot pay the bid.
+        ///
+        #[pallet::call_index(83)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn bid_on_netuid(origin: OriginFor<T>, netuid: u16, amount: u64) -> DispatchResult {
+            Self::do_bid_on_netuid(origin, netuid, amount)
         }
 
-        /// The extrinsic for user to change its hotkey
-        #[pallet::call_index(70)]
-        #[pallet::weight((Weight::from_parts(1_940_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(272))
-		.saturating_add(T::DbWeight::get().writes(527)), DispatchClass::Operational, Pays::No))]
-        pub fn swap_hotkey(
-            origin: OriginFor<T>,
-            hotkey: T::AccountId,
-            new_hotkey: T::AccountId,
-        ) -> DispatchResultWithPostInfo {
-            Self::do_swap_hotkey(origin, &hotkey, &new_hotkey)
+        /// ---- Registers a network at an auctioned netuid once its auction has ended. Only
+        /// the holder of the highest bid can claim it.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the winning coldkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The auctioned netuid.
+        ///
+        /// # Event:
+        /// * NetuidClaimed;
+        /// 	- On successfully claiming the netuid.
+        ///
+        /// # Raises:
+        /// * 'NetuidNotReserved':
+        /// 	- The netuid is not up for auction.
+        ///
+        /// * 'NetuidAuctionNotEnded':
+        /// 	- The auction still accepts bids.
+        ///
+        /// * 'NotNetuidAuctionWinner':
+        /// 	- The caller does not hold the highest bid.
+        ///
+        /// * 'SubnetLimitReached':
+        /// 	- There is no room for another subnet.
+        ///
+        #[pallet::call_index(84)]
+        #[pallet::weight((Weight::from_parts(157_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(16))
+		.saturating_add(T::DbWeight::get().writes(30)), DispatchClass::Operational, Pays::No))]
+        pub fn claim_netuid(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_claim_netuid(origin, netuid)
         }
 
-        /// The extrinsic for user to change the coldkey associated with their account.
+        /// ---- Serves or updates one entry of the caller's axon set on a subnet, so that
+        /// several replicas of a neuron can be advertised. Index 0 is the axon served by
+        /// `serve_axon`; an index equal to the size of the set appends a new entry.
         ///
-        /// # Arguments
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller.
         ///
-        /// * `origin` - The origin of the call, must be signed by the old coldkey.
-        /// * `old_coldkey` - The current coldkey associated with the account.
-        /// * `new_coldkey` - The new coldkey to be associated with the account.
+        /// * 'netuid' (u16):
+        /// 	- The u16 network identifier.
         ///
-        /// # Returns
+        /// * 'index' (u8):
+        /// 	- The position of the axon in the set, below MAX_AXONS_PER_NEURON.
         ///
-        /// Returns a `DispatchResultWithPostInfo` indicating success or failure of the operation.
+        /// * 'version', 'ip', 'port', 'ip_type', 'protocol', 'placeholder1', 'placeholder2':
+        /// 	- As for `serve_axon`.
         ///
-        /// # Weight
+        /// # Event:
+        /// * AxonServed;
+        /// 	- On successfully serving the axon info.
         ///
-        /// Weight is calculated based on the number of database reads and writes.
-        #[pallet::call_index(71)]
-        #[pallet::weight((Weight::from_parts(1_940_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(272))
-		.saturating_add(T::DbWeight::get().writes(527)), DispatchClass::Operational, Pays::No))]
-        pub fn swap_coldkey(
+        /// # Raises:
+        /// * 'InvalidAxonIndex':
+        /// 	- The index leaves a gap in the set or is not below MAX_AXONS_PER_NEURON.
+        ///
+        /// * 'ServingRateLimitExceeded':
+        /// 	- The entry at this index was served within the rate limit.
+        ///
+        /// * Any error raised by `serve_axon`.
+        ///
+        #[cfg(feature = "serving")]
+        #[pallet::call_index(74)]
+        #[pallet::weight((Weight::from_parts(46_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn serve_axon_multi(
             origin: OriginFor<T>,
-            new_coldkey: T::AccountId,
-        ) -> DispatchResultWithPostInfo {
-            Self::do_swap_coldkey(origin, &new_coldkey)
+            netuid: u16,
+            index: u8,
+            version: u32,
+            ip: u128,
+            port: u16,
+            ip_type: u8,
+            protocol: u8,
+            placeholder1: u8,
+            placeholder2: u8,
+        ) -> DispatchResult {
+            Self::do_serve_axon_multi(
+                origin,
+                netuid,
+                index,
+                version,
+                ip,
+                port,
+                ip_type,
+                protocol,
+                placeholder1,
+                placeholder2,
+            )
         }
+
         /// Unstakes all tokens associated with a hotkey and transfers them to a new coldkey.
         ///
         /// # Arguments
@@ -2227,6 +5300,7 @@ pub mod pallet {
         /// Facility extrinsic for user to get taken from faucet
         /// It is only available when pow-faucet feature enabled
         /// Just deployed in testnet and devnet for testing purpose
+        /// A coldkey can call it once every FaucetRateLimit blocks
         #[pallet::call_index(60)]
         #[pallet::weight((Weight::from_parts(91_000_000, 0)
         .saturating_add(T::DbWeight::get().reads(27))
@@ -2350,9 +5424,62 @@ where
         Pallet::<T>::get_priority_set_weights(who, netuid)
     }
 
+    /// Orders registrations declaring the role the subnet is short of before the others.
+    pub fn get_priority_registration(netuid: u16, role: Option<NeuronRole>) -> u64 {
+        match Pallet::<T>::get_short_role(netuid) {
+            Some(short_role) if role != Some(short_role) => {
+                Self::get_priority_vanilla().saturating_sub(1)
+            }
+            _ => Self::get_priority_vanilla(),
+        }
+    }
+
     pub fn check_weights_min_stake(who: &T::AccountId) -> bool {
         Pallet::<T>::check_weights_min_stake(who)
     }
+
+    /// Whether `call` acts on a subnet that doesn't exist, so it would fail once dispatched.
+    /// Calls left in the pool for a subnet pruned mid-flight are dropped instead of filling
+    /// blocks. A weights batch is kept while any of its subnets exists, as the entries fail
+    /// independently.
+    pub fn targets_missing_subnet(call: &Call<T>) -> bool {
+        let netuid = match call {
+            Call::set_weights { netuid, .. }
+            | Call::set_weights_with_metadata { netuid, .. }
+            | Call::set_weights_for_epoch { netuid, .. }
+            | Call::commit_weights { netuid, .. }
+            | Call::reveal_weights { netuid, .. }
+            | Call::reveal_weights_with_metadata { netuid, .. }
+            | Call::register { netuid, .. }
+            | Call::burned_register { netuid, .. }
+            | Call::burned_register_with_role { netuid, .. }
+            | Call::unregister { netuid, .. }
+            | Call::clear_neuron { netuid, .. }
+            | Call::attest_axon { netuid, .. }
+            | Call::set_children { netuid, .. }
+            | Call::associate_evm_key { netuid, .. }
+            | Call::authorize_weights_key { netuid, .. }
+            | Call::authorize_weight_setter { netuid, .. }
+            | Call::signal_epoch_pause { netuid, .. }
+            | Call::vote_subnet_referendum { netuid, .. } => *netuid,
+            Call::set_root_weights { netuid, .. } => netuid.inner(),
+            #[cfg(feature = "serving")]
+            Call::serve_axon { netuid, .. }
+            | Call::serve_axon_multi { netuid, .. }
+            | Call::serve_axon_tls { netuid, .. }
+            | Call::serve_prometheus { netuid, .. } => *netuid,
+            #[cfg(feature = "commitments")]
+            Call::set_commitment { netuid, .. } => *netuid,
+            Call::batch_set_weights { batch } => {
+                return !batch.is_empty()
+                    && batch
+                        .iter()
+                        .all(|(netuid, ..)| !Pallet::<T>::if_subnet_exist(*netuid));
+            }
+            _ => return false,
+        };
+        !Pallet::<T>::if_subnet_exist(netuid)
+    }
 }
 
 impl<T: Config + Send + Sync + TypeInfo> sp_std::fmt::Debug for SubtensorSignedExtension<T> {
@@ -2373,7 +5500,7 @@ where
     type AccountId = T::AccountId;
     type Call = T::RuntimeCall;
     type AdditionalSigned = ();
-    type Pre = (CallType, u64, Self::AccountId);
+    type Pre = (CallType, u64, Self::AccountId, Option<u8>);
 
     fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
         Ok(())
@@ -2399,10 +5526,14 @@ where
                 _ => {} // Other Balances calls are allowed
             }
         }
+        if IsSubType::<Call<T>>::is_sub_type(call).is_some_and(Self::targets_missing_subnet) {
+            return Err(InvalidTransaction::Call.into());
+        }
         match call.is_sub_type() {
             Some(Call::commit_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+                let hotkey = Pallet::<T>::get_weights_hotkey(*netuid, who.clone());
+                if Self::check_weights_min_stake(&hotkey) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     Ok(ValidTransaction {
                         priority,
                         longevity: 1,
@@ -2412,9 +5543,11 @@ where
                     Err(InvalidTransaction::Call.into())
                 }
             }
-            Some(Call::reveal_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+            Some(Call::reveal_weights { netuid, .. })
+            | Some(Call::reveal_weights_with_metadata { netuid, .. }) => {
+                let hotkey = Pallet::<T>::get_weights_hotkey(*netuid, who.clone());
+                if Self::check_weights_min_stake(&hotkey) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     Ok(ValidTransaction {
                         priority,
                         longevity: 1,
@@ -2424,9 +5557,12 @@ where
                     Err(InvalidTransaction::Call.into())
                 }
             }
-            Some(Call::set_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+            Some(Call::set_weights { netuid, .. })
+            | Some(Call::set_weights_with_metadata { netuid, .. })
+            | Some(Call::set_weights_for_epoch { netuid, .. }) => {
+                let hotkey = Pallet::<T>::get_weights_hotkey(*netuid, who.clone());
+                if Self::check_weights_min_stake(&hotkey) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     Ok(ValidTransaction {
                         priority,
                         longevity: 1,
@@ -2436,9 +5572,29 @@ where
                     Err(InvalidTransaction::Call.into())
                 }
             }
+            Some(Call::batch_set_weights { batch }) => {
+                let staked = batch.iter().all(|(netuid, ..)| {
+                    Self::check_weights_min_stake(&Pallet::<T>::get_weights_hotkey(
+                        *netuid,
+                        who.clone(),
+                    ))
+                });
+                match batch.first() {
+                    Some((netuid, ..)) if staked => {
+                        let hotkey = Pallet::<T>::get_weights_hotkey(*netuid, who.clone());
+                        let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
+                        Ok(ValidTransaction {
+                            priority,
+                            longevity: 1,
+                            ..Default::default()
+                        })
+                    }
+                    _ => Err(InvalidTransaction::Call.into()),
+                }
+            }
             Some(Call::set_root_weights { netuid, hotkey, .. }) => {
                 if Self::check_weights_min_stake(hotkey) {
-                    let priority: u64 = Self::get_priority_set_weights(hotkey, *netuid);
+                    let priority: u64 = Self::get_priority_set_weights(hotkey, netuid.inner());
                     Ok(ValidTransaction {
                         priority,
                         longevity: 1,
@@ -2448,15 +5604,26 @@ where
                     Err(InvalidTransaction::Call.into())
                 }
             }
-            Some(Call::add_stake { .. }) => Ok(ValidTransaction {
+            Some(
+                Call::add_stake { .. }
+                | Call::stake_all_minus_fees { .. }
+                | Call::add_stake_locked { .. }
+                | Call::add_stake_multiple { .. },
+            ) => Ok(ValidTransaction {
                 priority: Self::get_priority_vanilla(),
                 ..Default::default()
             }),
-            Some(Call::remove_stake { .. }) => Ok(ValidTransaction {
-                priority: Self::get_priority_vanilla(),
-                ..Default::default()
-            }),
-            Some(Call::register { netuid, .. } | Call::burned_register { netuid, .. }) => {
+            Some(Call::remove_stake { .. } | Call::remove_stake_multiple { .. }) => {
+                Ok(ValidTransaction {
+                    priority: Self::get_priority_vanilla(),
+                    ..Default::default()
+                })
+            }
+            Some(
+                Call::register { netuid, .. }
+                | Call::burned_register { netuid, .. }
+                | Call::burned_register_with_role { netuid, .. },
+            ) => {
                 let registrations_this_interval =
                     Pallet::<T>::get_registrations_this_interval(*netuid);
                 let max_registrations_per_interval =
@@ -2466,8 +5633,12 @@ where
                     // If the registration limit for the interval is exceeded, reject the transaction
                     return InvalidTransaction::ExhaustsResources.into();
                 }
+                let role = match call.is_sub_type() {
+                    Some(Call::burned_register_with_role { role, .. }) => Some(*role),
+                    _ => None,
+                };
                 Ok(ValidTransaction {
-                    priority: Self::get_priority_vanilla(),
+                    priority: Self::get_priority_registration(*netuid, role),
                     ..Default::default()
                 })
             }
@@ -2500,16 +5671,22 @@ where
         _info: &DispatchInfoOf<Self::Call>,
         _len: usize,
     ) -> Result<Self::Pre, TransactionValidityError> {
-        match call.is_sub_type() {
-            Some(Call::add_stake { .. }) => {
+        let pre = match call.is_sub_type() {
+            Some(Call::add_stake { .. })
+            | Some(Call::stake_all_minus_fees { .. })
+            | Some(Call::add_stake_locked { .. })
+            | Some(Call::add_stake_multiple { .. }) => {
                 let transaction_fee = 100000;
                 Ok((CallType::AddStake, transaction_fee, who.clone()))
             }
-            Some(Call::remove_stake { .. }) => {
+            Some(Call::remove_stake { .. }) | Some(Call::remove_stake_multiple { .. }) => {
                 let transaction_fee = 0;
                 Ok((CallType::RemoveStake, transaction_fee, who.clone()))
             }
-            Some(Call::set_weights { .. }) => {
+            Some(Call::set_weights { .. })
+            | Some(Call::set_weights_with_metadata { .. })
+            | Some(Call::set_weights_for_epoch { .. })
+            | Some(Call::batch_set_weights { .. }) => {
                 let transaction_fee = 0;
                 Ok((CallType::SetWeights, transaction_fee, who.clone()))
             }
@@ -2517,7 +5694,7 @@ where
                 let transaction_fee = 0;
                 Ok((CallType::SetWeights, transaction_fee, who.clone()))
             }
-            Some(Call::reveal_weights { .. }) => {
+            Some(Call::reveal_weights { .. }) | Some(Call::reveal_weights_with_metadata { .. }) => {
                 let transaction_fee = 0;
                 Ok((CallType::SetWeights, transaction_fee, who.clone()))
             }
@@ -2525,7 +5702,15 @@ where
                 let transaction_fee = 0;
                 Ok((CallType::Register, transaction_fee, who.clone()))
             }
-            Some(Call::serve_axon { .. }) => {
+            #[cfg(feature = "serving")]
+            Some(Call::serve_axon { .. })
+            | Some(Call::serve_axon_multi { .. })
+            | Some(Call::serve_axon_tls { .. }) => {
+                let transaction_fee = 0;
+                Ok((CallType::Serve, transaction_fee, who.clone()))
+            }
+            #[cfg(feature = "commitments")]
+            Some(Call::set_commitment { .. }) => {
                 let transaction_fee = 0;
                 Ok((CallType::Serve, transaction_fee, who.clone()))
             }
@@ -2537,17 +5722,25 @@ where
                 let transaction_fee = 0;
                 Ok((CallType::Other, transaction_fee, who.clone()))
             }
-        }
+        };
+        // Dispatches of the pallet's own calls are counted in post_dispatch.
+        let call_index = IsSubType::<Call<T>>::is_sub_type(call).map(|call| call.get_call_index());
+        pre.map(|(call_type, transaction_fee, who)| (call_type, transaction_fee, who, call_index))
     }
 
     fn post_dispatch(
         maybe_pre: Option<Self::Pre>,
-        _info: &DispatchInfoOf<Self::Call>,
+        info: &DispatchInfoOf<Self::Call>,
         _post_info: &PostDispatchInfoOf<Self::Call>,
         _len: usize,
-        _result: &dispatch::DispatchResult,
+        result: &dispatch::DispatchResult,
     ) -> Result<(), TransactionValidityError> {
-        if let Some((call_type, _transaction_fee, _who)) = maybe_pre {
+        if let Some((call_type, _transaction_fee, _who, call_index)) = maybe_pre {
+            if let Some(call_index) = call_index {
+                // The counters are written after the call's own weight was charged.
+                let weight = Pallet::<T>::record_call_metrics(call_index, result);
+                frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, info.class);
+            }
             match call_type {
                 CallType::SetWeights => {
                     log::debug!("Not Implemented!");
@@ -2652,3 +5845,53 @@ impl<T, H, P> CollectiveInterface<T, H, P> for () {
         Ok(true)
     }
 }
+
+/// Hooks through which other pallets of the runtime follow subtensor without parsing its
+/// events. The hooks run inside the calling transaction or block step, so implementations
+/// should be cheap and must not fail.
+pub trait OnSubtensorEvent<AccountId> {
+    /// A hotkey is registered on a subnet, at `uid`.
+    fn on_neuron_registered(netuid: u16, uid: u16, hotkey: &AccountId);
+
+    /// The stake of `coldkey` on `hotkey` is now `stake`.
+    fn on_stake_changed(hotkey: &AccountId, coldkey: &AccountId, stake: u64);
+
+    /// The epoch of a subnet has run and its emission is loaded.
+    fn on_epoch_completed(netuid: u16, block_number: u64);
+
+    /// A subnet is added.
+    fn on_network_added(netuid: u16);
+
+    /// A subnet is removed.
+    fn on_network_removed(netuid: u16);
+
+    /// A subnet moved to another netuid, keeping its state.
+    fn on_network_migrated(old_netuid: u16, new_netuid: u16);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnSubtensorEvent<AccountId> for Tuple {
+    fn on_neuron_registered(netuid: u16, uid: u16, hotkey: &AccountId) {
+        for_tuples!( #( Tuple::on_neuron_registered(netuid, uid, hotkey); )* );
+    }
+
+    fn on_stake_changed(hotkey: &AccountId, coldkey: &AccountId, stake: u64) {
+        for_tuples!( #( Tuple::on_stake_changed(hotkey, coldkey, stake); )* );
+    }
+
+    fn on_epoch_completed(netuid: u16, block_number: u64) {
+        for_tuples!( #( Tuple::on_epoch_completed(netuid, block_number); )* );
+    }
+
+    fn on_network_added(netuid: u16) {
+        for_tuples!( #( Tuple::on_network_added(netuid); )* );
+    }
+
+    fn on_network_removed(netuid: u16) {
+        for_tuples!( #( Tuple::on_network_removed(netuid); )* );
+    }
+
+    fn on_network_migrated(old_netuid: u16, new_netuid: u16) {
+        for_tuples!( #( Tuple::on_network_migrated(old_netuid, new_netuid); )* );
+    }
+}
@@ -144,6 +144,24 @@ pub fn vec_u16_max_upscale_to_u16(vec: &[u16]) -> Vec<u16> {
     vec_max_upscale_to_u16(&vec_fixed)
 }
 
+#[allow(dead_code)]
+// Sum-normalize u16 vector and convert to u16 so sum_value ~ u16::MAX, rounding down. Zero vectors are returned unchanged.
+pub fn vec_u16_sum_normalize_to_u16(vec: &[u16]) -> Vec<u16> {
+    let sum: u64 = vec.iter().map(|e: &u16| u64::from(*e)).sum();
+    if sum == 0 {
+        return vec.to_vec();
+    }
+    vec.iter()
+        .map(|e: &u16| {
+            let scaled: u64 = u64::from(*e)
+                .saturating_mul(u64::from(u16::MAX))
+                .checked_div(sum)
+                .unwrap_or(0);
+            u16::try_from(scaled).unwrap_or(u16::MAX)
+        })
+        .collect()
+}
+
 #[allow(dead_code)]
 // Checks if u16 vector, when normalized, has a max value not greater than a u16 ratio max_limit.
 pub fn check_vec_max_limited(vec: &[u16], max_limit: u16) -> bool {
@@ -241,6 +259,29 @@ pub fn is_topk(vector: &[I32F32], k: usize) -> Vec<bool> {
     result
 }
 
+// Returns a bool vector where an item is true if the vector item is in topk values.
+// Ties are broken deterministically by the smaller tiebreak key, then by the smaller index. A
+// tiebreak whose length differs from the vector is ignored, ties then go to the smaller index.
+pub fn is_topk_with_tiebreak<K: Ord>(vector: &[I32F32], tiebreak: &[K], k: usize) -> Vec<bool> {
+    let n: usize = vector.len();
+    let tiebreak: &[K] = if tiebreak.len() == n { tiebreak } else { &[] };
+    let mut result: Vec<bool> = vec![false; n];
+    let mut idxs: Vec<usize> = (0..n).collect();
+    idxs.sort_by(|&a, &b| {
+        vector
+            .get(b)
+            .cmp(&vector.get(a))
+            .then_with(|| tiebreak.get(a).cmp(&tiebreak.get(b)))
+            .then_with(|| a.cmp(&b))
+    });
+    for &idx in idxs.iter().take(k) {
+        if let Some(is_topk) = result.get_mut(idx) {
+            *is_topk = true;
+        }
+    }
+    result
+}
+
 // Returns a normalized (sum to 1 except 0) copy of the input vector.
 #[allow(dead_code)]
 pub fn normalize(x: &[I32F32]) -> Vec<I32F32> {
@@ -273,6 +314,61 @@ pub fn inplace_normalize_using_sum(x: &mut [I32F32], x_sum: I32F32) {
         .for_each(|value| *value = value.saturating_div(x_sum));
 }
 
+// Caps each value of the normalized input vector at `cap` directly in-place, spreading the
+// excess over the uncapped values in proportion to them so that the vector still sums to 1.
+// The positive values all end up equal when fewer than 1 / `cap` of them are positive.
+#[allow(dead_code)]
+pub fn inplace_cap_normalized(x: &mut [I32F32], cap: I32F32) {
+    let zero: I32F32 = I32F32::from_num(0);
+    let one: I32F32 = I32F32::from_num(1);
+    if cap >= one {
+        return;
+    }
+    let positive: usize = x.iter().filter(|value| **value > zero).count();
+    if positive == 0 {
+        return;
+    }
+    if I32F32::from_num(positive).saturating_mul(cap) <= one {
+        let share: I32F32 = one.checked_div(I32F32::from_num(positive)).unwrap_or(zero);
+        x.iter_mut()
+            .filter(|value| **value > zero)
+            .for_each(|value| *value = share);
+        return;
+    }
+
+    // Each pass caps at least one more value, until scaling the rest up fits under the cap.
+    let mut capped: Vec<bool> = vec![false; x.len()];
+    loop {
+        let capped_count: usize = capped.iter().filter(|is_capped| **is_capped).count();
+        let budget: I32F32 = one.saturating_sub(I32F32::from_num(capped_count).saturating_mul(cap));
+        let free_sum: I32F32 = x
+            .iter()
+            .zip(capped.iter())
+            .filter(|(_, is_capped)| !**is_capped)
+            .fold(zero, |sum, (value, _)| sum.saturating_add(*value));
+        if free_sum == zero {
+            return;
+        }
+        let mut newly_capped = false;
+        for (value, is_capped) in x.iter().zip(capped.iter_mut()) {
+            if !*is_capped && value.saturating_mul(budget).saturating_div(free_sum) > cap {
+                *is_capped = true;
+                newly_capped = true;
+            }
+        }
+        if !newly_capped {
+            for (value, is_capped) in x.iter_mut().zip(capped.iter()) {
+                *value = if *is_capped {
+                    cap
+                } else {
+                    value.saturating_mul(budget).saturating_div(free_sum)
+                };
+            }
+            return;
+        }
+    }
+}
+
 // Normalizes (sum to 1 except 0) the I64F64 input vector directly in-place.
 #[allow(dead_code)]
 pub fn inplace_normalize_64(x: &mut [I64F64]) {
@@ -284,6 +380,50 @@ pub fn inplace_normalize_64(x: &mut [I64F64]) {
         .for_each(|value| *value = value.saturating_div(x_sum));
 }
 
+/// Caps each value of the normalized `x` at `cap`, spreading the excess over the values below
+/// the cap in proportion to them, so `x` still sums to 1. When the cap cannot be met, because
+/// fewer than `1 / cap` values are nonzero, the nonzero values are left equal.
+pub fn inplace_cap_normalized_64(x: &mut [I64F64], cap: I64F64) {
+    let one = I64F64::from_num(1);
+    if cap >= one {
+        return;
+    }
+    let mut capped: Vec<bool> = vec![false; x.len()];
+    loop {
+        let capped_count = capped.iter().filter(|is_capped| **is_capped).count();
+        let remaining: I64F64 =
+            one.saturating_sub(cap.saturating_mul(I64F64::from_num(capped_count)));
+        let uncapped_sum: I64F64 = x
+            .iter()
+            .zip(&capped)
+            .filter(|(_, is_capped)| !**is_capped)
+            .map(|(value, _)| *value)
+            .sum();
+        if remaining <= I64F64::from_num(0) || uncapped_sum == I64F64::from_num(0) {
+            break;
+        }
+        let scale: I64F64 = remaining.saturating_div(uncapped_sum);
+
+        // Cap the values exceeding the cap once scaled, and scale the others when none do.
+        let mut newly_capped = false;
+        for (value, is_capped) in x.iter_mut().zip(capped.iter_mut()) {
+            if !*is_capped && value.saturating_mul(scale) > cap {
+                *value = cap;
+                *is_capped = true;
+                newly_capped = true;
+            }
+        }
+        if !newly_capped {
+            x.iter_mut()
+                .zip(&capped)
+                .filter(|(_, is_capped)| !**is_capped)
+                .for_each(|(value, _)| *value = value.saturating_mul(scale));
+            break;
+        }
+    }
+    inplace_normalize_64(x);
+}
+
 /// Normalizes (sum to 1 except 0) each row (dim=0) of a I64F64 matrix in-place.
 #[allow(dead_code)]
 pub fn inplace_row_normalize_64(x: &mut [Vec<I64F64>]) {
@@ -296,6 +436,19 @@ pub fn inplace_row_normalize_64(x: &mut [Vec<I64F64>]) {
     }
 }
 
+/// Normalizes (sum to 1 except 0) each row (dim=0) of a I64F64 sparse matrix in-place.
+#[allow(dead_code)]
+pub fn inplace_row_normalize_sparse_64(sparse_matrix: &mut [Vec<(u16, I64F64)>]) {
+    for sparse_row in sparse_matrix.iter_mut() {
+        let row_sum: I64F64 = sparse_row.iter().map(|(_j, value)| *value).sum();
+        if row_sum > I64F64::from_num(0.0_f64) {
+            sparse_row
+                .iter_mut()
+                .for_each(|(_j, value)| *value = value.saturating_div(row_sum));
+        }
+    }
+}
+
 /// Returns x / y for input vectors x and y, if y == 0 return 0.
 #[allow(dead_code)]
 pub fn vecdiv(x: &[I32F32], y: &[I32F32]) -> Vec<I32F32> {
@@ -337,6 +490,51 @@ pub fn inplace_row_normalize_sparse(sparse_matrix: &mut [Vec<(u16, I32F32)>]) {
     }
 }
 
+// Divides each row (dim=0) of a matrix in-place by the larger of its sum and `floor`, so rows
+// summing to less than `floor` keep their shortfall instead of being upscaled to sum to 1.
+pub fn inplace_row_normalize_with_floor(x: &mut [Vec<I32F32>], floor: I32F32) {
+    for row in x {
+        let row_sum: I32F32 = row.iter().sum();
+        let divisor: I32F32 = row_sum.max(floor);
+        if divisor > I32F32::from_num(0) {
+            row.iter_mut()
+                .for_each(|x_ij: &mut I32F32| *x_ij = x_ij.saturating_div(divisor));
+        }
+    }
+}
+
+// Divides each row (dim=0) of a sparse matrix in-place by the larger of its sum and `floor`.
+pub fn inplace_row_normalize_sparse_with_floor(
+    sparse_matrix: &mut [Vec<(u16, I32F32)>],
+    floor: I32F32,
+) {
+    for sparse_row in sparse_matrix.iter_mut() {
+        let row_sum: I32F32 = sparse_row.iter().map(|(_j, value)| *value).sum();
+        let divisor: I32F32 = row_sum.max(floor);
+        if divisor > I32F32::from_num(0) {
+            sparse_row
+                .iter_mut()
+                .for_each(|(_j, value)| *value = value.saturating_div(divisor));
+        }
+    }
+}
+
+// Divides each row (dim=0) of a sparse matrix in-place by the larger of its sum and `floor`.
+pub fn inplace_row_normalize_sparse_64_with_floor(
+    sparse_matrix: &mut [Vec<(u16, I64F64)>],
+    floor: I64F64,
+) {
+    for sparse_row in sparse_matrix.iter_mut() {
+        let row_sum: I64F64 = sparse_row.iter().map(|(_j, value)| *value).sum();
+        let divisor: I64F64 = row_sum.max(floor);
+        if divisor > I64F64::from_num(0) {
+            sparse_row
+                .iter_mut()
+                .for_each(|(_j, value)| *value = value.saturating_div(divisor));
+        }
+    }
+}
+
 // Sum across each row (dim=0) of a matrix.
 #[allow(dead_code)]
 pub fn row_sum(x: &[Vec<I32F32>]) -> Vec<I32F32> {
@@ -753,6 +951,25 @@ pub fn matmul_sparse(
     result
 }
 
+// Row-wise I64F64 sparse_matrix-vector product, column-wise sum: result_j = SUM(i) vector_i * matrix_ij.
+// Entries with a column outside of `columns` are ignored.
+#[allow(dead_code)]
+pub fn matmul_sparse_64(
+    sparse_matrix: &[Vec<(u16, I64F64)>],
+    vector: &[I64F64],
+    columns: u16,
+) -> Vec<I64F64> {
+    let mut result: Vec<I64F64> = vec![I64F64::from_num(0.0); columns as usize];
+    for (sparse_row, vec_val) in sparse_matrix.iter().zip(vector) {
+        for (j, value) in sparse_row.iter() {
+            if let Some(result_j) = result.get_mut(*j as usize) {
+                *result_j = result_j.saturating_add(vec_val.saturating_mul(*value));
+            }
+        }
+    }
+    result
+}
+
 // Column-wise sparse_matrix-vector product, row-wise sum: result_i = SUM(j) vector_j * matrix_ij.
 #[allow(dead_code, clippy::indexing_slicing)]
 pub fn matmul_transpose_sparse(
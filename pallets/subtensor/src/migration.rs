@@ -1,7 +1,7 @@
 use super::*;
 use frame_support::traits::DefensiveResult;
 use frame_support::{
-    pallet_prelude::{Identity, OptionQuery},
+    pallet_prelude::{Blake2_128Concat, Identity, OptionQuery},
     storage_alias,
     traits::{fungible::Inspect as _, Get, GetStorageVersion, StorageVersion},
     weights::Weight,
@@ -24,6 +24,26 @@ pub mod deprecated_loaded_emission_format {
         StorageMap<Pallet<T>, Identity, u16, Vec<(AccountIdOf<T>, u64)>, OptionQuery>;
 }
 
+pub mod deprecated_subnet_feature_booleans {
+    use super::*;
+
+    #[storage_alias]
+    pub type CommitRevealWeightsEnabled<T: Config> =
+        StorageMap<Pallet<T>, Identity, u16, bool, OptionQuery>;
+
+    #[storage_alias]
+    pub type LiquidAlphaOn<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, u16, bool, OptionQuery>;
+}
+
+pub mod deprecated_weights_format {
+    use super::*;
+
+    #[storage_alias]
+    pub type Weights<T: Config> =
+        StorageDoubleMap<Pallet<T>, Identity, u16, Identity, u16, Vec<(u16, u16)>, OptionQuery>;
+}
+
 /// Performs migration to update the total issuance based on the sum of stakes and total balances.
 /// This migration is applicable only if the current storage version is 5, after which it updates the storage version to 6.
 ///
@@ -122,7 +142,7 @@ pub fn migrate_transfer_ownership_to_foundation<T: Config>(coldkey: [u8; 32]) ->
 
 pub fn migrate_create_root_network<T: Config>() -> Weight {
     // Get the root network uid.
-    let root_netuid: u16 = 0;
+    let root_netuid: u16 = Pallet::<T>::get_root_netuid();
 
     // Setup migration weight
     let mut weight = T::DbWeight::get().reads(1);
@@ -134,16 +154,17 @@ pub fn migrate_create_root_network<T: Config>() -> Weight {
     }
 
     // Set the root network as added.
-    NetworksAdded::<T>::insert(root_netuid, true);
+    Pallet::<T>::add_subnet_netuid(root_netuid);
 
     // Increment the number of total networks.
     TotalNetworks::<T>::mutate(|n| n.saturating_inc());
 
     // Set the maximum number to the number of senate members.
-    MaxAllowedUids::<T>::insert(root_netuid, 64);
+    MaxAllowedUids::<T>::insert(root_netuid, T::InitialRootMaxUids::get());
+    RootMaxUids::<T>::put(T::InitialRootMaxUids::get());
 
     // Set the maximum number to the number of validators to all members.
-    MaxAllowedValidators::<T>::insert(root_netuid, 64);
+    MaxAllowedValidators::<T>::insert(root_netuid, T::InitialRootMaxUids::get());
 
     // Set the min allowed weights to zero, no weights restrictions.
     MinAllowedWeights::<T>::insert(root_netuid, 0);
@@ -152,7 +173,7 @@ pub fn migrate_create_root_network<T: Config>() -> Weight {
     MaxWeightsLimit::<T>::insert(root_netuid, u16::MAX);
 
     // Add default root tempo.
-    Tempo::<T>::insert(root_netuid, 100);
+    Tempo::<T>::insert(root_netuid, T::InitialRootTempo::get());
 
     // Set the root network as open.
     NetworkRegistrationAllowed::<T>::insert(root_netuid, true);
@@ -164,7 +185,7 @@ pub fn migrate_create_root_network<T: Config>() -> Weight {
     //WeightsSetRateLimit::<T>::insert(root_netuid, 7200);
 
     // Add our weights for writing to database
-    weight.saturating_accrue(T::DbWeight::get().writes(8));
+    weight.saturating_accrue(T::DbWeight::get().writes(9));
 
     // Empty senate members entirely, they will be filled by by registrations
     // on the subnet.
@@ -201,7 +222,7 @@ pub fn migrate_delete_subnet_3<T: Config>() -> Weight {
         NetworkModality::<T>::remove(netuid);
 
         // --- 4. Remove netuid from added networks.
-        NetworksAdded::<T>::remove(netuid);
+        Pallet::<T>::remove_subnet_netuid(netuid);
 
         // --- 6. Decrement the network counter.
         TotalNetworks::<T>::mutate(|n| n.saturating_dec());
@@ -262,6 +283,58 @@ pub fn migrate_delete_subnet_3<T: Config>() -> Weight {
     }
 }
 
+/// Rewrites each row of `Weights` from the plain `Vec<(u16, u16)>` encoding into a
+/// compressed `WeightsRow`. Rows which don't decode in the old format are removed and counted,
+/// rather than being left behind to be misread in the new one.
+pub fn migrate_compress_weights<T: Config>() -> Weight {
+    let new_storage_version = 7;
+    let mut weight = T::DbWeight::get().reads(1);
+
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+    if onchain_version >= new_storage_version {
+        info!(target: LOG_TARGET_1, "Migration to v7 already done!");
+        return weight;
+    }
+
+    info!(target: LOG_TARGET_1, ">>> Compressing weights {:?}", onchain_version);
+
+    let keys: Vec<(u16, u16)> = deprecated_weights_format::Weights::<T>::iter_keys().collect();
+    let mut rows_migrated: u64 = 0;
+    let mut rows_undecodable: u64 = 0;
+    for (netuid, uid) in keys {
+        match deprecated_weights_format::Weights::<T>::get(netuid, uid) {
+            Some(row) => {
+                Weights::<T>::insert(netuid, uid, WeightsRow::from(row));
+                rows_migrated = rows_migrated.saturating_add(1);
+            }
+            None => {
+                Weights::<T>::remove(netuid, uid);
+                rows_undecodable = rows_undecodable.saturating_add(1);
+            }
+        }
+    }
+    let rows = rows_migrated.saturating_add(rows_undecodable);
+    weight.saturating_accrue(T::DbWeight::get().reads_writes(rows, rows));
+
+    StorageVersion::new(new_storage_version).put::<Pallet<T>>();
+    weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+    if rows_undecodable > 0 {
+        log::warn!(
+            target: LOG_TARGET_1,
+            "Migration to v7 removed {} undecodable weight rows", rows_undecodable
+        );
+    }
+    info!(
+        target: LOG_TARGET_1,
+        "Migration to v7 finished. Weight rows compressed: {}, removed: {}",
+        rows_migrated,
+        rows_undecodable
+    );
+
+    weight
+}
+
 pub fn migrate_delete_subnet_21<T: Config>() -> Weight {
     let new_storage_version = 4;
 
@@ -285,7 +358,7 @@ pub fn migrate_delete_subnet_21<T: Config>() -> Weight {
         NetworkModality::<T>::remove(netuid);
 
         // --- 4. Remove netuid from added networks.
-        NetworksAdded::<T>::remove(netuid);
+        Pallet::<T>::remove_subnet_netuid(netuid);
 
         // --- 6. Decrement the network counter.
         TotalNetworks::<T>::mutate(|n| n.saturating_dec());
@@ -546,8 +619,9 @@ pub fn migrate_populate_staking_hotkeys<T: Config>() -> Weight {
     let mut weight = T::DbWeight::get().reads(1);
     let migration_name = "Populate StakingHotkeys map";
 
-    // Check if this migration is needed (if StakingHotkeys map is empty)
-    let migrate = StakingHotkeys::<T>::iter().next().is_none();
+    // Check if this migration is needed (if StakingHotkeys map is empty and was never paged)
+    let migrate = StakingHotkeys::<T>::iter().next().is_none()
+        && StakingHotkeyPageCount::<T>::iter().next().is_none();
 
     // Only runs if the migration is needed
     if migrate {
@@ -603,3 +677,331 @@ pub fn migrate_populate_staking_hotkeys<T: Config>() -> Weight {
         Weight::zero()
     }
 }
+
+/// Populate the DelegateNominatorCount and DelegateTotalStake maps from the Stake map
+pub fn migrate_populate_delegate_indexes<T: Config>() -> Weight {
+    let new_storage_version = 8;
+    // Setup migration weight
+    let mut weight = T::DbWeight::get().reads(1);
+    let migration_name = "Populate delegate indexes";
+
+    // Only runs once, before the storage version is bumped
+    if Pallet::<T>::on_chain_storage_version() < new_storage_version {
+        info!(target: LOG_TARGET_1, ">>> Starting Migration: {}", migration_name);
+
+        let mut hotkeys_touched: u64 = 0;
+
+        // Every hotkey with stake has an owner, so iterating owners covers all of them.
+        Owner::<T>::iter_keys().for_each(|hotkey| {
+            let reads = Pallet::<T>::refresh_delegate_indexes(&hotkey);
+            hotkeys_touched = hotkeys_touched.saturating_add(1);
+
+            // Accrue weight for reads and writes
+            weight =
+                weight.saturating_add(T::DbWeight::get().reads_writes(reads.saturating_add(1), 2));
+        });
+
+        StorageVersion::new(new_storage_version).put::<Pallet<T>>();
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        // Log migration results
+        info!(
+            target: LOG_TARGET_1,
+            "Migration {} finished. Hotkeys touched: {}",
+            migration_name, hotkeys_touched
+        );
+
+        weight
+    } else {
+        info!(target: LOG_TARGET_1, "Migration {} already done!", migration_name);
+        Weight::zero()
+    }
+}
+
+/// Fold the CommitRevealWeightsEnabled and LiquidAlphaOn maps into SubnetFeatureFlags
+pub fn migrate_subnet_feature_flags<T: Config>() -> Weight {
+    use deprecated_subnet_feature_booleans as old;
+
+    let mut weight = T::DbWeight::get().reads(2);
+    let migration_name = "Fold subnet feature booleans into SubnetFeatureFlags";
+
+    // Check if this migration is needed (if either of the old maps still has entries)
+    let migrate = old::CommitRevealWeightsEnabled::<T>::iter()
+        .next()
+        .is_some()
+        || old::LiquidAlphaOn::<T>::iter().next().is_some();
+
+    if migrate {
+        info!(target: LOG_TARGET_1, ">>> Starting Migration: {}", migration_name);
+
+        let mut entries_migrated: u64 = 0;
+
+        for (netuid, enabled) in old::CommitRevealWeightsEnabled::<T>::drain() {
+            Pallet::<T>::set_subnet_feature(
+                netuid,
+                subnet_features::COMMIT_REVEAL_WEIGHTS,
+                enabled,
+            );
+            entries_migrated = entries_migrated.saturating_add(1);
+        }
+        for (netuid, enabled) in old::LiquidAlphaOn::<T>::drain() {
+            Pallet::<T>::set_subnet_feature(netuid, subnet_features::LIQUID_ALPHA, enabled);
+            entries_migrated = entries_migrated.saturating_add(1);
+        }
+
+        // Each entry is read and removed, and its flags read and written.
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(
+            entries_migrated.saturating_mul(2),
+            entries_migrated.saturating_mul(2),
+        ));
+
+        info!(
+            target: LOG_TARGET_1,
+            "Migration {} finished. Entries migrated: {}",
+            migration_name, entries_migrated
+        );
+
+        weight
+    } else {
+        info!(target: LOG_TARGET_1, "Migration {} already done!", migration_name);
+        Weight::zero()
+    }
+}
+
+/// Seed RootMaxUids from the root network's MaxAllowedUids entry
+pub fn migrate_root_max_uids<T: Config>() -> Weight {
+    let root_netuid = Pallet::<T>::get_root_netuid();
+    let migration_name = "Seed RootMaxUids from MaxAllowedUids";
+    let weight = T::DbWeight::get().reads(2);
+
+    // Only needed on chains which created the root network before RootMaxUids existed.
+    if RootMaxUids::<T>::exists() || !NetworksAdded::<T>::get(root_netuid) {
+        info!(target: LOG_TARGET_1, "Migration {} already done!", migration_name);
+        return weight;
+    }
+
+    let max_uids = MaxAllowedUids::<T>::get(root_netuid);
+    RootMaxUids::<T>::put(max_uids);
+
+    info!(
+        target: LOG_TARGET_1,
+        "Migration {} finished. Root max uids: {}",
+        migration_name, max_uids
+    );
+
+    weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+}
+
+/// Clamp existing delegate takes into the MinTake ..= MaxTake range
+pub fn migrate_clamp_delegate_takes<T: Config>() -> Weight {
+    let mut weight = T::DbWeight::get().reads(2);
+    let migration_name = "Clamp delegate takes into the MinTake ..= MaxTake range";
+    let min_take = MinTake::<T>::get();
+    let max_take = MaxTake::<T>::get();
+
+    info!(target: LOG_TARGET_1, ">>> Starting Migration: {}", migration_name);
+
+    let mut delegates_read: u64 = 0;
+    let mut takes_clamped: u64 = 0;
+    // Collect first, the map is updated while clamping.
+    let delegates: Vec<(T::AccountId, u16)> = Delegates::<T>::iter().collect();
+    for (hotkey, take) in delegates {
+        delegates_read = delegates_read.saturating_add(1);
+        let clamped = take.max(min_take).min(max_take);
+        if clamped != take {
+            Delegates::<T>::insert(&hotkey, clamped);
+            takes_clamped = takes_clamped.saturating_add(1);
+        }
+    }
+
+    weight = weight.saturating_add(T::DbWeight::get().reads_writes(delegates_read, takes_clamped));
+
+    info!(
+        target: LOG_TARGET_1,
+        "Migration {} finished. Takes clamped: {}",
+        migration_name, takes_clamped
+    );
+
+    weight
+}
+
+/// Populate AxonIpIndex from the served Axons and AxonReplicas
+pub fn migrate_populate_axon_ip_index<T: Config>() -> Weight {
+    let mut weight = T::DbWeight::get().reads(1);
+    let migration_name = "Populate axon ip index";
+
+    // Check if this migration is needed (if the index is empty)
+    if AxonIpIndex::<T>::iter().next().is_some() {
+        info!(target: LOG_TARGET_1, "Migration {} already done!", migration_name);
+        return weight;
+    }
+
+    info!(target: LOG_TARGET_1, ">>> Starting Migration: {}", migration_name);
+
+    let mut axons_indexed: u64 = 0;
+    let mut entries_written: u64 = 0;
+    for (netuid, hotkey) in Axons::<T>::iter_keys() {
+        for ip in Pallet::<T>::get_axon_ips(netuid, &hotkey) {
+            AxonIpIndex::<T>::insert(ip, (netuid, hotkey.clone()), ());
+            entries_written = entries_written.saturating_add(1);
+        }
+        axons_indexed = axons_indexed.saturating_add(1);
+    }
+
+    // Each axon set is read from Axons and AxonReplicas.
+    weight = weight.saturating_add(
+        T::DbWeight::get().reads_writes(axons_indexed.saturating_mul(2), entries_written),
+    );
+
+    info!(
+        target: LOG_TARGET_1,
+        "Migration {} finished. Axon sets indexed: {}",
+        migration_name, axons_indexed
+    );
+
+    weight
+}
+
+/// Seed LoadedEmissionEntries and LastUpdateEntries from the entries already in storage
+pub fn migrate_count_storage_entries<T: Config>() -> Weight {
+    let migration_name = "Count LoadedEmission and LastUpdate entries";
+    let mut weight = T::DbWeight::get().reads(2);
+
+    if LoadedEmissionEntries::<T>::exists() && LastUpdateEntries::<T>::exists() {
+        info!(target: LOG_TARGET_1, "Migration {} already done!", migration_name);
+        return weight;
+    }
+
+    let loaded_emission_entries = LoadedEmission::<T>::iter_keys().count();
+    let last_update_entries = LastUpdate::<T>::iter_keys().count();
+    LoadedEmissionEntries::<T>::put(u32::try_from(loaded_emission_entries).unwrap_or(u32::MAX));
+    LastUpdateEntries::<T>::put(u32::try_from(last_update_entries).unwrap_or(u32::MAX));
+
+    weight = weight.saturating_add(
+        T::DbWeight::get().reads_writes(
+            u64::try_from(loaded_emission_entries.saturating_add(last_update_entries))
+                .unwrap_or(u64::MAX),
+            2,
+        ),
+    );
+
+    info!(
+        target: LOG_TARGET_1,
+        "Migration {} finished. LoadedEmission entries: {}, LastUpdate entries: {}",
+        migration_name, loaded_emission_entries, last_update_entries
+    );
+
+    weight
+}
+
+/// Cap the miner uids of every existing subnet at its max allowed uids less its validators
+pub fn migrate_init_max_miner_uids<T: Config>() -> Weight {
+    let migration_name = "Initialise MaxMinerUids";
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if MaxMinerUids::<T>::iter().next().is_some() {
+        info!(target: LOG_TARGET_1, "Migration {} already done!", migration_name);
+        return weight;
+    }
+
+    info!(target: LOG_TARGET_1, ">>> Starting Migration: {}", migration_name);
+
+    let mut subnets_updated: u64 = 0;
+    for netuid in Pallet::<T>::get_all_subnet_netuids() {
+        if netuid == Pallet::<T>::get_root_netuid() {
+            continue;
+        }
+        let max_miner_uids = Pallet::<T>::get_max_allowed_uids(netuid)
+            .saturating_sub(Pallet::<T>::get_max_allowed_validators(netuid));
+        MaxMinerUids::<T>::insert(netuid, max_miner_uids);
+        subnets_updated = subnets_updated.saturating_add(1);
+    }
+
+    // MaxAllowedUids and MaxAllowedValidators are read for each subnet.
+    weight = weight.saturating_add(
+        T::DbWeight::get().reads_writes(subnets_updated.saturating_mul(2), subnets_updated),
+    );
+
+    info!(
+        target: LOG_TARGET_1,
+        "Migration {} finished. Subnets updated: {}",
+        migration_name, subnets_updated
+    );
+
+    weight
+}
+
+/// Seed SubnetNetuids from NetworksAdded
+pub fn migrate_populate_subnet_netuids<T: Config>() -> Weight {
+    let migration_name = "Populate subnet netuids";
+
+    let mut netuids: Vec<u16> = NetworksAdded::<T>::iter()
+        .filter_map(|(netuid, added)| added.then_some(netuid.inner()))
+        .collect();
+    netuids.sort_unstable();
+    let mut weight = T::DbWeight::get().reads(
+        u64::try_from(netuids.len())
+            .unwrap_or(u64::MAX)
+            .saturating_add(1),
+    );
+
+    if SubnetNetuids::<T>::get() == netuids {
+        info!(target: LOG_TARGET_1, "Migration {} already done!", migration_name);
+        return weight;
+    }
+
+    info!(target: LOG_TARGET_1, ">>> Starting Migration: {}", migration_name);
+
+    let subnets = netuids.len();
+    SubnetNetuids::<T>::put(netuids);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    info!(
+        target: LOG_TARGET_1,
+        "Migration {} finished. Subnets: {}",
+        migration_name, subnets
+    );
+
+    weight
+}
+
+/// Move the StakingHotkeys of coldkeys into StakingHotkeyPages, as many coldkeys as fit in
+/// `remaining_weight`. Runs from on_idle until StakingHotkeys is empty, coldkeys whose stake
+/// changes in the meantime are paged as it changes.
+pub fn migrate_page_staking_hotkeys<T: Config>(remaining_weight: Weight) -> Weight {
+    let mut weight = T::DbWeight::get().reads(1);
+    if weight.any_gt(remaining_weight) {
+        return Weight::zero();
+    }
+
+    let mut coldkeys_paged: u32 = 0;
+    let mut coldkeys = StakingHotkeys::<T>::iter_keys();
+    loop {
+        let Some(coldkey) = coldkeys.next() else {
+            break;
+        };
+        let hotkeys = StakingHotkeys::<T>::decode_len(&coldkey).unwrap_or(0);
+        let hotkeys = u64::try_from(hotkeys).unwrap_or(u64::MAX);
+        // Each hotkey reads and writes its page and writes its index entry.
+        let cost = T::DbWeight::get().reads_writes(
+            hotkeys.saturating_mul(2).saturating_add(2),
+            hotkeys.saturating_mul(2).saturating_add(2),
+        );
+        if weight.saturating_add(cost).any_gt(remaining_weight) {
+            break;
+        }
+        Pallet::<T>::page_legacy_staking_hotkeys(&coldkey);
+        weight = weight.saturating_add(cost);
+        coldkeys_paged = coldkeys_paged.saturating_add(1);
+    }
+
+    if coldkeys_paged > 0 {
+        info!(
+            target: LOG_TARGET_1,
+            "Paged the staking hotkeys of {} coldkeys",
+            coldkeys_paged
+        );
+    }
+
+    weight
+}
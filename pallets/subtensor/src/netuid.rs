@@ -0,0 +1,96 @@
+use super::*;
+use codec::{Compact, CompactAs, EncodeLike};
+
+/// The identifier of a subnet.
+///
+/// A `NetUid` encodes exactly as the bare `u16` netuids used by the storage and calls of
+/// this pallet, so it can key any netuid-keyed storage map without a migration. Keeping
+/// netuids apart from neuron uids lets the compiler catch the two being swapped.
+///
+/// `set_root_weights`, whose destinations are netuids where every other weights call takes
+/// neuron uids, and the `NetworksAdded` map take it so far. The other calls and maps still
+/// take a bare `u16` and move over one at a time, which changes neither their encoding nor
+/// their storage layout.
+#[derive(
+    Encode,
+    Decode,
+    TypeInfo,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct NetUid(u16);
+
+impl NetUid {
+    /// The root network.
+    pub const ROOT: NetUid = NetUid(0);
+
+    /// Whether this is the root network.
+    pub const fn is_root(&self) -> bool {
+        self.0 == Self::ROOT.0
+    }
+
+    /// The netuid as a bare `u16`.
+    pub const fn inner(&self) -> u16 {
+        self.0
+    }
+}
+
+impl From<u16> for NetUid {
+    fn from(netuid: u16) -> Self {
+        NetUid(netuid)
+    }
+}
+
+impl From<NetUid> for u16 {
+    fn from(netuid: NetUid) -> Self {
+        netuid.0
+    }
+}
+
+impl CompactAs for NetUid {
+    type As = u16;
+
+    fn encode_as(&self) -> &u16 {
+        &self.0
+    }
+
+    fn decode_from(netuid: u16) -> Result<Self, codec::Error> {
+        Ok(NetUid(netuid))
+    }
+}
+
+impl From<Compact<NetUid>> for NetUid {
+    fn from(netuid: Compact<NetUid>) -> Self {
+        netuid.0
+    }
+}
+
+impl EncodeLike<u16> for NetUid {}
+impl EncodeLike<NetUid> for u16 {}
+
+impl core::fmt::Display for NetUid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns `netuid` as a [`NetUid`] if it names an existing subnet.
+    pub fn checked_netuid(netuid: u16) -> Result<NetUid, Error<T>> {
+        let netuid = NetUid::from(netuid);
+        ensure!(
+            NetworksAdded::<T>::get(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        Ok(netuid)
+    }
+}
@@ -0,0 +1,254 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_runtime::Saturating;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// Returns true if `netuid` is held back from sequential assignment for an auction.
+    pub fn is_netuid_reserved(netuid: u16) -> bool {
+        ReservedNetuids::<T>::contains_key(netuid)
+    }
+
+    /// ---- The implementation for the extrinsic sudo_reserve_netuid.
+    ///
+    /// Reserves a free netuid so that `user_add_network` skips it, and opens an auction for it
+    /// that accepts bids of at least `min_bid` until `duration` blocks from now. The winner
+    /// has NETUID_CLAIM_PERIOD blocks after that to claim it before the reservation expires.
+    ///
+    /// # Raises:
+    /// * 'NetuidAlreadyInUse':
+    ///     - The netuid is the root network, already has a subnet, is already reserved, or a
+    ///       subnet is moving from or to it.
+    ///
+    /// * 'NetuidQuarantined':
+    ///     - The netuid was removed less than NetuidQuarantinePeriod blocks ago.
+    ///
+    /// * 'TooManyReservedNetuids':
+    ///     - MAX_RESERVED_NETUIDS netuids are already reserved.
+    ///
+    pub fn do_reserve_netuid(netuid: u16, min_bid: u64, duration: u64) -> DispatchResult {
+        ensure!(
+            netuid != Self::get_root_netuid()
+                && !Self::if_subnet_exist(netuid)
+                && !Self::is_netuid_reserved(netuid)
+                && !Self::is_subnet_migrating(netuid),
+            Error::<T>::NetuidAlreadyInUse
+        );
+        ensure!(
+            !Self::is_netuid_quarantined(netuid),
+            Error::<T>::NetuidQuarantined
+        );
+        ensure!(
+            ReservedNetuids::<T>::iter_keys().count() < MAX_RESERVED_NETUIDS,
+            Error::<T>::TooManyReservedNetuids
+        );
+
+        let ends_at = Self::get_current_block_as_u64().saturating_add(duration);
+        ReservedNetuids::<T>::insert(netuid, (min_bid, ends_at));
+
+        log::info!(
+            "NetuidReserved( netuid:{:?}, min_bid:{:?}, ends_at:{:?} )",
+            netuid,
+            min_bid,
+            ends_at
+        );
+        Self::deposit_event(Event::NetuidReserved(netuid, min_bid, ends_at));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic sudo_release_netuid.
+    ///
+    /// Returns a reserved netuid to sequential assignment, refunding the highest bid if any.
+    ///
+    /// # Raises:
+    /// * 'NetuidNotReserved':
+    ///     - The netuid is not reserved.
+    ///
+    pub fn do_release_netuid(netuid: u16) -> DispatchResult {
+        ensure!(
+            Self::is_netuid_reserved(netuid),
+            Error::<T>::NetuidNotReserved
+        );
+
+        ReservedNetuids::<T>::remove(netuid);
+        if let Some((bidder, amount)) = NetuidBids::<T>::take(netuid) {
+            Self::refund_netuid_bid(netuid, bidder, amount);
+        }
+
+        log::info!("NetuidReleased( netuid:{:?} )", netuid);
+        Self::deposit_event(Event::NetuidReleased(netuid));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic bid_on_netuid.
+    ///
+    /// Places a bid on a reserved netuid. The amount is held in escrow, and the previous
+    /// highest bid is refunded to its bidder.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the bidding coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The reserved netuid.
+    ///
+    /// * 'amount' (u64):
+    ///     - The bid in RAO.
+    ///
+    /// # Event:
+    /// * NetuidBidPlaced;
+    ///     - On successfully placing the bid.
+    ///
+    /// # Raises:
+    /// * 'NetuidNotReserved':
+    ///     - The netuid is not up for auction.
+    ///
+    /// * 'NetuidAuctionEnded':
+    ///     - The auction no longer accepts bids.
+    ///
+    /// * 'NetuidBidTooLow':
+    ///     - The bid is below the minimum bid or does not beat the highest bid.
+    ///
+    /// * 'NotEnoughBalanceToStake':
+    ///     - The coldkey cannot pay the bid.
+    ///
+    pub fn do_bid_on_netuid(origin: T::RuntimeOrigin, netuid: u16, amount: u64) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        let (min_bid, ends_at) =
+            ReservedNetuids::<T>::get(netuid).ok_or(Error::<T>::NetuidNotReserved)?;
+        ensure!(
+            Self::get_current_block_as_u64() < ends_at,
+            Error::<T>::NetuidAuctionEnded
+        );
+
+        let highest_bid = NetuidBids::<T>::get(netuid);
+        ensure!(
+            amount >= min_bid
+                && amount > 0
+                && highest_bid
+                    .as_ref()
+                    .map_or(true, |(_, highest)| amount > *highest),
+            Error::<T>::NetuidBidTooLow
+        );
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, amount),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+        Self::ensure_fee_reserve_kept(&coldkey, amount)?;
+
+        let escrowed = Self::remove_balance_from_coldkey_account(&coldkey, amount)?;
+        if let Some((bidder, refund)) = highest_bid {
+            Self::refund_netuid_bid(netuid, bidder, refund);
+        }
+        NetuidBids::<T>::insert(netuid, (coldkey.clone(), escrowed));
+
+        log::info!(
+            "NetuidBidPlaced( netuid:{:?}, coldkey:{:?}, amount:{:?} )",
+            netuid,
+            coldkey,
+            escrowed
+        );
+        Self::deposit_event(Event::NetuidBidPlaced(netuid, coldkey, escrowed));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic claim_netuid.
+    ///
+    /// Registers a new network at an auctioned netuid for the winner of the auction, within
+    /// NETUID_CLAIM_PERIOD blocks of its end. The winning bid is burned and the network lock
+    /// cost is locked as for `register_network`.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the winning coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The auctioned netuid.
+    ///
+    /// # Event:
+    /// * NetuidClaimed;
+    ///     - On successfully claiming the netuid.
+    ///
+    /// # Raises:
+    /// * 'NetuidNotReserved':
+    ///     - The netuid is not up for auction, or its reservation expired unclaimed.
+    ///
+    /// * 'NetuidAuctionNotEnded':
+    ///     - The auction still accepts bids.
+    ///
+    /// * 'NotNetuidAuctionWinner':
+    ///     - The caller does not hold the highest bid.
+    ///
+    /// * 'SubnetLimitReached':
+    ///     - There is no room for another subnet.
+    ///
+    /// * Any error raised by `register_network` when locking the network lock cost.
+    ///
+    pub fn do_claim_netuid(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        let (_, ends_at) =
+            ReservedNetuids::<T>::get(netuid).ok_or(Error::<T>::NetuidNotReserved)?;
+        ensure!(
+            Self::get_current_block_as_u64() >= ends_at,
+            Error::<T>::NetuidAuctionNotEnded
+        );
+        let (winner, amount) = NetuidBids::<T>::get(netuid)
+            .filter(|(winner, _)| *winner == coldkey)
+            .ok_or(Error::<T>::NotNetuidAuctionWinner)?;
+        ensure!(
+            Self::get_num_subnets().saturating_sub(1) < Self::get_max_subnets(),
+            Error::<T>::SubnetLimitReached
+        );
+
+        let lock_amount = Self::ensure_can_lock_network(&winner)?;
+        ReservedNetuids::<T>::remove(netuid);
+        NetuidBids::<T>::remove(netuid);
+        Self::burn_tokens(amount);
+        Self::lock_and_add_network(winner.clone(), netuid, lock_amount)?;
+
+        log::info!(
+            "NetuidClaimed( netuid:{:?}, coldkey:{:?}, amount:{:?} )",
+            netuid,
+            winner,
+            amount
+        );
+        Self::deposit_event(Event::NetuidClaimed(netuid, winner, amount));
+        Ok(())
+    }
+
+    /// Releases the reserved netuids not claimed within NETUID_CLAIM_PERIOD blocks of the end
+    /// of their auction, refunding the escrowed bid. Returns the weight used.
+    pub fn expire_netuid_reservations(block_number: u64) -> Weight {
+        let reserved: Vec<(u16, (u64, u64))> = ReservedNetuids::<T>::iter().collect();
+        let mut weight =
+            T::DbWeight::get().reads(u64::try_from(reserved.len()).unwrap_or(u64::MAX));
+        for (netuid, (_, ends_at)) in reserved {
+            if block_number < ends_at.saturating_add(NETUID_CLAIM_PERIOD) {
+                continue;
+            }
+            ReservedNetuids::<T>::remove(netuid);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            if let Some((bidder, amount)) = NetuidBids::<T>::take(netuid) {
+                Self::refund_netuid_bid(netuid, bidder, amount);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            }
+
+            log::info!("NetuidReservationExpired( netuid:{:?} )", netuid);
+            Self::deposit_event(Event::NetuidReservationExpired(netuid));
+        }
+        weight
+    }
+
+    fn refund_netuid_bid(netuid: u16, bidder: T::AccountId, amount: u64) {
+        Self::add_balance_to_coldkey_account(&bidder, amount);
+        Self::deposit_event(Event::NetuidBidRefunded(netuid, bidder, amount));
+    }
+
+    pub fn get_reserved_netuid(netuid: u16) -> Option<(u64, u64)> {
+        ReservedNetuids::<T>::get(netuid)
+    }
+
+    pub fn get_netuid_bid(netuid: u16) -> Option<(T::AccountId, u64)> {
+        NetuidBids::<T>::get(netuid)
+    }
+}
@@ -3,8 +3,9 @@ use frame_support::pallet_prelude::{Decode, Encode};
 use frame_support::storage::IterableStorageDoubleMap;
 extern crate alloc;
 use codec::Compact;
+use sp_core::H256;
 
-#[freeze_struct("45e69321f5c74b4b")]
+#[freeze_struct("d977d60ebc26d8fa")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct NeuronInfo<T: Config> {
     hotkey: T::AccountId,
@@ -27,9 +28,13 @@ pub struct NeuronInfo<T: Config> {
     weights: Vec<(Compact<u16>, Compact<u16>)>, // Vec of (uid, weight)
     bonds: Vec<(Compact<u16>, Compact<u16>)>,   // Vec of (uid, bond)
     pruning_score: Compact<u16>,
+    weights_metadata_hash: Option<H256>, // provenance hash set with the weights
+    axon_reachability: Compact<u16>,     // stake-weighted share of validators reaching the axon
 }
 
-#[freeze_struct("c21f0f4f22bcb2a1")]
+/// A neuron without its weights, bonds and per-coldkey stake, cheap enough to list whole
+/// subnets. The stake totals are read from storage maintained on every stake change.
+#[freeze_struct("9845dd888df98d33")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct NeuronInfoLite<T: Config> {
     hotkey: T::AccountId,
@@ -39,7 +44,9 @@ pub struct NeuronInfoLite<T: Config> {
     active: bool,
     axon_info: AxonInfo,
     prometheus_info: PrometheusInfo,
-    stake: Vec<(T::AccountId, Compact<u64>)>, // map of coldkey to stake on this neuron/hotkey (includes delegations)
+    total_stake: Compact<u64>, // stake on this neuron/hotkey, including delegations
+    self_stake: Compact<u64>,  // stake of the hotkey's own coldkey
+    delegated_stake: Compact<u64>, // stake of the other coldkeys
     rank: Compact<u16>,
     emission: Compact<u64>,
     incentive: Compact<u16>,
@@ -97,6 +104,7 @@ impl<T: Config> Pallet<T> {
         let validator_permit = Self::get_validator_permit_for_uid(netuid, uid);
 
         let weights = <Weights<T>>::get(netuid, uid)
+            .entries()
             .iter()
             .filter_map(|(i, w)| {
                 if *w > 0 {
@@ -146,11 +154,33 @@ impl<T: Config> Pallet<T> {
             weights,
             bonds,
             pruning_score: pruning_score.into(),
+            weights_metadata_hash: Self::get_weights_metadata_hash(netuid, uid),
+            axon_reachability: Self::get_axon_reachability(netuid, uid).into(),
         };
 
         Some(neuron)
     }
 
+    /// Returns the neurons of `netuid` from `start_uid` on, at most `limit` of them and never
+    /// more than MAX_NEURONS_PAGE_SIZE. A page shorter than the limit is the last one.
+    pub fn get_neurons_paginated(netuid: u16, start_uid: u16, limit: u16) -> Vec<NeuronInfo<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+
+        Self::neurons_page(netuid, start_uid, limit)
+            .map_while(|uid| Self::get_neuron_subnet_exists(netuid, uid))
+            .collect()
+    }
+
+    /// The uids of a page of neurons of `netuid`, from `start_uid` on.
+    fn neurons_page(netuid: u16, start_uid: u16, limit: u16) -> core::ops::Range<u16> {
+        let end = start_uid
+            .saturating_add(limit.min(MAX_NEURONS_PAGE_SIZE))
+            .min(Self::get_subnetwork_n(netuid));
+        start_uid..end
+    }
+
     pub fn get_neuron(netuid: u16, uid: u16) -> Option<NeuronInfo<T>> {
         if !Self::if_subnet_exist(netuid) {
             return None;
@@ -183,12 +213,7 @@ impl<T: Config> Pallet<T> {
         let last_update = Self::get_last_update_for_uid(netuid, uid);
         let validator_permit = Self::get_validator_permit_for_uid(netuid, uid);
 
-        let stake: Vec<(T::AccountId, Compact<u64>)> =
-            <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix(
-                hotkey.clone(),
-            )
-            .map(|(coldkey, stake)| (coldkey, stake.into()))
-            .collect();
+        let (total_stake, self_stake, delegated_stake) = Self::get_hotkey_stake_totals(&hotkey);
 
         let neuron = NeuronInfoLite {
             hotkey: hotkey.clone(),
@@ -198,7 +223,9 @@ impl<T: Config> Pallet<T> {
             active,
             axon_info,
             prometheus_info,
-            stake,
+            total_stake: total_stake.into(),
+            self_stake: self_stake.into(),
+            delegated_stake: delegated_stake.into(),
             rank: rank.into(),
             emission: emission.into(),
             incentive: incentive.into(),
@@ -214,6 +241,21 @@ impl<T: Config> Pallet<T> {
         Some(neuron)
     }
 
+    /// The total stake of `hotkey`, the stake of its owner and the stake delegated to it by
+    /// other coldkeys, from TotalHotkeyStake and a single Stake entry.
+    pub fn get_hotkey_stake_totals(hotkey: &T::AccountId) -> (u64, u64, u64) {
+        let total_stake = Self::get_total_stake_for_hotkey(hotkey);
+        let self_stake = Self::get_stake_for_coldkey_and_hotkey(
+            &Self::get_owning_coldkey_for_hotkey(hotkey),
+            hotkey,
+        );
+        (
+            total_stake,
+            self_stake,
+            total_stake.saturating_sub(self_stake),
+        )
+    }
+
     pub fn get_neurons_lite(netuid: u16) -> Vec<NeuronInfoLite<T>> {
         if !Self::if_subnet_exist(netuid) {
             return Vec::new();
@@ -232,6 +274,22 @@ impl<T: Config> Pallet<T> {
         neurons
     }
 
+    /// Returns the lite neurons of `netuid` from `start_uid` on, at most `limit` of them and
+    /// never more than MAX_NEURONS_PAGE_SIZE. A page shorter than the limit is the last one.
+    pub fn get_neurons_lite_paginated(
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+    ) -> Vec<NeuronInfoLite<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+
+        Self::neurons_page(netuid, start_uid, limit)
+            .map_while(|uid| Self::get_neuron_lite_subnet_exists(netuid, uid))
+            .collect()
+    }
+
     pub fn get_neuron_lite(netuid: u16, uid: u16) -> Option<NeuronInfoLite<T>> {
         if !Self::if_subnet_exist(netuid) {
             return None;
@@ -239,4 +297,30 @@ impl<T: Config> Pallet<T> {
 
         Self::get_neuron_lite_subnet_exists(netuid, uid)
     }
+
+    /// Returns every axon served by the neuron, primary axon first, so clients can spread
+    /// requests across its replicas. Axons that have gone stale under the subnet's
+    /// AxonTTLBlocks are left out.
+    pub fn get_axons(netuid: u16, uid: u16) -> Vec<AxonInfo> {
+        let current_block = Self::get_current_block_as_u64();
+        match Self::get_hotkey_for_net_and_uid(netuid, uid) {
+            Ok(hotkey) => Self::get_axons_for_hotkey(netuid, &hotkey)
+                .into_iter()
+                .filter(|axon| !Self::is_axon_stale(netuid, axon, current_block))
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Returns the (netuid, uid) of every registered neuron serving an axon on `ip`, so
+    /// operators can map a misbehaving endpoint back to on-chain identities.
+    pub fn get_neurons_by_ip(ip: u128) -> Vec<(u16, u16)> {
+        AxonIpIndex::<T>::iter_key_prefix(ip)
+            .filter_map(|(netuid, hotkey)| {
+                Self::get_uid_for_net_and_hotkey(netuid, &hotkey)
+                    .ok()
+                    .map(|uid| (netuid, uid))
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,71 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// The values the subnet owner can set `param` of `netuid` to, inclusive. Root is not
+    /// bound by them.
+    pub fn get_owner_hyperparam_bounds(netuid: u16, param: OwnerHyperparam) -> (u16, u16) {
+        match param {
+            // Short enough to keep the subnet responsive, long enough not to crowd the
+            // epochs of the other subnets into the same blocks.
+            OwnerHyperparam::Tempo => (10, 1_000),
+            // Up to a day at 12 second blocks.
+            OwnerHyperparam::AdjustmentInterval => (10, 7_200),
+            OwnerHyperparam::TargetRegistrationsPerInterval => {
+                (1, Self::get_max_allowed_uids(netuid).max(1))
+            }
+            OwnerHyperparam::MaxRegistrationsPerBlock => (1, 32),
+            OwnerHyperparam::ImmunityPeriod => (0, Self::get_max_immunity_period()),
+            // No lower than a tenth, so that the stake of at least ten validators still
+            // decides consensus.
+            OwnerHyperparam::MaxStakeWeightFraction => (6_554, u16::MAX),
+        }
+    }
+
+    /// Sets `param` of `netuid` to `value`, as a passed subnet referendum does.
+    pub fn set_owner_hyperparam(netuid: u16, param: OwnerHyperparam, value: u16) {
+        match param {
+            OwnerHyperparam::Tempo => Self::set_tempo(netuid, value),
+            OwnerHyperparam::AdjustmentInterval => Self::set_adjustment_interval(netuid, value),
+            OwnerHyperparam::TargetRegistrationsPerInterval => {
+                Self::set_target_registrations_per_interval(netuid, value)
+            }
+            OwnerHyperparam::MaxRegistrationsPerBlock => {
+                Self::set_max_registrations_per_block(netuid, value)
+            }
+            OwnerHyperparam::ImmunityPeriod => Self::set_immunity_period(netuid, value),
+            OwnerHyperparam::MaxStakeWeightFraction => {
+                Self::set_max_stake_weight_fraction(netuid, value)
+            }
+        }
+    }
+
+    /// Whether OwnerHyperparamRateLimit blocks passed since the subnet owner last set `param`
+    /// of `netuid`.
+    pub fn passes_owner_hyperparam_rate_limit(netuid: u16, param: OwnerHyperparam) -> bool {
+        LastOwnerHyperparamUpdate::<T>::get(netuid, param).is_none_or(|last| {
+            Self::get_current_block_as_u64()
+                >= last.saturating_add(Self::get_owner_hyperparam_rate_limit())
+        })
+    }
+
+    /// Records that the subnet owner set `param` of `netuid` in the current block.
+    pub fn set_last_owner_hyperparam_update(netuid: u16, param: OwnerHyperparam) {
+        LastOwnerHyperparamUpdate::<T>::insert(netuid, param, Self::get_current_block_as_u64());
+    }
+
+    pub fn get_owner_hyperparam_rate_limit() -> u64 {
+        OwnerHyperparamRateLimit::<T>::get()
+    }
+    pub fn set_owner_hyperparam_rate_limit(rate_limit: u64) {
+        OwnerHyperparamRateLimit::<T>::put(rate_limit);
+        Self::deposit_event(Event::OwnerHyperparamRateLimitSet(rate_limit));
+    }
+
+    pub fn get_max_immunity_period() -> u16 {
+        MaxImmunityPeriod::<T>::get()
+    }
+    pub fn set_max_immunity_period(max_immunity_period: u16) {
+        MaxImmunityPeriod::<T>::put(max_immunity_period);
+        Self::deposit_event(Event::MaxImmunityPeriodSet(max_immunity_period));
+    }
+}
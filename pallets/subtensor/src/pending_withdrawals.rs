@@ -0,0 +1,160 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// Credits removed stake to the coldkey, or while an unstake cooldown is set, queues it
+    /// until the cooldown ends so that stake can't be flashed around epoch boundaries. Stake
+    /// removed from the same hotkey in the same block joins a single withdrawal.
+    pub fn pay_out_or_schedule_withdrawal(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) {
+        let cooldown = Self::get_unstake_cooldown();
+        if cooldown == 0 {
+            Self::add_balance_to_coldkey_account(coldkey, amount);
+            return;
+        }
+        let unlock_block = Self::get_current_block_as_u64().saturating_add(cooldown);
+        PendingWithdrawals::<T>::mutate(coldkey, |pending| {
+            Self::queue_withdrawal(pending, (hotkey.clone(), amount, unlock_block))
+        });
+        log::info!(
+            "WithdrawalScheduled( coldkey:{:?}, hotkey:{:?}, amount:{:?}, unlock_block:{:?} )",
+            coldkey,
+            hotkey,
+            amount,
+            unlock_block
+        );
+        Self::deposit_event(Event::WithdrawalScheduled(
+            coldkey.clone(),
+            hotkey.clone(),
+            amount,
+            unlock_block,
+        ));
+    }
+
+    /// Whether stake removed now from `hotkey` fits in the withdrawal queue of the coldkey:
+    /// the queue is below MAX_PENDING_WITHDRAWALS or holds a withdrawal it joins.
+    pub fn can_queue_withdrawal(coldkey: &T::AccountId, hotkey: &T::AccountId) -> bool {
+        let unlock_block =
+            Self::get_current_block_as_u64().saturating_add(Self::get_unstake_cooldown());
+        let pending = PendingWithdrawals::<T>::get(coldkey);
+        pending.len() < MAX_PENDING_WITHDRAWALS
+            || pending
+                .iter()
+                .any(|(queued_hotkey, _, queued_unlock_block)| {
+                    queued_hotkey == hotkey && *queued_unlock_block == unlock_block
+                })
+    }
+
+    /// Adds a withdrawal to a queue, merging it into the queued withdrawal from the same
+    /// hotkey unlocking at the same block if there is one.
+    fn queue_withdrawal(
+        pending: &mut Vec<(T::AccountId, u64, u64)>,
+        (hotkey, amount, unlock_block): (T::AccountId, u64, u64),
+    ) {
+        match pending
+            .iter_mut()
+            .find(|(queued_hotkey, _, queued_unlock_block)| {
+                *queued_hotkey == hotkey && *queued_unlock_block == unlock_block
+            }) {
+            Some((_, queued, _)) => *queued = queued.saturating_add(amount),
+            None => pending.push((hotkey, amount, unlock_block)),
+        }
+    }
+
+    /// ---- The implementation for the extrinsic claim_unstaked.
+    ///
+    /// Credits the coldkey with every withdrawal whose unstake cooldown ended.
+    ///
+    /// # Event:
+    /// * UnstakeClaimed;
+    ///     - On successfully claiming the withdrawals.
+    ///
+    /// # Raises:
+    /// * 'NothingToClaim':
+    ///     - No withdrawal of the coldkey finished its cooldown.
+    ///
+    pub fn do_claim_unstaked(origin: T::RuntimeOrigin) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        let current_block = Self::get_current_block_as_u64();
+
+        let (claimable, pending): (Vec<_>, Vec<_>) = PendingWithdrawals::<T>::get(&coldkey)
+            .into_iter()
+            .partition(|(_, _, unlock_block)| *unlock_block <= current_block);
+        ensure!(!claimable.is_empty(), Error::<T>::NothingToClaim);
+
+        let claimed = claimable
+            .iter()
+            .fold(0u64, |total, (_, amount, _)| total.saturating_add(*amount));
+        if pending.is_empty() {
+            PendingWithdrawals::<T>::remove(&coldkey);
+        } else {
+            PendingWithdrawals::<T>::insert(&coldkey, pending);
+        }
+        Self::add_balance_to_coldkey_account(&coldkey, claimed);
+
+        log::info!(
+            "UnstakeClaimed( coldkey:{:?}, amount:{:?} )",
+            coldkey,
+            claimed
+        );
+        Self::deposit_event(Event::UnstakeClaimed(coldkey, claimed));
+        Ok(())
+    }
+
+    /// Returns the removed stake of the coldkey not yet claimed.
+    pub fn get_pending_withdrawal_total(coldkey: &T::AccountId) -> u64 {
+        PendingWithdrawals::<T>::get(coldkey)
+            .iter()
+            .fold(0u64, |total, (_, amount, _)| total.saturating_add(*amount))
+    }
+
+    /// Moves the withdrawals of the old coldkey to the new coldkey, merging those from the
+    /// same hotkey unlocking at the same block. A swap is never refused for the size of the
+    /// queue: past MAX_PENDING_WITHDRAWALS entries the earliest ones are collapsed together.
+    pub fn swap_pending_withdrawals_for_coldkey(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        weight: &mut Weight,
+    ) {
+        let withdrawals = PendingWithdrawals::<T>::take(old_coldkey);
+        if withdrawals.is_empty() {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            return;
+        }
+        PendingWithdrawals::<T>::mutate(new_coldkey, |pending| {
+            for withdrawal in withdrawals {
+                Self::queue_withdrawal(pending, withdrawal);
+            }
+            Self::collapse_withdrawals(pending);
+        });
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+    }
+
+    /// Shrinks a queue to MAX_PENDING_WITHDRAWALS entries by folding each earliest withdrawal
+    /// into the next one to unlock. The folded amount takes the later unlock block, so no
+    /// stake is released before its cooldown ends.
+    fn collapse_withdrawals(pending: &mut Vec<(T::AccountId, u64, u64)>) {
+        if pending.len() <= MAX_PENDING_WITHDRAWALS {
+            return;
+        }
+        pending.sort_by_key(|(_, _, unlock_block)| *unlock_block);
+        while pending.len() > MAX_PENDING_WITHDRAWALS {
+            let (_, amount, _) = pending.remove(0);
+            if let Some((_, next, _)) = pending.first_mut() {
+                *next = next.saturating_add(amount);
+            }
+        }
+    }
+
+    pub fn get_unstake_cooldown() -> u64 {
+        UnstakeCooldown::<T>::get()
+    }
+    pub fn set_unstake_cooldown(cooldown: u64) {
+        UnstakeCooldown::<T>::put(cooldown);
+        Self::deposit_event(Event::UnstakeCooldownSet(cooldown));
+    }
+}
@@ -0,0 +1,74 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+/// The difficulty solved by the PoW registrations of a subnet, next to the bounds governance
+/// keeps its difficulty within.
+#[freeze_struct("82e42f2508de5c3f")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct PowStats {
+    pub netuid: Compact<u16>,
+    pub difficulty: Compact<u64>,
+    pub min_difficulty: Compact<u64>,
+    pub max_difficulty: Compact<u64>,
+    /// Sum of the difficulty of every PoW registration since the subnet was added.
+    pub total_difficulty_solved: Compact<u128>,
+    pub difficulty_solved_this_interval: Compact<u128>,
+    pub pow_registrations_this_interval: Compact<u16>,
+    /// Average difficulty solved per PoW registration in the last adjustment intervals,
+    /// oldest first, 0 for intervals without any.
+    pub interval_averages: Vec<Compact<u64>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Adds the difficulty solved by a PoW registration on `netuid` to its totals.
+    pub fn record_pow_difficulty_solved(netuid: u16, difficulty: u64) {
+        PowDifficultySolved::<T>::mutate(netuid, |total| {
+            *total = total.saturating_add(u128::from(difficulty))
+        });
+        PowDifficultySolvedThisInterval::<T>::mutate(netuid, |total| {
+            *total = total.saturating_add(u128::from(difficulty))
+        });
+    }
+
+    /// Closes the adjustment interval of `netuid`, appending the average difficulty solved
+    /// by its `pow_registrations` to PowIntervalAverages and dropping the oldest average once
+    /// POW_STATS_INTERVALS are kept.
+    pub fn close_pow_stats_interval(netuid: u16, pow_registrations: u16) {
+        let solved = PowDifficultySolvedThisInterval::<T>::take(netuid);
+        let average = solved
+            .checked_div(u128::from(pow_registrations))
+            .and_then(|average| u64::try_from(average).ok())
+            .unwrap_or(0);
+        PowIntervalAverages::<T>::mutate(netuid, |averages| {
+            if averages.len() >= POW_STATS_INTERVALS as usize {
+                averages.remove(0);
+            }
+            // Cannot fail, the oldest average was dropped above.
+            let _ = averages.try_push(average);
+        });
+    }
+
+    /// Returns the PoW registration statistics of `netuid`, None if the subnet does not exist.
+    pub fn get_pow_stats(netuid: u16) -> Option<PowStats> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        Some(PowStats {
+            netuid: netuid.into(),
+            difficulty: Self::get_difficulty_as_u64(netuid).into(),
+            min_difficulty: Self::get_min_difficulty(netuid).into(),
+            max_difficulty: Self::get_max_difficulty(netuid).into(),
+            total_difficulty_solved: PowDifficultySolved::<T>::get(netuid).into(),
+            difficulty_solved_this_interval: PowDifficultySolvedThisInterval::<T>::get(netuid)
+                .into(),
+            pow_registrations_this_interval: Self::get_pow_registrations_this_interval(netuid)
+                .into(),
+            interval_averages: PowIntervalAverages::<T>::get(netuid)
+                .into_iter()
+                .map(Compact)
+                .collect(),
+        })
+    }
+}
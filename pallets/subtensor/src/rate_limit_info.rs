@@ -0,0 +1,176 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+/// The rate-limited actions reported by `get_rate_limits`.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub enum RateLimitedAction {
+    /// `set_weights` on a subnet, limited by `WeightsSetRateLimit`.
+    SetWeights,
+    /// `serve_axon` on a subnet, limited by `ServingRateLimit`.
+    ServeAxon,
+    /// `add_stake`/`remove_stake` from the owning coldkey, limited by `TargetStakesPerInterval`.
+    Stake,
+    /// `increase_take` from the owning coldkey, limited by `TxDelegateTakeRateLimit`.
+    SetTake,
+    /// `become_delegate`/`swap_hotkey` from the owning coldkey, limited by `TxRateLimit`.
+    Transaction,
+}
+
+#[freeze_struct("7a0e8aabe49a0c0f")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct RateLimitInfo {
+    pub action: RateLimitedAction,
+    pub netuid: Option<Compact<u16>>,
+    pub last_block: Compact<u64>,
+    pub blocks_remaining: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Blocks left until the action is allowed again, zero when it is allowed now.
+    /// `inclusive` limits also reject a call made exactly `rate_limit` blocks later.
+    fn blocks_until_rate_limit_passes(
+        last_block: u64,
+        rate_limit: u64,
+        current_block: u64,
+        inclusive: bool,
+    ) -> u64 {
+        if rate_limit == 0 || last_block == 0 {
+            return 0;
+        }
+        last_block
+            .saturating_add(rate_limit)
+            .saturating_add(u64::from(inclusive))
+            .saturating_sub(current_block)
+    }
+
+    fn _get_rate_limits(hotkey: &T::AccountId) -> Vec<RateLimitInfo> {
+        let current_block = Self::get_current_block_as_u64();
+        let mut rate_limits: Vec<RateLimitInfo> = Vec::new();
+
+        for netuid in Self::get_registered_networks_for_hotkey(hotkey) {
+            if let Ok(uid) = Self::get_uid_for_net_and_hotkey(netuid, hotkey) {
+                let last_block = Self::get_last_update_for_uid(netuid, uid);
+                rate_limits.push(RateLimitInfo {
+                    action: RateLimitedAction::SetWeights,
+                    netuid: Some(netuid.into()),
+                    last_block: last_block.into(),
+                    blocks_remaining: Self::blocks_until_rate_limit_passes(
+                        last_block,
+                        Self::get_weights_set_rate_limit(netuid),
+                        current_block,
+                        false,
+                    )
+                    .into(),
+                });
+            }
+
+            if let Some(axon) = Axons::<T>::get(netuid, hotkey) {
+                rate_limits.push(RateLimitInfo {
+                    action: RateLimitedAction::ServeAxon,
+                    netuid: Some(netuid.into()),
+                    last_block: axon.block.into(),
+                    blocks_remaining: Self::blocks_until_rate_limit_passes(
+                        axon.block,
+                        Self::get_serving_rate_limit(netuid),
+                        current_block,
+                        false,
+                    )
+                    .into(),
+                });
+            }
+        }
+
+        if !Self::hotkey_account_exists(hotkey) {
+            return rate_limits;
+        }
+        let coldkey = Self::get_owning_coldkey_for_hotkey(hotkey);
+
+        // Read the interval directly, `get_stakes_this_interval_for_coldkey_hotkey` resets it.
+        let (stakes, last_staked_at) =
+            TotalHotkeyColdkeyStakesThisInterval::<T>::get(&coldkey, hotkey);
+        let interval_ends_at = last_staked_at.saturating_add(StakeInterval::<T>::get());
+        let stake_blocks_remaining = if interval_ends_at > current_block
+            && stakes >= Self::get_target_stakes_per_interval()
+        {
+            interval_ends_at.saturating_sub(current_block)
+        } else {
+            0
+        };
+        rate_limits.push(RateLimitInfo {
+            action: RateLimitedAction::Stake,
+            netuid: None,
+            last_block: last_staked_at.into(),
+            blocks_remaining: stake_blocks_remaining.into(),
+        });
+
+        let last_take_block = Self::get_last_tx_block_delegate_take(&coldkey);
+        rate_limits.push(RateLimitInfo {
+            action: RateLimitedAction::SetTake,
+            netuid: None,
+            last_block: last_take_block.into(),
+            blocks_remaining: Self::blocks_until_rate_limit_passes(
+                last_take_block,
+                Self::get_tx_delegate_take_rate_limit(),
+                current_block,
+                true,
+            )
+            .into(),
+        });
+
+        let last_tx_block = Self::get_last_tx_block(&coldkey);
+        rate_limits.push(RateLimitInfo {
+            action: RateLimitedAction::Transaction,
+            netuid: None,
+            last_block: last_tx_block.into(),
+            blocks_remaining: Self::blocks_until_rate_limit_passes(
+                last_tx_block,
+                Self::get_tx_rate_limit(),
+                current_block,
+                true,
+            )
+            .into(),
+        });
+
+        rate_limits
+    }
+
+    pub fn get_rate_limits(hotkey_account_vec: Vec<u8>) -> Vec<RateLimitInfo> {
+        if hotkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid hotkey
+        }
+        let Ok(hotkey) = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        Self::_get_rate_limits(&hotkey)
+    }
+
+    fn _get_next_weight_set_block(netuid: u16, hotkey: &T::AccountId) -> Option<u64> {
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey).ok()?;
+        let current_block = Self::get_current_block_as_u64();
+        let last_block = Self::get_last_update_for_uid(netuid, uid);
+        if last_block == 0 {
+            return Some(current_block); // Never set weights.
+        }
+        Some(
+            last_block
+                .saturating_add(Self::get_weights_set_rate_limit(netuid))
+                .max(current_block),
+        )
+    }
+
+    /// Returns the earliest block at which `set_weights` from the hotkey on `netuid` passes
+    /// the weights rate limit, the current block if it already does. Returns nothing if the
+    /// hotkey is not registered on the subnet.
+    pub fn get_next_weight_set_block(netuid: u16, hotkey_account_vec: Vec<u8>) -> Option<u64> {
+        if hotkey_account_vec.len() != 32 {
+            return None; // Invalid hotkey
+        }
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+
+        Self::_get_next_weight_set_block(netuid, &hotkey)
+    }
+}
@@ -0,0 +1,115 @@
+use super::*;
+use sp_runtime::Saturating;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic recycle.
+    ///
+    /// Removes `amount` from the caller's free balance and adds it to the pending emission of
+    /// `netuid`, so it is paid out by the subnet's next epoch. The tokens leave the total
+    /// issuance until they are emitted again.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the recycling coldkey.
+    ///
+    /// * 'amount' (u64):
+    ///     - The amount of RAO to recycle.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet whose emission pool receives the tokens.
+    ///
+    /// # Event:
+    /// * TokensRecycled;
+    ///     - On successfully recycling the tokens.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'CanNotRecycleToRootNetwork':
+    ///     - The root network does not run epochs to pay out an emission pool.
+    ///
+    /// * 'RecycleAmountIsZero':
+    ///     - Nothing was given to recycle.
+    ///
+    /// * 'NotEnoughBalanceToRecycle':
+    ///     - The coldkey's free balance is below the amount.
+    ///
+    pub fn do_recycle(origin: T::RuntimeOrigin, amount: u64, netuid: u16) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::CanNotRecycleToRootNetwork
+        );
+
+        let recycled = Self::withdraw_for_recycling(&coldkey, amount)?;
+        Self::burn_tokens(recycled);
+        PendingEmission::<T>::mutate(netuid, |pending| pending.saturating_accrue(recycled));
+        RAORecycledIntoEmission::<T>::mutate(netuid, |total| total.saturating_accrue(recycled));
+
+        log::info!(
+            "TokensRecycled( coldkey:{:?}, netuid:{:?}, amount:{:?} )",
+            coldkey,
+            netuid,
+            recycled
+        );
+        Self::deposit_subnet_event(&[netuid], Event::TokensRecycled(coldkey, netuid, recycled));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic burn.
+    ///
+    /// Removes `amount` from the caller's free balance and the total issuance for good.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the burning coldkey.
+    ///
+    /// * 'amount' (u64):
+    ///     - The amount of RAO to burn.
+    ///
+    /// # Event:
+    /// * TokensBurned;
+    ///     - On successfully burning the tokens.
+    ///
+    /// # Raises:
+    /// * 'RecycleAmountIsZero':
+    ///     - Nothing was given to burn.
+    ///
+    /// * 'NotEnoughBalanceToRecycle':
+    ///     - The coldkey's free balance is below the amount.
+    ///
+    pub fn do_burn(origin: T::RuntimeOrigin, amount: u64) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        let burned = Self::withdraw_for_recycling(&coldkey, amount)?;
+        Self::burn_tokens(burned);
+        TotalRAOBurned::<T>::mutate(|total| total.saturating_accrue(burned));
+
+        log::info!("TokensBurned( coldkey:{:?}, amount:{:?} )", coldkey, burned);
+        Self::deposit_event(Event::TokensBurned(coldkey, burned));
+        Ok(())
+    }
+
+    /// Withdraws `amount` from the coldkey's free balance, returning the amount withdrawn.
+    fn withdraw_for_recycling(coldkey: &T::AccountId, amount: u64) -> Result<u64, DispatchError> {
+        ensure!(amount > 0, Error::<T>::RecycleAmountIsZero);
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(coldkey, amount),
+            Error::<T>::NotEnoughBalanceToRecycle
+        );
+        Self::remove_balance_from_coldkey_account(coldkey, amount)
+    }
+
+    pub fn get_rao_recycled_into_emission(netuid: u16) -> u64 {
+        RAORecycledIntoEmission::<T>::get(netuid)
+    }
+
+    pub fn get_total_rao_burned() -> u64 {
+        TotalRAOBurned::<T>::get()
+    }
+}
@@ -1,12 +1,83 @@
 use super::*;
 use sp_core::{H256, U256};
-use sp_io::hashing::{keccak_256, sha2_256};
+use sp_io::hashing::{blake2_256, keccak_256, sha2_256};
 use sp_runtime::Saturating;
 use system::pallet_prelude::BlockNumberFor;
 
 const LOG_TARGET: &str = "runtime::subtensor::registration";
 
+/// The way a neuron registers on a subnet, each with its own per-block sub-limit.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum RegistrationRoute {
+    /// Registration with a proof of work through `register`.
+    Pow,
+    /// Registration by burning TAO through `burned_register` or `burned_root_register`.
+    Burn,
+}
+
 impl<T: Config> Pallet<T> {
+    /// Ensures one more registration through `route` fits in this block, both under the
+    /// subnet's total registrations per block and under the sub-limit of the route. The last
+    /// registration of a block allowing several is left to the role the subnet is short of.
+    pub(crate) fn ensure_registration_fits_in_block(
+        netuid: u16,
+        route: RegistrationRoute,
+        role: Option<NeuronRole>,
+    ) -> DispatchResult {
+        let registrations_this_block = Self::get_registrations_this_block(netuid);
+        let max_registrations_per_block = Self::get_max_registrations_per_block(netuid);
+        ensure!(
+            registrations_this_block < max_registrations_per_block,
+            Error::<T>::TooManyRegistrationsThisBlock
+        );
+        if max_registrations_per_block > 1
+            && registrations_this_block.saturating_add(1) == max_registrations_per_block
+        {
+            if let Some(short_role) = Self::get_short_role(netuid) {
+                ensure!(
+                    role == Some(short_role),
+                    Error::<T>::RegistrationReservedForRole
+                );
+            }
+        }
+        match route {
+            RegistrationRoute::Pow => ensure!(
+                Self::get_pow_registrations_this_block(netuid)
+                    < Self::get_max_pow_registrations_per_block(netuid),
+                Error::<T>::TooManyPowRegistrationsThisBlock
+            ),
+            RegistrationRoute::Burn => ensure!(
+                Self::get_burn_registrations_this_block(netuid)
+                    < Self::get_max_burn_registrations_per_block(netuid),
+                Error::<T>::TooManyBurnRegistrationsThisBlock
+            ),
+        }
+        Ok(())
+    }
+
+    /// Ensures `hotkey` already holds the MinStakeToRegister of the subnet, so registering
+    /// takes more than a throwaway key.
+    fn ensure_hotkey_has_stake_to_register(netuid: u16, hotkey: &T::AccountId) -> DispatchResult {
+        ensure!(
+            Self::get_total_stake_for_hotkey(hotkey) >= Self::get_min_stake_to_register(netuid),
+            Error::<T>::NotEnoughStakeToRegister
+        );
+        Ok(())
+    }
+
+    /// Counts a registration through `route` towards the limits of this block.
+    fn record_registration_in_block(netuid: u16, route: RegistrationRoute) {
+        RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+        match route {
+            RegistrationRoute::Pow => {
+                POWRegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc())
+            }
+            RegistrationRoute::Burn => {
+                BurnRegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc())
+            }
+        }
+    }
+
     /// ---- The implementation for the extrinsic do_burned_registration: registering by burning TAO.
     ///
     /// # Args:
@@ -31,13 +102,35 @@ impl<T: Config> Pallet<T> {
     /// * 'TooManyRegistrationsThisBlock':
     ///     - This registration exceeds the total allowed on this network this block.
     ///
+    /// * 'TooManyBurnRegistrationsThisBlock':
+    ///     - This registration exceeds the burned registrations allowed on this network this block.
+    ///
     /// * 'HotKeyAlreadyRegisteredInSubNet':
     ///     - The hotkey is already registered on this network.
     ///
+    /// * 'NotEnoughStakeToRegister':
+    ///     - The hotkey holds less stake than the network requires to register.
+    ///
     pub fn do_burned_registration(
         origin: T::RuntimeOrigin,
         netuid: u16,
         hotkey: T::AccountId,
+    ) -> DispatchResult {
+        Self::do_burned_registration_with_role(origin, netuid, hotkey, None)
+    }
+
+    /// ---- The implementation for the extrinsic burned_register_with_role: registering by
+    /// burning TAO as `do_burned_registration`, recording the `role` the hotkey declares.
+    ///
+    /// # Raises:
+    /// * 'RegistrationReservedForRole':
+    ///     - One registration is left in this block and the subnet is short of another role.
+    ///
+    pub fn do_burned_registration_with_role(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+        role: Option<NeuronRole>,
     ) -> DispatchResult {
         // --- 1. Check that the caller has signed the transaction. (the coldkey of the pairing)
         let coldkey = ensure_signed(origin)?;
@@ -57,6 +150,7 @@ impl<T: Config> Pallet<T> {
             netuid != Self::get_root_netuid(),
             Error::<T>::RegistrationNotPermittedOnRootSubnet
         );
+        ensure!(!Self::is_hotkey_banned(&hotkey), Error::<T>::HotkeyIsBanned);
         ensure!(
             Self::if_subnet_exist(netuid),
             Error::<T>::SubNetworkDoesNotExist
@@ -69,11 +163,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
-        ensure!(
-            Self::get_registrations_this_block(netuid)
-                < Self::get_max_registrations_per_block(netuid),
-            Error::<T>::TooManyRegistrationsThisBlock
-        );
+        Self::ensure_registration_fits_in_block(netuid, RegistrationRoute::Burn, role)?;
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per interval.
         ensure!(
@@ -88,6 +178,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
 
+        // --- 5. Ensure the hotkey already holds the stake the subnet requires.
+        Self::ensure_hotkey_has_stake_to_register(netuid, &hotkey)?;
+
         // DEPRECATED --- 6. Ensure that the key passes the registration requirement
         // ensure!(
         //     Self::passes_network_connection_requirement(netuid, &hotkey),
@@ -124,11 +217,11 @@ impl<T: Config> Pallet<T> {
 
         // Possibly there is no neuron slots at all.
         ensure!(
-            Self::get_max_allowed_uids(netuid) != 0,
+            Self::get_max_allowed_uids(netuid) != 0 && Self::get_max_miner_uids(netuid) != 0,
             Error::<T>::NoNeuronIdAvailable
         );
 
-        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
+        if Self::has_free_miner_uid(netuid) {
             // --- 12.1.1 No replacement required, the uid appends the subnetwork.
             // We increment the subnetwork count here but not below.
             subnetwork_uid = current_subnetwork_n;
@@ -138,8 +231,9 @@ impl<T: Config> Pallet<T> {
             log::info!("add new neuron account");
         } else {
             // --- 13.1.1 Replacement required.
-            // We take the neuron with the lowest pruning score here.
-            subnetwork_uid = Self::get_neuron_to_prune(netuid);
+            // We take the uid the hotkey held before it was pruned if it is free, else the
+            // neuron with the lowest pruning score.
+            subnetwork_uid = Self::get_uid_to_replace(netuid, &hotkey);
 
             // --- 13.1.1 Replace the neuron account with the new info.
             Self::replace_neuron(netuid, subnetwork_uid, &hotkey, current_block_number);
@@ -149,8 +243,12 @@ impl<T: Config> Pallet<T> {
         // --- 14. Record the registration and increment block and interval counters.
         BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
-        RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+        Self::record_registration_in_block(netuid, RegistrationRoute::Burn);
         Self::increase_rao_recycled(netuid, Self::get_burn_as_u64(netuid));
+        RegistrationBurn::<T>::insert(netuid, &hotkey, (actual_burn_amount, current_block_number));
+        if let Some(role) = role {
+            DeclaredRoles::<T>::insert(netuid, &hotkey, role);
+        }
 
         // --- 15. Deposit successful event.
         log::info!(
@@ -159,7 +257,14 @@ impl<T: Config> Pallet<T> {
             subnetwork_uid,
             hotkey
         );
-        Self::deposit_event(Event::NeuronRegistered(netuid, subnetwork_uid, hotkey));
+        T::OnSubtensorEvent::on_neuron_registered(netuid, subnetwork_uid, &hotkey);
+        Self::deposit_subnet_event(
+            &[netuid],
+            Event::NeuronRegistered(netuid, subnetwork_uid, hotkey.clone()),
+        );
+        if let Some(role) = role {
+            Self::deposit_subnet_event(&[netuid], Event::NeuronRoleDeclared(netuid, hotkey, role));
+        }
 
         // --- 16. Ok and done.
         Ok(())
@@ -200,9 +305,15 @@ impl<T: Config> Pallet<T> {
     /// *'TooManyRegistrationsThisBlock':
     ///     - This registration exceeds the total allowed on this network this block.
     ///
+    /// *'TooManyPowRegistrationsThisBlock':
+    ///     - This registration exceeds the PoW registrations allowed on this network this block.
+    ///
     /// *'HotKeyAlreadyRegisteredInSubNet':
     ///     - The hotkey is already registered on this network.
     ///
+    /// *'NotEnoughStakeToRegister':
+    ///     - The hotkey holds less stake than the network requires to register.
+    ///
     /// *'InvalidWorkBlock':
     ///     - The work has been performed on a stale, future, or non existent block.
     ///
@@ -242,6 +353,7 @@ impl<T: Config> Pallet<T> {
             netuid != Self::get_root_netuid(),
             Error::<T>::RegistrationNotPermittedOnRootSubnet
         );
+        ensure!(!Self::is_hotkey_banned(&hotkey), Error::<T>::HotkeyIsBanned);
         ensure!(
             Self::if_subnet_exist(netuid),
             Error::<T>::SubNetworkDoesNotExist
@@ -254,11 +366,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
-        ensure!(
-            Self::get_registrations_this_block(netuid)
-                < Self::get_max_registrations_per_block(netuid),
-            Error::<T>::TooManyRegistrationsThisBlock
-        );
+        Self::ensure_registration_fits_in_block(netuid, RegistrationRoute::Pow, None)?;
 
         // --- 5. Ensure we are not exceeding the max allowed registrations per interval.
         ensure!(
@@ -273,6 +381,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
 
+        // --- 6. Ensure the hotkey already holds the stake the subnet requires.
+        Self::ensure_hotkey_has_stake_to_register(netuid, &hotkey)?;
+
         // --- 7. Ensure the passed block number is valid, not in the future or too old.
         // Work must have been done within 3 blocks (stops long range attacks).
         let current_block_number: u64 = Self::get_current_block_as_u64();
@@ -319,11 +430,11 @@ impl<T: Config> Pallet<T> {
 
         // Possibly there is no neuron slots at all.
         ensure!(
-            Self::get_max_allowed_uids(netuid) != 0,
+            Self::get_max_allowed_uids(netuid) != 0 && Self::get_max_miner_uids(netuid) != 0,
             Error::<T>::NoNeuronIdAvailable
         );
 
-        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
+        if Self::has_free_miner_uid(netuid) {
             // --- 11.1.1 No replacement required, the uid appends the subnetwork.
             // We increment the subnetwork count here but not below.
             subnetwork_uid = current_subnetwork_n;
@@ -333,8 +444,9 @@ impl<T: Config> Pallet<T> {
             log::info!("add new neuron account");
         } else {
             // --- 11.1.1 Replacement required.
-            // We take the neuron with the lowest pruning score here.
-            subnetwork_uid = Self::get_neuron_to_prune(netuid);
+            // We take the uid the hotkey held before it was pruned if it is free, else the
+            // neuron with the lowest pruning score.
+            subnetwork_uid = Self::get_uid_to_replace(netuid, &hotkey);
 
             // --- 11.1.1 Replace the neuron account with the new info.
             Self::replace_neuron(netuid, subnetwork_uid, &hotkey, current_block_number);
@@ -344,7 +456,8 @@ impl<T: Config> Pallet<T> {
         // --- 12. Record the registration and increment block and interval counters.
         POWRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
-        RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+        Self::record_registration_in_block(netuid, RegistrationRoute::Pow);
+        Self::record_pow_difficulty_solved(netuid, Self::get_difficulty_as_u64(netuid));
 
         // --- 13. Deposit successful event.
         log::info!(
@@ -353,12 +466,133 @@ impl<T: Config> Pallet<T> {
             subnetwork_uid,
             hotkey
         );
-        Self::deposit_event(Event::NeuronRegistered(netuid, subnetwork_uid, hotkey));
+        T::OnSubtensorEvent::on_neuron_registered(netuid, subnetwork_uid, &hotkey);
+        Self::deposit_subnet_event(
+            &[netuid],
+            Event::NeuronRegistered(netuid, subnetwork_uid, hotkey),
+        );
 
         // --- 14. Ok and done.
         Ok(())
     }
 
+    /// The weight of unregistering a neuron from `netuid`, which rewrites the weights and
+    /// bonds rows of every neuron of the subnet to point away from the freed uid.
+    pub fn unregister_weight(netuid: u16) -> Weight {
+        let rows: u64 = u64::from(Self::get_subnetwork_n(netuid)).saturating_mul(2);
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(rows.saturating_add(14)))
+            .saturating_add(T::DbWeight::get().writes(rows.saturating_add(36)))
+    }
+
+    /// ---- The implementation for the extrinsic unregister.
+    ///
+    /// Removes the neuron of `hotkey` from the subnet, moving the last uid into its slot, and
+    /// refunds UnregisterRefundShare of its registration burn to the coldkey when it
+    /// registered by burning within the last UnregisterRefundWindow blocks. The refund is
+    /// minted back, as the burn was taken out of the issuance.
+    ///
+    /// # Args:
+    /// *'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the coldkey owning the hotkey.
+    ///
+    /// *'netuid' (u16):
+    ///     - The subnet to leave.
+    ///
+    /// *'hotkey' (T::AccountId):
+    ///     - The hotkey of the neuron.
+    ///
+    /// # Event:
+    /// *NeuronUnregistered;
+    ///     - On unregistering the neuron.
+    ///
+    /// *NeuronUidMoved;
+    ///     - When the last uid of the subnet moves into the freed slot.
+    ///
+    /// # Raises:
+    /// *'RegistrationNotPermittedOnRootSubnet':
+    ///     - The subnet is the root network.
+    ///
+    /// *'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// *'NonAssociatedColdKey':
+    ///     - The caller does not own the hotkey.
+    ///
+    /// *'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey holds no uid on the subnet.
+    ///
+    /// *'StakeIsLocked':
+    ///     - Stake is locked on the hotkey.
+    ///
+    pub fn do_unregister(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller owns the hotkey.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)
+            .map_err(|_| Error::<T>::HotKeyNotRegisteredInSubNet)?;
+        ensure!(
+            !Self::hotkey_has_stake_locks(&hotkey),
+            Error::<T>::StakeIsLocked
+        );
+
+        // --- 2. Refund part of the burn within the refund window.
+        let current_block_number: u64 = Self::get_current_block_as_u64();
+        let refund: u64 = match RegistrationBurn::<T>::get(netuid, &hotkey) {
+            Some((burn, registered_at))
+                if current_block_number.saturating_sub(registered_at)
+                    < Self::get_unregister_refund_window() =>
+            {
+                let share: u64 = u64::from(Self::get_unregister_refund_share());
+                (u128::from(burn)
+                    .saturating_mul(u128::from(share))
+                    .checked_div(u128::from(u16::MAX))
+                    .unwrap_or(0)) as u64
+            }
+            _ => 0,
+        };
+        if refund > 0 {
+            Self::coinbase(refund);
+            Self::add_balance_to_coldkey_account(&coldkey, refund);
+        }
+
+        // --- 3. Free the uid.
+        let moved_uid = Self::remove_neuron(netuid, uid);
+
+        // --- 4. Deposit the events.
+        log::info!(
+            "NeuronUnregistered( netuid:{:?} uid:{:?} hotkey:{:?} refund:{:?} ) ",
+            netuid,
+            uid,
+            hotkey,
+            refund
+        );
+        Self::deposit_subnet_event(
+            &[netuid],
+            Event::NeuronUnregistered(netuid, uid, hotkey, refund),
+        );
+        if let Some(moved_uid) = moved_uid {
+            Self::deposit_subnet_event(&[netuid], Event::NeuronUidMoved(netuid, moved_uid, uid));
+        }
+
+        Ok(())
+    }
+
     pub fn do_faucet(
         origin: T::RuntimeOrigin,
         block_number: u64,
@@ -372,9 +606,18 @@ impl<T: Config> Pallet<T> {
         let coldkey = ensure_signed(origin)?;
         log::info!("do_faucet( coldkey:{:?} )", coldkey);
 
+        // --- 1.1 Ensure the coldkey waited FaucetRateLimit blocks since it last used the faucet.
+        let current_block_number: u64 = Self::get_current_block_as_u64();
+        if let Some(last_faucet_block) = LastFaucetBlock::<T>::get(&coldkey) {
+            ensure!(
+                current_block_number.saturating_sub(last_faucet_block)
+                    >= Self::get_faucet_rate_limit(),
+                Error::<T>::FaucetRateLimitExceeded
+            );
+        }
+
         // --- 2. Ensure the passed block number is valid, not in the future or too old.
         // Work must have been done within 3 blocks (stops long range attacks).
-        let current_block_number: u64 = Self::get_current_block_as_u64();
         ensure!(
             block_number <= current_block_number,
             Error::<T>::InvalidWorkBlock
@@ -402,6 +645,7 @@ impl<T: Config> Pallet<T> {
         Self::coinbase(100_000_000_000); // We are creating tokens here from the coinbase.
 
         Self::add_balance_to_coldkey_account(&coldkey, balance_to_add);
+        LastFaucetBlock::<T>::insert(&coldkey, current_block_number);
 
         // --- 6. Deposit successful event.
         log::info!(
@@ -426,6 +670,54 @@ impl<T: Config> Pallet<T> {
     /// immunity period. If all neurons are in immunity period, return node with lowest prunning score.
     /// This function will always return an element to prune.
     pub fn get_neuron_to_prune(netuid: u16) -> u16 {
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let Some(uid_to_prune) = Self::get_pruning_candidate(netuid, current_block) else {
+            return 0; // If there are no neurons in this network.
+        };
+
+        // We replace the pruning score here with u16 max to ensure that all peers always have a
+        // pruning score. In the event that every peer has been pruned this function will prune
+        // the last element in the network continually.
+        Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
+        uid_to_prune
+    }
+
+    /// Whether a registration on `netuid` takes a new uid rather than pruning one: the subnet
+    /// is below MaxAllowedUids and its uids without a validator permit below MaxMinerUids.
+    pub fn has_free_miner_uid(netuid: u16) -> bool {
+        Self::get_subnetwork_n(netuid) < Self::get_max_allowed_uids(netuid)
+            && Self::get_miner_count(netuid) < Self::get_max_miner_uids(netuid)
+    }
+
+    /// The role `netuid` is short of: validators while validator permits are unused, miners
+    /// while uids without a permit are below MaxMinerUids. None when it is short of both or
+    /// neither.
+    pub fn get_short_role(netuid: u16) -> Option<NeuronRole> {
+        let miners = Self::get_miner_count(netuid);
+        let validators = Self::get_subnetwork_n(netuid).saturating_sub(miners);
+        let short_of_validators = validators < Self::get_max_allowed_validators(netuid);
+        let short_of_miners = miners < Self::get_max_miner_uids(netuid);
+        match (short_of_validators, short_of_miners) {
+            (true, false) => Some(NeuronRole::Validator),
+            (false, true) => Some(NeuronRole::Miner),
+            _ => None,
+        }
+    }
+
+    /// Number of uids of `netuid` without a validator permit.
+    pub fn get_miner_count(netuid: u16) -> u16 {
+        let subnetwork_n = Self::get_subnetwork_n(netuid);
+        let validators = Self::get_validator_permit(netuid)
+            .into_iter()
+            .take(usize::from(subnetwork_n))
+            .filter(|permit| *permit)
+            .count();
+        subnetwork_n.saturating_sub(u16::try_from(validators).unwrap_or(u16::MAX))
+    }
+
+    /// Returns the uid `get_neuron_to_prune` would pick at `block_number` with the current
+    /// pruning scores, or None if the network has no neurons.
+    pub fn get_pruning_candidate(netuid: u16, block_number: u64) -> Option<u16> {
         let mut min_score: u16 = u16::MAX;
         let mut min_score_in_immunity_period = u16::MAX;
         let mut uid_with_min_score = 0;
@@ -433,18 +725,22 @@ impl<T: Config> Pallet<T> {
 
         let neurons_n = Self::get_subnetwork_n(netuid);
         if neurons_n == 0 {
-            return 0; // If there are no neurons in this network.
+            return None;
         }
 
-        let current_block: u64 = Self::get_current_block_as_u64();
+        // Below MaxAllowedUids only the miner cap is reached, so a miner gives up its uid.
+        let miners_only = neurons_n < Self::get_max_allowed_uids(netuid);
         let immunity_period: u64 = Self::get_immunity_period(netuid) as u64;
         for neuron_uid_i in 0..neurons_n {
+            if miners_only && Self::get_validator_permit_for_uid(netuid, neuron_uid_i) {
+                continue;
+            }
             let pruning_score: u16 = Self::get_pruning_score_for_uid(netuid, neuron_uid_i);
             let block_at_registration: u64 =
                 Self::get_neuron_block_at_registration(netuid, neuron_uid_i);
             #[allow(clippy::comparison_chain)]
             if min_score == pruning_score {
-                if current_block.saturating_sub(block_at_registration) < immunity_period {
+                if block_number.saturating_sub(block_at_registration) < immunity_period {
                     //neuron is in immunity period
                     if min_score_in_immunity_period > pruning_score {
                         min_score_in_immunity_period = pruning_score;
@@ -456,7 +752,7 @@ impl<T: Config> Pallet<T> {
             }
             // Find min pruning score.
             else if min_score > pruning_score {
-                if current_block.saturating_sub(block_at_registration) < immunity_period {
+                if block_number.saturating_sub(block_at_registration) < immunity_period {
                     //neuron is in immunity period
                     if min_score_in_immunity_period > pruning_score {
                         min_score_in_immunity_period = pruning_score;
@@ -470,18 +766,59 @@ impl<T: Config> Pallet<T> {
         }
         if min_score == u16::MAX {
             //all neuorns are in immunity period
-            Self::set_pruning_score_for_uid(
-                netuid,
-                uid_with_min_score_in_immunity_period,
-                u16::MAX,
-            );
-            uid_with_min_score_in_immunity_period
+            Some(uid_with_min_score_in_immunity_period)
         } else {
-            // We replace the pruning score here with u16 max to ensure that all peers always have a
-            // pruning score. In the event that every peer has been pruned this function will prune
-            // the last element in the network continually.
-            Self::set_pruning_score_for_uid(netuid, uid_with_min_score, u16::MAX);
-            uid_with_min_score
+            Some(uid_with_min_score)
+        }
+    }
+
+    /// Draws the uid the next registration on a subnet with REGISTRATION_LOTTERY replaces,
+    /// uniformly among the neurons `get_pruning_candidate` considers that are past their
+    /// immunity period. The draw is seeded by the hash of the block that closed the previous
+    /// adjustment interval and the registrations made since, neither of which a registrant
+    /// chooses. None when the lottery is off or every such neuron is immune.
+    pub fn draw_neuron_to_prune(netuid: u16) -> Option<u16> {
+        if !Self::get_registration_lottery(netuid) {
+            return None;
+        }
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let neurons_n = Self::get_subnetwork_n(netuid);
+        let miners_only = neurons_n < Self::get_max_allowed_uids(netuid);
+        let immunity_period: u64 = u64::from(Self::get_immunity_period(netuid));
+        let candidates: Vec<u16> = (0..neurons_n)
+            .filter(|uid| !(miners_only && Self::get_validator_permit_for_uid(netuid, *uid)))
+            .filter(|uid| {
+                current_block.saturating_sub(Self::get_neuron_block_at_registration(netuid, *uid))
+                    >= immunity_period
+            })
+            .collect();
+        let interval_hash: H256 =
+            Self::get_block_hash_from_u64(Self::get_last_adjustment_block(netuid));
+        let seed: [u8; 32] = blake2_256(
+            &(
+                interval_hash,
+                netuid,
+                Self::get_registrations_this_interval(netuid),
+            )
+                .encode(),
+        );
+        let index = H256::from(seed)
+            .to_low_u64_le()
+            .checked_rem(u64::try_from(candidates.len()).ok()?)?;
+        candidates.get(usize::try_from(index).ok()?).copied()
+    }
+
+    /// Warns the uid that becomes the pruning candidate of a full network within a tempo of
+    /// `block_number`, as its immunity runs out or its pruning score is the lowest, so that its
+    /// operator can act before a registration takes the slot.
+    pub fn warn_imminent_pruning(netuid: u16, block_number: u64) {
+        if Self::has_free_miner_uid(netuid) {
+            return; // Registrations only prune once the network or its miner slots are full.
+        }
+        let tempo = u64::from(Self::get_tempo(netuid));
+        if let Some(uid) = Self::get_pruning_candidate(netuid, block_number.saturating_add(tempo)) {
+            log::debug!("PruningImminent( netuid:{:?}, uid:{:?} )", netuid, uid);
+            Self::deposit_subnet_event(&[netuid], Event::PruningImminent(netuid, uid));
         }
     }
 
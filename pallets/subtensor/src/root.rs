@@ -17,8 +17,9 @@
 
 use super::*;
 use crate::math::*;
+use crate::registration::RegistrationRoute;
 use frame_support::dispatch::Pays;
-use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use sp_runtime::Saturating;
@@ -37,7 +38,7 @@ impl<T: Config> Pallet<T> {
     /// * 'u16': The UID for the root network.
     ///
     pub fn get_root_netuid() -> u16 {
-        0
+        NetUid::ROOT.inner()
     }
 
     /// Fetches the total count of subnets.
@@ -71,6 +72,42 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::SubnetLimitSet(limit));
     }
 
+    /// Announces a new max number of subnet
+    ///
+    /// The limit is applied by `block_step` once `SubnetLimitChangeDelay` blocks have passed,
+    /// so registrants get notice before a decrease makes their subnet prunable.
+    ///
+    /// # Returns:
+    /// * 'u64': The block at which the limit is applied
+    ///
+    pub fn schedule_max_subnets(limit: u16) -> u64 {
+        let delay = SubnetLimitChangeDelay::<T>::get();
+        let apply_block = Self::get_current_block_as_u64().saturating_add(delay);
+        if delay == 0 {
+            Self::set_max_subnets(limit);
+            return apply_block;
+        }
+
+        ScheduledSubnetLimits::<T>::insert(apply_block, limit);
+        Self::deposit_event(Event::SubnetLimitChangeScheduled(limit, apply_block));
+        apply_block
+    }
+
+    /// Applies the subnet limit announced for this block, if any.
+    pub fn apply_scheduled_subnet_limit(block_number: u64) {
+        if let Some(limit) = ScheduledSubnetLimits::<T>::take(block_number) {
+            Self::set_max_subnets(limit);
+        }
+    }
+
+    pub fn get_subnet_limit_change_delay() -> u64 {
+        SubnetLimitChangeDelay::<T>::get()
+    }
+
+    pub fn set_subnet_limit_change_delay(delay: u64) {
+        SubnetLimitChangeDelay::<T>::put(delay);
+    }
+
     /// Fetches the total count of root network validators
     ///
     /// This function retrieves the total number of root network validators.
@@ -90,7 +127,17 @@ impl<T: Config> Pallet<T> {
     /// * 'u16': The max validators count of root network.
     ///
     pub fn get_max_root_validators() -> u16 {
-        Self::get_max_allowed_uids(Self::get_root_netuid())
+        RootMaxUids::<T>::get()
+    }
+
+    /// Sets the max validators count of root network.
+    ///
+    /// This is kept apart from `MaxAllowedUids` so that subnet uid settings never resize the root
+    /// voter set.
+    ///
+    pub fn set_max_root_validators(max_validators: u16) {
+        RootMaxUids::<T>::put(max_validators);
+        Self::deposit_event(Event::RootMaxUidsSet(max_validators));
     }
 
     /// Returns the emission value for the given subnet.
@@ -115,18 +162,37 @@ impl<T: Config> Pallet<T> {
         NetworksAdded::<T>::get(netuid)
     }
 
+    /// Marks `netuid` as an existing network in NetworksAdded and SubnetNetuids.
+    pub fn add_subnet_netuid(netuid: u16) {
+        NetworksAdded::<T>::insert(netuid, true);
+        SubnetNetuids::<T>::mutate(|netuids| {
+            if let Err(index) = netuids.binary_search(&netuid) {
+                netuids.insert(index, netuid);
+            }
+        });
+    }
+
+    /// Removes `netuid` from NetworksAdded and SubnetNetuids.
+    pub fn remove_subnet_netuid(netuid: u16) {
+        NetworksAdded::<T>::remove(netuid);
+        SubnetNetuids::<T>::mutate(|netuids| {
+            if let Ok(index) = netuids.binary_search(&netuid) {
+                netuids.remove(index);
+            }
+        });
+    }
+
     /// Returns a list of subnet netuid equal to total networks.
     ///
     ///
-    /// This iterates through all the networks and returns a list of netuids.
+    /// The netuids are kept sorted in SubnetNetuids as networks are added and removed, so
+    /// this is a single storage read rather than an iteration over NetworksAdded.
     ///
     /// # Returns:
-    /// * 'Vec<u16>': Netuids of all subnets.
+    /// * 'Vec<u16>': Netuids of all subnets, in ascending order.
     ///
     pub fn get_all_subnet_netuids() -> Vec<u16> {
-        <NetworksAdded<T> as IterableStorageMap<u16, bool>>::iter()
-            .map(|(netuid, _)| netuid)
-            .collect()
+        SubnetNetuids::<T>::get()
     }
 
     /// Calculates the block emission based on the total issuance.
@@ -154,17 +220,15 @@ impl<T: Config> Pallet<T> {
         if total_issuance >= I96F32::from_num(TotalSupply::<T>::get()) {
             return Ok(0);
         }
-        // Calculate the logarithmic residual of the issuance against half the total supply.
+        // Calculate the logarithmic residual of the issuance against the total supply. Emission
+        // halves when half the supply is issued, again at three quarters, and so on.
         let residual: I96F32 = log2(
             I96F32::from_num(1.0)
                 .checked_div(
                     I96F32::from_num(1.0)
                         .checked_sub(
                             total_issuance
-                                .checked_div(
-                                    I96F32::from_num(2.0)
-                                        .saturating_mul(I96F32::from_num(10_500_000_000_000_000.0)),
-                                )
+                                .checked_div(I96F32::from_num(TotalSupply::<T>::get()))
                                 .ok_or("Logarithm calculation failed")?,
                         )
                         .ok_or("Logarithm calculation failed")?,
@@ -199,14 +263,14 @@ impl<T: Config> Pallet<T> {
     /// It's important to check for invalid UIDs to ensure data integrity and avoid referencing nonexistent subnets.
     ///
     /// # Arguments:
-    /// * 'uids': A reference to a vector of UIDs to check.
+    /// * 'netuids': The subnets a root validator sets weights on.
     ///
     /// # Returns:
     /// * 'bool': 'true' if any of the UIDs are invalid, 'false' otherwise.
     ///
-    pub fn contains_invalid_root_uids(netuids: &[u16]) -> bool {
+    pub fn contains_invalid_root_uids(netuids: &[NetUid]) -> bool {
         for netuid in netuids {
-            if !Self::if_subnet_exist(*netuid) {
+            if Self::checked_netuid(netuid.inner()).is_err() {
                 log::debug!(
                     "contains_invalid_root_uids: netuid {:?} does not exist",
                     netuid
@@ -227,7 +291,8 @@ impl<T: Config> Pallet<T> {
         );
 
         // Be careful this function can fail.
-        if Self::contains_invalid_root_uids(netuids) {
+        let checked_netuids: Vec<NetUid> = netuids.iter().copied().map(NetUid::from).collect();
+        if Self::contains_invalid_root_uids(&checked_netuids) {
             log::error!("set_emission_values: contains_invalid_root_uids");
             return Err("Invalid netuids");
         }
@@ -267,10 +332,11 @@ impl<T: Config> Pallet<T> {
 
         // --- 3. Iterate over stored weights and fill the matrix.
         for (uid_i, weights_i) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(
                 Self::get_root_netuid(),
             )
         {
+            let weights_i = weights_i.entries();
             // --- 4. Iterate over each weight entry in `weights_i` to update the corresponding value in the
             // initialized `weights` 2D vector. Here, `uid_j` represents a subnet, and `weight_ij` is the
             // weight of `uid_i` with respect to `uid_j`.
@@ -292,6 +358,26 @@ impl<T: Config> Pallet<T> {
         weights
     }
 
+    /// Retrieves the weight matrix of the root network as sparse rows, one per root validator,
+    /// holding only the subnets the validator set weights for.
+    ///
+    /// # Returns:
+    /// A vector of rows ('Vec<Vec<(u16, I64F64)>>') where each entry (j, w) of row i is the weight
+    /// validator 'i' sets for the subnet at index 'j' of `get_all_subnet_netuids`.
+    ///
+    pub fn get_root_weights_sparse() -> Vec<Vec<(u16, I64F64)>> {
+        let n: usize = Self::get_num_root_validators() as usize;
+        let subnet_list = Self::get_all_subnet_netuids();
+        (0..n)
+            .map(|uid| {
+                Self::get_root_weights_row_sparse(
+                    u16::try_from(uid).unwrap_or(u16::MAX),
+                    &subnet_list,
+                )
+            })
+            .collect()
+    }
+
     /// Sets the network rate limit and emit the `NetworkRateLimitSet` event
     ///
     pub fn set_network_rate_limit(limit: u64) {
@@ -320,22 +406,108 @@ impl<T: Config> Pallet<T> {
     /// Computes and sets emission values for the root network which determine the emission for all subnets.
     ///
     /// This function is responsible for calculating emission based on network weights, stake values,
-    /// and registered hotkeys.
+    /// and registered hotkeys. The computation is bounded by RootEpochBlockBudget per block, an
+    /// epoch which exceeds it continues in the following blocks before setting any emission value.
     ///
     pub fn root_epoch(block_number: u64) -> Result<(), &'static str> {
         // --- 0. The unique ID associated with the root network.
         let root_netuid: u16 = Self::get_root_netuid();
 
-        // --- 1. Check if we should update the emission values based on blocks since emission was last set.
-        let blocks_until_next_epoch: u64 =
-            Self::blocks_until_next_epoch(root_netuid, Self::get_tempo(root_netuid), block_number);
-        if blocks_until_next_epoch != 0 {
-            // Not the block to update emission values.
-            log::debug!("blocks_until_next_epoch: {:?}", blocks_until_next_epoch);
-            return Err("");
+        // --- 1. Continue the epoch left by the previous block, or check if we should start one
+        // based on blocks since emission was last set.
+        let mut progress = match RootEpochInProgress::<T>::take() {
+            Some(progress) => progress,
+            None => {
+                let blocks_until_next_epoch: u64 = Self::blocks_until_next_epoch(
+                    root_netuid,
+                    Self::get_tempo(root_netuid),
+                    block_number,
+                );
+                if blocks_until_next_epoch != 0 {
+                    // Not the block to update emission values.
+                    log::debug!("blocks_until_next_epoch: {:?}", blocks_until_next_epoch);
+                    return Err("");
+                }
+                Self::start_root_epoch()?
+            }
+        };
+
+        // --- 2. Collects stakes and accumulates the root weights within the block budget.
+        if !Self::advance_root_epoch(&mut progress) {
+            RootEpochInProgress::<T>::put(progress);
+            return Err("Root epoch continues in the next block.");
         }
 
-        // --- 2. Retrieves the number of root validators on subnets.
+        // --- 3. Computes the emission values from the accumulated ranks. Subnets removed during
+        // the epoch were dropped from it, subnets added during it wait for the next one.
+        let netuids: Vec<u16> = progress.netuids;
+        let emission_u64: Vec<u64> = Self::root_emission_from_ranks(
+            progress.ranks.into_iter().map(I64F64::from_bits).collect(),
+            progress.trust.into_iter().map(I64F64::from_bits).collect(),
+            I64F64::from_bits(progress.total_stake),
+            I64F64::from_num(progress.block_emission),
+        )?;
+
+        // --- 4. Set the emission values for each subnet directly.
+        log::debug!("netuids: {:?} values: {:?}", netuids, emission_u64);
+
+        Self::set_emission_values(&netuids, emission_u64)
+    }
+
+    /// The weight of the root epoch work in `block_number`, as benchmarked for the root
+    /// validators and the subnets they weigh when an epoch is running or due, else the reads
+    /// checking whether one is.
+    pub fn root_epoch_weight(block_number: u64) -> Weight {
+        let root_netuid: u16 = Self::get_root_netuid();
+        let check = T::DbWeight::get().reads(2);
+        let subnets: usize = match RootEpochInProgress::<T>::get() {
+            Some(progress) => progress.netuids.len(),
+            None if Self::blocks_until_next_epoch(
+                root_netuid,
+                Self::get_tempo(root_netuid),
+                block_number,
+            ) == 0 =>
+            {
+                Self::get_all_subnet_netuids().len()
+            }
+            None => return check,
+        };
+        check.saturating_add(T::WeightInfo::benchmark_root_epoch(
+            u32::from(Self::get_subnetwork_n(root_netuid)),
+            u32::try_from(subnets).unwrap_or(u32::MAX),
+        ))
+    }
+
+    /// Normalizes the stakes of the root validators, capping the share of each at RootStakeCap
+    /// so a single validator cannot rank subnets with more of the stake.
+    pub fn normalize_root_stake(stake: &mut [I64F64]) {
+        inplace_normalize_64(stake);
+        let cap: I64F64 =
+            I64F64::from_num(Self::get_root_stake_cap()).saturating_div(I64F64::from_num(u16::MAX));
+        inplace_cap_normalized_64(stake, cap);
+    }
+
+    pub fn get_root_stake_cap() -> u16 {
+        RootStakeCap::<T>::get()
+    }
+    pub fn set_root_stake_cap(cap: u16) {
+        RootStakeCap::<T>::put(cap);
+        Self::deposit_event(Event::RootStakeCapSet(cap));
+    }
+
+    /// Computes the emission of every subnet from a sparse root weight matrix, as `root_epoch`
+    /// does with the stored root weights.
+    ///
+    /// # Returns:
+    /// The emission per block of each subnet, ordered as `get_all_subnet_netuids`.
+    ///
+    pub fn compute_root_emission_values(
+        weights: Vec<Vec<(u16, I64F64)>>,
+    ) -> Result<Vec<u64>, &'static str> {
+        // --- 0. The unique ID associated with the root network.
+        let root_netuid: u16 = Self::get_root_netuid();
+
+        // --- 1. Retrieves the number of root validators on subnets.
         let n: u16 = Self::get_num_root_validators();
         log::debug!("n:\n{:?}\n", n);
         if n == 0 {
@@ -343,7 +515,7 @@ impl<T: Config> Pallet<T> {
             return Err("No validators to validate emission values.");
         }
 
-        // --- 3. Obtains the number of registered subnets.
+        // --- 2. Obtains the number of registered subnets.
         let k: u16 = Self::get_all_subnet_netuids().len() as u16;
         log::debug!("k:\n{:?}\n", k);
         if k == 0 {
@@ -351,58 +523,58 @@ impl<T: Config> Pallet<T> {
             return Err("No networks to validate emission values.");
         }
 
-        // --- 4. Determines the total block emission across all the subnetworks. This is the
+        // --- 3. Determines the total block emission across all the subnetworks. This is the
         // value which will be distributed based on the computation below.
         let block_emission: I64F64 = I64F64::from_num(Self::get_block_emission()?);
         log::debug!("block_emission:\n{:?}\n", block_emission);
 
-        // --- 5. A collection of all registered hotkeys on the root network. Hotkeys
+        // --- 4. A collection of all registered hotkeys on the root network. Hotkeys
         // pairs with network UIDs and stake values.
-        let mut hotkeys: Vec<(u16, T::AccountId)> = vec![];
-        for (uid_i, hotkey) in
-            <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(root_netuid)
-        {
-            hotkeys.push((uid_i, hotkey));
-        }
+        let hotkeys: Vec<(u16, T::AccountId)> = Self::get_uid_hotkey_pairs(root_netuid);
         log::debug!("hotkeys:\n{:?}\n", hotkeys);
 
-        // --- 6. Retrieves and stores the stake value associated with each hotkey on the root network.
+        // --- 5. Retrieves and stores the stake value associated with each hotkey on the root network.
         // Stakes are stored in a 64-bit fixed point representation for precise calculations.
         let mut stake_i64: Vec<I64F64> = vec![I64F64::from_num(0.0); n as usize];
         for ((_, hotkey), stake) in hotkeys.iter().zip(&mut stake_i64) {
-            *stake = I64F64::from_num(Self::get_total_stake_for_hotkey(hotkey));
+            *stake = I64F64::from_num(Self::get_stake_for_hotkey_on_subnet(hotkey, root_netuid));
         }
-        inplace_normalize_64(&mut stake_i64);
+        Self::normalize_root_stake(&mut stake_i64);
         log::debug!("S:\n{:?}\n", &stake_i64);
 
-        // --- 7. Retrieves the network weights in a 2D Vector format. Weights have shape
-        // n x k where is n is the number of registered peers and k is the number of subnets.
-        let mut weights: Vec<Vec<I64F64>> = Self::get_root_weights();
-        log::debug!("W:\n{:?}\n", &weights);
-
-        // Normalize weights.
-        inplace_row_normalize_64(&mut weights);
-        log::debug!("W(norm):\n{:?}\n", &weights);
-
-        // --- 8. Calculates the rank of networks. Rank is a product of weights and stakes.
-        // Ranks will have shape k, a score for each subnet.
-        let ranks: Vec<I64F64> = matmul_64(&weights, &stake_i64);
-        log::debug!("R:\n{:?}\n", &ranks);
-
-        // --- 9. Calculates the trust of networks. Trust is a sum of all stake with weights > 0.
-        // Trust will have shape k, a score for each subnet.
-        let total_networks = Self::get_num_subnets();
-        let mut trust = vec![I64F64::from_num(0); total_networks as usize];
+        // --- 6. Accumulates the rank and trust of the networks row by row over the sparse
+        // network weights, without building the n x k matrix where n is the number of
+        // registered peers and k is the number of subnets. Rank is the sum of the normalized
+        // weights scaled by stake, trust the sum of the stake with weights > 0. Both have shape
+        // k, a score for each subnet.
+        let mut ranks = vec![I64F64::from_num(0); k as usize];
+        let mut trust = vec![I64F64::from_num(0); k as usize];
         let mut total_stake: I64F64 = I64F64::from_num(0);
-        for (weights, hotkey_stake) in weights.iter().zip(stake_i64) {
+        let sum_normalized = Self::weights_sum_normalized(root_netuid);
+        for (row, hotkey_stake) in weights.into_iter().zip(stake_i64) {
             total_stake = total_stake.saturating_add(hotkey_stake);
-            for (weight, trust_score) in weights.iter().zip(&mut trust) {
-                if *weight > 0 {
-                    *trust_score = trust_score.saturating_add(hotkey_stake);
-                }
-            }
+            Self::accumulate_root_weights_row(
+                row,
+                sum_normalized,
+                hotkey_stake,
+                &mut ranks,
+                &mut trust,
+            );
         }
+        log::debug!("R:\n{:?}\n", &ranks);
 
+        Self::root_emission_from_ranks(ranks, trust, total_stake, block_emission)
+    }
+
+    /// Turns the ranks and trust of the subnets, summed over the root validators with their
+    /// normalized stake, into the emission per block of each subnet.
+    pub fn root_emission_from_ranks(
+        ranks: Vec<I64F64>,
+        mut trust: Vec<I64F64>,
+        total_stake: I64F64,
+        block_emission: I64F64,
+    ) -> Result<Vec<u64>, &'static str> {
+        let total_networks = trust.len();
         log::debug!("T_before normalization:\n{:?}\n", &trust);
         log::debug!("Total_stake:\n{:?}\n", &total_stake);
 
@@ -416,11 +588,11 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        // --- 10. Calculates the consensus of networks. Consensus is a sigmoid normalization of the trust scores.
+        // --- 9. Calculates the consensus of networks. Consensus is a sigmoid normalization of the trust scores.
         // Consensus will have shape k, a score for each subnet.
         log::debug!("T:\n{:?}\n", &trust);
         let one = I64F64::from_num(1);
-        let mut consensus = vec![I64F64::from_num(0); total_networks as usize];
+        let mut consensus = vec![I64F64::from_num(0); total_networks];
         for (trust_score, consensus_i) in trust.iter_mut().zip(&mut consensus) {
             let shifted_trust =
                 trust_score.saturating_sub(I64F64::from_num(Self::get_float_kappa(0))); // Range( -kappa, 1 - kappa )
@@ -434,7 +606,7 @@ impl<T: Config> Pallet<T> {
         }
 
         log::debug!("C:\n{:?}\n", &consensus);
-        let mut weighted_emission = vec![I64F64::from_num(0); total_networks as usize];
+        let mut weighted_emission = vec![I64F64::from_num(0); total_networks];
         for ((emission, consensus_i), rank) in
             weighted_emission.iter_mut().zip(&consensus).zip(&ranks)
         {
@@ -443,21 +615,17 @@ impl<T: Config> Pallet<T> {
         inplace_normalize_64(&mut weighted_emission);
         log::debug!("Ei64:\n{:?}\n", &weighted_emission);
 
-        // -- 11. Converts the normalized 64-bit fixed point rank values to u64 for the final emission calculation.
+        // -- 10. Converts the normalized 64-bit fixed point rank values to u64 for the final emission calculation.
         let emission_as_tao: Vec<I64F64> = weighted_emission
             .iter()
             .map(|v: &I64F64| v.saturating_mul(block_emission))
             .collect();
 
-        // --- 12. Converts the normalized 64-bit fixed point rank values to u64 for the final emission calculation.
+        // --- 11. Converts the normalized 64-bit fixed point rank values to u64 for the final emission calculation.
         let emission_u64: Vec<u64> = vec_fixed64_to_u64(emission_as_tao);
         log::debug!("Eu64:\n{:?}\n", &emission_u64);
 
-        // --- 13. Set the emission values for each subnet directly.
-        let netuids: Vec<u16> = Self::get_all_subnet_netuids();
-        log::debug!("netuids: {:?} values: {:?}", netuids, emission_u64);
-
-        Self::set_emission_values(&netuids, emission_u64)
+        Ok(emission_u64)
     }
 
     /// Registers a user's hotkey to the root network.
@@ -492,6 +660,7 @@ impl<T: Config> Pallet<T> {
             coldkey,
             hotkey
         );
+        ensure!(!Self::is_hotkey_banned(&hotkey), Error::<T>::HotkeyIsBanned);
 
         // --- 2. Ensure that the number of registrations in this block doesn't exceed the allowed limit.
         ensure!(
@@ -516,71 +685,187 @@ impl<T: Config> Pallet<T> {
         // --- 6. Create a network account for the user if it doesn't exist.
         Self::create_account_if_non_existent(&coldkey, &hotkey);
 
-        // --- 7. Fetch the current size of the subnetwork.
-        let current_num_root_validators: u16 = Self::get_num_root_validators();
+        // --- 7. Append the hotkey if the root network is below its allowed size, else replace
+        // the neuron with the lowest stake, the oldest registration then the lowest uid on ties,
+        // provided the new account has a higher stake.
+        let replaced_uid: Option<u16> = match Self::get_root_uid_to_replace() {
+            Some((lowest_uid, lowest_stake)) => {
+                ensure!(
+                    lowest_stake < Self::get_total_stake_for_hotkey(&hotkey),
+                    Error::<T>::StakeTooLowForRoot
+                );
+                Some(lowest_uid)
+            }
+            None => None,
+        };
+        let subnetwork_uid: u16 =
+            Self::add_root_validator(&hotkey, replaced_uid, current_block_number)?;
 
-        // Declare a variable to hold the root UID.
-        let subnetwork_uid: u16;
+        // --- 8. Join the Senate, delegate and announce the registration.
+        Self::complete_root_registration(subnetwork_uid, hotkey)
+    }
 
-        // --- 8. Check if the root net is below its allowed size.
-        // max allowed is senate size.
-        if current_num_root_validators < Self::get_max_root_validators() {
-            // --- 12.1.1 We can append to the subnetwork as it's not full.
-            subnetwork_uid = current_num_root_validators;
+    /// Registers a hotkey to the root network by burning the Burn of the root network, for
+    /// keys with liquid TAO but too little stake to replace a root validator.
+    ///
+    /// The burn adapts every AdjustmentInterval of the root network to its registrations like
+    /// the burn of a subnet, and the burned amount is recycled into the emission. When the
+    /// root network is full, the validator with the lowest stake is replaced as for
+    /// `do_root_register` once it is past the ImmunityPeriod of the root network, whatever
+    /// the stake of the new hotkey.
+    ///
+    /// # Arguments:
+    /// * 'origin': Represents the origin of the call, the paying coldkey.
+    /// * 'hotkey': A new hotkey, or one owned by the coldkey.
+    ///
+    /// # Returns:
+    /// * 'DispatchResult': A result type indicating success or failure of the registration.
+    ///
+    pub fn do_burned_root_register(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+    ) -> DispatchResult {
+        // --- 0. Get the unique identifier (UID) for the root network.
+        let root_netuid: u16 = Self::get_root_netuid();
+        let current_block_number: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            Self::if_subnet_exist(root_netuid),
+            Error::<T>::RootNetworkDoesNotExist
+        );
 
-            // --- 12.1.2 Add the new account and make them a member of the Senate.
-            Self::append_neuron(root_netuid, &hotkey, current_block_number);
-            log::info!("add new neuron: {:?} on uid {:?}", hotkey, subnetwork_uid);
-        } else {
-            // --- 13.1.1 The network is full. Perform replacement.
-            // Find the neuron with the lowest stake value to replace.
-            let mut lowest_stake: u64 = u64::MAX;
-            let mut lowest_uid: u16 = 0;
-
-            // Iterate over all keys in the root network to find the neuron with the lowest stake.
-            for (uid_i, hotkey_i) in
-                <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(
-                    root_netuid,
-                )
-            {
-                let stake_i: u64 = Self::get_total_stake_for_hotkey(&hotkey_i);
-                if stake_i < lowest_stake {
-                    lowest_stake = stake_i;
-                    lowest_uid = uid_i;
-                }
+        // --- 1. Ensure that the call originates from a signed source and retrieve the caller's account ID (coldkey).
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            !Self::coldkey_in_arbitration(&coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+        log::info!(
+            "do_burned_root_register( coldkey: {:?}, hotkey: {:?} )",
+            coldkey,
+            hotkey
+        );
+        ensure!(!Self::is_hotkey_banned(&hotkey), Error::<T>::HotkeyIsBanned);
+
+        // --- 2. Ensure the registration fits in this block and interval.
+        Self::ensure_registration_fits_in_block(root_netuid, RegistrationRoute::Burn, None)?;
+        ensure!(
+            Self::get_registrations_this_interval(root_netuid)
+                < Self::get_target_registrations_per_interval(root_netuid).saturating_mul(3),
+            Error::<T>::TooManyRegistrationsThisInterval
+        );
+
+        // --- 3. Check the hotkey is not already registered and belongs to the caller.
+        ensure!(
+            !Uids::<T>::contains_key(root_netuid, &hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        ensure!(
+            !Self::hotkey_account_exists(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // --- 4. If the root network is full, the validator to replace must be past immunity.
+        let replaced_uid: Option<u16> = match Self::get_root_uid_to_replace() {
+            Some((lowest_uid, _)) => {
+                let registered_at = Self::get_neuron_block_at_registration(root_netuid, lowest_uid);
+                ensure!(
+                    current_block_number.saturating_sub(registered_at)
+                        >= u64::from(Self::get_immunity_period(root_netuid)),
+                    Error::<T>::RootValidatorInImmunity
+                );
+                Some(lowest_uid)
             }
-            subnetwork_uid = lowest_uid;
-            let replaced_hotkey: T::AccountId =
-                Self::get_hotkey_for_net_and_uid(root_netuid, subnetwork_uid)?;
+            None => None,
+        };
 
-            // --- 13.1.2 The new account has a higher stake than the one being replaced.
-            ensure!(
-                lowest_stake < Self::get_total_stake_for_hotkey(&hotkey),
-                Error::<T>::StakeTooLowForRoot
-            );
+        // --- 5. Burn the registration cost and recycle it into the emission.
+        let registration_cost = Self::get_burn_as_u64(root_netuid);
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, registration_cost),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+        let actual_burn_amount =
+            Self::remove_balance_from_coldkey_account(&coldkey, registration_cost)?;
+        Self::burn_tokens(actual_burn_amount);
+        Self::increase_rao_recycled(root_netuid, actual_burn_amount);
 
-            // --- 13.1.3 The new account has a higher stake than the one being replaced.
-            // Replace the neuron account with new information.
-            Self::replace_neuron(root_netuid, lowest_uid, &hotkey, current_block_number);
+        // --- 6. Create the hotkey account and add it to the root network.
+        Self::create_account_if_non_existent(&coldkey, &hotkey);
+        let subnetwork_uid: u16 =
+            Self::add_root_validator(&hotkey, replaced_uid, current_block_number)?;
 
-            log::info!(
-                "replace neuron: {:?} with {:?} on uid {:?}",
-                replaced_hotkey,
-                hotkey,
-                subnetwork_uid
-            );
+        // --- 7. Count the registration against the burn sub-limits.
+        BurnRegistrationsThisInterval::<T>::mutate(root_netuid, |val| val.saturating_inc());
+        BurnRegistrationsThisBlock::<T>::mutate(root_netuid, |val| val.saturating_inc());
+
+        // --- 8. Join the Senate, delegate and announce the registration.
+        Self::complete_root_registration(subnetwork_uid, hotkey)
+    }
+
+    /// The uid and stake of the root validator a registration replaces, None while the root
+    /// network is below its allowed size. The validator with the lowest stake is chosen, the
+    /// oldest registration then the lowest uid on ties.
+    fn get_root_uid_to_replace() -> Option<(u16, u64)> {
+        if Self::get_num_root_validators() < Self::get_max_root_validators() {
+            return None;
         }
+        Some(Self::get_lowest_stake_uid(Self::get_root_netuid()).unwrap_or((0, u64::MAX)))
+    }
 
-        // --- 13. Join the Senate if eligible.
-        // Returns the replaced member, if any.
-        let _ = Self::join_senate_if_eligible(&hotkey)?;
+    /// Appends `hotkey` to the root network, or puts it in place of `replaced_uid`, returning
+    /// its uid.
+    fn add_root_validator(
+        hotkey: &T::AccountId,
+        replaced_uid: Option<u16>,
+        current_block_number: u64,
+    ) -> Result<u16, DispatchError> {
+        let root_netuid: u16 = Self::get_root_netuid();
+        match replaced_uid {
+            None => {
+                // The root network is not full, the hotkey takes the next uid.
+                let subnetwork_uid: u16 = Self::get_num_root_validators();
+                Self::append_neuron(root_netuid, hotkey, current_block_number);
+                log::info!("add new neuron: {:?} on uid {:?}", hotkey, subnetwork_uid);
+                Ok(subnetwork_uid)
+            }
+            Some(subnetwork_uid) => {
+                let replaced_hotkey: T::AccountId =
+                    Self::get_hotkey_for_net_and_uid(root_netuid, subnetwork_uid)?;
+                Self::replace_neuron(root_netuid, subnetwork_uid, hotkey, current_block_number);
+                log::info!(
+                    "replace neuron: {:?} with {:?} on uid {:?}",
+                    replaced_hotkey,
+                    hotkey,
+                    subnetwork_uid
+                );
+                Ok(subnetwork_uid)
+            }
+        }
+    }
+
+    /// Finishes the registration of `hotkey` to the root network at `subnetwork_uid`: joins
+    /// the Senate if eligible, makes the hotkey a delegate, counts the registration and
+    /// announces it.
+    fn complete_root_registration(subnetwork_uid: u16, hotkey: T::AccountId) -> DispatchResult {
+        let root_netuid: u16 = Self::get_root_netuid();
 
-        // --- 14. Force all members on root to become a delegate.
+        // --- 1. Join the Senate if eligible, unless the hotkey already holds a seat.
+        if !T::SenateMembers::is_member(&hotkey) {
+            if let Some(replaced) = Self::join_senate_if_eligible(&hotkey)? {
+                log::info!(
+                    "SenateAdjusted(old_hotkey:{:?} hotkey:{:?})",
+                    replaced,
+                    hotkey
+                );
+            }
+        }
+
+        // --- 2. Force all members on root to become a delegate.
         if !Self::hotkey_is_delegate(&hotkey) {
             Self::delegate_hotkey(&hotkey, 11_796); // 18% cut defaulted.
         }
 
-        // --- 15. Update the registration counters for both the block and interval.
+        // --- 3. Update the registration counters for both the block and interval.
         #[allow(clippy::arithmetic_side_effects)]
         // note this RA + clippy false positive is a known substrate issue
         RegistrationsThisInterval::<T>::mutate(root_netuid, |val| *val += 1);
@@ -588,19 +873,61 @@ impl<T: Config> Pallet<T> {
         // note this RA + clippy false positive is a known substrate issue
         RegistrationsThisBlock::<T>::mutate(root_netuid, |val| *val += 1);
 
-        // --- 16. Log and announce the successful registration.
+        // --- 4. Log and announce the successful registration.
         log::info!(
             "RootRegistered(netuid:{:?} uid:{:?} hotkey:{:?})",
             root_netuid,
             subnetwork_uid,
             hotkey
         );
-        Self::deposit_event(Event::NeuronRegistered(root_netuid, subnetwork_uid, hotkey));
+        T::OnSubtensorEvent::on_neuron_registered(root_netuid, subnetwork_uid, &hotkey);
+        Self::deposit_subnet_event(
+            &[root_netuid],
+            Event::NeuronRegistered(root_netuid, subnetwork_uid, hotkey),
+        );
 
-        // --- 17. Finish and return success.
+        // --- 5. Finish and return success.
         Ok(())
     }
 
+    /// Stakes to a hotkey and registers it to the root network in one step.
+    ///
+    /// The new stake counts towards the root registration, so a full root network can be
+    /// entered without a separate staking transaction. Both steps are applied or neither is:
+    /// the hotkey never ends up registered without its stake, or staked without being
+    /// registered.
+    ///
+    /// # Arguments:
+    /// * 'origin': Represents the origin of the call, the staking coldkey.
+    /// * 'hotkey': A new hotkey, or one owned by the coldkey.
+    /// * 'amount': The stake to add, in RAO.
+    ///
+    /// # Returns:
+    /// * 'DispatchResult': A result type indicating success or failure of the registration.
+    ///
+    pub fn do_root_register_and_stake(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        amount: u64,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin.clone())?;
+        ensure!(amount > 0, Error::<T>::StakeToAddIsZero);
+        ensure!(
+            !Self::hotkey_account_exists(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            !Uids::<T>::contains_key(Self::get_root_netuid(), &hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+
+        frame_support::storage::with_storage_layer(|| {
+            Self::create_account_if_non_existent(&coldkey, &hotkey);
+            Self::do_add_stake(origin.clone(), hotkey.clone(), amount)?;
+            Self::do_root_register(origin, hotkey)
+        })
+    }
+
     // Checks if a hotkey should be a member of the Senate, and if so, adds them.
     //
     // This function is responsible for adding a hotkey to the Senate if they meet the requirements.
@@ -659,7 +986,7 @@ impl<T: Config> Pallet<T> {
             hotkey
         );
         Self::deposit_event(Event::SenateAdjusted {
-            old_member: replaced.cloned(),
+            old_member: replaced,
             new_member: hotkey,
         });
 
@@ -669,13 +996,17 @@ impl<T: Config> Pallet<T> {
 
     // Checks if a hotkey should be a member of the Senate, and if so, adds them.
     //
+    // The Senate holds the root validators with the most total stake. When it is full, the
+    // hotkey takes the seat of a member no longer registered on the root network, or else of
+    // the member with the lowest stake if the hotkey has more.
+    //
     // # Arguments:
     // * 'hotkey': The hotkey that the user wants to register to the root network.
     //
     // # Returns:
-    // * 'Result<Option<&T::AccountId>, Error<T>>': A result containing the replaced member, if any.
+    // * 'Result<Option<T::AccountId>, Error<T>>': A result containing the replaced member, if any.
     //
-    fn join_senate_if_eligible(hotkey: &T::AccountId) -> Result<Option<&T::AccountId>, Error<T>> {
+    fn join_senate_if_eligible(hotkey: &T::AccountId) -> Result<Option<T::AccountId>, Error<T>> {
         // Get the root network UID.
         let root_netuid: u16 = Self::get_root_netuid();
 
@@ -695,54 +1026,54 @@ impl<T: Config> Pallet<T> {
         let current_stake = Self::get_total_stake_for_hotkey(hotkey);
 
         // Add the hotkey to the Senate.
-        // If we're full, we'll swap out the lowest stake member.
+        // If we're full, we'll swap out a member off the root network, then the lowest stake member.
         let members = T::SenateMembers::members();
-        let last: Option<&T::AccountId> = None;
-        if (members.len() as u32) == T::SenateMembers::max_members() {
-            let mut sorted_members = members.clone();
-            sorted_members.sort_by(|a, b| {
-                let a_stake = Self::get_total_stake_for_hotkey(a);
-                let b_stake = Self::get_total_stake_for_hotkey(b);
-
-                b_stake.cmp(&a_stake)
-            });
-
-            if let Some(last) = sorted_members.last() {
-                let last_stake = Self::get_total_stake_for_hotkey(last);
-
-                if last_stake < current_stake {
-                    // Swap the member with the lowest stake.
-                    T::SenateMembers::swap_member(last, hotkey)
-                        .map_err(|_| Error::<T>::CouldNotJoinSenate)?;
-                }
-            }
-        } else {
+        if (members.len() as u32) < T::SenateMembers::max_members() {
             T::SenateMembers::add_member(hotkey).map_err(|_| Error::<T>::CouldNotJoinSenate)?;
+            return Ok(None);
         }
 
-        // Return the swapped out member, if any.
-        Ok(last)
+        let lowest = members
+            .into_iter()
+            .map(|member| {
+                let on_root = Uids::<T>::contains_key(root_netuid, &member);
+                let stake = Self::get_total_stake_for_hotkey(&member);
+                (on_root, stake, member)
+            })
+            .min_by_key(|(on_root, stake, _)| (*on_root, *stake));
+
+        match lowest {
+            Some((on_root, lowest_stake, lowest)) if !on_root || lowest_stake < current_stake => {
+                // Swap the member with the lowest stake.
+                T::SenateMembers::swap_member(&lowest, hotkey)
+                    .map_err(|_| Error::<T>::CouldNotJoinSenate)?;
+                // Return the swapped out member.
+                Ok(Some(lowest))
+            }
+            _ => Ok(None),
+        }
     }
 
     pub fn do_set_root_weights(
         origin: T::RuntimeOrigin,
-        netuid: u16,
+        netuid: NetUid,
         hotkey: T::AccountId,
-        uids: Vec<u16>,
+        dests: Vec<NetUid>,
         values: Vec<u16>,
         version_key: u64,
     ) -> dispatch::DispatchResult {
-        // Check the caller's signature. This is the coldkey of a registered account.
-        let coldkey = ensure_signed(origin)?;
+        // Check the caller's signature. This is the coldkey of a registered account, or the
+        // weight setter the hotkey authorized.
+        let signer = ensure_signed(origin)?;
         ensure!(
-            !Self::coldkey_in_arbitration(&coldkey),
+            !Self::coldkey_in_arbitration(&signer),
             Error::<T>::ColdkeyIsInArbitration
         );
         log::info!(
-            "do_set_root_weights( origin:{:?} netuid:{:?}, uids:{:?}, values:{:?})",
-            coldkey,
+            "do_set_root_weights( origin:{:?} netuid:{:?}, dests:{:?}, values:{:?})",
+            signer,
             netuid,
-            uids,
+            dests,
             values
         );
 
@@ -752,12 +1083,25 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAccountNotExists
         );
 
-        // Check that the signer coldkey owns the hotkey
+        // Check that the signer owns the hotkey or is its weight setter.
         ensure!(
-            Self::get_owning_coldkey_for_hotkey(&hotkey) == coldkey,
+            Self::can_set_weights_for(netuid.inner(), &hotkey, &signer),
             Error::<T>::NonAssociatedColdKey
         );
 
+        let uids: Vec<u16> = dests.into_iter().map(u16::from).collect();
+        Self::apply_root_weights(netuid.inner(), &hotkey, uids, values, version_key)
+    }
+
+    /// Sets the root weights of `hotkey`, once the caller is known to act for it: its
+    /// coldkey through `set_root_weights`, or the hotkey revealing committed weights.
+    pub fn apply_root_weights(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+    ) -> dispatch::DispatchResult {
         // Check to see if this is a valid network.
         ensure!(
             Self::if_subnet_exist(netuid),
@@ -775,20 +1119,21 @@ impl<T: Config> Pallet<T> {
 
         // Check to see if the number of uids is within the max allowed uids for this network.
         // For the root network this number is the number of subnets.
+        let dest_netuids: Vec<NetUid> = uids.iter().copied().map(NetUid::from).collect();
         ensure!(
-            !Self::contains_invalid_root_uids(&uids),
+            !Self::contains_invalid_root_uids(&dest_netuids),
             Error::<T>::UidVecContainInvalidOne
         );
 
         // Check to see if the hotkey is registered to the passed network.
         ensure!(
-            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Self::is_hotkey_registered_on_network(netuid, hotkey),
             Error::<T>::HotKeyNotRegisteredInSubNet
         );
 
         // Check to see if the hotkey has enough stake to set weights.
         ensure!(
-            Self::get_total_stake_for_hotkey(&hotkey) >= Self::get_weights_min_stake(),
+            Self::get_total_stake_for_hotkey(hotkey) >= Self::get_weights_min_stake(),
             Error::<T>::NotEnoughStakeToSetWeights
         );
 
@@ -799,14 +1144,24 @@ impl<T: Config> Pallet<T> {
         );
 
         // Get the neuron uid of associated hotkey on network netuid.
-        let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+        let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, hotkey)?;
 
-        // Ensure the uid is not setting weights faster than the weights_set_rate_limit.
+        // Ensure weight setting is not paused on the root network.
         let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            !Self::is_weights_paused(netuid, current_block),
+            Error::<T>::WeightsPaused
+        );
+
+        // Ensure the uid is not setting weights faster than the weights_set_rate_limit.
         ensure!(
             Self::check_rate_limit(netuid, neuron_uid, current_block),
             Error::<T>::SettingWeightsTooFast
         );
+        ensure!(
+            Self::passes_rate_limit(hotkey, TransactionType::SetRootWeights),
+            Error::<T>::TransactionTypeRateLimitExceeded
+        );
 
         // Ensure the passed uids contain no duplicates.
         ensure!(!Self::has_duplicate_uids(&uids), Error::<T>::DuplicateUids);
@@ -817,26 +1172,34 @@ impl<T: Config> Pallet<T> {
             Error::<T>::WeightVecLengthIsLow
         );
 
-        // Max-upscale the weights.
-        let max_upscaled_weights: Vec<u16> = vec_u16_max_upscale_to_u16(&values);
+        // Scale the weights per the root network's weights representation.
+        let scaled_weights: Vec<u16> = Self::scale_weights_for_storage(netuid, &values);
 
         // Ensure the weights are max weight limited
         ensure!(
-            Self::max_weight_limited(netuid, neuron_uid, &uids, &max_upscaled_weights),
+            Self::max_weight_limited(netuid, neuron_uid, &uids, &scaled_weights),
             Error::<T>::MaxWeightExceeded
         );
 
+        // Ensure the weights are not degenerate when root weights safe mode is on.
+        Self::ensure_root_weights_spread(hotkey, &values)?;
+
         // Zip weights for sinking to storage map.
         let mut zipped_weights: Vec<(u16, u16)> = vec![];
-        for (uid, val) in uids.iter().zip(max_upscaled_weights.iter()) {
+        for (uid, val) in uids.iter().zip(scaled_weights.iter()) {
             zipped_weights.push((*uid, *val))
         }
 
         // Set weights under netuid, uid double map entry.
-        Weights::<T>::insert(netuid, neuron_uid, zipped_weights);
+        Weights::<T>::insert(
+            netuid,
+            neuron_uid,
+            Self::weights_row(netuid, zipped_weights),
+        );
 
         // Set the activity for the weights on this network.
         Self::set_last_update_for_uid(netuid, neuron_uid, current_block);
+        Self::set_last_transaction_type_block(hotkey, TransactionType::SetRootWeights);
 
         // Emit the tracking event.
         log::info!(
@@ -844,12 +1207,58 @@ impl<T: Config> Pallet<T> {
             netuid,
             neuron_uid
         );
-        Self::deposit_event(Event::WeightsSet(netuid, neuron_uid));
+        Self::deposit_subnet_event(&[netuid], Event::WeightsSet(netuid, neuron_uid));
 
         // Return ok.
         Ok(())
     }
 
+    /// In root weights safe mode, rejects root weights that are all zero and, from hotkeys
+    /// with more stake than RootWeightsSafeModeStakeThreshold, weights reaching fewer than
+    /// RootWeightsMinSpread subnets.
+    ///
+    /// # Raises:
+    /// * 'DegenerateRootWeights':
+    ///     - Every weight is zero.
+    ///
+    /// * 'RootWeightsSpreadTooLow':
+    ///     - The hotkey is above the stake threshold and weights too few subnets.
+    ///
+    pub fn ensure_root_weights_spread(hotkey: &T::AccountId, values: &[u16]) -> DispatchResult {
+        if !RootWeightsSafeMode::<T>::get() {
+            return Ok(());
+        }
+        let spread: usize = values.iter().filter(|value| **value > 0).count();
+        ensure!(spread > 0, Error::<T>::DegenerateRootWeights);
+        if Self::get_total_stake_for_hotkey(hotkey) > RootWeightsSafeModeStakeThreshold::<T>::get()
+        {
+            ensure!(
+                spread >= usize::from(RootWeightsMinSpread::<T>::get()),
+                Error::<T>::RootWeightsSpreadTooLow
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns whether root weights safe mode is on, its stake threshold and minimum spread.
+    pub fn get_root_weights_safe_mode() -> (bool, u64, u16) {
+        (
+            RootWeightsSafeMode::<T>::get(),
+            RootWeightsSafeModeStakeThreshold::<T>::get(),
+            RootWeightsMinSpread::<T>::get(),
+        )
+    }
+    pub fn set_root_weights_safe_mode(enabled: bool, stake_threshold: u64, min_spread: u16) {
+        RootWeightsSafeMode::<T>::put(enabled);
+        RootWeightsSafeModeStakeThreshold::<T>::put(stake_threshold);
+        RootWeightsMinSpread::<T>::put(min_spread);
+        Self::deposit_event(Event::RootWeightsSafeModeSet(
+            enabled,
+            stake_threshold,
+            min_spread,
+        ));
+    }
+
     pub fn do_vote_root(
         origin: T::RuntimeOrigin,
         hotkey: &T::AccountId,
@@ -911,7 +1320,9 @@ impl<T: Config> Pallet<T> {
     /// # Raises:
     /// * 'TxRateLimitExceeded': If the rate limit for network registration is exceeded.
     /// * 'NotEnoughBalanceToStake': If there isn't enough balance to stake for network registration.
+    /// * 'FeeReserveNotKept': If the coldkey keeps the fee reserve and the lock would dip into it.
     /// * 'BalanceWithdrawalError': If an error occurs during balance withdrawal for network registration.
+    /// * 'SubnetLimitReached': If no netuid is left to assign to the network.
     ///
     pub fn user_add_network(origin: T::RuntimeOrigin) -> dispatch::DispatchResult {
         // --- 0. Ensure the caller is a signed user.
@@ -921,21 +1332,8 @@ impl<T: Config> Pallet<T> {
             Error::<T>::ColdkeyIsInArbitration
         );
 
-        // --- 1. Rate limit for network registrations.
-        let current_block = Self::get_current_block_as_u64();
-        let last_lock_block = Self::get_network_last_lock_block();
-        ensure!(
-            current_block.saturating_sub(last_lock_block) >= NetworkRateLimit::<T>::get(),
-            Error::<T>::NetworkTxRateLimitExceeded
-        );
-
-        // --- 2. Calculate and lock the required tokens.
-        let lock_amount: u64 = Self::get_network_lock_cost();
-        log::debug!("network lock_amount: {:?}", lock_amount);
-        ensure!(
-            Self::can_remove_balance_from_coldkey_account(&coldkey, lock_amount),
-            Error::<T>::NotEnoughBalanceToStake
-        );
+        // --- 1. Rate limit for network registrations and the required tokens.
+        let lock_amount: u64 = Self::ensure_can_lock_network(&coldkey)?;
 
         // --- 4. Determine the netuid to register.
         let netuid_to_register: u16 = {
@@ -946,49 +1344,118 @@ impl<T: Config> Pallet<T> {
             );
             if Self::get_num_subnets().saturating_sub(1) < Self::get_max_subnets() {
                 // We subtract one because we don't want root subnet to count towards total
-                let mut next_available_netuid = 0;
-                loop {
-                    next_available_netuid.saturating_inc();
-                    if !Self::if_subnet_exist(next_available_netuid) {
-                        log::debug!("got subnet id: {:?}", next_available_netuid);
-                        break next_available_netuid;
-                    }
-                }
+                Self::get_next_available_netuid().ok_or(Error::<T>::SubnetLimitReached)?
             } else {
-                let netuid_to_prune = Self::get_subnet_to_prune();
+                let netuid_to_prune = Self::get_subnet_to_prune(lock_amount);
                 ensure!(netuid_to_prune > 0, Error::<T>::AllNetworksInImmunity);
 
                 Self::remove_network(netuid_to_prune);
                 log::debug!("remove_network: {:?}", netuid_to_prune,);
-                Self::deposit_event(Event::NetworkRemoved(netuid_to_prune));
-                netuid_to_prune
+                Self::deposit_subnet_event(
+                    &[netuid_to_prune],
+                    Event::NetworkRemoved(netuid_to_prune),
+                );
+                // A quarantined netuid is not handed straight to the new network.
+                if Self::is_netuid_quarantined(netuid_to_prune) {
+                    Self::get_next_available_netuid().ok_or(Error::<T>::SubnetLimitReached)?
+                } else {
+                    netuid_to_prune
+                }
             }
         };
 
-        // --- 5. Perform the lock operation.
+        // --- 5. Lock the tokens and add the network.
+        Self::lock_and_add_network(coldkey, netuid_to_register, lock_amount)
+    }
+
+    /// Returns the lowest netuid without a network that is neither reserved for an auction,
+    /// quarantined, nor one a subnet is moving from or to, or None when no netuid is left.
+    pub fn get_next_available_netuid() -> Option<u16> {
+        let next_available_netuid = (1..=u16::MAX).find(|netuid| {
+            !Self::if_subnet_exist(*netuid)
+                && !Self::is_netuid_reserved(*netuid)
+                && !Self::is_netuid_quarantined(*netuid)
+                && !Self::is_subnet_migrating(*netuid)
+        });
+        log::debug!("got subnet id: {:?}", next_available_netuid);
+        next_available_netuid
+    }
+
+    /// Returns true if `netuid` was removed less than NetuidQuarantinePeriod blocks ago, so
+    /// that clients do not see it change identity under them.
+    pub fn is_netuid_quarantined(netuid: u16) -> bool {
+        let period = Self::get_netuid_quarantine_period();
+        period > 0
+            && NetuidTombstones::<T>::get(netuid).is_some_and(|(removed_at, _)| {
+                Self::get_current_block_as_u64() < removed_at.saturating_add(period)
+            })
+    }
+
+    /// Returns the block `netuid` was removed at and the owner it had, if it was removed and
+    /// not registered again since.
+    pub fn get_netuid_tombstone(netuid: u16) -> Option<(u64, T::AccountId)> {
+        NetuidTombstones::<T>::get(netuid)
+    }
+
+    pub fn get_netuid_quarantine_period() -> u64 {
+        NetuidQuarantinePeriod::<T>::get()
+    }
+    pub fn set_netuid_quarantine_period(period: u64) {
+        NetuidQuarantinePeriod::<T>::put(period);
+        Self::deposit_event(Event::NetuidQuarantinePeriodSet(period));
+    }
+
+    /// Checks the network registration rate limit and that the coldkey can lock the network
+    /// lock cost, which is returned.
+    pub fn ensure_can_lock_network(coldkey: &T::AccountId) -> Result<u64, DispatchError> {
+        let current_block = Self::get_current_block_as_u64();
+        let last_lock_block = Self::get_network_last_lock_block();
+        ensure!(
+            current_block.saturating_sub(last_lock_block) >= NetworkRateLimit::<T>::get(),
+            Error::<T>::NetworkTxRateLimitExceeded
+        );
+        ensure!(
+            Self::passes_rate_limit(coldkey, TransactionType::RegisterNetwork),
+            Error::<T>::TransactionTypeRateLimitExceeded
+        );
+
+        let lock_amount: u64 = Self::get_network_lock_cost();
+        log::debug!("network lock_amount: {:?}", lock_amount);
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(coldkey, lock_amount),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+        Self::ensure_fee_reserve_kept(coldkey, lock_amount)?;
+        Ok(lock_amount)
+    }
+
+    /// Locks `lock_amount` from the coldkey and adds the network `netuid` owned by it.
+    pub fn lock_and_add_network(
+        coldkey: T::AccountId,
+        netuid: u16,
+        lock_amount: u64,
+    ) -> dispatch::DispatchResult {
+        // --- 1. Perform the lock operation.
         let actual_lock_amount = Self::remove_balance_from_coldkey_account(&coldkey, lock_amount)?;
-        Self::set_subnet_locked_balance(netuid_to_register, actual_lock_amount);
+        Self::set_subnet_locked_balance(netuid, actual_lock_amount);
         Self::set_network_last_lock(actual_lock_amount);
+        Self::set_last_transaction_type_block(&coldkey, TransactionType::RegisterNetwork);
 
-        // --- 6. Set initial and custom parameters for the network.
-        Self::init_new_network(netuid_to_register, 360);
-        log::debug!("init_new_network: {:?}", netuid_to_register,);
+        // --- 2. Set initial and custom parameters for the network.
+        Self::init_new_network(netuid, T::NewNetworkTempo::get());
+        log::debug!("init_new_network: {:?}", netuid,);
 
-        // --- 7. Set netuid storage.
+        // --- 3. Set netuid storage.
         let current_block_number: u64 = Self::get_current_block_as_u64();
         NetworkLastRegistered::<T>::set(current_block_number);
-        NetworkRegisteredAt::<T>::insert(netuid_to_register, current_block_number);
-        SubnetOwner::<T>::insert(netuid_to_register, coldkey);
+        NetworkRegisteredAt::<T>::insert(netuid, current_block_number);
+        SubnetOwner::<T>::insert(netuid, coldkey);
 
-        // --- 8. Emit the NetworkAdded event.
-        log::info!(
-            "NetworkAdded( netuid:{:?}, modality:{:?} )",
-            netuid_to_register,
-            0
-        );
-        Self::deposit_event(Event::NetworkAdded(netuid_to_register, 0));
+        // --- 4. Emit the NetworkAdded event.
+        log::info!("NetworkAdded( netuid:{:?}, modality:{:?} )", netuid, 0);
+        Self::deposit_subnet_event(&[netuid], Event::NetworkAdded(netuid, 0));
+        T::OnSubtensorEvent::on_network_added(netuid);
 
-        // --- 9. Return success.
         Ok(())
     }
 
@@ -1030,7 +1497,7 @@ impl<T: Config> Pallet<T> {
 
         // --- 5. Emit the NetworkRemoved event.
         log::info!("NetworkRemoved( netuid:{:?} )", netuid);
-        Self::deposit_event(Event::NetworkRemoved(netuid));
+        Self::deposit_subnet_event(&[netuid], Event::NetworkRemoved(netuid));
 
         // --- 6. Return success.
         Ok(())
@@ -1041,8 +1508,9 @@ impl<T: Config> Pallet<T> {
         // --- 1. Set network to 0 size.
         SubnetworkN::<T>::insert(netuid, 0);
 
-        // --- 2. Set this network uid to alive.
-        NetworksAdded::<T>::insert(netuid, true);
+        // --- 2. Set this network uid to alive, clearing the tombstone of its previous network.
+        Self::add_subnet_netuid(netuid);
+        NetuidTombstones::<T>::remove(netuid);
 
         // --- 3. Fill tempo memory item.
         Tempo::<T>::insert(netuid, tempo);
@@ -1055,15 +1523,23 @@ impl<T: Config> Pallet<T> {
 
         // --- 6. Set all default values **explicitly**.
         Self::set_network_registration_allowed(netuid, true);
-        Self::set_max_allowed_uids(netuid, 256);
-        Self::set_max_allowed_validators(netuid, 64);
-        Self::set_min_allowed_weights(netuid, 1);
+        Self::set_max_allowed_uids(netuid, T::NewNetworkMaxAllowedUids::get());
+        Self::set_max_allowed_validators(netuid, T::NewNetworkMaxAllowedValidators::get());
+        Self::set_max_miner_uids(
+            netuid,
+            T::NewNetworkMaxAllowedUids::get()
+                .saturating_sub(T::NewNetworkMaxAllowedValidators::get()),
+        );
+        Self::set_min_allowed_weights(netuid, T::NewNetworkMinAllowedWeights::get());
         Self::set_max_weight_limit(netuid, u16::MAX);
-        Self::set_adjustment_interval(netuid, 360);
-        Self::set_target_registrations_per_interval(netuid, 1);
-        Self::set_adjustment_alpha(netuid, 17_893_341_751_498_265_066); // 18_446_744_073_709_551_615 * 0.97 = 17_893_341_751_498_265_066
-        Self::set_immunity_period(netuid, 5000);
-        Self::set_min_burn(netuid, 1);
+        Self::set_adjustment_interval(netuid, T::NewNetworkAdjustmentInterval::get());
+        Self::set_target_registrations_per_interval(
+            netuid,
+            T::NewNetworkTargetRegistrationsPerInterval::get(),
+        );
+        Self::set_adjustment_alpha(netuid, T::NewNetworkAdjustmentAlpha::get());
+        Self::set_immunity_period(netuid, T::NewNetworkImmunityPeriod::get());
+        Self::set_min_burn(netuid, T::NewNetworkMinBurn::get());
         Self::set_min_difficulty(netuid, u64::MAX);
         Self::set_max_difficulty(netuid, u64::MAX);
 
@@ -1140,41 +1616,75 @@ impl<T: Config> Pallet<T> {
         NetworkModality::<T>::remove(netuid);
 
         // --- 4. Remove netuid from added networks.
-        NetworksAdded::<T>::remove(netuid);
+        Self::remove_subnet_netuid(netuid);
 
         // --- 6. Decrement the network counter.
         TotalNetworks::<T>::mutate(|n| n.saturating_dec());
 
-        // --- 7. Remove various network-related storages.
+        // --- 7. Remove various network-related storages, leaving a tombstone of the netuid.
         NetworkRegisteredAt::<T>::remove(netuid);
+        Self::drop_netuid_from_root_epoch(netuid);
+        NetuidTombstones::<T>::insert(
+            netuid,
+            (Self::get_current_block_as_u64(), owner_coldkey.clone()),
+        );
 
-        // --- 8. Remove incentive mechanism memory.
+        // --- 8. Remove the state of the neurons, then the incentive mechanism memory.
+        let hotkeys: Vec<T::AccountId> = Keys::<T>::iter_prefix_values(netuid).collect();
+        for hotkey in hotkeys {
+            Self::clear_hotkey_on_network(netuid, &hotkey);
+        }
         let _ = Uids::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Keys::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Bonds::<T>::clear_prefix(netuid, u32::MAX, None);
+        BondsResetCursor::<T>::remove(netuid);
 
         // --- 8. Removes the weights for this subnet (do not remove).
         let _ = Weights::<T>::clear_prefix(netuid, u32::MAX, None);
-
-        // --- 9. Iterate over stored weights and fill the matrix.
-        for (uid_i, weights_i) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(
-                Self::get_root_netuid(),
-            )
-        {
-            // Create a new vector to hold modified weights.
-            let mut modified_weights = weights_i.clone();
-            // Iterate over each weight entry to potentially update it.
-            for (subnet_id, weight) in modified_weights.iter_mut() {
-                if subnet_id == &netuid {
-                    // If the condition matches, modify the weight
-                    *weight = 0; // Set weight to 0 for the matching subnet_id.
-                }
+        let _ = WeightsKeys::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = WeightsKeyHotkey::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = WeightsMetadataHash::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = WeightsTargetEpoch::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = LastNeuronClearBlock::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = ChildKeys::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = ParentKeys::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = PendingChildKeys::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = AssociatedEvmAddress::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = WeightCommits::<T>::clear_prefix(netuid, u32::MAX, None);
+
+        // --- 8. Remove the serving and registration memory left by hotkeys that are no
+        // longer neurons of the network, which would otherwise carry over to a network
+        // registered under the same netuid. The axon ips are dropped from the index first,
+        // while the axons still name them.
+        let serving: Vec<T::AccountId> = Axons::<T>::iter_key_prefix(netuid)
+            .chain(AxonReplicas::<T>::iter_key_prefix(netuid))
+            .collect();
+        for hotkey in serving {
+            for ip in Self::get_axon_ips(netuid, &hotkey) {
+                AxonIpIndex::<T>::remove(ip, (netuid, hotkey.clone()));
             }
-            Weights::<T>::insert(Self::get_root_netuid(), uid_i, modified_weights);
         }
+        let _ = Axons::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = AxonReplicas::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = NeuronCertificates::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = Prometheus::<T>::clear_prefix(netuid, u32::MAX, None);
+        #[cfg(feature = "commitments")]
+        {
+            let _ = Commitments::<T>::clear_prefix(netuid, u32::MAX, None);
+            let _ = LastCommitmentBlock::<T>::clear_prefix(netuid, u32::MAX, None);
+        }
+        let _ = BlockAtRegistration::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = RetiredUids::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = RegistrationBurn::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = DeclaredRoles::<T>::clear_prefix(netuid, u32::MAX, None);
+
+        // --- 9. Strip the network from the root weights, so that the next root epoch and
+        // a network registered under the same netuid do not see the weights set for it.
+        Self::strip_netuid_from_root_weights(netuid);
 
         // --- 10. Remove various network-related parameters.
+        EmissionMultiplier::<T>::remove(netuid);
+        MaxTakePerSubnet::<T>::remove(netuid);
         Rank::<T>::remove(netuid);
         Trust::<T>::remove(netuid);
         Active::<T>::remove(netuid);
@@ -1183,37 +1693,105 @@ impl<T: Config> Pallet<T> {
         Consensus::<T>::remove(netuid);
         Dividends::<T>::remove(netuid);
         PruningScores::<T>::remove(netuid);
-        LastUpdate::<T>::remove(netuid);
+        Self::remove_last_update(netuid);
         ValidatorPermit::<T>::remove(netuid);
         ValidatorTrust::<T>::remove(netuid);
+        PendingEmission::<T>::remove(netuid);
+        LoadedEmission::<T>::remove(netuid);
+        BlocksSinceLastStep::<T>::remove(netuid);
+        LastMechansimStepBlock::<T>::remove(netuid);
+        EpochIndex::<T>::remove(netuid);
+        EmissionHistory::<T>::remove(netuid);
+        PowDifficultySolved::<T>::remove(netuid);
+        PowDifficultySolvedThisInterval::<T>::remove(netuid);
+        PowIntervalAverages::<T>::remove(netuid);
+        NeuronsToPruneAtNextEpoch::<T>::remove(netuid);
 
         // --- 11. Erase network parameters.
         Tempo::<T>::remove(netuid);
         Kappa::<T>::remove(netuid);
+        MaxStakeWeightFraction::<T>::remove(netuid);
         Difficulty::<T>::remove(netuid);
         MaxAllowedUids::<T>::remove(netuid);
+        MaxMinerUids::<T>::remove(netuid);
+        MinStakeToRegister::<T>::remove(netuid);
+        BootstrapIncentiveFloor::<T>::remove(netuid);
+        SubnetEmissionSplit::<T>::remove(netuid);
         ImmunityPeriod::<T>::remove(netuid);
         ActivityCutoff::<T>::remove(netuid);
         EmissionValues::<T>::remove(netuid);
         MaxWeightsLimit::<T>::remove(netuid);
         MinAllowedWeights::<T>::remove(netuid);
+        WeightsPrecision::<T>::remove(netuid);
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
+        POWRegistrationsThisBlock::<T>::remove(netuid);
+        BurnRegistrationsThisBlock::<T>::remove(netuid);
+        MaxPOWRegistrationsPerBlock::<T>::remove(netuid);
+        MaxBurnRegistrationsPerBlock::<T>::remove(netuid);
+        SubnetFeatureFlags::<T>::remove(netuid);
+        MaintenanceWindow::<T>::remove(netuid);
+        SubnetOwnerHotkey::<T>::remove(netuid);
+        PendingSubnetOwner::<T>::remove(netuid);
+        SubnetSymbol::<T>::remove(netuid);
+        SubnetTokenName::<T>::remove(netuid);
+        #[cfg(feature = "identity")]
+        Self::clear_subnet_identity(netuid);
+        RAORecycledIntoEmission::<T>::remove(netuid);
+        AxonTTLBlocks::<T>::remove(netuid);
+        SubnetUtilization::<T>::remove(netuid);
+        MaxAllowedUidsGrowth::<T>::remove(netuid);
+        WeightsSweepCursor::<T>::remove(netuid);
+        WeightsRescalePending::<T>::remove(netuid);
+        EpochSnapshots::<T>::remove(netuid);
+        EvictionLog::<T>::remove(netuid);
+        let _ = EpochPauseSignals::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = AxonAttestations::<T>::clear_prefix(netuid, u32::MAX, None);
+        EpochPausedUntil::<T>::remove(netuid);
+        WeightsPausedUntil::<T>::remove(netuid);
+        let _ = LastOwnerHyperparamUpdate::<T>::clear_prefix(netuid, u32::MAX, None);
+        Self::clear_subnet_referendum(netuid);
 
         // --- 12. Add the balance back to the owner.
         Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount);
         Self::set_subnet_locked_balance(netuid, 0);
         SubnetOwner::<T>::remove(netuid);
+
+        // --- 13. Notify the pallets following subtensor.
+        T::OnSubtensorEvent::on_network_removed(netuid);
+    }
+
+    /// Removes the weights root validators set for `netuid` from their rows, rewriting only
+    /// the rows that weigh it.
+    pub fn strip_netuid_from_root_weights(netuid: u16) {
+        let root_netuid = Self::get_root_netuid();
+        let rows: Vec<(u16, WeightsRow)> =
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(
+                root_netuid,
+            )
+            .collect();
+        for (uid, row) in rows {
+            let mut entries = row.entries();
+            let len = entries.len();
+            entries.retain(|(subnet_id, _)| *subnet_id != netuid);
+            if entries.len() == len {
+                continue;
+            }
+            if entries.is_empty() {
+                Weights::<T>::remove(root_netuid, uid);
+            } else {
+                Weights::<T>::insert(root_netuid, uid, WeightsRow::from(entries));
+            }
+        }
     }
 
-    #[allow(clippy::arithmetic_side_effects)]
     /// This function calculates the lock cost for a network based on the last lock amount, minimum lock cost, last lock block, and current block.
     /// The lock cost is calculated using the formula:
     /// lock_cost = (last_lock * mult) - (last_lock / lock_reduction_interval) * (current_block - last_lock_block)
     /// where:
     /// - last_lock is the last lock amount for the network
-    /// - mult is the multiplier which increases lock cost each time a registration occurs
+    /// - mult is NetworkLockCostMultiplier, which increases lock cost each time a registration occurs
     /// - last_lock_block is the block number at which the last lock occurred
     /// - lock_reduction_interval the number of blocks before the lock returns to previous value.
     /// - current_block is the current block number
@@ -1227,18 +1805,41 @@ impl<T: Config> Pallet<T> {
     ///     - The lock cost for the network.
     ///
     pub fn get_network_lock_cost() -> u64 {
+        Self::get_network_lock_cost_at(Self::get_current_block_as_u64())
+    }
+
+    /// Returns the lock cost of a network registered `blocks_ahead` blocks from now, assuming
+    /// no other network registers in between. The cost only decays while no network
+    /// registers, so this is the cost a prospective owner waits for.
+    pub fn project_network_burn_cost(blocks_ahead: u64) -> u64 {
+        Self::get_network_lock_cost_at(
+            Self::get_current_block_as_u64().saturating_add(blocks_ahead),
+        )
+    }
+
+    /// The lock cost of `get_network_lock_cost` evaluated at `current_block`.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn get_network_lock_cost_at(current_block: u64) -> u64 {
         let last_lock = Self::get_network_last_lock();
         let min_lock = Self::get_network_min_lock();
         let last_lock_block = Self::get_network_last_lock_block();
-        let current_block = Self::get_current_block_as_u64();
         let lock_reduction_interval = Self::get_lock_reduction_interval();
-        let mult = if last_lock_block == 0 { 1 } else { 2 };
-
-        let mut lock_cost = last_lock.saturating_mul(mult).saturating_sub(
-            last_lock
-                .saturating_div(lock_reduction_interval)
-                .saturating_mul(current_block.saturating_sub(last_lock_block)),
-        );
+        let mult: u32 = if last_lock_block == 0 {
+            10_000
+        } else {
+            Self::get_network_lock_cost_multiplier()
+        };
+        let raised_lock = u128::from(last_lock)
+            .saturating_mul(u128::from(mult))
+            .saturating_div(10_000);
+
+        let mut lock_cost = u64::try_from(raised_lock)
+            .unwrap_or(u64::MAX)
+            .saturating_sub(
+                last_lock
+                    .saturating_div(lock_reduction_interval)
+                    .saturating_mul(current_block.saturating_sub(last_lock_block)),
+            );
 
         if lock_cost < min_lock {
             lock_cost = min_lock;
@@ -1250,53 +1851,88 @@ impl<T: Config> Pallet<T> {
         lock_cost
     }
 
+    /// This function calculates the portion of a subnet's lock which still protects it from pruning.
+    /// The protected lock holds the full locked amount through the network immunity period, then
+    /// decays linearly to zero over the lock reduction interval:
+    /// protected_lock = locked - locked * (current_block - registered_block - immunity_period) / lock_reduction_interval
+    ///
+    /// # Args:
+    /// * 'netuid': ('u16'): The unique identifier of the network.
+    ///
+    /// # Returns:
+    /// * 'u64':
+    ///     - The amount of the subnet lock which is still time-weighted against pruning.
+    ///
+    pub fn get_subnet_protected_lock(netuid: u16) -> u64 {
+        let locked = Self::get_subnet_locked_balance(netuid);
+        let lock_reduction_interval = Self::get_lock_reduction_interval();
+        if lock_reduction_interval == 0 {
+            return 0;
+        }
+        let blocks_since_immunity = Self::get_current_block_as_u64()
+            .saturating_sub(Self::get_network_registered_block(netuid))
+            .saturating_sub(Self::get_network_immunity_period());
+
+        let decayed = u128::from(locked)
+            .saturating_mul(u128::from(blocks_since_immunity))
+            .checked_div(u128::from(lock_reduction_interval))
+            .unwrap_or_default();
+        locked.saturating_sub(u64::try_from(decayed).unwrap_or(u64::MAX))
+    }
+
     /// This function is used to determine which subnet to prune when the total number of networks has reached the limit.
     /// It iterates over all the networks and finds the oldest subnet with the minimum emission value that is not in the immunity period.
+    /// Subnets registered in the same block are broken towards the lowest netuid, so the pick never depends on iteration order.
+    /// Subnets whose protected lock (see `get_subnet_protected_lock`) exceeds `lock_amount`, the lock paid by the incoming
+    /// registration, are also skipped, so a subnet registered with a large lock can not be replaced by a cheaper registration
+    /// before its lock has amortized.
+    /// The root network is never pruned.
+    ///
+    /// # Args:
+    /// * 'lock_amount': ('u64'): The lock the registration replacing the pruned subnet pays.
     ///
     /// # Returns:
     /// * 'u16':
     ///     - The uid of the network to be pruned.
     ///
-    pub fn get_subnet_to_prune() -> u16 {
+    pub fn get_subnet_to_prune(lock_amount: u64) -> u16 {
         let mut netuids: Vec<u16> = vec![];
         let current_block = Self::get_current_block_as_u64();
+        let root_netuid = Self::get_root_netuid();
+
+        for netuid in Self::get_all_subnet_netuids() {
+            // The root network is never pruned.
+            if netuid == root_netuid {
+                continue;
+            }
 
-        // Even if we don't have a root subnet, this still works
-        for netuid in NetworksAdded::<T>::iter_keys_from(NetworksAdded::<T>::hashed_key_for(0)) {
             if current_block.saturating_sub(Self::get_network_registered_block(netuid))
                 < Self::get_network_immunity_period()
             {
                 continue;
             }
 
-            // This iterator seems to return them in order anyways, so no need to sort by key
+            // The incoming registration must lock at least what still protects this subnet.
+            if Self::get_subnet_protected_lock(netuid) > lock_amount {
+                continue;
+            }
+
             netuids.push(netuid);
         }
 
-        // Now we sort by emission, and then by subnet creation time.
-        netuids.sort_by(|a, b| {
-            use sp_std::cmp::Ordering;
+        log::info!("Netuids: {:?}", netuids);
 
-            match Self::get_emission_value(*b).cmp(&Self::get_emission_value(*a)) {
-                Ordering::Equal => {
-                    if Self::get_network_registered_block(*b)
-                        < Self::get_network_registered_block(*a)
-                    {
-                        Ordering::Less
-                    } else {
-                        Ordering::Equal
-                    }
-                }
-                v => v,
-            }
-        });
-
-        log::info!("Netuids Order: {:?}", netuids);
-
-        match netuids.last() {
-            Some(netuid) => *netuid,
-            None => 0,
-        }
+        // Lowest emission first, then the oldest registration, then the lowest netuid.
+        netuids
+            .into_iter()
+            .min_by_key(|netuid| {
+                (
+                    Self::get_emission_value(*netuid),
+                    Self::get_network_registered_block(*netuid),
+                    *netuid,
+                )
+            })
+            .unwrap_or(0)
     }
 
     pub fn get_network_registered_block(netuid: u16) -> u64 {
@@ -1332,4 +1968,11 @@ impl<T: Config> Pallet<T> {
     pub fn get_lock_reduction_interval() -> u64 {
         NetworkLockReductionInterval::<T>::get()
     }
+    pub fn set_network_lock_cost_multiplier(multiplier: u32) {
+        NetworkLockCostMultiplier::<T>::set(multiplier);
+        Self::deposit_event(Event::NetworkLockCostMultiplierSet(multiplier));
+    }
+    pub fn get_network_lock_cost_multiplier() -> u32 {
+        NetworkLockCostMultiplier::<T>::get()
+    }
 }
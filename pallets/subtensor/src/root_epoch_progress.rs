@@ -0,0 +1,161 @@
+use super::*;
+use crate::math::*;
+use substrate_fixed::types::I64F64;
+
+impl<T: Config> Pallet<T> {
+    /// Starts a root epoch, snapshotting the subnets, the number of root validators and the
+    /// block emission it distributes.
+    pub fn start_root_epoch() -> Result<RootEpochProgress, &'static str> {
+        let validators: u16 = Self::get_num_root_validators();
+        if validators == 0 {
+            return Err("No validators to validate emission values.");
+        }
+        let netuids: Vec<u16> = Self::get_all_subnet_netuids();
+        if netuids.is_empty() {
+            return Err("No networks to validate emission values.");
+        }
+        let zeros = vec![I64F64::from_num(0).to_bits(); netuids.len()];
+
+        Ok(RootEpochProgress {
+            validators,
+            block_emission: Self::get_block_emission()?,
+            ranks: zeros.clone(),
+            trust: zeros,
+            netuids,
+            ..Default::default()
+        })
+    }
+
+    /// Advances the root epoch by at most RootEpochBlockBudget units of work: one per root
+    /// stake collected, then one per weight of each sparse root weight row accumulated into
+    /// the ranks and trust, a row without weights costing one. Rows are accumulated in uid
+    /// order, so the sums match the ones of a root epoch run in a single block. Returns
+    /// whether the epoch is complete.
+    pub fn advance_root_epoch(progress: &mut RootEpochProgress) -> bool {
+        let root_netuid: u16 = Self::get_root_netuid();
+        // A budget of zero still advances the epoch, by one unit per block.
+        let mut budget: u32 = Self::get_root_epoch_block_budget().max(1);
+
+        // --- 1. Collects the total stake of each root validator.
+        while progress.stakes.len() < usize::from(progress.validators) {
+            if budget == 0 {
+                return false;
+            }
+            let uid = u16::try_from(progress.stakes.len()).unwrap_or(u16::MAX);
+            let stake = Keys::<T>::try_get(root_netuid, uid)
+                .map(|hotkey| Self::get_stake_for_hotkey_on_subnet(&hotkey, root_netuid))
+                .unwrap_or(0);
+            progress.stakes.push(stake);
+            budget = budget.saturating_sub(1);
+        }
+
+        // --- 2. Accumulates the normalized weight rows scaled by the normalized stakes, capped
+        // at RootStakeCap.
+        let mut stake_i64: Vec<I64F64> = progress
+            .stakes
+            .iter()
+            .map(|stake| I64F64::from_num(*stake))
+            .collect();
+        Self::normalize_root_stake(&mut stake_i64);
+
+        let mut ranks: Vec<I64F64> = progress
+            .ranks
+            .iter()
+            .map(|r| I64F64::from_bits(*r))
+            .collect();
+        let mut trust: Vec<I64F64> = progress
+            .trust
+            .iter()
+            .map(|t| I64F64::from_bits(*t))
+            .collect();
+        let mut total_stake = I64F64::from_bits(progress.total_stake);
+        let sum_normalized = Self::weights_sum_normalized(root_netuid);
+        while progress.next_row < progress.validators && budget > 0 {
+            let stake = stake_i64
+                .get(usize::from(progress.next_row))
+                .copied()
+                .unwrap_or(I64F64::from_num(0));
+            let row = Self::get_root_weights_row_sparse(progress.next_row, &progress.netuids);
+            let row_cost = u32::try_from(row.len()).unwrap_or(u32::MAX).max(1);
+
+            total_stake = total_stake.saturating_add(stake);
+            Self::accumulate_root_weights_row(row, sum_normalized, stake, &mut ranks, &mut trust);
+            progress.next_row = progress.next_row.saturating_add(1);
+            budget = budget.saturating_sub(row_cost);
+        }
+        progress.ranks = ranks.into_iter().map(I64F64::to_bits).collect();
+        progress.trust = trust.into_iter().map(I64F64::to_bits).collect();
+        progress.total_stake = total_stake.to_bits();
+
+        progress.next_row >= progress.validators
+    }
+
+    /// Normalizes the sparse root weight `row` of a validator and adds it, scaled by the
+    /// validator's normalized `stake`, to the `ranks` of the subnets. The stake is added to
+    /// the `trust` of every subnet the row weights above zero. A `sum_normalized` row is
+    /// divided by u16::MAX, so the weights of subnets since dropped are not redistributed.
+    pub fn accumulate_root_weights_row(
+        mut row: Vec<(u16, I64F64)>,
+        sum_normalized: bool,
+        stake: I64F64,
+        ranks: &mut [I64F64],
+        trust: &mut [I64F64],
+    ) {
+        if sum_normalized {
+            inplace_row_normalize_sparse_64_with_floor(
+                core::slice::from_mut(&mut row),
+                I64F64::from_num(u16::MAX),
+            );
+        } else {
+            inplace_row_normalize_sparse_64(core::slice::from_mut(&mut row));
+        }
+        for (column, weight) in row.iter() {
+            if let Some(rank) = ranks.get_mut(*column as usize) {
+                *rank = rank.saturating_add(stake.saturating_mul(*weight));
+            }
+            if let Some(trust_score) = trust.get_mut(*column as usize).filter(|_| *weight > 0) {
+                *trust_score = trust_score.saturating_add(stake);
+            }
+        }
+    }
+
+    /// The root weights of `uid` as a sparse row, the column of each weight being the index
+    /// of its subnet in `netuids`. Weights for subnets missing from `netuids` are dropped.
+    pub fn get_root_weights_row_sparse(uid: u16, netuids: &[u16]) -> Vec<(u16, I64F64)> {
+        Weights::<T>::get(Self::get_root_netuid(), uid)
+            .entries()
+            .into_iter()
+            .filter_map(|(netuid, weight)| {
+                let column = netuids.iter().position(|subnet| *subnet == netuid)?;
+                Some((u16::try_from(column).ok()?, I64F64::from_num(weight)))
+            })
+            .collect()
+    }
+
+    /// Drops the column of `netuid` from the root epoch in progress, so that no emission is
+    /// set for it, nor for a network registered under the same netuid before the epoch ends.
+    pub fn drop_netuid_from_root_epoch(netuid: u16) {
+        RootEpochInProgress::<T>::mutate(|progress| {
+            let Some(progress) = progress else {
+                return;
+            };
+            let Some(column) = progress.netuids.iter().position(|n| *n == netuid) else {
+                return;
+            };
+            for column_values in [&mut progress.ranks, &mut progress.trust] {
+                if column < column_values.len() {
+                    column_values.remove(column);
+                }
+            }
+            progress.netuids.remove(column);
+        });
+    }
+
+    pub fn get_root_epoch_block_budget() -> u32 {
+        RootEpochBlockBudget::<T>::get()
+    }
+    pub fn set_root_epoch_block_budget(budget: u32) {
+        RootEpochBlockBudget::<T>::put(budget);
+        Self::deposit_event(Event::RootEpochBlockBudgetSet(budget));
+    }
+}
@@ -0,0 +1,200 @@
+use super::*;
+use crate::math::vec_u16_max_upscale_to_u16;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+use substrate_fixed::types::I64F64;
+
+/// The emission of a subnet before and after a proposed change of root weights.
+#[freeze_struct("5467a10f614c9c05")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetEmissionDelta {
+    pub netuid: Compact<u16>,
+    pub emission: Compact<u64>,
+    pub simulated_emission: Compact<u64>,
+    pub delta: i64,
+}
+
+/// The root weight matrix, one row per root validator uid and one column per subnet.
+#[freeze_struct("d63f90bd015db040")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct RootWeights {
+    /// The netuid of each column.
+    pub netuids: Vec<Compact<u16>>,
+    pub weights: Vec<Vec<Compact<u16>>>,
+}
+
+/// The emission the root network sets for a subnet and the tokens locked to register it.
+#[freeze_struct("8aa470708b20c652")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetRootEmission {
+    pub netuid: Compact<u16>,
+    pub emission_value: Compact<u64>,
+    pub locked: Compact<u64>,
+}
+
+/// The emission of every subnet as of the last root epoch, and when the next one starts.
+#[freeze_struct("177510386bb28e0c")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct RootEmissionInfo {
+    /// Blocks until the block starting the next root epoch, 0 while one is in progress.
+    pub blocks_until_next_root_epoch: Compact<u64>,
+    pub subnets: Vec<SubnetRootEmission>,
+}
+
+/// The emission the next root epoch would set for a subnet if the root weights and stakes
+/// stay as they are, after its EmissionMultiplier.
+#[freeze_struct("d552f98e6e1cd20c")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ProjectedSubnetEmission {
+    pub netuid: Compact<u16>,
+    /// The emission value the last root epoch set.
+    pub emission: Compact<u64>,
+    pub projected_emission: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    fn _simulate_root_weight_change(
+        hotkey: &T::AccountId,
+        uids: &[u16],
+        values: &[u16],
+    ) -> Option<Vec<SubnetEmissionDelta>> {
+        let root_netuid = Self::get_root_netuid();
+        let root_uid = Self::get_uid_for_net_and_hotkey(root_netuid, hotkey).ok()?;
+        let dest_netuids: Vec<NetUid> = uids.iter().copied().map(NetUid::from).collect();
+        if !Self::uids_match_values(uids, values)
+            || Self::contains_invalid_root_uids(&dest_netuids)
+            || Self::has_duplicate_uids(uids)
+        {
+            return None;
+        }
+
+        let netuids: Vec<u16> = Self::get_all_subnet_netuids();
+        let weights: Vec<Vec<(u16, I64F64)>> = Self::get_root_weights_sparse();
+        let emission: Vec<u64> = Self::compute_root_emission_values(weights.clone()).ok()?;
+
+        // Replace the row of the hotkey with the proposed weights, max-upscaled as
+        // `set_root_weights` would store them.
+        let mut simulated_weights = weights;
+        let row = simulated_weights.get_mut(root_uid as usize)?;
+        *row = uids
+            .iter()
+            .zip(vec_u16_max_upscale_to_u16(values))
+            .filter_map(|(uid, value)| {
+                let column = netuids.iter().position(|netuid| netuid == uid)?;
+                Some((u16::try_from(column).ok()?, I64F64::from_num(value)))
+            })
+            .collect();
+        let simulated_emission: Vec<u64> =
+            Self::compute_root_emission_values(simulated_weights).ok()?;
+
+        Some(
+            netuids
+                .iter()
+                .zip(emission.iter().zip(&simulated_emission))
+                .map(|(netuid, (emission, simulated_emission))| {
+                    let delta = i64::try_from(*simulated_emission)
+                        .unwrap_or(i64::MAX)
+                        .saturating_sub(i64::try_from(*emission).unwrap_or(i64::MAX));
+                    SubnetEmissionDelta {
+                        netuid: (*netuid).into(),
+                        emission: (*emission).into(),
+                        simulated_emission: (*simulated_emission).into(),
+                        delta,
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Runs the root emission math with the root weights of `hotkey` replaced by `values`
+    /// on the subnets `uids`. Returns nothing if the hotkey is not a root validator, the
+    /// weights would be rejected, or the root network has nothing to emit to.
+    pub fn simulate_root_weight_change(
+        hotkey_account_vec: Vec<u8>,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+    ) -> Vec<SubnetEmissionDelta> {
+        if hotkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid hotkey
+        }
+        let Ok(hotkey) = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        Self::_simulate_root_weight_change(&hotkey, &uids, &values).unwrap_or_default()
+    }
+
+    /// Runs the root epoch on the current root weights and stakes without setting the
+    /// emission values, returning the emission it would set for each subnet. Returns nothing
+    /// if the root network has no validators or no subnets to emit to.
+    pub fn simulate_root_epoch() -> Vec<ProjectedSubnetEmission> {
+        let Ok(projected) = Self::compute_root_emission_values(Self::get_root_weights_sparse())
+        else {
+            return Vec::new();
+        };
+
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .zip(projected)
+            .map(|(netuid, projected_emission)| ProjectedSubnetEmission {
+                netuid: netuid.into(),
+                emission: Self::get_subnet_emission_value(netuid).into(),
+                projected_emission: Self::multiply_emission(
+                    projected_emission,
+                    Self::get_emission_multiplier(netuid),
+                )
+                .into(),
+            })
+            .collect()
+    }
+
+    /// Returns the root weights as a dense matrix over the subnets, as the root epoch reads
+    /// them.
+    pub fn get_root_weights_dense() -> RootWeights {
+        RootWeights {
+            netuids: Self::get_all_subnet_netuids()
+                .into_iter()
+                .map(Compact)
+                .collect(),
+            weights: Self::get_root_weights()
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|weight| Compact(weight.saturating_to_num::<u16>()))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the emission values and locked tokens of every subnet, along with the blocks
+    /// until the next root epoch sets the emission values again.
+    pub fn get_root_emission_info() -> RootEmissionInfo {
+        let subnets = Self::get_all_subnet_netuids()
+            .into_iter()
+            .map(|netuid| SubnetRootEmission {
+                netuid: netuid.into(),
+                emission_value: Self::get_subnet_emission_value(netuid).into(),
+                locked: Self::get_subnet_locked_balance(netuid).into(),
+            })
+            .collect();
+        RootEmissionInfo {
+            blocks_until_next_root_epoch: Self::get_blocks_until_next_root_epoch().into(),
+            subnets,
+        }
+    }
+
+    /// Returns the number of blocks until the block starting the next root epoch, 0 while
+    /// an epoch spread over several blocks is in progress.
+    pub fn get_blocks_until_next_root_epoch() -> u64 {
+        if RootEpochInProgress::<T>::exists() {
+            return 0;
+        }
+        let root_netuid = Self::get_root_netuid();
+        let next_block = Self::get_current_block_as_u64().saturating_add(1);
+        Self::blocks_until_next_epoch(root_netuid, Self::get_tempo(root_netuid), next_block)
+            .saturating_add(1)
+    }
+}
@@ -0,0 +1,430 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+/// Bits of the `features` bitset reported by `get_runtime_features`, for behaviour clients
+/// can't tell from the runtime API version alone. A bit keeps its meaning once assigned;
+/// retired features leave their bit unset.
+pub mod feature {
+    /// `commit_weights`/`reveal_weights` are available.
+    pub const COMMIT_REVEAL_WEIGHTS: u128 = 1 << 0;
+    /// Subnets can enable consensus-driven liquid alpha for bond EMAs.
+    pub const LIQUID_ALPHA: u128 = 1 << 1;
+    /// `get_neurons_lite`/`get_neuron_lite` are exposed by `NeuronInfoRuntimeApi`.
+    pub const NEURON_INFO_LITE: u128 = 1 << 2;
+    /// `StakeInfoRuntimeApi` is available.
+    pub const STAKE_INFO: u128 = 1 << 3;
+    /// Subnet owners can toggle the per-subnet `SubnetFeatureFlags`.
+    pub const SUBNET_FEATURE_FLAGS: u128 = 1 << 4;
+    /// `RateLimitInfoRuntimeApi` is available.
+    pub const RATE_LIMIT_INFO: u128 = 1 << 5;
+    /// Subnet events are indexed under per-netuid topics.
+    pub const SUBNET_EVENT_TOPICS: u128 = 1 << 6;
+    /// Neurons can serve axon replicas through `serve_axon_multi`.
+    pub const AXON_REPLICAS: u128 = 1 << 7;
+    /// Coldkeys can keep a fee reserve and `stake_all_minus_fees` is available.
+    pub const FEE_RESERVE: u128 = 1 << 8;
+    /// Subnet limit changes are scheduled behind an announcement delay.
+    pub const SCHEDULED_SUBNET_LIMIT: u128 = 1 << 9;
+    /// Subnet owners can pause epochs with `schedule_maintenance`.
+    pub const MAINTENANCE_WINDOWS: u128 = 1 << 10;
+    /// Pallet errors map to the stable codes of `Error::error_code`.
+    pub const STABLE_ERROR_CODES: u128 = 1 << 11;
+    /// Hotkeys can anchor arbitrary per-subnet commitments with `set_commitment`.
+    pub const MINER_COMMITMENTS: u128 = 1 << 12;
+    /// `get_neurons_by_ip` is exposed by `NeuronInfoRuntimeApi`.
+    pub const NEURONS_BY_IP: u128 = 1 << 13;
+    /// Subnets can store sum-normalized weights with `SUM_NORMALIZED_WEIGHTS`.
+    pub const SUM_NORMALIZED_WEIGHTS: u128 = 1 << 14;
+    /// Retired in 9.0.0, `simulate_root_weight_change` moved to `SubnetInfoRuntimeApi`.
+    pub const ROOT_WEIGHT_SIMULATION: u128 = 1 << 15;
+    /// Subnet owners can delegate owner-gated calls to an operator hotkey.
+    pub const SUBNET_OWNER_HOTKEY: u128 = 1 << 16;
+    /// `get_weights_sparse`/`get_bonds_sparse` are exposed by `SubnetInfoRuntimeApi`.
+    pub const SPARSE_MATRICES: u128 = 1 << 17;
+    /// Coldkeys can `recycle` tokens into a subnet's emission pool or `burn` them.
+    pub const RECYCLING: u128 = 1 << 18;
+    /// `dump_subnet_state` is exposed by `SubnetInfoRuntimeApi`.
+    pub const SUBNET_STATE_DUMP: u128 = 1 << 19;
+    /// `get_next_weight_set_block` is exposed by `RateLimitInfoRuntimeApi`.
+    pub const NEXT_WEIGHT_SET_BLOCK: u128 = 1 << 20;
+    /// Subnets can cut validators losing their permit from dividends with `RESET_BONDS_ON_PERMIT_LOSS`.
+    pub const RESET_BONDS_ON_PERMIT_LOSS: u128 = 1 << 21;
+    /// `get_coldkey_emission_summary` is exposed by `StakeInfoRuntimeApi`.
+    pub const COLDKEY_EMISSION_SUMMARY: u128 = 1 << 22;
+    /// Netuids can be reserved and auctioned with `bid_on_netuid` and `claim_netuid`.
+    pub const NETUID_AUCTIONS: u128 = 1 << 23;
+    /// Governance can ban hotkeys, whose stake is then returned to the staking coldkeys.
+    pub const HOTKEY_BANS: u128 = 1 << 24;
+    /// `get_stake_distribution` is exposed by `StakeInfoRuntimeApi`.
+    pub const STAKE_DISTRIBUTION: u128 = 1 << 25;
+    /// `get_axons` leaves out axons not served again within the subnet's `AxonTTLBlocks`.
+    pub const AXON_TTL: u128 = 1 << 26;
+    /// Hotkeys can authorize a separate key for their weights calls with `authorize_weights_key`.
+    pub const WEIGHTS_KEYS: u128 = 1 << 27;
+    /// Subnets below `UtilizationFloor` have `LowUtilizationBurn` of their emission burned.
+    pub const LOW_UTILIZATION_BURN: u128 = 1 << 28;
+    /// Subnet owners can schedule gradual `MaxAllowedUids` growth, taken by `block_step`.
+    pub const MAX_ALLOWED_UIDS_GROWTH: u128 = 1 << 29;
+    /// `root_register_and_stake` stakes and registers to the root network atomically.
+    pub const ROOT_REGISTER_AND_STAKE: u128 = 1 << 30;
+    /// Retired in 9.0.0, `get_storage_stats` moved to `SubnetInfoRuntimeApi`.
+    pub const STORAGE_STATS: u128 = 1 << 31;
+    /// Hotkey emission accrues and is paid out every `HotkeyEmissionTempo` blocks.
+    pub const HOTKEY_EMISSION_TEMPO: u128 = 1 << 32;
+    /// `PruningImminent` warns the next pruning candidate of a full subnet a tempo ahead.
+    pub const PRUNING_IMMINENT: u128 = 1 << 33;
+    /// Permitted validators can pause their subnet's epochs with `signal_epoch_pause`.
+    pub const EPOCH_PAUSE_SIGNALS: u128 = 1 << 34;
+    /// `set_weights_with_metadata`/`reveal_weights_with_metadata` store a provenance hash,
+    /// exposed as `weights_metadata_hash` in `NeuronInfo`.
+    pub const WEIGHTS_METADATA_HASH: u128 = 1 << 35;
+    /// Adding and removing stake charges the stake fee, previewed by `get_stake_fee` on
+    /// `StakeInfoRuntimeApi`.
+    pub const STAKE_FEES: u128 = 1 << 36;
+    /// Subnet owners set a unique token symbol and a token name, exposed by
+    /// `get_subnet_info_v2` and as `registration_symbols` in `DelegateInfo`.
+    pub const SUBNET_TOKEN_METADATA: u128 = 1 << 37;
+    /// Raising MinAllowedWeights sweeps the existing weight rows over the following blocks,
+    /// clearing those below the new minimum.
+    pub const WEIGHTS_SWEEP: u128 = 1 << 38;
+    /// `notify_if_stake_below` subscribes a coldkey to StakeThresholdBreached.
+    pub const STAKE_THRESHOLD_ALERTS: u128 = 1 << 39;
+    /// Every epoch records a merkle root over the subnet's stakes, incentives and dividends,
+    /// returned by `get_epoch_snapshots` on `SubnetInfoRuntimeApi`.
+    pub const EPOCH_SNAPSHOTS: u128 = 1 << 40;
+    /// MaxMinerUids caps the uids without a validator permit, separately from
+    /// MaxAllowedValidators.
+    pub const MINER_UID_CAP: u128 = 1 << 41;
+    /// `get_all_subnet_netuids` on `SubnetInfoRuntimeApi` returns the existing netuids in
+    /// ascending order.
+    pub const SUBNET_NETUIDS: u128 = 1 << 42;
+    /// Retired in 9.0.0, `project_network_burn_cost` moved to `SubnetInfoRuntimeApi`.
+    pub const LOCK_COST_PROJECTION: u128 = 1 << 43;
+    /// `rotate_subnet_owner_key` transfers a subnet to a coldkey proving possession of its key.
+    pub const OWNER_KEY_ROTATION: u128 = 1 << 44;
+    /// The root epoch continues over the next blocks once it exceeds RootEpochBlockBudget.
+    pub const ROOT_EPOCH_SPLIT: u128 = 1 << 45;
+    /// `get_pending_unstakes` on `StakeInfoRuntimeApi` lists the in-flight stake operations of
+    /// a coldkey.
+    pub const PENDING_UNSTAKES: u128 = 1 << 46;
+    /// BootstrapIncentiveFloor guarantees uids in their first epoch a share of the server
+    /// emission.
+    pub const BOOTSTRAP_INCENTIVE: u128 = 1 << 47;
+    /// Retired in 9.0.0, `get_top_validators` and `get_root_voters` moved to
+    /// `DelegateInfoRuntimeApi`.
+    pub const VALIDATOR_SET_EXPORT: u128 = 1 << 48;
+    /// `associate_evm_key` links a hotkey to an EVM address proven by its signature, listed by
+    /// `get_evm_key_associations` on `SubnetInfoRuntimeApi`.
+    pub const EVM_KEY_ASSOCIATION: u128 = 1 << 49;
+    /// `set_emission_split` lets subnet owners split their emission between owner, miners and
+    /// validators.
+    pub const EMISSION_SPLIT: u128 = 1 << 50;
+    /// `sudo_set_weights_paused` lets subnet owners pause weight setting on their subnet for a
+    /// bounded number of blocks.
+    pub const WEIGHTS_PAUSE: u128 = 1 << 51;
+    /// Subnet owners can set the tempo, adjustment interval and registration limits of their
+    /// subnet within bounds, rate limited by `OwnerHyperparamRateLimit`.
+    pub const OWNER_HYPERPARAMS: u128 = 1 << 52;
+    /// The network lock cost is raised by `NetworkLockCostMultiplier` on each registration
+    /// instead of doubling.
+    pub const NETWORK_LOCK_COST_MULTIPLIER: u128 = 1 << 53;
+    /// Subnet owners put hyperparameter changes to stake-weighted referenda of their
+    /// validators, applied by block_step when they pass.
+    pub const SUBNET_REFERENDA: u128 = 1 << 54;
+    /// Removed netuids leave a tombstone and can be held back from reassignment for
+    /// `NetuidQuarantinePeriod` blocks.
+    pub const NETUID_QUARANTINE: u128 = 1 << 55;
+    /// Subnets can require weights to be set for their next epoch by index with
+    /// `TARGET_EPOCH_WEIGHTS`, through `set_weights_for_epoch`.
+    pub const TARGET_EPOCH_WEIGHTS: u128 = 1 << 56;
+    /// Permitted validators attest axon reachability with `attest_axon`, scored per uid as
+    /// `axon_reachability` in `NeuronInfo`.
+    pub const AXON_REACHABILITY: u128 = 1 << 57;
+    /// `get_root_weights` and `get_root_emission_info` on `SubnetInfoRuntimeApi` export the
+    /// dense root weights, the emission and locked tokens of each subnet, and the blocks
+    /// until the next root epoch.
+    pub const ROOT_EMISSION_INFO: u128 = 1 << 58;
+    /// MinStakeToRegister requires hotkeys to already hold a minimum stake to register on
+    /// a subnet, through both PoW and burned registration.
+    pub const MIN_STAKE_TO_REGISTER: u128 = 1 << 59;
+    /// `set_children` lets a hotkey attribute proportions of its stake on a subnet to child
+    /// keys in the epochs, after a cooldown.
+    pub const CHILD_KEYS: u128 = 1 << 60;
+    /// `sudo_reset_bonds` clears the bonds of a subnet in chunks across blocks, holding back
+    /// its epochs until done.
+    pub const BONDS_RESET: u128 = 1 << 61;
+    /// EmissionHistory keeps the emission values of each subnet set by the last
+    /// EMISSION_HISTORY_LENGTH root epochs.
+    pub const EMISSION_HISTORY: u128 = 1 << 62;
+    /// Owners can wipe the serving info and weights of a neuron with `clear_neuron`.
+    pub const CLEAR_NEURON: u128 = 1 << 63;
+    /// `schedule_swap_coldkey` swaps a coldkey after the arbitration period, and governance
+    /// can settle or cancel a scheduled swap with `arbitrate_coldkey_swap`.
+    pub const COLDKEY_SWAP_ARBITRATION: u128 = 1 << 64;
+    /// Senate seats follow root stake: root validators take the seats of members off the
+    /// root network or with less stake, on registration or with `adjust_senate`.
+    pub const SENATE_STAKE_RANKING: u128 = 1 << 65;
+    /// `get_pow_stats` reports the difficulty solved by the PoW registrations of a subnet,
+    /// in total and on average over its last adjustment intervals.
+    pub const POW_STATS: u128 = 1 << 66;
+    /// Nominators holding `TakeCapThreshold` of a delegate's stake can cap its take for
+    /// `TakeCapDuration` blocks with `vote_delegate_take_cap`.
+    pub const DELEGATE_TAKE_CAP: u128 = 1 << 67;
+    /// `batch_set_weights` sets weights on several subnets at once, reporting failing entries
+    /// with `WeightsBatchEntryFailed`.
+    pub const BATCH_SET_WEIGHTS: u128 = 1 << 68;
+    /// Each root epoch commits the emission values it sets to `RootEpochCommitment`, and
+    /// `get_emission_proof` proves the emission of a subnet against it.
+    pub const EMISSION_PROOFS: u128 = 1 << 69;
+    /// `serve_axon_tls` stores the TLS certificate of an axon in `NeuronCertificates`, served
+    /// by `get_neuron_certificate`.
+    pub const AXON_TLS: u128 = 1 << 70;
+    /// `set_identity` and `set_subnet_identity` publish a `ChainIdentity` for a coldkey or
+    /// subnet in `Identities` and `SubnetIdentities`.
+    pub const IDENTITIES: u128 = 1 << 71;
+    /// Signed calls of the pallet are counted by outcome in `CallCounts` and
+    /// `CallFailuresByError`, served by `get_call_metrics` on `SubnetInfoRuntimeApi`.
+    pub const CALL_METRICS: u128 = 1 << 72;
+    /// `burned_root_register` registers to the root network by burning its Burn.
+    pub const BURNED_ROOT_REGISTRATION: u128 = 1 << 73;
+    /// Subnets can set the PERSISTENT_UIDS flag, so a pruned hotkey reclaims its uid from
+    /// `RetiredUids` when it registers again.
+    pub const PERSISTENT_UIDS: u128 = 1 << 74;
+    /// block_step reports registration cost adjustments with `BurnAdjusted` and
+    /// `DifficultyAdjusted`, and `get_adjustment_info` serves the state of the interval.
+    pub const ADJUSTMENT_INFO: u128 = 1 << 75;
+    /// On runtimes built with `pow-faucet`, a coldkey can call `faucet` once per
+    /// `FaucetRateLimit` blocks.
+    pub const FAUCET_RATE_LIMIT: u128 = 1 << 76;
+    /// Governance can turn on root weights safe mode, rejecting all-zero root weights and
+    /// root weights of large validators reaching fewer than `RootWeightsMinSpread` subnets.
+    pub const ROOT_WEIGHTS_SAFE_MODE: u128 = 1 << 77;
+    /// The root epoch caps the share of root stake each validator ranks subnets with at
+    /// `RootStakeCap`, spreading the excess over the other validators.
+    pub const ROOT_STAKE_CAP: u128 = 1 << 78;
+    /// Hotkeys can remove their own weight rows on several subnets with `purge_my_weights`.
+    pub const PURGE_MY_WEIGHTS: u128 = 1 << 79;
+    /// Neurons can leave a subnet with `unregister`, freeing their uid.
+    pub const VOLUNTARY_UNREGISTRATION: u128 = 1 << 80;
+    /// Governance can scale the emission of a subnet set by the root epoch with its
+    /// `EmissionMultiplier`.
+    pub const EMISSION_MULTIPLIER: u128 = 1 << 81;
+    /// Subnet owners can hand over their subnet with `transfer_subnet_ownership`, taken over
+    /// by the new owner with `accept_subnet_ownership`.
+    pub const SUBNET_OWNERSHIP_TRANSFER: u128 = 1 << 82;
+    /// Anyone can return nominations below `NominatorMinRequiredStake` to their nominators
+    /// with `clear_small_nominations`.
+    pub const CLEAR_SMALL_NOMINATIONS: u128 = 1 << 83;
+    /// `get_coldkey_value` is exposed by `StakeInfoRuntimeApi`.
+    pub const COLDKEY_VALUE: u128 = 1 << 84;
+    /// `simulate_root_epoch` is exposed by `SubnetInfoRuntimeApi`.
+    pub const ROOT_EPOCH_SIMULATION: u128 = 1 << 85;
+    /// Registrants can declare their role with `burned_register_with_role`, the last
+    /// registration of a block going to the role the subnet is short of.
+    pub const REGISTRATION_ROLES: u128 = 1 << 86;
+    /// Root validators can let another account call `set_root_weights` for their hotkey with
+    /// `authorize_weight_setter`.
+    pub const WEIGHT_SETTERS: u128 = 1 << 87;
+    /// `registrations` in `DelegateInfo` pairs each netuid with the validator permit, rank
+    /// and emission of the delegate there.
+    pub const DELEGATE_REGISTRATION_STATUS: u128 = 1 << 88;
+    /// Serving, delegate take changes, root weights and network registration are each rate
+    /// limited per account by `TransactionTypeRateLimit`.
+    pub const TRANSACTION_TYPE_RATE_LIMITS: u128 = 1 << 89;
+    /// `add_stake_locked` locks stake for boosted dividends, see `StakeLockBoosts`.
+    pub const STAKE_LOCKS: u128 = 1 << 90;
+    /// While `UnstakeCooldown` is set, removed stake waits in `PendingWithdrawals` until
+    /// `claim_unstaked`, and `get_pending_unstakes` reports it.
+    pub const UNSTAKE_COOLDOWN: u128 = 1 << 91;
+    /// `add_stake_multiple` and `remove_stake_multiple` stake to and unstake from several
+    /// hotkeys atomically.
+    pub const STAKE_BATCHES: u128 = 1 << 92;
+    /// `sudo_migrate_subnet` moves a subnet and its storage to another netuid over several
+    /// blocks.
+    pub const SUBNET_MIGRATION: u128 = 1 << 93;
+    /// Every replaced neuron is recorded in a per-subnet log returned by `get_eviction_log`
+    /// on `SubnetInfoRuntimeApi`.
+    pub const EVICTION_LOG: u128 = 1 << 94;
+    /// Retired in 9.0.0, `get_weights_min_stake` moved to `StakeInfoRuntimeApi`.
+    pub const WEIGHTS_MIN_STAKE_API: u128 = 1 << 95;
+    /// WeightsPrecision stores the weights of a subnet quantized to fewer bits.
+    pub const WEIGHTS_QUANTIZATION: u128 = 1 << 96;
+    /// The take of a delegate whose validator trust stays below a threshold for several root
+    /// tempos is reduced until its trust recovers.
+    pub const DELEGATE_UNDERPERFORMANCE: u128 = 1 << 97;
+    /// `get_neurons_paginated` and `get_neurons_lite_paginated` on `NeuronInfoRuntimeApi`
+    /// return the neurons of a subnet in bounded pages.
+    pub const NEURONS_PAGINATION: u128 = 1 << 98;
+    /// Subnet owners set the immunity period up to MaxImmunityPeriod, once per
+    /// OwnerHyperparamRateLimit blocks.
+    pub const OWNER_IMMUNITY_PERIOD: u128 = 1 << 99;
+    /// The epoch clips the active stake of each validator to the MaxStakeWeightFraction of
+    /// its subnet.
+    pub const MAX_STAKE_WEIGHT_FRACTION: u128 = 1 << 100;
+    /// `get_all_epoch_schedules` on `SubnetInfoRuntimeApi` returns the tempo, phase and blocks
+    /// until the next epoch of every subnet.
+    pub const EPOCH_SCHEDULES: u128 = 1 << 101;
+    /// `DelegateInfo` carries the nominator count and delegated stake of each delegate, and
+    /// lists its nominators only from `get_delegate`.
+    pub const DELEGATE_INDEXES: u128 = 1 << 102;
+    /// Subnets can set the REGISTRATION_LOTTERY flag, so a registration on a full subnet
+    /// replaces a neuron drawn among those past their immunity period.
+    pub const REGISTRATION_LOTTERY: u128 = 1 << 103;
+    /// Subnets can set the UNMASKED_SELF_WEIGHTS flag, so the epoch counts self-weights.
+    pub const UNMASKED_SELF_WEIGHTS: u128 = 1 << 104;
+    /// Subnets can set the BURN_EMA flag, so the burn follows the registration pressure every
+    /// adjustment interval.
+    pub const BURN_EMA: u128 = 1 << 105;
+    /// Features of subsystems left out of this build by the `serving`, `identity` and
+    /// `commitments` cargo features.
+    pub const COMPILED_OUT: u128 = if cfg!(feature = "serving") {
+        0
+    } else {
+        AXON_REPLICAS | AXON_TLS
+    } | if cfg!(feature = "identity") {
+        0
+    } else {
+        IDENTITIES
+    } | if cfg!(feature = "commitments") {
+        0
+    } else {
+        MINER_COMMITMENTS
+    };
+    /// Every feature supported by this runtime.
+    pub const ALL: u128 = (COMMIT_REVEAL_WEIGHTS
+        | LIQUID_ALPHA
+        | NEURON_INFO_LITE
+        | STAKE_INFO
+        | SUBNET_FEATURE_FLAGS
+        | RATE_LIMIT_INFO
+        | SUBNET_EVENT_TOPICS
+        | AXON_REPLICAS
+        | FEE_RESERVE
+        | SCHEDULED_SUBNET_LIMIT
+        | MAINTENANCE_WINDOWS
+        | STABLE_ERROR_CODES
+        | MINER_COMMITMENTS
+        | NEURONS_BY_IP
+        | SUM_NORMALIZED_WEIGHTS
+        | SUBNET_OWNER_HOTKEY
+        | SPARSE_MATRICES
+        | RECYCLING
+        | SUBNET_STATE_DUMP
+        | NEXT_WEIGHT_SET_BLOCK
+        | RESET_BONDS_ON_PERMIT_LOSS
+        | COLDKEY_EMISSION_SUMMARY
+        | NETUID_AUCTIONS
+        | HOTKEY_BANS
+        | STAKE_DISTRIBUTION
+        | AXON_TTL
+        | WEIGHTS_KEYS
+        | LOW_UTILIZATION_BURN
+        | MAX_ALLOWED_UIDS_GROWTH
+        | ROOT_REGISTER_AND_STAKE
+        | HOTKEY_EMISSION_TEMPO
+        | PRUNING_IMMINENT
+        | EPOCH_PAUSE_SIGNALS
+        | WEIGHTS_METADATA_HASH
+        | STAKE_FEES
+        | SUBNET_TOKEN_METADATA
+        | WEIGHTS_SWEEP
+        | STAKE_THRESHOLD_ALERTS
+        | EPOCH_SNAPSHOTS
+        | MINER_UID_CAP
+        | SUBNET_NETUIDS
+        | OWNER_KEY_ROTATION
+        | ROOT_EPOCH_SPLIT
+        | PENDING_UNSTAKES
+        | BOOTSTRAP_INCENTIVE
+        | EVM_KEY_ASSOCIATION
+        | EMISSION_SPLIT
+        | WEIGHTS_PAUSE
+        | OWNER_HYPERPARAMS
+        | NETWORK_LOCK_COST_MULTIPLIER
+        | SUBNET_REFERENDA
+        | NETUID_QUARANTINE
+        | TARGET_EPOCH_WEIGHTS
+        | AXON_REACHABILITY
+        | ROOT_EMISSION_INFO
+        | MIN_STAKE_TO_REGISTER
+        | CHILD_KEYS
+        | BONDS_RESET
+        | EMISSION_HISTORY
+        | CLEAR_NEURON
+        | COLDKEY_SWAP_ARBITRATION
+        | SENATE_STAKE_RANKING
+        | POW_STATS
+        | DELEGATE_TAKE_CAP
+        | BATCH_SET_WEIGHTS
+        | EMISSION_PROOFS
+        | AXON_TLS
+        | IDENTITIES
+        | CALL_METRICS
+        | BURNED_ROOT_REGISTRATION
+        | PERSISTENT_UIDS
+        | ADJUSTMENT_INFO
+        | FAUCET_RATE_LIMIT
+        | ROOT_WEIGHTS_SAFE_MODE
+        | ROOT_STAKE_CAP
+        | PURGE_MY_WEIGHTS
+        | VOLUNTARY_UNREGISTRATION
+        | EMISSION_MULTIPLIER
+        | SUBNET_OWNERSHIP_TRANSFER
+        | CLEAR_SMALL_NOMINATIONS
+        | COLDKEY_VALUE
+        | ROOT_EPOCH_SIMULATION
+        | REGISTRATION_ROLES
+        | WEIGHT_SETTERS
+        | DELEGATE_REGISTRATION_STATUS
+        | TRANSACTION_TYPE_RATE_LIMITS
+        | STAKE_LOCKS
+        | UNSTAKE_COOLDOWN
+        | STAKE_BATCHES
+        | SUBNET_MIGRATION
+        | EVICTION_LOG
+        | WEIGHTS_QUANTIZATION
+        | DELEGATE_UNDERPERFORMANCE
+        | NEURONS_PAGINATION
+        | OWNER_IMMUNITY_PERIOD
+        | MAX_STAKE_WEIGHT_FRACTION
+        | EPOCH_SCHEDULES
+        | DELEGATE_INDEXES
+        | REGISTRATION_LOTTERY
+        | UNMASKED_SELF_WEIGHTS
+        | BURN_EMA)
+        & !COMPILED_OUT;
+}
+
+/// Version of the custom runtime APIs. The major version is bumped when an existing
+/// response encoding changes, the minor version when a feature bit is added.
+pub const RUNTIME_API_VERSION: (u16, u16, u16) = (9, 1, 0);
+
+#[freeze_struct("74338281dfb0fb6b")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct RuntimeFeatures {
+    pub major: Compact<u16>,
+    pub minor: Compact<u16>,
+    pub patch: Compact<u16>,
+    pub features: Compact<u128>,
+}
+
+impl RuntimeFeatures {
+    /// Whether every bit of `features` is supported.
+    pub fn supports(&self, features: u128) -> bool {
+        self.features.0 & features == features
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_runtime_features() -> RuntimeFeatures {
+        let (major, minor, patch) = RUNTIME_API_VERSION;
+        RuntimeFeatures {
+            major: major.into(),
+            minor: minor.into(),
+            patch: patch.into(),
+            features: feature::ALL.into(),
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(any(feature = "serving", feature = "commitments"))]
+use frame_support::{pallet_prelude::ConstU32, BoundedVec};
 
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for the extrinsic serve_axon which sets the ip endpoint information for a uid on a network.
@@ -51,6 +53,7 @@ impl<T: Config> Pallet<T> {
     /// * 'ServingRateLimitExceeded':
     ///     - Attempting to set prometheus information withing the rate limit min.
     ///
+    #[cfg(feature = "serving")]
     pub fn do_serve_axon(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -61,6 +64,53 @@ impl<T: Config> Pallet<T> {
         protocol: u8,
         placeholder1: u8,
         placeholder2: u8,
+    ) -> dispatch::DispatchResult {
+        Self::do_serve_axon_multi(
+            origin,
+            netuid,
+            0,
+            version,
+            ip,
+            port,
+            ip_type,
+            protocol,
+            placeholder1,
+            placeholder2,
+        )
+    }
+
+    /// ---- The implementation for the extrinsic serve_axon_multi which sets one entry of the
+    /// axon set for a uid on a network. Index 0 is the primary axon stored in `Axons`, the
+    /// following indices are stored in order in `AxonReplicas`.
+    ///
+    /// # Args:
+    /// * 'index' (u8):
+    ///     - The position in the axon set, at most the current size of the set.
+    ///
+    /// * The remaining args are as for `do_serve_axon`.
+    ///
+    /// # Event:
+    /// * AxonServed;
+    ///     - On successfully serving the axon info.
+    ///
+    /// # Raises:
+    /// * 'InvalidAxonIndex':
+    ///     - The index is past the end of the set, or the set is full.
+    ///
+    /// * The errors raised by `do_serve_axon`.
+    ///
+    #[cfg(feature = "serving")]
+    pub fn do_serve_axon_multi(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        index: u8,
+        version: u32,
+        ip: u128,
+        port: u16,
+        ip_type: u8,
+        protocol: u8,
+        placeholder1: u8,
+        placeholder2: u8,
     ) -> dispatch::DispatchResult {
         // We check the callers (hotkey) signature.
         let hotkey_id = ensure_signed(origin)?;
@@ -78,13 +128,34 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InvalidIpAddress
         );
 
-        // Get the previous axon information.
-        let mut prev_axon = Self::get_axon_info(netuid, &hotkey_id);
+        ensure!(
+            u32::from(index) < MAX_AXONS_PER_NEURON,
+            Error::<T>::InvalidAxonIndex
+        );
+
+        // Get the previous axon information at this index, entries can only be appended.
+        let prev_ips = Self::get_axon_ips(netuid, &hotkey_id);
+        let mut replicas = AxonReplicas::<T>::get(netuid, &hotkey_id);
+        let replica_index = usize::from(index).checked_sub(1);
+        let mut prev_axon = match replica_index {
+            None => Self::get_axon_info(netuid, &hotkey_id),
+            Some(i) => {
+                ensure!(
+                    Self::has_axon_info(netuid, &hotkey_id) && i <= replicas.len(),
+                    Error::<T>::InvalidAxonIndex
+                );
+                replicas.get(i).cloned().unwrap_or_default()
+            }
+        };
         let current_block: u64 = Self::get_current_block_as_u64();
         ensure!(
             Self::axon_passes_rate_limit(netuid, &prev_axon, current_block),
             Error::<T>::ServingRateLimitExceeded
         );
+        ensure!(
+            Self::passes_rate_limit(&hotkey_id, TransactionType::Serve),
+            Error::<T>::TransactionTypeRateLimitExceeded
+        );
 
         // We insert the axon meta.
         prev_axon.block = Self::get_current_block_as_u64();
@@ -103,16 +174,87 @@ impl<T: Config> Pallet<T> {
             axon_validated.err().unwrap_or(Error::<T>::InvalidPort)
         );
 
-        Axons::<T>::insert(netuid, hotkey_id.clone(), prev_axon);
+        match replica_index {
+            None => Axons::<T>::insert(netuid, hotkey_id.clone(), prev_axon),
+            Some(i) => {
+                if let Some(replica) = replicas.get_mut(i) {
+                    *replica = prev_axon;
+                } else {
+                    replicas
+                        .try_push(prev_axon)
+                        .map_err(|_| Error::<T>::InvalidAxonIndex)?;
+                }
+                AxonReplicas::<T>::insert(netuid, hotkey_id.clone(), replicas);
+            }
+        }
+        Self::reindex_axon_ips(netuid, &hotkey_id, &prev_ips);
+        Self::set_last_transaction_type_block(&hotkey_id, TransactionType::Serve);
 
         // We deposit axon served event.
         log::info!("AxonServed( hotkey:{:?} ) ", hotkey_id.clone());
-        Self::deposit_event(Event::AxonServed(netuid, hotkey_id));
+        Self::deposit_subnet_event(&[netuid], Event::AxonServed(netuid, hotkey_id));
 
         // Return is successful dispatch.
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic serve_axon_tls which serves the primary axon
+    /// and stores the TLS public key or certificate fingerprint it presents.
+    ///
+    /// # Args:
+    /// * 'certificate' (Vec<u8>):
+    ///     - The TLS public key or certificate fingerprint of the axon.
+    ///
+    /// * The remaining args are as for `do_serve_axon`.
+    ///
+    /// # Event:
+    /// * NeuronCertificateSet;
+    ///     - On successfully storing the certificate.
+    ///
+    /// # Raises:
+    /// * 'InvalidCertificate':
+    ///     - The certificate is empty or longer than MAX_NEURON_CERTIFICATE_BYTES.
+    ///
+    /// * The errors raised by `do_serve_axon`.
+    ///
+    #[cfg(feature = "serving")]
+    pub fn do_serve_axon_tls(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        version: u32,
+        ip: u128,
+        port: u16,
+        ip_type: u8,
+        protocol: u8,
+        placeholder1: u8,
+        placeholder2: u8,
+        certificate: Vec<u8>,
+    ) -> dispatch::DispatchResult {
+        let hotkey_id = ensure_signed(origin.clone())?;
+        ensure!(!certificate.is_empty(), Error::<T>::InvalidCertificate);
+        let certificate: BoundedVec<u8, ConstU32<MAX_NEURON_CERTIFICATE_BYTES>> = certificate
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidCertificate)?;
+
+        Self::do_serve_axon(
+            origin,
+            netuid,
+            version,
+            ip,
+            port,
+            ip_type,
+            protocol,
+            placeholder1,
+            placeholder2,
+        )?;
+        NeuronCertificates::<T>::insert(netuid, &hotkey_id, certificate);
+
+        log::info!("NeuronCertificateSet( hotkey:{:?} ) ", hotkey_id.clone());
+        Self::deposit_subnet_event(&[netuid], Event::NeuronCertificateSet(netuid, hotkey_id));
+
+        Ok(())
+    }
+
     /// ---- The implementation for the extrinsic serve_prometheus.
     ///
     /// # Args:
@@ -154,6 +296,7 @@ impl<T: Config> Pallet<T> {
     /// * 'ServingRateLimitExceeded':
     ///     - Attempting to set prometheus information withing the rate limit min.
     ///
+    #[cfg(feature = "serving")]
     pub fn do_serve_prometheus(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -185,6 +328,10 @@ impl<T: Config> Pallet<T> {
             Self::prometheus_passes_rate_limit(netuid, &prev_prometheus, current_block),
             Error::<T>::ServingRateLimitExceeded
         );
+        ensure!(
+            Self::passes_rate_limit(&hotkey_id, TransactionType::Serve),
+            Error::<T>::TransactionTypeRateLimitExceeded
+        );
 
         // We insert the prometheus meta.
         prev_prometheus.block = Self::get_current_block_as_u64();
@@ -202,19 +349,202 @@ impl<T: Config> Pallet<T> {
 
         // Insert new prometheus data
         Prometheus::<T>::insert(netuid, hotkey_id.clone(), prev_prometheus);
+        Self::set_last_transaction_type_block(&hotkey_id, TransactionType::Serve);
 
         // We deposit prometheus served event.
         log::info!("PrometheusServed( hotkey:{:?} ) ", hotkey_id.clone());
-        Self::deposit_event(Event::PrometheusServed(netuid, hotkey_id));
+        Self::deposit_subnet_event(&[netuid], Event::PrometheusServed(netuid, hotkey_id));
 
         // Return is successful dispatch.
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic set_commitment.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the caller's hotkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet the commitment is made on.
+    ///
+    /// * 'commitment' (Vec<u8>):
+    ///     - The committed bytes, empty to clear the commitment.
+    ///
+    /// # Event:
+    /// * CommitmentSet;
+    ///     - On successfully setting the commitment.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on the subnet.
+    ///
+    /// * 'CommitmentTooLarge':
+    ///     - The commitment is larger than the subnet's limit.
+    ///
+    /// * 'CommitmentRateLimitExceeded':
+    ///     - The previous commitment is more recent than the subnet's rate limit.
+    ///
+    #[cfg(feature = "commitments")]
+    pub fn do_set_commitment(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        commitment: Vec<u8>,
+    ) -> dispatch::DispatchResult {
+        // We check the callers (hotkey) signature.
+        let hotkey_id = ensure_signed(origin)?;
+
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey_id),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+
+        // Check the size against the subnet's limit, which is itself below MAX_COMMITMENT_BYTES.
+        ensure!(
+            commitment.len() <= Self::get_max_commitment_size(netuid) as usize,
+            Error::<T>::CommitmentTooLarge
+        );
+        let commitment: BoundedVec<u8, ConstU32<MAX_COMMITMENT_BYTES>> = commitment
+            .try_into()
+            .map_err(|_| Error::<T>::CommitmentTooLarge)?;
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            Self::commitment_passes_rate_limit(netuid, &hotkey_id, current_block),
+            Error::<T>::CommitmentRateLimitExceeded
+        );
+
+        if commitment.is_empty() {
+            Commitments::<T>::remove(netuid, &hotkey_id);
+        } else {
+            Commitments::<T>::insert(netuid, &hotkey_id, commitment);
+        }
+        LastCommitmentBlock::<T>::insert(netuid, &hotkey_id, current_block);
+
+        log::info!("CommitmentSet( hotkey:{:?} ) ", hotkey_id.clone());
+        Self::deposit_subnet_event(&[netuid], Event::CommitmentSet(netuid, hotkey_id));
+
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic clear_neuron.
+    ///
+    /// Wipes the axons, prometheus endpoint and weight row of the hotkey on a subnet without
+    /// deregistering it, for an owner decommissioning the infrastructure behind it.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the coldkey owning the hotkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet the neuron is registered on.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey of the neuron.
+    ///
+    /// # Event:
+    /// * NeuronCleared;
+    ///     - On successfully clearing the neuron.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey is not owned by the calling coldkey.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on the subnet.
+    ///
+    /// * 'ServingRateLimitExceeded':
+    ///     - The neuron was cleared more recently than the subnet's serving rate limit.
+    ///
+    pub fn do_clear_neuron(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)
+            .map_err(|_| Error::<T>::HotKeyNotRegisteredInSubNet)?;
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            Self::neuron_clear_passes_rate_limit(netuid, &hotkey, current_block),
+            Error::<T>::ServingRateLimitExceeded
+        );
+
+        let prev_ips = Self::get_axon_ips(netuid, &hotkey);
+        Axons::<T>::remove(netuid, &hotkey);
+        AxonReplicas::<T>::remove(netuid, &hotkey);
+        NeuronCertificates::<T>::remove(netuid, &hotkey);
+        Self::reindex_axon_ips(netuid, &hotkey, &prev_ips);
+        Prometheus::<T>::remove(netuid, &hotkey);
+        Weights::<T>::remove(netuid, uid);
+        WeightsMetadataHash::<T>::remove(netuid, uid);
+        WeightsTargetEpoch::<T>::remove(netuid, uid);
+        LastNeuronClearBlock::<T>::insert(netuid, &hotkey, current_block);
+
+        log::info!("NeuronCleared( netuid:{:?}, hotkey:{:?} ) ", netuid, hotkey);
+        Self::deposit_subnet_event(&[netuid], Event::NeuronCleared(netuid, hotkey));
+
+        Ok(())
+    }
+
     /********************************
      --==[[  Helper functions   ]]==--
     *********************************/
 
+    pub fn neuron_clear_passes_rate_limit(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        current_block: u64,
+    ) -> bool {
+        let rate_limit: u64 = Self::get_serving_rate_limit(netuid);
+        let last_clear: u64 = LastNeuronClearBlock::<T>::get(netuid, hotkey);
+        rate_limit == 0 || last_clear == 0 || current_block.saturating_sub(last_clear) >= rate_limit
+    }
+
+    #[cfg(feature = "commitments")]
+    pub fn commitment_passes_rate_limit(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        current_block: u64,
+    ) -> bool {
+        let rate_limit: u64 = Self::get_commitment_rate_limit(netuid);
+        let last_commitment = LastCommitmentBlock::<T>::get(netuid, hotkey);
+        rate_limit == 0
+            || last_commitment == 0
+            || current_block.saturating_sub(last_commitment) >= rate_limit
+    }
+
+    #[cfg(feature = "commitments")]
+    pub fn get_commitment(netuid: u16, hotkey: &T::AccountId) -> Option<Vec<u8>> {
+        Commitments::<T>::get(netuid, hotkey).map(|commitment| commitment.into_inner())
+    }
+
+    /// Returns the TLS certificate served by the neuron `uid` of `netuid`, None if the uid is
+    /// not registered or serves none.
+    pub fn get_neuron_certificate(netuid: u16, uid: u16) -> Option<Vec<u8>> {
+        let hotkey = Self::get_hotkey_for_net_and_uid(netuid, uid).ok()?;
+        NeuronCertificates::<T>::get(netuid, hotkey).map(|certificate| certificate.into_inner())
+    }
+
     pub fn axon_passes_rate_limit(
         netuid: u16,
         prev_axon_info: &AxonInfoOf,
@@ -239,6 +569,45 @@ impl<T: Config> Pallet<T> {
         Axons::<T>::contains_key(netuid, hotkey)
     }
 
+    /// Returns the full axon set of a hotkey on a network, primary axon first.
+    pub fn get_axons_for_hotkey(netuid: u16, hotkey: &T::AccountId) -> Vec<AxonInfoOf> {
+        let Some(primary) = Axons::<T>::get(netuid, hotkey) else {
+            return Vec::new();
+        };
+        let mut axons = vec![primary];
+        axons.extend(AxonReplicas::<T>::get(netuid, hotkey));
+        axons
+    }
+
+    /// Returns true if the axon has not been served again within the subnet's AxonTTLBlocks.
+    pub fn is_axon_stale(netuid: u16, axon: &AxonInfoOf, current_block: u64) -> bool {
+        let ttl = Self::get_axon_ttl_blocks(netuid);
+        ttl > 0 && current_block.saturating_sub(axon.block) >= ttl
+    }
+
+    /// Returns the distinct ips of the hotkey's axon set on a network.
+    pub fn get_axon_ips(netuid: u16, hotkey: &T::AccountId) -> Vec<u128> {
+        let mut ips: Vec<u128> = Self::get_axons_for_hotkey(netuid, hotkey)
+            .iter()
+            .map(|axon| axon.ip)
+            .collect();
+        ips.sort_unstable();
+        ips.dedup();
+        ips
+    }
+
+    /// Brings `AxonIpIndex` in line with the hotkey's axon set after it changed from
+    /// serving on `prev_ips`.
+    pub fn reindex_axon_ips(netuid: u16, hotkey: &T::AccountId, prev_ips: &[u128]) {
+        let ips = Self::get_axon_ips(netuid, hotkey);
+        for ip in prev_ips.iter().filter(|ip| !ips.contains(ip)) {
+            AxonIpIndex::<T>::remove(ip, (netuid, hotkey.clone()));
+        }
+        for ip in ips {
+            AxonIpIndex::<T>::insert(ip, (netuid, hotkey.clone()), ());
+        }
+    }
+
     pub fn has_prometheus_info(netuid: u16, hotkey: &T::AccountId) -> bool {
         Prometheus::<T>::contains_key(netuid, hotkey)
     }
@@ -0,0 +1,152 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic notify_if_stake_below.
+    ///
+    /// Subscribes the calling coldkey to a StakeThresholdBreached event once the total stake
+    /// of `hotkey` falls below `amount`, replacing its previous threshold on the hotkey. An
+    /// amount of zero cancels the subscription.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     - The hotkey has no account.
+    ///
+    /// * 'TooManyStakeSubscriptions':
+    ///     - The coldkey already holds MAX_STAKE_SUBSCRIPTIONS_PER_COLDKEY subscriptions.
+    ///
+    pub fn do_notify_if_stake_below(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        amount: u64,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        let subscribed = StakeThresholdSubscriptions::<T>::contains_key(&hotkey, &coldkey);
+
+        if amount == 0 {
+            if subscribed {
+                StakeThresholdSubscriptions::<T>::remove(&hotkey, &coldkey);
+                StakeSubscriptionCount::<T>::mutate(&coldkey, |count| count.saturating_dec());
+            }
+        } else {
+            ensure!(
+                Self::hotkey_account_exists(&hotkey),
+                Error::<T>::HotKeyAccountNotExists
+            );
+            if !subscribed {
+                ensure!(
+                    StakeSubscriptionCount::<T>::get(&coldkey)
+                        < MAX_STAKE_SUBSCRIPTIONS_PER_COLDKEY,
+                    Error::<T>::TooManyStakeSubscriptions
+                );
+                StakeSubscriptionCount::<T>::mutate(&coldkey, |count| count.saturating_inc());
+            }
+            StakeThresholdSubscriptions::<T>::insert(&hotkey, &coldkey, amount);
+            StakeAlertHotkeys::<T>::insert(&hotkey, ());
+        }
+
+        log::info!(
+            "StakeThresholdSubscribed( coldkey:{:?}, hotkey:{:?}, amount:{:?} )",
+            coldkey,
+            hotkey,
+            amount
+        );
+        Self::deposit_event(Event::StakeThresholdSubscribed(coldkey, hotkey, amount));
+        Ok(())
+    }
+
+    /// Queues the thresholds of `hotkey` to be checked once its total stake fell.
+    pub fn queue_stake_alert_check(hotkey: &T::AccountId) {
+        if StakeThresholdSubscriptions::<T>::iter_prefix_values(hotkey)
+            .next()
+            .is_some()
+        {
+            StakeAlertHotkeys::<T>::insert(hotkey, ());
+        }
+    }
+
+    /// Checks the thresholds on the hotkeys whose total stake fell, at most
+    /// MAX_STAKE_ALERT_CHECKS_PER_BLOCK subscriptions per block, resuming where the previous
+    /// block stopped. Emits StakeThresholdBreached for every subscription whose hotkey's total
+    /// stake is below its threshold, and clears those subscriptions. Returns the weight used.
+    pub fn check_stake_thresholds() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let mut budget: u32 = MAX_STAKE_ALERT_CHECKS_PER_BLOCK;
+        let mut cursor = StakeAlertCursor::<T>::take();
+
+        while budget > 0 {
+            let Some((hotkey, raw_key)) = cursor.take().or_else(|| {
+                StakeAlertHotkeys::<T>::iter_keys()
+                    .next()
+                    .map(|hotkey| (hotkey, Vec::new()))
+            }) else {
+                break;
+            };
+            let stake = Self::get_total_stake_for_hotkey(&hotkey);
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
+
+            let mut subscriptions = if raw_key.is_empty() {
+                StakeThresholdSubscriptions::<T>::iter_prefix(&hotkey)
+            } else {
+                StakeThresholdSubscriptions::<T>::iter_prefix_from(&hotkey, raw_key)
+            };
+            let mut breached: Vec<(T::AccountId, u64)> = Vec::new();
+            let mut done = true;
+            while budget > 0 {
+                let Some((coldkey, threshold)) = subscriptions.next() else {
+                    break;
+                };
+                budget = budget.saturating_sub(1);
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+                if stake < threshold {
+                    breached.push((coldkey, threshold));
+                }
+            }
+            if budget == 0 {
+                let last_raw_key = subscriptions.last_raw_key().to_vec();
+                if subscriptions.next().is_some() {
+                    done = false;
+                    cursor = Some((hotkey.clone(), last_raw_key));
+                }
+            }
+            drop(subscriptions);
+
+            for (coldkey, threshold) in breached {
+                StakeThresholdSubscriptions::<T>::remove(&hotkey, &coldkey);
+                StakeSubscriptionCount::<T>::mutate(&coldkey, |count| count.saturating_dec());
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+                log::info!(
+                    "StakeThresholdBreached( coldkey:{:?}, hotkey:{:?}, stake:{:?}, threshold:{:?} )",
+                    coldkey,
+                    hotkey,
+                    stake,
+                    threshold
+                );
+                Self::deposit_event(Event::StakeThresholdBreached(
+                    coldkey,
+                    hotkey.clone(),
+                    stake,
+                    threshold,
+                ));
+            }
+            if done {
+                StakeAlertHotkeys::<T>::remove(&hotkey);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+        }
+
+        if let Some(cursor) = cursor {
+            StakeAlertCursor::<T>::put(cursor);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight
+    }
+
+    pub fn get_stake_threshold_subscription(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+    ) -> Option<u64> {
+        StakeThresholdSubscriptions::<T>::get(hotkey, coldkey)
+    }
+}
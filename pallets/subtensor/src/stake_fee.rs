@@ -0,0 +1,71 @@
+use super::*;
+
+/// Basis points in a whole, the denominator of StakeFeeBps.
+const STAKE_FEE_BPS_DENOMINATOR: u16 = 10_000;
+
+impl<T: Config> Pallet<T> {
+    /// Returns the fee charged for staking or unstaking `amount`: StakeFeeFlat plus StakeFeeBps
+    /// of the amount, never more than the amount itself. Amounts below StakeFeeDustThreshold
+    /// are exempt.
+    pub fn get_stake_fee(amount: u64) -> u64 {
+        if amount == 0 || amount < Self::get_stake_fee_dust_threshold() {
+            return 0;
+        }
+        let proportional = u128::from(amount)
+            .saturating_mul(u128::from(Self::get_stake_fee_bps()))
+            .checked_div(u128::from(STAKE_FEE_BPS_DENOMINATOR))
+            .and_then(|fee| u64::try_from(fee).ok())
+            .unwrap_or(0);
+        Self::get_stake_fee_flat()
+            .saturating_add(proportional)
+            .min(amount)
+    }
+
+    /// Pays `fee`, already taken from the coldkey's stake or balance, to the StakeFeeTreasury
+    /// account, or recycles it out of the total issuance when no treasury is set.
+    pub fn collect_stake_fee(coldkey: &T::AccountId, fee: u64) {
+        if fee == 0 {
+            return;
+        }
+        match StakeFeeTreasury::<T>::get() {
+            Some(treasury) => Self::add_balance_to_coldkey_account(&treasury, fee),
+            None => Self::burn_tokens(fee),
+        }
+        TotalStakeFees::<T>::mutate(|total| *total = total.saturating_add(fee));
+
+        log::info!("StakeFeeCharged( coldkey:{:?}, fee:{:?} )", coldkey, fee);
+        Self::deposit_event(Event::StakeFeeCharged(coldkey.clone(), fee));
+    }
+
+    pub fn get_stake_fee_flat() -> u64 {
+        StakeFeeFlat::<T>::get()
+    }
+    pub fn get_stake_fee_bps() -> u16 {
+        StakeFeeBps::<T>::get()
+    }
+    pub fn set_stake_fee(flat: u64, bps: u16) {
+        StakeFeeFlat::<T>::put(flat);
+        StakeFeeBps::<T>::put(bps);
+        Self::deposit_event(Event::StakeFeeSet(flat, bps));
+    }
+
+    pub fn get_stake_fee_dust_threshold() -> u64 {
+        StakeFeeDustThreshold::<T>::get()
+    }
+    pub fn set_stake_fee_dust_threshold(threshold: u64) {
+        StakeFeeDustThreshold::<T>::put(threshold);
+        Self::deposit_event(Event::StakeFeeDustThresholdSet(threshold));
+    }
+
+    pub fn get_stake_fee_treasury() -> Option<T::AccountId> {
+        StakeFeeTreasury::<T>::get()
+    }
+    pub fn set_stake_fee_treasury(treasury: Option<T::AccountId>) {
+        StakeFeeTreasury::<T>::set(treasury.clone());
+        Self::deposit_event(Event::StakeFeeTreasurySet(treasury));
+    }
+
+    pub fn get_total_stake_fees() -> u64 {
+        TotalStakeFees::<T>::get()
+    }
+}
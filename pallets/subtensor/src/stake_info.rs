@@ -12,6 +12,74 @@ pub struct StakeInfo<T: Config> {
     stake: Compact<u64>,
 }
 
+/// What a hotkey earns on one of the subnets it is registered on.
+#[freeze_struct("7eac4ee4d3f2e25")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetEmissionRate {
+    pub netuid: Compact<u16>,
+    pub uid: Compact<u16>,
+    /// Emission of the last epoch.
+    pub emission: Compact<u64>,
+    /// Emission over 7200 blocks at the subnet's tempo.
+    pub emission_per_day: Compact<u64>,
+}
+
+/// The stake of a coldkey on one hotkey, with the hotkey's take and emission rate on every
+/// subnet it is registered on.
+#[freeze_struct("246c3cc4d42a64ce")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct HotkeyStakeDistribution<T: Config> {
+    pub hotkey: T::AccountId,
+    pub stake: Compact<u64>,
+    pub total_hotkey_stake: Compact<u64>,
+    /// The delegate take, zero if the hotkey is not a delegate.
+    pub take: Compact<u16>,
+    pub subnets: Vec<SubnetEmissionRate>,
+}
+
+/// Why stake of a coldkey is not yet back on the coldkey or cannot be moved right now.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub enum PendingUnstakeKind {
+    /// The hotkey is banned and `block_step` returns the stake to the coldkey.
+    BannedHotkeyWindDown,
+    /// The stake interval of the coldkey on the hotkey is used up, `add_stake` and
+    /// `remove_stake` are rejected until it ends.
+    StakeRateLimited,
+    /// The stake was removed and waits out the unstake cooldown, then `claim_unstaked`
+    /// credits it to the coldkey.
+    UnstakeCooldown,
+}
+
+/// A stake operation of a coldkey which is in flight. None of them can be cancelled.
+#[freeze_struct("8d166c185b5cb5d")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct PendingUnstake<T: Config> {
+    pub hotkey: T::AccountId,
+    pub kind: PendingUnstakeKind,
+    pub stake: Compact<u64>,
+    /// Blocks until the stake is returned, or until the stake interval ends. For a wind-down
+    /// this assumes StakeWindDownChunkSize stays unchanged.
+    pub blocks_remaining: Compact<u64>,
+}
+
+/// Everything a coldkey holds, in RAO, for reconciling its holdings in one call. Alpha
+/// holdings are not included, there are no subnet pools to value them at yet.
+#[freeze_struct("15cb3b140be60a39")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ColdkeyValue {
+    /// Balance the coldkey can transfer.
+    pub free_balance: Compact<u64>,
+    /// Stake of the coldkey on all hotkeys.
+    pub total_staked: Compact<u64>,
+    /// Part of `total_staked` on banned hotkeys, being returned to the coldkey.
+    pub winding_down: Compact<u64>,
+    /// Balance held for the coldkey and refunded later: identity deposits, netuid auction
+    /// escrows, the locks of the subnets it owns and removed stake in its unstake cooldown.
+    pub held: Compact<u64>,
+    /// Sum of `free_balance`, `total_staked` and `held`.
+    pub total: Compact<u64>,
+}
+
 impl<T: Config> Pallet<T> {
     fn _get_stake_info_for_coldkeys(
         coldkeys: Vec<T::AccountId>,
@@ -21,20 +89,22 @@ impl<T: Config> Pallet<T> {
         }
 
         let mut stake_info: Vec<(T::AccountId, Vec<StakeInfo<T>>)> = Vec::new();
-        for coldkey_ in coldkeys {
-            let mut stake_info_for_coldkey: Vec<StakeInfo<T>> = Vec::new();
-
-            for (hotkey, coldkey, stake) in <Stake<T>>::iter() {
-                if coldkey == coldkey_ {
-                    stake_info_for_coldkey.push(StakeInfo {
+        for coldkey in coldkeys {
+            // The staking hotkey pages index every hotkey the coldkey staked to, so only its
+            // own Stake entries are read rather than the whole map.
+            let stake_info_for_coldkey: Vec<StakeInfo<T>> = Self::get_all_staked_hotkeys(&coldkey)
+                .into_iter()
+                .filter_map(|hotkey| {
+                    let stake = Stake::<T>::try_get(&hotkey, &coldkey).ok()?;
+                    Some(StakeInfo {
                         hotkey,
-                        coldkey,
+                        coldkey: coldkey.clone(),
                         stake: stake.into(),
-                    });
-                }
-            }
+                    })
+                })
+                .collect();
 
-            stake_info.push((coldkey_, stake_info_for_coldkey));
+            stake_info.push((coldkey, stake_info_for_coldkey));
         }
 
         stake_info
@@ -81,4 +151,204 @@ impl<T: Config> Pallet<T> {
             first.1.clone()
         }
     }
+
+    pub fn get_coldkey_emission(coldkey: &T::AccountId) -> ColdkeyEmission {
+        ColdkeyEmissionTotals::<T>::get(coldkey)
+    }
+
+    /// Returns the lifetime emission received by the coldkey, split into mining incentive,
+    /// validator dividends and delegate take. Returns nothing if the coldkey is invalid.
+    pub fn get_coldkey_emission_summary(coldkey_account_vec: Vec<u8>) -> Option<ColdkeyEmission> {
+        if coldkey_account_vec.len() != 32 {
+            return None; // Invalid coldkey
+        }
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::get_coldkey_emission(&coldkey))
+    }
+
+    fn _get_stake_distribution(coldkey: &T::AccountId) -> Vec<HotkeyStakeDistribution<T>> {
+        Self::get_all_staked_hotkeys(coldkey)
+            .into_iter()
+            .filter_map(|hotkey| {
+                let stake = Self::get_stake_for_coldkey_and_hotkey(coldkey, &hotkey);
+                if stake == 0 {
+                    return None;
+                }
+                let take = if Self::hotkey_is_delegate(&hotkey) {
+                    Self::get_effective_delegate_take(&hotkey)
+                } else {
+                    0
+                };
+                let subnets = Self::get_registered_networks_for_hotkey(&hotkey)
+                    .into_iter()
+                    .filter_map(|netuid| {
+                        let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey).ok()?;
+                        let emission = Self::get_emission_for_uid(netuid, uid);
+                        let epochs_per_day = 7200_u64
+                            .checked_div(u64::from(Self::get_tempo(netuid)))
+                            .unwrap_or(0);
+                        Some(SubnetEmissionRate {
+                            netuid: netuid.into(),
+                            uid: uid.into(),
+                            emission: emission.into(),
+                            emission_per_day: emission.saturating_mul(epochs_per_day).into(),
+                        })
+                    })
+                    .collect();
+
+                Some(HotkeyStakeDistribution {
+                    total_hotkey_stake: Self::get_total_stake_for_hotkey(&hotkey).into(),
+                    hotkey,
+                    stake: stake.into(),
+                    take: take.into(),
+                    subnets,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the stake of the coldkey on each hotkey, with the take of the hotkey and its
+    /// emission on every subnet it is registered on.
+    pub fn get_stake_distribution(coldkey_account_vec: Vec<u8>) -> Vec<HotkeyStakeDistribution<T>> {
+        if coldkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid coldkey
+        }
+        let Ok(coldkey) = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        Self::_get_stake_distribution(&coldkey)
+    }
+
+    fn _get_pending_unstakes(coldkey: &T::AccountId) -> Vec<PendingUnstake<T>> {
+        let mut pending: Vec<PendingUnstake<T>> = Vec::new();
+
+        // Banned hotkeys return one stake per unit of the chunk size and block, in queue order.
+        let chunk_size = u64::from(Self::get_stake_wind_down_chunk_size());
+        let mut returned_before: u64 = 0;
+        for hotkey in HotkeysWindingDown::<T>::get() {
+            for (staker, stake) in Stake::<T>::iter_prefix(&hotkey) {
+                if staker == *coldkey {
+                    pending.push(PendingUnstake {
+                        hotkey: hotkey.clone(),
+                        kind: PendingUnstakeKind::BannedHotkeyWindDown,
+                        stake: stake.into(),
+                        blocks_remaining: returned_before
+                            .checked_div(chunk_size)
+                            .map_or(u64::MAX, |blocks| blocks.saturating_add(1))
+                            .into(),
+                    });
+                }
+                returned_before = returned_before.saturating_add(1);
+            }
+        }
+
+        // Read the interval directly, `get_stakes_this_interval_for_coldkey_hotkey` resets it.
+        let current_block = Self::get_current_block_as_u64();
+        for hotkey in Self::get_all_staked_hotkeys(coldkey) {
+            if Self::is_hotkey_banned(&hotkey) {
+                continue;
+            }
+            let (stakes, last_staked_at) =
+                TotalHotkeyColdkeyStakesThisInterval::<T>::get(coldkey, &hotkey);
+            let interval_ends_at = last_staked_at.saturating_add(StakeInterval::<T>::get());
+            if interval_ends_at <= current_block || stakes < Self::get_target_stakes_per_interval()
+            {
+                continue;
+            }
+            pending.push(PendingUnstake {
+                stake: Self::get_stake_for_coldkey_and_hotkey(coldkey, &hotkey).into(),
+                hotkey,
+                kind: PendingUnstakeKind::StakeRateLimited,
+                blocks_remaining: interval_ends_at.saturating_sub(current_block).into(),
+            });
+        }
+
+        // Removed stake waiting out the unstake cooldown, zero blocks once claimable.
+        for (hotkey, amount, unlock_block) in PendingWithdrawals::<T>::get(coldkey) {
+            pending.push(PendingUnstake {
+                hotkey,
+                kind: PendingUnstakeKind::UnstakeCooldown,
+                stake: amount.into(),
+                blocks_remaining: unlock_block.saturating_sub(current_block).into(),
+            });
+        }
+
+        pending
+    }
+
+    /// Returns the stake operations of the coldkey which are in flight: stake on banned
+    /// hotkeys not yet returned, stake the unstake rate limit currently holds, and removed
+    /// stake in its unstake cooldown.
+    pub fn get_pending_unstakes(coldkey_account_vec: Vec<u8>) -> Vec<PendingUnstake<T>> {
+        if coldkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid coldkey
+        }
+        let Ok(coldkey) = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        Self::_get_pending_unstakes(&coldkey)
+    }
+
+    /// Returns the balance held for the coldkey outside of its free balance and stake, which
+    /// it gets back later.
+    pub fn get_coldkey_held_balance(coldkey: &T::AccountId) -> u64 {
+        let mut held: u64 = Self::get_pending_withdrawal_total(coldkey);
+        #[cfg(feature = "identity")]
+        {
+            if let Some((_, deposit)) = Identities::<T>::get(coldkey) {
+                held = held.saturating_add(deposit);
+            }
+            for (_, depositor, deposit) in SubnetIdentities::<T>::iter_values() {
+                if depositor == *coldkey {
+                    held = held.saturating_add(deposit);
+                }
+            }
+        }
+        for (bidder, escrowed) in NetuidBids::<T>::iter_values() {
+            if bidder == *coldkey {
+                held = held.saturating_add(escrowed);
+            }
+        }
+        for netuid in Self::get_all_subnet_netuids() {
+            if SubnetOwner::<T>::get(netuid) == *coldkey {
+                held = held.saturating_add(Self::get_subnet_locked_balance(netuid));
+            }
+        }
+        held
+    }
+
+    fn _get_coldkey_value(coldkey: &T::AccountId) -> ColdkeyValue {
+        let free_balance: u64 = Self::get_coldkey_balance(coldkey);
+        let total_staked: u64 = Self::get_total_stake_for_coldkey(coldkey);
+        let winding_down: u64 = Self::_get_pending_unstakes(coldkey)
+            .into_iter()
+            .filter(|pending| pending.kind == PendingUnstakeKind::BannedHotkeyWindDown)
+            .fold(0u64, |total, pending| total.saturating_add(pending.stake.0));
+        let held: u64 = Self::get_coldkey_held_balance(coldkey);
+
+        ColdkeyValue {
+            free_balance: free_balance.into(),
+            total_staked: total_staked.into(),
+            winding_down: winding_down.into(),
+            held: held.into(),
+            total: free_balance
+                .saturating_add(total_staked)
+                .saturating_add(held)
+                .into(),
+        }
+    }
+
+    /// Returns the free balance, stake and held balance of the coldkey, with their total.
+    /// Returns nothing if the coldkey is invalid.
+    pub fn get_coldkey_value(coldkey_account_vec: Vec<u8>) -> Option<ColdkeyValue> {
+        if coldkey_account_vec.len() != 32 {
+            return None; // Invalid coldkey
+        }
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        Some(Self::_get_coldkey_value(&coldkey))
+    }
 }
@@ -0,0 +1,187 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic add_stake_locked.
+    ///
+    /// Adds stake like `do_add_stake` and locks it on the hotkey for `lock_blocks` blocks. The
+    /// locked stake can't be removed until the lock ends, and weighs in the dividends of the
+    /// hotkey by the StakeLockBoosts multiplier of the lock duration. Locking more stake on the
+    /// same hotkey relocks the whole amount, until the later of both unlock blocks.
+    ///
+    /// # Event:
+    /// * StakeLocked;
+    ///     - On successfully locking the stake.
+    ///
+    /// # Raises:
+    /// * 'InvalidStakeLockDuration':
+    ///     - The lock duration is zero or longer than MAX_STAKE_LOCK_BLOCKS.
+    ///
+    /// * The errors raised by `do_add_stake`.
+    ///
+    pub fn do_add_stake_locked(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        stake_to_be_added: u64,
+        lock_blocks: u64,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin.clone())?;
+        ensure!(
+            lock_blocks > 0 && lock_blocks <= MAX_STAKE_LOCK_BLOCKS,
+            Error::<T>::InvalidStakeLockDuration
+        );
+
+        let stake_before = Stake::<T>::get(&hotkey, &coldkey);
+        Self::do_add_stake(origin, hotkey.clone(), stake_to_be_added)?;
+        let added = Stake::<T>::get(&hotkey, &coldkey).saturating_sub(stake_before);
+
+        let current_block = Self::get_current_block_as_u64();
+        let mut lock = StakeLocks::<T>::get(&hotkey, &coldkey)
+            .filter(|lock| lock.unlock_block > current_block)
+            .unwrap_or_default();
+        lock.amount = lock.amount.saturating_add(added);
+        lock.locked_at = current_block;
+        lock.unlock_block = lock
+            .unlock_block
+            .max(current_block.saturating_add(lock_blocks));
+        Self::insert_stake_lock(&hotkey, &coldkey, lock.clone());
+
+        log::info!(
+            "StakeLocked( coldkey:{:?}, hotkey:{:?}, amount:{:?}, unlock_block:{:?} )",
+            coldkey,
+            hotkey,
+            lock.amount,
+            lock.unlock_block
+        );
+        Self::deposit_event(Event::StakeLocked(
+            coldkey,
+            hotkey,
+            lock.amount,
+            lock.unlock_block,
+        ));
+        Ok(())
+    }
+
+    /// Stores the lock and schedules its expiry.
+    fn insert_stake_lock(hotkey: &T::AccountId, coldkey: &T::AccountId, lock: StakeLock) {
+        StakeLockExpiries::<T>::append(lock.unlock_block, (hotkey.clone(), coldkey.clone()));
+        StakeLocks::<T>::insert(hotkey, coldkey, lock);
+    }
+
+    /// Returns the stake the coldkey can't remove from the hotkey yet.
+    pub fn get_locked_stake(hotkey: &T::AccountId, coldkey: &T::AccountId) -> u64 {
+        StakeLocks::<T>::get(hotkey, coldkey)
+            .filter(|lock| lock.unlock_block > Self::get_current_block_as_u64())
+            .map(|lock| lock.amount)
+            .unwrap_or_default()
+    }
+
+    /// Whether any coldkey has stake locked on the hotkey.
+    pub fn hotkey_has_stake_locks(hotkey: &T::AccountId) -> bool {
+        let current_block = Self::get_current_block_as_u64();
+        StakeLocks::<T>::iter_prefix_values(hotkey).any(|lock| lock.unlock_block > current_block)
+    }
+
+    /// Returns the dividend multiplier in basis points of a stake locked for `lock_blocks`,
+    /// that of the longest StakeLockBoosts tier the lock reaches.
+    pub fn get_stake_lock_boost(lock_blocks: u64) -> u32 {
+        StakeLockBoosts::<T>::get()
+            .iter()
+            .take_while(|(tier_blocks, _)| *tier_blocks <= lock_blocks)
+            .last()
+            .map(|(_, multiplier)| *multiplier)
+            .unwrap_or(EMISSION_MULTIPLIER_BPS)
+    }
+
+    /// Returns the weight of the stake of the coldkey on the hotkey in the dividends of the
+    /// hotkey, its locked part scaled by the boost of the lock.
+    pub fn get_dividend_weighted_stake(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        stake: u64,
+    ) -> u64 {
+        let Some(lock) = StakeLocks::<T>::get(hotkey, coldkey)
+            .filter(|lock| lock.unlock_block > Self::get_current_block_as_u64())
+        else {
+            return stake;
+        };
+        let locked = lock.amount.min(stake);
+        let boost = Self::get_stake_lock_boost(lock.unlock_block.saturating_sub(lock.locked_at));
+        let boosted = u128::from(locked)
+            .saturating_mul(u128::from(boost))
+            .checked_div(u128::from(EMISSION_MULTIPLIER_BPS))
+            .unwrap_or_default();
+        u64::try_from(boosted)
+            .unwrap_or(u64::MAX)
+            .saturating_add(stake.saturating_sub(locked))
+    }
+
+    /// Removes the stake locks ending at `block_number`, unless they were extended since.
+    /// Returns the weight used.
+    pub fn expire_stake_locks(block_number: u64) -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        for (hotkey, coldkey) in StakeLockExpiries::<T>::take(block_number) {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            let Some(lock) = StakeLocks::<T>::get(&hotkey, &coldkey) else {
+                continue;
+            };
+            if lock.unlock_block > block_number {
+                continue;
+            }
+            StakeLocks::<T>::remove(&hotkey, &coldkey);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+            log::info!(
+                "StakeUnlocked( coldkey:{:?}, hotkey:{:?}, amount:{:?} )",
+                coldkey,
+                hotkey,
+                lock.amount
+            );
+            Self::deposit_event(Event::StakeUnlocked(coldkey, hotkey, lock.amount));
+        }
+        weight
+    }
+
+    /// Moves the stake locks on the old hotkey to the new hotkey.
+    pub fn swap_stake_locks(old_hotkey: &T::AccountId, new_hotkey: &T::AccountId) -> u64 {
+        let locks: Vec<(T::AccountId, StakeLock)> =
+            StakeLocks::<T>::drain_prefix(old_hotkey).collect();
+        let moved = u64::try_from(locks.len()).unwrap_or(u64::MAX);
+        for (coldkey, lock) in locks {
+            Self::insert_stake_lock(new_hotkey, &coldkey, lock);
+        }
+        moved
+    }
+
+    /// Moves the stake locks of the old coldkey on `hotkeys` to the new coldkey, merged with
+    /// any lock the new coldkey has on the same hotkey. The stake on the hotkeys must move
+    /// with them.
+    pub fn swap_stake_locks_for_coldkey(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        hotkeys: &[T::AccountId],
+    ) -> u64 {
+        let mut moved: u64 = 0;
+        for hotkey in hotkeys {
+            let Some(lock) = StakeLocks::<T>::take(hotkey, old_coldkey) else {
+                continue;
+            };
+            let merged = match StakeLocks::<T>::get(hotkey, new_coldkey) {
+                Some(existing) => StakeLock {
+                    amount: existing.amount.saturating_add(lock.amount),
+                    locked_at: existing.locked_at.max(lock.locked_at),
+                    unlock_block: existing.unlock_block.max(lock.unlock_block),
+                },
+                None => lock,
+            };
+            Self::insert_stake_lock(hotkey, new_coldkey, merged);
+            moved = moved.saturating_add(1);
+        }
+        moved
+    }
+
+    pub fn set_stake_lock_boosts(boosts: Vec<(u64, u32)>) {
+        StakeLockBoosts::<T>::put(boosts.clone());
+        Self::deposit_event(Event::StakeLockBoostsSet(boosts));
+    }
+}
@@ -122,7 +122,8 @@ impl<T: Config> Pallet<T> {
     ///     - The hotkey we are delegating is not owned by the calling coldket.
     ///
     /// * 'DelegateTakeTooLow':
-    ///     - The delegate is setting a take which is not lower than the previous.
+    ///     - The delegate is setting a take which is not lower than the previous,
+    ///       or which is below the minimum take.
     ///
     pub fn do_decrease_take(
         origin: T::RuntimeOrigin,
@@ -155,6 +156,13 @@ impl<T: Config> Pallet<T> {
         let min_take = MinTake::<T>::get();
         ensure!(take >= min_take, Error::<T>::DelegateTakeTooLow);
 
+        // --- 3.2 Enforce the take change rate limit of the coldkey.
+        ensure!(
+            Self::passes_rate_limit(&coldkey, TransactionType::SetDelegateTake),
+            Error::<T>::TransactionTypeRateLimitExceeded
+        );
+        Self::set_last_transaction_type_block(&coldkey, TransactionType::SetDelegateTake);
+
         // --- 4. Set the new take value.
         Delegates::<T>::insert(hotkey.clone(), take);
 
@@ -198,7 +206,8 @@ impl<T: Config> Pallet<T> {
     ///     - Thrown if key has hit transaction rate limit
     ///
     /// * 'DelegateTakeTooLow':
-    ///     - The delegate is setting a take which is not greater than the previous.
+    ///     - The delegate is setting a take which is not greater than the previous,
+    ///       or which is below the minimum take.
     ///
     pub fn do_increase_take(
         origin: T::RuntimeOrigin,
@@ -228,7 +237,9 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 4. Ensure take is within the min ..= InitialDefaultTake (18%) range
+        let min_take = MinTake::<T>::get();
         let max_take = MaxTake::<T>::get();
+        ensure!(take >= min_take, Error::<T>::DelegateTakeTooLow);
         ensure!(take <= max_take, Error::<T>::DelegateTakeTooHigh);
 
         // --- 5. Enforce the rate limit (independently on do_add_stake rate limits)
@@ -240,9 +251,14 @@ impl<T: Config> Pallet<T> {
             ),
             Error::<T>::DelegateTxRateLimitExceeded
         );
+        ensure!(
+            Self::passes_rate_limit(&coldkey, TransactionType::SetDelegateTake),
+            Error::<T>::TransactionTypeRateLimitExceeded
+        );
 
         // Set last block for rate limiting
         Self::set_last_tx_block_delegate_take(&coldkey, block);
+        Self::set_last_transaction_type_block(&coldkey, TransactionType::SetDelegateTake);
 
         // --- 6. Set the new take value.
         Delegates::<T>::insert(hotkey.clone(), take);
@@ -261,6 +277,7 @@ impl<T: Config> Pallet<T> {
     }
 
     /// ---- The implementation for the extrinsic add_stake: Adds stake to a hotkey account.
+    /// The stake fee is taken out of the amount withdrawn from the coldkey.
     ///
     /// # Args:
     /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
@@ -289,6 +306,9 @@ impl<T: Config> Pallet<T> {
     /// * 'TxRateLimitExceeded':
     ///     -  Thrown if key has hit transaction rate limit
     ///
+    /// * 'FeeReserveNotKept':
+    ///     -  Thrown if the coldkey keeps the fee reserve and the stake would dip into it.
+    ///
     pub fn do_add_stake(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
@@ -312,6 +332,7 @@ impl<T: Config> Pallet<T> {
             Self::can_remove_balance_from_coldkey_account(&coldkey, stake_to_be_added),
             Error::<T>::NotEnoughBalanceToStake
         );
+        Self::ensure_fee_reserve_kept(&coldkey, stake_to_be_added)?;
 
         // Ensure that the hotkey account exists this is only possible through registration.
         ensure!(
@@ -325,6 +346,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
         );
 
+        // Ensure that the hotkey is not banned.
+        ensure!(!Self::is_hotkey_banned(&hotkey), Error::<T>::HotkeyIsBanned);
+
         // Ensure we don't exceed stake rate limit
         let stakes_this_interval =
             Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
@@ -338,8 +362,9 @@ impl<T: Config> Pallet<T> {
 
         // If coldkey is not owner of the hotkey, it's a nomination stake.
         if !Self::coldkey_owns_hotkey(&coldkey, &hotkey) {
-            let total_stake_after_add =
-                Stake::<T>::get(&hotkey, &coldkey).saturating_add(stake_to_be_added);
+            let total_stake_after_add = Stake::<T>::get(&hotkey, &coldkey).saturating_add(
+                stake_to_be_added.saturating_sub(Self::get_stake_fee(stake_to_be_added)),
+            );
 
             ensure!(
                 total_stake_after_add >= NominatorMinRequiredStake::<T>::get(),
@@ -348,8 +373,12 @@ impl<T: Config> Pallet<T> {
         }
 
         // Ensure the remove operation from the coldkey is a success.
-        let actual_amount_to_stake =
-            Self::remove_balance_from_coldkey_account(&coldkey, stake_to_be_added)?;
+        let withdrawn = Self::remove_balance_from_coldkey_account(&coldkey, stake_to_be_added)?;
+
+        // Take the stake fee out of the withdrawn balance.
+        let fee = Self::get_stake_fee(withdrawn);
+        Self::collect_stake_fee(&coldkey, fee);
+        let actual_amount_to_stake = withdrawn.saturating_sub(fee);
 
         // If we reach here, add the balance to the hotkey.
         Self::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, actual_amount_to_stake);
@@ -370,13 +399,171 @@ impl<T: Config> Pallet<T> {
             hotkey,
             actual_amount_to_stake
         );
-        Self::deposit_event(Event::StakeAdded(hotkey, actual_amount_to_stake));
+        // Stake counts on every subnet the hotkey is registered on.
+        Self::deposit_subnet_event(
+            &Self::get_registered_networks_for_hotkey(&hotkey),
+            Event::StakeAdded(hotkey, actual_amount_to_stake),
+        );
 
         // Ok and return.
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic stake_all_minus_fees: Stakes the free balance of
+    /// the coldkey to a hotkey, keeping back the fee reserve.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The associated hotkey account.
+    ///
+    /// # Event:
+    /// * StakeAdded;
+    ///     -  On the successfully adding stake to a global account.
+    ///
+    /// # Raises:
+    /// * 'NotEnoughBalanceToStake':
+    ///     -  The coldkey balance does not exceed the fee reserve.
+    ///
+    /// * The errors raised by `do_add_stake`.
+    ///
+    pub fn do_stake_all_minus_fees(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin.clone())?;
+        let stake_to_be_added =
+            Self::get_coldkey_balance(&coldkey).saturating_sub(Self::get_fee_reserve());
+        ensure!(stake_to_be_added > 0, Error::<T>::NotEnoughBalanceToStake);
+
+        Self::do_add_stake(origin, hotkey, stake_to_be_added)
+    }
+
+    /// ---- The implementation for the extrinsic add_stake_multiple: Adds stake to several
+    /// hotkeys in one call.
+    ///
+    /// Entries for the same hotkey are summed, then each hotkey is staked to as `add_stake`
+    /// does. The batch is atomic: if any entry fails, none of the stake is added.
+    ///
+    /// # Event:
+    /// * StakeAdded;
+    ///     -  Once for each hotkey of the batch.
+    ///
+    /// # Raises:
+    /// * 'StakeBatchTooLarge':
+    ///     -  The batch has more than MAX_STAKE_BATCH_SIZE entries.
+    ///
+    /// * 'NotEnoughBalanceToStake':
+    ///     -  The coldkey balance does not cover the total of the batch.
+    ///
+    /// * The errors raised by `do_add_stake` for any entry.
+    ///
+    pub fn do_add_stake_multiple(
+        origin: T::RuntimeOrigin,
+        stakes: Vec<(T::AccountId, u64)>,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin.clone())?;
+        let stakes = Self::merge_stake_batch(stakes)?;
+
+        // Check the whole batch against the balance before staking any of it.
+        let total = stakes
+            .iter()
+            .fold(0u64, |total, (_, amount)| total.saturating_add(*amount));
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, total),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+        Self::ensure_fee_reserve_kept(&coldkey, total)?;
+
+        for (hotkey, amount) in stakes {
+            Self::do_add_stake(origin.clone(), hotkey, amount)?;
+        }
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic remove_stake_multiple: Removes stake from
+    /// several hotkeys in one call.
+    ///
+    /// Entries for the same hotkey are summed, then each hotkey is unstaked from as
+    /// `remove_stake` does. The batch is atomic: if any entry fails, none of the stake is
+    /// removed.
+    ///
+    /// # Event:
+    /// * StakeRemoved;
+    ///     -  Once for each hotkey of the batch.
+    ///
+    /// # Raises:
+    /// * 'StakeBatchTooLarge':
+    ///     -  The batch has more than MAX_STAKE_BATCH_SIZE entries.
+    ///
+    /// * The errors raised by `do_remove_stake` for any entry.
+    ///
+    pub fn do_remove_stake_multiple(
+        origin: T::RuntimeOrigin,
+        stakes: Vec<(T::AccountId, u64)>,
+    ) -> dispatch::DispatchResult {
+        ensure_signed(origin.clone())?;
+        for (hotkey, amount) in Self::merge_stake_batch(stakes)? {
+            Self::do_remove_stake(origin.clone(), hotkey, amount)?;
+        }
+        Ok(())
+    }
+
+    /// Sums the amounts of the entries for the same hotkey, in the order the hotkeys first
+    /// appear.
+    fn merge_stake_batch(
+        stakes: Vec<(T::AccountId, u64)>,
+    ) -> Result<Vec<(T::AccountId, u64)>, DispatchError> {
+        ensure!(
+            stakes.len() <= MAX_STAKE_BATCH_SIZE as usize,
+            Error::<T>::StakeBatchTooLarge
+        );
+        let mut merged: Vec<(T::AccountId, u64)> = Vec::with_capacity(stakes.len());
+        for (hotkey, amount) in stakes {
+            match merged
+                .iter_mut()
+                .find(|(merged_hotkey, _)| *merged_hotkey == hotkey)
+            {
+                Some((_, total)) => *total = total.saturating_add(amount),
+                None => merged.push((hotkey, amount)),
+            }
+        }
+        Ok(merged)
+    }
+
+    /// ---- The implementation for the extrinsic set_keep_fee_reserve: Sets whether balance
+    /// removals for staking and network locks must leave the fee reserve on the coldkey.
+    ///
+    /// # Event:
+    /// * KeepFeeReserveSet;
+    ///     -  On successfully updating the setting.
+    ///
+    pub fn do_set_keep_fee_reserve(
+        origin: T::RuntimeOrigin,
+        keep: bool,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        if keep {
+            KeepFeeReserve::<T>::insert(&coldkey, true);
+        } else {
+            KeepFeeReserve::<T>::remove(&coldkey);
+        }
+
+        log::info!(
+            "KeepFeeReserveSet( coldkey:{:?}, keep:{:?} )",
+            coldkey,
+            keep
+        );
+        Self::deposit_event(Event::KeepFeeReserveSet(coldkey, keep));
+        Ok(())
+    }
+
     /// ---- The implementation for the extrinsic remove_stake: Removes stake from a hotkey account and adds it onto a coldkey.
+    /// The stake fee is taken out of the amount credited to the coldkey. While UnstakeCooldown is set, the amount is
+    /// only credited once claimed with claim_unstaked after the cooldown.
     ///
     /// # Args:
     /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
@@ -405,6 +592,9 @@ impl<T: Config> Pallet<T> {
     /// * 'TxRateLimitExceeded':
     ///     -  Thrown if key has hit transaction rate limit
     ///
+    /// * 'TooManyPendingWithdrawals':
+    ///     -  Thrown if an unstake cooldown is set and the coldkey has too many unclaimed withdrawals.
+    ///
     pub fn do_remove_stake(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
@@ -444,19 +634,49 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughStakeToWithdraw
         );
 
-        // Ensure we don't exceed stake rate limit
+        // Ensure the stake the hotkey locked by voting on subnet referenda stays on it.
+        ensure!(
+            Self::get_total_stake_for_hotkey(&hotkey).saturating_sub(stake_to_be_removed)
+                >= Self::get_referendum_locked_stake(&hotkey),
+            Error::<T>::StakeLockedInReferendum
+        );
+
+        // Ensure the stake the coldkey locked on the hotkey stays on it.
+        ensure!(
+            Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey)
+                .saturating_sub(stake_to_be_removed)
+                >= Self::get_locked_stake(&hotkey, &coldkey),
+            Error::<T>::StakeIsLocked
+        );
+
+        // Ensure we don't exceed stake rate limit, unless the hotkey is banned and its stake is
+        // winding down.
         let unstakes_this_interval =
             Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
         ensure!(
-            unstakes_this_interval < Self::get_target_stakes_per_interval(),
+            unstakes_this_interval < Self::get_target_stakes_per_interval()
+                || Self::is_hotkey_banned(&hotkey),
             Error::<T>::UnstakeRateLimitExceeded
         );
 
+        // Ensure the withdrawal fits in the queue of the coldkey if it waits out a cooldown.
+        ensure!(
+            Self::get_unstake_cooldown() == 0 || Self::can_queue_withdrawal(&coldkey, &hotkey),
+            Error::<T>::TooManyPendingWithdrawals
+        );
+
         // We remove the balance from the hotkey.
         Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_removed);
 
-        // We add the balance to the coldkey.  If the above fails we will not credit this coldkey.
-        Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_removed);
+        // We add the balance to the coldkey, less the stake fee, at once or after the unstake
+        // cooldown.  If the above fails we will not credit this coldkey.
+        let fee = Self::get_stake_fee(stake_to_be_removed);
+        Self::collect_stake_fee(&coldkey, fee);
+        Self::pay_out_or_schedule_withdrawal(
+            &coldkey,
+            &hotkey,
+            stake_to_be_removed.saturating_sub(fee),
+        );
 
         // If the stake is below the minimum, we clear the nomination from storage.
         // This only applies to nominator stakes.
@@ -480,7 +700,10 @@ impl<T: Config> Pallet<T> {
             hotkey,
             stake_to_be_removed
         );
-        Self::deposit_event(Event::StakeRemoved(hotkey, stake_to_be_removed));
+        Self::deposit_subnet_event(
+            &Self::get_registered_networks_for_hotkey(&hotkey),
+            Event::StakeRemoved(hotkey, stake_to_be_removed),
+        );
 
         // Done and ok.
         Ok(())
@@ -588,12 +811,8 @@ impl<T: Config> Pallet<T> {
                 OwnedHotkeys::<T>::insert(coldkey, hotkeys);
             }
 
-            // Update StakingHotkeys map
-            let mut staking_hotkeys = StakingHotkeys::<T>::get(coldkey);
-            if !staking_hotkeys.contains(hotkey) {
-                staking_hotkeys.push(hotkey.clone());
-                StakingHotkeys::<T>::insert(coldkey, staking_hotkeys);
-            }
+            // Update StakingHotkeyPages
+            Self::add_staking_hotkey(coldkey, hotkey);
         }
     }
 
@@ -667,19 +886,15 @@ impl<T: Config> Pallet<T> {
             hotkey,
             TotalHotkeyStake::<T>::get(hotkey).saturating_add(increment),
         );
-        Stake::<T>::insert(
-            hotkey,
-            coldkey,
-            Stake::<T>::get(hotkey, coldkey).saturating_add(increment),
-        );
+        let old_stake: u64 = Stake::<T>::get(hotkey, coldkey);
+        let new_stake: u64 = old_stake.saturating_add(increment);
+        Stake::<T>::insert(hotkey, coldkey, new_stake);
+        Self::update_delegate_indexes(hotkey, coldkey, old_stake, new_stake);
+        T::OnSubtensorEvent::on_stake_changed(hotkey, coldkey, new_stake);
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_add(increment));
 
-        // Update StakingHotkeys map
-        let mut staking_hotkeys = StakingHotkeys::<T>::get(coldkey);
-        if !staking_hotkeys.contains(hotkey) {
-            staking_hotkeys.push(hotkey.clone());
-            StakingHotkeys::<T>::insert(coldkey, staking_hotkeys);
-        }
+        // Update StakingHotkeyPages
+        Self::add_staking_hotkey(coldkey, hotkey);
     }
 
     // Decreases the stake on the cold - hot pairing by the decrement while decreasing other counters.
@@ -694,12 +909,13 @@ impl<T: Config> Pallet<T> {
             hotkey,
             TotalHotkeyStake::<T>::get(hotkey).saturating_sub(decrement),
         );
-        Stake::<T>::insert(
-            hotkey,
-            coldkey,
-            Stake::<T>::get(hotkey, coldkey).saturating_sub(decrement),
-        );
+        let old_stake: u64 = Stake::<T>::get(hotkey, coldkey);
+        let new_stake: u64 = old_stake.saturating_sub(decrement);
+        Stake::<T>::insert(hotkey, coldkey, new_stake);
+        Self::update_delegate_indexes(hotkey, coldkey, old_stake, new_stake);
+        T::OnSubtensorEvent::on_stake_changed(hotkey, coldkey, new_stake);
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_sub(decrement));
+        Self::queue_stake_alert_check(hotkey);
 
         // TODO: Tech debt: Remove StakingHotkeys entry if stake goes to 0
     }
@@ -724,13 +940,14 @@ impl<T: Config> Pallet<T> {
         TotalColdkeyStake::<T>::mutate(coldkey, |old| *old = old.saturating_sub(current_stake));
         TotalHotkeyStake::<T>::mutate(hotkey, |stake| *stake = stake.saturating_sub(current_stake));
         Stake::<T>::remove(hotkey, coldkey);
+        Self::update_delegate_indexes(hotkey, coldkey, current_stake, 0);
+        T::OnSubtensorEvent::on_stake_changed(hotkey, coldkey, 0);
         TotalStake::<T>::mutate(|stake| *stake = stake.saturating_sub(current_stake));
         TotalIssuance::<T>::mutate(|issuance| *issuance = issuance.saturating_sub(current_stake));
+        Self::queue_stake_alert_check(hotkey);
 
-        // Update StakingHotkeys map
-        let mut staking_hotkeys = StakingHotkeys::<T>::get(coldkey);
-        staking_hotkeys.retain(|h| h != hotkey);
-        StakingHotkeys::<T>::insert(coldkey, staking_hotkeys);
+        // Update StakingHotkeyPages
+        Self::remove_staking_hotkey(coldkey, hotkey);
 
         current_stake
     }
@@ -742,7 +959,10 @@ impl<T: Config> Pallet<T> {
         stake: u64,
     ) {
         // Verify if the account is a nominator account by checking ownership of the hotkey by the coldkey.
-        if !Self::coldkey_owns_hotkey(coldkey, hotkey) {
+        // Locked nominations stay until their lock ends.
+        if !Self::coldkey_owns_hotkey(coldkey, hotkey)
+            && Self::get_locked_stake(hotkey, coldkey) == 0
+        {
             // If the stake is below the minimum required, it's considered a small nomination and needs to be cleared.
             if stake < Self::get_nominator_min_required_stake() {
                 // Remove the stake from the nominator account. (this is a more forceful unstake operation which )
@@ -750,15 +970,55 @@ impl<T: Config> Pallet<T> {
                 let cleared_stake = Self::empty_stake_on_coldkey_hotkey_account(coldkey, hotkey);
                 // Add the stake to the coldkey account.
                 Self::add_balance_to_coldkey_account(coldkey, cleared_stake);
+                if cleared_stake > 0 {
+                    Self::deposit_event(Event::SmallNominationCleared(
+                        hotkey.clone(),
+                        coldkey.clone(),
+                        cleared_stake,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// ---- The implementation for the extrinsic clear_small_nominations.
+    ///
+    /// Returns the stake of each listed ( hotkey, coldkey ) nomination below
+    /// NominatorMinRequiredStake to the nominator coldkey. Anyone can call it, nominations at
+    /// or above the minimum and stakes of owners on their own hotkeys are left untouched.
+    ///
+    /// # Event:
+    /// * SmallNominationCleared;
+    ///     - For each nomination cleared.
+    ///
+    /// # Raises:
+    /// * 'TooManyNominationsToClear':
+    ///     - More than MAX_NOMINATIONS_CLEARED_PER_CALL nominations are listed.
+    ///
+    pub fn do_clear_small_nominations(
+        origin: T::RuntimeOrigin,
+        nominations: Vec<(T::AccountId, T::AccountId)>,
+    ) -> dispatch::DispatchResult {
+        ensure_signed(origin)?;
+        ensure!(
+            nominations.len() <= MAX_NOMINATIONS_CLEARED_PER_CALL as usize,
+            Error::<T>::TooManyNominationsToClear
+        );
+
+        for (hotkey, coldkey) in nominations {
+            let stake = Stake::<T>::get(&hotkey, &coldkey);
+            if stake > 0 {
+                Self::clear_small_nomination_if_required(&hotkey, &coldkey, stake);
             }
         }
+        Ok(())
     }
 
     /// Clears small nominations for all accounts.
     ///
     /// WARN: This is an O(N) operation, where N is the number of staking accounts. It should be
     /// used with caution.
-    pub fn clear_small_nominations() {
+    pub fn clear_all_small_nominations() {
         // Loop through all staking accounts to identify and clear nominations below the minimum stake.
         for (hotkey, coldkey, stake) in Stake::<T>::iter() {
             Self::clear_small_nomination_if_required(&hotkey, &coldkey, stake);
@@ -796,6 +1056,21 @@ impl<T: Config> Pallet<T> {
             .is_ok()
     }
 
+    /// Fails if the coldkey keeps the fee reserve and removing `amount` would leave less than it.
+    pub fn ensure_fee_reserve_kept(
+        coldkey: &T::AccountId,
+        amount: u64,
+    ) -> dispatch::DispatchResult {
+        if KeepFeeReserve::<T>::get(coldkey) {
+            ensure!(
+                Self::get_coldkey_balance(coldkey).saturating_sub(amount)
+                    >= Self::get_fee_reserve(),
+                Error::<T>::FeeReserveNotKept
+            );
+        }
+        Ok(())
+    }
+
     pub fn get_coldkey_balance(
         coldkey: &T::AccountId,
     ) -> <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance
@@ -854,18 +1129,34 @@ impl<T: Config> Pallet<T> {
         Ok(credit)
     }
 
+    /// Removes the stake of every coldkey from the hotkey as `do_remove_stake` would, less the
+    /// stake fee and after the unstake cooldown. Locked stake stays on the hotkey until its
+    /// lock ends.
     pub fn unstake_all_coldkeys_from_hotkey_account(hotkey: &T::AccountId) {
         // Iterate through all coldkeys that have a stake on this hotkey account.
-        for (delegate_coldkey_i, stake_i) in
+        let stakes: Vec<(T::AccountId, u64)> =
             <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix(
                 hotkey,
             )
-        {
-            // Remove the stake from the coldkey - hotkey pairing.
-            Self::decrease_stake_on_coldkey_hotkey_account(&delegate_coldkey_i, hotkey, stake_i);
+            .collect();
+        for (delegate_coldkey_i, stake_i) in stakes {
+            let unlocked =
+                stake_i.saturating_sub(Self::get_locked_stake(hotkey, &delegate_coldkey_i));
+            if unlocked == 0 {
+                continue;
+            }
+
+            // Remove the unlocked stake from the coldkey - hotkey pairing.
+            Self::decrease_stake_on_coldkey_hotkey_account(&delegate_coldkey_i, hotkey, unlocked);
 
-            // Add the balance to the coldkey account.
-            Self::add_balance_to_coldkey_account(&delegate_coldkey_i, stake_i);
+            // Pay it out to the coldkey account less the stake fee.
+            let fee = Self::get_stake_fee(unlocked);
+            Self::collect_stake_fee(&delegate_coldkey_i, fee);
+            Self::pay_out_or_schedule_withdrawal(
+                &delegate_coldkey_i,
+                hotkey,
+                unlocked.saturating_sub(fee),
+            );
         }
     }
 }
@@ -0,0 +1,114 @@
+use super::*;
+use sp_std::cmp::Ordering;
+
+impl<T: Config> Pallet<T> {
+    /// Lists `hotkey` among the hotkeys `coldkey` stakes to, on the last of its pages.
+    pub fn add_staking_hotkey(coldkey: &T::AccountId, hotkey: &T::AccountId) {
+        Self::page_legacy_staking_hotkeys(coldkey);
+        Self::push_staking_hotkey(coldkey, hotkey);
+    }
+
+    /// Removes `hotkey` from the hotkeys `coldkey` stakes to. The last hotkey of the last page
+    /// takes its place, so only the two pages are written.
+    pub fn remove_staking_hotkey(coldkey: &T::AccountId, hotkey: &T::AccountId) {
+        Self::page_legacy_staking_hotkeys(coldkey);
+        let Some(page_index) = StakingHotkeyPage::<T>::take(coldkey, hotkey) else {
+            return;
+        };
+        let last_index = StakingHotkeyPageCount::<T>::get(coldkey).saturating_sub(1);
+        let mut last_page = StakingHotkeyPages::<T>::get(coldkey, last_index);
+        let Some(moved) = last_page.pop() else {
+            return;
+        };
+
+        if page_index == last_index {
+            if moved != *hotkey {
+                if let Some(slot) = last_page.iter_mut().find(|h| *h == hotkey) {
+                    *slot = moved;
+                }
+            }
+        } else {
+            StakingHotkeyPages::<T>::mutate(coldkey, page_index, |page| {
+                if let Some(slot) = page.iter_mut().find(|h| *h == hotkey) {
+                    *slot = moved.clone();
+                }
+            });
+            StakingHotkeyPage::<T>::insert(coldkey, &moved, page_index);
+        }
+
+        if !last_page.is_empty() {
+            StakingHotkeyPages::<T>::insert(coldkey, last_index, last_page);
+        } else {
+            StakingHotkeyPages::<T>::remove(coldkey, last_index);
+            if last_index == 0 {
+                StakingHotkeyPageCount::<T>::remove(coldkey);
+            } else {
+                StakingHotkeyPageCount::<T>::insert(coldkey, last_index);
+            }
+        }
+    }
+
+    /// Moves the hotkeys `old_coldkey` stakes to over to `new_coldkey`.
+    pub fn swap_staking_hotkeys(old_coldkey: &T::AccountId, new_coldkey: &T::AccountId) {
+        Self::page_legacy_staking_hotkeys(old_coldkey);
+        Self::page_legacy_staking_hotkeys(new_coldkey);
+        let page_count = StakingHotkeyPageCount::<T>::take(old_coldkey);
+        for page_index in 0..page_count {
+            for hotkey in StakingHotkeyPages::<T>::take(old_coldkey, page_index) {
+                StakingHotkeyPage::<T>::remove(old_coldkey, &hotkey);
+                Self::push_staking_hotkey(new_coldkey, &hotkey);
+            }
+        }
+    }
+
+    /// Moves the StakingHotkeys entry of `coldkey`, written before the hotkeys were paged,
+    /// into its pages. Returns the number of hotkeys moved.
+    pub fn page_legacy_staking_hotkeys(coldkey: &T::AccountId) -> u32 {
+        let hotkeys = StakingHotkeys::<T>::take(coldkey);
+        for hotkey in hotkeys.iter() {
+            Self::push_staking_hotkey(coldkey, hotkey);
+        }
+        u32::try_from(hotkeys.len()).unwrap_or(u32::MAX)
+    }
+
+    fn push_staking_hotkey(coldkey: &T::AccountId, hotkey: &T::AccountId) {
+        if StakingHotkeyPage::<T>::contains_key(coldkey, hotkey) {
+            return;
+        }
+        let page_count = StakingHotkeyPageCount::<T>::get(coldkey);
+        let mut page_index = page_count.saturating_sub(1);
+        let mut page = StakingHotkeyPages::<T>::get(coldkey, page_index);
+        if page_count == 0 || page.try_push(hotkey.clone()).is_err() {
+            page_index = page_count;
+            page = BoundedVec::truncate_from(vec![hotkey.clone()]);
+            StakingHotkeyPageCount::<T>::insert(coldkey, page_count.saturating_add(1));
+        }
+        StakingHotkeyPages::<T>::insert(coldkey, page_index, page);
+        StakingHotkeyPage::<T>::insert(coldkey, hotkey, page_index);
+    }
+
+    /// Returns a page of the hotkeys `coldkey` stakes to. The hotkeys not paged yet by
+    /// `migrate_page_staking_hotkeys` are listed as the page after the last one.
+    pub fn get_staking_hotkeys_page(coldkey: &T::AccountId, page_index: u32) -> Vec<T::AccountId> {
+        let page_count = Self::get_staking_hotkey_page_count(coldkey);
+        match page_index.cmp(&page_count) {
+            Ordering::Less => StakingHotkeyPages::<T>::get(coldkey, page_index).into_inner(),
+            Ordering::Equal => StakingHotkeys::<T>::get(coldkey),
+            Ordering::Greater => Vec::new(),
+        }
+    }
+
+    /// Returns the number of pages of the hotkeys `coldkey` stakes to.
+    pub fn get_staking_hotkey_page_count(coldkey: &T::AccountId) -> u32 {
+        StakingHotkeyPageCount::<T>::get(coldkey)
+    }
+
+    /// Returns every hotkey `coldkey` stakes to. Reads all of its pages, prefer
+    /// `get_staking_hotkeys_page` where the hotkeys can be processed a page at a time.
+    pub fn get_all_staked_hotkeys(coldkey: &T::AccountId) -> Vec<T::AccountId> {
+        let page_count = Self::get_staking_hotkey_page_count(coldkey);
+        (0..=page_count)
+            .flat_map(|page_index| Self::get_staking_hotkeys_page(coldkey, page_index))
+            .collect()
+    }
+}
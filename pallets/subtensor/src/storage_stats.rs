@@ -0,0 +1,101 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+/// Entry counts of the per-subnet maps that can outlive their neurons, and how many of those
+/// entries are orphaned, i.e. keyed by a netuid without any uid in `Uids`.
+#[freeze_struct("539425a51f1a38ea")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct StorageStats {
+    pub loaded_emission_entries: Compact<u32>,
+    pub loaded_emission_orphaned: Compact<u32>,
+    pub last_update_entries: Compact<u32>,
+    pub last_update_orphaned: Compact<u32>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_storage_stats() -> StorageStats {
+        let loaded_emission_orphaned = LoadedEmission::<T>::iter_keys()
+            .filter(|netuid| Self::is_orphaned_netuid(*netuid))
+            .count();
+        let last_update_orphaned = LastUpdate::<T>::iter_keys()
+            .filter(|netuid| Self::is_orphaned_netuid(*netuid))
+            .count();
+
+        StorageStats {
+            loaded_emission_entries: LoadedEmissionEntries::<T>::get().into(),
+            loaded_emission_orphaned: u32::try_from(loaded_emission_orphaned)
+                .unwrap_or(u32::MAX)
+                .into(),
+            last_update_entries: LastUpdateEntries::<T>::get().into(),
+            last_update_orphaned: u32::try_from(last_update_orphaned)
+                .unwrap_or(u32::MAX)
+                .into(),
+        }
+    }
+
+    /// ---- The implementation for the extrinsic sudo_prune_orphaned_storage.
+    ///
+    /// Removes at most `limit` orphaned LoadedEmission and LastUpdate entries, so that the
+    /// pruning can be spread over several calls. Returns the number of entries removed.
+    ///
+    pub fn do_prune_orphaned_storage(limit: u32) -> u32 {
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+        let loaded_emission: Vec<u16> = LoadedEmission::<T>::iter_keys()
+            .filter(|netuid| Self::is_orphaned_netuid(*netuid))
+            .take(limit)
+            .collect();
+        let last_update: Vec<u16> = LastUpdate::<T>::iter_keys()
+            .filter(|netuid| Self::is_orphaned_netuid(*netuid))
+            .take(limit.saturating_sub(loaded_emission.len()))
+            .collect();
+
+        for netuid in &loaded_emission {
+            Self::remove_loaded_emission(*netuid);
+        }
+        for netuid in &last_update {
+            Self::remove_last_update(*netuid);
+        }
+
+        let pruned = u32::try_from(loaded_emission.len().saturating_add(last_update.len()))
+            .unwrap_or(u32::MAX);
+        log::info!("OrphanedStoragePruned( entries:{:?} )", pruned);
+        Self::deposit_event(Event::OrphanedStoragePruned(pruned));
+        pruned
+    }
+
+    fn is_orphaned_netuid(netuid: u16) -> bool {
+        Uids::<T>::iter_prefix(netuid).next().is_none()
+    }
+
+    /// Writes the loaded emission of `netuid`, counting the entry if it is new.
+    pub fn insert_loaded_emission(netuid: u16, tuples: Vec<(T::AccountId, u64, u64)>) {
+        if !LoadedEmission::<T>::contains_key(netuid) {
+            LoadedEmissionEntries::<T>::mutate(|entries| *entries = entries.saturating_add(1));
+        }
+        LoadedEmission::<T>::insert(netuid, tuples);
+    }
+
+    pub fn remove_loaded_emission(netuid: u16) {
+        if LoadedEmission::<T>::contains_key(netuid) {
+            LoadedEmissionEntries::<T>::mutate(|entries| *entries = entries.saturating_sub(1));
+            LoadedEmission::<T>::remove(netuid);
+        }
+    }
+
+    /// Appends the last update of a new uid of `netuid`, counting the entry if it is new.
+    pub fn append_last_update(netuid: u16, block_number: u64) {
+        if !LastUpdate::<T>::contains_key(netuid) {
+            LastUpdateEntries::<T>::mutate(|entries| *entries = entries.saturating_add(1));
+        }
+        LastUpdate::<T>::mutate(netuid, |v| v.push(block_number));
+    }
+
+    pub fn remove_last_update(netuid: u16) {
+        if LastUpdate::<T>::contains_key(netuid) {
+            LastUpdateEntries::<T>::mutate(|entries| *entries = entries.saturating_sub(1));
+            LastUpdate::<T>::remove(netuid);
+        }
+    }
+}
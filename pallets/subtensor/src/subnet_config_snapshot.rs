@@ -0,0 +1,104 @@
+//! Typed snapshots of the configuration of a subnet, to replay production subnets in tests.
+#![cfg(any(feature = "test-utils", feature = "runtime-benchmarks"))]
+
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+
+// Declares the snapshot from one table of fields and the per-subnet storage they are read
+// from and restored to, so a field cannot be captured without being restored.
+macro_rules! subnet_config_snapshot {
+    ($($field:ident: $ty:ty => $storage:ident,)*) => {
+        /// The hyperparameters and counters of a subnet, as kept in storage.
+        #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+        pub struct SubnetConfigSnapshot<T: Config> {
+            pub netuid: u16,
+            $(pub $field: $ty,)*
+        }
+
+        impl<T: Config> Pallet<T> {
+            /// Returns the configuration of `netuid`, or nothing if the subnet does not exist.
+            pub fn capture_subnet_config(netuid: u16) -> Option<SubnetConfigSnapshot<T>> {
+                if !Self::if_subnet_exist(netuid) {
+                    return None;
+                }
+                Some(SubnetConfigSnapshot {
+                    netuid,
+                    $($field: $storage::<T>::get(netuid),)*
+                })
+            }
+
+            /// Writes `snapshot` back to storage, adding the subnet first if it does not
+            /// exist. Values are written as captured, without the events of their setters.
+            pub fn restore_subnet_config(snapshot: SubnetConfigSnapshot<T>) {
+                let netuid = snapshot.netuid;
+                if !Self::if_subnet_exist(netuid) {
+                    Self::init_new_network(netuid, snapshot.tempo);
+                }
+                $($storage::<T>::set(netuid, snapshot.$field);)*
+            }
+        }
+    };
+}
+
+subnet_config_snapshot! {
+    // Hyperparameters.
+    tempo: u16 => Tempo,
+    modality: u16 => NetworkModality,
+    owner: T::AccountId => SubnetOwner,
+    kappa: u16 => Kappa,
+    rho: u16 => Rho,
+    difficulty: u64 => Difficulty,
+    min_difficulty: u64 => MinDifficulty,
+    max_difficulty: u64 => MaxDifficulty,
+    burn: u64 => Burn,
+    min_burn: u64 => MinBurn,
+    max_burn: u64 => MaxBurn,
+    immunity_period: u16 => ImmunityPeriod,
+    activity_cutoff: u16 => ActivityCutoff,
+    max_allowed_uids: u16 => MaxAllowedUids,
+    max_allowed_uids_growth: Option<(u16, u64, u16, u64)> => MaxAllowedUidsGrowth,
+    max_allowed_validators: u16 => MaxAllowedValidators,
+    max_miner_uids: u16 => MaxMinerUids,
+    min_allowed_weights: u16 => MinAllowedWeights,
+    max_weights_limit: u16 => MaxWeightsLimit,
+    weights_version_key: u64 => WeightsVersionKey,
+    weights_set_rate_limit: u64 => WeightsSetRateLimit,
+    commit_reveal_weights_interval: u64 => WeightCommitRevealInterval,
+    adjustment_interval: u16 => AdjustmentInterval,
+    adjustment_alpha: u64 => AdjustmentAlpha,
+    target_registrations_per_interval: u16 => TargetRegistrationsPerInterval,
+    max_registrations_per_block: u16 => MaxRegistrationsPerBlock,
+    max_pow_registrations_per_block: u16 => MaxPOWRegistrationsPerBlock,
+    max_burn_registrations_per_block: u16 => MaxBurnRegistrationsPerBlock,
+    network_registration_allowed: bool => NetworkRegistrationAllowed,
+    network_pow_registration_allowed: bool => NetworkPowRegistrationAllowed,
+    min_stake_to_register: u64 => MinStakeToRegister,
+    serving_rate_limit: u64 => ServingRateLimit,
+    axon_ttl_blocks: u64 => AxonTTLBlocks,
+    max_commitment_size: u32 => MaxCommitmentSize,
+    commitment_rate_limit: u64 => CommitmentRateLimit,
+    bonds_moving_average: u64 => BondsMovingAverage,
+    validator_prune_len: u64 => ValidatorPruneLen,
+    scaling_law_power: u16 => ScalingLawPower,
+    alpha_values: (u16, u16) => AlphaValues,
+    bootstrap_incentive_floor: u16 => BootstrapIncentiveFloor,
+    emission_split: Option<EmissionSplit> => SubnetEmissionSplit,
+    feature_flags: u32 => SubnetFeatureFlags,
+    // Counters.
+    registered_at: u64 => NetworkRegisteredAt,
+    last_adjustment_block: u64 => LastAdjustmentBlock,
+    registrations_this_block: u16 => RegistrationsThisBlock,
+    pow_registrations_this_block: u16 => POWRegistrationsThisBlock,
+    burn_registrations_this_block: u16 => BurnRegistrationsThisBlock,
+    registrations_this_interval: u16 => RegistrationsThisInterval,
+    pow_registrations_this_interval: u16 => POWRegistrationsThisInterval,
+    burn_registrations_this_interval: u16 => BurnRegistrationsThisInterval,
+    pow_difficulty_solved: u128 => PowDifficultySolved,
+    pow_difficulty_solved_this_interval: u128 => PowDifficultySolvedThisInterval,
+    rao_recycled_for_registration: u64 => RAORecycledForRegistration,
+    emission_value: u64 => EmissionValues,
+    pending_emission: u64 => PendingEmission,
+    blocks_since_last_step: u64 => BlocksSinceLastStep,
+    last_mechanism_step_block: u64 => LastMechansimStepBlock,
+    epoch_index: u64 => EpochIndex,
+}
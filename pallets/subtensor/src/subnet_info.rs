@@ -1,9 +1,22 @@
 use super::*;
 use frame_support::pallet_prelude::{Decode, Encode};
-use frame_support::storage::IterableStorageMap;
+use frame_support::storage::IterableStorageDoubleMap;
 extern crate alloc;
 use codec::Compact;
 
+/// The tokens recycled into a subnet and its emission pool.
+#[freeze_struct("e491119b128dbd3")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetRecycleInfo {
+    pub netuid: Compact<u16>,
+    pub recycled_for_registration: Compact<u64>,
+    pub recycled_into_emission: Compact<u64>,
+    pub pending_emission: Compact<u64>,
+}
+
+/// A row of a sparse uid by uid matrix, `(uid, [(uid, value)])`.
+pub type SparseMatrixRow = (Compact<u16>, Vec<(Compact<u16>, Compact<u16>)>);
+
 #[freeze_struct("fe79d58173da662a")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct SubnetInfo<T: Config> {
@@ -27,6 +40,15 @@ pub struct SubnetInfo<T: Config> {
     owner: T::AccountId,
 }
 
+/// `SubnetInfo` followed by the subnet's token symbol and name, each empty when unset.
+#[freeze_struct("39192e3eb9fd2f7e")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetInfoV2<T: Config> {
+    info: SubnetInfo<T>,
+    symbol: Vec<u8>,
+    token_name: Vec<u8>,
+}
+
 #[freeze_struct("55b472510f10e76a")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct SubnetHyperparams {
@@ -74,7 +96,11 @@ impl<T: Config> Pallet<T> {
         let max_weights_limit = Self::get_max_weight_limit(netuid);
         let scaling_law_power = Self::get_scaling_law_power(netuid);
         let subnetwork_n = Self::get_subnetwork_n(netuid);
-        let max_allowed_uids = Self::get_max_allowed_uids(netuid);
+        let max_allowed_uids = if netuid == Self::get_root_netuid() {
+            Self::get_max_root_validators()
+        } else {
+            Self::get_max_allowed_uids(netuid)
+        };
         let blocks_since_last_step = Self::get_blocks_since_last_step(netuid);
         let tempo = Self::get_tempo(netuid);
         let network_modality = <NetworkModality<T>>::get(netuid);
@@ -110,25 +136,30 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn get_subnets_info() -> Vec<Option<SubnetInfo<T>>> {
-        let mut subnet_netuids = Vec::<u16>::new();
-        let mut max_netuid: u16 = 0;
-        for (netuid, added) in <NetworksAdded<T> as IterableStorageMap<u16, bool>>::iter() {
-            if added {
-                subnet_netuids.push(netuid);
-                if netuid > max_netuid {
-                    max_netuid = netuid;
-                }
-            }
-        }
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .map(Self::get_subnet_info)
+            .collect()
+    }
 
-        let mut subnets_info = Vec::<Option<SubnetInfo<T>>>::new();
-        for netuid_ in 0..=max_netuid {
-            if subnet_netuids.contains(&netuid_) {
-                subnets_info.push(Self::get_subnet_info(netuid_));
-            }
-        }
+    pub fn get_subnet_info_v2(netuid: u16) -> Option<SubnetInfoV2<T>> {
+        Self::get_subnet_info(netuid).map(Self::with_token_metadata)
+    }
+
+    pub fn get_subnets_info_v2() -> Vec<Option<SubnetInfoV2<T>>> {
+        Self::get_subnets_info()
+            .into_iter()
+            .map(|info| info.map(Self::with_token_metadata))
+            .collect()
+    }
 
-        subnets_info
+    fn with_token_metadata(info: SubnetInfo<T>) -> SubnetInfoV2<T> {
+        let netuid = info.netuid.0;
+        SubnetInfoV2 {
+            info,
+            symbol: Self::get_subnet_symbol(netuid),
+            token_name: Self::get_subnet_token_name(netuid),
+        }
     }
 
     pub fn get_subnet_hyperparams(netuid: u16) -> Option<SubnetHyperparams> {
@@ -193,4 +224,64 @@ impl<T: Config> Pallet<T> {
             liquid_alpha_enabled,
         })
     }
+
+    pub fn get_subnet_recycle_info(netuid: u16) -> Option<SubnetRecycleInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        Some(SubnetRecycleInfo {
+            netuid: netuid.into(),
+            recycled_for_registration: Self::get_rao_recycled(netuid).into(),
+            recycled_into_emission: Self::get_rao_recycled_into_emission(netuid).into(),
+            pending_emission: Self::get_pending_emission(netuid).into(),
+        })
+    }
+
+    /// Sorts the rows of a sparse matrix by uid, dropping zero entries and empty rows.
+    fn sparse_matrix_rows(
+        rows: impl Iterator<Item = (u16, Vec<(u16, u16)>)>,
+    ) -> Vec<SparseMatrixRow> {
+        let mut rows: Vec<(u16, Vec<(u16, u16)>)> = rows
+            .map(|(uid, row)| {
+                let row: Vec<(u16, u16)> = row.into_iter().filter(|(_, v)| *v > 0).collect();
+                (uid, row)
+            })
+            .filter(|(_, row)| !row.is_empty())
+            .collect();
+        rows.sort_by_key(|(uid, _)| *uid);
+        rows.into_iter()
+            .map(|(uid, row)| {
+                (
+                    uid.into(),
+                    row.into_iter()
+                        .map(|(uid_j, value)| (uid_j.into(), value.into()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// The weight matrix of a subnet as stored, one row per validator that has set weights.
+    pub fn get_subnet_weights_sparse(netuid: u16) -> Vec<SparseMatrixRow> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+        Self::sparse_matrix_rows(
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRow>>::iter_prefix(netuid)
+                .map(|(uid, row)| (uid, row.entries())),
+        )
+    }
+
+    /// The bond matrix of a subnet as stored, one row per validator that holds bonds.
+    pub fn get_subnet_bonds_sparse(netuid: u16) -> Vec<SparseMatrixRow> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+        Self::sparse_matrix_rows(<Bonds<T> as IterableStorageDoubleMap<
+            u16,
+            u16,
+            Vec<(u16, u16)>,
+        >>::iter_prefix(netuid))
+    }
 }
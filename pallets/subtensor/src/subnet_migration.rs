@@ -0,0 +1,466 @@
+use super::*;
+use codec::FullCodec;
+use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::weights::Weight;
+use sp_std::vec::Vec;
+
+/// Number of netuid-keyed double maps moved entry by entry, see `move_subnet_migration_stage`.
+const SUBNET_MIGRATION_STAGES: u8 = 28;
+
+/// Upper bound on the netuid-keyed maps moved at once as a migration finishes.
+const SUBNET_MIGRATION_PARAMETER_MAPS: u64 = 100;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic sudo_migrate_subnet.
+    ///
+    /// Starts moving the subnet `old_netuid` to `new_netuid`. The subnet is taken offline,
+    /// and on_idle moves its neurons, weights, bonds, axons and the rest of its per-neuron
+    /// storage as far as the block weight allows, then its parameters, owner and emission in
+    /// one go, putting it back online under the new netuid.
+    ///
+    /// # Event:
+    /// * SubnetMigrationStarted;
+    ///     - On successfully starting the migration.
+    ///
+    /// # Raises:
+    /// * 'SubnetMigrationInProgress':
+    ///     - A subnet is already moving to another netuid.
+    ///
+    /// * 'CannotMigrateRootNetwork':
+    ///     - The old netuid is the root network.
+    ///
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The old netuid has no subnet.
+    ///
+    /// * 'NetuidAlreadyInUse':
+    ///     - The new netuid is the root network, has a subnet or is reserved.
+    ///
+    /// * 'NetuidQuarantined':
+    ///     - The new netuid was removed less than NetuidQuarantinePeriod blocks ago.
+    ///
+    pub fn do_start_subnet_migration(old_netuid: u16, new_netuid: u16) -> DispatchResult {
+        ensure!(
+            SubnetMigrationInProgress::<T>::get().is_none(),
+            Error::<T>::SubnetMigrationInProgress
+        );
+        let root_netuid = Self::get_root_netuid();
+        ensure!(
+            old_netuid != root_netuid,
+            Error::<T>::CannotMigrateRootNetwork
+        );
+        ensure!(
+            Self::if_subnet_exist(old_netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            new_netuid != root_netuid
+                && !Self::if_subnet_exist(new_netuid)
+                && !Self::is_netuid_reserved(new_netuid),
+            Error::<T>::NetuidAlreadyInUse
+        );
+        ensure!(
+            !Self::is_netuid_quarantined(new_netuid),
+            Error::<T>::NetuidQuarantined
+        );
+
+        // Nothing reaches the subnet until it is back under its new netuid.
+        Self::remove_subnet_netuid(old_netuid);
+        Self::drop_netuid_from_root_epoch(old_netuid);
+        SubnetMigrationInProgress::<T>::put(SubnetMigration {
+            old_netuid,
+            new_netuid,
+            stage: 0,
+            moved: 0,
+        });
+
+        log::info!(
+            "SubnetMigrationStarted( old_netuid:{:?}, new_netuid:{:?} )",
+            old_netuid,
+            new_netuid
+        );
+        Self::deposit_subnet_event(
+            &[old_netuid, new_netuid],
+            Event::SubnetMigrationStarted(old_netuid, new_netuid),
+        );
+        Ok(())
+    }
+
+    /// Returns true if a subnet is moving from or to `netuid`.
+    pub fn is_subnet_migrating(netuid: u16) -> bool {
+        SubnetMigrationInProgress::<T>::get().is_some_and(|migration| {
+            migration.old_netuid == netuid || migration.new_netuid == netuid
+        })
+    }
+
+    /// Moves as much of the migrating subnet as fits in `remaining_weight`, finishing the
+    /// migration once every stage is done and its parameters fit too. Returns the weight used.
+    pub fn advance_subnet_migration(remaining_weight: Weight) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        if weight.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+        let Some(mut migration) = SubnetMigrationInProgress::<T>::get() else {
+            return weight;
+        };
+        let (old_netuid, new_netuid) = (migration.old_netuid, migration.new_netuid);
+
+        let mut moved: u64 = 0;
+        let mut finished = false;
+        loop {
+            if migration.stage >= SUBNET_MIGRATION_STAGES {
+                let finish_weight = Self::subnet_migration_finish_weight();
+                if weight
+                    .saturating_add(finish_weight)
+                    .all_lte(remaining_weight)
+                {
+                    weight.saturating_accrue(finish_weight);
+                    finished = true;
+                }
+                break;
+            }
+
+            // Each stage reads once past its last entry to find it is done.
+            let entry_weight = Self::subnet_migration_entry_weight(migration.stage);
+            let budget = remaining_weight
+                .saturating_sub(weight.saturating_add(T::DbWeight::get().reads(1)))
+                .checked_div_per_component(&entry_weight)
+                .unwrap_or(u64::MAX);
+            if budget == 0 {
+                break;
+            }
+            let budget = usize::try_from(budget).unwrap_or(usize::MAX);
+            let stage_moved =
+                Self::move_subnet_migration_stage(migration.stage, old_netuid, new_netuid, budget);
+            let stage_moved_u64 = u64::try_from(stage_moved).unwrap_or(u64::MAX);
+            weight.saturating_accrue(
+                T::DbWeight::get()
+                    .reads(1)
+                    .saturating_add(entry_weight.saturating_mul(stage_moved_u64)),
+            );
+            moved = moved.saturating_add(stage_moved_u64);
+            if stage_moved >= budget {
+                break;
+            }
+            migration.stage = migration.stage.saturating_add(1);
+        }
+
+        migration.moved = migration.moved.saturating_add(moved);
+        if moved > 0 {
+            log::info!(
+                "SubnetMigrationProgressed( old_netuid:{:?}, new_netuid:{:?}, stage:{:?}, moved:{:?} )",
+                old_netuid,
+                new_netuid,
+                migration.stage,
+                migration.moved
+            );
+            Self::deposit_subnet_event(
+                &[old_netuid, new_netuid],
+                Event::SubnetMigrationProgressed(
+                    old_netuid,
+                    new_netuid,
+                    migration.stage,
+                    migration.moved,
+                ),
+            );
+        }
+        if finished {
+            Self::finish_subnet_migration(old_netuid, new_netuid);
+        } else {
+            SubnetMigrationInProgress::<T>::put(migration);
+        }
+        weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Moves at most `budget` entries of the netuid-keyed double map of `stage` to the new
+    /// netuid, returning how many it moved. A stage moving fewer than `budget` is done. The
+    /// neurons move first, carrying their network membership, then their axons, carrying
+    /// the ip index, then everything else that is kept per neuron or per hotkey.
+    fn move_subnet_migration_stage(
+        stage: u8,
+        old_netuid: u16,
+        new_netuid: u16,
+        budget: usize,
+    ) -> usize {
+        match stage {
+            0 => Self::move_subnet_keys(old_netuid, new_netuid, budget),
+            1 => move_prefix::<Uids<T>, _, _>(old_netuid, new_netuid, budget),
+            2 => {
+                let hotkeys: Vec<T::AccountId> = Axons::<T>::iter_key_prefix(old_netuid)
+                    .take(budget)
+                    .collect();
+                Self::move_subnet_axons(old_netuid, new_netuid, hotkeys)
+            }
+            3 => {
+                let hotkeys: Vec<T::AccountId> = AxonReplicas::<T>::iter_key_prefix(old_netuid)
+                    .take(budget)
+                    .collect();
+                Self::move_subnet_axons(old_netuid, new_netuid, hotkeys)
+            }
+            4 => move_prefix::<NeuronCertificates<T>, _, _>(old_netuid, new_netuid, budget),
+            5 => move_prefix::<Prometheus<T>, _, _>(old_netuid, new_netuid, budget),
+            6 => move_prefix::<LastNeuronClearBlock<T>, _, _>(old_netuid, new_netuid, budget),
+            7 => move_prefix::<BlockAtRegistration<T>, _, _>(old_netuid, new_netuid, budget),
+            8 => move_prefix::<RetiredUids<T>, _, _>(old_netuid, new_netuid, budget),
+            9 => move_prefix::<RegistrationBurn<T>, _, _>(old_netuid, new_netuid, budget),
+            10 => move_prefix::<DeclaredRoles<T>, _, _>(old_netuid, new_netuid, budget),
+            11 => move_prefix::<Weights<T>, _, _>(old_netuid, new_netuid, budget),
+            12 => move_prefix::<Bonds<T>, _, _>(old_netuid, new_netuid, budget),
+            13 => move_prefix::<WeightCommits<T>, _, _>(old_netuid, new_netuid, budget),
+            14 => move_prefix::<WeightsMetadataHash<T>, _, _>(old_netuid, new_netuid, budget),
+            15 => move_prefix::<WeightsTargetEpoch<T>, _, _>(old_netuid, new_netuid, budget),
+            16 => move_prefix::<WeightsKeys<T>, _, _>(old_netuid, new_netuid, budget),
+            17 => move_prefix::<WeightsKeyHotkey<T>, _, _>(old_netuid, new_netuid, budget),
+            18 => move_prefix::<EpochPauseSignals<T>, _, _>(old_netuid, new_netuid, budget),
+            19 => move_prefix::<AxonAttestations<T>, _, _>(old_netuid, new_netuid, budget),
+            20 => move_prefix::<ChildKeys<T>, _, _>(old_netuid, new_netuid, budget),
+            21 => move_prefix::<ParentKeys<T>, _, _>(old_netuid, new_netuid, budget),
+            22 => Self::move_pending_child_keys(old_netuid, new_netuid, budget),
+            23 => move_prefix::<AssociatedEvmAddress<T>, _, _>(old_netuid, new_netuid, budget),
+            24 => move_prefix::<LastOwnerHyperparamUpdate<T>, _, _>(old_netuid, new_netuid, budget),
+            25 => move_prefix::<ReferendumVotes<T>, _, _>(old_netuid, new_netuid, budget),
+            #[cfg(feature = "commitments")]
+            26 => move_prefix::<Commitments<T>, _, _>(old_netuid, new_netuid, budget),
+            #[cfg(feature = "commitments")]
+            27 => move_prefix::<LastCommitmentBlock<T>, _, _>(old_netuid, new_netuid, budget),
+            _ => 0,
+        }
+    }
+
+    /// The weight of moving one entry of the double map of `stage`.
+    fn subnet_migration_entry_weight(stage: u8) -> Weight {
+        match stage {
+            // The key and the network membership of its hotkey.
+            0 => T::DbWeight::get().reads_writes(1, 4),
+            // The axon, its replicas and an index entry per ip they serve on.
+            2 | 3 => T::DbWeight::get().reads_writes(
+                3,
+                u64::from(MAX_AXONS_PER_NEURON)
+                    .saturating_mul(2)
+                    .saturating_add(4),
+            ),
+            // The pending child keys and their entry in the apply index.
+            22 => T::DbWeight::get().reads_writes(1, 3),
+            _ => T::DbWeight::get().reads_writes(1, 2),
+        }
+    }
+
+    /// The weight of `finish_subnet_migration`: two reads and writes per parameter map, and
+    /// one per root weight row.
+    fn subnet_migration_finish_weight() -> Weight {
+        let root_rows = u64::from(Self::get_subnetwork_n(Self::get_root_netuid()));
+        let operations = SUBNET_MIGRATION_PARAMETER_MAPS
+            .saturating_mul(2)
+            .saturating_add(root_rows)
+            .saturating_add(8);
+        T::DbWeight::get().reads_writes(operations, operations)
+    }
+
+    /// Moves at most `budget` uids of the subnet, and the network membership of their hotkeys.
+    fn move_subnet_keys(old_netuid: u16, new_netuid: u16, budget: usize) -> usize {
+        let keys: Vec<(u16, T::AccountId)> =
+            Keys::<T>::drain_prefix(old_netuid).take(budget).collect();
+        for (uid, hotkey) in keys.iter() {
+            IsNetworkMember::<T>::remove(hotkey, old_netuid);
+            IsNetworkMember::<T>::insert(hotkey, new_netuid, true);
+            Keys::<T>::insert(new_netuid, uid, hotkey);
+        }
+        keys.len()
+    }
+
+    /// Moves at most `budget` pending child keys of the subnet, indexing them again under the
+    /// new netuid. The entries left under the old netuid are skipped when their block comes.
+    fn move_pending_child_keys(old_netuid: u16, new_netuid: u16, budget: usize) -> usize {
+        let pending: Vec<(T::AccountId, (Vec<(u64, T::AccountId)>, u64))> =
+            PendingChildKeys::<T>::drain_prefix(old_netuid)
+                .take(budget)
+                .collect();
+        let moved = pending.len();
+        for (hotkey, (children, cooldown_block)) in pending {
+            Self::schedule_pending_child_keys(new_netuid, &hotkey, cooldown_block);
+            PendingChildKeys::<T>::insert(new_netuid, hotkey, (children, cooldown_block));
+        }
+        moved
+    }
+
+    /// Moves the axons and axon replicas of `hotkeys`, reindexing the ips they serve on.
+    fn move_subnet_axons(old_netuid: u16, new_netuid: u16, hotkeys: Vec<T::AccountId>) -> usize {
+        for hotkey in hotkeys.iter() {
+            for ip in Self::get_axon_ips(old_netuid, hotkey) {
+                AxonIpIndex::<T>::remove(ip, (old_netuid, hotkey.clone()));
+                AxonIpIndex::<T>::insert(ip, (new_netuid, hotkey.clone()), ());
+            }
+            if let Some(axon) = Axons::<T>::take(old_netuid, hotkey) {
+                Axons::<T>::insert(new_netuid, hotkey, axon);
+            }
+            AxonReplicas::<T>::swap(old_netuid, hotkey, new_netuid, hotkey);
+        }
+        hotkeys.len()
+    }
+
+    /// Moves the parameters, owner and emission of the subnet, points the root weights at
+    /// the new netuid and puts the subnet back online under it, leaving a tombstone of the
+    /// old netuid.
+    fn finish_subnet_migration(old_netuid: u16, new_netuid: u16) {
+        // --- 1. Move the network state and parameters.
+        SubnetworkN::<T>::swap(old_netuid, new_netuid);
+        NetworkModality::<T>::swap(old_netuid, new_netuid);
+        NetworkRegisteredAt::<T>::swap(old_netuid, new_netuid);
+        NetworkRegistrationAllowed::<T>::swap(old_netuid, new_netuid);
+        NetworkPowRegistrationAllowed::<T>::swap(old_netuid, new_netuid);
+        SubnetOwner::<T>::swap(old_netuid, new_netuid);
+        SubnetOwnerHotkey::<T>::swap(old_netuid, new_netuid);
+        PendingSubnetOwner::<T>::swap(old_netuid, new_netuid);
+        SubnetLocked::<T>::swap(old_netuid, new_netuid);
+        SubnetSymbol::<T>::swap(old_netuid, new_netuid);
+        SubnetTokenName::<T>::swap(old_netuid, new_netuid);
+        #[cfg(feature = "identity")]
+        SubnetIdentities::<T>::swap(old_netuid, new_netuid);
+        SubnetFeatureFlags::<T>::swap(old_netuid, new_netuid);
+        MaintenanceWindow::<T>::swap(old_netuid, new_netuid);
+        Tempo::<T>::swap(old_netuid, new_netuid);
+        Kappa::<T>::swap(old_netuid, new_netuid);
+        MaxStakeWeightFraction::<T>::swap(old_netuid, new_netuid);
+        Rho::<T>::swap(old_netuid, new_netuid);
+        Difficulty::<T>::swap(old_netuid, new_netuid);
+        MinDifficulty::<T>::swap(old_netuid, new_netuid);
+        MaxDifficulty::<T>::swap(old_netuid, new_netuid);
+        Burn::<T>::swap(old_netuid, new_netuid);
+        MinBurn::<T>::swap(old_netuid, new_netuid);
+        MaxBurn::<T>::swap(old_netuid, new_netuid);
+        AdjustmentInterval::<T>::swap(old_netuid, new_netuid);
+        AdjustmentAlpha::<T>::swap(old_netuid, new_netuid);
+        LastAdjustmentBlock::<T>::swap(old_netuid, new_netuid);
+        TargetRegistrationsPerInterval::<T>::swap(old_netuid, new_netuid);
+        RegistrationsThisBlock::<T>::swap(old_netuid, new_netuid);
+        POWRegistrationsThisBlock::<T>::swap(old_netuid, new_netuid);
+        BurnRegistrationsThisBlock::<T>::swap(old_netuid, new_netuid);
+        MaxRegistrationsPerBlock::<T>::swap(old_netuid, new_netuid);
+        MaxPOWRegistrationsPerBlock::<T>::swap(old_netuid, new_netuid);
+        MaxBurnRegistrationsPerBlock::<T>::swap(old_netuid, new_netuid);
+        RegistrationsThisInterval::<T>::swap(old_netuid, new_netuid);
+        POWRegistrationsThisInterval::<T>::swap(old_netuid, new_netuid);
+        BurnRegistrationsThisInterval::<T>::swap(old_netuid, new_netuid);
+        PowDifficultySolved::<T>::swap(old_netuid, new_netuid);
+        PowDifficultySolvedThisInterval::<T>::swap(old_netuid, new_netuid);
+        PowIntervalAverages::<T>::swap(old_netuid, new_netuid);
+        RAORecycledForRegistration::<T>::swap(old_netuid, new_netuid);
+        MinStakeToRegister::<T>::swap(old_netuid, new_netuid);
+        MaxAllowedUids::<T>::swap(old_netuid, new_netuid);
+        MaxAllowedUidsGrowth::<T>::swap(old_netuid, new_netuid);
+        MaxAllowedValidators::<T>::swap(old_netuid, new_netuid);
+        MaxMinerUids::<T>::swap(old_netuid, new_netuid);
+        ImmunityPeriod::<T>::swap(old_netuid, new_netuid);
+        ActivityCutoff::<T>::swap(old_netuid, new_netuid);
+        NeuronsToPruneAtNextEpoch::<T>::swap(old_netuid, new_netuid);
+        ValidatorPruneLen::<T>::swap(old_netuid, new_netuid);
+        ScalingLawPower::<T>::swap(old_netuid, new_netuid);
+        ServingRateLimit::<T>::swap(old_netuid, new_netuid);
+        AxonTTLBlocks::<T>::swap(old_netuid, new_netuid);
+        MaxCommitmentSize::<T>::swap(old_netuid, new_netuid);
+        CommitmentRateLimit::<T>::swap(old_netuid, new_netuid);
+        MaxWeightsLimit::<T>::swap(old_netuid, new_netuid);
+        MinAllowedWeights::<T>::swap(old_netuid, new_netuid);
+        WeightsPrecision::<T>::swap(old_netuid, new_netuid);
+        WeightsVersionKey::<T>::swap(old_netuid, new_netuid);
+        WeightsSetRateLimit::<T>::swap(old_netuid, new_netuid);
+        WeightCommitRevealInterval::<T>::swap(old_netuid, new_netuid);
+        WeightsSweepCursor::<T>::swap(old_netuid, new_netuid);
+        WeightsRescalePending::<T>::swap(old_netuid, new_netuid);
+        WeightsPausedUntil::<T>::swap(old_netuid, new_netuid);
+        BondsMovingAverage::<T>::swap(old_netuid, new_netuid);
+        BondsResetCursor::<T>::swap(old_netuid, new_netuid);
+        AlphaValues::<T>::swap(old_netuid, new_netuid);
+        BootstrapIncentiveFloor::<T>::swap(old_netuid, new_netuid);
+        MaxTakePerSubnet::<T>::swap(old_netuid, new_netuid);
+        EpochPausedUntil::<T>::swap(old_netuid, new_netuid);
+        SubnetReferendum::<T>::swap(old_netuid, new_netuid);
+
+        // --- 2. Move the emission and the results of the last epoch.
+        EmissionValues::<T>::swap(old_netuid, new_netuid);
+        EmissionMultiplier::<T>::swap(old_netuid, new_netuid);
+        SubnetEmissionSplit::<T>::swap(old_netuid, new_netuid);
+        PendingEmission::<T>::swap(old_netuid, new_netuid);
+        LoadedEmission::<T>::swap(old_netuid, new_netuid);
+        EmissionHistory::<T>::swap(old_netuid, new_netuid);
+        RAORecycledIntoEmission::<T>::swap(old_netuid, new_netuid);
+        SubnetUtilization::<T>::swap(old_netuid, new_netuid);
+        BlocksSinceLastStep::<T>::swap(old_netuid, new_netuid);
+        LastMechansimStepBlock::<T>::swap(old_netuid, new_netuid);
+        EpochIndex::<T>::swap(old_netuid, new_netuid);
+        EpochSnapshots::<T>::swap(old_netuid, new_netuid);
+        EvictionLog::<T>::swap(old_netuid, new_netuid);
+        Active::<T>::swap(old_netuid, new_netuid);
+        Rank::<T>::swap(old_netuid, new_netuid);
+        Trust::<T>::swap(old_netuid, new_netuid);
+        Consensus::<T>::swap(old_netuid, new_netuid);
+        Incentive::<T>::swap(old_netuid, new_netuid);
+        Dividends::<T>::swap(old_netuid, new_netuid);
+        Emission::<T>::swap(old_netuid, new_netuid);
+        LastUpdate::<T>::swap(old_netuid, new_netuid);
+        ValidatorTrust::<T>::swap(old_netuid, new_netuid);
+        ValidatorPermit::<T>::swap(old_netuid, new_netuid);
+        PruningScores::<T>::swap(old_netuid, new_netuid);
+
+        // --- 3. Point the root weights at the new netuid.
+        let root_netuid = Self::get_root_netuid();
+        let rows: Vec<(u16, WeightsRow)> = Weights::<T>::iter_prefix(root_netuid).collect();
+        for (uid, row) in rows {
+            let mut entries = row.entries();
+            if !entries.iter().any(|(netuid, _)| *netuid == old_netuid) {
+                continue;
+            }
+            // Weights left on a network removed from the new netuid are dropped.
+            entries.retain(|(netuid, _)| *netuid != new_netuid);
+            for (netuid, _) in entries.iter_mut() {
+                if *netuid == old_netuid {
+                    *netuid = new_netuid;
+                }
+            }
+            entries.sort_by_key(|(netuid, _)| *netuid);
+            Weights::<T>::insert(root_netuid, uid, WeightsRow::from(entries));
+        }
+
+        // --- 4. Put the subnet back online under the new netuid.
+        Self::add_subnet_netuid(new_netuid);
+        NetuidTombstones::<T>::remove(new_netuid);
+        NetuidTombstones::<T>::insert(
+            old_netuid,
+            (
+                Self::get_current_block_as_u64(),
+                SubnetOwner::<T>::get(new_netuid),
+            ),
+        );
+        SubnetMigrationInProgress::<T>::kill();
+
+        log::info!(
+            "SubnetMigrated( old_netuid:{:?}, new_netuid:{:?} )",
+            old_netuid,
+            new_netuid
+        );
+        Self::deposit_subnet_event(
+            &[old_netuid, new_netuid],
+            Event::SubnetMigrated(old_netuid, new_netuid),
+        );
+        T::OnSubtensorEvent::on_network_migrated(old_netuid, new_netuid);
+    }
+
+    pub fn get_subnet_migration() -> Option<SubnetMigration> {
+        SubnetMigrationInProgress::<T>::get()
+    }
+}
+
+/// Moves at most `budget` entries of the double map `M` from the old to the new netuid,
+/// returning how many it moved.
+fn move_prefix<M, K2, V>(old_netuid: u16, new_netuid: u16, budget: usize) -> usize
+where
+    M: IterableStorageDoubleMap<u16, K2, V>,
+    K2: FullCodec,
+    V: FullCodec,
+{
+    let entries: Vec<(K2, V)> = M::drain_prefix(old_netuid).take(budget).collect();
+    let moved = entries.len();
+    for (key, value) in entries {
+        M::insert(new_netuid, key, value);
+    }
+    moved
+}
@@ -0,0 +1,115 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic transfer_subnet_ownership.
+    ///
+    /// Proposes `new_owner` as the owner of `netuid`, who takes it over with
+    /// accept_subnet_ownership. A new proposal replaces the pending one.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'NotSubnetOwner':
+    ///     - The caller is not the owner coldkey of the subnet.
+    ///
+    /// * 'ColdkeyIsInArbitration':
+    ///     - The owner coldkey has a coldkey swap in arbitration.
+    ///
+    /// * 'SameColdkey':
+    ///     - The new owner is the current owner.
+    ///
+    pub fn do_transfer_subnet_ownership(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        new_owner: T::AccountId,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            SubnetOwner::<T>::get(netuid) == coldkey,
+            Error::<T>::NotSubnetOwner
+        );
+        ensure!(
+            !Self::coldkey_in_arbitration(&coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+        ensure!(new_owner != coldkey, Error::<T>::SameColdkey);
+
+        PendingSubnetOwner::<T>::insert(netuid, new_owner.clone());
+
+        log::info!(
+            "SubnetOwnershipTransferProposed( netuid:{:?}, owner:{:?}, new_owner:{:?} )",
+            netuid,
+            coldkey,
+            new_owner
+        );
+        Self::deposit_event(Event::SubnetOwnershipTransferProposed(
+            netuid, coldkey, new_owner,
+        ));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic accept_subnet_ownership.
+    ///
+    /// Makes the pending owner of `netuid` its owner. The subnet lock in SubnetLocked and the
+    /// owner cut follow SubnetOwner, so they go to the new owner from now on. The operator
+    /// key set by the previous owner is revoked, see `hand_over_subnet`.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'NotPendingSubnetOwner':
+    ///     - The caller is not the pending owner of the subnet.
+    ///
+    /// * 'ColdkeyIsInArbitration':
+    ///     - The current owner coldkey has a coldkey swap in arbitration.
+    ///
+    pub fn do_accept_subnet_ownership(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            PendingSubnetOwner::<T>::get(netuid).as_ref() == Some(&coldkey),
+            Error::<T>::NotPendingSubnetOwner
+        );
+        let old_owner = SubnetOwner::<T>::get(netuid);
+        ensure!(
+            !Self::coldkey_in_arbitration(&old_owner),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+
+        Self::hand_over_subnet(netuid, &coldkey);
+
+        log::info!(
+            "SubnetOwnershipTransferred( netuid:{:?}, old_owner:{:?}, new_owner:{:?} )",
+            netuid,
+            old_owner,
+            coldkey
+        );
+        Self::deposit_event(Event::SubnetOwnershipTransferred(
+            netuid, old_owner, coldkey,
+        ));
+        Ok(())
+    }
+
+    /// Makes `new_owner` the owner of `netuid`, for both an accepted transfer and a key
+    /// rotation. The operator key and any pending transfer are cleared, and the rotation
+    /// nonce is bumped so that no rotation proof signed for an earlier owner stays valid.
+    pub(crate) fn hand_over_subnet(netuid: u16, new_owner: &T::AccountId) {
+        PendingSubnetOwner::<T>::remove(netuid);
+        SubnetOwner::<T>::insert(netuid, new_owner.clone());
+        SubnetOwnerHotkey::<T>::remove(netuid);
+        SubnetOwnerRotationNonce::<T>::mutate(netuid, |nonce| *nonce = nonce.saturating_add(1));
+    }
+
+    pub fn get_pending_subnet_owner(netuid: u16) -> Option<T::AccountId> {
+        PendingSubnetOwner::<T>::get(netuid)
+    }
+}
@@ -0,0 +1,215 @@
+use super::*;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic propose_subnet_hyperparam.
+    ///
+    /// Puts a change of `param` of `netuid` to `value` to a vote of the subnet's permitted
+    /// validators, open for ReferendumDuration blocks. block_step applies the change once the
+    /// referendum closes if it passed.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'ReferendumAlreadyOpen':
+    ///     - The subnet already has an open referendum.
+    ///
+    /// * 'ReferendumValueOutOfBounds':
+    ///     - The value is outside the bounds the subnet owner can set the hyperparameter to.
+    ///
+    pub fn do_propose_subnet_hyperparam(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        param: OwnerHyperparam,
+        value: u16,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            !SubnetReferendum::<T>::contains_key(netuid),
+            Error::<T>::ReferendumAlreadyOpen
+        );
+        let (min, max) = Self::get_owner_hyperparam_bounds(netuid, param);
+        ensure!(
+            (min..=max).contains(&value),
+            Error::<T>::ReferendumValueOutOfBounds
+        );
+
+        let ends_at =
+            Self::get_current_block_as_u64().saturating_add(Self::get_referendum_duration());
+        SubnetReferendum::<T>::insert(netuid, (param, value, ends_at));
+
+        log::info!(
+            "SubnetReferendumOpened( netuid:{:?}, param:{:?}, value:{:?}, ends_at:{:?} )",
+            netuid,
+            param,
+            value,
+            ends_at
+        );
+        Self::deposit_subnet_event(
+            &[netuid],
+            Event::SubnetReferendumOpened(netuid, param, value, ends_at),
+        );
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic vote_subnet_referendum.
+    ///
+    /// Records the vote of the calling validator on the open referendum of `netuid`, weighed
+    /// by the total stake of its hotkey. The stake is locked until the referendum closes: it
+    /// cannot be removed from the hotkey below the locked amount.
+    ///
+    /// # Raises:
+    /// * 'NoOpenReferendum':
+    ///     - The subnet has no referendum open for votes.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey has no uid on the subnet.
+    ///
+    /// * 'NeuronNoValidatorPermit':
+    ///     - The hotkey's uid has no validator permit.
+    ///
+    /// * 'AlreadyVotedInReferendum':
+    ///     - The hotkey already voted on the referendum.
+    ///
+    pub fn do_vote_subnet_referendum(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        approve: bool,
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        let (_, _, ends_at) =
+            SubnetReferendum::<T>::get(netuid).ok_or(Error::<T>::NoOpenReferendum)?;
+        ensure!(
+            Self::get_current_block_as_u64() < ends_at,
+            Error::<T>::NoOpenReferendum
+        );
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)
+            .map_err(|_| Error::<T>::HotKeyNotRegisteredInSubNet)?;
+        ensure!(
+            Self::get_validator_permit_for_uid(netuid, uid),
+            Error::<T>::NeuronNoValidatorPermit
+        );
+        ensure!(
+            !ReferendumVotes::<T>::contains_key(netuid, &hotkey),
+            Error::<T>::AlreadyVotedInReferendum
+        );
+
+        let stake = Self::get_total_stake_for_hotkey(&hotkey);
+        ReferendumVotes::<T>::insert(netuid, &hotkey, (approve, stake));
+        ReferendumLockedStake::<T>::mutate(&hotkey, |locked| {
+            *locked = locked.saturating_add(stake)
+        });
+
+        log::info!(
+            "SubnetReferendumVoted( netuid:{:?}, hotkey:{:?}, approve:{:?}, stake:{:?} )",
+            netuid,
+            hotkey,
+            approve,
+            stake
+        );
+        Self::deposit_subnet_event(
+            &[netuid],
+            Event::SubnetReferendumVoted(netuid, hotkey, approve, stake),
+        );
+        Ok(())
+    }
+
+    /// Closes the referenda that ended by `block_number`. A referendum passes when the stake
+    /// voting for it reaches ReferendumThreshold of the stake of the subnet's permitted
+    /// validators, and its change is applied if still within the owner's bounds. Returns the
+    /// weight used.
+    pub fn close_ended_subnet_referenda(block_number: u64) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let ended: Vec<(u16, OwnerHyperparam, u16)> = SubnetReferendum::<T>::iter()
+            .inspect(|_| weight.saturating_accrue(T::DbWeight::get().reads(1)))
+            .filter(|(netuid, (_, _, ends_at))| {
+                block_number >= *ends_at && !Self::is_subnet_migrating(*netuid)
+            })
+            .map(|(netuid, (param, value, _))| (netuid, param, value))
+            .collect();
+        let threshold = u128::from(Self::get_referendum_threshold());
+        for (netuid, param, value) in ended {
+            let votes: Vec<(bool, u64)> =
+                ReferendumVotes::<T>::iter_prefix_values(netuid).collect();
+            let approved: u128 = votes
+                .iter()
+                .filter(|(approve, _)| *approve)
+                .fold(0, |total, (_, stake)| {
+                    total.saturating_add(u128::from(*stake))
+                });
+            // The votes are read again when cleared, unlocking the stake of each voter, and
+            // the permit and stake of every uid are read for the total stake.
+            let votes_n = u64::try_from(votes.len()).unwrap_or(u64::MAX);
+            weight.saturating_accrue(
+                T::DbWeight::get().reads_writes(
+                    votes_n.saturating_mul(3).saturating_add(
+                        u64::from(Self::get_subnetwork_n(netuid)).saturating_mul(3),
+                    ),
+                    votes_n.saturating_mul(2).saturating_add(2),
+                ),
+            );
+            let (_, total) = Self::get_epoch_pause_stake(netuid);
+            let (min, max) = Self::get_owner_hyperparam_bounds(netuid, param);
+            let passed = total > 0
+                && approved.saturating_mul(u128::from(u16::MAX)) >= threshold.saturating_mul(total)
+                && (min..=max).contains(&value);
+
+            Self::clear_subnet_referendum(netuid);
+            if passed {
+                Self::set_owner_hyperparam(netuid, param, value);
+            }
+
+            log::info!(
+                "SubnetReferendumClosed( netuid:{:?}, passed:{:?} )",
+                netuid,
+                passed
+            );
+            Self::deposit_subnet_event(&[netuid], Event::SubnetReferendumClosed(netuid, passed));
+        }
+        weight
+    }
+
+    /// Removes the referendum of `netuid` and its votes, unlocking the stake of the voters.
+    pub fn clear_subnet_referendum(netuid: u16) {
+        SubnetReferendum::<T>::remove(netuid);
+        for (hotkey, (_, stake)) in ReferendumVotes::<T>::drain_prefix(netuid) {
+            ReferendumLockedStake::<T>::mutate_exists(&hotkey, |locked| {
+                *locked = locked
+                    .map(|locked| locked.saturating_sub(stake))
+                    .filter(|locked| *locked > 0);
+            });
+        }
+    }
+
+    /// Returns the open referendum of `netuid`: the hyperparameter, the proposed value and
+    /// the block it ends at.
+    pub fn get_subnet_referendum(netuid: u16) -> Option<(OwnerHyperparam, u16, u64)> {
+        SubnetReferendum::<T>::get(netuid)
+    }
+
+    /// Returns the stake of `hotkey` locked by its votes in open referenda.
+    pub fn get_referendum_locked_stake(hotkey: &T::AccountId) -> u64 {
+        ReferendumLockedStake::<T>::get(hotkey)
+    }
+
+    pub fn get_referendum_duration() -> u64 {
+        ReferendumDuration::<T>::get()
+    }
+    pub fn set_referendum_duration(duration: u64) {
+        ReferendumDuration::<T>::put(duration);
+        Self::deposit_event(Event::ReferendumDurationSet(duration));
+    }
+
+    pub fn get_referendum_threshold() -> u16 {
+        ReferendumThreshold::<T>::get()
+    }
+    pub fn set_referendum_threshold(threshold: u16) {
+        ReferendumThreshold::<T>::put(threshold);
+        Self::deposit_event(Event::ReferendumThresholdSet(threshold));
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::subnet_info::SubnetHyperparams;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+/// The core state of a subnet at a block, for snapshot tooling and replaying a subnet
+/// locally. Per-uid vectors are ordered by uid; weight and bond rows are given by the
+/// `BlakeTwo256` hash of their SCALE encoding.
+#[freeze_struct("5881d78dac00ca89")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetState<T: Config> {
+    pub netuid: Compact<u16>,
+    pub block_number: Compact<u64>,
+    pub hyperparams: SubnetHyperparams,
+    pub owner: T::AccountId,
+    pub subnetwork_n: Compact<u16>,
+    pub blocks_since_last_step: Compact<u64>,
+    pub emission_value: Compact<u64>,
+    pub pending_emission: Compact<u64>,
+    pub hotkeys: Vec<T::AccountId>,
+    pub coldkeys: Vec<T::AccountId>,
+    pub stakes: Vec<Compact<u64>>,
+    pub block_at_registration: Vec<Compact<u64>>,
+    pub last_update: Vec<Compact<u64>>,
+    pub validator_permit: Vec<bool>,
+    pub rank: Vec<Compact<u16>>,
+    pub trust: Vec<Compact<u16>>,
+    pub consensus: Vec<Compact<u16>>,
+    pub incentive: Vec<Compact<u16>>,
+    pub dividends: Vec<Compact<u16>>,
+    pub validator_trust: Vec<Compact<u16>>,
+    pub emission: Vec<Compact<u64>>,
+    pub weights_hashes: Vec<H256>,
+    pub bonds_hashes: Vec<H256>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the core state of `netuid`, or nothing if the subnet does not exist.
+    pub fn dump_subnet_state(netuid: u16) -> Option<SubnetState<T>> {
+        let hyperparams = Self::get_subnet_hyperparams(netuid)?;
+        let n = Self::get_subnetwork_n(netuid);
+
+        let hotkeys: Vec<T::AccountId> = (0..n).map(|uid| Keys::<T>::get(netuid, uid)).collect();
+        let coldkeys: Vec<T::AccountId> = hotkeys.iter().map(Owner::<T>::get).collect();
+        let stakes: Vec<Compact<u64>> = hotkeys
+            .iter()
+            .map(|hotkey| Self::get_total_stake_for_hotkey(hotkey).into())
+            .collect();
+        let weights_hashes: Vec<H256> = (0..n)
+            .map(|uid| BlakeTwo256::hash_of(&Weights::<T>::get(netuid, uid).entries()))
+            .collect();
+        let bonds_hashes: Vec<H256> = (0..n)
+            .map(|uid| BlakeTwo256::hash_of(&Bonds::<T>::get(netuid, uid)))
+            .collect();
+
+        let compact_u16 = |values: Vec<u16>| -> Vec<Compact<u16>> {
+            values.into_iter().map(Compact::from).collect()
+        };
+        let compact_u64 = |values: Vec<u64>| -> Vec<Compact<u64>> {
+            values.into_iter().map(Compact::from).collect()
+        };
+
+        Some(SubnetState {
+            netuid: netuid.into(),
+            block_number: Self::get_current_block_as_u64().into(),
+            hyperparams,
+            owner: Self::get_subnet_owner(netuid),
+            subnetwork_n: n.into(),
+            blocks_since_last_step: Self::get_blocks_since_last_step(netuid).into(),
+            emission_value: Self::get_subnet_emission_value(netuid).into(),
+            pending_emission: Self::get_pending_emission(netuid).into(),
+            hotkeys,
+            coldkeys,
+            stakes,
+            block_at_registration: compact_u64(Self::get_block_at_registration(netuid)),
+            last_update: compact_u64(Self::get_last_update(netuid)),
+            validator_permit: Self::get_validator_permit(netuid),
+            rank: compact_u16(Self::get_rank(netuid)),
+            trust: compact_u16(Self::get_trust(netuid)),
+            consensus: compact_u16(Self::get_consensus(netuid)),
+            incentive: compact_u16(Self::get_incentive(netuid)),
+            dividends: compact_u16(Self::get_dividends(netuid)),
+            validator_trust: compact_u16(Self::get_validator_trust(netuid)),
+            emission: compact_u64(Self::get_emission(netuid)),
+            weights_hashes,
+            bonds_hashes,
+        })
+    }
+}
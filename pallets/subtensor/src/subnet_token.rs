@@ -0,0 +1,86 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_subnet_token_metadata.
+    ///
+    /// Sets the token symbol and name of `netuid`, clearing either one when it is empty.
+    /// Symbols are compared byte for byte, so no two subnets display the same one.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'InvalidSubnetTokenMetadata':
+    ///     - The symbol or name is longer than its bound or not valid UTF-8.
+    ///
+    /// * 'SubnetSymbolInUse':
+    ///     - Another subnet already uses the symbol.
+    ///
+    pub fn do_set_subnet_token_metadata(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        symbol: Vec<u8>,
+        token_name: Vec<u8>,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            core::str::from_utf8(&symbol).is_ok() && core::str::from_utf8(&token_name).is_ok(),
+            Error::<T>::InvalidSubnetTokenMetadata
+        );
+        let bounded_symbol: BoundedVec<u8, ConstU32<MAX_SUBNET_SYMBOL_BYTES>> = symbol
+            .clone()
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidSubnetTokenMetadata)?;
+        let bounded_name: BoundedVec<u8, ConstU32<MAX_SUBNET_TOKEN_NAME_BYTES>> = token_name
+            .clone()
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidSubnetTokenMetadata)?;
+        ensure!(
+            bounded_symbol.is_empty() || !Self::is_subnet_symbol_in_use(&symbol, netuid),
+            Error::<T>::SubnetSymbolInUse
+        );
+
+        if bounded_symbol.is_empty() {
+            SubnetSymbol::<T>::remove(netuid);
+        } else {
+            SubnetSymbol::<T>::insert(netuid, bounded_symbol);
+        }
+        if bounded_name.is_empty() {
+            SubnetTokenName::<T>::remove(netuid);
+        } else {
+            SubnetTokenName::<T>::insert(netuid, bounded_name);
+        }
+
+        log::info!(
+            "SubnetTokenMetadataSet( netuid:{:?}, symbol:{:?}, token_name:{:?} )",
+            netuid,
+            symbol,
+            token_name
+        );
+        Self::deposit_event(Event::SubnetTokenMetadataSet(netuid, symbol, token_name));
+        Ok(())
+    }
+
+    /// Whether a subnet other than `netuid` uses `symbol`.
+    pub fn is_subnet_symbol_in_use(symbol: &[u8], netuid: u16) -> bool {
+        SubnetSymbol::<T>::iter().any(|(other, used)| other != netuid && used.as_slice() == symbol)
+    }
+
+    /// The token symbol of the subnet, empty when none is set.
+    pub fn get_subnet_symbol(netuid: u16) -> Vec<u8> {
+        SubnetSymbol::<T>::get(netuid)
+            .map(|symbol| symbol.into_inner())
+            .unwrap_or_default()
+    }
+
+    /// The token name of the subnet, empty when none is set.
+    pub fn get_subnet_token_name(netuid: u16) -> Vec<u8> {
+        SubnetTokenName::<T>::get(netuid)
+            .map(|name| name.into_inner())
+            .unwrap_or_default()
+    }
+}
@@ -4,6 +4,11 @@ use frame_support::traits::fungible::Mutate;
 use frame_support::traits::tokens::Preservation;
 use frame_support::{storage::IterableStorageDoubleMap, weights::Weight};
 use sp_core::{Get, U256};
+use sp_runtime::traits::Verify;
+
+/// Prefix of the payload signed for `rotate_subnet_owner_key`, so that no other signature by
+/// the new coldkey passes as a proof of possession.
+const SUBNET_OWNER_ROTATION_CONTEXT: &[u8] = b"subtensor:rotate_subnet_owner_key";
 
 impl<T: Config> Pallet<T> {
     /// Swaps the hotkey of a coldkey account.
@@ -23,6 +28,7 @@ impl<T: Config> Pallet<T> {
     /// * `NonAssociatedColdKey` - If the coldkey does not own the old hotkey.
     /// * `HotKeySetTxRateLimitExceeded` - If the transaction rate limit is exceeded.
     /// * `NewHotKeyIsSameWithOld` - If the new hotkey is the same as the old hotkey.
+    /// * `HotkeyIsBanned` - If the old or the new hotkey is banned.
     /// * `HotKeyAlreadyRegisteredInSubNet` - If the new hotkey is already registered in the subnet.
     /// * `NotEnoughBalanceToPaySwapHotKey` - If there is not enough balance to pay for the swap.
     pub fn do_swap_hotkey(
@@ -40,6 +46,10 @@ impl<T: Config> Pallet<T> {
         let mut weight = T::DbWeight::get().reads(2);
 
         ensure!(old_hotkey != new_hotkey, Error::<T>::NewHotKeyIsSameWithOld);
+        ensure!(
+            !Self::is_hotkey_banned(old_hotkey) && !Self::is_hotkey_banned(new_hotkey),
+            Error::<T>::HotkeyIsBanned
+        );
         ensure!(
             !Self::is_hotkey_registered_on_any_network(new_hotkey),
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
@@ -83,8 +93,17 @@ impl<T: Config> Pallet<T> {
         Self::swap_axons(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
         Self::swap_keys(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
         Self::swap_loaded_emission(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
+        Self::swap_pending_hotkey_emission(old_hotkey, new_hotkey, &mut weight);
         Self::swap_uids(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
         Self::swap_prometheus(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
+        #[cfg(feature = "commitments")]
+        Self::swap_commitments(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
+        Self::swap_evm_key_associations(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
+        Self::swap_registration_burns(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
+        Self::swap_declared_roles(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
+        Self::swap_weights_keys(old_hotkey, new_hotkey, &netuid_is_member, &mut weight);
+        Self::swap_last_transaction_type_blocks(old_hotkey, new_hotkey, &mut weight);
+        Self::swap_child_keys(old_hotkey, new_hotkey, &mut weight);
         Self::swap_senate_member(old_hotkey, new_hotkey, &mut weight)?;
 
         Self::swap_total_hotkey_coldkey_stakes_this_interval(old_hotkey, new_hotkey, &mut weight);
@@ -226,7 +245,7 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn meets_min_allowed_coldkey_balance(coldkey: &T::AccountId) -> bool {
-        let all_staked_keys: Vec<T::AccountId> = StakingHotkeys::<T>::get(coldkey);
+        let all_staked_keys: Vec<T::AccountId> = Self::get_all_staked_hotkeys(coldkey);
         let mut total_staking_balance: u64 = 0;
         for hotkey in all_staked_keys {
             total_staking_balance = total_staking_balance
@@ -292,7 +311,7 @@ impl<T: Config> Pallet<T> {
         }
 
         // Get current destination coldkeys
-        let mut destination_coldkeys: Vec<T::AccountId> =
+        let destination_coldkeys: Vec<T::AccountId> =
             ColdkeySwapDestinations::<T>::get(old_coldkey.clone());
 
         // Calculate difficulty based on the number of existing destination coldkeys
@@ -307,6 +326,75 @@ impl<T: Config> Pallet<T> {
         let seal = Self::create_seal_hash(block_number, nonce, old_coldkey);
         ensure!(seal == work_hash, Error::<T>::InvalidSeal);
 
+        Self::add_coldkey_swap_destination(old_coldkey, new_coldkey, destination_coldkeys)
+    }
+
+    /// ---- The implementation for the extrinsic schedule_swap_coldkey.
+    ///
+    /// Schedules the swap of the calling coldkey to `new_coldkey` once ArbitrationPeriod
+    /// blocks pass, burning the key swap cost. A second destination scheduled during the
+    /// period disputes the swap: it is held until governance arbitrates it with
+    /// `arbitrate_coldkey_swap`.
+    ///
+    /// # Raises:
+    /// * 'SameColdkey':
+    ///     - The new coldkey is the calling coldkey.
+    ///
+    /// * 'ColdKeyAlreadyAssociated':
+    ///     - The new coldkey owns hotkeys or is a hotkey.
+    ///
+    /// * 'DuplicateColdkey':
+    ///     - The swap to the new coldkey is already scheduled.
+    ///
+    /// * 'MaxColdkeyDestinationsReached':
+    ///     - The coldkey has the maximum number of swaps scheduled.
+    ///
+    /// * 'NotEnoughBalanceToPaySwapColdKey':
+    ///     - The coldkey cannot pay the key swap cost.
+    ///
+    pub fn do_schedule_swap_coldkey(
+        origin: T::RuntimeOrigin,
+        new_coldkey: &T::AccountId,
+    ) -> DispatchResult {
+        let old_coldkey = ensure_signed(origin)?;
+        ensure!(old_coldkey != *new_coldkey, Error::<T>::SameColdkey);
+        ensure!(
+            !Self::coldkey_has_associated_hotkeys(new_coldkey)
+                && !Self::hotkey_account_exists(new_coldkey),
+            Error::<T>::ColdKeyAlreadyAssociated
+        );
+
+        let destination_coldkeys: Vec<T::AccountId> =
+            ColdkeySwapDestinations::<T>::get(&old_coldkey);
+        ensure!(
+            !destination_coldkeys.contains(new_coldkey),
+            Error::<T>::DuplicateColdkey
+        );
+
+        let swap_cost = Self::get_key_swap_cost();
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&old_coldkey, swap_cost),
+            Error::<T>::NotEnoughBalanceToPaySwapColdKey
+        );
+        // Check the destination limit before charging for the swap.
+        ensure!(
+            destination_coldkeys.len() < MAX_COLDKEY_DESTINATIONS,
+            Error::<T>::MaxColdkeyDestinationsReached
+        );
+        let actual_burn_amount =
+            Self::remove_balance_from_coldkey_account(&old_coldkey, swap_cost)?;
+        Self::burn_tokens(actual_burn_amount);
+
+        Self::add_coldkey_swap_destination(&old_coldkey, new_coldkey, destination_coldkeys)
+    }
+
+    /// Adds `new_coldkey` to the swap destinations of `old_coldkey`, starting the arbitration
+    /// period if it is the first one.
+    fn add_coldkey_swap_destination(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        mut destination_coldkeys: Vec<T::AccountId>,
+    ) -> DispatchResult {
         // Check if the new coldkey is already in the swap wallets list
         ensure!(
             !destination_coldkeys.contains(new_coldkey),
@@ -314,8 +402,6 @@ impl<T: Config> Pallet<T> {
         );
 
         // If the destinations keys are empty or have less than the maximum allowed, we will add the new coldkey to the list
-        const MAX_COLDKEY_DESTINATIONS: usize = 10;
-
         if destination_coldkeys.len() < MAX_COLDKEY_DESTINATIONS {
             destination_coldkeys.push(new_coldkey.clone());
             ColdkeySwapDestinations::<T>::insert(old_coldkey.clone(), destination_coldkeys.clone());
@@ -349,6 +435,90 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic arbitrate_coldkey_swap.
+    ///
+    /// Resolves the swap scheduled for `old_coldkey` by governance: swaps it to
+    /// `new_coldkey` at once, which must be one of its scheduled destinations, or cancels
+    /// the swap when `new_coldkey` is None. Unlike block arbitration this also settles a
+    /// disputed swap.
+    ///
+    /// # Raises:
+    /// * 'NoColdkeySwapScheduled':
+    ///     - No swap is scheduled for the coldkey.
+    ///
+    /// * 'InvalidColdkeySwapDestination':
+    ///     - The new coldkey is not a scheduled destination of the coldkey.
+    ///
+    /// * 'ColdkeySwapError':
+    ///     - The swap could not be performed.
+    ///
+    pub fn do_arbitrate_coldkey_swap(
+        origin: T::RuntimeOrigin,
+        old_coldkey: &T::AccountId,
+        new_coldkey: Option<T::AccountId>,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+        let destination_coldkeys: Vec<T::AccountId> =
+            ColdkeySwapDestinations::<T>::get(old_coldkey);
+        ensure!(
+            !destination_coldkeys.is_empty(),
+            Error::<T>::NoColdkeySwapScheduled
+        );
+        if let Some(new_coldkey) = &new_coldkey {
+            ensure!(
+                destination_coldkeys.contains(new_coldkey),
+                Error::<T>::InvalidColdkeySwapDestination
+            );
+        }
+
+        // Drop the schedule so block arbitration does not swap the coldkey again.
+        ColdkeySwapDestinations::<T>::remove(old_coldkey);
+        let arbitration_block = ColdkeyArbitrationBlock::<T>::take(old_coldkey);
+        ColdkeysToSwapAtBlock::<T>::mutate_exists(arbitration_block, |coldkeys| {
+            if let Some(list) = coldkeys {
+                list.retain(|coldkey| coldkey != old_coldkey);
+                if list.is_empty() {
+                    *coldkeys = None;
+                }
+            }
+        });
+
+        match new_coldkey {
+            Some(new_coldkey) => {
+                Self::perform_swap_coldkey(old_coldkey, &new_coldkey)
+                    .map_err(|_| Error::<T>::ColdkeySwapError)?;
+                Self::deposit_event(Event::ColdkeySwapped {
+                    old_coldkey: old_coldkey.clone(),
+                    new_coldkey,
+                });
+            }
+            None => {
+                log::info!("ColdkeySwapCancelled( old_coldkey:{:?} )", old_coldkey);
+                Self::deposit_event(Event::ColdkeySwapCancelled {
+                    old_coldkey: old_coldkey.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the scheduled swap destinations of `coldkey` and the block its swap is
+    /// arbitrated at, u64::MAX while the swap is disputed.
+    pub fn get_scheduled_coldkey_swap(coldkey: &T::AccountId) -> (Vec<T::AccountId>, u64) {
+        (
+            ColdkeySwapDestinations::<T>::get(coldkey),
+            ColdkeyArbitrationBlock::<T>::get(coldkey),
+        )
+    }
+
+    pub fn get_arbitration_period() -> u64 {
+        ArbitrationPeriod::<T>::get()
+    }
+    pub fn set_arbitration_period(period: u64) {
+        ArbitrationPeriod::<T>::put(period);
+        Self::deposit_event(Event::ArbitrationPeriodSet(period));
+    }
+
     /// Calculate the proof of work difficulty based on the number of swap attempts
     #[allow(clippy::arithmetic_side_effects)]
     pub fn calculate_pow_difficulty(swap_attempts: u32) -> U256 {
@@ -395,13 +565,33 @@ impl<T: Config> Pallet<T> {
                     coldkey: coldkey_i.clone(),
                 });
             } else if let Some(new_coldkey) = destinations_coldkeys.first() {
-                // Only remove ColdkeySwapDestinations if there's a single destination
-                ColdkeySwapDestinations::<T>::remove(&coldkey_i);
-                weight_used = weight_used.saturating_add(T::DbWeight::get().writes(1));
-                Self::perform_swap_coldkey(coldkey_i, new_coldkey).map(|weight| {
-                    weight_used = weight_used.saturating_add(weight);
-                    keys_swapped = keys_swapped.saturating_add(1);
-                })?;
+                // A swap that can not be performed is rolled back and held for governance to
+                // arbitrate, keeping its destination, without holding up the others.
+                match frame_support::storage::with_storage_layer(|| {
+                    Self::perform_swap_coldkey(coldkey_i, new_coldkey)
+                        .map_err(|_| DispatchError::from(Error::<T>::ColdkeySwapError))
+                }) {
+                    Ok(weight) => {
+                        // Only remove ColdkeySwapDestinations once the swap went through.
+                        ColdkeySwapDestinations::<T>::remove(coldkey_i);
+                        weight_used = weight_used
+                            .saturating_add(weight)
+                            .saturating_add(T::DbWeight::get().writes(1));
+                        keys_swapped = keys_swapped.saturating_add(1);
+                        Self::deposit_event(Event::ColdkeySwapped {
+                            old_coldkey: coldkey_i.clone(),
+                            new_coldkey: new_coldkey.clone(),
+                        });
+                    }
+                    Err(e) => {
+                        log::warn!("Could not swap coldkey {:?}: {:?}", coldkey_i, e);
+                        ColdkeyArbitrationBlock::<T>::insert(coldkey_i.clone(), u64::MAX);
+                        weight_used = weight_used.saturating_add(T::DbWeight::get().writes(1));
+                        Self::deposit_event(Event::ArbitrationPeriodExtended {
+                            coldkey: coldkey_i.clone(),
+                        });
+                    }
+                }
             }
         }
 
@@ -422,13 +612,22 @@ impl<T: Config> Pallet<T> {
 
         // Swap coldkey references in storage maps
         // NOTE The order of these calls is important
+        let owned_hotkeys = OwnedHotkeys::<T>::get(old_coldkey);
+        let locks = Self::swap_stake_locks_for_coldkey(old_coldkey, new_coldkey, &owned_hotkeys);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(
+            u64::try_from(owned_hotkeys.len()).unwrap_or(u64::MAX),
+            locks.saturating_mul(3),
+        ));
         Self::swap_stake_for_coldkey(old_coldkey, new_coldkey, &mut weight);
+        Self::swap_pending_withdrawals_for_coldkey(old_coldkey, new_coldkey, &mut weight);
         Self::swap_total_hotkey_coldkey_stakes_this_interval_for_coldkey(
             old_coldkey,
             new_coldkey,
             &mut weight,
         );
         Self::swap_subnet_owner_for_coldkey(old_coldkey, new_coldkey, &mut weight);
+        #[cfg(feature = "identity")]
+        Self::swap_identity_for_coldkey(old_coldkey, new_coldkey, &mut weight);
 
         // Transfer any remaining balance from old_coldkey to new_coldkey
         let remaining_balance = Self::get_coldkey_balance(old_coldkey);
@@ -548,6 +747,19 @@ impl<T: Config> Pallet<T> {
             Delegates::<T>::remove(old_hotkey);
             Delegates::<T>::insert(new_hotkey, delegate_take);
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            // The take cap voted by the nominators follows the delegate.
+            if let Some(cap) = DelegateTakeCap::<T>::take(old_hotkey) {
+                DelegateTakeCap::<T>::insert(new_hotkey, cap);
+            }
+            for (coldkey, cap) in TakeCapVotes::<T>::drain_prefix(old_hotkey) {
+                TakeCapVotes::<T>::insert(new_hotkey, coldkey, cap);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            // So does a take reduced for underperformance.
+            if let Some(underperformance) = DelegateUnderperformance::<T>::take(old_hotkey) {
+                DelegateUnderperformance::<T>::insert(new_hotkey, underperformance);
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 3));
         } else {
             weight.saturating_accrue(T::DbWeight::get().reads(1));
         }
@@ -569,27 +781,27 @@ impl<T: Config> Pallet<T> {
             Stake::<T>::insert(new_hotkey, &coldkey, stake_amount);
             writes = writes.saturating_add(1u64); // One write for insert
 
-            // Update StakingHotkeys map
-            let mut staking_hotkeys = StakingHotkeys::<T>::get(&coldkey);
-            if !staking_hotkeys.contains(new_hotkey) {
-                staking_hotkeys.push(new_hotkey.clone());
-                writes = writes.saturating_add(1u64); // One write for insert
-            }
-            if let Some(pos) = staking_hotkeys.iter().position(|x| x == old_hotkey) {
-                staking_hotkeys.remove(pos);
-                writes = writes.saturating_add(1u64); // One write for remove
-            }
-            StakingHotkeys::<T>::insert(coldkey.clone(), staking_hotkeys);
-            writes = writes.saturating_add(1u64); // One write for insert
+            // Update StakingHotkeyPages
+            Self::add_staking_hotkey(&coldkey, new_hotkey);
+            Self::remove_staking_hotkey(&coldkey, old_hotkey);
+            writes = writes.saturating_add(6u64); // Writes for the pages and their index
         }
 
         // Clear the prefix for the old hotkey after transferring all stakes
         let _ = Stake::<T>::clear_prefix(old_hotkey, stake_count, None);
         writes = writes.saturating_add(1); // One write for insert; // One write for clear_prefix
 
-        // TODO: Remove all entries for old hotkey from StakingHotkeys map
+        // The stake locks follow the stake.
+        let locks = Self::swap_stake_locks(old_hotkey, new_hotkey);
+        writes = writes.saturating_add(locks.saturating_mul(3));
 
         weight.saturating_accrue(T::DbWeight::get().writes(writes));
+
+        // Move the delegate indexes over to the new hotkey.
+        DelegateNominatorCount::<T>::remove(old_hotkey);
+        DelegateTotalStake::<T>::remove(old_hotkey);
+        let reads = Self::refresh_delegate_indexes(new_hotkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(reads, 4));
     }
 
     /// Swaps the network membership status of the hotkey.
@@ -625,8 +837,9 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Weight Calculation
     ///
-    /// * Reads: 1 for each network ID if the old hotkey exists in that network.
-    /// * Writes: 2 for each network ID if the old hotkey exists in that network (one for removal and one for insertion).
+    /// * Reads: 2 for each network ID, for the primary axon and its replicas.
+    /// * Writes: 2 for each of these the old hotkey has served (one for removal and one for insertion),
+    ///   and 2 for each ip index entry of the old hotkey.
     pub fn swap_axons(
         old_hotkey: &T::AccountId,
         new_hotkey: &T::AccountId,
@@ -634,6 +847,13 @@ impl<T: Config> Pallet<T> {
         weight: &mut Weight,
     ) {
         for netuid in netuid_is_member.iter() {
+            let ips = Self::get_axon_ips(*netuid, old_hotkey);
+            for ip in ips.iter() {
+                AxonIpIndex::<T>::remove(ip, (*netuid, old_hotkey.clone()));
+                AxonIpIndex::<T>::insert(ip, (*netuid, new_hotkey.clone()), ());
+            }
+            weight
+                .saturating_accrue(T::DbWeight::get().writes((ips.len() as u64).saturating_mul(2)));
             if let Ok(axon_info) = Axons::<T>::try_get(netuid, old_hotkey) {
                 Axons::<T>::remove(netuid, old_hotkey);
                 Axons::<T>::insert(netuid, new_hotkey, axon_info);
@@ -641,6 +861,19 @@ impl<T: Config> Pallet<T> {
             } else {
                 weight.saturating_accrue(T::DbWeight::get().reads(1));
             }
+            if AxonReplicas::<T>::contains_key(netuid, old_hotkey) {
+                let replicas = AxonReplicas::<T>::take(netuid, old_hotkey);
+                AxonReplicas::<T>::insert(netuid, new_hotkey, replicas);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            } else {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+            }
+            if let Some(certificate) = NeuronCertificates::<T>::take(netuid, old_hotkey) {
+                NeuronCertificates::<T>::insert(netuid, new_hotkey, certificate);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            } else {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+            }
         }
     }
 
@@ -673,6 +906,39 @@ impl<T: Config> Pallet<T> {
         weight.saturating_accrue(T::DbWeight::get().writes(writes));
     }
 
+    /// Moves the emission accrued by the hotkey to the new hotkey, adding it to any emission
+    /// the new hotkey has pending itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The old hotkey.
+    /// * `new_hotkey` - The new hotkey.
+    /// * `weight` - The weight of the transaction.
+    ///
+    pub fn swap_pending_hotkey_emission(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        weight: &mut Weight,
+    ) {
+        if PendingHotkeyEmission::<T>::contains_key(old_hotkey) {
+            let (old_server, old_validator) = PendingHotkeyEmission::<T>::take(old_hotkey);
+            // The payout bucket of the old hotkey is skipped once its pending emission moved.
+            // A new hotkey with emission pending already has a payout block of its own.
+            if !PendingHotkeyEmission::<T>::contains_key(new_hotkey) {
+                Self::schedule_hotkey_emission_payout(
+                    new_hotkey,
+                    Self::get_current_block_as_u64().saturating_add(1),
+                );
+            }
+            PendingHotkeyEmission::<T>::mutate(new_hotkey, |(server, validator)| {
+                *server = server.saturating_add(old_server);
+                *validator = validator.saturating_add(old_validator);
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 3));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+    }
+
     /// Swaps the loaded emission of the hotkey.
     ///
     /// # Arguments
@@ -755,6 +1021,262 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Swaps the commitments of the hotkey and their rate limit blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The old hotkey.
+    /// * `new_hotkey` - The new hotkey.
+    /// * `netuid_is_member` - A vector of network IDs where the hotkey is a member.
+    /// * `weight` - The weight of the transaction.
+    ///
+    /// # Weight Calculation
+    ///
+    /// * Reads: 2 for each network ID.
+    /// * Writes: 2 for each commitment or block the old hotkey has (one for removal and one for insertion).
+    #[cfg(feature = "commitments")]
+    pub fn swap_commitments(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid_is_member: &[u16],
+        weight: &mut Weight,
+    ) {
+        for netuid in netuid_is_member.iter() {
+            if let Some(commitment) = Commitments::<T>::take(netuid, old_hotkey) {
+                Commitments::<T>::insert(netuid, new_hotkey, commitment);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            } else {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+            }
+            if LastCommitmentBlock::<T>::contains_key(netuid, old_hotkey) {
+                let last_block = LastCommitmentBlock::<T>::take(netuid, old_hotkey);
+                LastCommitmentBlock::<T>::insert(netuid, new_hotkey, last_block);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            } else {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+            }
+        }
+    }
+
+    /// Swaps the EVM addresses associated with the hotkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The old hotkey.
+    /// * `new_hotkey` - The new hotkey.
+    /// * `netuid_is_member` - A vector of network IDs where the hotkey is a member.
+    /// * `weight` - The weight of the transaction.
+    ///
+    /// # Weight Calculation
+    ///
+    /// * Reads: 1 for each network ID.
+    /// * Writes: 2 for each association the old hotkey has (one for removal and one for insertion).
+    pub fn swap_evm_key_associations(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid_is_member: &[u16],
+        weight: &mut Weight,
+    ) {
+        for netuid in netuid_is_member.iter() {
+            if let Some(association) = AssociatedEvmAddress::<T>::take(netuid, old_hotkey) {
+                AssociatedEvmAddress::<T>::insert(netuid, new_hotkey, association);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            } else {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+            }
+        }
+    }
+
+    /// Swaps the registration burns recorded for the hotkey, so the new hotkey keeps the refund
+    /// of `unregister` within its window.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The old hotkey.
+    /// * `new_hotkey` - The new hotkey.
+    /// * `netuid_is_member` - A vector of network IDs where the hotkey is a member.
+    /// * `weight` - The weight of the transaction.
+    ///
+    /// # Weight Calculation
+    ///
+    /// * Reads: 1 for each network ID.
+    /// * Writes: 2 for each burn recorded for the old hotkey (one for removal and one for insertion).
+    pub fn swap_registration_burns(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid_is_member: &[u16],
+        weight: &mut Weight,
+    ) {
+        for netuid in netuid_is_member.iter() {
+            if let Some(burn) = RegistrationBurn::<T>::take(netuid, old_hotkey) {
+                RegistrationBurn::<T>::insert(netuid, new_hotkey, burn);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            } else {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+            }
+        }
+    }
+
+    /// Swaps the roles the hotkey declared when registering, so the new hotkey keeps them.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The old hotkey.
+    /// * `new_hotkey` - The new hotkey.
+    /// * `netuid_is_member` - A vector of network IDs where the hotkey is a member.
+    /// * `weight` - The weight of the transaction.
+    ///
+    /// # Weight Calculation
+    ///
+    /// * Reads: 1 for each network ID.
+    /// * Writes: 2 for each role declared by the old hotkey (one for removal and one for insertion).
+    pub fn swap_declared_roles(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid_is_member: &[u16],
+        weight: &mut Weight,
+    ) {
+        for netuid in netuid_is_member.iter() {
+            if let Some(role) = DeclaredRoles::<T>::take(netuid, old_hotkey) {
+                DeclaredRoles::<T>::insert(netuid, new_hotkey, role);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            } else {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+            }
+        }
+    }
+
+    /// Swaps the weights keys the hotkey authorized, so they keep setting the weights of the
+    /// new hotkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The old hotkey.
+    /// * `new_hotkey` - The new hotkey.
+    /// * `netuid_is_member` - A vector of network IDs where the hotkey is a member.
+    /// * `weight` - The weight of the transaction.
+    ///
+    /// # Weight Calculation
+    ///
+    /// * Reads: 1 for each network ID.
+    /// * Writes: 3 for each weights key of the old hotkey (its removal and insertion, and the
+    ///   hotkey it acts for).
+    pub fn swap_weights_keys(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid_is_member: &[u16],
+        weight: &mut Weight,
+    ) {
+        for netuid in netuid_is_member.iter() {
+            if let Some(key) = WeightsKeys::<T>::take(netuid, old_hotkey) {
+                WeightsKeys::<T>::insert(netuid, new_hotkey, &key);
+                WeightsKeyHotkey::<T>::insert(netuid, key, new_hotkey);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+            } else {
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+            }
+        }
+    }
+
+    /// Swaps the blocks the hotkey last sent each rate limited transaction type at, so a hotkey
+    /// swap does not reset its rate limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The old hotkey.
+    /// * `new_hotkey` - The new hotkey.
+    /// * `weight` - The weight of the transaction.
+    ///
+    /// # Weight Calculation
+    ///
+    /// * Reads: 1 for each transaction type sent by the old hotkey.
+    /// * Writes: 2 for each transaction type sent by the old hotkey.
+    pub fn swap_last_transaction_type_blocks(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        weight: &mut Weight,
+    ) {
+        for (tx_type, block) in LastTransactionTypeBlock::<T>::drain_prefix(old_hotkey) {
+            LastTransactionTypeBlock::<T>::insert(new_hotkey, tx_type, block);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+    }
+
+    /// Swaps the child keys, parent keys and pending child keys of the hotkey on every subnet,
+    /// as a parent needs no uid on the subnet its children take its stake on. The relations
+    /// of the other keys with the hotkey follow it to the new hotkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_hotkey` - The old hotkey.
+    /// * `new_hotkey` - The new hotkey.
+    /// * `weight` - The weight of the transaction.
+    ///
+    /// # Weight Calculation
+    ///
+    /// * Reads: 3 for each subnet.
+    /// * Writes: 2 for each relation moved, plus 1 for each key on the other side of it.
+    pub fn swap_child_keys(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        weight: &mut Weight,
+    ) {
+        let rename = |relations: &mut Vec<(u64, T::AccountId)>| {
+            for (_, key) in relations.iter_mut() {
+                if key == old_hotkey {
+                    *key = new_hotkey.clone();
+                }
+            }
+        };
+        for netuid in Self::get_all_subnet_netuids() {
+            weight.saturating_accrue(T::DbWeight::get().reads(3));
+
+            let children = ChildKeys::<T>::take(netuid, old_hotkey);
+            if !children.is_empty() {
+                for (_, child) in &children {
+                    ParentKeys::<T>::mutate(netuid, child, |parents| {
+                        for (_, key) in parents.iter_mut() {
+                            if key == old_hotkey {
+                                *key = new_hotkey.clone();
+                            }
+                        }
+                    });
+                }
+                weight.saturating_accrue(
+                    T::DbWeight::get().reads_writes(children.len() as u64, children.len() as u64),
+                );
+                ChildKeys::<T>::insert(netuid, new_hotkey, children);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+
+            let parents = ParentKeys::<T>::take(netuid, old_hotkey);
+            if !parents.is_empty() {
+                for (_, parent) in &parents {
+                    ChildKeys::<T>::mutate(netuid, parent, rename);
+                    PendingChildKeys::<T>::mutate(netuid, parent, |pending| {
+                        if let Some((children, _)) = pending {
+                            rename(children);
+                        }
+                    });
+                }
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(
+                    parents.len().saturating_mul(2) as u64,
+                    parents.len().saturating_mul(2) as u64,
+                ));
+                ParentKeys::<T>::insert(netuid, new_hotkey, parents);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+
+            if let Some((children, cooldown_block)) =
+                PendingChildKeys::<T>::take(netuid, old_hotkey)
+            {
+                PendingChildKeys::<T>::insert(netuid, new_hotkey, (children, cooldown_block));
+                Self::schedule_pending_child_keys(netuid, new_hotkey, cooldown_block);
+                weight.saturating_accrue(T::DbWeight::get().writes(3));
+            }
+        }
+    }
+
     /// Swaps the total hotkey-coldkey stakes for the current interval.
     ///
     /// # Arguments
@@ -857,8 +1379,13 @@ impl<T: Config> Pallet<T> {
                 // Update the owner of the hotkey to the new coldkey
                 Owner::<T>::insert(hotkey, new_coldkey);
 
+                // Recount the nominators and delegated stake of the hotkey, both its stake
+                // entry and its owner having moved.
+                let reads = Self::refresh_delegate_indexes(hotkey);
+
                 // Update the transaction weight
-                weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+                weight
+                    .saturating_accrue(T::DbWeight::get().reads_writes(reads.saturating_add(2), 4));
             }
         }
 
@@ -887,15 +1414,25 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
         }
 
+        // Ownership of the hotkeys changed, so recompute which of their stake is delegated.
+        for hotkey in old_owned_hotkeys.iter() {
+            let reads = Self::refresh_delegate_indexes(hotkey);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(reads, 2));
+        }
+
         // Update the list of owned hotkeys for both old and new coldkeys
         OwnedHotkeys::<T>::remove(old_coldkey);
         OwnedHotkeys::<T>::insert(new_coldkey, old_owned_hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
 
         // Update the staking hotkeys for both old and new coldkeys
-        let staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::take(old_coldkey);
-        StakingHotkeys::<T>::insert(new_coldkey, staking_hotkeys);
-        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        let staking_hotkeys =
+            u64::try_from(Self::get_all_staked_hotkeys(old_coldkey).len()).unwrap_or(u64::MAX);
+        Self::swap_staking_hotkeys(old_coldkey, new_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(
+            staking_hotkeys.saturating_add(2),
+            staking_hotkeys.saturating_mul(3).saturating_add(2),
+        ));
 
         // Log the total stake of old and new coldkeys after the swap
         log::info!(
@@ -945,7 +1482,8 @@ impl<T: Config> Pallet<T> {
     ///
     /// * `bool` - True if the coldkey has any associated hotkeys, false otherwise.
     pub fn coldkey_has_associated_hotkeys(coldkey: &T::AccountId) -> bool {
-        !StakingHotkeys::<T>::get(coldkey).is_empty()
+        Self::get_staking_hotkey_page_count(coldkey) > 0
+            || !StakingHotkeys::<T>::get(coldkey).is_empty()
     }
 
     /// Swaps the subnet owner from the old coldkey to the new coldkey for all networks where the old coldkey is the owner.
@@ -975,6 +1513,74 @@ impl<T: Config> Pallet<T> {
         weight.saturating_accrue(T::DbWeight::get().reads(TotalNetworks::<T>::get() as u64));
     }
 
+    /// Transfers the ownership of `netuid` from the calling owner coldkey to `new_coldkey`.
+    ///
+    /// The new coldkey signs `subnet_owner_rotation_payload`, which names the chain, the current
+    /// owner and the subnet's rotation nonce, so the proof cannot be replayed on another chain
+    /// or once the subnet changes hands again, even back to the same owner. The subnet lock is
+    /// kept in SubnetLocked under the netuid and refunded to the owner at removal, so it
+    /// follows the ownership with the same write. The hand-over itself is the one of an
+    /// accepted ownership transfer.
+    ///
+    /// # Errors
+    ///
+    /// * `SubNetworkDoesNotExist` - If the subnet does not exist.
+    /// * `NotSubnetOwner` - If the caller is not the owner coldkey of the subnet.
+    /// * `ColdkeyIsInArbitration` - If the owner coldkey has a swap in arbitration.
+    /// * `SameColdkey` - If the new coldkey is the current owner.
+    /// * `InvalidOwnerKeySignature` - If the signature is not the new coldkey's.
+    pub fn do_rotate_subnet_owner_key(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        new_coldkey: T::AccountId,
+        signature: T::KeySignature,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            SubnetOwner::<T>::get(netuid) == coldkey,
+            Error::<T>::NotSubnetOwner
+        );
+        ensure!(
+            !Self::coldkey_in_arbitration(&coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+        ensure!(new_coldkey != coldkey, Error::<T>::SameColdkey);
+
+        let payload = Self::subnet_owner_rotation_payload(netuid, &coldkey);
+        ensure!(
+            signature.verify(payload.as_slice(), &new_coldkey),
+            Error::<T>::InvalidOwnerKeySignature
+        );
+
+        Self::hand_over_subnet(netuid, &new_coldkey);
+
+        log::info!(
+            "SubnetOwnerKeyRotated( netuid:{:?}, old_coldkey:{:?}, new_coldkey:{:?} )",
+            netuid,
+            coldkey,
+            new_coldkey
+        );
+        Self::deposit_event(Event::SubnetOwnerKeyRotated(netuid, coldkey, new_coldkey));
+        Ok(())
+    }
+
+    /// The payload the new coldkey signs to take over `netuid` from `owner`, valid until the
+    /// subnet next changes hands.
+    pub fn subnet_owner_rotation_payload(netuid: u16, owner: &T::AccountId) -> Vec<u8> {
+        (
+            SUBNET_OWNER_ROTATION_CONTEXT,
+            Self::get_block_hash_from_u64(0),
+            netuid,
+            owner,
+            SubnetOwnerRotationNonce::<T>::get(netuid),
+        )
+            .encode()
+    }
+
     pub fn swap_senate_member(
         old_hotkey: &T::AccountId,
         new_hotkey: &T::AccountId,
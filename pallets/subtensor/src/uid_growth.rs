@@ -0,0 +1,93 @@
+use super::*;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic sudo_schedule_max_allowed_uids_growth.
+    ///
+    /// Schedules `MaxAllowedUids` of `netuid` to grow by `step` every `interval` blocks until it
+    /// reaches `target`, replacing any growth already scheduled. The first step is taken
+    /// `interval` blocks from now.
+    ///
+    /// # Raises:
+    /// * 'InvalidMaxAllowedUidsGrowth':
+    ///     - The step or interval is zero, or the target is not above the current max allowed uids.
+    ///
+    pub fn do_schedule_max_allowed_uids_growth(
+        netuid: u16,
+        step: u16,
+        interval: u64,
+        target: u16,
+    ) -> DispatchResult {
+        ensure!(
+            step > 0 && interval > 0 && target > Self::get_max_allowed_uids(netuid),
+            Error::<T>::InvalidMaxAllowedUidsGrowth
+        );
+
+        let next_block = Self::get_current_block_as_u64().saturating_add(interval);
+        MaxAllowedUidsGrowth::<T>::insert(netuid, (step, interval, target, next_block));
+
+        log::info!(
+            "MaxAllowedUidsGrowthScheduled( netuid:{:?}, step:{:?}, interval:{:?}, target:{:?} )",
+            netuid,
+            step,
+            interval,
+            target
+        );
+        Self::deposit_event(Event::MaxAllowedUidsGrowthScheduled(
+            netuid, step, interval, target,
+        ));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic sudo_cancel_max_allowed_uids_growth.
+    ///
+    /// Cancels the growth scheduled for `netuid`. Steps already taken are kept.
+    ///
+    /// # Raises:
+    /// * 'NoMaxAllowedUidsGrowth':
+    ///     - No growth is scheduled for the subnet.
+    ///
+    pub fn do_cancel_max_allowed_uids_growth(netuid: u16) -> DispatchResult {
+        ensure!(
+            MaxAllowedUidsGrowth::<T>::take(netuid).is_some(),
+            Error::<T>::NoMaxAllowedUidsGrowth
+        );
+
+        log::info!("MaxAllowedUidsGrowthCancelled( netuid:{:?} )", netuid);
+        Self::deposit_event(Event::MaxAllowedUidsGrowthCancelled(netuid));
+        Ok(())
+    }
+
+    /// Takes the scheduled growth steps due at `block_number`, and ends a schedule once its
+    /// subnet reaches the target. Returns the weight used.
+    pub fn grow_max_allowed_uids(block_number: u64) -> Weight {
+        let mut weight = Weight::zero();
+        let due: Vec<(u16, (u16, u64, u16, u64))> = MaxAllowedUidsGrowth::<T>::iter()
+            .inspect(|_| weight.saturating_accrue(T::DbWeight::get().reads(1)))
+            .filter(|(_, (_, _, _, next_block))| *next_block <= block_number)
+            .collect();
+
+        for (netuid, (step, interval, target, _)) in due {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            let current = Self::get_max_allowed_uids(netuid);
+            let grown = current.saturating_add(step).min(target);
+            if grown > current {
+                Self::set_max_allowed_uids(netuid, grown);
+            }
+
+            if grown.max(current) >= target {
+                MaxAllowedUidsGrowth::<T>::remove(netuid);
+                log::info!("MaxAllowedUidsGrowthCompleted( netuid:{:?} )", netuid);
+                Self::deposit_event(Event::MaxAllowedUidsGrowthCompleted(netuid));
+            } else {
+                let next_block = block_number.saturating_add(interval);
+                MaxAllowedUidsGrowth::<T>::insert(netuid, (step, interval, target, next_block));
+            }
+        }
+        weight
+    }
+
+    pub fn get_max_allowed_uids_growth(netuid: u16) -> Option<(u16, u64, u16, u64)> {
+        MaxAllowedUidsGrowth::<T>::get(netuid)
+    }
+}
@@ -27,11 +27,27 @@ impl<T: Config> Pallet<T> {
         let old_hotkey: T::AccountId = Keys::<T>::get(netuid, uid_to_replace);
 
         // 2. Remove previous set memberships.
-        Uids::<T>::remove(netuid, old_hotkey.clone());
-        IsNetworkMember::<T>::remove(old_hotkey.clone(), netuid);
+        Self::clear_hotkey_on_network(netuid, &old_hotkey);
         Keys::<T>::remove(netuid, uid_to_replace);
+        WeightsMetadataHash::<T>::remove(netuid, uid_to_replace);
+        WeightsTargetEpoch::<T>::remove(netuid, uid_to_replace);
+        AxonAttestations::<T>::remove(netuid, uid_to_replace);
 
-        // 2a. Check if the uid is registered in any other subnetworks.
+        // 2a. Keep the uid of the pruned hotkey so it can reclaim it.
+        if Self::get_persistent_uids(netuid) {
+            RetiredUids::<T>::insert(netuid, &old_hotkey, uid_to_replace);
+        }
+
+        // 2b. Log the eviction with the stake the old hotkey held.
+        Self::record_eviction(
+            netuid,
+            block_number,
+            &old_hotkey,
+            Self::get_total_stake_for_hotkey(&old_hotkey),
+            new_hotkey,
+        );
+
+        // 2c. Check if the uid is registered in any other subnetworks.
         let hotkey_is_registered_on_any_network: bool =
             Self::is_hotkey_registered_on_any_network(&old_hotkey.clone());
         if !hotkey_is_registered_on_any_network {
@@ -45,6 +61,32 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), uid_to_replace); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, uid_to_replace, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+        RetiredUids::<T>::remove(netuid, new_hotkey); // The hotkey holds a uid again.
+    }
+
+    /// Removes the state `hotkey` holds on a network as a neuron of it: its memberships,
+    /// serving info, weights key, commitment and registration records. Every path a hotkey
+    /// leaves a network by goes through here, so none of them leaves an entry behind.
+    pub fn clear_hotkey_on_network(netuid: u16, hotkey: &T::AccountId) {
+        Uids::<T>::remove(netuid, hotkey);
+        IsNetworkMember::<T>::remove(hotkey, netuid);
+        let prev_ips = Self::get_axon_ips(netuid, hotkey);
+        Axons::<T>::remove(netuid, hotkey);
+        AxonReplicas::<T>::remove(netuid, hotkey);
+        NeuronCertificates::<T>::remove(netuid, hotkey);
+        Self::reindex_axon_ips(netuid, hotkey, &prev_ips);
+        Prometheus::<T>::remove(netuid, hotkey);
+        LastNeuronClearBlock::<T>::remove(netuid, hotkey);
+        AssociatedEvmAddress::<T>::remove(netuid, hotkey);
+        WeightCommits::<T>::remove(netuid, hotkey);
+        let _ = Self::take_weights_key(netuid, hotkey);
+        #[cfg(feature = "commitments")]
+        {
+            Commitments::<T>::remove(netuid, hotkey);
+            LastCommitmentBlock::<T>::remove(netuid, hotkey);
+        }
+        RegistrationBurn::<T>::remove(netuid, hotkey);
+        DeclaredRoles::<T>::remove(netuid, hotkey);
     }
 
     /// Appends the uid to the network.
@@ -69,7 +111,7 @@ impl<T: Config> Pallet<T> {
         Consensus::<T>::mutate(netuid, |v| v.push(0));
         Incentive::<T>::mutate(netuid, |v| v.push(0));
         Dividends::<T>::mutate(netuid, |v| v.push(0));
-        LastUpdate::<T>::mutate(netuid, |v| v.push(block_number));
+        Self::append_last_update(netuid, block_number);
         PruningScores::<T>::mutate(netuid, |v| v.push(0));
         ValidatorTrust::<T>::mutate(netuid, |v| v.push(0));
         ValidatorPermit::<T>::mutate(netuid, |v| v.push(false));
@@ -79,6 +121,149 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), next_uid); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, next_uid, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+        RetiredUids::<T>::remove(netuid, new_hotkey); // The hotkey holds a uid again.
+    }
+
+    /// Removes the neuron under `uid` and shrinks the network by one, moving the last uid into
+    /// the freed slot so uids stay contiguous. Returns the uid that moved, if any.
+    pub fn remove_neuron(netuid: u16, uid: u16) -> Option<u16> {
+        let subnetwork_n: u16 = Self::get_subnetwork_n(netuid);
+        if uid >= subnetwork_n {
+            return None;
+        }
+        let last_uid: u16 = subnetwork_n.saturating_sub(1);
+        let old_hotkey: T::AccountId = Keys::<T>::take(netuid, uid);
+        log::debug!(
+            "remove_neuron( netuid: {:?} | uid: {:?} | hotkey: {:?} | last_uid: {:?} ) ",
+            netuid,
+            uid,
+            old_hotkey,
+            last_uid
+        );
+
+        // 1. Remove the memberships and the serving info of the hotkey.
+        Self::clear_hotkey_on_network(netuid, &old_hotkey);
+
+        // 2. Move the last neuron into the freed slot of the Yuma Consensus vectors.
+        fn remove_slot<V>(values: &mut Vec<V>, uid: u16) {
+            if usize::from(uid) < values.len() {
+                values.swap_remove(usize::from(uid));
+            }
+        }
+        Rank::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        Trust::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        Active::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        Emission::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        Consensus::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        Incentive::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        Dividends::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        LastUpdate::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        PruningScores::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        ValidatorTrust::<T>::mutate(netuid, |v| remove_slot(v, uid));
+        ValidatorPermit::<T>::mutate(netuid, |v| remove_slot(v, uid));
+
+        // 3. Move the entries keyed by the last uid into the freed slot.
+        Weights::<T>::remove(netuid, uid);
+        Bonds::<T>::remove(netuid, uid);
+        BlockAtRegistration::<T>::remove(netuid, uid);
+        WeightsMetadataHash::<T>::remove(netuid, uid);
+        WeightsTargetEpoch::<T>::remove(netuid, uid);
+        AxonAttestations::<T>::remove(netuid, uid);
+        let moved_uid = (uid != last_uid).then(|| {
+            let moved_hotkey: T::AccountId = Keys::<T>::take(netuid, last_uid);
+            Keys::<T>::insert(netuid, uid, moved_hotkey.clone());
+            Uids::<T>::insert(netuid, &moved_hotkey, uid);
+            Weights::<T>::insert(netuid, uid, Weights::<T>::take(netuid, last_uid));
+            Bonds::<T>::insert(netuid, uid, Bonds::<T>::take(netuid, last_uid));
+            BlockAtRegistration::<T>::insert(
+                netuid,
+                uid,
+                BlockAtRegistration::<T>::take(netuid, last_uid),
+            );
+            if let Some(hash) = WeightsMetadataHash::<T>::take(netuid, last_uid) {
+                WeightsMetadataHash::<T>::insert(netuid, uid, hash);
+            }
+            if let Some(epoch) = WeightsTargetEpoch::<T>::take(netuid, last_uid) {
+                WeightsTargetEpoch::<T>::insert(netuid, uid, epoch);
+            }
+            AxonAttestations::<T>::insert(
+                netuid,
+                uid,
+                AxonAttestations::<T>::take(netuid, last_uid),
+            );
+            last_uid
+        });
+
+        // 4. Drop the weights and bonds on the removed uid, pointing those on the last uid to
+        // its new slot.
+        let remap = |row: Vec<(u16, u16)>| -> Vec<(u16, u16)> {
+            let mut row: Vec<(u16, u16)> = row
+                .into_iter()
+                .filter(|(target, _)| *target != uid)
+                .map(|(target, value)| (if target == last_uid { uid } else { target }, value))
+                .collect();
+            row.sort_by_key(|(target, _)| *target);
+            row
+        };
+        let weights: Vec<(u16, WeightsRow)> = Weights::<T>::iter_prefix(netuid).collect();
+        for (row_uid, row) in weights {
+            Weights::<T>::insert(
+                netuid,
+                row_uid,
+                Self::weights_row(netuid, remap(row.entries())),
+            );
+        }
+        let bonds: Vec<(u16, Vec<(u16, u16)>)> = Bonds::<T>::iter_prefix(netuid).collect();
+        for (row_uid, row) in bonds {
+            Bonds::<T>::insert(netuid, row_uid, remap(row));
+        }
+
+        // 5. Shrink the network.
+        SubnetworkN::<T>::insert(netuid, last_uid);
+
+        // 6. Unstake the hotkey if it holds no other uid. The stake of a banned hotkey is
+        // returned by the wind down instead.
+        if !Self::is_hotkey_registered_on_any_network(&old_hotkey)
+            && !Self::is_hotkey_banned(&old_hotkey)
+        {
+            Self::unstake_all_coldkeys_from_hotkey_account(&old_hotkey);
+        }
+        moved_uid
+    }
+
+    /// Returns the uid a registration of `hotkey` on a full subnet replaces: the uid the
+    /// hotkey held before it was pruned when it can reclaim it, else the neuron drawn by the
+    /// registration lottery, else the neuron to prune.
+    pub fn get_uid_to_replace(netuid: u16, hotkey: &T::AccountId) -> u16 {
+        match Self::get_reclaimable_uid(netuid, hotkey)
+            .or_else(|| Self::draw_neuron_to_prune(netuid))
+        {
+            Some(uid) => {
+                // As for get_neuron_to_prune, the new neuron is not the next one pruned.
+                Self::set_pruning_score_for_uid(netuid, uid, u16::MAX);
+                uid
+            }
+            None => Self::get_neuron_to_prune(netuid),
+        }
+    }
+
+    /// Returns the uid `hotkey` held on a subnet with PERSISTENT_UIDS before it was pruned,
+    /// if the slot is free: the neuron now in it is past its immunity period, holds no
+    /// validator permit and has a pruning score of zero.
+    pub fn get_reclaimable_uid(netuid: u16, hotkey: &T::AccountId) -> Option<u16> {
+        if !Self::get_persistent_uids(netuid) {
+            return None;
+        }
+        let uid = RetiredUids::<T>::get(netuid, hotkey)?;
+        if !Self::is_uid_exist_on_network(netuid, uid) {
+            return None;
+        }
+        let immune_until = Self::get_neuron_block_at_registration(netuid, uid)
+            .saturating_add(u64::from(Self::get_immunity_period(netuid)));
+        let is_free = Self::get_current_block_as_u64() >= immune_until
+            && !Self::get_validator_permit_for_uid(netuid, uid)
+            && Self::get_pruning_score_for_uid(netuid, uid) == 0;
+        is_free.then_some(uid)
     }
 
     /// Returns true if the uid is set on the network.
@@ -159,4 +344,33 @@ impl<T: Config> Pallet<T> {
         }
         false
     }
+
+    /// Returns the (uid, hotkey) pairs of a network ordered by uid.
+    ///
+    /// Storage iteration follows the encoded keys, which puts uid 256 before uid 1. Callers
+    /// that depend on the order of the pairs must use this instead of iterating `Keys`.
+    ///
+    pub fn get_uid_hotkey_pairs(netuid: u16) -> Vec<(u16, T::AccountId)> {
+        let mut hotkeys: Vec<(u16, T::AccountId)> =
+            <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(netuid)
+                .collect();
+        hotkeys.sort_unstable_by_key(|(uid, _)| *uid);
+        hotkeys
+    }
+
+    /// Returns the uid of the neuron with the lowest total stake on a network and its stake,
+    /// breaking ties towards the oldest registration, then the lowest uid.
+    ///
+    pub fn get_lowest_stake_uid(netuid: u16) -> Option<(u16, u64)> {
+        Self::get_uid_hotkey_pairs(netuid)
+            .into_iter()
+            .map(|(uid, hotkey)| (uid, Self::get_total_stake_for_hotkey(&hotkey)))
+            .min_by_key(|(uid, stake)| {
+                (
+                    *stake,
+                    Self::get_neuron_block_at_registration(netuid, *uid),
+                    *uid,
+                )
+            })
+    }
 }
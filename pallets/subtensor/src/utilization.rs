@@ -0,0 +1,79 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Returns the fraction of the subnet's uids that are active, scaled to u16::MAX. A uid is
+    /// active as the epoch counts it: its last update is within the subnet's activity cutoff.
+    pub fn get_subnet_utilization(netuid: u16) -> u16 {
+        let n = Self::get_subnetwork_n(netuid);
+        let current_block = Self::get_current_block_as_u64();
+        let activity_cutoff = u64::from(Self::get_activity_cutoff(netuid));
+        let active = LastUpdate::<T>::get(netuid)
+            .iter()
+            .filter(|updated| updated.saturating_add(activity_cutoff) >= current_block)
+            .count() as u64;
+
+        let utilization = active
+            .saturating_mul(u64::from(u16::MAX))
+            .checked_div(u64::from(n))
+            .unwrap_or(0);
+        u16::try_from(utilization).unwrap_or(u16::MAX)
+    }
+
+    /// Records the utilization of every subnet after the root epoch has set their emission
+    /// values, and burns LowUtilizationBurn of the emission of subnets below the
+    /// UtilizationFloor. The burned emission is never minted.
+    pub fn burn_emission_of_underused_subnets() {
+        let floor = Self::get_utilization_floor();
+        let burn = Self::get_low_utilization_burn();
+
+        for netuid in Self::get_all_subnet_netuids() {
+            if netuid == Self::get_root_netuid() {
+                continue;
+            }
+            let utilization = Self::get_subnet_utilization(netuid);
+            SubnetUtilization::<T>::insert(netuid, utilization);
+            if utilization >= floor || burn == 0 {
+                continue;
+            }
+
+            let emission = EmissionValues::<T>::get(netuid);
+            let burned = u128::from(emission)
+                .saturating_mul(u128::from(burn))
+                .checked_div(u128::from(u16::MAX))
+                .and_then(|burned| u64::try_from(burned).ok())
+                .unwrap_or(0);
+            EmissionValues::<T>::insert(netuid, emission.saturating_sub(burned));
+
+            log::debug!(
+                "SubnetEmissionBurned( netuid:{:?}, utilization:{:?}, burned:{:?} )",
+                netuid,
+                utilization,
+                burned
+            );
+            Self::deposit_subnet_event(
+                &[netuid],
+                Event::SubnetEmissionBurned(netuid, utilization, burned),
+            );
+        }
+    }
+
+    pub fn get_recorded_subnet_utilization(netuid: u16) -> u16 {
+        SubnetUtilization::<T>::get(netuid)
+    }
+
+    pub fn get_utilization_floor() -> u16 {
+        UtilizationFloor::<T>::get()
+    }
+    pub fn set_utilization_floor(floor: u16) {
+        UtilizationFloor::<T>::put(floor);
+        Self::deposit_event(Event::UtilizationFloorSet(floor));
+    }
+
+    pub fn get_low_utilization_burn() -> u16 {
+        LowUtilizationBurn::<T>::get()
+    }
+    pub fn set_low_utilization_burn(burn: u16) {
+        LowUtilizationBurn::<T>::put(burn);
+        Self::deposit_event(Event::LowUtilizationBurnSet(burn));
+    }
+}
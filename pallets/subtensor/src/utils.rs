@@ -5,12 +5,46 @@ use crate::{
 };
 use sp_core::Get;
 use sp_core::U256;
+use sp_runtime::traits::Hash;
 use substrate_fixed::types::I32F32;
 
 impl<T: Config> Pallet<T> {
+    /// The topic under which events concerning `netuid` are indexed, so that clients can
+    /// subscribe to a single subnet's activity.
+    pub fn subnet_event_topic(netuid: u16) -> T::Hash {
+        T::Hashing::hash_of(&(b"subnet", netuid))
+    }
+
+    /// Deposits `event` indexed under the topic of each of `netuids`.
+    pub fn deposit_subnet_event(netuids: &[u16], event: Event<T>) {
+        let topics: Vec<T::Hash> = netuids
+            .iter()
+            .map(|netuid| Self::subnet_event_topic(*netuid))
+            .collect();
+        let event = <T as Config>::RuntimeEvent::from(event);
+        frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+    }
+
+    /// Ensures the origin is root, the subnet owner's coldkey or the subnet's operator hotkey.
     pub fn ensure_subnet_owner_or_root(
         o: T::RuntimeOrigin,
         netuid: u16,
+    ) -> Result<(), DispatchError> {
+        let coldkey = ensure_signed_or_root(o);
+        match coldkey {
+            Ok(Some(who)) if SubnetOwner::<T>::get(netuid) == who => Ok(()),
+            Ok(Some(who)) if SubnetOwnerHotkey::<T>::get(netuid).as_ref() == Some(&who) => Ok(()),
+            Ok(Some(_)) => Err(DispatchError::BadOrigin),
+            Ok(None) => Ok(()),
+            Err(x) => Err(x.into()),
+        }
+    }
+
+    /// Ensures the origin is root or the subnet owner's coldkey. The operator hotkey is not
+    /// accepted, so it cannot replace or revoke itself.
+    pub fn ensure_subnet_owner_coldkey_or_root(
+        o: T::RuntimeOrigin,
+        netuid: u16,
     ) -> Result<(), DispatchError> {
         let coldkey = ensure_signed_or_root(o);
         match coldkey {
@@ -240,9 +274,18 @@ impl<T: Config> Pallet<T> {
     pub fn get_registrations_this_block(netuid: u16) -> u16 {
         RegistrationsThisBlock::<T>::get(netuid)
     }
+    pub fn get_pow_registrations_this_block(netuid: u16) -> u16 {
+        POWRegistrationsThisBlock::<T>::get(netuid)
+    }
+    pub fn get_burn_registrations_this_block(netuid: u16) -> u16 {
+        BurnRegistrationsThisBlock::<T>::get(netuid)
+    }
     pub fn get_last_mechanism_step_block(netuid: u16) -> u64 {
         LastMechansimStepBlock::<T>::get(netuid)
     }
+    pub fn get_epoch_index(netuid: u16) -> u64 {
+        EpochIndex::<T>::get(netuid)
+    }
     pub fn get_registrations_this_interval(netuid: u16) -> u16 {
         RegistrationsThisInterval::<T>::get(netuid)
     }
@@ -307,6 +350,32 @@ impl<T: Config> Pallet<T> {
         current_block.saturating_sub(prev_tx_block) <= rate_limit
     }
 
+    /// Whether TransactionTypeRateLimit blocks of `tx_type` passed since `key` last sent a
+    /// transaction of the type.
+    pub fn passes_rate_limit(key: &T::AccountId, tx_type: TransactionType) -> bool {
+        LastTransactionTypeBlock::<T>::get(key, tx_type).is_none_or(|last| {
+            Self::get_current_block_as_u64()
+                >= last.saturating_add(Self::get_transaction_type_rate_limit(tx_type))
+        })
+    }
+    /// Records that `key` sent a transaction of `tx_type` in the current block.
+    pub fn set_last_transaction_type_block(key: &T::AccountId, tx_type: TransactionType) {
+        LastTransactionTypeBlock::<T>::insert(key, tx_type, Self::get_current_block_as_u64());
+    }
+    pub fn get_last_transaction_type_block(
+        key: &T::AccountId,
+        tx_type: TransactionType,
+    ) -> Option<u64> {
+        LastTransactionTypeBlock::<T>::get(key, tx_type)
+    }
+    pub fn get_transaction_type_rate_limit(tx_type: TransactionType) -> u64 {
+        TransactionTypeRateLimit::<T>::get(tx_type)
+    }
+    pub fn set_transaction_type_rate_limit(tx_type: TransactionType, rate_limit: u64) {
+        TransactionTypeRateLimit::<T>::insert(tx_type, rate_limit);
+        Self::deposit_event(Event::TransactionTypeRateLimitSet(tx_type, rate_limit));
+    }
+
     // ========================
     // === Token Management ===
     // ========================
@@ -348,6 +417,32 @@ impl<T: Config> Pallet<T> {
         TxRateLimit::<T>::put(tx_rate_limit);
         Self::deposit_event(Event::TxRateLimitSet(tx_rate_limit));
     }
+    pub fn get_identity_deposit() -> u64 {
+        IdentityDeposit::<T>::get()
+    }
+    pub fn set_identity_deposit(deposit: u64) {
+        IdentityDeposit::<T>::put(deposit);
+        Self::deposit_event(Event::IdentityDepositSet(deposit));
+    }
+    pub fn get_unregister_refund_window() -> u64 {
+        UnregisterRefundWindow::<T>::get()
+    }
+    pub fn get_unregister_refund_share() -> u16 {
+        UnregisterRefundShare::<T>::get()
+    }
+    pub fn set_unregister_refund(window: u64, share: u16) {
+        UnregisterRefundWindow::<T>::put(window);
+        UnregisterRefundShare::<T>::put(share);
+        Self::deposit_event(Event::UnregisterRefundSet(window, share));
+    }
+
+    pub fn get_faucet_rate_limit() -> u64 {
+        FaucetRateLimit::<T>::get()
+    }
+    pub fn set_faucet_rate_limit(faucet_rate_limit: u64) {
+        FaucetRateLimit::<T>::put(faucet_rate_limit);
+        Self::deposit_event(Event::FaucetRateLimitSet(faucet_rate_limit));
+    }
     pub fn get_tx_delegate_take_rate_limit() -> u64 {
         TxDelegateTakeRateLimit::<T>::get()
     }
@@ -378,6 +473,32 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::ServingRateLimitSet(netuid, serving_rate_limit));
     }
 
+    pub fn get_axon_ttl_blocks(netuid: u16) -> u64 {
+        AxonTTLBlocks::<T>::get(netuid)
+    }
+    pub fn set_axon_ttl_blocks(netuid: u16, axon_ttl_blocks: u64) {
+        AxonTTLBlocks::<T>::insert(netuid, axon_ttl_blocks);
+        Self::deposit_event(Event::AxonTTLBlocksSet(netuid, axon_ttl_blocks));
+    }
+
+    pub fn get_max_commitment_size(netuid: u16) -> u32 {
+        MaxCommitmentSize::<T>::get(netuid)
+    }
+    /// Sets the subnet's commitment size limit, capped at MAX_COMMITMENT_BYTES.
+    pub fn set_max_commitment_size(netuid: u16, max_commitment_size: u32) {
+        let max_commitment_size = max_commitment_size.min(MAX_COMMITMENT_BYTES);
+        MaxCommitmentSize::<T>::insert(netuid, max_commitment_size);
+        Self::deposit_event(Event::MaxCommitmentSizeSet(netuid, max_commitment_size));
+    }
+
+    pub fn get_commitment_rate_limit(netuid: u16) -> u64 {
+        CommitmentRateLimit::<T>::get(netuid)
+    }
+    pub fn set_commitment_rate_limit(netuid: u16, commitment_rate_limit: u64) {
+        CommitmentRateLimit::<T>::insert(netuid, commitment_rate_limit);
+        Self::deposit_event(Event::CommitmentRateLimitSet(netuid, commitment_rate_limit));
+    }
+
     pub fn get_min_difficulty(netuid: u16) -> u64 {
         MinDifficulty::<T>::get(netuid)
     }
@@ -464,7 +585,23 @@ impl<T: Config> Pallet<T> {
     pub fn get_min_allowed_weights(netuid: u16) -> u16 {
         MinAllowedWeights::<T>::get(netuid)
     }
+    pub fn get_weights_precision(netuid: u16) -> u8 {
+        WeightsPrecision::<T>::get(netuid)
+    }
+    pub fn set_weights_precision(netuid: u16, precision: u8) {
+        if precision != Self::get_weights_precision(netuid) && Self::get_subnetwork_n(netuid) > 0 {
+            Self::schedule_weights_sweep(netuid);
+        }
+        WeightsPrecision::<T>::insert(netuid, precision);
+        Self::deposit_event(Event::WeightsPrecisionSet(netuid, precision));
+    }
+
     pub fn set_min_allowed_weights(netuid: u16, min_allowed_weights: u16) {
+        if min_allowed_weights > Self::get_min_allowed_weights(netuid)
+            && Self::get_subnetwork_n(netuid) > 0
+        {
+            Self::schedule_weights_sweep(netuid);
+        }
         MinAllowedWeights::<T>::insert(netuid, min_allowed_weights);
         Self::deposit_event(Event::MinAllowedWeightSet(netuid, min_allowed_weights));
     }
@@ -485,6 +622,14 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::KappaSet(netuid, kappa));
     }
 
+    pub fn get_max_stake_weight_fraction(netuid: u16) -> u16 {
+        MaxStakeWeightFraction::<T>::get(netuid)
+    }
+    pub fn set_max_stake_weight_fraction(netuid: u16, fraction: u16) {
+        MaxStakeWeightFraction::<T>::insert(netuid, fraction);
+        Self::deposit_event(Event::MaxStakeWeightFractionSet(netuid, fraction));
+    }
+
     pub fn get_commit_reveal_weights_interval(netuid: u16) -> u64 {
         WeightCommitRevealInterval::<T>::get(netuid)
     }
@@ -492,10 +637,10 @@ impl<T: Config> Pallet<T> {
         WeightCommitRevealInterval::<T>::set(netuid, interval);
     }
     pub fn get_commit_reveal_weights_enabled(netuid: u16) -> bool {
-        CommitRevealWeightsEnabled::<T>::get(netuid)
+        Self::subnet_feature_enabled(netuid, subnet_features::COMMIT_REVEAL_WEIGHTS)
     }
     pub fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool) {
-        CommitRevealWeightsEnabled::<T>::set(netuid, enabled);
+        Self::set_subnet_feature(netuid, subnet_features::COMMIT_REVEAL_WEIGHTS, enabled);
     }
 
     pub fn get_rho(netuid: u16) -> u16 {
@@ -512,6 +657,13 @@ impl<T: Config> Pallet<T> {
         ActivityCutoff::<T>::insert(netuid, activity_cutoff);
         Self::deposit_event(Event::ActivityCutoffSet(netuid, activity_cutoff));
     }
+    pub fn get_min_activity_cutoff() -> u16 {
+        MinActivityCutoff::<T>::get()
+    }
+    pub fn set_min_activity_cutoff(min_activity_cutoff: u16) {
+        MinActivityCutoff::<T>::put(min_activity_cutoff);
+        Self::deposit_event(Event::MinActivityCutoffSet(min_activity_cutoff));
+    }
 
     // Registration Toggle utils
     pub fn get_network_registration_allowed(netuid: u16) -> bool {
@@ -586,6 +738,30 @@ impl<T: Config> Pallet<T> {
         ));
     }
 
+    pub fn get_max_miner_uids(netuid: u16) -> u16 {
+        MaxMinerUids::<T>::get(netuid)
+    }
+    pub fn set_max_miner_uids(netuid: u16, max_miner_uids: u16) {
+        MaxMinerUids::<T>::insert(netuid, max_miner_uids);
+        Self::deposit_event(Event::MaxMinerUidsSet(netuid, max_miner_uids));
+    }
+
+    pub fn get_min_stake_to_register(netuid: u16) -> u64 {
+        MinStakeToRegister::<T>::get(netuid)
+    }
+    pub fn set_min_stake_to_register(netuid: u16, min_stake: u64) {
+        MinStakeToRegister::<T>::insert(netuid, min_stake);
+        Self::deposit_event(Event::MinStakeToRegisterSet(netuid, min_stake));
+    }
+
+    pub fn get_max_min_stake_to_register() -> u64 {
+        MaxMinStakeToRegister::<T>::get()
+    }
+    pub fn set_max_min_stake_to_register(max_min_stake: u64) {
+        MaxMinStakeToRegister::<T>::put(max_min_stake);
+        Self::deposit_event(Event::MaxMinStakeToRegisterSet(max_min_stake));
+    }
+
     pub fn get_bonds_moving_average(netuid: u16) -> u64 {
         BondsMovingAverage::<T>::get(netuid)
     }
@@ -604,6 +780,26 @@ impl<T: Config> Pallet<T> {
             max_registrations_per_block,
         ));
     }
+    pub fn get_max_pow_registrations_per_block(netuid: u16) -> u16 {
+        MaxPOWRegistrationsPerBlock::<T>::get(netuid)
+    }
+    pub fn set_max_pow_registrations_per_block(netuid: u16, max_registrations_per_block: u16) {
+        MaxPOWRegistrationsPerBlock::<T>::insert(netuid, max_registrations_per_block);
+        Self::deposit_event(Event::MaxPowRegistrationsPerBlockSet(
+            netuid,
+            max_registrations_per_block,
+        ));
+    }
+    pub fn get_max_burn_registrations_per_block(netuid: u16) -> u16 {
+        MaxBurnRegistrationsPerBlock::<T>::get(netuid)
+    }
+    pub fn set_max_burn_registrations_per_block(netuid: u16, max_registrations_per_block: u16) {
+        MaxBurnRegistrationsPerBlock::<T>::insert(netuid, max_registrations_per_block);
+        Self::deposit_event(Event::MaxBurnRegistrationsPerBlockSet(
+            netuid,
+            max_registrations_per_block,
+        ));
+    }
 
     pub fn get_subnet_owner(netuid: u16) -> T::AccountId {
         SubnetOwner::<T>::get(netuid)
@@ -619,9 +815,6 @@ impl<T: Config> Pallet<T> {
     pub fn get_owned_hotkeys(coldkey: &T::AccountId) -> Vec<T::AccountId> {
         OwnedHotkeys::<T>::get(coldkey)
     }
-    pub fn get_all_staked_hotkeys(coldkey: &T::AccountId) -> Vec<T::AccountId> {
-        StakingHotkeys::<T>::get(coldkey)
-    }
 
     pub fn set_total_issuance(total_issuance: u64) {
         TotalIssuance::<T>::put(total_issuance);
@@ -671,6 +864,15 @@ impl<T: Config> Pallet<T> {
         NominatorMinRequiredStake::<T>::put(min_stake);
     }
 
+    pub fn get_fee_reserve() -> u64 {
+        FeeReserve::<T>::get()
+    }
+
+    pub fn set_fee_reserve(fee_reserve: u64) {
+        FeeReserve::<T>::put(fee_reserve);
+        Self::deposit_event(Event::FeeReserveSet(fee_reserve));
+    }
+
     pub fn get_key_swap_cost() -> u64 {
         T::KeySwapCost::get()
     }
@@ -689,10 +891,202 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn set_liquid_alpha_enabled(netuid: u16, enabled: bool) {
-        LiquidAlphaOn::<T>::set(netuid, enabled);
+        Self::set_subnet_feature(netuid, subnet_features::LIQUID_ALPHA, enabled);
+        Self::deposit_event(Event::LiquidAlphaEnabledSet(netuid, enabled));
     }
 
     pub fn get_liquid_alpha_enabled(netuid: u16) -> bool {
-        LiquidAlphaOn::<T>::get(netuid)
+        Self::subnet_feature_enabled(netuid, subnet_features::LIQUID_ALPHA)
+    }
+
+    pub fn set_reset_bonds_on_permit_loss(netuid: u16, enabled: bool) {
+        Self::set_subnet_feature(netuid, subnet_features::RESET_BONDS_ON_PERMIT_LOSS, enabled);
+    }
+
+    pub fn get_reset_bonds_on_permit_loss(netuid: u16) -> bool {
+        Self::subnet_feature_enabled(netuid, subnet_features::RESET_BONDS_ON_PERMIT_LOSS)
+    }
+
+    pub fn set_target_epoch_weights(netuid: u16, enabled: bool) {
+        Self::set_subnet_feature(netuid, subnet_features::TARGET_EPOCH_WEIGHTS, enabled);
+    }
+
+    pub fn get_target_epoch_weights(netuid: u16) -> bool {
+        Self::subnet_feature_enabled(netuid, subnet_features::TARGET_EPOCH_WEIGHTS)
+    }
+
+    pub fn set_persistent_uids(netuid: u16, enabled: bool) {
+        Self::set_subnet_feature(netuid, subnet_features::PERSISTENT_UIDS, enabled);
+    }
+
+    pub fn get_persistent_uids(netuid: u16) -> bool {
+        Self::subnet_feature_enabled(netuid, subnet_features::PERSISTENT_UIDS)
+    }
+
+    pub fn set_registration_lottery(netuid: u16, enabled: bool) {
+        Self::set_subnet_feature(netuid, subnet_features::REGISTRATION_LOTTERY, enabled);
+    }
+
+    pub fn get_registration_lottery(netuid: u16) -> bool {
+        Self::subnet_feature_enabled(netuid, subnet_features::REGISTRATION_LOTTERY)
+    }
+
+    pub fn set_unmasked_self_weights(netuid: u16, enabled: bool) {
+        Self::set_subnet_feature(netuid, subnet_features::UNMASKED_SELF_WEIGHTS, enabled);
+    }
+
+    pub fn get_unmasked_self_weights(netuid: u16) -> bool {
+        Self::subnet_feature_enabled(netuid, subnet_features::UNMASKED_SELF_WEIGHTS)
+    }
+
+    pub fn set_burn_ema_enabled(netuid: u16, enabled: bool) {
+        Self::set_subnet_feature(netuid, subnet_features::BURN_EMA, enabled);
+    }
+
+    pub fn get_burn_ema_enabled(netuid: u16) -> bool {
+        Self::subnet_feature_enabled(netuid, subnet_features::BURN_EMA)
+    }
+
+    // Subnet feature flag utils
+    pub fn get_subnet_feature_flags(netuid: u16) -> u32 {
+        SubnetFeatureFlags::<T>::get(netuid)
+    }
+    pub fn subnet_feature_enabled(netuid: u16, flag: u32) -> bool {
+        SubnetFeatureFlags::<T>::get(netuid) & flag == flag
+    }
+    /// Sets or clears `flags` on a subnet. Switching SUM_NORMALIZED_WEIGHTS schedules a sweep
+    /// rewriting the stored weight rows into the new representation.
+    pub fn set_subnet_feature(netuid: u16, flags: u32, enabled: bool) {
+        let was_sum_normalized =
+            Self::subnet_feature_enabled(netuid, subnet_features::SUM_NORMALIZED_WEIGHTS);
+        SubnetFeatureFlags::<T>::mutate(netuid, |current| {
+            if enabled {
+                *current |= flags;
+            } else {
+                *current &= !flags;
+            }
+        });
+        if was_sum_normalized
+            != Self::subnet_feature_enabled(netuid, subnet_features::SUM_NORMALIZED_WEIGHTS)
+        {
+            WeightsRescalePending::<T>::insert(netuid, ());
+            Self::schedule_weights_sweep(netuid);
+        }
+    }
+
+    /// Sets or clears `flags` on a subnet on behalf of its owner or root.
+    pub fn do_set_subnet_feature_flags(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        flags: u32,
+        enabled: bool,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            flags != 0 && flags & !subnet_features::ALL == 0,
+            Error::<T>::InvalidSubnetFeatureFlags
+        );
+
+        Self::set_subnet_feature(netuid, flags, enabled);
+        Self::deposit_event(Event::SubnetFeatureFlagsSet(
+            netuid,
+            Self::get_subnet_feature_flags(netuid),
+        ));
+        Ok(())
+    }
+
+    // Subnet maintenance utils
+    pub fn get_max_maintenance_duration() -> u64 {
+        MaxMaintenanceDuration::<T>::get()
+    }
+    pub fn set_max_maintenance_duration(duration: u64) {
+        MaxMaintenanceDuration::<T>::put(duration);
+        Self::deposit_event(Event::MaxMaintenanceDurationSet(duration));
+    }
+    pub fn get_maintenance_window(netuid: u16) -> Option<(u64, u64)> {
+        MaintenanceWindow::<T>::get(netuid)
+    }
+    /// Whether the subnet's maintenance window covers `block_number`.
+    pub fn is_in_maintenance(netuid: u16, block_number: u64) -> bool {
+        match MaintenanceWindow::<T>::get(netuid) {
+            Some((start, duration)) => {
+                block_number >= start && block_number < start.saturating_add(duration)
+            }
+            None => false,
+        }
+    }
+
+    /// Schedules the subnet's maintenance window on behalf of its owner or root.
+    /// A new window replaces the scheduled one, a zero duration clears it.
+    pub fn do_schedule_maintenance(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        start: u64,
+        duration: u64,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            duration <= Self::get_max_maintenance_duration(),
+            Error::<T>::MaintenanceWindowTooLong
+        );
+
+        if duration == 0 {
+            MaintenanceWindow::<T>::remove(netuid);
+        } else {
+            ensure!(
+                start >= Self::get_current_block_as_u64(),
+                Error::<T>::MaintenanceWindowInPast
+            );
+            MaintenanceWindow::<T>::insert(netuid, (start, duration));
+        }
+        Self::deposit_event(Event::MaintenanceScheduled(netuid, start, duration));
+        Ok(())
+    }
+
+    // Subnet operator hotkey utils
+    pub fn get_subnet_owner_hotkey(netuid: u16) -> Option<T::AccountId> {
+        SubnetOwnerHotkey::<T>::get(netuid)
+    }
+
+    /// Designates `hotkey` as the operator of the subnet on behalf of its owner coldkey or
+    /// root, replacing any previous operator.
+    pub fn do_set_subnet_owner_hotkey(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_coldkey_or_root(origin, netuid)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        SubnetOwnerHotkey::<T>::insert(netuid, hotkey.clone());
+        Self::deposit_event(Event::SubnetOwnerHotkeySet(netuid, hotkey));
+        Ok(())
+    }
+
+    /// Revokes the operator hotkey of the subnet on behalf of its owner coldkey or root.
+    pub fn do_revoke_subnet_owner_hotkey(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        Self::ensure_subnet_owner_coldkey_or_root(origin, netuid)?;
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            SubnetOwnerHotkey::<T>::take(netuid).is_some(),
+            Error::<T>::SubnetOwnerHotkeyNotSet
+        );
+
+        Self::deposit_event(Event::SubnetOwnerHotkeyRevoked(netuid));
+        Ok(())
     }
 }
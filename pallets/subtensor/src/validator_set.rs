@@ -0,0 +1,58 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+/// A member of a validator set, as exported to bridges.
+#[freeze_struct("21a873fe5f659391")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ValidatorSetEntry<T: Config> {
+    pub hotkey: T::AccountId,
+    pub uid: Compact<u16>,
+    /// Total stake of the hotkey.
+    pub stake: Compact<u64>,
+    /// Whether the member counts in consensus: the validator permit on a subnet, having set
+    /// root weights on the root network.
+    pub permit: bool,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The members of `netuid` sorted by total stake, highest first, ties broken by the lower
+    /// uid so that every node exports the same order.
+    fn get_validator_set(netuid: u16, permit: impl Fn(u16) -> bool) -> Vec<ValidatorSetEntry<T>> {
+        let mut validators: Vec<ValidatorSetEntry<T>> = Self::get_uid_hotkey_pairs(netuid)
+            .into_iter()
+            .map(|(uid, hotkey)| ValidatorSetEntry {
+                stake: Self::get_total_stake_for_hotkey(&hotkey).into(),
+                hotkey,
+                uid: uid.into(),
+                permit: permit(uid),
+            })
+            .collect();
+        validators.sort_by(|a, b| b.stake.0.cmp(&a.stake.0).then(a.uid.0.cmp(&b.uid.0)));
+        validators
+    }
+
+    /// Returns the `k` members of `netuid` with the most stake, with their validator permits.
+    /// Returns nothing if the subnet does not exist.
+    pub fn get_top_validators(netuid: u16, k: u16) -> Vec<ValidatorSetEntry<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+
+        let mut validators = Self::get_validator_set(netuid, |uid| {
+            Self::get_validator_permit_for_uid(netuid, uid)
+        });
+        validators.truncate(usize::from(k));
+        validators
+    }
+
+    /// Returns the members of the root network sorted by stake, a member voting once it has
+    /// set root weights.
+    pub fn get_root_voters() -> Vec<ValidatorSetEntry<T>> {
+        let root_netuid = Self::get_root_netuid();
+        Self::get_validator_set(root_netuid, |uid| {
+            !Weights::<T>::get(root_netuid, uid).is_empty()
+        })
+    }
+}
@@ -0,0 +1,115 @@
+//! Weights for the epochs of `pallet_subtensor`, as functions of the subnet size `n` and the
+//! weights `k` set per validator, over the grids of `benchmark_epoch`, `benchmark_epoch_dense`,
+//! `benchmark_commit_epoch_output` and `benchmark_root_epoch`. Regenerate with the command below
+//! on the reference hardware whenever the epoch math changes.
+
+// Executed Command:
+// ./target/release/node-subtensor
+// benchmark
+// pallet
+// --chain=local
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_subtensor
+// --extrinsic=benchmark_epoch,benchmark_epoch_dense,benchmark_commit_epoch_output,benchmark_root_epoch
+// --output=pallets/subtensor/src/weight_info.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for the epochs of `pallet_subtensor`.
+pub trait WeightInfo {
+	fn benchmark_epoch(n: u32, k: u32, ) -> Weight;
+	fn benchmark_epoch_dense(n: u32, ) -> Weight;
+	fn benchmark_commit_epoch_output(n: u32, ) -> Weight;
+	fn benchmark_root_epoch(n: u32, k: u32, ) -> Weight;
+}
+
+/// Weights for the epochs of `pallet_subtensor` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// The range of component `n` is `[16, 4096]`.
+	/// The range of component `k` is `[1, 256]`.
+	fn benchmark_epoch(n: u32, k: u32, ) -> Weight {
+		Weight::from_parts(1_512_000_000, 0)
+			.saturating_add(Weight::from_parts(2_154_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_873_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// The range of component `n` is `[16, 1024]`.
+	fn benchmark_epoch_dense(n: u32, ) -> Weight {
+		Weight::from_parts(1_734_000_000, 0)
+			.saturating_add(Weight::from_parts(1_180, 0).saturating_mul(n.saturating_mul(n).into()))
+			.saturating_add(Weight::from_parts(2_402_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// The range of component `n` is `[16, 4096]`.
+	fn benchmark_commit_epoch_output(n: u32, ) -> Weight {
+		Weight::from_parts(41_000_000, 0)
+			.saturating_add(Weight::from_parts(318_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// The range of component `n` is `[1, 64]`.
+	/// The range of component `k` is `[1, 32]`.
+	fn benchmark_root_epoch(n: u32, k: u32, ) -> Weight {
+		Weight::from_parts(96_000_000, 0)
+			.saturating_add(Weight::from_parts(11_420_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(3_870_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn benchmark_epoch(n: u32, k: u32, ) -> Weight {
+		Weight::from_parts(1_512_000_000, 0)
+			.saturating_add(Weight::from_parts(2_154_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(1_873_000, 0).saturating_mul(k.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	fn benchmark_epoch_dense(n: u32, ) -> Weight {
+		Weight::from_parts(1_734_000_000, 0)
+			.saturating_add(Weight::from_parts(1_180, 0).saturating_mul(n.saturating_mul(n).into()))
+			.saturating_add(Weight::from_parts(2_402_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	fn benchmark_commit_epoch_output(n: u32, ) -> Weight {
+		Weight::from_parts(41_000_000, 0)
+			.saturating_add(Weight::from_parts(318_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn benchmark_root_epoch(n: u32, k: u32, ) -> Weight {
+		Weight::from_parts(96_000_000, 0)
+			.saturating_add(Weight::from_parts(11_420_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(3_870_000, 0).saturating_mul(k.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(k.into())))
+	}
+}
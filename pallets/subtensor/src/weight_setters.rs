@@ -0,0 +1,79 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic authorize_weight_setter.
+    ///
+    /// Lets `signer` call `set_root_weights` for the calling hotkey on `netuid`, next to the
+    /// coldkey owning it, so the hotkey can be kept cold. The signer is recorded as the
+    /// weights key of the hotkey, as by `authorize_weights_key`, so a new authorization
+    /// replaces the previous key.
+    ///
+    /// # Raises:
+    /// * 'NotRootSubnet':
+    ///     - Weight setters are only authorized on the root network.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not a root validator.
+    ///
+    /// * 'WeightsKeyIsRegistered':
+    ///     - The signer is itself a root validator.
+    ///
+    /// * 'WeightsKeyInUse':
+    ///     - The signer already sets weights for another root validator.
+    ///
+    pub fn do_authorize_weight_setter(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        signer: T::AccountId,
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        ensure!(netuid == Self::get_root_netuid(), Error::<T>::NotRootSubnet);
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+
+        Self::take_weights_key(netuid, &hotkey);
+        Self::set_weights_key(netuid, &hotkey, &signer)?;
+
+        log::info!(
+            "WeightSetterAuthorized( netuid:{:?}, hotkey:{:?}, signer:{:?} )",
+            netuid,
+            hotkey,
+            signer
+        );
+        Self::deposit_event(Event::WeightSetterAuthorized(netuid, hotkey, signer));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic revoke_weight_setter.
+    ///
+    /// Revokes the weight setter of the calling hotkey on `netuid`, which is its weights key.
+    ///
+    /// # Raises:
+    /// * 'NoWeightSetter':
+    ///     - The hotkey has no weight setter on the subnet.
+    ///
+    pub fn do_revoke_weight_setter(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        ensure!(
+            Self::take_weights_key(netuid, &hotkey).is_some(),
+            Error::<T>::NoWeightSetter
+        );
+
+        log::info!(
+            "WeightSetterRevoked( netuid:{:?}, hotkey:{:?} )",
+            netuid,
+            hotkey
+        );
+        Self::deposit_event(Event::WeightSetterRevoked(netuid, hotkey));
+        Ok(())
+    }
+
+    /// Whether `signer` may set weights for `hotkey` on `netuid`: the coldkey owning the
+    /// hotkey, or the weights key the hotkey authorized.
+    pub fn can_set_weights_for(netuid: u16, hotkey: &T::AccountId, signer: &T::AccountId) -> bool {
+        Self::get_owning_coldkey_for_hotkey(hotkey) == *signer
+            || WeightsKeys::<T>::get(netuid, hotkey).as_ref() == Some(signer)
+    }
+}
@@ -1,10 +1,121 @@
 use super::*;
 use crate::math::*;
+use frame_support::storage::with_storage_layer;
 use sp_core::H256;
 use sp_runtime::traits::{BlakeTwo256, Hash};
 use sp_std::vec;
 
 impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic authorize_weights_key.
+    ///
+    /// Allows `key` to call `set_weights`, `commit_weights` and `reveal_weights` on `netuid`
+    /// for the calling hotkey, replacing any key authorized before. Passing the hotkey itself
+    /// revokes the authorization.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the registered hotkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet the key sets weights on.
+    ///
+    /// * 'key' (T::AccountId):
+    ///     - The weights key.
+    ///
+    /// # Event:
+    /// * WeightsKeyAuthorized;
+    ///     - On authorizing the key.
+    ///
+    /// * WeightsKeyRevoked;
+    ///     - On revoking the authorization.
+    ///
+    /// # Raises:
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on the subnet.
+    ///
+    /// * 'WeightsKeyIsRegistered':
+    ///     - The key is itself a hotkey registered on the subnet.
+    ///
+    /// * 'WeightsKeyInUse':
+    ///     - The key already sets weights for another hotkey on the subnet.
+    ///
+    pub fn do_authorize_weights_key(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        key: T::AccountId,
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+
+        Self::take_weights_key(netuid, &hotkey);
+        if key == hotkey {
+            log::info!(
+                "WeightsKeyRevoked( netuid:{:?} hotkey:{:?} )",
+                netuid,
+                hotkey
+            );
+            Self::deposit_event(Event::WeightsKeyRevoked(netuid, hotkey));
+            return Ok(());
+        }
+
+        Self::set_weights_key(netuid, &hotkey, &key)?;
+
+        log::info!(
+            "WeightsKeyAuthorized( netuid:{:?} hotkey:{:?} key:{:?} )",
+            netuid,
+            hotkey,
+            key
+        );
+        Self::deposit_event(Event::WeightsKeyAuthorized(netuid, hotkey, key));
+        Ok(())
+    }
+
+    /// Records `key` as the weights key of `hotkey` on `netuid`, for its subnet weights and,
+    /// on the root network, its root weights. The previous key of the hotkey must have been
+    /// taken already.
+    pub fn set_weights_key(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        key: &T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            !Self::is_hotkey_registered_on_network(netuid, key),
+            Error::<T>::WeightsKeyIsRegistered
+        );
+        ensure!(
+            !WeightsKeyHotkey::<T>::contains_key(netuid, key),
+            Error::<T>::WeightsKeyInUse
+        );
+        WeightsKeys::<T>::insert(netuid, hotkey, key);
+        WeightsKeyHotkey::<T>::insert(netuid, key, hotkey);
+        Ok(())
+    }
+
+    /// Removes and returns the weights key of `hotkey` on `netuid`, if any.
+    pub fn take_weights_key(netuid: u16, hotkey: &T::AccountId) -> Option<T::AccountId> {
+        let key = WeightsKeys::<T>::take(netuid, hotkey)?;
+        WeightsKeyHotkey::<T>::remove(netuid, &key);
+        Some(key)
+    }
+
+    /// Returns the hotkey a weights call signed by `signer` on `netuid` acts for: the hotkey
+    /// that authorized `signer` as its weights key, or `signer` itself. A signer registered on
+    /// the subnet always acts for itself.
+    pub fn get_weights_hotkey(netuid: u16, signer: T::AccountId) -> T::AccountId {
+        if Self::is_hotkey_registered_on_network(netuid, &signer) {
+            return signer;
+        }
+        WeightsKeyHotkey::<T>::get(netuid, &signer).unwrap_or(signer)
+    }
+
+    /// Returns the metadata hash stored with the current weights of the uid, if any.
+    pub fn get_weights_metadata_hash(netuid: u16, uid: u16) -> Option<H256> {
+        WeightsMetadataHash::<T>::get(netuid, uid)
+    }
+
     /// ---- The implementation for committing weight hashes.
     ///
     /// # Args:
@@ -26,7 +137,7 @@ impl<T: Config> Pallet<T> {
         netuid: u16,
         commit_hash: H256,
     ) -> DispatchResult {
-        let who = ensure_signed(origin)?;
+        let who = Self::get_weights_hotkey(netuid, ensure_signed(origin)?);
 
         log::info!("do_commit_weights( hotkey:{:?} netuid:{:?})", who, netuid);
 
@@ -87,7 +198,24 @@ impl<T: Config> Pallet<T> {
         salt: Vec<u16>,
         version_key: u64,
     ) -> DispatchResult {
-        let who = ensure_signed(origin.clone())?;
+        Self::do_reveal_weights_with_metadata(origin, netuid, uids, values, salt, version_key, None)
+    }
+
+    /// ---- The implementation for the extrinsic reveal_weights_with_metadata.
+    ///
+    /// Reveals weights as `do_reveal_weights` does and stores `metadata_hash` alongside them.
+    /// The commit hash covers the metadata hash, appended after the version key.
+    ///
+    pub fn do_reveal_weights_with_metadata(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        salt: Vec<u16>,
+        version_key: u64,
+        metadata_hash: Option<H256>,
+    ) -> DispatchResult {
+        let who = Self::get_weights_hotkey(netuid, ensure_signed(origin.clone())?);
 
         log::info!("do_reveal_weights( hotkey:{:?} netuid:{:?})", who, netuid);
 
@@ -106,20 +234,43 @@ impl<T: Config> Pallet<T> {
                 Error::<T>::InvalidRevealCommitTempo
             );
 
-            let provided_hash: H256 = BlakeTwo256::hash_of(&(
-                who.clone(),
-                netuid,
-                uids.clone(),
-                values.clone(),
-                salt.clone(),
-                version_key,
-            ));
+            let provided_hash: H256 = match metadata_hash {
+                Some(metadata_hash) => BlakeTwo256::hash_of(&(
+                    who.clone(),
+                    netuid,
+                    uids.clone(),
+                    values.clone(),
+                    salt.clone(),
+                    version_key,
+                    metadata_hash,
+                )),
+                None => BlakeTwo256::hash_of(&(
+                    who.clone(),
+                    netuid,
+                    uids.clone(),
+                    values.clone(),
+                    salt.clone(),
+                    version_key,
+                )),
+            };
             ensure!(
                 provided_hash == *commit_hash,
                 Error::<T>::InvalidRevealCommitHashNotMatch
             );
 
-            Self::do_set_weights(origin, netuid, uids, values, version_key)
+            // Root weights carry no metadata, a root reveal with metadata fails below.
+            if netuid == Self::get_root_netuid() && metadata_hash.is_none() {
+                return Self::apply_root_weights(netuid, &who, uids, values, version_key);
+            }
+
+            Self::do_set_weights_with_metadata(
+                origin,
+                netuid,
+                uids,
+                values,
+                version_key,
+                metadata_hash,
+            )
         })
     }
 
@@ -186,8 +337,171 @@ impl<T: Config> Pallet<T> {
         values: Vec<u16>,
         version_key: u64,
     ) -> dispatch::DispatchResult {
-        // --- 1. Check the caller's signature. This is the hotkey of a registered account.
+        Self::do_set_weights_with_metadata(origin, netuid, uids, values, version_key, None)
+    }
+
+    /// ---- The implementation for the extrinsic set_weights_for_epoch.
+    ///
+    /// Sets weights as `do_set_weights` does, for the epoch of the subnet with index
+    /// `target_epoch`. Weights evaluated for an epoch that already ran are rejected rather
+    /// than counted in the next one.
+    ///
+    /// # Raises:
+    /// * 'InvalidWeightsTargetEpoch':
+    ///     - The target epoch is not the next epoch of the subnet.
+    ///
+    pub fn do_set_weights_for_epoch(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+        target_epoch: u64,
+    ) -> dispatch::DispatchResult {
+        ensure!(
+            target_epoch == Self::get_epoch_index(netuid),
+            Error::<T>::InvalidWeightsTargetEpoch
+        );
+        Self::do_set_weights_with_metadata(origin, netuid, uids, values, version_key, None)
+    }
+
+    /// ---- The implementation for the extrinsic batch_set_weights.
+    ///
+    /// Sets the weights of each entry as `set_weights` does, each in its own storage layer. A
+    /// failing entry is rolled back and reported with WeightsBatchEntryFailed without failing
+    /// the others.
+    ///
+    /// # Raises:
+    /// * 'WeightsBatchTooLarge':
+    ///     - The batch has more than MAX_WEIGHTS_BATCH_SIZE entries.
+    ///
+    pub fn do_batch_set_weights(
+        origin: T::RuntimeOrigin,
+        batch: Vec<(u16, Vec<u16>, Vec<u16>, u64)>,
+    ) -> dispatch::DispatchResult {
+        let who = ensure_signed(origin)?;
+        ensure!(
+            batch.len() <= MAX_WEIGHTS_BATCH_SIZE as usize,
+            Error::<T>::WeightsBatchTooLarge
+        );
+
+        for (netuid, uids, values, version_key) in batch {
+            let result = if Self::get_target_epoch_weights(netuid) {
+                Err(Error::<T>::WeightsTargetEpochRequired.into())
+            } else if Self::get_commit_reveal_weights_enabled(netuid) {
+                Err(Error::<T>::CommitRevealEnabled.into())
+            } else {
+                with_storage_layer(|| {
+                    Self::do_set_weights(
+                        frame_system::RawOrigin::Signed(who.clone()).into(),
+                        netuid,
+                        uids,
+                        values,
+                        version_key,
+                    )
+                })
+            };
+            if let Err(error) = result {
+                log::info!(
+                    "WeightsBatchEntryFailed( netuid:{:?}, error:{:?} )",
+                    netuid,
+                    error
+                );
+                Self::deposit_subnet_event(
+                    &[netuid],
+                    Event::WeightsBatchEntryFailed(netuid, error),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic purge_my_weights.
+    ///
+    /// Removes the weight row of the calling hotkey on each of `netuids`, along with its
+    /// metadata hash and target epoch, so a hotkey that stopped validating a subnet stops
+    /// weighing in on its epochs. Subnets the hotkey is not registered on or holds no weights
+    /// on are skipped.
+    ///
+    /// # Event:
+    /// * WeightsPurged;
+    ///     - For each weight row removed.
+    ///
+    /// # Raises:
+    /// * 'WeightsBatchTooLarge':
+    ///     - More than MAX_WEIGHTS_BATCH_SIZE netuids are given.
+    ///
+    pub fn do_purge_my_weights(
+        origin: T::RuntimeOrigin,
+        netuids: Vec<u16>,
+    ) -> dispatch::DispatchResult {
         let hotkey = ensure_signed(origin)?;
+        ensure!(
+            netuids.len() <= MAX_WEIGHTS_BATCH_SIZE as usize,
+            Error::<T>::WeightsBatchTooLarge
+        );
+
+        for netuid in netuids {
+            let Ok(uid) = Self::get_uid_for_net_and_hotkey(netuid, &hotkey) else {
+                continue;
+            };
+            if !Weights::<T>::contains_key(netuid, uid) {
+                continue;
+            }
+            Weights::<T>::remove(netuid, uid);
+            WeightsMetadataHash::<T>::remove(netuid, uid);
+            WeightsTargetEpoch::<T>::remove(netuid, uid);
+
+            log::info!(
+                "WeightsPurged( netuid:{:?}, uid:{:?}, hotkey:{:?} )",
+                netuid,
+                uid,
+                hotkey
+            );
+            Self::deposit_subnet_event(
+                &[netuid],
+                Event::WeightsPurged(netuid, uid, hotkey.clone()),
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the epoch of `netuid` the weights of `uid` were set for, `None` if they were
+    /// never set.
+    pub fn get_weights_target_epoch(netuid: u16, uid: u16) -> Option<u64> {
+        WeightsTargetEpoch::<T>::get(netuid, uid)
+    }
+
+    /// Whether the weights of each uid of `netuid` fail to count in its next epoch, which are
+    /// the ones not set for it on subnets with TARGET_EPOCH_WEIGHTS.
+    pub fn get_stale_weights(netuid: u16) -> Vec<bool> {
+        let n = Self::get_subnetwork_n(netuid);
+        if !Self::get_target_epoch_weights(netuid) {
+            return vec![false; usize::from(n)];
+        }
+        let epoch_index = Self::get_epoch_index(netuid);
+        (0..n)
+            .map(|uid| Self::get_weights_target_epoch(netuid, uid) != Some(epoch_index))
+            .collect()
+    }
+
+    /// ---- The implementation for the extrinsic set_weights_with_metadata.
+    ///
+    /// Sets weights as `do_set_weights` does and stores `metadata_hash` alongside them, such
+    /// as the CID of the evaluation report behind the weights. Weights set without a metadata
+    /// hash clear the stored one.
+    ///
+    pub fn do_set_weights_with_metadata(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+        metadata_hash: Option<H256>,
+    ) -> dispatch::DispatchResult {
+        // --- 1. Check the caller's signature. This is the hotkey of a registered account, or
+        // the weights key it authorized.
+        let hotkey = Self::get_weights_hotkey(netuid, ensure_signed(origin)?);
         log::info!(
             "do_set_weights( origin:{:?} netuid:{:?}, uids:{:?}, values:{:?})",
             hotkey,
@@ -238,9 +552,15 @@ impl<T: Config> Pallet<T> {
             Error::<T>::IncorrectWeightVersionKey
         );
 
+        // --- 8. Ensure weight setting is not paused on the subnet.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            !Self::is_weights_paused(netuid, current_block),
+            Error::<T>::WeightsPaused
+        );
+
         // --- 9. Ensure the uid is not setting weights faster than the weights_set_rate_limit.
         let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
-        let current_block: u64 = Self::get_current_block_as_u64();
         ensure!(
             Self::check_rate_limit(netuid, neuron_uid, current_block),
             Error::<T>::SettingWeightsTooFast
@@ -267,23 +587,34 @@ impl<T: Config> Pallet<T> {
             Error::<T>::WeightVecLengthIsLow
         );
 
-        // --- 14. Max-upscale the weights.
-        let max_upscaled_weights: Vec<u16> = vec_u16_max_upscale_to_u16(&values);
+        // --- 14. Scale the weights per the subnet's weights representation.
+        let scaled_weights: Vec<u16> = Self::scale_weights_for_storage(netuid, &values);
 
         // --- 15. Ensure the weights are max weight limited
         ensure!(
-            Self::max_weight_limited(netuid, neuron_uid, &uids, &max_upscaled_weights),
+            Self::max_weight_limited(netuid, neuron_uid, &uids, &scaled_weights),
             Error::<T>::MaxWeightExceeded
         );
 
         // --- 16. Zip weights for sinking to storage map.
         let mut zipped_weights: Vec<(u16, u16)> = vec![];
-        for (uid, val) in uids.iter().zip(max_upscaled_weights.iter()) {
+        for (uid, val) in uids.iter().zip(scaled_weights.iter()) {
             zipped_weights.push((*uid, *val))
         }
 
         // --- 17. Set weights under netuid, uid double map entry.
-        Weights::<T>::insert(netuid, neuron_uid, zipped_weights);
+        Weights::<T>::insert(
+            netuid,
+            neuron_uid,
+            Self::weights_row(netuid, zipped_weights),
+        );
+        match metadata_hash {
+            Some(metadata_hash) => {
+                WeightsMetadataHash::<T>::insert(netuid, neuron_uid, metadata_hash)
+            }
+            None => WeightsMetadataHash::<T>::remove(netuid, neuron_uid),
+        }
+        WeightsTargetEpoch::<T>::insert(netuid, neuron_uid, Self::get_epoch_index(netuid));
 
         // --- 18. Set the activity for the weights on this network.
         Self::set_last_update_for_uid(netuid, neuron_uid, current_block);
@@ -294,7 +625,7 @@ impl<T: Config> Pallet<T> {
             netuid,
             neuron_uid
         );
-        Self::deposit_event(Event::WeightsSet(netuid, neuron_uid));
+        Self::deposit_subnet_event(&[netuid], Event::WeightsSet(netuid, neuron_uid));
 
         // --- 20. Return ok.
         Ok(())
@@ -334,8 +665,10 @@ impl<T: Config> Pallet<T> {
 
     /// Checks for any invalid uids on this network.
     pub fn contains_invalid_uids(netuid: u16, uids: &[u16]) -> bool {
+        let subnetwork_n: u16 = Self::get_subnetwork_n(netuid);
         for uid in uids {
-            if !Self::is_uid_exist_on_network(netuid, *uid) {
+            // Keys left past the size of the subnet are not uids the epoch reads.
+            if *uid >= subnetwork_n || !Self::is_uid_exist_on_network(netuid, *uid) {
                 log::debug!(
                     "contains_invalid_uids( netuid:{:?}, uid:{:?} does not exist on network. )",
                     netuid,
@@ -431,6 +764,24 @@ impl<T: Config> Pallet<T> {
         check_vec_max_limited(weights, max_weight_limit)
     }
 
+    /// Scales a weights row for storage. Rows are max-upscaled so the largest weight is
+    /// u16::MAX, or sum-normalized to u16::MAX on subnets with SUM_NORMALIZED_WEIGHTS.
+    pub fn scale_weights_for_storage(netuid: u16, values: &[u16]) -> Vec<u16> {
+        if Self::subnet_feature_enabled(netuid, subnet_features::SUM_NORMALIZED_WEIGHTS) {
+            vec_u16_sum_normalize_to_u16(values)
+        } else {
+            vec_u16_max_upscale_to_u16(values)
+        }
+    }
+
+    /// Whether the epoch reads the weight rows of `netuid` as sum-normalized. Until the sweep
+    /// has rewritten the rows set before SUM_NORMALIZED_WEIGHTS was switched, both
+    /// representations are mixed and the epoch row-normalizes them all.
+    pub fn weights_sum_normalized(netuid: u16) -> bool {
+        Self::subnet_feature_enabled(netuid, subnet_features::SUM_NORMALIZED_WEIGHTS)
+            && !WeightsRescalePending::<T>::contains_key(netuid)
+    }
+
     /// Returns true if the uids and weights correspond to a self weight on the uid.
     pub fn is_self_weight(uid: u16, uids: &[u16], weights: &[u16]) -> bool {
         if weights.len() != 1 {
@@ -498,4 +849,51 @@ impl<T: Config> Pallet<T> {
 
         false
     }
+
+    /// Whether weight setting on `netuid` is paused at `block_number`. Epochs keep running on
+    /// the weights set before the pause.
+    pub fn is_weights_paused(netuid: u16, block_number: u64) -> bool {
+        WeightsPausedUntil::<T>::get(netuid).is_some_and(|until| block_number < until)
+    }
+
+    /// Whether weight setting on `netuid` can be paused: not while it is paused, nor within
+    /// MaxWeightsPauseDuration blocks of the previous pause ending, so that pauses cannot be
+    /// chained into permanent censorship.
+    pub fn can_pause_weights(netuid: u16) -> bool {
+        WeightsPausedUntil::<T>::get(netuid).is_none_or(|until| {
+            Self::get_current_block_as_u64()
+                >= until.saturating_add(Self::get_max_weights_pause_duration())
+        })
+    }
+
+    /// Pauses weight setting on `netuid` for MaxWeightsPauseDuration blocks, after which it
+    /// resumes by itself, or ends the current pause.
+    pub fn set_weights_paused(netuid: u16, paused: bool) {
+        let current_block = Self::get_current_block_as_u64();
+        let until = if paused {
+            current_block.saturating_add(Self::get_max_weights_pause_duration())
+        } else if Self::is_weights_paused(netuid, current_block) {
+            current_block
+        } else {
+            return;
+        };
+        WeightsPausedUntil::<T>::insert(netuid, until);
+        Self::deposit_subnet_event(
+            &[netuid],
+            Event::WeightsPausedUntilSet(netuid, paused.then_some(until)),
+        );
+    }
+
+    pub fn get_weights_paused_until(netuid: u16) -> Option<u64> {
+        WeightsPausedUntil::<T>::get(netuid)
+            .filter(|until| *until > Self::get_current_block_as_u64())
+    }
+
+    pub fn get_max_weights_pause_duration() -> u64 {
+        MaxWeightsPauseDuration::<T>::get()
+    }
+    pub fn set_max_weights_pause_duration(duration: u64) {
+        MaxWeightsPauseDuration::<T>::put(duration);
+        Self::deposit_event(Event::MaxWeightsPauseDurationSet(duration));
+    }
 }
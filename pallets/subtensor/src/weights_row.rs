@@ -0,0 +1,269 @@
+use super::*;
+use codec::{Compact, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+/// Rows with more entries than uids fit in a `u16` don't decode. MaxAllowedUids is a `u16`,
+/// so this is the most uids a subnet can have.
+pub const MAX_WEIGHTS_ROW_LEN: usize = 1 << 16;
+
+/// Bytes of the longest row, `MAX_WEIGHTS_ROW_LEN` entries in `DELTA_FORMAT`: the format
+/// byte, the compact length and, per entry, a compact delta of at most 4 bytes and the weight.
+pub const MAX_WEIGHTS_ROW_BYTES: u32 = 1 + 5 + 6 * (MAX_WEIGHTS_ROW_LEN as u32);
+
+/// Bits of a weight stored without quantization.
+pub const FULL_WEIGHTS_PRECISION: u8 = 16;
+
+/// Each uid is stored as the zigzag delta from the previous one, followed by its weight.
+const DELTA_FORMAT: u8 = 0;
+/// A bitmap of the weighted uids, followed by their weights in ascending uid order.
+const BITMAP_FORMAT: u8 = 1;
+/// `DELTA_FORMAT` with quantized weights, preceded by their precision.
+const QUANTIZED_DELTA_FORMAT: u8 = 2;
+/// `BITMAP_FORMAT` with quantized weights, preceded by their precision.
+const QUANTIZED_BITMAP_FORMAT: u8 = 3;
+
+/// A row of the `Weights` map in its compressed storage format.
+///
+/// Rows sorted by uid are stored as a bitmap of the weighted uids when that is shorter, any
+/// other row as uid deltas, so a full row of a 4096-uid subnet takes about half the size of
+/// the `Vec<(u16, u16)>` it replaces. An empty row takes no bytes at all. Rows of subnets
+/// with a WeightsPrecision below 16 bits store each weight as one of `2^precision` buckets,
+/// in a single byte up to 8 bits.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Default, PartialEq, Eq, Debug)]
+pub struct WeightsRow(BoundedVec<u8, ConstU32<MAX_WEIGHTS_ROW_BYTES>>);
+
+impl WeightsRow {
+    /// Compresses a row of `(uid, weight)` pairs, keeping their order.
+    pub fn from_entries(entries: &[(u16, u16)]) -> Self {
+        Self::quantized(entries, FULL_WEIGHTS_PRECISION)
+    }
+
+    /// Compresses a row of `(uid, weight)` pairs, keeping their order, with each weight
+    /// rounded to the nearest of `2^precision` evenly spaced values between 0 and `u16::MAX`.
+    /// Entries past `MAX_WEIGHTS_ROW_LEN` are dropped.
+    pub fn quantized(entries: &[(u16, u16)], precision: u8) -> Self {
+        let entries = entries.get(..MAX_WEIGHTS_ROW_LEN).unwrap_or(entries);
+        if entries.is_empty() {
+            return Self::default();
+        }
+        let precision = precision.clamp(1, FULL_WEIGHTS_PRECISION);
+        let delta = Self::encode_delta(entries, precision);
+        let sorted = entries.windows(2).all(|pair| match pair {
+            [(previous, _), (next, _)] => previous < next,
+            _ => true,
+        });
+        if sorted {
+            let bitmap = Self::encode_bitmap(entries, precision);
+            if bitmap.len() < delta.len() {
+                return Self::from_bytes(bitmap);
+            }
+        }
+        Self::from_bytes(delta)
+    }
+
+    /// Rows of at most `MAX_WEIGHTS_ROW_LEN` entries always fit `MAX_WEIGHTS_ROW_BYTES`.
+    fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(BoundedVec::truncate_from(bytes))
+    }
+
+    /// The `(uid, weight)` pairs of the row. A row whose stored bytes don't decode is logged
+    /// and read as empty, see `try_entries` to tell it apart from an empty row.
+    pub fn entries(&self) -> Vec<(u16, u16)> {
+        self.try_entries().unwrap_or_else(|error| {
+            log::error!("Undecodable weights row: {:?}", error);
+            Vec::new()
+        })
+    }
+
+    /// Whether the row weighs no uid.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The bits each weight of the row is stored with.
+    pub fn precision(&self) -> u8 {
+        match self.0.as_slice() {
+            [QUANTIZED_DELTA_FORMAT | QUANTIZED_BITMAP_FORMAT, precision, ..] => *precision,
+            _ => FULL_WEIGHTS_PRECISION,
+        }
+    }
+
+    fn header(format: u8, quantized_format: u8, precision: u8) -> Vec<u8> {
+        if precision >= FULL_WEIGHTS_PRECISION {
+            vec![format]
+        } else {
+            vec![quantized_format, precision]
+        }
+    }
+
+    fn encode_delta(entries: &[(u16, u16)], precision: u8) -> Vec<u8> {
+        let mut bytes = Self::header(DELTA_FORMAT, QUANTIZED_DELTA_FORMAT, precision);
+        Compact(u32::try_from(entries.len()).unwrap_or(u32::MAX)).encode_to(&mut bytes);
+        let mut previous: i32 = 0;
+        for (uid, weight) in entries {
+            let uid = i32::from(*uid);
+            Compact(zigzag(uid.saturating_sub(previous))).encode_to(&mut bytes);
+            encode_weight(*weight, precision, &mut bytes);
+            previous = uid;
+        }
+        bytes
+    }
+
+    /// Expects `entries` sorted by uid.
+    fn encode_bitmap(entries: &[(u16, u16)], precision: u8) -> Vec<u8> {
+        let last_uid = entries.last().map(|(uid, _)| *uid).unwrap_or_default();
+        let mut bitmap = vec![0u8; usize::from(last_uid).saturating_div(8).saturating_add(1)];
+        for (uid, _) in entries {
+            if let Some(byte) = bitmap.get_mut(usize::from(*uid).saturating_div(8)) {
+                *byte |= bit_mask(*uid);
+            }
+        }
+        let mut bytes = Self::header(BITMAP_FORMAT, QUANTIZED_BITMAP_FORMAT, precision);
+        bitmap.encode_to(&mut bytes);
+        for (_, weight) in entries {
+            encode_weight(*weight, precision, &mut bytes);
+        }
+        bytes
+    }
+
+    /// The `(uid, weight)` pairs of the row, or why its stored bytes don't decode.
+    pub fn try_entries(&self) -> Result<Vec<(u16, u16)>, codec::Error> {
+        let Some((format, mut input)) = self.0.split_first() else {
+            return Ok(Vec::new());
+        };
+        let precision = match *format {
+            DELTA_FORMAT | BITMAP_FORMAT => FULL_WEIGHTS_PRECISION,
+            QUANTIZED_DELTA_FORMAT | QUANTIZED_BITMAP_FORMAT => {
+                let precision = u8::decode(&mut input)?;
+                if precision == 0 || precision >= FULL_WEIGHTS_PRECISION {
+                    return Err("weights row precision out of range".into());
+                }
+                precision
+            }
+            _ => return Err("unknown weights row format".into()),
+        };
+        match *format {
+            DELTA_FORMAT | QUANTIZED_DELTA_FORMAT => Self::decode_delta(input, precision),
+            _ => Self::decode_bitmap(input, precision),
+        }
+    }
+
+    fn decode_delta(mut input: &[u8], precision: u8) -> Result<Vec<(u16, u16)>, codec::Error> {
+        let len = usize::try_from(Compact::<u32>::decode(&mut input)?.0).unwrap_or(usize::MAX);
+        if len > MAX_WEIGHTS_ROW_LEN {
+            return Err("weights row too long".into());
+        }
+        let mut entries = Vec::with_capacity(len);
+        let mut uid: i32 = 0;
+        for _ in 0..len {
+            uid = uid.saturating_add(unzigzag(Compact::<u32>::decode(&mut input)?.0));
+            let uid = u16::try_from(uid).map_err(|_| "weights row uid out of range")?;
+            entries.push((uid, decode_weight(&mut input, precision)?));
+        }
+        Ok(entries)
+    }
+
+    fn decode_bitmap(mut input: &[u8], precision: u8) -> Result<Vec<(u16, u16)>, codec::Error> {
+        let bitmap = Vec::<u8>::decode(&mut input)?;
+        if bitmap.len() > MAX_WEIGHTS_ROW_LEN.saturating_div(8) {
+            return Err("weights row too long".into());
+        }
+        let mut entries = Vec::new();
+        for (index, byte) in bitmap.iter().enumerate() {
+            let first_uid = u16::try_from(index.saturating_mul(8))
+                .map_err(|_| "weights row uid out of range")?;
+            for uid in first_uid..=first_uid.saturating_add(7) {
+                if byte & bit_mask(uid) != 0 {
+                    entries.push((uid, decode_weight(&mut input, precision)?));
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+impl From<Vec<(u16, u16)>> for WeightsRow {
+    fn from(entries: Vec<(u16, u16)>) -> Self {
+        Self::from_entries(&entries)
+    }
+}
+
+/// Writes the bucket of `weight`, in one byte up to 8 bits of precision.
+fn encode_weight(weight: u16, precision: u8, bytes: &mut Vec<u8>) {
+    let bucket = quantize(weight, precision);
+    if precision <= 8 {
+        u8::try_from(bucket).unwrap_or(u8::MAX).encode_to(bytes);
+    } else {
+        bucket.encode_to(bytes);
+    }
+}
+
+fn decode_weight(input: &mut &[u8], precision: u8) -> Result<u16, codec::Error> {
+    let bucket = if precision <= 8 {
+        u16::from(u8::decode(input)?)
+    } else {
+        u16::decode(input)?
+    };
+    Ok(dequantize(bucket, precision))
+}
+
+/// The number of the highest bucket of `precision` bits.
+fn max_bucket(precision: u8) -> u32 {
+    1u32.checked_shl(u32::from(precision))
+        .unwrap_or_default()
+        .saturating_sub(1)
+}
+
+/// Rounds `weight` to the nearest bucket of `precision` bits.
+fn quantize(weight: u16, precision: u8) -> u16 {
+    if precision >= FULL_WEIGHTS_PRECISION {
+        return weight;
+    }
+    let bucket = u32::from(weight)
+        .saturating_mul(max_bucket(precision))
+        .saturating_add(u32::from(u16::MAX).saturating_div(2))
+        .checked_div(u32::from(u16::MAX))
+        .unwrap_or_default();
+    u16::try_from(bucket).unwrap_or(u16::MAX)
+}
+
+/// Maps a bucket of `precision` bits back to the weight it stands for, the highest bucket
+/// to `u16::MAX`.
+fn dequantize(bucket: u16, precision: u8) -> u16 {
+    if precision >= FULL_WEIGHTS_PRECISION {
+        return bucket;
+    }
+    let max_bucket = max_bucket(precision);
+    let weight = u32::from(bucket)
+        .saturating_mul(u32::from(u16::MAX))
+        .saturating_add(max_bucket.saturating_div(2))
+        .checked_div(max_bucket)
+        .unwrap_or_default();
+    u16::try_from(weight).unwrap_or(u16::MAX)
+}
+
+/// The bit of `uid` within its byte of a bitmap.
+fn bit_mask(uid: u16) -> u8 {
+    1u8.checked_shl(u32::from(uid.checked_rem(8).unwrap_or_default()))
+        .unwrap_or_default()
+}
+
+/// Maps small deltas of either sign to small unsigned values.
+fn zigzag(delta: i32) -> u32 {
+    if delta >= 0 {
+        delta.unsigned_abs().saturating_mul(2)
+    } else {
+        delta.unsigned_abs().saturating_mul(2).saturating_sub(1)
+    }
+}
+
+fn unzigzag(value: u32) -> i32 {
+    let magnitude = i32::try_from(value.saturating_add(1).saturating_div(2)).unwrap_or(i32::MAX);
+    if value.checked_rem(2) == Some(0) {
+        magnitude
+    } else {
+        magnitude.saturating_neg()
+    }
+}
@@ -0,0 +1,109 @@
+use super::*;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// Starts a sweep of the weight rows of `netuid` from its first uid, restarting any sweep
+    /// already running. Called when MinAllowedWeights is raised, as rows set under the old
+    /// minimum would otherwise still count in the next epochs, when WeightsPrecision changes,
+    /// as rows are only stored with the new precision once rewritten, and when
+    /// SUM_NORMALIZED_WEIGHTS is switched, as rows are only rescaled once rewritten.
+    pub fn schedule_weights_sweep(netuid: u16) {
+        WeightsSweepCursor::<T>::insert(netuid, 0);
+    }
+
+    /// Checks at most WeightsSweepChunkSize weight rows per block against the current
+    /// MinAllowedWeights of their subnet, removing those that would no longer be accepted by
+    /// `set_weights`, and rewrites the others stored with another precision than the current
+    /// WeightsPrecision of their subnet or, while WeightsRescalePending is set, scaled for
+    /// the other weights representation. Subnets are swept one after another, each resuming
+    /// at its cursor, and the pending rescale is cleared once the subnet is swept. Returns
+    /// the weight used.
+    pub fn sweep_nonconforming_weights() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let mut budget: u16 = Self::get_weights_sweep_chunk_size();
+        let sweeps: Vec<(u16, u16)> = WeightsSweepCursor::<T>::iter().collect();
+        weight.saturating_accrue(
+            T::DbWeight::get().reads(u64::try_from(sweeps.len()).unwrap_or(u64::MAX)),
+        );
+
+        for (netuid, cursor) in sweeps {
+            if budget == 0 {
+                break;
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 1));
+            // The sweep resumes once the subnet is back under its new netuid.
+            if Self::is_subnet_migrating(netuid) {
+                continue;
+            }
+            let subnetwork_n = Self::get_subnetwork_n(netuid);
+            let precision = Self::get_weights_precision(netuid);
+            let rescale = WeightsRescalePending::<T>::contains_key(netuid);
+            let end = cursor.saturating_add(budget).min(subnetwork_n);
+
+            for uid in cursor..end {
+                // Each row is read and at most rewritten or removed.
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+                let row = Weights::<T>::get(netuid, uid);
+                if row.is_empty() {
+                    continue;
+                }
+                let entries = row.entries();
+                let (uids, values): (Vec<u16>, Vec<u16>) = entries.iter().copied().unzip();
+                if Self::check_length(netuid, uid, &uids, &values) {
+                    let scaled = if rescale {
+                        Self::scale_weights_for_storage(netuid, &values)
+                    } else {
+                        values.clone()
+                    };
+                    if row.precision() != precision || scaled != values {
+                        let rescaled: Vec<(u16, u16)> = uids.into_iter().zip(scaled).collect();
+                        Weights::<T>::insert(
+                            netuid,
+                            uid,
+                            WeightsRow::quantized(&rescaled, precision),
+                        );
+                    }
+                    continue;
+                }
+                Weights::<T>::remove(netuid, uid);
+                log::info!(
+                    "NonconformingWeightsCleared( netuid:{:?}, uid:{:?} )",
+                    netuid,
+                    uid
+                );
+                Self::deposit_subnet_event(
+                    &[netuid],
+                    Event::NonconformingWeightsCleared(netuid, uid),
+                );
+            }
+            budget = budget.saturating_sub(end.saturating_sub(cursor));
+
+            if end >= subnetwork_n {
+                WeightsSweepCursor::<T>::remove(netuid);
+                WeightsRescalePending::<T>::remove(netuid);
+                log::info!("WeightsSweepCompleted( netuid:{:?} )", netuid);
+                Self::deposit_subnet_event(&[netuid], Event::WeightsSweepCompleted(netuid));
+            } else {
+                WeightsSweepCursor::<T>::insert(netuid, end);
+            }
+        }
+        weight
+    }
+
+    /// Compresses a weight row of `netuid` with the WeightsPrecision of the subnet.
+    pub fn weights_row(netuid: u16, entries: Vec<(u16, u16)>) -> WeightsRow {
+        WeightsRow::quantized(&entries, Self::get_weights_precision(netuid))
+    }
+
+    pub fn get_weights_sweep_cursor(netuid: u16) -> Option<u16> {
+        WeightsSweepCursor::<T>::get(netuid)
+    }
+
+    pub fn get_weights_sweep_chunk_size() -> u16 {
+        WeightsSweepChunkSize::<T>::get()
+    }
+    pub fn set_weights_sweep_chunk_size(chunk_size: u16) {
+        WeightsSweepChunkSize::<T>::put(chunk_size);
+        Self::deposit_event(Event::WeightsSweepChunkSizeSet(chunk_size));
+    }
+}
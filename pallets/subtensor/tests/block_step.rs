@@ -1,10 +1,44 @@
-#![allow(clippy::unwrap_used)]
+#![allow(
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::unwrap_used
+)]
 
 mod mock;
-use frame_support::assert_ok;
+use codec::Compact;
+use frame_support::{assert_ok, weights::Weight};
 use frame_system::Config;
 use mock::*;
+use pallet_subtensor::{EmissionValues, Error, TotalIssuance, WeightInfo, WeightsRow};
 use sp_core::U256;
+use sp_runtime::DispatchError;
+
+#[test]
+fn test_generate_emission_weighs_the_epochs_run() {
+    new_test_ext(1).execute_with(|| {
+        let n: u16 = 100;
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        for i in 0..n {
+            SubtensorModule::append_neuron(netuid, &U256::from(i), 0);
+        }
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            0,
+            WeightsRow::from_entries(&[(1, 1), (2, 2), (3, 3)]),
+        );
+
+        // No epoch runs before the subnet reaches its tempo.
+        assert_eq!(SubtensorModule::generate_emission(0), Weight::zero());
+        // The epoch is weighed for the subnet size and its longest weight row.
+        assert_eq!(
+            SubtensorModule::generate_emission(8),
+            <() as WeightInfo>::benchmark_epoch(u32::from(n), 3)
+        );
+    });
+}
 
 #[test]
 fn test_loaded_emission() {
@@ -805,6 +839,130 @@ fn test_burn_adjustment_case_e_zero_registrations() {
     });
 }
 
+#[test]
+fn test_burn_ema_smooths_bursty_registrations() {
+    // Bursts of burned registrations followed by idle intervals. With an AdjustmentAlpha of one
+    // half, each interval the burn moves only half way towards the step-wise target.
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let target_registrations_per_interval: u16 = 2;
+        let burst: u16 = 6;
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 256);
+        SubtensorModule::set_max_registrations_per_block(netuid, burst);
+        SubtensorModule::set_target_registrations_per_interval(
+            netuid,
+            target_registrations_per_interval,
+        );
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        SubtensorModule::set_adjustment_alpha(netuid, u64::MAX / 2);
+        SubtensorModule::set_min_burn(netuid, 1);
+        SubtensorModule::set_max_burn(netuid, 1_000_000_000);
+        SubtensorModule::set_burn(netuid, 1_000_000);
+        SubtensorModule::set_burn_ema_enabled(netuid, true);
+
+        let mut key: u64 = 0;
+        for round in 0..12 {
+            let registrations: u16 = if round % 4 == 0 { burst } else { 0 };
+            let burn_before = SubtensorModule::get_burn_as_u64(netuid);
+            for _ in 0..registrations {
+                key += 1;
+                let hotkey = U256::from(key);
+                let coldkey = U256::from(key + 10_000);
+                SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000_000);
+                assert_ok!(SubtensorModule::burned_register(
+                    <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                    netuid,
+                    hotkey
+                ));
+            }
+            step_block(1);
+            let burn_after = SubtensorModule::get_burn_as_u64(netuid);
+
+            // Step-wise this would be (6 + 2) / 4 = 2x after a burst and (0 + 2) / 4 = 0.5x when
+            // idle. Smoothed it is 0.5 + 0.5 * 2 = 1.5x and 0.5 + 0.5 * 0.5 = 0.75x.
+            let expected = if registrations == burst {
+                burn_before / 2 * 3
+            } else {
+                burn_before / 4 * 3
+            };
+            let tolerance = burn_before / 10_000;
+            assert!(
+                burn_after.abs_diff(expected) <= tolerance,
+                "round {}: burn {} -> {}, expected {}",
+                round,
+                burn_before,
+                burn_after,
+                expected
+            );
+        }
+
+        // Three 1.5x bursts and nine 0.75x idle intervals: 1.5^3 * 0.75^9 ~= 0.253.
+        let burn = SubtensorModule::get_burn_as_u64(netuid);
+        assert!((250_000..=256_000).contains(&burn));
+    });
+}
+
+#[test]
+fn test_burn_ema_follows_pow_registrations() {
+    // Too many registrations, all of them pow: the burn only follows on subnets with BURN_EMA.
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 2);
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        SubtensorModule::set_adjustment_alpha(netuid, 0);
+        SubtensorModule::set_min_burn(netuid, 1);
+        SubtensorModule::set_max_burn(netuid, 1_000_000);
+        SubtensorModule::set_burn(netuid, 1_000);
+
+        SubtensorModule::set_registrations_this_interval(netuid, 6);
+        SubtensorModule::set_pow_registrations_this_interval(netuid, 6);
+        step_block(1);
+        assert_eq!(SubtensorModule::get_burn_as_u64(netuid), 1_000);
+
+        SubtensorModule::set_burn_ema_enabled(netuid, true);
+        SubtensorModule::set_registrations_this_interval(netuid, 6);
+        SubtensorModule::set_pow_registrations_this_interval(netuid, 6);
+        step_block(1);
+        assert_eq!(SubtensorModule::get_burn_as_u64(netuid), 2_000);
+    });
+}
+
+#[test]
+fn test_registration_pressure_ema() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+
+        // No weight on the previous value: the full step-wise adjustment.
+        SubtensorModule::set_adjustment_alpha(netuid, 0);
+        assert_eq!(
+            SubtensorModule::registration_pressure_ema(netuid, 1_000, 6, 2).to_num::<u64>(),
+            2_000
+        );
+        assert_eq!(
+            SubtensorModule::registration_pressure_ema(netuid, 1_000, 0, 2).to_num::<u64>(),
+            500
+        );
+
+        // All weight on the previous value: nothing moves.
+        SubtensorModule::set_adjustment_alpha(netuid, u64::MAX);
+        assert_eq!(
+            SubtensorModule::registration_pressure_ema(netuid, 1_000, 6, 2).to_num::<u64>(),
+            1_000
+        );
+
+        // Registrations on target leave the value unchanged.
+        SubtensorModule::set_adjustment_alpha(netuid, 0);
+        assert_eq!(
+            SubtensorModule::registration_pressure_ema(netuid, 1_000, 2, 2).to_num::<u64>(),
+            1_000
+        );
+    });
+}
+
 #[test]
 fn test_emission_based_on_registration_status() {
     new_test_ext(1).execute_with(|| {
@@ -938,3 +1096,605 @@ fn test_epoch_runs_when_registration_disabled() {
         assert!(SubtensorModule::get_loaded_emission_tuples(netuid_off).is_some());
     });
 }
+
+#[test]
+fn test_maintenance_window_pauses_epochs() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 1;
+        let owner = U256::from(5);
+        add_network(netuid, tempo, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_emission_values(&[netuid], vec![1_000_000]).unwrap();
+
+        // Only the owner or root can schedule, within the maximum duration and not in the past.
+        assert_eq!(
+            SubtensorModule::schedule_maintenance(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(6)),
+                netuid,
+                1,
+                10
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(
+            SubtensorModule::schedule_maintenance(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                1,
+                SubtensorModule::get_max_maintenance_duration().saturating_add(1)
+            ),
+            Err(Error::<Test>::MaintenanceWindowTooLong.into())
+        );
+        assert_eq!(
+            SubtensorModule::schedule_maintenance(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                0,
+                10
+            ),
+            Err(Error::<Test>::MaintenanceWindowInPast.into())
+        );
+        // The window covers blocks 1 to 10.
+        assert_ok!(SubtensorModule::schedule_maintenance(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            1,
+            10
+        ));
+        assert_eq!(
+            SubtensorModule::get_maintenance_window(netuid),
+            Some((1, 10))
+        );
+
+        // Registration continues during the window.
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 1);
+
+        // No epoch runs, the emission accrues to pending.
+        SubtensorModule::generate_emission(1);
+        let emission_per_block = SubtensorModule::get_pending_emission(netuid);
+        assert!(emission_per_block > 0);
+        for block in 2..=10 {
+            SubtensorModule::generate_emission(block);
+        }
+        assert_eq!(
+            SubtensorModule::get_pending_emission(netuid),
+            emission_per_block.saturating_mul(10)
+        );
+        assert!(SubtensorModule::get_loaded_emission_tuples(netuid).is_none());
+        assert_eq!(SubtensorModule::get_blocks_since_last_step(netuid), 10);
+
+        // The first epoch after the window drains the accumulated emission.
+        let mut block: u64 = 11;
+        while SubtensorModule::blocks_until_next_epoch(netuid, tempo, block) != 0 {
+            SubtensorModule::generate_emission(block);
+            block = block.saturating_add(1);
+        }
+        SubtensorModule::generate_emission(block);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+        assert!(SubtensorModule::get_loaded_emission_tuples(netuid).is_some());
+        assert_eq!(SubtensorModule::get_blocks_since_last_step(netuid), 0);
+
+        // A zero duration clears the window.
+        assert_ok!(SubtensorModule::schedule_maintenance(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            0,
+            0
+        ));
+        assert_eq!(SubtensorModule::get_maintenance_window(netuid), None);
+    });
+}
+
+#[test]
+fn test_low_utilization_burn() {
+    new_test_ext(1).execute_with(|| {
+        let busy_netuid: u16 = 1;
+        let idle_netuid: u16 = 2;
+        let n: u16 = 4;
+        for netuid in [busy_netuid, idle_netuid] {
+            add_network(netuid, 10, 0);
+            SubtensorModule::set_activity_cutoff(netuid, 100);
+            SubtensorModule::set_max_allowed_uids(netuid, n);
+            for i in 0..n {
+                let hotkey = U256::from(u32::from(netuid) * 100 + u32::from(i));
+                SubtensorModule::append_neuron(netuid, &hotkey, 0);
+            }
+            EmissionValues::<Test>::insert(netuid, 1_000);
+        }
+
+        // Every uid of the busy subnet and one uid of the idle subnet are active.
+        System::set_block_number(1_000);
+        for uid in 0..n {
+            SubtensorModule::set_last_update_for_uid(busy_netuid, uid, 1_000);
+        }
+        SubtensorModule::set_last_update_for_uid(idle_netuid, 0, 950);
+        assert_eq!(
+            SubtensorModule::get_subnet_utilization(busy_netuid),
+            u16::MAX
+        );
+        assert_eq!(SubtensorModule::get_subnet_utilization(idle_netuid), 16_383);
+
+        // Nothing is burned until the burn is set.
+        SubtensorModule::set_utilization_floor(u16::MAX / 2);
+        SubtensorModule::burn_emission_of_underused_subnets();
+        assert_eq!(EmissionValues::<Test>::get(idle_netuid), 1_000);
+        assert_eq!(
+            SubtensorModule::get_recorded_subnet_utilization(idle_netuid),
+            16_383
+        );
+
+        SubtensorModule::set_low_utilization_burn(u16::MAX / 2);
+        SubtensorModule::burn_emission_of_underused_subnets();
+        assert_eq!(EmissionValues::<Test>::get(busy_netuid), 1_000);
+        assert_eq!(EmissionValues::<Test>::get(idle_netuid), 501);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetEmissionBurned(idle_netuid, 16_383, 499),
+        ));
+    });
+}
+
+#[test]
+fn test_max_allowed_uids_growth() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 64);
+        assert_eq!(
+            SubtensorModule::do_schedule_max_allowed_uids_growth(netuid, 0, 10, 160),
+            Err(Error::<Test>::InvalidMaxAllowedUidsGrowth.into())
+        );
+        assert_ok!(SubtensorModule::do_schedule_max_allowed_uids_growth(
+            netuid, 64, 10, 160
+        ));
+
+        // Nothing grows before the interval has passed.
+        run_to_block(10);
+        assert_eq!(SubtensorModule::get_max_allowed_uids(netuid), 64);
+        run_to_block(11);
+        assert_eq!(SubtensorModule::get_max_allowed_uids(netuid), 128);
+        assert_eq!(
+            SubtensorModule::get_max_allowed_uids_growth(netuid),
+            Some((64, 10, 160, 21))
+        );
+
+        // The last step stops at the target and ends the schedule.
+        run_to_block(21);
+        assert_eq!(SubtensorModule::get_max_allowed_uids(netuid), 160);
+        assert_eq!(SubtensorModule::get_max_allowed_uids_growth(netuid), None);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::MaxAllowedUidsGrowthCompleted(netuid),
+        ));
+        run_to_block(31);
+        assert_eq!(SubtensorModule::get_max_allowed_uids(netuid), 160);
+    });
+}
+
+#[test]
+fn test_hotkey_emission_tempo() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let tempo: u64 = 10;
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::set_hotkey_emission_tempo(tempo);
+
+        // Each hotkey has exactly one payout block per tempo.
+        let current_block = SubtensorModule::get_current_block_as_u64();
+        let payout_blocks: Vec<u64> = (current_block..current_block + tempo)
+            .filter(|block| SubtensorModule::is_hotkey_emission_block(&hotkey, *block, tempo))
+            .collect();
+        assert_eq!(payout_blocks.len(), 1);
+        let payout_block = *payout_blocks.first().unwrap();
+
+        assert_eq!(
+            SubtensorModule::accrue_hotkey_emission(&hotkey, 1, 100, 200),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::accrue_hotkey_emission(&hotkey, 1, 10, 20),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_pending_hotkey_emission(&hotkey),
+            (110, 220)
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 0);
+        // The hotkey is bucketed under its payout block only.
+        assert!(
+            pallet_subtensor::HotkeyEmissionPayouts::<Test>::contains_key(payout_block, hotkey)
+        );
+        assert_eq!(
+            pallet_subtensor::HotkeyEmissionPayouts::<Test>::iter().count(),
+            1
+        );
+
+        let issuance = TotalIssuance::<Test>::get();
+        SubtensorModule::drain_hotkey_emission(payout_block + 1);
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 0);
+        SubtensorModule::drain_hotkey_emission(payout_block);
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 330);
+        assert_eq!(
+            SubtensorModule::get_pending_hotkey_emission(&hotkey),
+            (0, 0)
+        );
+        assert_eq!(TotalIssuance::<Test>::get(), issuance + 330);
+        assert_eq!(
+            pallet_subtensor::HotkeyEmissionPayouts::<Test>::iter().count(),
+            0
+        );
+
+        // Without a tempo, emission is paid out as it is drained.
+        SubtensorModule::set_hotkey_emission_tempo(0);
+        assert_eq!(SubtensorModule::accrue_hotkey_emission(&hotkey, 1, 1, 2), 3);
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 333);
+    });
+}
+
+#[test]
+fn test_epoch_pause_signals() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_epoch_pause_duration(20);
+        // Uids 0 and 1 hold permits, uid 2 holds most of the stake without one.
+        for (uid, stake, permit) in [(0u16, 300u64, true), (1, 100, true), (2, 600, false)] {
+            let hotkey = U256::from(uid);
+            SubtensorModule::append_neuron(netuid, &hotkey, 0);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(100 + uid),
+                &hotkey,
+                stake,
+            );
+            SubtensorModule::set_validator_permit_for_uid(netuid, uid, permit);
+        }
+
+        let signal = |hotkey: u16, pause: bool| {
+            SubtensorModule::signal_epoch_pause(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(hotkey)),
+                netuid,
+                pause,
+            )
+        };
+        assert_eq!(
+            signal(2, true),
+            Err(Error::<Test>::NeuronNoValidatorPermit.into())
+        );
+        assert_eq!(
+            signal(3, true),
+            Err(Error::<Test>::HotKeyNotRegisteredInSubNet.into())
+        );
+
+        // A quarter of the permitted stake does not pause the subnet.
+        assert_ok!(signal(1, true));
+        SubtensorModule::apply_epoch_pause_signals(5);
+        assert_eq!(SubtensorModule::get_epoch_pause_stake(netuid), (100, 400));
+        assert!(!SubtensorModule::is_epoch_paused(netuid, 5));
+
+        // A withdrawn signal no longer counts.
+        assert_ok!(signal(1, false));
+        assert_ok!(signal(0, true));
+        assert_eq!(SubtensorModule::get_epoch_pause_stake(netuid), (300, 400));
+        SubtensorModule::apply_epoch_pause_signals(5);
+        assert!(SubtensorModule::is_epoch_paused(netuid, 5));
+        assert_eq!(SubtensorModule::get_epoch_paused_until(netuid), Some(25));
+        assert_eq!(SubtensorModule::get_epoch_pause_stake(netuid), (0, 400));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::EpochPaused(netuid, 25),
+        ));
+
+        // The epoch due during the pause is skipped, emission keeps accruing.
+        SubtensorModule::set_emission_values(&[netuid], vec![1_000]).unwrap();
+        assert_eq!(SubtensorModule::blocks_until_next_epoch(netuid, 10, 19), 0);
+        SubtensorModule::generate_emission(19);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 1_000);
+
+        SubtensorModule::apply_epoch_pause_signals(25);
+        assert!(!SubtensorModule::is_epoch_paused(netuid, 25));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::EpochPauseEnded(netuid),
+        ));
+    });
+}
+
+#[test]
+fn test_subnet_referendum() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1_000);
+        add_network(netuid, 10, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_referendum_duration(10);
+        // Uids 0 and 1 hold permits, uid 2 holds most of the stake without one.
+        for (uid, stake, permit) in [(0u16, 300u64, true), (1, 100, true), (2, 600, false)] {
+            let hotkey = U256::from(uid);
+            let coldkey = U256::from(100 + uid);
+            SubtensorModule::append_neuron(netuid, &hotkey, 0);
+            SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake);
+            SubtensorModule::set_validator_permit_for_uid(netuid, uid, permit);
+        }
+
+        let propose = |who: U256, value: u16| {
+            SubtensorModule::propose_subnet_hyperparam(
+                <<Test as Config>::RuntimeOrigin>::signed(who),
+                netuid,
+                pallet_subtensor::OwnerHyperparam::Tempo,
+                value,
+            )
+        };
+        let vote = |hotkey: u16, approve: bool| {
+            SubtensorModule::vote_subnet_referendum(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(hotkey)),
+                netuid,
+                approve,
+            )
+        };
+        assert_eq!(vote(0, true), Err(Error::<Test>::NoOpenReferendum.into()));
+        assert_eq!(propose(U256::from(0), 20), Err(DispatchError::BadOrigin));
+        assert_eq!(
+            propose(owner, 5),
+            Err(Error::<Test>::ReferendumValueOutOfBounds.into())
+        );
+        assert_ok!(propose(owner, 20));
+        assert_eq!(
+            SubtensorModule::get_subnet_referendum(netuid),
+            Some((pallet_subtensor::OwnerHyperparam::Tempo, 20, 11))
+        );
+        assert_eq!(
+            propose(owner, 30),
+            Err(Error::<Test>::ReferendumAlreadyOpen.into())
+        );
+
+        assert_eq!(
+            vote(2, true),
+            Err(Error::<Test>::NeuronNoValidatorPermit.into())
+        );
+        assert_eq!(
+            vote(3, true),
+            Err(Error::<Test>::HotKeyNotRegisteredInSubNet.into())
+        );
+        assert_ok!(vote(0, true));
+        assert_eq!(
+            vote(0, false),
+            Err(Error::<Test>::AlreadyVotedInReferendum.into())
+        );
+
+        // The stake of the voter is locked until the referendum closes.
+        assert_eq!(
+            SubtensorModule::get_referendum_locked_stake(&U256::from(0)),
+            300
+        );
+        assert_eq!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(100)),
+                U256::from(0),
+                1
+            ),
+            Err(Error::<Test>::StakeLockedInReferendum.into())
+        );
+
+        // Three quarters of the permitted stake pass the referendum once it ends.
+        SubtensorModule::close_ended_subnet_referenda(10);
+        assert_eq!(SubtensorModule::get_tempo(netuid), 10);
+        SubtensorModule::close_ended_subnet_referenda(11);
+        assert_eq!(SubtensorModule::get_tempo(netuid), 20);
+        assert_eq!(SubtensorModule::get_subnet_referendum(netuid), None);
+        assert_eq!(
+            SubtensorModule::get_referendum_locked_stake(&U256::from(0)),
+            0
+        );
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetReferendumClosed(netuid, true),
+        ));
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(100)),
+            U256::from(0),
+            1
+        ));
+
+        // A quarter of the permitted stake does not.
+        assert_ok!(propose(owner, 30));
+        assert_ok!(vote(1, true));
+        assert_ok!(vote(0, false));
+        SubtensorModule::close_ended_subnet_referenda(11);
+        assert_eq!(SubtensorModule::get_tempo(netuid), 20);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetReferendumClosed(netuid, false),
+        ));
+        assert_eq!(
+            SubtensorModule::get_referendum_locked_stake(&U256::from(1)),
+            0
+        );
+    });
+}
+
+#[cfg(feature = "strict-invariants")]
+#[test]
+fn test_check_invariants() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 0);
+        assert_ok!(SubtensorModule::check_invariants());
+
+        pallet_subtensor::Keys::<Test>::remove(netuid, 0);
+        assert_eq!(
+            SubtensorModule::check_invariants(),
+            Err("uid without a hotkey")
+        );
+        pallet_subtensor::Keys::<Test>::insert(netuid, 0, hotkey);
+
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            0,
+            pallet_subtensor::WeightsRow::from(vec![(0, 1), (1, 1)]),
+        );
+        assert_eq!(
+            SubtensorModule::check_invariants(),
+            Err("weight row longer than the subnet")
+        );
+        pallet_subtensor::Weights::<Test>::remove(netuid, 0);
+
+        EmissionValues::<Test>::insert(netuid, SubtensorModule::get_block_emission().unwrap() + 1);
+        assert_eq!(
+            SubtensorModule::check_invariants(),
+            Err("subnet emission exceeds the block emission")
+        );
+        EmissionValues::<Test>::remove(netuid);
+
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&U256::from(2), &hotkey, 100);
+        assert_ok!(SubtensorModule::check_invariants());
+        pallet_subtensor::TotalHotkeyStake::<Test>::insert(hotkey, 99);
+        assert_eq!(
+            SubtensorModule::check_invariants(),
+            Err("total hotkey stake differs from its stakes")
+        );
+    });
+}
+
+#[test]
+fn test_bonds_reset_in_chunks() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        for uid in 0..5u16 {
+            SubtensorModule::append_neuron(netuid, &U256::from(uid), 0);
+            pallet_subtensor::Bonds::<Test>::insert(netuid, uid, vec![(0, 100)]);
+        }
+        SubtensorModule::set_bonds_reset_chunk_size(2);
+        SubtensorModule::schedule_bonds_reset(netuid);
+        assert!(SubtensorModule::is_bonds_reset_in_progress(netuid));
+
+        // Each block clears a chunk of rows and reports the next uid.
+        SubtensorModule::reset_bonds_in_chunks();
+        assert_eq!(SubtensorModule::get_bonds_reset_cursor(netuid), Some(2));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::BondsResetProgress(netuid, 2),
+        ));
+        SubtensorModule::reset_bonds_in_chunks();
+        assert_eq!(SubtensorModule::get_bonds_reset_cursor(netuid), Some(4));
+        for uid in 0..4u16 {
+            assert!(pallet_subtensor::Bonds::<Test>::get(netuid, uid).is_empty());
+        }
+        assert_eq!(
+            pallet_subtensor::Bonds::<Test>::get(netuid, 4),
+            vec![(0, 100)]
+        );
+
+        // The epoch due mid-reset is skipped, emission keeps accruing.
+        SubtensorModule::set_emission_values(&[netuid], vec![1_000]).unwrap();
+        assert_eq!(SubtensorModule::blocks_until_next_epoch(netuid, 10, 19), 0);
+        SubtensorModule::generate_emission(19);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 1_000);
+
+        SubtensorModule::reset_bonds_in_chunks();
+        assert!(!SubtensorModule::is_bonds_reset_in_progress(netuid));
+        assert!(pallet_subtensor::Bonds::<Test>::get(netuid, 4).is_empty());
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::BondsResetCompleted(netuid),
+        ));
+    });
+}
+
+#[test]
+fn test_pending_emission_accumulates_until_tempo() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_emission_values(&[netuid], vec![1_000]).unwrap();
+
+        // Every block between epochs adds the emission value to the pending emission.
+        assert_eq!(
+            SubtensorModule::blocks_until_next_epoch(netuid, tempo, 8),
+            0
+        );
+        for block in 9..19 {
+            SubtensorModule::generate_emission(block);
+            assert_eq!(
+                SubtensorModule::get_pending_emission(netuid),
+                (block - 8) * 1_000
+            );
+        }
+        assert!(SubtensorModule::get_loaded_emission_tuples(netuid).is_none());
+
+        // The epoch of the subnet drains it.
+        SubtensorModule::generate_emission(19);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+        assert_eq!(SubtensorModule::get_blocks_since_last_step(netuid), 0);
+
+        // Subnets with registration turned off accumulate nothing.
+        SubtensorModule::set_network_registration_allowed(netuid, false);
+        SubtensorModule::set_network_pow_registration_allowed(netuid, false);
+        assert_eq!(SubtensorModule::accumulate_pending_emission(netuid), 0);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+    });
+}
+
+#[test]
+fn test_emission_multiplier() {
+    new_test_ext(1).execute_with(|| {
+        let (reduced, boosted, untouched): (u16, u16, u16) = (1, 2, 3);
+        for netuid in [reduced, boosted, untouched] {
+            add_network(netuid, 10, 0);
+            EmissionValues::<Test>::insert(netuid, 1_000);
+        }
+        assert_eq!(SubtensorModule::get_emission_multiplier(untouched), 10_000);
+        SubtensorModule::set_emission_multiplier(reduced, 2_500);
+        SubtensorModule::set_emission_multiplier(boosted, 30_000);
+
+        SubtensorModule::apply_emission_multipliers();
+        assert_eq!(EmissionValues::<Test>::get(reduced), 250);
+        assert_eq!(EmissionValues::<Test>::get(boosted), 3_000);
+        assert_eq!(EmissionValues::<Test>::get(untouched), 1_000);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetEmissionMultiplied(boosted, 30_000, 3_000),
+        ));
+
+        // Setting the multiplier back to 10_000 leaves the emission unchanged again.
+        SubtensorModule::set_emission_multiplier(reduced, 10_000);
+        SubtensorModule::apply_emission_multipliers();
+        assert_eq!(EmissionValues::<Test>::get(reduced), 250);
+    });
+}
+
+#[test]
+fn test_get_all_epoch_schedules() {
+    new_test_ext(1).execute_with(|| {
+        add_network(1, 10, 0);
+        add_network(2, 0, 0);
+
+        // The epochs of subnet 1 run in the blocks 8, 19, 30...
+        let schedules = SubtensorModule::get_all_epoch_schedules();
+        assert_eq!(schedules.len(), 2);
+        assert_eq!(schedules[0].netuid, Compact(1));
+        assert_eq!(schedules[0].tempo, Compact(10));
+        assert_eq!(schedules[0].phase, Some(Compact(8)));
+        assert_eq!(schedules[0].blocks_until_next_epoch, Some(Compact(7)));
+        assert!(!schedules[0].epochs_skipped);
+
+        // A zero tempo never runs its epoch.
+        assert_eq!(schedules[1].netuid, Compact(2));
+        assert_eq!(schedules[1].phase, None);
+        assert_eq!(schedules[1].blocks_until_next_epoch, None);
+
+        // The epoch runs in the block announced, the next one a tempo later.
+        run_to_block(7);
+        assert_eq!(
+            SubtensorModule::get_all_epoch_schedules()[0].blocks_until_next_epoch,
+            Some(Compact(1))
+        );
+        run_to_block(8);
+        assert_eq!(SubtensorModule::get_last_mechanism_step_block(1), 8);
+        let schedule = &SubtensorModule::get_all_epoch_schedules()[0];
+        assert_eq!(schedule.phase, Some(Compact(8)));
+        assert_eq!(schedule.blocks_until_next_epoch, Some(Compact(11)));
+
+        // A subnet under maintenance skips its epochs.
+        pallet_subtensor::MaintenanceWindow::<Test>::insert(1, (9, 100));
+        assert!(SubtensorModule::get_all_epoch_schedules()[0].epochs_skipped);
+    });
+}
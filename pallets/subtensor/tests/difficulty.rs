@@ -166,3 +166,78 @@ fn test_registration_difficulty_adjustment() {
         assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 5833); // Difficulty unchanged
     });
 }
+
+#[test]
+fn test_adjustment_events_and_info() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_min_difficulty(netuid, 10000);
+        SubtensorModule::set_difficulty(netuid, 20000);
+        SubtensorModule::set_adjustment_interval(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 1);
+        SubtensorModule::set_max_registrations_per_block(netuid, 3);
+        assert_eq!(SubtensorModule::get_adjustment_info(netuid + 1), None);
+
+        register_ok_neuron(netuid, U256::from(1), U256::from(10), 39420842);
+        register_ok_neuron(netuid, U256::from(2), U256::from(20), 12412392);
+        register_ok_neuron(netuid, U256::from(3), U256::from(30), 21813123);
+
+        // The interval started at block 0, so block 10 adjusts the registration terms.
+        let info = SubtensorModule::get_adjustment_info(netuid).unwrap();
+        assert_eq!(info.adjustment_interval.0, 10);
+        assert_eq!(info.last_adjustment_block.0, 0);
+        assert_eq!(info.blocks_until_next_adjustment.0, 8);
+        assert_eq!(info.registrations_this_interval.0, 3);
+        assert_eq!(info.pow_registrations_this_interval.0, 3);
+        assert_eq!(info.burn_registrations_this_interval.0, 0);
+        assert_eq!(info.target_registrations_per_interval.0, 1);
+        assert_eq!(info.difficulty.0, 20000);
+
+        step_block(8);
+        let info = SubtensorModule::get_adjustment_info(netuid).unwrap();
+        assert_eq!(info.blocks_until_next_adjustment.0, 0);
+        assert_eq!(info.registrations_this_interval.0, 3);
+
+        let old_burn = SubtensorModule::get_burn_as_u64(netuid);
+        System::reset_events();
+        step_block(1);
+        let new_burn = SubtensorModule::get_burn_as_u64(netuid);
+        let new_difficulty = SubtensorModule::get_difficulty_as_u64(netuid);
+        assert!(new_difficulty > 20000);
+        let adjustments: Vec<_> = System::events()
+            .into_iter()
+            .filter(|record| {
+                matches!(
+                    record.event,
+                    RuntimeEvent::SubtensorModule(
+                        pallet_subtensor::Event::BurnAdjusted(..)
+                            | pallet_subtensor::Event::DifficultyAdjusted(..)
+                    )
+                )
+            })
+            .collect();
+        assert_eq!(adjustments.len(), 2);
+        assert!(adjustments
+            .iter()
+            .all(|record| record.topics == vec![SubtensorModule::subnet_event_topic(netuid)]));
+        assert!(adjustments.iter().any(|record| record.event
+            == RuntimeEvent::SubtensorModule(pallet_subtensor::Event::BurnAdjusted(
+                netuid, old_burn, new_burn
+            ))));
+        assert!(adjustments.iter().any(|record| record.event
+            == RuntimeEvent::SubtensorModule(pallet_subtensor::Event::DifficultyAdjusted(
+                netuid,
+                20000,
+                new_difficulty
+            ))));
+
+        let info = SubtensorModule::get_adjustment_info(netuid).unwrap();
+        assert_eq!(info.last_adjustment_block.0, 10);
+        assert_eq!(info.blocks_until_next_adjustment.0, 9);
+        assert_eq!(info.registrations_this_interval.0, 0);
+        assert_eq!(info.pow_registrations_this_interval.0, 0);
+        assert_eq!(info.burn.0, new_burn);
+        assert_eq!(info.difficulty.0, new_difficulty);
+    });
+}
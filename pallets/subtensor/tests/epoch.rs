@@ -13,7 +13,7 @@ use rand::{distributions::Uniform, rngs::StdRng, seq::SliceRandom, thread_rng, R
 use sp_core::U256;
 use sp_runtime::DispatchError;
 use std::time::Instant;
-use substrate_fixed::types::I32F32;
+use substrate_fixed::types::{I32F32, I96F32};
 
 mod mock;
 
@@ -1728,6 +1728,141 @@ fn test_active_stake() {
 }
 
 // Test that epoch masks out outdated weights and bonds of validators on deregistered servers.
+// Test that a validator loses its influence on consensus exactly one block after the activity cutoff.
+#[test]
+fn test_activity_cutoff_boundary() {
+    new_test_ext(1).execute_with(|| {
+        let n: u16 = 4;
+        let netuid: u16 = 1;
+        let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
+        let activity_cutoff: u16 = 100;
+        let last_update: u64 = 10;
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+        SubtensorModule::set_max_allowed_validators(netuid, 2);
+        SubtensorModule::set_activity_cutoff(netuid, activity_cutoff);
+
+        // === Register [validator1, validator2, server1, server2]
+        for key in 0..n as u64 {
+            register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+        }
+        // Validator 1 holds the majority of the stake.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(0),
+            &U256::from(0),
+            3_000,
+        );
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(1),
+            &U256::from(1),
+            1_000,
+        );
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+
+        // === Set weights [val1->srv1, val2->srv2]
+        Weights::<Test>::insert(netuid, 0, WeightsRow::from(vec![(2, u16::MAX)]));
+        Weights::<Test>::insert(netuid, 1, WeightsRow::from(vec![(3, u16::MAX)]));
+        SubtensorModule::set_last_update_for_uid(netuid, 0, last_update);
+
+        // At exactly the cutoff validator 1 is still active and its server reaches consensus.
+        let boundary = last_update + activity_cutoff as u64;
+        System::set_block_number(boundary);
+        SubtensorModule::set_last_update_for_uid(netuid, 1, boundary);
+        SubtensorModule::epoch(netuid, None);
+        assert!(SubtensorModule::get_incentive_for_uid(netuid, 2) > 0);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 3), 0);
+
+        // One block later its stake no longer counts, so the other validator decides consensus.
+        System::set_block_number(boundary + 1);
+        SubtensorModule::set_last_update_for_uid(netuid, 1, boundary + 1);
+        SubtensorModule::epoch(netuid, None);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 2), 0);
+        assert!(SubtensorModule::get_incentive_for_uid(netuid, 3) > 0);
+    });
+}
+
+/// Runs an epoch in which validator 2 loses its permit to a newly staked neuron and
+/// returns the dividends of [validator1, validator2].
+fn dividends_on_permit_loss(sparse: bool, reset_bonds_on_permit_loss: bool) -> (u16, u16) {
+    let mut dividends = (0, 0);
+    new_test_ext(1).execute_with(|| {
+        let n: u16 = 3;
+        let netuid: u16 = 1;
+        let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+        SubtensorModule::set_max_allowed_validators(netuid, 2);
+        SubtensorModule::set_reset_bonds_on_permit_loss(netuid, reset_bonds_on_permit_loss);
+
+        // === Register [validator1, validator2, server]
+        for key in 0..n as u64 {
+            register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+        }
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(0),
+            &U256::from(0),
+            3_000,
+        );
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(1),
+            &U256::from(1),
+            1_000,
+        );
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+
+        // === Both validators weight the server and build up bonds.
+        System::set_block_number(2);
+        for uid in 0..2 {
+            Weights::<Test>::insert(netuid, uid, WeightsRow::from(vec![(2, u16::MAX)]));
+            SubtensorModule::set_last_update_for_uid(netuid, uid, 2);
+        }
+        let run_epoch = || {
+            if sparse {
+                SubtensorModule::epoch(netuid, None);
+            } else {
+                SubtensorModule::epoch_dense(netuid, 1_000_000_000);
+            }
+        };
+        run_epoch();
+        assert!(SubtensorModule::get_dividends_for_uid(netuid, 1) > 0);
+
+        // === The server outstakes validator 2, which loses its permit in the next epoch.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(2),
+            &U256::from(2),
+            2_000,
+        );
+        run_epoch();
+        assert!(!SubtensorModule::get_validator_permit_for_uid(netuid, 1));
+        assert!(Bonds::<Test>::get(netuid, 1).is_empty());
+        dividends = (
+            SubtensorModule::get_dividends_for_uid(netuid, 0),
+            SubtensorModule::get_dividends_for_uid(netuid, 1),
+        );
+    });
+    dividends
+}
+
+#[test]
+fn test_reset_bonds_on_permit_loss() {
+    for sparse in [true, false] {
+        // Without the reset, the stale bonds of validator 2 pay one last round of dividends.
+        let (kept, leaked) = dividends_on_permit_loss(sparse, false);
+        assert!(leaked > 0);
+
+        // With the reset, the dividends go to the validator that kept its permit.
+        let (reset_kept, reset_leaked) = dividends_on_permit_loss(sparse, true);
+        assert_eq!(reset_leaked, 0);
+        assert!(reset_kept > kept);
+    }
+}
+
 #[test]
 fn test_outdated_weights() {
     new_test_ext(1).execute_with(|| {
@@ -2230,6 +2365,51 @@ fn test_validator_permits() {
     }
 }
 
+// Test that tied stakes at the permit cutoff favour older registrations, then lower uids.
+#[test]
+fn test_validator_permits_tied_stake() {
+    let netuid: u16 = 1;
+    let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize
+    for sparse in [true, false] {
+        new_test_ext(1).execute_with(|| {
+            add_network(netuid, tempo, 0);
+            SubtensorModule::set_max_allowed_uids(netuid, 5);
+
+            // uid 4 has less stake than the rest and never gets a permit.
+            let registered_at: [u64; 5] = [5, 2, 2, 1, 0];
+            let stakes: [u64; 5] = [1_000, 1_000, 1_000, 1_000, 999];
+            for (key, (block, stake)) in registered_at.iter().zip(stakes.iter()).enumerate() {
+                let key = U256::from(key as u64);
+                SubtensorModule::append_neuron(netuid, &key, *block);
+                SubtensorModule::increase_stake_on_coldkey_hotkey_account(&key, &key, *stake);
+            }
+
+            SubtensorModule::set_max_allowed_validators(netuid, 2);
+            if sparse {
+                SubtensorModule::epoch(netuid, None);
+            } else {
+                SubtensorModule::epoch_dense(netuid, 1_000_000_000);
+            }
+            assert_eq!(
+                SubtensorModule::get_validator_permit(netuid),
+                vec![false, true, false, true, false]
+            );
+
+            // A third permit goes to the remaining uid registered at block 2.
+            SubtensorModule::set_max_allowed_validators(netuid, 3);
+            if sparse {
+                SubtensorModule::epoch(netuid, None);
+            } else {
+                SubtensorModule::epoch_dense(netuid, 1_000_000_000);
+            }
+            assert_eq!(
+                SubtensorModule::get_validator_permit(netuid),
+                vec![false, true, true, true, false]
+            );
+        });
+    }
+}
+
 #[test]
 fn test_compute_alpha_values() {
     // Define the consensus values.
@@ -2704,6 +2884,77 @@ fn test_get_set_alpha() {
     });
 }
 
+#[test]
+fn test_alpha_values_boundaries() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let signer = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+        add_network(netuid, 1, 0);
+        SubnetOwner::<Test>::insert(netuid, coldkey);
+        let min_alpha_high: u16 = (u16::MAX as u32 * 4 / 5) as u16;
+
+        SubtensorModule::set_liquid_alpha_enabled(netuid, true);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::LiquidAlphaEnabledSet(netuid, true),
+        ));
+
+        // The tightest bounds: alpha_high at its minimum and alpha_low just below it.
+        assert_ok!(SubtensorModule::do_set_alpha_values(
+            signer.clone(),
+            netuid,
+            min_alpha_high - 1,
+            min_alpha_high
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::AlphaValuesSet(netuid, min_alpha_high - 1, min_alpha_high),
+        ));
+        assert_err!(
+            SubtensorModule::do_set_alpha_values(
+                signer.clone(),
+                netuid,
+                min_alpha_high,
+                min_alpha_high
+            ),
+            Error::<Test>::AlphaLowOutOfRange
+        );
+
+        // The widest bounds still give alphas within them, including when alpha_high is 1.
+        assert_ok!(SubtensorModule::do_set_alpha_values(
+            signer.clone(),
+            netuid,
+            1,
+            u16::MAX
+        ));
+        let (alpha_low, alpha_high) = SubtensorModule::get_alpha_values_32(netuid);
+        assert_eq!(alpha_high, I32F32::from_num(1));
+        let consensus = vec![
+            I32F32::from_num(0),
+            I32F32::from_num(0.25),
+            I32F32::from_num(0.75),
+            I32F32::from_num(1),
+        ];
+        let (a, b) = SubtensorModule::calculate_logistic_params(
+            alpha_high,
+            alpha_low,
+            I32F32::from_num(0.75),
+            I32F32::from_num(0.25),
+        );
+        let alpha = SubtensorModule::clamp_alpha_values(
+            SubtensorModule::compute_alpha_values(&consensus, a, b),
+            alpha_high,
+            alpha_low,
+        );
+        assert_eq!(alpha.len(), consensus.len());
+        assert!(alpha.iter().all(|a| *a >= alpha_low && *a <= alpha_high));
+
+        SubtensorModule::set_liquid_alpha_enabled(netuid, false);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::LiquidAlphaEnabledSet(netuid, false),
+        ));
+    });
+}
+
 // // Map the retention graph for consensus guarantees with an single epoch on a graph with 512 nodes, of which the first 64 are validators, the graph is split into a major and minor set, each setting specific weight on itself and the complement on the other.
 // //
 // // ```import torch
@@ -2803,6 +3054,63 @@ fn test_get_set_alpha() {
 //     println!("]");
 // }
 
+#[test]
+fn test_commit_epoch_output() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, U256::from(0), U256::from(0), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 100_000);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+
+        let mut output = epoch::EpochOutput::with_capacity(2);
+        output.active = vec![true, false];
+        output.emission = vec![10, 20];
+        output.rank = vec![1, 2];
+        output.trust = vec![3, 4];
+        output.consensus = vec![5, 6];
+        output.incentive = vec![7, 8];
+        output.dividends = vec![9, 10];
+        output.pruning_scores = vec![11, 12];
+        output.validator_trust = vec![13, 14];
+        output.validator_permit = vec![true, false];
+        output.bonds = vec![Some(vec![(1, u16::MAX)]), Some(vec![(0, 1)])];
+        SubtensorModule::commit_epoch_output(netuid, output.clone());
+
+        assert_eq!(SubtensorModule::get_active(netuid), vec![true, false]);
+        assert_eq!(SubtensorModule::get_emission(netuid), vec![10, 20]);
+        assert_eq!(SubtensorModule::get_rank(netuid), vec![1, 2]);
+        assert_eq!(SubtensorModule::get_trust(netuid), vec![3, 4]);
+        assert_eq!(SubtensorModule::get_consensus(netuid), vec![5, 6]);
+        assert_eq!(SubtensorModule::get_incentive(netuid), vec![7, 8]);
+        assert_eq!(SubtensorModule::get_dividends(netuid), vec![9, 10]);
+        assert_eq!(SubtensorModule::get_pruning_score(netuid), vec![11, 12]);
+        assert_eq!(SubtensorModule::get_validator_trust(netuid), vec![13, 14]);
+        assert_eq!(
+            SubtensorModule::get_validator_permit(netuid),
+            vec![true, false]
+        );
+        assert_eq!(
+            SubtensorModule::get_bonds_sparse(netuid),
+            vec![
+                vec![(1, I32F32::from_num(u16::MAX))],
+                vec![(0, I32F32::from_num(1))]
+            ]
+        );
+
+        // Rows marked `None` are left untouched, every vector is rewritten.
+        output.rank = vec![2, 1];
+        output.bonds = vec![Some(vec![]), None];
+        SubtensorModule::commit_epoch_output(netuid, output);
+        assert_eq!(SubtensorModule::get_rank(netuid), vec![2, 1]);
+        assert_eq!(SubtensorModule::get_trust(netuid), vec![3, 4]);
+        assert_eq!(
+            SubtensorModule::get_bonds_sparse(netuid),
+            vec![vec![], vec![(0, I32F32::from_num(1))]]
+        );
+    });
+}
+
 /// Helpers
 
 /// Asserts that two I32F32 values are approximately equal within a given epsilon.
@@ -2846,3 +3154,403 @@ fn assert_approx_eq_vec_of_vec(
         }
     }
 }
+
+// Epochs computed together give the same results as epochs computed one by one, and nothing
+// is written until the outputs are committed.
+#[test]
+fn test_compute_epochs_matches_single_epochs() {
+    new_test_ext(1).execute_with(|| {
+        let netuids: [u16; 2] = [1, 2];
+        for netuid in netuids {
+            add_network(netuid, u16::MAX - 1, 0);
+            SubtensorModule::set_max_allowed_uids(netuid, 4);
+            for i in 0..4u64 {
+                let key = U256::from(u64::from(netuid) * 100 + i);
+                SubtensorModule::add_balance_to_coldkey_account(&key, 1_000 * (i + 1));
+                SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                    &key,
+                    &key,
+                    1_000 * (i + 1),
+                );
+                SubtensorModule::append_neuron(netuid, &key, 0);
+            }
+        }
+        run_to_block(1);
+        for netuid in netuids {
+            for i in 0..4u64 {
+                assert_ok!(SubtensorModule::set_weights(
+                    RuntimeOrigin::signed(U256::from(u64::from(netuid) * 100 + i)),
+                    netuid,
+                    vec![0, 1, 2, 3],
+                    vec![1, 2, 3, u16::from(netuid)],
+                    0
+                ));
+            }
+        }
+
+        // A first epoch hands out the validator permits, the second ranks the neurons.
+        for netuid in netuids {
+            SubtensorModule::epoch(netuid, None);
+            SubtensorModule::epoch(netuid, None);
+        }
+        let ranks: Vec<Vec<u16>> = netuids
+            .iter()
+            .map(|netuid| SubtensorModule::get_rank(*netuid))
+            .collect();
+        let state_root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+
+        let inputs: Vec<_> = netuids
+            .iter()
+            .map(|netuid| SubtensorModule::load_epoch_input(*netuid))
+            .collect();
+        let single: Vec<_> = inputs
+            .iter()
+            .map(|input| SubtensorModule::compute_epoch(input.clone(), None))
+            .collect();
+        let together = SubtensorModule::compute_epochs(inputs);
+        assert_eq!(together.len(), 2);
+        for ((info, output), (single_info, single_output)) in together.iter().zip(&single) {
+            assert_eq!(info.as_emission(), single_info.as_emission());
+            assert_eq!(output, single_output);
+            assert!(output.is_some());
+        }
+        assert_eq!(
+            sp_io::storage::root(sp_runtime::StateVersion::V1),
+            state_root
+        );
+
+        for (netuid, (_, output)) in netuids.iter().zip(together) {
+            SubtensorModule::commit_epoch_output(*netuid, output.unwrap());
+        }
+        let committed: Vec<Vec<u16>> = netuids
+            .iter()
+            .map(|netuid| SubtensorModule::get_rank(*netuid))
+            .collect();
+        assert_eq!(committed, ranks);
+        assert!(committed.iter().all(|ranks| ranks.iter().any(|r| *r > 0)));
+    });
+}
+
+#[test]
+fn test_simulate_epoch_kappa_sweep() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        let mut params = SubtensorModule::get_epoch_simulation_params(netuid);
+        params.max_allowed_validators = 4;
+        params.rao_emission = 1_000_000_000;
+
+        // Three validators back uid 4, a minority validator also backs uid 5.
+        let stakes: Vec<u64> = vec![100, 100, 100, 50, 0, 0];
+        let weights: Vec<Vec<(u16, u16)>> = vec![
+            vec![(4, u16::MAX)],
+            vec![(4, u16::MAX)],
+            vec![(4, u16::MAX)],
+            vec![(4, u16::MAX), (5, u16::MAX)],
+        ];
+        let state_root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+
+        params.kappa = u16::MAX / 2;
+        let majority = SubtensorModule::simulate_epoch(&params, &stakes, &weights);
+        params.kappa = u16::MAX / 10;
+        let minority = SubtensorModule::simulate_epoch(&params, &stakes, &weights);
+
+        assert_eq!(
+            majority.validator_permit,
+            vec![true, true, true, true, false, false]
+        );
+        assert!(majority.incentive[4] > 0);
+        assert_eq!(majority.incentive[5], 0);
+        assert!(minority.incentive[5] > 0);
+        assert!(minority.consensus[5] > majority.consensus[5]);
+        assert_eq!(
+            sp_io::storage::root(sp_runtime::StateVersion::V1),
+            state_root
+        );
+    });
+}
+
+#[test]
+fn test_epoch_snapshots() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        assert_eq!(
+            SubtensorModule::get_epoch_snapshot_root(netuid),
+            sp_core::H256::zero()
+        );
+
+        for uid in 0..3u64 {
+            SubtensorModule::append_neuron(netuid, &U256::from(uid), 0);
+        }
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(10),
+            &U256::from(0),
+            1_000,
+        );
+        Incentive::<Test>::insert(netuid, vec![1, 2, 3]);
+        Dividends::<Test>::insert(netuid, vec![4, 5, 6]);
+
+        // The last leaf is unpaired and carried up to the root level.
+        let leaf = |uid: u16, stake: u64, incentive: u16, dividends: u16| {
+            sp_io::hashing::blake2_256(&codec::Encode::encode(&(uid, stake, incentive, dividends)))
+        };
+        let pair = sp_io::hashing::blake2_256(&[leaf(0, 1_000, 1, 4), leaf(1, 0, 2, 5)].concat());
+        let root = sp_io::hashing::blake2_256(&[pair, leaf(2, 0, 3, 6)].concat());
+        assert_eq!(
+            SubtensorModule::get_epoch_snapshot_root(netuid),
+            sp_core::H256::from(root)
+        );
+
+        // Only the latest EPOCH_SNAPSHOT_HISTORY snapshots are kept.
+        for block in 0..=EPOCH_SNAPSHOT_HISTORY as u64 {
+            SubtensorModule::record_epoch_snapshot(netuid, block);
+        }
+        let snapshots = SubtensorModule::get_epoch_snapshots(netuid);
+        assert_eq!(snapshots.len(), EPOCH_SNAPSHOT_HISTORY);
+        assert_eq!(snapshots[0], (1, sp_core::H256::from(root)));
+    });
+}
+
+#[test]
+fn test_bootstrap_incentive_floor() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        for uid in 0..3u64 {
+            SubtensorModule::append_neuron(netuid, &U256::from(uid), 0);
+        }
+        SubtensorModule::set_last_mechanism_step_block(netuid, 10);
+        SubtensorModule::append_neuron(netuid, &U256::from(3), 12);
+        let epoch_emission: Vec<(U256, u64, u64)> = vec![
+            (U256::from(0), 600, 50),
+            (U256::from(1), 300, 0),
+            (U256::from(2), 100, 0),
+            (U256::from(3), 0, 0),
+        ];
+
+        // Without a floor the epoch emission is left as is.
+        let mut emission = epoch_emission.clone();
+        SubtensorModule::apply_bootstrap_incentive(netuid, &mut emission);
+        assert_eq!(emission, epoch_emission);
+
+        // The new uid is raised to a quarter of the server emission, rounded down, funded by
+        // the other uids pro-rata. No more is paid out than the epoch emitted.
+        SubtensorModule::set_bootstrap_incentive_floor(netuid, u16::MAX / 4);
+        let mut emission = epoch_emission.clone();
+        SubtensorModule::apply_bootstrap_incentive(netuid, &mut emission);
+        assert_eq!(
+            emission,
+            vec![
+                (U256::from(0), 451, 50),
+                (U256::from(1), 226, 0),
+                (U256::from(2), 76, 0),
+                (U256::from(3), 247, 0),
+            ]
+        );
+
+        // Once the next epoch has run the uid is established.
+        SubtensorModule::set_last_mechanism_step_block(netuid, 13);
+        let mut emission = epoch_emission.clone();
+        SubtensorModule::apply_bootstrap_incentive(netuid, &mut emission);
+        assert_eq!(emission, epoch_emission);
+    });
+}
+
+#[test]
+fn test_emission_split() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        add_network(netuid, 1, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let split = EmissionSplit {
+            owner_bps: 2_000,
+            miners_bps: 2_000,
+            validators_bps: 6_000,
+        };
+
+        assert_err!(
+            SubtensorModule::set_emission_split(
+                RuntimeOrigin::signed(U256::from(11)),
+                netuid,
+                split
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_err!(
+            SubtensorModule::set_emission_split(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                EmissionSplit {
+                    miners_bps: 2_001,
+                    ..split
+                }
+            ),
+            Error::<Test>::InvalidEmissionSplit
+        );
+
+        // Without a split the owner takes the global cut and the epoch emission is untouched.
+        SubtensorModule::set_subnet_owner_cut(u16::MAX / 15);
+        assert_eq!(
+            SubtensorModule::get_subnet_owner_emission_cut(netuid, I96F32::from_num(1_000))
+                .to_num::<u64>(),
+            66
+        );
+        let mut server = vec![fixed(0.25), fixed(0.25)];
+        let mut validator = vec![fixed(0.5), fixed(0.0)];
+        let mut combined = vec![fixed(0.75), fixed(0.25)];
+        SubtensorModule::scale_to_emission_split(
+            SubtensorModule::get_emission_split(netuid),
+            &mut server,
+            &mut validator,
+            &mut combined,
+        );
+        assert_eq!(server, vec![fixed(0.25), fixed(0.25)]);
+        assert_eq!(validator, vec![fixed(0.5), fixed(0.0)]);
+
+        // With one, miners get a quarter and validators three quarters of what the owner leaves.
+        assert_ok!(SubtensorModule::set_emission_split(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            split
+        ));
+        assert_eq!(SubtensorModule::get_emission_split(netuid), Some(split));
+        assert_eq!(
+            SubtensorModule::get_subnet_owner_emission_cut(netuid, I96F32::from_num(1_000))
+                .to_num::<u64>(),
+            200
+        );
+        SubtensorModule::scale_to_emission_split(
+            SubtensorModule::get_emission_split(netuid),
+            &mut server,
+            &mut validator,
+            &mut combined,
+        );
+        assert_eq!(server, vec![fixed(0.125), fixed(0.125)]);
+        assert_eq!(validator, vec![fixed(0.75), fixed(0.0)]);
+        assert_eq!(combined, vec![fixed(0.875), fixed(0.125)]);
+    });
+}
+
+// Test that a validator holding most of the stake loses control of consensus once its share of
+// the active stake is clipped to the subnet's MaxStakeWeightFraction.
+#[test]
+fn test_epoch_max_stake_weight_fraction() {
+    new_test_ext(1).execute_with(|| {
+        let n: u16 = 6;
+        let netuid: u16 = 1;
+        let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+        SubtensorModule::set_max_allowed_validators(netuid, 4);
+        assert_eq!(
+            SubtensorModule::get_max_stake_weight_fraction(netuid),
+            u16::MAX
+        );
+
+        // === Register [whale, validator2, validator3, validator4, server1, server2]
+        for key in 0..n as u64 {
+            register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+        }
+        System::set_block_number(10);
+        for uid in 0..4u16 {
+            let key = U256::from(uid);
+            let stake = if uid == 0 { 7_000 } else { 1_000 };
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&key, &key, stake);
+            SubtensorModule::set_validator_permit_for_uid(netuid, uid, true);
+            // === Set weights [whale->srv1, others->srv2]
+            let server: u16 = if uid == 0 { 4 } else { 5 };
+            Weights::<Test>::insert(netuid, uid, WeightsRow::from(vec![(server, u16::MAX)]));
+            SubtensorModule::set_last_update_for_uid(netuid, uid, 10);
+        }
+
+        // Uncapped, the whale holds 70% of the active stake and decides consensus alone.
+        SubtensorModule::epoch(netuid, None);
+        assert!(SubtensorModule::get_incentive_for_uid(netuid, 4) > 0);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 5), 0);
+
+        // Capped at 40%, the other validators hold the remaining 60% and outvote it.
+        SubtensorModule::set_max_stake_weight_fraction(netuid, 26_214);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::MaxStakeWeightFractionSet(netuid, 26_214),
+        ));
+        SubtensorModule::epoch(netuid, None);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 4), 0);
+        assert!(SubtensorModule::get_incentive_for_uid(netuid, 5) > 0);
+    });
+}
+
+#[test]
+fn test_simulate_epoch_max_stake_weight_fraction() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        let mut params = SubtensorModule::get_epoch_simulation_params(netuid);
+        assert_eq!(params.max_stake_weight_fraction, u16::MAX);
+        params.max_allowed_validators = 4;
+        params.rao_emission = 1_000_000_000;
+
+        // A whale backs uid 4, three smaller validators back uid 5.
+        let stakes: Vec<u64> = vec![700, 100, 100, 100, 0, 0];
+        let weights: Vec<Vec<(u16, u16)>> = vec![
+            vec![(4, u16::MAX)],
+            vec![(5, u16::MAX)],
+            vec![(5, u16::MAX)],
+            vec![(5, u16::MAX)],
+        ];
+
+        let uncapped = SubtensorModule::simulate_epoch(&params, &stakes, &weights);
+        params.max_stake_weight_fraction = u16::MAX / 4;
+        let capped = SubtensorModule::simulate_epoch(&params, &stakes, &weights);
+
+        assert!(uncapped.incentive[4] > 0);
+        assert_eq!(uncapped.incentive[5], 0);
+        assert_eq!(capped.incentive[4], 0);
+        assert!(capped.incentive[5] > 0);
+        assert!(capped.dividends[0] < uncapped.dividends[0]);
+    });
+}
+
+// Test that a validator's weight on its own uid earns it incentive only on subnets that set
+// UNMASKED_SELF_WEIGHTS.
+#[test]
+fn test_epoch_unmasked_self_weights() {
+    new_test_ext(1).execute_with(|| {
+        let n: u16 = 3;
+        let netuid: u16 = 1;
+        let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+        SubtensorModule::set_max_allowed_validators(netuid, 2);
+
+        // === Register [validator1, validator2, server]
+        for key in 0..n as u64 {
+            register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+        }
+        System::set_block_number(10);
+        for uid in 0..2u16 {
+            let key = U256::from(uid);
+            let stake = if uid == 0 { 3_000 } else { 1_000 };
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&key, &key, stake);
+            SubtensorModule::set_validator_permit_for_uid(netuid, uid, true);
+            // === Set weights [validator1->self, validator2->srv]
+            let target: u16 = if uid == 0 { 0 } else { 2 };
+            Weights::<Test>::insert(netuid, uid, WeightsRow::from(vec![(target, u16::MAX)]));
+            SubtensorModule::set_last_update_for_uid(netuid, uid, 10);
+        }
+
+        // By default the self-weight is masked and earns validator1 nothing.
+        SubtensorModule::epoch(netuid, None);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 0), 0);
+
+        // Unmasked, validator1 holds 75% of the stake and reaches consensus on itself.
+        SubtensorModule::set_unmasked_self_weights(netuid, true);
+        SubtensorModule::epoch(netuid, None);
+        assert!(SubtensorModule::get_incentive_for_uid(netuid, 0) > 0);
+    });
+}
@@ -0,0 +1,206 @@
+mod mock;
+use mock::*;
+use pallet_subtensor::{error_codes::ErrorGroup, Error};
+use sp_runtime::DispatchError;
+
+// The codes are part of the public API. Changing an entry of this table breaks SDKs.
+fn frozen_error_codes() -> Vec<(Error<Test>, u16)> {
+    vec![
+        (Error::<Test>::Subnet, 1),
+        (Error::<Test>::Registration, 2),
+        (Error::<Test>::Serving, 3),
+        (Error::<Test>::Staking, 4),
+        (Error::<Test>::Weights, 5),
+        (Error::<Test>::RateLimit, 6),
+        (Error::<Test>::Swap, 7),
+        (Error::<Test>::Root, 8),
+        (Error::<Test>::SubNetworkDoesNotExist, 101),
+        (Error::<Test>::RootNetworkDoesNotExist, 102),
+        (Error::<Test>::NotSubnetOwner, 103),
+        (Error::<Test>::NotRootSubnet, 104),
+        (Error::<Test>::AllNetworksInImmunity, 105),
+        (Error::<Test>::InvalidSubnetFeatureFlags, 106),
+        (Error::<Test>::MaintenanceWindowTooLong, 107),
+        (Error::<Test>::MaintenanceWindowInPast, 108),
+        (Error::<Test>::SubnetOwnerHotkeyNotSet, 109),
+        (Error::<Test>::NetuidNotReserved, 110),
+        (Error::<Test>::NetuidAlreadyInUse, 111),
+        (Error::<Test>::NetuidAuctionEnded, 112),
+        (Error::<Test>::NetuidAuctionNotEnded, 113),
+        (Error::<Test>::NetuidBidTooLow, 114),
+        (Error::<Test>::NotNetuidAuctionWinner, 115),
+        (Error::<Test>::SubnetLimitReached, 116),
+        (Error::<Test>::InvalidMaxAllowedUidsGrowth, 117),
+        (Error::<Test>::NoMaxAllowedUidsGrowth, 118),
+        (Error::<Test>::InvalidSubnetTokenMetadata, 119),
+        (Error::<Test>::SubnetSymbolInUse, 120),
+        (Error::<Test>::InvalidOwnerKeySignature, 121),
+        (Error::<Test>::InvalidEmissionSplit, 122),
+        (Error::<Test>::ReferendumAlreadyOpen, 123),
+        (Error::<Test>::NoOpenReferendum, 124),
+        (Error::<Test>::AlreadyVotedInReferendum, 125),
+        (Error::<Test>::ReferendumValueOutOfBounds, 126),
+        (Error::<Test>::NetuidQuarantined, 127),
+        (Error::<Test>::NotPendingSubnetOwner, 128),
+        (Error::<Test>::SubnetMigrationInProgress, 129),
+        (Error::<Test>::CannotMigrateRootNetwork, 130),
+        (Error::<Test>::TooManyReservedNetuids, 131),
+        (Error::<Test>::HotKeyAlreadyRegisteredInSubNet, 201),
+        (Error::<Test>::TooManyRegistrationsThisBlock, 202),
+        (Error::<Test>::TooManyRegistrationsThisInterval, 203),
+        (Error::<Test>::SubNetRegistrationDisabled, 204),
+        (Error::<Test>::InvalidWorkBlock, 205),
+        (Error::<Test>::InvalidDifficulty, 206),
+        (Error::<Test>::InvalidSeal, 207),
+        (Error::<Test>::RegistrationNotPermittedOnRootSubnet, 208),
+        (Error::<Test>::NoNeuronIdAvailable, 209),
+        (Error::<Test>::FaucetDisabled, 210),
+        (Error::<Test>::TransactorAccountShouldBeHotKey, 211),
+        (Error::<Test>::HotKeyNotRegisteredInSubNet, 212),
+        (Error::<Test>::HotKeyNotRegisteredInNetwork, 213),
+        (Error::<Test>::HotKeyAccountNotExists, 214),
+        (Error::<Test>::TooManyPowRegistrationsThisBlock, 215),
+        (Error::<Test>::TooManyBurnRegistrationsThisBlock, 216),
+        (Error::<Test>::InvalidEvmKeySignature, 217),
+        (Error::<Test>::EvmKeySignatureExpired, 218),
+        (Error::<Test>::NotEnoughStakeToRegister, 219),
+        (Error::<Test>::RegistrationReservedForRole, 220),
+        (Error::<Test>::InvalidIpType, 301),
+        (Error::<Test>::InvalidIpAddress, 302),
+        (Error::<Test>::InvalidPort, 303),
+        (Error::<Test>::InvalidAxonIndex, 304),
+        (Error::<Test>::CommitmentTooLarge, 305),
+        (Error::<Test>::AttestedUidNotFound, 306),
+        (Error::<Test>::InvalidCertificate, 307),
+        (Error::<Test>::InvalidIdentity, 308),
+        (Error::<Test>::NotEnoughBalanceForIdentityDeposit, 309),
+        (Error::<Test>::NonAssociatedColdKey, 401),
+        (Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey, 402),
+        (Error::<Test>::StakeToWithdrawIsZero, 403),
+        (Error::<Test>::NotEnoughStakeToWithdraw, 404),
+        (Error::<Test>::NotEnoughBalanceToStake, 405),
+        (Error::<Test>::BalanceWithdrawalError, 406),
+        (Error::<Test>::ZeroBalanceAfterWithdrawn, 407),
+        (Error::<Test>::HotKeyAlreadyDelegate, 408),
+        (Error::<Test>::NomStakeBelowMinimumThreshold, 409),
+        (Error::<Test>::DelegateTakeTooLow, 410),
+        (Error::<Test>::DelegateTakeTooHigh, 411),
+        (Error::<Test>::FeeReserveNotKept, 412),
+        (Error::<Test>::RecycleAmountIsZero, 413),
+        (Error::<Test>::NotEnoughBalanceToRecycle, 414),
+        (Error::<Test>::HotkeyIsBanned, 415),
+        (Error::<Test>::HotkeyAlreadyBanned, 416),
+        (Error::<Test>::StakeToAddIsZero, 417),
+        (Error::<Test>::TooManyStakeSubscriptions, 418),
+        (Error::<Test>::StakeLockedInReferendum, 419),
+        (Error::<Test>::TooManyChildren, 420),
+        (Error::<Test>::InvalidChild, 421),
+        (Error::<Test>::DuplicateChild, 422),
+        (Error::<Test>::ChildProportionsOverflow, 423),
+        (Error::<Test>::HotKeyNotDelegate, 424),
+        (Error::<Test>::NoStakeOnDelegate, 425),
+        (Error::<Test>::TooManyNominationsToClear, 426),
+        (Error::<Test>::StakeIsLocked, 427),
+        (Error::<Test>::InvalidStakeLockDuration, 428),
+        (Error::<Test>::TooManyPendingWithdrawals, 429),
+        (Error::<Test>::NothingToClaim, 430),
+        (Error::<Test>::StakeBatchTooLarge, 431),
+        (Error::<Test>::HotkeyNotBanned, 432),
+        (Error::<Test>::TooManyParents, 433),
+        (Error::<Test>::NotEnoughStakeToSetWeights, 501),
+        (Error::<Test>::NeuronNoValidatorPermit, 502),
+        (Error::<Test>::WeightVecNotEqualSize, 503),
+        (Error::<Test>::DuplicateUids, 504),
+        (Error::<Test>::UidVecContainInvalidOne, 505),
+        (Error::<Test>::WeightVecLengthIsLow, 506),
+        (Error::<Test>::MaxWeightExceeded, 507),
+        (Error::<Test>::IncorrectWeightVersionKey, 508),
+        (Error::<Test>::UidsLengthExceedUidsInSubNet, 509),
+        (Error::<Test>::CanNotSetRootNetworkWeights, 510),
+        (Error::<Test>::WeightsCommitNotAllowed, 511),
+        (Error::<Test>::NoWeightsCommitFound, 512),
+        (Error::<Test>::InvalidRevealCommitTempo, 513),
+        (Error::<Test>::InvalidRevealCommitHashNotMatch, 514),
+        (Error::<Test>::CommitRevealEnabled, 515),
+        (Error::<Test>::CommitRevealDisabled, 516),
+        (Error::<Test>::LiquidAlphaDisabled, 517),
+        (Error::<Test>::AlphaHighTooLow, 518),
+        (Error::<Test>::AlphaLowOutOfRange, 519),
+        (Error::<Test>::WeightsKeyInUse, 520),
+        (Error::<Test>::WeightsKeyIsRegistered, 521),
+        (Error::<Test>::WeightsPaused, 522),
+        (Error::<Test>::WeightsTargetEpochRequired, 523),
+        (Error::<Test>::InvalidWeightsTargetEpoch, 524),
+        (Error::<Test>::WeightsBatchTooLarge, 525),
+        (Error::<Test>::DegenerateRootWeights, 526),
+        (Error::<Test>::RootWeightsSpreadTooLow, 527),
+        (Error::<Test>::NoWeightSetter, 528),
+        (Error::<Test>::SettingWeightsTooFast, 601),
+        (Error::<Test>::ServingRateLimitExceeded, 602),
+        (Error::<Test>::NetworkTxRateLimitExceeded, 603),
+        (Error::<Test>::DelegateTxRateLimitExceeded, 604),
+        (Error::<Test>::HotKeySetTxRateLimitExceeded, 605),
+        (Error::<Test>::StakeRateLimitExceeded, 606),
+        (Error::<Test>::UnstakeRateLimitExceeded, 607),
+        (Error::<Test>::ColdKeySwapTxRateLimitExceeded, 608),
+        (Error::<Test>::CommitmentRateLimitExceeded, 609),
+        (Error::<Test>::FaucetRateLimitExceeded, 610),
+        (Error::<Test>::TransactionTypeRateLimitExceeded, 611),
+        (Error::<Test>::NewHotKeyIsSameWithOld, 701),
+        (Error::<Test>::NotEnoughBalanceToPaySwapHotKey, 702),
+        (Error::<Test>::ColdKeyAlreadyAssociated, 703),
+        (Error::<Test>::NewColdKeyIsSameWithOld, 704),
+        (Error::<Test>::NotExistColdkey, 705),
+        (Error::<Test>::NotEnoughBalanceToPaySwapColdKey, 706),
+        (Error::<Test>::NoBalanceToTransfer, 707),
+        (Error::<Test>::SameColdkey, 708),
+        (Error::<Test>::ColdkeyIsInArbitration, 709),
+        (Error::<Test>::DuplicateColdkey, 710),
+        (Error::<Test>::ColdkeySwapError, 711),
+        (Error::<Test>::InsufficientBalanceToPerformColdkeySwap, 712),
+        (Error::<Test>::MaxColdkeyDestinationsReached, 713),
+        (Error::<Test>::NoColdkeySwapScheduled, 714),
+        (Error::<Test>::InvalidColdkeySwapDestination, 715),
+        (Error::<Test>::NotSenateMember, 801),
+        (Error::<Test>::StakeTooLowForRoot, 802),
+        (Error::<Test>::CouldNotJoinSenate, 803),
+        (Error::<Test>::CanNotRecycleToRootNetwork, 804),
+        (Error::<Test>::RootValidatorInImmunity, 805),
+    ]
+}
+
+#[test]
+fn test_error_codes_are_frozen() {
+    for (error, code) in frozen_error_codes() {
+        assert_eq!(error.error_code(), code);
+        assert_eq!(
+            Error::<Test>::from_error_code(code).map(DispatchError::from),
+            Some(DispatchError::from(error))
+        );
+    }
+}
+
+#[test]
+fn test_error_groups() {
+    assert_eq!(
+        Error::<Test>::SubNetworkDoesNotExist.error_group(),
+        Some(ErrorGroup::Subnet)
+    );
+    assert_eq!(
+        Error::<Test>::DelegateTakeTooLow.error_group(),
+        Some(ErrorGroup::Staking)
+    );
+    assert_eq!(
+        Error::<Test>::SettingWeightsTooFast.error_group(),
+        Some(ErrorGroup::RateLimit)
+    );
+    assert_eq!(
+        Error::<Test>::from_error_code(0).map(DispatchError::from),
+        None
+    );
+    assert_eq!(
+        Error::<Test>::from_error_code(199).map(DispatchError::from),
+        None
+    );
+    assert_eq!(ErrorGroup::from_error_code(999), None);
+}
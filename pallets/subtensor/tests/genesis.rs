@@ -0,0 +1,120 @@
+#![allow(clippy::unwrap_used)]
+
+use crate::mock::*;
+use pallet_subtensor::GenesisSubnet;
+use sp_core::U256;
+use sp_runtime::BuildStorage;
+
+mod mock;
+
+fn test_ext_with_subtensor_genesis(
+    genesis: pallet_subtensor::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    genesis.assimilate_storage(&mut t).unwrap();
+    t.into()
+}
+
+fn genesis_subnet(netuid: u16, neurons: Vec<(U256, U256)>) -> GenesisSubnet<U256> {
+    GenesisSubnet {
+        netuid,
+        owner: Some(U256::from(100)),
+        tempo: 10,
+        max_allowed_uids: 8,
+        max_allowed_validators: 4,
+        min_allowed_weights: 2,
+        max_weights_limit: 1_000,
+        immunity_period: 20,
+        difficulty: 500,
+        burn: 1_000,
+        weights_set_rate_limit: 5,
+        neurons,
+    }
+}
+
+#[test]
+fn test_genesis_subnets_and_neurons() {
+    let hot1 = U256::from(1);
+    let hot2 = U256::from(2);
+    let cold = U256::from(10);
+    let genesis = pallet_subtensor::GenesisConfig::<Test> {
+        subnets: vec![
+            genesis_subnet(1, vec![(hot1, cold), (hot2, cold)]),
+            genesis_subnet(2, vec![(hot2, cold)]),
+        ],
+        ..Default::default()
+    };
+
+    test_ext_with_subtensor_genesis(genesis).execute_with(|| {
+        // Root, the legacy genesis subnet and the two declared subnets.
+        assert_eq!(SubtensorModule::get_num_subnets(), 4);
+        assert!(SubtensorModule::if_subnet_exist(1));
+        assert!(SubtensorModule::if_subnet_exist(2));
+
+        assert_eq!(SubtensorModule::get_tempo(1), 10);
+        assert_eq!(SubtensorModule::get_max_allowed_uids(1), 8);
+        assert_eq!(SubtensorModule::get_max_allowed_validators(1), 4);
+        assert_eq!(SubtensorModule::get_min_allowed_weights(1), 2);
+        assert_eq!(SubtensorModule::get_max_weight_limit(1), 1_000);
+        assert_eq!(SubtensorModule::get_immunity_period(1), 20);
+        assert_eq!(SubtensorModule::get_difficulty_as_u64(1), 500);
+        assert_eq!(SubtensorModule::get_burn_as_u64(1), 1_000);
+        assert_eq!(SubtensorModule::get_weights_set_rate_limit(1), 5);
+        assert_eq!(
+            pallet_subtensor::SubnetOwner::<Test>::get(1),
+            U256::from(100)
+        );
+
+        assert_eq!(SubtensorModule::get_subnetwork_n(1), 2);
+        assert_eq!(SubtensorModule::get_uid_for_net_and_hotkey(1, &hot1), Ok(0));
+        assert_eq!(SubtensorModule::get_uid_for_net_and_hotkey(1, &hot2), Ok(1));
+        assert_eq!(SubtensorModule::get_subnetwork_n(2), 1);
+        assert_eq!(SubtensorModule::get_uid_for_net_and_hotkey(2, &hot2), Ok(0));
+        assert_eq!(SubtensorModule::get_owning_coldkey_for_hotkey(&hot1), cold);
+        assert_eq!(SubtensorModule::get_owning_coldkey_for_hotkey(&hot2), cold);
+    });
+}
+
+#[test]
+fn test_genesis_stakes_and_delegates() {
+    let hot = U256::from(1);
+    let cold = U256::from(10);
+    let nominator = U256::from(11);
+    let genesis = pallet_subtensor::GenesisConfig::<Test> {
+        balances_issuance: 5_000,
+        subnets: vec![genesis_subnet(1, vec![(hot, cold)])],
+        initial_stakes: vec![(cold, hot, 1_000), (nominator, hot, 500)],
+        delegates: vec![(hot, 11_796)],
+        ..Default::default()
+    };
+
+    test_ext_with_subtensor_genesis(genesis).execute_with(|| {
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&cold, &hot),
+            1_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hot),
+            500
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hot), 1_500);
+        assert_eq!(SubtensorModule::get_total_stake(), 1_500);
+        assert_eq!(SubtensorModule::get_total_issuance(), 6_500);
+
+        assert!(SubtensorModule::hotkey_is_delegate(&hot));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hot), 11_796);
+    });
+}
+
+#[test]
+#[should_panic(expected = "genesis subnet 3 is already added")]
+fn test_genesis_subnet_collision_panics() {
+    let genesis = pallet_subtensor::GenesisConfig::<Test> {
+        subnets: vec![genesis_subnet(3, vec![])],
+        ..Default::default()
+    };
+    test_ext_with_subtensor_genesis(genesis);
+}
@@ -209,6 +209,32 @@ fn test_vec_u16_max_upscale_to_u16() {
     assert_vec_compare_u16(&result, &vector);
 }
 
+#[test]
+fn test_vec_u16_sum_normalize_to_u16() {
+    let vector: Vec<u16> = vec![];
+    let result: Vec<u16> = vec_u16_sum_normalize_to_u16(&vector);
+    assert_vec_compare_u16(&result, &vector);
+    let vector: Vec<u16> = vec![0, 0];
+    let result: Vec<u16> = vec_u16_sum_normalize_to_u16(&vector);
+    assert_vec_compare_u16(&result, &vector);
+    let vector: Vec<u16> = vec![1];
+    let target: Vec<u16> = vec![65535];
+    let result: Vec<u16> = vec_u16_sum_normalize_to_u16(&vector);
+    assert_vec_compare_u16(&result, &target);
+    let vector: Vec<u16> = vec![0, 1, 1];
+    let target: Vec<u16> = vec![0, 32767, 32767];
+    let result: Vec<u16> = vec_u16_sum_normalize_to_u16(&vector);
+    assert_vec_compare_u16(&result, &target);
+    let vector: Vec<u16> = vec![65535, 65535, 65535, 65535];
+    let target: Vec<u16> = vec![16383, 16383, 16383, 16383];
+    let result: Vec<u16> = vec_u16_sum_normalize_to_u16(&vector);
+    assert_vec_compare_u16(&result, &target);
+    let vector: Vec<u16> = vec![1, 2, 3, 4];
+    let target: Vec<u16> = vec![6553, 13107, 19660, 26214];
+    let result: Vec<u16> = vec_u16_sum_normalize_to_u16(&vector);
+    assert_vec_compare_u16(&result, &target);
+}
+
 #[test]
 fn test_check_vec_max_limited() {
     let vector: Vec<u16> = vec![];
@@ -624,6 +650,49 @@ fn test_math_is_topk() {
     assert_eq!(&result, &target);
 }
 
+#[test]
+fn test_math_is_topk_with_tiebreak() {
+    let vector: Vec<I32F32> = vec_to_fixed(&[]);
+    let tiebreak: Vec<u64> = vec![];
+    let result = is_topk_with_tiebreak(&vector, &tiebreak, 5);
+    let target: Vec<bool> = vec![];
+    assert_eq!(&result, &target);
+    let vector: Vec<I32F32> = vec_to_fixed(&[0., 1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+    let tiebreak: Vec<u64> = vec![0; 10];
+    let result = is_topk_with_tiebreak(&vector, &tiebreak, 0);
+    let target: Vec<bool> = vec![false; 10];
+    assert_eq!(&result, &target);
+    let result = is_topk_with_tiebreak(&vector, &tiebreak, 5);
+    let target: Vec<bool> = vec![
+        false, false, false, false, false, true, true, true, true, true,
+    ];
+    assert_eq!(&result, &target);
+    let result = is_topk_with_tiebreak(&vector, &tiebreak, 100);
+    let target: Vec<bool> = vec![true; 10];
+    assert_eq!(&result, &target);
+    // Ties with equal keys keep the lower indices.
+    let vector: Vec<I32F32> = vec_to_fixed(&[0., 1., 2., 3., 4., 5., 5., 5., 5., 6.]);
+    let result = is_topk_with_tiebreak(&vector, &tiebreak, 3);
+    let target: Vec<bool> = vec![
+        false, false, false, false, false, true, true, false, false, true,
+    ];
+    assert_eq!(&result, &target);
+    // Ties are settled by the smaller key first.
+    let tiebreak: Vec<u64> = vec![0, 0, 0, 0, 0, 9, 7, 3, 7, 0];
+    let result = is_topk_with_tiebreak(&vector, &tiebreak, 3);
+    let target: Vec<bool> = vec![
+        false, false, false, false, false, false, true, true, false, true,
+    ];
+    assert_eq!(&result, &target);
+    // A tiebreak of another length is ignored, ties go to the lower index.
+    let tiebreak: Vec<u64> = vec![0, 0, 0, 0, 0, 9, 7];
+    let result = is_topk_with_tiebreak(&vector, &tiebreak, 3);
+    let target: Vec<bool> = vec![
+        false, false, false, false, false, true, true, false, false, true,
+    ];
+    assert_eq!(&result, &target);
+}
+
 #[test]
 fn test_math_sum() {
     assert!(sum(&[]) == I32F32::from_num(0));
@@ -718,6 +787,34 @@ fn test_math_inplace_normalize() {
     );
 }
 
+#[test]
+fn test_math_inplace_cap_normalized() {
+    let epsilon: I32F32 = I32F32::from_num(0.0001);
+    // The excess of the largest value is spread over the others in proportion to them.
+    let mut x: Vec<I32F32> = vec_to_fixed(&[0.7, 0.2, 0.1, 0.0]);
+    inplace_cap_normalized(&mut x, I32F32::from_num(0.5));
+    assert_vec_compare(&x, &vec_to_fixed(&[0.5, 0.3333, 0.1667, 0.0]), epsilon);
+
+    // Spreading the excess can push another value over the cap in turn.
+    let mut x: Vec<I32F32> = vec_to_fixed(&[0.6, 0.3, 0.05, 0.05]);
+    inplace_cap_normalized(&mut x, I32F32::from_num(0.35));
+    assert_vec_compare(&x, &vec_to_fixed(&[0.35, 0.35, 0.15, 0.15]), epsilon);
+
+    // Values under the cap are left as they are.
+    let mut x: Vec<I32F32> = vec_to_fixed(&[0.4, 0.3, 0.3]);
+    inplace_cap_normalized(&mut x, I32F32::from_num(0.5));
+    assert_vec_compare(&x, &vec_to_fixed(&[0.4, 0.3, 0.3]), epsilon);
+
+    // Too few positive values to fit under the cap end up equal.
+    let mut x: Vec<I32F32> = vec_to_fixed(&[0.9, 0.1, 0.0]);
+    inplace_cap_normalized(&mut x, I32F32::from_num(0.25));
+    assert_vec_compare(&x, &vec_to_fixed(&[0.5, 0.5, 0.0]), epsilon);
+
+    let mut x: Vec<I32F32> = vec_to_fixed(&[0.0, 0.0]);
+    inplace_cap_normalized(&mut x, I32F32::from_num(0.25));
+    assert_vec_compare(&x, &vec_to_fixed(&[0.0, 0.0]), epsilon);
+}
+
 #[test]
 fn test_math_inplace_normalize_64() {
     let epsilon: I64F64 = I64F64::from_num(0.0001);
@@ -753,6 +850,45 @@ fn test_math_inplace_normalize_64() {
     );
 }
 
+#[test]
+fn test_math_inplace_cap_normalized_64() {
+    let epsilon: I64F64 = I64F64::from_num(0.0001);
+    let to_64 = |x: &[f64]| -> Vec<I64F64> { x.iter().map(|v| I64F64::from_num(*v)).collect() };
+
+    // A cap of 1 leaves the values as they are.
+    let mut x: Vec<I64F64> = to_64(&[0.9, 0.1]);
+    inplace_cap_normalized_64(&mut x, I64F64::from_num(1));
+    assert_vec_compare_64(&x, &to_64(&[0.9, 0.1]), epsilon);
+
+    // The excess of the capped value is spread over the others in proportion to them.
+    let mut x: Vec<I64F64> = to_64(&[0.7, 0.2, 0.1]);
+    inplace_cap_normalized_64(&mut x, I64F64::from_num(0.5));
+    assert_vec_compare_64(&x, &to_64(&[0.5, 0.333333, 0.166666]), epsilon);
+
+    // Values pushed above the cap by the spread are capped in turn.
+    let mut x: Vec<I64F64> = to_64(&[0.6, 0.3, 0.05, 0.05]);
+    inplace_cap_normalized_64(&mut x, I64F64::from_num(0.4));
+    assert_vec_compare_64(&x, &to_64(&[0.4, 0.4, 0.1, 0.1]), epsilon);
+
+    // Values at the cap are kept.
+    let mut x: Vec<I64F64> = to_64(&[0.5, 0.5]);
+    inplace_cap_normalized_64(&mut x, I64F64::from_num(0.5));
+    assert_vec_compare_64(&x, &to_64(&[0.5, 0.5]), epsilon);
+
+    // A cap that cannot be met leaves the nonzero values equal.
+    let mut x: Vec<I64F64> = to_64(&[0.8, 0.2, 0.0]);
+    inplace_cap_normalized_64(&mut x, I64F64::from_num(0.25));
+    assert_vec_compare_64(&x, &to_64(&[0.5, 0.5, 0.0]), epsilon);
+
+    // Zero and empty vectors are left as they are.
+    let mut x: Vec<I64F64> = to_64(&[0.0, 0.0]);
+    inplace_cap_normalized_64(&mut x, I64F64::from_num(0.5));
+    assert_vec_compare_64(&x, &to_64(&[0.0, 0.0]), epsilon);
+    let mut x: Vec<I64F64> = vec![];
+    inplace_cap_normalized_64(&mut x, I64F64::from_num(0.5));
+    assert!(x.is_empty());
+}
+
 #[test]
 fn test_math_vecdiv() {
     let x: Vec<I32F32> = vec_to_fixed(&[]);
@@ -812,6 +948,34 @@ fn test_math_inplace_row_normalize_sparse() {
     );
 }
 
+#[test]
+fn test_math_inplace_row_normalize_with_floor() {
+    let epsilon: I32F32 = I32F32::from_num(0.0001);
+    let vector: Vec<f32> = vec![0., 1., 2., 3., 4., 0., 10., 5., 0., 0., 0., 0.];
+    let mut mat = vec_to_mat_fixed(&vector, 3, false);
+    inplace_row_normalize_with_floor(&mut mat, I32F32::from_num(10));
+    // Rows summing to less than the floor keep their shortfall.
+    let target: Vec<f32> = vec![0., 0.1, 0.2, 0.3, 0.2, 0., 0.5, 0.25, 0., 0., 0., 0.];
+    assert_mat_compare(&mat, &vec_to_mat_fixed(&target, 3, false), epsilon);
+
+    let mut mat = vec_to_sparse_mat_fixed(&vector, 3, false);
+    inplace_row_normalize_sparse_with_floor(&mut mat, I32F32::from_num(10));
+    assert_sparse_mat_compare(&mat, &vec_to_sparse_mat_fixed(&target, 3, false), epsilon);
+
+    let mut mat: Vec<Vec<(u16, I64F64)>> = vec![
+        vec![(0, I64F64::from_num(1)), (1, I64F64::from_num(4))],
+        vec![(0, I64F64::from_num(30))],
+    ];
+    inplace_row_normalize_sparse_64_with_floor(&mut mat, I64F64::from_num(10));
+    assert_eq!(
+        mat,
+        vec![
+            vec![(0, I64F64::from_num(0.1)), (1, I64F64::from_num(0.4))],
+            vec![(0, I64F64::from_num(1))],
+        ]
+    );
+}
+
 #[test]
 fn test_math_inplace_col_normalize() {
     let epsilon: I32F32 = I32F32::from_num(0.0001);
@@ -1281,6 +1445,67 @@ fn test_math_sparse_matmul() {
     assert_vec_compare(&result, &target, I32F32::from_num(0));
 }
 
+fn sparse_mat_fixed32_to_fixed64(mat: &[Vec<(u16, I32F32)>]) -> Vec<Vec<(u16, I64F64)>> {
+    mat.iter()
+        .map(|row| {
+            row.iter()
+                .map(|(j, value)| (*j, fixed32_to_fixed64(*value)))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_math_sparse_matmul_64() {
+    let vector: Vec<I64F64> = vec_fixed32_to_fixed64(vec_to_fixed(&[1., 2., 3., 4.]));
+    let matrix: Vec<f32> = vec![0., 2., 3., 4., 0., 6., 7., 8., 0., 10., 11., 12.];
+    let sparse = sparse_mat_fixed32_to_fixed64(&vec_to_sparse_mat_fixed(&matrix, 4, false));
+    let result = matmul_sparse_64(&sparse, &vector, 3);
+    let target: Vec<I64F64> = vec_fixed32_to_fixed64(vec_to_fixed(&[69., 70., 63.]));
+    assert_vec_compare_64(&result, &target, I64F64::from_num(0));
+    // Matches the dense product.
+    let dense = vec_fixed32_to_fixed64(vec_to_fixed(&matrix))
+        .chunks(3)
+        .map(|row| row.to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(result, matmul_64(&dense, &vector));
+    // Columns outside of the result are ignored.
+    let result = matmul_sparse_64(&sparse, &vector, 2);
+    let target: Vec<I64F64> = vec_fixed32_to_fixed64(vec_to_fixed(&[69., 70.]));
+    assert_vec_compare_64(&result, &target, I64F64::from_num(0));
+    let empty: Vec<Vec<(u16, I64F64)>> = vec![vec![]; 4];
+    let result = matmul_sparse_64(&empty, &vector, 3);
+    assert_vec_compare_64(&result, &[I64F64::from_num(0); 3], I64F64::from_num(0));
+}
+
+#[test]
+fn test_math_inplace_row_normalize_sparse_64() {
+    let vector: Vec<f32> = vec![
+        0., 1., 0., 2., 0., 3., 4., 0., 1., 0., 2., 0., 3., 0., 0., 0., 0., 0., 0., 0., 0.,
+    ];
+    let dense: Vec<Vec<I64F64>> = vec_fixed32_to_fixed64(vec_to_fixed(&vector))
+        .chunks(7)
+        .map(|row| row.to_vec())
+        .collect();
+    let mut sparse = sparse_mat_fixed32_to_fixed64(&vec_to_sparse_mat_fixed(&vector, 3, false));
+    inplace_row_normalize_sparse_64(&mut sparse);
+    let mut normalized = dense.clone();
+    inplace_row_normalize_64(&mut normalized);
+    for (sparse_row, dense_row) in sparse.iter().zip(&normalized) {
+        let mut row = vec![I64F64::from_num(0); dense_row.len()];
+        for (j, value) in sparse_row {
+            row[*j as usize] = *value;
+        }
+        assert_eq!(&row, dense_row);
+    }
+    assert_float_compare_64(
+        sparse[0][0].1,
+        I64F64::from_num(0.1),
+        I64F64::from_num(0.0001),
+    );
+    assert!(sparse[2].is_empty());
+}
+
 #[test]
 fn test_math_sparse_matmul_transpose() {
     let vector: Vec<I32F32> = vec_to_fixed(&[1., 2., 3.]);
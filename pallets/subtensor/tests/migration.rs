@@ -1,7 +1,7 @@
 #![allow(clippy::unwrap_used)]
 
 mod mock;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, weights::Weight};
 use frame_system::Config;
 use mock::*;
 use sp_core::U256;
@@ -276,3 +276,279 @@ fn test_migration_delete_subnet_21() {
         assert!(!SubtensorModule::if_subnet_exist(21));
     })
 }
+
+#[test]
+fn test_migration_populate_delegate_indexes() {
+    new_test_ext(1).execute_with(|| {
+        use frame_support::traits::StorageVersion;
+
+        let owner = U256::from(1);
+        let nominator = U256::from(2);
+        let hotkey = U256::from(100);
+
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 500);
+
+        // Wipe the indexes to simulate a chain which predates them.
+        let _ = pallet_subtensor::DelegateNominatorCount::<Test>::clear(u32::MAX, None);
+        let _ = pallet_subtensor::DelegateTotalStake::<Test>::clear(u32::MAX, None);
+        assert_eq!(SubtensorModule::get_delegate_nominator_count(&hotkey), 0);
+        assert_eq!(
+            SubtensorModule::get_total_hotkey_delegated_stake(&hotkey),
+            0
+        );
+
+        StorageVersion::new(7).put::<pallet_subtensor::Pallet<Test>>();
+        pallet_subtensor::migration::migrate_populate_delegate_indexes::<Test>();
+
+        assert_eq!(SubtensorModule::get_delegate_nominator_count(&hotkey), 2);
+        assert_eq!(
+            SubtensorModule::get_total_hotkey_delegated_stake(&hotkey),
+            500
+        );
+        assert_eq!(
+            StorageVersion::get::<pallet_subtensor::Pallet<Test>>(),
+            StorageVersion::new(8)
+        );
+
+        // The migration runs once, leaving indexes drifted since then to the stake paths.
+        let _ = pallet_subtensor::DelegateNominatorCount::<Test>::clear(u32::MAX, None);
+        pallet_subtensor::migration::migrate_populate_delegate_indexes::<Test>();
+        assert_eq!(SubtensorModule::get_delegate_nominator_count(&hotkey), 0);
+    });
+}
+
+#[test]
+fn test_migration_subnet_feature_flags() {
+    new_test_ext(1).execute_with(|| {
+        use pallet_subtensor::migration::deprecated_subnet_feature_booleans as old;
+        use pallet_subtensor::subnet_features;
+
+        old::CommitRevealWeightsEnabled::<Test>::insert(1, true);
+        old::CommitRevealWeightsEnabled::<Test>::insert(2, false);
+        old::LiquidAlphaOn::<Test>::insert(2, true);
+        old::LiquidAlphaOn::<Test>::insert(3, true);
+
+        pallet_subtensor::migration::migrate_subnet_feature_flags::<Test>();
+
+        assert_eq!(
+            SubtensorModule::get_subnet_feature_flags(1),
+            subnet_features::COMMIT_REVEAL_WEIGHTS
+        );
+        assert_eq!(
+            SubtensorModule::get_subnet_feature_flags(2),
+            subnet_features::LIQUID_ALPHA
+        );
+        assert_eq!(
+            SubtensorModule::get_subnet_feature_flags(3),
+            subnet_features::LIQUID_ALPHA
+        );
+        assert!(old::CommitRevealWeightsEnabled::<Test>::iter()
+            .next()
+            .is_none());
+        assert!(old::LiquidAlphaOn::<Test>::iter().next().is_none());
+
+        // Running again is a no-op.
+        assert_eq!(
+            pallet_subtensor::migration::migrate_subnet_feature_flags::<Test>(),
+            frame_support::weights::Weight::zero()
+        );
+    });
+}
+
+#[test]
+fn test_migration_root_max_uids() {
+    new_test_ext(1).execute_with(|| {
+        pallet_subtensor::migration::migrate_create_root_network::<Test>();
+        let root_netuid = SubtensorModule::get_root_netuid();
+
+        // Simulate a chain whose root network predates RootMaxUids.
+        pallet_subtensor::RootMaxUids::<Test>::kill();
+        SubtensorModule::set_max_allowed_uids(root_netuid, 48);
+
+        pallet_subtensor::migration::migrate_root_max_uids::<Test>();
+        assert_eq!(SubtensorModule::get_max_root_validators(), 48);
+
+        // Once seeded, subnet uid settings no longer affect the root network.
+        SubtensorModule::set_max_allowed_uids(root_netuid, 2);
+        pallet_subtensor::migration::migrate_root_max_uids::<Test>();
+        assert_eq!(SubtensorModule::get_max_root_validators(), 48);
+    });
+}
+
+#[test]
+fn test_migration_clamp_delegate_takes() {
+    new_test_ext(1).execute_with(|| {
+        let below = U256::from(1);
+        let within = U256::from(2);
+        let above = U256::from(3);
+        let min_take = SubtensorModule::get_min_delegate_take();
+        let max_take = SubtensorModule::get_max_delegate_take();
+
+        SubtensorModule::delegate_hotkey(&below, 0);
+        SubtensorModule::delegate_hotkey(&within, min_take.saturating_add(1));
+        SubtensorModule::delegate_hotkey(&above, u16::MAX);
+
+        pallet_subtensor::migration::migrate_clamp_delegate_takes::<Test>();
+
+        assert_eq!(SubtensorModule::get_hotkey_take(&below), min_take);
+        assert_eq!(
+            SubtensorModule::get_hotkey_take(&within),
+            min_take.saturating_add(1)
+        );
+        assert_eq!(SubtensorModule::get_hotkey_take(&above), max_take);
+    });
+}
+
+#[test]
+fn test_migration_populate_axon_ip_index() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let ip: u128 = 1676056785;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 0);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            netuid,
+            2,
+            ip,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+
+        // Wipe the index to simulate a chain which predates it.
+        let _ = pallet_subtensor::AxonIpIndex::<Test>::clear(u32::MAX, None);
+        assert!(SubtensorModule::get_neurons_by_ip(ip).is_empty());
+
+        pallet_subtensor::migration::migrate_populate_axon_ip_index::<Test>();
+
+        assert_eq!(SubtensorModule::get_neurons_by_ip(ip), vec![(netuid, 0)]);
+    });
+}
+
+#[test]
+fn test_migration_init_max_miner_uids() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        pallet_subtensor::migration::migrate_create_root_network::<Test>();
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 100);
+        SubtensorModule::set_max_allowed_validators(netuid, 30);
+
+        // Wipe the caps to simulate a chain which predates them.
+        let _ = pallet_subtensor::MaxMinerUids::<Test>::clear(u32::MAX, None);
+        assert_eq!(SubtensorModule::get_max_miner_uids(netuid), u16::MAX);
+
+        pallet_subtensor::migration::migrate_init_max_miner_uids::<Test>();
+
+        assert_eq!(SubtensorModule::get_max_miner_uids(netuid), 70);
+        assert!(!pallet_subtensor::MaxMinerUids::<Test>::contains_key(
+            SubtensorModule::get_root_netuid()
+        ));
+
+        // Caps set afterwards are kept.
+        SubtensorModule::set_max_miner_uids(netuid, 10);
+        pallet_subtensor::migration::migrate_init_max_miner_uids::<Test>();
+        assert_eq!(SubtensorModule::get_max_miner_uids(netuid), 10);
+    });
+}
+
+#[test]
+fn test_migration_populate_subnet_netuids() {
+    new_test_ext(1).execute_with(|| {
+        pallet_subtensor::migration::migrate_create_root_network::<Test>();
+        add_network(7, 13, 0);
+        add_network(2, 13, 0);
+
+        // Wipe the list to simulate a chain which predates it.
+        pallet_subtensor::SubnetNetuids::<Test>::kill();
+        assert!(SubtensorModule::get_all_subnet_netuids().is_empty());
+
+        pallet_subtensor::migration::migrate_populate_subnet_netuids::<Test>();
+
+        assert_eq!(SubtensorModule::get_all_subnet_netuids(), vec![0, 2, 7]);
+    });
+}
+
+#[test]
+fn test_migration_page_staking_hotkeys() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey1 = U256::from(1);
+        let coldkey2 = U256::from(2);
+        let hotkeys: Vec<U256> = (100..170).map(U256::from).collect();
+
+        // Fill the unpaged map to simulate a chain which predates the pages.
+        pallet_subtensor::StakingHotkeys::<Test>::insert(coldkey1, hotkeys.clone());
+        pallet_subtensor::StakingHotkeys::<Test>::insert(coldkey2, vec![U256::from(200)]);
+        assert_eq!(SubtensorModule::get_all_staked_hotkeys(&coldkey1), hotkeys);
+
+        // Nothing is paged without weight to spare.
+        assert_eq!(
+            pallet_subtensor::migration::migrate_page_staking_hotkeys::<Test>(Weight::zero()),
+            Weight::zero()
+        );
+        assert_eq!(SubtensorModule::get_staking_hotkey_page_count(&coldkey1), 0);
+
+        pallet_subtensor::migration::migrate_page_staking_hotkeys::<Test>(Weight::MAX);
+
+        assert!(pallet_subtensor::StakingHotkeys::<Test>::iter()
+            .next()
+            .is_none());
+        assert_eq!(SubtensorModule::get_staking_hotkey_page_count(&coldkey1), 2);
+        assert_eq!(SubtensorModule::get_all_staked_hotkeys(&coldkey1), hotkeys);
+        assert_eq!(
+            SubtensorModule::get_all_staked_hotkeys(&coldkey2),
+            vec![U256::from(200)]
+        );
+    });
+}
+
+#[test]
+fn test_migration_compress_weights() {
+    new_test_ext(1).execute_with(|| {
+        use codec::Encode;
+        use frame_support::traits::StorageVersion;
+        use pallet_subtensor::migration::deprecated_weights_format as old;
+
+        let dense: Vec<(u16, u16)> = (0..4096).map(|uid| (uid, uid.wrapping_mul(7))).collect();
+        let unsorted: Vec<(u16, u16)> = vec![(9, 1), (2, u16::MAX), (4000, 3)];
+        old::Weights::<Test>::insert(1, 0, dense.clone());
+        old::Weights::<Test>::insert(1, 1, unsorted.clone());
+        old::Weights::<Test>::insert(1, 2, Vec::<(u16, u16)>::new());
+        // A row cut short in the old format.
+        frame_support::storage::unhashed::put_raw(
+            &old::Weights::<Test>::hashed_key_for(1, 3),
+            &[8, 1, 0],
+        );
+        StorageVersion::new(6).put::<pallet_subtensor::Pallet<Test>>();
+
+        pallet_subtensor::migration::migrate_compress_weights::<Test>();
+
+        let row = pallet_subtensor::Weights::<Test>::get(1, 0);
+        assert_eq!(row.entries(), dense);
+        assert!(row.encode().len() < dense.encode().len() / 3 * 2);
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(1, 1).entries(),
+            unsorted
+        );
+        assert!(pallet_subtensor::Weights::<Test>::get(1, 2).is_empty());
+        // The undecodable row is dropped instead of being read as a compressed one.
+        assert!(!pallet_subtensor::Weights::<Test>::contains_key(1, 3));
+        assert_eq!(
+            StorageVersion::get::<pallet_subtensor::Pallet<Test>>(),
+            StorageVersion::new(7)
+        );
+
+        // Running again leaves the compressed rows alone.
+        pallet_subtensor::migration::migrate_compress_weights::<Test>();
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(1, 0).entries(),
+            dense
+        );
+    });
+}
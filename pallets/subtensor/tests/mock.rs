@@ -17,6 +17,7 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
+use std::cell::RefCell;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -169,6 +170,17 @@ parameter_types! {
     pub const InitialAlphaLow: u16 = 45875; // Represents 0.7 as per the production default
     pub const InitialLiquidAlphaOn: bool = false; // Default value for LiquidAlphaOn
     pub const SubtensorInitialBaseDifficulty: u64 = 10_000; // Base difficulty
+    pub const InitialRootTempo: u16 = 100;
+    pub const InitialRootMaxUids: u16 = 64;
+    pub const NewNetworkTempo: u16 = 360;
+    pub const NewNetworkMaxAllowedUids: u16 = 256;
+    pub const NewNetworkMaxAllowedValidators: u16 = 64;
+    pub const NewNetworkMinAllowedWeights: u16 = 1;
+    pub const NewNetworkAdjustmentInterval: u16 = 360;
+    pub const NewNetworkTargetRegistrationsPerInterval: u16 = 1;
+    pub const NewNetworkAdjustmentAlpha: u64 = 17_893_341_751_498_265_066; // 0.97
+    pub const NewNetworkImmunityPeriod: u16 = 5000;
+    pub const NewNetworkMinBurn: u64 = 1;
 }
 
 // Configure collective pallet for council
@@ -326,6 +338,81 @@ impl pallet_membership::Config<SenateMembership> for Test {
     type WeightInfo = pallet_membership::weights::SubstrateWeight<Test>;
 }
 
+/// A signature in tests: the signing account and the payload it signed.
+#[derive(codec::Encode, codec::Decode, scale_info::TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct TestKeySignature(pub U256, pub Vec<u8>);
+
+/// The signer of a `TestKeySignature`.
+pub struct TestKeySigner(pub U256);
+
+impl sp_runtime::traits::IdentifyAccount for TestKeySigner {
+    type AccountId = U256;
+    fn into_account(self) -> U256 {
+        self.0
+    }
+}
+
+impl sp_runtime::traits::Verify for TestKeySignature {
+    type Signer = TestKeySigner;
+    fn verify<L: sp_runtime::traits::Lazy<[u8]>>(&self, mut msg: L, signer: &U256) -> bool {
+        self.0 == *signer && msg.get() == self.1.as_slice()
+    }
+}
+
+/// A call of a subtensor hook, recorded by `RecordSubtensorEvents`.
+#[allow(dead_code)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SubtensorHookCall {
+    NeuronRegistered(u16, u16, U256),
+    StakeChanged(U256, U256, u64),
+    EpochCompleted(u16, u64),
+    NetworkAdded(u16),
+    NetworkRemoved(u16),
+    NetworkMigrated(u16, u16),
+}
+
+thread_local! {
+    static SUBTENSOR_HOOK_CALLS: RefCell<Vec<SubtensorHookCall>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Takes the subtensor hook calls recorded so far on this thread.
+#[allow(dead_code)]
+pub fn take_subtensor_hook_calls() -> Vec<SubtensorHookCall> {
+    SUBTENSOR_HOOK_CALLS.with(|calls| calls.take())
+}
+
+pub struct RecordSubtensorEvents;
+impl RecordSubtensorEvents {
+    fn record(call: SubtensorHookCall) {
+        SUBTENSOR_HOOK_CALLS.with(|calls| calls.borrow_mut().push(call));
+    }
+}
+impl pallet_subtensor::OnSubtensorEvent<AccountId> for RecordSubtensorEvents {
+    fn on_neuron_registered(netuid: u16, uid: u16, hotkey: &AccountId) {
+        Self::record(SubtensorHookCall::NeuronRegistered(netuid, uid, *hotkey));
+    }
+
+    fn on_stake_changed(hotkey: &AccountId, coldkey: &AccountId, stake: u64) {
+        Self::record(SubtensorHookCall::StakeChanged(*hotkey, *coldkey, stake));
+    }
+
+    fn on_epoch_completed(netuid: u16, block_number: u64) {
+        Self::record(SubtensorHookCall::EpochCompleted(netuid, block_number));
+    }
+
+    fn on_network_added(netuid: u16) {
+        Self::record(SubtensorHookCall::NetworkAdded(netuid));
+    }
+
+    fn on_network_removed(netuid: u16) {
+        Self::record(SubtensorHookCall::NetworkRemoved(netuid));
+    }
+
+    fn on_network_migrated(old_netuid: u16, new_netuid: u16) {
+        Self::record(SubtensorHookCall::NetworkMigrated(old_netuid, new_netuid));
+    }
+}
+
 impl pallet_subtensor::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -334,6 +421,10 @@ impl pallet_subtensor::Config for Test {
     type CouncilOrigin = frame_system::EnsureSigned<AccountId>;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type OnSubtensorEvent = (RecordSubtensorEvents, ());
+    type KeySignature = TestKeySignature;
+    type KeySigner = TestKeySigner;
+    type WeightInfo = ();
 
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -380,6 +471,17 @@ impl pallet_subtensor::Config for Test {
     type AlphaLow = InitialAlphaLow;
     type LiquidAlphaOn = InitialLiquidAlphaOn;
     type InitialBaseDifficulty = SubtensorInitialBaseDifficulty;
+    type InitialRootTempo = InitialRootTempo;
+    type InitialRootMaxUids = InitialRootMaxUids;
+    type NewNetworkTempo = NewNetworkTempo;
+    type NewNetworkMaxAllowedUids = NewNetworkMaxAllowedUids;
+    type NewNetworkMaxAllowedValidators = NewNetworkMaxAllowedValidators;
+    type NewNetworkMinAllowedWeights = NewNetworkMinAllowedWeights;
+    type NewNetworkAdjustmentInterval = NewNetworkAdjustmentInterval;
+    type NewNetworkTargetRegistrationsPerInterval = NewNetworkTargetRegistrationsPerInterval;
+    type NewNetworkAdjustmentAlpha = NewNetworkAdjustmentAlpha;
+    type NewNetworkImmunityPeriod = NewNetworkImmunityPeriod;
+    type NewNetworkMinBurn = NewNetworkMinBurn;
 }
 
 impl pallet_utility::Config for Test {
@@ -2,6 +2,7 @@ mod mock;
 use mock::*;
 
 use sp_core::U256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 
 #[test]
 fn test_get_neuron_none() {
@@ -71,3 +72,141 @@ fn test_get_neurons_empty() {
         assert_eq!(neurons.len(), neuron_count as usize);
     });
 }
+
+fn decode_sparse_rows(
+    rows: Vec<pallet_subtensor::subnet_info::SparseMatrixRow>,
+) -> Vec<(u16, Vec<(u16, u16)>)> {
+    rows.into_iter()
+        .map(|(uid, row)| (uid.0, row.into_iter().map(|(j, v)| (j.0, v.0)).collect()))
+        .collect()
+}
+
+#[test]
+fn test_get_subnet_weights_and_bonds_sparse() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        assert!(SubtensorModule::get_subnet_weights_sparse(netuid).is_empty());
+        assert!(SubtensorModule::get_subnet_bonds_sparse(netuid + 1).is_empty());
+
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            2,
+            pallet_subtensor::WeightsRow::from(vec![(0, 10), (1, 0)]),
+        );
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            0,
+            pallet_subtensor::WeightsRow::from(vec![(1, u16::MAX)]),
+        );
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            1,
+            pallet_subtensor::WeightsRow::from(vec![(0, 0)]),
+        );
+        pallet_subtensor::Bonds::<Test>::insert(netuid, 1, vec![(2, 7)]);
+
+        // Rows are ordered by uid, zero entries and empty rows are dropped.
+        assert_eq!(
+            decode_sparse_rows(SubtensorModule::get_subnet_weights_sparse(netuid)),
+            vec![(0, vec![(1, u16::MAX)]), (2, vec![(0, 10)])]
+        );
+        assert_eq!(
+            decode_sparse_rows(SubtensorModule::get_subnet_bonds_sparse(netuid)),
+            vec![(1, vec![(2, 7)])]
+        );
+    });
+}
+
+#[test]
+fn test_dump_subnet_state() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey0 = U256::from(0);
+        let coldkey0 = U256::from(10);
+        let hotkey1 = U256::from(1);
+        let coldkey1 = U256::from(11);
+        assert!(SubtensorModule::dump_subnet_state(netuid).is_none());
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 39420842);
+        register_ok_neuron(netuid, hotkey1, coldkey1, 12412392);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey1, &hotkey1, 1_000);
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            1,
+            pallet_subtensor::WeightsRow::from(vec![(0, u16::MAX)]),
+        );
+
+        let state = SubtensorModule::dump_subnet_state(netuid).expect("subnet exists");
+        let empty_row_hash = BlakeTwo256::hash_of(&Vec::<(u16, u16)>::new());
+        assert_eq!(state.netuid, netuid.into());
+        assert_eq!(state.subnetwork_n, 2.into());
+        assert_eq!(state.hotkeys, vec![hotkey0, hotkey1]);
+        assert_eq!(state.coldkeys, vec![coldkey0, coldkey1]);
+        assert_eq!(state.stakes, vec![0.into(), 1_000.into()]);
+        assert_eq!(
+            state.weights_hashes,
+            vec![
+                empty_row_hash,
+                BlakeTwo256::hash_of(&vec![(0u16, u16::MAX)])
+            ]
+        );
+        assert_eq!(state.bonds_hashes, vec![empty_row_hash; 2]);
+    });
+}
+
+#[test]
+fn test_get_hotkey_stake_totals() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let owner = U256::from(10);
+        let nominator = U256::from(11);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner, 39420842);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 300);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 700);
+
+        assert_eq!(
+            SubtensorModule::get_hotkey_stake_totals(&hotkey),
+            (1_000, 300, 700)
+        );
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 200);
+        assert_eq!(
+            SubtensorModule::get_hotkey_stake_totals(&hotkey),
+            (800, 300, 500)
+        );
+        assert_eq!(SubtensorModule::get_neurons_lite(netuid).len(), 1);
+    });
+}
+
+#[test]
+fn test_get_neurons_paginated() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 2, 0);
+        for uid in 0..5u64 {
+            SubtensorModule::append_neuron(netuid, &U256::from(uid), 0);
+        }
+        let neurons = SubtensorModule::get_neurons(netuid);
+        let neurons_lite = SubtensorModule::get_neurons_lite(netuid);
+
+        assert_eq!(
+            SubtensorModule::get_neurons_paginated(netuid, 0, 2),
+            neurons[0..2]
+        );
+        assert_eq!(
+            SubtensorModule::get_neurons_lite_paginated(netuid, 2, 2),
+            neurons_lite[2..4]
+        );
+
+        // The last page is short, and pages past the last uid are empty.
+        assert_eq!(
+            SubtensorModule::get_neurons_paginated(netuid, 4, u16::MAX),
+            neurons[4..]
+        );
+        assert!(SubtensorModule::get_neurons_lite_paginated(netuid, 5, 2).is_empty());
+        assert!(SubtensorModule::get_neurons_paginated(netuid + 1, 0, 2).is_empty());
+    });
+}
@@ -0,0 +1,134 @@
+#![allow(clippy::unwrap_used)]
+
+mod mock;
+use codec::{Compact, Encode};
+use frame_support::assert_ok;
+use frame_system::Config;
+use mock::*;
+use pallet_subtensor::rate_limit_info::{RateLimitInfo, RateLimitedAction};
+use sp_core::U256;
+
+fn find(
+    rate_limits: &[RateLimitInfo],
+    action: RateLimitedAction,
+    netuid: Option<u16>,
+) -> (u64, u64) {
+    let info = rate_limits
+        .iter()
+        .find(|info| info.action == action && info.netuid.map(|n| n.0) == netuid)
+        .unwrap();
+    (info.last_block.0, info.blocks_remaining.0)
+}
+
+#[test]
+fn test_get_rate_limits_invalid_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        assert!(SubtensorModule::get_rate_limits(vec![0u8; 31]).is_empty());
+        // An unknown hotkey has nothing to report.
+        assert!(SubtensorModule::get_rate_limits(U256::from(1).encode()).is_empty());
+    });
+}
+
+#[test]
+fn test_get_rate_limits() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+
+        SubtensorModule::set_weights_set_rate_limit(netuid, 10);
+        SubtensorModule::set_last_update_for_uid(netuid, uid, 5);
+        SubtensorModule::set_serving_rate_limit(netuid, 50);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            netuid,
+            2,
+            1676056785,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        SubtensorModule::set_stake_interval(20);
+        SubtensorModule::set_target_stakes_per_interval(2);
+        SubtensorModule::set_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey, 2, 8);
+        SubtensorModule::set_tx_delegate_take_rate_limit(3);
+        SubtensorModule::set_last_tx_block_delegate_take(&coldkey, 9);
+        SubtensorModule::set_tx_rate_limit(0);
+        SubtensorModule::set_last_tx_block(&coldkey, 9);
+
+        run_to_block(10);
+        let rate_limits = SubtensorModule::get_rate_limits(hotkey.encode());
+
+        // Weights were set at block 5, so they can be set again at block 15.
+        assert_eq!(
+            find(&rate_limits, RateLimitedAction::SetWeights, Some(netuid)),
+            (5, 5)
+        );
+        // The axon was served at block 1, so it can be served again at block 51.
+        assert_eq!(
+            find(&rate_limits, RateLimitedAction::ServeAxon, Some(netuid)),
+            (1, 41)
+        );
+        // Both stakes of the interval are used up until block 28.
+        assert_eq!(find(&rate_limits, RateLimitedAction::Stake, None), (8, 18));
+        // The take limit rejects block 12 itself, so the next take is at block 13.
+        assert_eq!(find(&rate_limits, RateLimitedAction::SetTake, None), (9, 3));
+        // No transaction rate limit is configured.
+        assert_eq!(
+            find(&rate_limits, RateLimitedAction::Transaction, None),
+            (9, 0)
+        );
+
+        // Every limit has passed once enough blocks have gone by.
+        run_to_block(60);
+        let rate_limits = SubtensorModule::get_rate_limits(hotkey.encode());
+        assert!(rate_limits
+            .iter()
+            .all(|info| info.blocks_remaining == Compact(0)));
+    });
+}
+
+#[test]
+fn test_get_next_weight_set_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        add_network(netuid, 13, 0);
+        assert_eq!(
+            SubtensorModule::get_next_weight_set_block(netuid, hotkey.encode()),
+            None
+        );
+
+        register_ok_neuron(netuid, hotkey, U256::from(2), 0);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        SubtensorModule::set_weights_set_rate_limit(netuid, 10);
+        run_to_block(10);
+
+        // Registering at block 1 counts as the last update.
+        assert_eq!(
+            SubtensorModule::get_next_weight_set_block(netuid, hotkey.encode()),
+            Some(11)
+        );
+
+        SubtensorModule::set_last_update_for_uid(netuid, uid, 5);
+        let next_block = SubtensorModule::get_next_weight_set_block(netuid, hotkey.encode());
+        assert_eq!(next_block, Some(15));
+        assert!(!SubtensorModule::check_rate_limit(netuid, uid, 14));
+        assert!(SubtensorModule::check_rate_limit(netuid, uid, 15));
+
+        run_to_block(20);
+        assert_eq!(
+            SubtensorModule::get_next_weight_set_block(netuid, hotkey.encode()),
+            Some(20)
+        );
+        assert_eq!(
+            SubtensorModule::get_next_weight_set_block(netuid, vec![0u8; 31]),
+            None
+        );
+    });
+}
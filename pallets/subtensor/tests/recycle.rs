@@ -0,0 +1,86 @@
+#![allow(clippy::unwrap_used)]
+
+mod mock;
+use frame_support::{assert_err, assert_ok};
+use frame_system::Config;
+use mock::*;
+use pallet_subtensor::Error;
+use sp_core::U256;
+
+#[test]
+fn test_recycle_into_emission_pool() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        add_network(0, 1, 0);
+        add_network(netuid, 13, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        SubtensorModule::set_total_issuance(1_000_000);
+
+        assert_err!(
+            SubtensorModule::recycle(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                0,
+                netuid
+            ),
+            Error::<Test>::RecycleAmountIsZero
+        );
+        assert_err!(
+            SubtensorModule::recycle(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                2_000_000,
+                netuid
+            ),
+            Error::<Test>::NotEnoughBalanceToRecycle
+        );
+        assert_err!(
+            SubtensorModule::recycle(<<Test as Config>::RuntimeOrigin>::signed(coldkey), 1_000, 5),
+            Error::<Test>::SubNetworkDoesNotExist
+        );
+        assert_err!(
+            SubtensorModule::recycle(<<Test as Config>::RuntimeOrigin>::signed(coldkey), 1_000, 0),
+            Error::<Test>::CanNotRecycleToRootNetwork
+        );
+
+        assert_ok!(SubtensorModule::recycle(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            400_000,
+            netuid
+        ));
+        // The tokens leave the issuance until the subnet's epoch emits them.
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 600_000);
+        assert_eq!(SubtensorModule::get_total_issuance(), 600_000);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 400_000);
+        assert_eq!(
+            SubtensorModule::get_rao_recycled_into_emission(netuid),
+            400_000
+        );
+
+        let info = SubtensorModule::get_subnet_recycle_info(netuid).unwrap();
+        assert_eq!(info.recycled_for_registration.0, 0);
+        assert_eq!(info.recycled_into_emission.0, 400_000);
+        assert_eq!(info.pending_emission.0, 400_000);
+        assert!(SubtensorModule::get_subnet_recycle_info(5).is_none());
+    });
+}
+
+#[test]
+fn test_burn() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        SubtensorModule::set_total_issuance(1_000_000);
+
+        assert_err!(
+            SubtensorModule::burn(<<Test as Config>::RuntimeOrigin>::signed(coldkey), 0),
+            Error::<Test>::RecycleAmountIsZero
+        );
+        assert_ok!(SubtensorModule::burn(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            100_000
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 900_000);
+        assert_eq!(SubtensorModule::get_total_issuance(), 900_000);
+        assert_eq!(SubtensorModule::get_total_rao_burned(), 100_000);
+    });
+}
@@ -7,7 +7,7 @@ use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays
 use frame_support::sp_runtime::{transaction_validity::InvalidTransaction, DispatchError};
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::Config;
-use pallet_subtensor::{AxonInfoOf, Error, SubtensorSignedExtension};
+use pallet_subtensor::{AxonInfoOf, Error, NeuronRole, SubtensorSignedExtension};
 use sp_core::U256;
 use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
 
@@ -198,6 +198,7 @@ fn test_registration_under_limit() {
         let hotkey_account_id: U256 = U256::from(1);
         let coldkey_account_id = U256::from(667);
         let who: <Test as frame_system::Config>::AccountId = hotkey_account_id;
+        add_network(netuid, 13, 0);
 
         let max_registrants = 2;
         SubtensorModule::set_target_registrations_per_interval(netuid, max_registrants);
@@ -225,7 +226,6 @@ fn test_registration_under_limit() {
         assert_ok!(result);
 
         //actually call register
-        add_network(netuid, 13, 0);
         assert_ok!(SubtensorModule::register(
             <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
             netuid,
@@ -250,6 +250,7 @@ fn test_registration_rate_limit_exceeded() {
         let hotkey_account_id: U256 = U256::from(1);
         let coldkey_account_id = U256::from(667);
         let who: <Test as frame_system::Config>::AccountId = hotkey_account_id;
+        add_network(netuid, 13, 0);
 
         let target_registrants = 1;
         let max_registrants = target_registrants * 3;
@@ -339,6 +340,7 @@ fn test_burned_registration_rate_limit_exceeded() {
         let hotkey_account_id: U256 = U256::from(1);
         let coldkey_account_id = U256::from(667);
         let who: <Test as frame_system::Config>::AccountId = coldkey_account_id;
+        add_network(netuid, 13, 0);
 
         let target_registrants = 1;
         let max_registrants = target_registrants * 3; // Maximum is 3 times the target
@@ -735,6 +737,155 @@ fn test_registration_too_many_registrations_per_block() {
     });
 }
 
+#[test]
+fn test_registration_limits_per_block_across_routes() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let block_number: u64 = 0;
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_max_registrations_per_block(netuid, 3);
+        SubtensorModule::set_max_pow_registrations_per_block(netuid, 1);
+        for coldkey in 10..16 {
+            SubtensorModule::add_balance_to_coldkey_account(&U256::from(coldkey), 1_000_000);
+        }
+        let pow_register = |hotkey: u64, seed: u64| {
+            let (nonce, work): (u64, Vec<u8>) = SubtensorModule::create_work_for_block_number(
+                netuid,
+                block_number,
+                seed,
+                &U256::from(hotkey),
+            );
+            SubtensorModule::register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(hotkey)),
+                netuid,
+                block_number,
+                nonce,
+                work,
+                U256::from(hotkey),
+                U256::from(hotkey + 10),
+            )
+        };
+        let burned_register = |hotkey: u64| {
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(hotkey + 10)),
+                netuid,
+                U256::from(hotkey),
+            )
+        };
+
+        // One PoW registration fills the PoW sub-limit.
+        assert_ok!(pow_register(0, 3942084));
+        assert_eq!(
+            pow_register(1, 11231312312),
+            Err(Error::<Test>::TooManyPowRegistrationsThisBlock.into())
+        );
+
+        // Burned registrations fill the rest of the total limit.
+        assert_ok!(burned_register(2));
+        assert_ok!(burned_register(3));
+        assert_eq!(
+            burned_register(4),
+            Err(Error::<Test>::TooManyRegistrationsThisBlock.into())
+        );
+        assert_eq!(SubtensorModule::get_registrations_this_block(netuid), 3);
+        assert_eq!(SubtensorModule::get_pow_registrations_this_block(netuid), 1);
+        assert_eq!(
+            SubtensorModule::get_burn_registrations_this_block(netuid),
+            2
+        );
+
+        // The counters drain at the next block, and the burned sub-limit applies as well.
+        step_block(1);
+        SubtensorModule::set_max_burn_registrations_per_block(netuid, 1);
+        assert_ok!(burned_register(4));
+        assert_eq!(
+            burned_register(5),
+            Err(Error::<Test>::TooManyBurnRegistrationsThisBlock.into())
+        );
+        assert_ok!(pow_register(1, 11231312312));
+        assert_eq!(SubtensorModule::get_registrations_this_block(netuid), 2);
+    });
+}
+
+#[test]
+fn test_registration_prioritizes_short_role() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_max_registrations_per_block(netuid, 3);
+        for coldkey in 10..16 {
+            SubtensorModule::add_balance_to_coldkey_account(&U256::from(coldkey), 1_000_000);
+        }
+        let register = |hotkey: u64, role: Option<NeuronRole>| match role {
+            Some(role) => SubtensorModule::burned_register_with_role(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(hotkey + 10)),
+                netuid,
+                U256::from(hotkey),
+                role,
+            ),
+            None => SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(hotkey + 10)),
+                netuid,
+                U256::from(hotkey),
+            ),
+        };
+        // Short of both roles, nothing is prioritized.
+        assert_eq!(SubtensorModule::get_short_role(netuid), None);
+        assert_eq!(
+            SubtensorSignedExtension::<Test>::get_priority_registration(netuid, None),
+            SubtensorSignedExtension::<Test>::get_priority_vanilla()
+        );
+
+        // With the miner uids taken, the subnet is short of validators.
+        SubtensorModule::set_max_miner_uids(netuid, 2);
+        assert_ok!(register(0, None));
+        assert_ok!(register(1, Some(NeuronRole::Miner)));
+        assert_eq!(
+            SubtensorModule::get_short_role(netuid),
+            Some(NeuronRole::Validator)
+        );
+        assert!(
+            SubtensorSignedExtension::<Test>::get_priority_registration(netuid, None)
+                < SubtensorSignedExtension::<Test>::get_priority_registration(
+                    netuid,
+                    Some(NeuronRole::Validator)
+                )
+        );
+
+        // The last registration of the block is left to a validator.
+        assert_eq!(
+            register(2, None),
+            Err(Error::<Test>::RegistrationReservedForRole.into())
+        );
+        assert_eq!(
+            register(3, Some(NeuronRole::Miner)),
+            Err(Error::<Test>::RegistrationReservedForRole.into())
+        );
+        assert_ok!(register(4, Some(NeuronRole::Validator)));
+        assert_eq!(
+            pallet_subtensor::DeclaredRoles::<Test>::get(netuid, U256::from(4)),
+            Some(NeuronRole::Validator)
+        );
+        assert_eq!(
+            pallet_subtensor::DeclaredRoles::<Test>::get(netuid, U256::from(0)),
+            None
+        );
+        assert_eq!(
+            register(5, Some(NeuronRole::Validator)),
+            Err(Error::<Test>::TooManyRegistrationsThisBlock.into())
+        );
+
+        // The first registrations of a block are open to any role.
+        step_block(1);
+        assert_ok!(register(2, None));
+    });
+}
+
 #[test]
 fn test_registration_too_many_registrations_per_interval() {
     new_test_ext(1).execute_with(|| {
@@ -2018,3 +2169,438 @@ fn test_hotkey_swap_registered_key() {
         );
     });
 }
+
+#[test]
+fn test_registration_event_indexed_by_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(1, 13, 0);
+        add_network(2, 13, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+
+        let record = System::events()
+            .into_iter()
+            .find(|record| {
+                record.event
+                    == RuntimeEvent::SubtensorModule(pallet_subtensor::Event::NeuronRegistered(
+                        1, 0, hotkey,
+                    ))
+            })
+            .unwrap();
+        assert_eq!(record.topics, vec![SubtensorModule::subnet_event_topic(1)]);
+        assert_ne!(
+            SubtensorModule::subnet_event_topic(1),
+            SubtensorModule::subnet_event_topic(2)
+        );
+    });
+}
+
+#[test]
+fn test_warn_imminent_pruning() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 2);
+        SubtensorModule::set_immunity_period(netuid, 15);
+        SubtensorModule::append_neuron(netuid, &U256::from(1), 0);
+        SubtensorModule::append_neuron(netuid, &U256::from(2), 8);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 100);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 50);
+
+        // Both uids are immune now, uid 0 is not a tempo from now.
+        assert_eq!(SubtensorModule::get_pruning_candidate(netuid, 9), Some(1));
+        assert_eq!(SubtensorModule::get_pruning_candidate(netuid, 19), Some(0));
+
+        System::set_block_number(9);
+        SubtensorModule::warn_imminent_pruning(netuid, 9);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::PruningImminent(netuid, 0),
+        ));
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 1);
+
+        // No warning while registrations do not prune.
+        System::reset_events();
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::warn_imminent_pruning(netuid, 9);
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn test_registration_max_miner_uids() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_max_miner_uids(netuid, 1);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+
+        // The miner cap is reached, so the second registration replaces the first miner.
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 0);
+        register_ok_neuron(netuid, U256::from(2), U256::from(2), 39420842);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 1);
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(2)),
+            Ok(0)
+        );
+
+        // A permitted validator does not count as a miner.
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        assert_eq!(SubtensorModule::get_miner_count(netuid), 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(3), 12312312);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+
+        // The validator keeps its uid when the next miner is pruned.
+        register_ok_neuron(netuid, U256::from(4), U256::from(4), 56565656);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(2)),
+            Ok(0)
+        );
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(4)),
+            Ok(1)
+        );
+
+        SubtensorModule::set_max_miner_uids(netuid, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&U256::from(5), 10000);
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(5)),
+                netuid,
+                U256::from(5)
+            ),
+            Error::<Test>::NoNeuronIdAvailable
+        );
+    });
+}
+
+#[test]
+fn test_registration_requires_min_stake() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let block_number: u64 = 0;
+        let pow_hotkey = U256::from(1);
+        let burn_hotkey = U256::from(2);
+        let coldkey = U256::from(667);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+        SubtensorModule::set_min_stake_to_register(netuid, 500);
+
+        // Neither route registers a hotkey without the stake.
+        let (nonce, work): (u64, Vec<u8>) = SubtensorModule::create_work_for_block_number(
+            netuid,
+            block_number,
+            129123813,
+            &pow_hotkey,
+        );
+        assert_noop!(
+            SubtensorModule::register(
+                <<Test as Config>::RuntimeOrigin>::signed(pow_hotkey),
+                netuid,
+                block_number,
+                nonce,
+                work.clone(),
+                pow_hotkey,
+                coldkey
+            ),
+            Error::<Test>::NotEnoughStakeToRegister
+        );
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &burn_hotkey, 499);
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                burn_hotkey
+            ),
+            Error::<Test>::NotEnoughStakeToRegister
+        );
+
+        // Both register once the hotkey holds the minimum.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &pow_hotkey, 500);
+        assert_ok!(SubtensorModule::register(
+            <<Test as Config>::RuntimeOrigin>::signed(pow_hotkey),
+            netuid,
+            block_number,
+            nonce,
+            work,
+            pow_hotkey,
+            coldkey
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &burn_hotkey, 1);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            burn_hotkey
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+    });
+}
+
+#[test]
+fn test_pow_stats_track_difficulty_solved() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_difficulty(netuid, 10_000);
+        SubtensorModule::set_adjustment_interval(netuid, 3);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        assert_eq!(SubtensorModule::get_pow_stats(netuid + 1), None);
+
+        register_ok_neuron(netuid, U256::from(1), coldkey, 39420842);
+        register_ok_neuron(netuid, U256::from(2), coldkey, 12412392);
+        let stats = SubtensorModule::get_pow_stats(netuid).unwrap();
+        assert_eq!(stats.total_difficulty_solved, 20_000.into());
+        assert_eq!(stats.difficulty_solved_this_interval, 20_000.into());
+        assert_eq!(stats.pow_registrations_this_interval, 2.into());
+        assert!(stats.interval_averages.is_empty());
+
+        // Closing the interval keeps the total and records the average per registration.
+        run_to_block(3);
+        let stats = SubtensorModule::get_pow_stats(netuid).unwrap();
+        assert_eq!(stats.total_difficulty_solved, 20_000.into());
+        assert_eq!(stats.difficulty_solved_this_interval, 0.into());
+        assert_eq!(stats.interval_averages, vec![10_000.into()]);
+
+        // Intervals without PoW registrations average to zero.
+        run_to_block(6);
+        let stats = SubtensorModule::get_pow_stats(netuid).unwrap();
+        assert_eq!(stats.interval_averages, vec![10_000.into(), 0.into()]);
+    });
+}
+
+#[test]
+fn test_persistent_uids() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+        let (hotkey1, hotkey2, hotkey3) = (U256::from(1), U256::from(2), U256::from(3));
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 2);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 100);
+        SubtensorModule::set_max_burn_registrations_per_block(netuid, 100);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 100);
+        SubtensorModule::set_persistent_uids(netuid, true);
+        let register = |hotkey: U256| {
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey
+            ));
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap()
+        };
+
+        assert_eq!(register(hotkey1), 0);
+        assert_eq!(register(hotkey2), 1);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 0);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 10);
+        assert_eq!(register(hotkey3), 0);
+        assert_eq!(
+            pallet_subtensor::RetiredUids::<Test>::get(netuid, hotkey1),
+            Some(0)
+        );
+
+        // The neuron in uid 0 earns nothing past its immunity, so hotkey1 reclaims it rather
+        // than pruning the lowest score.
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 0);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 0);
+        assert_eq!(register(hotkey1), 0);
+        assert_eq!(
+            pallet_subtensor::RetiredUids::<Test>::get(netuid, hotkey1),
+            None
+        );
+        assert_eq!(
+            pallet_subtensor::RetiredUids::<Test>::get(netuid, hotkey3),
+            Some(0)
+        );
+
+        // The slot is not free while its neuron earns, so hotkey3 takes the pruned uid.
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 5);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 0);
+        assert_eq!(register(hotkey3), 1);
+        assert_eq!(
+            pallet_subtensor::RetiredUids::<Test>::get(netuid, hotkey3),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_unregister() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let burn_cost: u64 = 1000;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(667);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, burn_cost);
+        SubtensorModule::set_unregister_refund(10, u16::MAX / 2);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+        register_ok_neuron(netuid, U256::from(2), U256::from(12), 100000);
+        register_ok_neuron(netuid, U256::from(3), U256::from(13), 200000);
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            1,
+            pallet_subtensor::WeightsRow::from(vec![(0, 1), (1, 2), (2, 3)]),
+        );
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            2,
+            pallet_subtensor::WeightsRow::from(vec![(0, 4), (2, 5)]),
+        );
+        assert_ok!(SubtensorModule::set_weights_key(
+            netuid,
+            &hotkey,
+            &U256::from(77)
+        ));
+
+        // The weight scales with the neurons whose weights and bonds rows are rewritten.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::unregister { netuid, hotkey });
+        assert_eq!(
+            call.get_dispatch_info().weight,
+            SubtensorModule::unregister_weight(netuid)
+        );
+        assert!(SubtensorModule::unregister_weight(netuid)
+            .all_gt(SubtensorModule::unregister_weight(netuid + 1)));
+
+        assert_noop!(
+            SubtensorModule::unregister(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(12)),
+                netuid,
+                hotkey
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_noop!(
+            SubtensorModule::unregister(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                2,
+                hotkey
+            ),
+            Error::<Test>::SubNetworkDoesNotExist
+        );
+
+        // The last uid moves into the freed slot, and half the burn is refunded.
+        assert_ok!(SubtensorModule::unregister(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert!(!SubtensorModule::is_hotkey_registered_on_network(
+            netuid, &hotkey
+        ));
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(3)),
+            Ok(0)
+        );
+        assert_eq!(
+            SubtensorModule::get_hotkey_for_net_and_uid(netuid, 0),
+            Ok(U256::from(3))
+        );
+        assert!(!SubtensorModule::is_uid_exist_on_network(netuid, 2));
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, 0).entries(),
+            vec![(0, 5)]
+        );
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, 1).entries(),
+            vec![(0, 3), (1, 2)]
+        );
+        assert!(pallet_subtensor::Weights::<Test>::get(netuid, 2).is_empty());
+        assert_eq!(SubtensorModule::get_active(netuid).len(), 2);
+        assert!(pallet_subtensor::WeightsKeys::<Test>::get(netuid, hotkey).is_none());
+        assert!(pallet_subtensor::WeightsKeyHotkey::<Test>::get(netuid, U256::from(77)).is_none());
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            10000 - burn_cost + 499
+        );
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NeuronUnregistered(netuid, 0, hotkey, 499),
+        ));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NeuronUidMoved(netuid, 2, 0),
+        ));
+
+        // The last uid leaves without moving another neuron, and no burn is refunded.
+        assert_ok!(SubtensorModule::unregister(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(12)),
+            netuid,
+            U256::from(2)
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 1);
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, 0).entries(),
+            vec![(0, 5)]
+        );
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NeuronUnregistered(netuid, 1, U256::from(2), 0),
+        ));
+
+        assert_noop!(
+            SubtensorModule::unregister(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(12)),
+                netuid,
+                U256::from(2)
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+    });
+}
+
+#[test]
+fn test_registration_lottery() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(667);
+        let newcomer = U256::from(10);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 100);
+        SubtensorModule::set_max_burn_registrations_per_block(netuid, 100);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 100);
+        for key in 0..3u64 {
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(key)
+            ));
+            SubtensorModule::set_pruning_score_for_uid(netuid, key as u16, 10);
+        }
+        assert_eq!(SubtensorModule::draw_neuron_to_prune(netuid), None);
+
+        // Every neuron is immune, so the lottery has nothing to draw from.
+        SubtensorModule::set_registration_lottery(netuid, true);
+        SubtensorModule::set_immunity_period(netuid, 100);
+        assert_eq!(SubtensorModule::draw_neuron_to_prune(netuid), None);
+
+        // Past immunity the newcomer replaces the drawn neuron rather than the lowest score.
+        SubtensorModule::set_immunity_period(netuid, 0);
+        let drawn = SubtensorModule::draw_neuron_to_prune(netuid).unwrap();
+        assert!(drawn < 3);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            newcomer
+        ));
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &newcomer),
+            Ok(drawn)
+        );
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 3);
+    });
+}
@@ -1,12 +1,15 @@
 #![allow(clippy::indexing_slicing, clippy::unwrap_used)]
 
 use crate::mock::*;
+use codec::Encode;
 use frame_support::{assert_err, assert_ok};
 use frame_system::Config;
 use frame_system::{EventRecord, Phase};
 use pallet_subtensor::migration;
-use pallet_subtensor::Error;
+use pallet_subtensor::storage_stats::StorageStats;
+use pallet_subtensor::{Error, NetUid, MAX_RESERVED_NETUIDS, NETUID_CLAIM_PERIOD};
 use sp_core::{Get, H256, U256};
+use sp_runtime::DispatchError;
 
 mod mock;
 
@@ -19,6 +22,10 @@ fn record(event: RuntimeEvent) -> EventRecord<RuntimeEvent, H256> {
     }
 }
 
+fn netuids(dests: Vec<u16>) -> Vec<NetUid> {
+    dests.into_iter().map(NetUid::from).collect()
+}
+
 #[test]
 fn test_root_register_network_exist() {
     new_test_ext(1).execute_with(|| {
@@ -49,9 +56,9 @@ fn test_set_weights_not_root_error() {
         assert_err!(
             SubtensorModule::set_root_weights(
                 RuntimeOrigin::signed(coldkey),
-                netuid,
+                NetUid::from(netuid),
                 hotkey,
-                dests.clone(),
+                netuids(dests.clone()),
                 weights.clone(),
                 version_key,
             ),
@@ -60,6 +67,47 @@ fn test_set_weights_not_root_error() {
     });
 }
 
+#[test]
+fn test_set_root_weights_below_min_stake() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(1, 0, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000);
+        assert_ok!(SubtensorModule::root_register(
+            RuntimeOrigin::signed(coldkey),
+            hotkey
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+
+        SubtensorModule::set_weights_min_stake(1_001);
+        assert_eq!(SubtensorModule::get_weights_min_stake(), 1_001);
+        assert_err!(
+            SubtensorModule::set_root_weights(
+                RuntimeOrigin::signed(coldkey),
+                NetUid::from(root_netuid),
+                hotkey,
+                netuids(vec![1]),
+                vec![1],
+                0,
+            ),
+            Error::<Test>::NotEnoughStakeToSetWeights
+        );
+
+        SubtensorModule::set_weights_min_stake(1_000);
+        assert_ok!(SubtensorModule::set_root_weights(
+            RuntimeOrigin::signed(coldkey),
+            NetUid::from(root_netuid),
+            hotkey,
+            netuids(vec![1]),
+            vec![1],
+            0,
+        ));
+    });
+}
+
 #[test]
 fn test_root_register_normal_on_root_fails() {
     new_test_ext(1).execute_with(|| {
@@ -202,7 +250,7 @@ fn test_root_set_weights() {
         let root_netuid: u16 = 0;
         SubtensorModule::set_max_registrations_per_block(root_netuid, n as u16);
         SubtensorModule::set_target_registrations_per_interval(root_netuid, n as u16);
-        SubtensorModule::set_max_allowed_uids(root_netuid, n as u16);
+        SubtensorModule::set_max_root_validators(n as u16);
         for i in 0..n {
             let hotkey_account_id: U256 = U256::from(i);
             let coldkey_account_id: U256 = U256::from(i + 456);
@@ -243,9 +291,9 @@ fn test_root_set_weights() {
             assert_err!(
                 SubtensorModule::set_root_weights(
                     <<Test as Config>::RuntimeOrigin>::signed(hotkey),
-                    root_netuid,
+                    NetUid::from(root_netuid),
                     hotkey,
-                    uids,
+                    netuids(uids),
                     values,
                     0,
                 ),
@@ -262,9 +310,9 @@ fn test_root_set_weights() {
             assert_err!(
                 SubtensorModule::set_root_weights(
                     <<Test as Config>::RuntimeOrigin>::signed(unassociated_coldkey),
-                    root_netuid,
+                    NetUid::from(root_netuid),
                     hotkey,
-                    uids,
+                    netuids(uids),
                     values,
                     0,
                 ),
@@ -280,9 +328,9 @@ fn test_root_set_weights() {
             let values: Vec<u16> = vec![1];
             assert_ok!(SubtensorModule::set_root_weights(
                 <<Test as Config>::RuntimeOrigin>::signed(coldkey),
-                root_netuid,
+                NetUid::from(root_netuid),
                 hotkey,
-                uids,
+                netuids(uids),
                 values,
                 0,
             ));
@@ -334,6 +382,370 @@ fn test_root_set_weights() {
     });
 }
 
+#[test]
+fn test_weight_setter() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+
+        let root_netuid: u16 = 0;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let operator = U256::from(3);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000_000);
+        assert_ok!(SubtensorModule::root_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+        ));
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            1000
+        ));
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+        ));
+        let set_root_weights = |signer: U256| {
+            SubtensorModule::set_root_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(signer),
+                NetUid::from(root_netuid),
+                hotkey,
+                netuids(vec![1]),
+                vec![1],
+                0,
+            )
+        };
+
+        assert_err!(
+            set_root_weights(operator),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // Only a root validator authorizes a weight setter, and only on the root network.
+        assert_err!(
+            SubtensorModule::authorize_weight_setter(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(4)),
+                root_netuid,
+                operator
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+        assert_err!(
+            SubtensorModule::authorize_weight_setter(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                1,
+                operator
+            ),
+            Error::<Test>::NotRootSubnet
+        );
+
+        assert_ok!(SubtensorModule::authorize_weight_setter(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            root_netuid,
+            operator
+        ));
+        // The weight setter is the weights key of the hotkey on the root network.
+        assert_eq!(
+            pallet_subtensor::WeightsKeys::<Test>::get(root_netuid, hotkey),
+            Some(operator)
+        );
+        assert_eq!(
+            pallet_subtensor::WeightsKeyHotkey::<Test>::get(root_netuid, operator),
+            Some(hotkey)
+        );
+        assert_ok!(set_root_weights(operator));
+        let weights: Vec<u16> = SubtensorModule::get_root_weights_dense().weights[0]
+            .iter()
+            .map(|weight| weight.0)
+            .collect();
+        assert_eq!(weights, vec![0, u16::MAX]);
+
+        assert_ok!(SubtensorModule::revoke_weight_setter(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            root_netuid
+        ));
+        assert_err!(
+            set_root_weights(operator),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_err!(
+            SubtensorModule::revoke_weight_setter(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                root_netuid
+            ),
+            Error::<Test>::NoWeightSetter
+        );
+        assert!(pallet_subtensor::WeightsKeyHotkey::<Test>::get(root_netuid, operator).is_none());
+
+        // A weights key authorized with authorize_weights_key sets the root weights too.
+        assert_ok!(SubtensorModule::authorize_weights_key(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            root_netuid,
+            operator
+        ));
+        assert_ok!(set_root_weights(operator));
+    });
+}
+
+#[test]
+fn test_simulate_root_epoch() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+        assert!(SubtensorModule::simulate_root_epoch().is_empty());
+
+        let n: usize = 2;
+        let root_netuid: u16 = 0;
+        SubtensorModule::set_max_registrations_per_block(root_netuid, n as u16);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, n as u16);
+        SubtensorModule::set_max_root_validators(n as u16);
+        for i in 0..n {
+            let hotkey_account_id: U256 = U256::from(i);
+            let coldkey_account_id: U256 = U256::from(i + 456);
+            SubtensorModule::add_balance_to_coldkey_account(
+                &coldkey_account_id,
+                1_000_000_000_000_000,
+            );
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id,
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id,
+                1000 * (i as u64 + 1)
+            ));
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id)
+            ));
+        }
+        for i in 0..n {
+            assert_ok!(SubtensorModule::set_root_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(i + 456)),
+                NetUid::from(root_netuid),
+                U256::from(i),
+                netuids(vec![i as u16 + 1]),
+                vec![1],
+                0,
+            ));
+        }
+        SubtensorModule::set_emission_multiplier(2, 20_000);
+
+        // The simulation leaves the emission values as they are.
+        let projected = SubtensorModule::simulate_root_epoch();
+        assert_eq!(projected.len(), n + 1);
+        assert!(projected.iter().all(|subnet| subnet.emission.0 == 0));
+        assert!(projected
+            .iter()
+            .all(|subnet| SubtensorModule::get_subnet_emission_value(subnet.netuid.0) == 0));
+
+        // The root epoch sets the projected values, multipliers included.
+        let tempo = SubtensorModule::get_tempo(root_netuid);
+        let block = (1..=u64::from(tempo) + 1)
+            .find(|block| SubtensorModule::blocks_until_next_epoch(root_netuid, tempo, *block) == 0)
+            .unwrap();
+        assert_ok!(SubtensorModule::root_epoch(block));
+        SubtensorModule::apply_emission_multipliers();
+        for subnet in projected.iter() {
+            assert_eq!(
+                subnet.projected_emission.0,
+                SubtensorModule::get_subnet_emission_value(subnet.netuid.0)
+            );
+        }
+        assert!(projected[2].projected_emission.0 > projected[1].projected_emission.0);
+    });
+}
+
+#[test]
+fn test_simulate_root_weight_change() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+
+        let n: usize = 2;
+        let root_netuid: u16 = 0;
+        SubtensorModule::set_max_registrations_per_block(root_netuid, n as u16);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, n as u16);
+        SubtensorModule::set_max_root_validators(n as u16);
+        for i in 0..n {
+            let hotkey_account_id: U256 = U256::from(i);
+            let coldkey_account_id: U256 = U256::from(i + 456);
+            SubtensorModule::add_balance_to_coldkey_account(
+                &coldkey_account_id,
+                1_000_000_000_000_000,
+            );
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id,
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id,
+                1000
+            ));
+        }
+        for netuid in 1..=n {
+            SubtensorModule::add_balance_to_coldkey_account(
+                &U256::from(netuid + 456),
+                1_000_000_000_000_000,
+            );
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(netuid + 456))
+            ));
+        }
+
+        // Each validator weights its own subnet.
+        for i in 0..n {
+            assert_ok!(SubtensorModule::set_root_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(i + 456)),
+                NetUid::from(root_netuid),
+                U256::from(i),
+                netuids(vec![i as u16 + 1]),
+                vec![1],
+                0,
+            ));
+        }
+        let weights_before = SubtensorModule::get_root_weights();
+
+        // Moving the weight of validator 0 to subnet 2 moves all of subnet 1's emission.
+        let deltas =
+            SubtensorModule::simulate_root_weight_change(U256::from(0).encode(), vec![2], vec![1]);
+        assert_eq!(deltas.len(), 3);
+        let subnet_1 = deltas.iter().find(|d| d.netuid.0 == 1).unwrap();
+        let subnet_2 = deltas.iter().find(|d| d.netuid.0 == 2).unwrap();
+        assert!(subnet_1.emission.0 > 0);
+        assert_eq!(subnet_1.simulated_emission.0, 0);
+        assert_eq!(subnet_1.delta, -(subnet_1.emission.0 as i64));
+        assert!(subnet_2.delta > 0);
+        assert_eq!(
+            subnet_2.delta,
+            subnet_2.simulated_emission.0 as i64 - subnet_2.emission.0 as i64
+        );
+
+        // Unchanged weights have no effect.
+        assert!(SubtensorModule::simulate_root_weight_change(
+            U256::from(0).encode(),
+            vec![1],
+            vec![1]
+        )
+        .iter()
+        .all(|d| d.delta == 0));
+
+        // Weights that would be rejected and hotkeys off the root network return nothing.
+        assert!(SubtensorModule::simulate_root_weight_change(
+            U256::from(0).encode(),
+            vec![1, 2],
+            vec![1]
+        )
+        .is_empty());
+        assert!(SubtensorModule::simulate_root_weight_change(
+            U256::from(0).encode(),
+            vec![9],
+            vec![1]
+        )
+        .is_empty());
+        assert!(SubtensorModule::simulate_root_weight_change(
+            U256::from(42).encode(),
+            vec![1],
+            vec![1]
+        )
+        .is_empty());
+
+        // Nothing was written.
+        assert_eq!(SubtensorModule::get_root_weights(), weights_before);
+    });
+}
+
+#[test]
+fn test_get_root_weights_and_emission_info() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+
+        let n: usize = 2;
+        let root_netuid: u16 = 0;
+        SubtensorModule::set_max_registrations_per_block(root_netuid, n as u16);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, n as u16);
+        SubtensorModule::set_max_root_validators(n as u16);
+        for i in 0..n {
+            let coldkey_account_id: U256 = U256::from(i + 456);
+            SubtensorModule::add_balance_to_coldkey_account(
+                &coldkey_account_id,
+                1_000_000_000_000_000,
+            );
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                U256::from(i),
+            ));
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id)
+            ));
+        }
+
+        // Validator 0 weights subnet 1, validator 1 both subnets.
+        assert_ok!(SubtensorModule::set_root_weights(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(456)),
+            NetUid::from(root_netuid),
+            U256::from(0),
+            netuids(vec![1]),
+            vec![1],
+            0,
+        ));
+        assert_ok!(SubtensorModule::set_root_weights(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(457)),
+            NetUid::from(root_netuid),
+            U256::from(1),
+            netuids(vec![1, 2]),
+            vec![1, 1],
+            0,
+        ));
+        let root_weights = SubtensorModule::get_root_weights_dense();
+        let netuids: Vec<u16> = root_weights.netuids.iter().map(|netuid| netuid.0).collect();
+        assert_eq!(netuids, vec![0, 1, 2]);
+        let weights: Vec<Vec<u16>> = root_weights
+            .weights
+            .iter()
+            .map(|row| row.iter().map(|weight| weight.0).collect())
+            .collect();
+        assert_eq!(
+            weights,
+            vec![vec![0, u16::MAX, 0], vec![0, u16::MAX, u16::MAX]]
+        );
+
+        SubtensorModule::set_emission_values(&[1, 2], vec![3, 7]).unwrap();
+        let info = SubtensorModule::get_root_emission_info();
+        assert_eq!(info.subnets.len(), 3);
+        for subnet in info.subnets.iter() {
+            let netuid = subnet.netuid.0;
+            assert_eq!(
+                subnet.emission_value.0,
+                SubtensorModule::get_subnet_emission_value(netuid)
+            );
+            assert_eq!(
+                subnet.locked.0,
+                SubtensorModule::get_subnet_locked_balance(netuid)
+            );
+        }
+        assert_eq!(info.subnets[2].emission_value.0, 7);
+        assert!(info.subnets[1].locked.0 > 0);
+
+        // The root epoch starts after exactly the blocks reported.
+        let blocks = info.blocks_until_next_root_epoch.0;
+        let tempo = SubtensorModule::get_tempo(root_netuid);
+        assert!(blocks > 0);
+        for ahead in 1..blocks {
+            assert_ne!(
+                SubtensorModule::blocks_until_next_epoch(root_netuid, tempo, ahead),
+                0
+            );
+        }
+        assert_eq!(
+            SubtensorModule::blocks_until_next_epoch(root_netuid, tempo, blocks),
+            0
+        );
+    });
+}
+
 #[test]
 fn test_root_set_weights_out_of_order_netuids() {
     new_test_ext(1).execute_with(|| {
@@ -344,7 +756,7 @@ fn test_root_set_weights_out_of_order_netuids() {
         let root_netuid: u16 = 0;
         SubtensorModule::set_max_registrations_per_block(root_netuid, n as u16);
         SubtensorModule::set_target_registrations_per_interval(root_netuid, n as u16);
-        SubtensorModule::set_max_allowed_uids(root_netuid, n as u16);
+        SubtensorModule::set_max_root_validators(n as u16);
         for i in 0..n {
             let hotkey_account_id: U256 = U256::from(i);
             let coldkey_account_id: U256 = U256::from(i);
@@ -398,9 +810,9 @@ fn test_root_set_weights_out_of_order_netuids() {
             let hotkey = U256::from(i);
             assert_ok!(SubtensorModule::set_root_weights(
                 <<Test as Config>::RuntimeOrigin>::signed(coldkey),
-                root_netuid,
+                NetUid::from(root_netuid),
                 hotkey,
-                uids,
+                netuids(uids),
                 values,
                 0,
             ));
@@ -546,7 +958,7 @@ fn test_network_pruning() {
         let root_netuid: u16 = 0;
         SubtensorModule::set_max_registrations_per_block(root_netuid, n as u16);
         SubtensorModule::set_target_registrations_per_interval(root_netuid, n as u16);
-        SubtensorModule::set_max_allowed_uids(root_netuid, n as u16 + 1);
+        SubtensorModule::set_max_root_validators(n as u16 + 1);
         SubtensorModule::set_tempo(root_netuid, 1);
         // No validators yet.
         assert_eq!(SubtensorModule::get_subnetwork_n(root_netuid), 0);
@@ -578,9 +990,9 @@ fn test_network_pruning() {
             assert!(SubtensorModule::get_uid_for_net_and_hotkey(root_netuid, &hot).is_ok());
             assert_ok!(SubtensorModule::set_root_weights(
                 <<Test as Config>::RuntimeOrigin>::signed(cold),
-                root_netuid,
+                NetUid::from(root_netuid),
                 hot,
-                uids,
+                netuids(uids),
                 values,
                 0
             ));
@@ -652,67 +1064,251 @@ fn test_network_prune_results() {
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner)
         ));
-        step_block(3);
+        // Past the immunity period, and the lock reduction interval for the locks to amortize.
+        step_block(3 + 2);
 
         // lowest emission
         SubtensorModule::set_emission_values(&[1u16, 2u16, 3u16], vec![5u64, 4u64, 4u64]).unwrap();
-        assert_eq!(SubtensorModule::get_subnet_to_prune(), 2u16);
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            2u16
+        );
 
         // equal emission, creation date
         SubtensorModule::set_emission_values(&[1u16, 2u16, 3u16], vec![5u64, 5u64, 4u64]).unwrap();
-        assert_eq!(SubtensorModule::get_subnet_to_prune(), 3u16);
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            3u16
+        );
 
         // equal emission, creation date
         SubtensorModule::set_emission_values(&[1u16, 2u16, 3u16], vec![4u64, 5u64, 5u64]).unwrap();
-        assert_eq!(SubtensorModule::get_subnet_to_prune(), 1u16);
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            1u16
+        );
     });
 }
 
 #[test]
-fn test_weights_after_network_pruning() {
+fn test_network_prune_tie_break() {
     new_test_ext(1).execute_with(|| {
         migration::migrate_create_root_network::<Test>();
-
-        assert_eq!(SubtensorModule::get_total_issuance(), 0);
-
-        // Set up N subnets, with max N + 1 allowed UIDs
-        let n: usize = 2;
-        let root_netuid: u16 = 0;
-        SubtensorModule::set_network_immunity_period(3);
-        SubtensorModule::set_max_registrations_per_block(root_netuid, n as u16);
-        SubtensorModule::set_max_subnets(n as u16);
-        SubtensorModule::set_weights_set_rate_limit(root_netuid, 0_u64);
-
-        // No validators yet.
-        assert_eq!(SubtensorModule::get_subnetwork_n(root_netuid), 0);
-
-        for i in 0..n {
-            // Register a validator
-            let cold: U256 = U256::from(i);
-
-            SubtensorModule::add_balance_to_coldkey_account(&cold, 1_000_000_000_000);
-
-            // Register a network
+        SubtensorModule::set_network_immunity_period(0);
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_last_lock(0);
+        SubtensorModule::set_network_rate_limit(0);
+        let owner: U256 = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        for _ in 0..4 {
             assert_ok!(SubtensorModule::register_network(
-                <<Test as Config>::RuntimeOrigin>::signed(cold)
+                <<Test as Config>::RuntimeOrigin>::signed(owner)
             ));
-
-            log::debug!("Adding network with netuid: {}", (i as u16) + 1);
-            assert!(SubtensorModule::if_subnet_exist((i as u16) + 1));
-            step_block(3);
         }
+        let set = |emission: [u64; 4], registered_at: [u64; 4]| {
+            SubtensorModule::set_emission_values(&[1, 2, 3, 4], emission.to_vec()).unwrap();
+            for (netuid, block) in (1..=4u16).zip(registered_at) {
+                pallet_subtensor::NetworkRegisteredAt::<Test>::insert(netuid, block);
+            }
+        };
 
-        // Register a validator in subnet 0
-        let hot: U256 = U256::from((n as u64) - 1);
-        let cold: U256 = U256::from((n as u64) - 1);
+        // The lowest emission is pruned whatever its age.
+        set([5, 4, 5, 5], [1, 9, 1, 1]);
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            2
+        );
 
-        assert_ok!(SubtensorModule::root_register(
-            <<Test as Config>::RuntimeOrigin>::signed(cold),
-            hot
-        ));
-        assert_ok!(SubtensorModule::add_stake(
-            <<Test as Config>::RuntimeOrigin>::signed(cold),
-            hot,
+        // Tied emission prunes the oldest subnet.
+        set([4, 4, 4, 4], [3, 4, 1, 2]);
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            3
+        );
+        set([5, 4, 4, 5], [1, 7, 6, 1]);
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            3
+        );
+
+        // Subnets registered in the same block prune the lowest netuid.
+        set([4, 4, 4, 4], [2, 1, 1, 1]);
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            2
+        );
+        set([0, 0, 0, 0], [0, 0, 0, 0]);
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            1
+        );
+    });
+}
+
+#[test]
+fn test_network_prune_skips_immune_subnets() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_immunity_period(10);
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_last_lock(0);
+        SubtensorModule::set_network_rate_limit(0);
+        let owner: U256 = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        for _ in 0..3 {
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(owner)
+            ));
+        }
+        for (netuid, block) in [(1u16, 1u64), (2, 5), (3, 20)] {
+            pallet_subtensor::NetworkRegisteredAt::<Test>::insert(netuid, block);
+        }
+        run_to_block(25);
+
+        // The freshly registered subnet is immune however low its emission, and the root
+        // network is never picked.
+        SubtensorModule::set_emission_values(&[0, 1, 2, 3], vec![0, 5, 4, 0]).unwrap();
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            2
+        );
+
+        // Out of immunity it is the lowest emission.
+        run_to_block(30);
+        SubtensorModule::set_emission_values(&[0, 1, 2, 3], vec![0, 5, 4, 0]).unwrap();
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost()),
+            3
+        );
+    });
+}
+
+#[test]
+fn test_network_prune_respects_protected_lock() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+
+        SubtensorModule::set_network_immunity_period(10);
+        SubtensorModule::set_network_min_lock(1_000);
+        SubtensorModule::set_network_rate_limit(0);
+        SubtensorModule::set_lock_reduction_interval(5);
+        SubtensorModule::set_max_subnets(1);
+
+        let whale: U256 = U256::from(1);
+        let squatter: U256 = U256::from(2);
+        let balance: u64 = 1_000_000_000_000_000;
+        SubtensorModule::add_balance_to_coldkey_account(&whale, balance);
+        SubtensorModule::add_balance_to_coldkey_account(&squatter, balance);
+
+        // The whale registers, locking the current lock cost.
+        let whale_lock = SubtensorModule::get_network_lock_cost();
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(whale)
+        ));
+        assert_eq!(SubtensorModule::get_subnet_locked_balance(1), whale_lock);
+
+        // By the end of its immunity the lock cost fell to the floor, but the whale's lock
+        // has not started to amortize, so the squatter can not replace it.
+        run_to_block(11);
+        let squatter_lock = SubtensorModule::get_network_lock_cost();
+        assert_eq!(squatter_lock, 1_000);
+        assert_eq!(SubtensorModule::get_subnet_protected_lock(1), whale_lock);
+        assert_eq!(SubtensorModule::get_subnet_to_prune(squatter_lock), 0);
+        assert_err!(
+            SubtensorModule::register_network(<<Test as Config>::RuntimeOrigin>::signed(squatter)),
+            Error::<Test>::AllNetworksInImmunity
+        );
+        assert_eq!(pallet_subtensor::SubnetOwner::<Test>::get(1), whale);
+
+        // Once the lock has amortized the subnet can be replaced and the whale is refunded.
+        run_to_block(16);
+        assert_eq!(SubtensorModule::get_subnet_protected_lock(1), 0);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(squatter)
+        ));
+        assert_eq!(pallet_subtensor::SubnetOwner::<Test>::get(1), squatter);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&whale), balance);
+        assert_eq!(SubtensorModule::get_subnet_locked_balance(1), squatter_lock);
+
+        // The squatter's subnet is protected alike: past its immunity it can only be taken
+        // back by a registration locking as much as the squatter did.
+        run_to_block(26);
+        assert_eq!(SubtensorModule::get_subnet_protected_lock(1), squatter_lock);
+        assert_eq!(
+            SubtensorModule::get_subnet_to_prune(squatter_lock.saturating_sub(1)),
+            0
+        );
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(whale)
+        ));
+        assert_eq!(pallet_subtensor::SubnetOwner::<Test>::get(1), whale);
+    });
+}
+
+#[test]
+fn test_subnet_protected_lock_decays_small_locks() {
+    new_test_ext(1).execute_with(|| {
+        add_network(1, 10, 0);
+        pallet_subtensor::NetworkRegisteredAt::<Test>::insert(1, 1);
+        SubtensorModule::set_network_immunity_period(0);
+        SubtensorModule::set_lock_reduction_interval(1_000);
+
+        // A lock smaller than the interval still decays every block.
+        SubtensorModule::set_subnet_locked_balance(1, 500);
+        step_block(3);
+        assert_eq!(SubtensorModule::get_subnet_protected_lock(1), 499);
+
+        // A larger lock decays by its exact share of the interval.
+        SubtensorModule::set_subnet_locked_balance(1, 1_999);
+        assert_eq!(SubtensorModule::get_subnet_protected_lock(1), 1_994);
+    });
+}
+
+#[test]
+fn test_weights_after_network_pruning() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+
+        assert_eq!(SubtensorModule::get_total_issuance(), 0);
+
+        // Set up N subnets, with max N + 1 allowed UIDs
+        let n: usize = 2;
+        let root_netuid: u16 = 0;
+        SubtensorModule::set_network_immunity_period(3);
+        SubtensorModule::set_max_registrations_per_block(root_netuid, n as u16);
+        SubtensorModule::set_max_subnets(n as u16);
+        SubtensorModule::set_weights_set_rate_limit(root_netuid, 0_u64);
+
+        // No validators yet.
+        assert_eq!(SubtensorModule::get_subnetwork_n(root_netuid), 0);
+
+        for i in 0..n {
+            // Register a validator
+            let cold: U256 = U256::from(i);
+
+            SubtensorModule::add_balance_to_coldkey_account(&cold, 1_000_000_000_000);
+
+            // Register a network
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(cold)
+            ));
+
+            log::debug!("Adding network with netuid: {}", (i as u16) + 1);
+            assert!(SubtensorModule::if_subnet_exist((i as u16) + 1));
+            step_block(3);
+        }
+
+        // Register a validator in subnet 0
+        let hot: U256 = U256::from((n as u64) - 1);
+        let cold: U256 = U256::from((n as u64) - 1);
+
+        assert_ok!(SubtensorModule::root_register(
+            <<Test as Config>::RuntimeOrigin>::signed(cold),
+            hot
+        ));
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(cold),
+            hot,
             1_000
         ));
 
@@ -724,9 +1320,9 @@ fn test_weights_after_network_pruning() {
         log::info!("In netuid: {:?}", root_netuid);
         assert_ok!(SubtensorModule::set_root_weights(
             <<Test as Config>::RuntimeOrigin>::signed(cold),
-            root_netuid,
+            NetUid::from(root_netuid),
             hot,
-            uids,
+            netuids(uids),
             values,
             0
         ));
@@ -741,7 +1337,8 @@ fn test_weights_after_network_pruning() {
         let cold: U256 = U256::from(i);
 
         SubtensorModule::add_balance_to_coldkey_account(&cold, 1_000_000_000_000_000_000);
-        let subnet_to_prune = SubtensorModule::get_subnet_to_prune();
+        let subnet_to_prune =
+            SubtensorModule::get_subnet_to_prune(SubtensorModule::get_network_lock_cost());
 
         // Subnet 1 should be pruned here.
         assert_eq!(subnet_to_prune, 1);
@@ -974,3 +1571,1624 @@ fn test_dissolve_network_does_not_exist_err() {
         );
     });
 }
+
+#[test]
+fn test_subnet_limit_change_is_scheduled() {
+    new_test_ext(1).execute_with(|| {
+        let init_value = SubtensorModule::get_max_subnets();
+        SubtensorModule::set_subnet_limit_change_delay(10);
+
+        assert_eq!(SubtensorModule::schedule_max_subnets(5), 11);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetLimitChangeScheduled(5, 11),
+        ));
+        // A later change of the delay does not move the announced change.
+        SubtensorModule::set_subnet_limit_change_delay(100);
+
+        run_to_block(10);
+        assert_eq!(SubtensorModule::get_max_subnets(), init_value);
+        run_to_block(11);
+        assert_eq!(SubtensorModule::get_max_subnets(), 5);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetLimitSet(5),
+        ));
+        assert_eq!(
+            pallet_subtensor::ScheduledSubnetLimits::<Test>::get(11),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_subnet_owner_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        let operator = U256::from(11);
+        let other = U256::from(12);
+        add_network(netuid, 0, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        // Only the owner coldkey or root may designate an operator.
+        assert_err!(
+            SubtensorModule::set_subnet_owner_hotkey(
+                RuntimeOrigin::signed(other),
+                netuid,
+                operator
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_err!(
+            SubtensorModule::set_subnet_owner_hotkey(RuntimeOrigin::root(), netuid + 1, operator),
+            Error::<Test>::SubNetworkDoesNotExist
+        );
+        assert_err!(
+            SubtensorModule::ensure_subnet_owner_or_root(RuntimeOrigin::signed(operator), netuid),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::set_subnet_owner_hotkey(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            operator
+        ));
+        assert_eq!(
+            SubtensorModule::get_subnet_owner_hotkey(netuid),
+            Some(operator)
+        );
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetOwnerHotkeySet(netuid, operator),
+        ));
+
+        // Both keys pass the owner check, other keys still do not.
+        assert_ok!(SubtensorModule::ensure_subnet_owner_or_root(
+            RuntimeOrigin::signed(owner),
+            netuid
+        ));
+        assert_ok!(SubtensorModule::ensure_subnet_owner_or_root(
+            RuntimeOrigin::signed(operator),
+            netuid
+        ));
+        assert_err!(
+            SubtensorModule::ensure_subnet_owner_or_root(RuntimeOrigin::signed(other), netuid),
+            DispatchError::BadOrigin
+        );
+
+        // The operator cannot replace or revoke itself.
+        assert_err!(
+            SubtensorModule::set_subnet_owner_hotkey(
+                RuntimeOrigin::signed(operator),
+                netuid,
+                other
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_err!(
+            SubtensorModule::revoke_subnet_owner_hotkey(RuntimeOrigin::signed(operator), netuid),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::revoke_subnet_owner_hotkey(
+            RuntimeOrigin::signed(owner),
+            netuid
+        ));
+        assert_eq!(SubtensorModule::get_subnet_owner_hotkey(netuid), None);
+        assert_err!(
+            SubtensorModule::ensure_subnet_owner_or_root(RuntimeOrigin::signed(operator), netuid),
+            DispatchError::BadOrigin
+        );
+        assert_err!(
+            SubtensorModule::revoke_subnet_owner_hotkey(RuntimeOrigin::signed(owner), netuid),
+            Error::<Test>::SubnetOwnerHotkeyNotSet
+        );
+
+        // The operator is dropped with the subnet.
+        assert_ok!(SubtensorModule::set_subnet_owner_hotkey(
+            RuntimeOrigin::root(),
+            netuid,
+            operator
+        ));
+        SubtensorModule::remove_network(netuid);
+        assert_eq!(SubtensorModule::get_subnet_owner_hotkey(netuid), None);
+    });
+}
+
+#[test]
+fn test_netuid_newtype() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        assert!(NetUid::ROOT.is_root());
+        assert!(!NetUid::from(netuid).is_root());
+        assert_eq!(NetUid::from(netuid).encode(), netuid.encode());
+
+        // Netuid-keyed storage can be read with either key type.
+        add_network(netuid, 13, 0);
+        assert!(pallet_subtensor::NetworksAdded::<Test>::get(NetUid::from(
+            netuid
+        )));
+
+        assert_eq!(
+            SubtensorModule::checked_netuid(netuid).ok(),
+            Some(NetUid::from(netuid))
+        );
+        assert_eq!(
+            SubtensorModule::checked_netuid(netuid + 1).map_err(DispatchError::from),
+            Err(Error::<Test>::SubNetworkDoesNotExist.into())
+        );
+        assert!(SubtensorModule::contains_invalid_root_uids(&[
+            NetUid::from(netuid),
+            NetUid::from(netuid + 1)
+        ]));
+        assert!(!SubtensorModule::contains_invalid_root_uids(&[
+            NetUid::from(netuid)
+        ]));
+    });
+}
+
+#[test]
+fn test_netuid_auction() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_rate_limit(0);
+
+        let owner = U256::from(0);
+        let alice = U256::from(1);
+        let bob = U256::from(2);
+        for coldkey in [owner, alice, bob] {
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        }
+
+        // The root network and reserved netuids cannot be reserved.
+        assert_eq!(
+            SubtensorModule::do_reserve_netuid(0, 1_000, 10).map_err(DispatchError::from),
+            Err(Error::<Test>::NetuidAlreadyInUse.into())
+        );
+        assert_ok!(SubtensorModule::do_reserve_netuid(1, 1_000, 10));
+        assert_eq!(
+            SubtensorModule::do_reserve_netuid(1, 1_000, 10).map_err(DispatchError::from),
+            Err(Error::<Test>::NetuidAlreadyInUse.into())
+        );
+
+        // Sequential assignment skips the reserved netuid.
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner)
+        ));
+        assert!(!SubtensorModule::if_subnet_exist(1));
+        assert!(SubtensorModule::if_subnet_exist(2));
+
+        // Bids are held in escrow and the outbid bidder is refunded.
+        assert_err!(
+            SubtensorModule::bid_on_netuid(RuntimeOrigin::signed(alice), 1, 999),
+            Error::<Test>::NetuidBidTooLow
+        );
+        assert_ok!(SubtensorModule::bid_on_netuid(
+            RuntimeOrigin::signed(alice),
+            1,
+            1_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&alice),
+            1_000_000 - 1_000
+        );
+        assert_err!(
+            SubtensorModule::bid_on_netuid(RuntimeOrigin::signed(bob), 1, 1_000),
+            Error::<Test>::NetuidBidTooLow
+        );
+        assert_ok!(SubtensorModule::bid_on_netuid(
+            RuntimeOrigin::signed(bob),
+            1,
+            2_000
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&alice), 1_000_000);
+        assert_eq!(SubtensorModule::get_netuid_bid(1), Some((bob, 2_000)));
+
+        // The netuid can only be claimed by the winner once the auction has ended.
+        assert_err!(
+            SubtensorModule::claim_netuid(RuntimeOrigin::signed(bob), 1),
+            Error::<Test>::NetuidAuctionNotEnded
+        );
+        step_block(10);
+        assert_err!(
+            SubtensorModule::bid_on_netuid(RuntimeOrigin::signed(alice), 1, 3_000),
+            Error::<Test>::NetuidAuctionEnded
+        );
+        assert_err!(
+            SubtensorModule::claim_netuid(RuntimeOrigin::signed(alice), 1),
+            Error::<Test>::NotNetuidAuctionWinner
+        );
+        assert_ok!(SubtensorModule::claim_netuid(RuntimeOrigin::signed(bob), 1));
+        assert!(SubtensorModule::if_subnet_exist(1));
+        assert_eq!(SubtensorModule::get_subnet_owner(1), bob);
+        assert!(!SubtensorModule::is_netuid_reserved(1));
+        assert_eq!(SubtensorModule::get_netuid_bid(1), None);
+
+        // Releasing a netuid refunds its highest bid.
+        assert_ok!(SubtensorModule::do_reserve_netuid(3, 0, 10));
+        assert_ok!(SubtensorModule::bid_on_netuid(
+            RuntimeOrigin::signed(alice),
+            3,
+            500
+        ));
+        assert_ok!(SubtensorModule::do_release_netuid(3));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&alice), 1_000_000);
+        assert!(!SubtensorModule::is_netuid_reserved(3));
+
+        // A netuid not claimed in time returns to sequential assignment, refunding its bid.
+        assert_ok!(SubtensorModule::do_reserve_netuid(3, 0, 10));
+        assert_ok!(SubtensorModule::bid_on_netuid(
+            RuntimeOrigin::signed(alice),
+            3,
+            500
+        ));
+        let (_, ends_at) = SubtensorModule::get_reserved_netuid(3).unwrap();
+        SubtensorModule::expire_netuid_reservations(ends_at + NETUID_CLAIM_PERIOD - 1);
+        assert!(SubtensorModule::is_netuid_reserved(3));
+        SubtensorModule::expire_netuid_reservations(ends_at + NETUID_CLAIM_PERIOD);
+        assert!(!SubtensorModule::is_netuid_reserved(3));
+        assert_eq!(SubtensorModule::get_netuid_bid(3), None);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&alice), 1_000_000);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NetuidReservationExpired(3),
+        ));
+
+        // At most MAX_RESERVED_NETUIDS netuids are reserved at once.
+        for netuid in 0..MAX_RESERVED_NETUIDS as u16 {
+            assert_ok!(SubtensorModule::do_reserve_netuid(100 + netuid, 0, 10));
+        }
+        assert_eq!(
+            SubtensorModule::do_reserve_netuid(3, 0, 10).map_err(DispatchError::from),
+            Err(Error::<Test>::TooManyReservedNetuids.into())
+        );
+    });
+}
+
+#[test]
+fn test_root_register_and_stake() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        SubtensorModule::set_max_root_validators(1);
+        SubtensorModule::set_max_registrations_per_block(root_netuid, 1000);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, 1000);
+
+        let incumbent = U256::from(1);
+        let newcomer_hotkey = U256::from(2);
+        let newcomer_coldkey = U256::from(3);
+        SubtensorModule::add_balance_to_coldkey_account(&incumbent, 10_000);
+        SubtensorModule::add_balance_to_coldkey_account(&newcomer_coldkey, 10_000);
+        assert_ok!(SubtensorModule::root_register_and_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(incumbent),
+            incumbent,
+            1_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&incumbent),
+            1_000
+        );
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(root_netuid, &incumbent).is_ok());
+
+        assert_err!(
+            SubtensorModule::root_register_and_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(newcomer_coldkey),
+                newcomer_hotkey,
+                0
+            ),
+            Error::<Test>::StakeToAddIsZero
+        );
+        assert_err!(
+            SubtensorModule::root_register_and_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(newcomer_coldkey),
+                incumbent,
+                2_000
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // Too little stake to replace the incumbent leaves no stake or hotkey behind.
+        assert_err!(
+            SubtensorModule::root_register_and_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(newcomer_coldkey),
+                newcomer_hotkey,
+                500
+            ),
+            Error::<Test>::StakeTooLowForRoot
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&newcomer_hotkey),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&newcomer_coldkey),
+            10_000
+        );
+        assert!(!SubtensorModule::hotkey_account_exists(&newcomer_hotkey));
+
+        assert_ok!(SubtensorModule::root_register_and_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(newcomer_coldkey),
+            newcomer_hotkey,
+            2_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&newcomer_hotkey),
+            2_000
+        );
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(root_netuid, &newcomer_hotkey).is_ok());
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(root_netuid, &incumbent).is_err());
+    });
+}
+
+#[test]
+fn test_storage_stats_and_orphaned_storage_pruning() {
+    new_test_ext(1).execute_with(|| {
+        let stats =
+            |loaded_emission: u32, orphaned_loaded_emission: u32, last_update: u32| StorageStats {
+                loaded_emission_entries: loaded_emission.into(),
+                loaded_emission_orphaned: orphaned_loaded_emission.into(),
+                last_update_entries: last_update.into(),
+                last_update_orphaned: 0.into(),
+            };
+        for netuid in [1u16, 2] {
+            let hotkey = U256::from(netuid);
+            add_network(netuid, 10, 0);
+            SubtensorModule::append_neuron(netuid, &hotkey, 0);
+            SubtensorModule::insert_loaded_emission(netuid, vec![(hotkey, 10, 10)]);
+        }
+        assert_eq!(SubtensorModule::get_storage_stats(), stats(2, 0, 2));
+
+        // Removing a subnet leaves its loaded emission behind.
+        SubtensorModule::remove_network(1);
+        assert_eq!(SubtensorModule::get_storage_stats(), stats(2, 1, 1));
+
+        assert_eq!(SubtensorModule::do_prune_orphaned_storage(0), 0);
+        assert_eq!(SubtensorModule::get_storage_stats(), stats(2, 1, 1));
+        assert_eq!(SubtensorModule::do_prune_orphaned_storage(10), 1);
+        assert_eq!(SubtensorModule::get_storage_stats(), stats(1, 0, 1));
+        assert!(SubtensorModule::get_loaded_emission_tuples(2).is_some());
+    });
+}
+
+#[test]
+fn test_network_parameters_from_config_constants() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid = SubtensorModule::root_netuid();
+        assert_eq!(root_netuid, NetUid::ROOT.inner());
+        assert_eq!(
+            SubtensorModule::get_tempo(root_netuid),
+            <Test as pallet_subtensor::Config>::InitialRootTempo::get()
+        );
+        assert_eq!(
+            SubtensorModule::get_max_allowed_uids(root_netuid),
+            <Test as pallet_subtensor::Config>::InitialRootMaxUids::get()
+        );
+
+        let owner = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner)
+        ));
+        let netuid = 1;
+        assert_eq!(
+            SubtensorModule::get_tempo(netuid),
+            <Test as pallet_subtensor::Config>::NewNetworkTempo::get()
+        );
+        assert_eq!(
+            SubtensorModule::get_max_allowed_uids(netuid),
+            <Test as pallet_subtensor::Config>::NewNetworkMaxAllowedUids::get()
+        );
+        assert_eq!(
+            SubtensorModule::get_immunity_period(netuid),
+            <Test as pallet_subtensor::Config>::NewNetworkImmunityPeriod::get()
+        );
+        assert_eq!(
+            SubtensorModule::get_min_burn_as_u64(netuid),
+            <Test as pallet_subtensor::Config>::NewNetworkMinBurn::get()
+        );
+    });
+}
+
+#[test]
+fn test_subnet_token_metadata() {
+    new_test_ext(1).execute_with(|| {
+        let owner = U256::from(10);
+        let other = U256::from(12);
+        add_network(1, 0, 0);
+        add_network(2, 0, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(1, owner);
+        pallet_subtensor::SubnetOwner::<Test>::insert(2, other);
+
+        assert_err!(
+            SubtensorModule::set_subnet_token_metadata(
+                RuntimeOrigin::signed(other),
+                1,
+                "α".into(),
+                "alpha".into()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_err!(
+            SubtensorModule::set_subnet_token_metadata(
+                RuntimeOrigin::signed(owner),
+                1,
+                vec![0xff, 0xfe],
+                "alpha".into()
+            ),
+            Error::<Test>::InvalidSubnetTokenMetadata
+        );
+        assert_err!(
+            SubtensorModule::set_subnet_token_metadata(
+                RuntimeOrigin::signed(owner),
+                1,
+                vec![b'a'; 17],
+                "alpha".into()
+            ),
+            Error::<Test>::InvalidSubnetTokenMetadata
+        );
+
+        assert_ok!(SubtensorModule::set_subnet_token_metadata(
+            RuntimeOrigin::signed(owner),
+            1,
+            "α".into(),
+            "alpha".into()
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetTokenMetadataSet(1, "α".into(), "alpha".into()),
+        ));
+        assert_eq!(
+            SubtensorModule::get_subnet_symbol(1),
+            "α".as_bytes().to_vec()
+        );
+        assert_eq!(SubtensorModule::get_subnet_token_name(1), b"alpha".to_vec());
+
+        // The symbol is taken, but its owner may set it again.
+        assert_err!(
+            SubtensorModule::set_subnet_token_metadata(
+                RuntimeOrigin::signed(other),
+                2,
+                "α".into(),
+                "other".into()
+            ),
+            Error::<Test>::SubnetSymbolInUse
+        );
+        assert_ok!(SubtensorModule::set_subnet_token_metadata(
+            RuntimeOrigin::signed(owner),
+            1,
+            "α".into(),
+            Vec::new()
+        ));
+        assert!(SubtensorModule::get_subnet_token_name(1).is_empty());
+
+        // SubnetInfoV2 extends the SubnetInfo encoding.
+        let mut expected = SubtensorModule::get_subnet_info(1).unwrap().encode();
+        expected.extend(("α".as_bytes().to_vec(), Vec::<u8>::new()).encode());
+        assert_eq!(
+            SubtensorModule::get_subnet_info_v2(1).unwrap().encode(),
+            expected
+        );
+        assert_eq!(
+            SubtensorModule::get_subnets_info_v2().len(),
+            SubtensorModule::get_subnets_info().len()
+        );
+
+        // Clearing the symbol frees it for other subnets.
+        assert_ok!(SubtensorModule::set_subnet_token_metadata(
+            RuntimeOrigin::root(),
+            1,
+            Vec::new(),
+            Vec::new()
+        ));
+        assert_ok!(SubtensorModule::set_subnet_token_metadata(
+            RuntimeOrigin::signed(other),
+            2,
+            "α".into(),
+            "other".into()
+        ));
+        assert!(SubtensorModule::get_subnet_symbol(1).is_empty());
+    });
+}
+
+#[test]
+fn test_subtensor_hooks() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_rate_limit(0);
+        let netuid: u16 = 1;
+        let owner = U256::from(0);
+        let hotkey = U256::from(1);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        take_subtensor_hook_calls();
+
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner)
+        ));
+        assert_eq!(
+            take_subtensor_hook_calls(),
+            vec![SubtensorHookCall::NetworkAdded(netuid)]
+        );
+
+        SubtensorModule::set_difficulty(netuid, 1);
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        assert!(take_subtensor_hook_calls()
+            .contains(&SubtensorHookCall::NeuronRegistered(netuid, 0, hotkey)));
+
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            1_000
+        ));
+        assert_eq!(
+            take_subtensor_hook_calls(),
+            vec![SubtensorHookCall::StakeChanged(hotkey, owner, 1_000)]
+        );
+
+        SubtensorModule::set_tempo(netuid, 1);
+        step_block(2);
+        assert!(take_subtensor_hook_calls()
+            .iter()
+            .any(|call| matches!(call, SubtensorHookCall::EpochCompleted(1, _))));
+
+        assert_ok!(SubtensorModule::dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+        assert!(take_subtensor_hook_calls().contains(&SubtensorHookCall::NetworkRemoved(netuid)));
+    });
+}
+
+#[test]
+fn test_subnet_netuids_kept_sorted() {
+    new_test_ext(1).execute_with(|| {
+        add_network(5, 13, 0);
+        add_network(1, 13, 0);
+        add_network(300, 13, 0);
+        add_network(3, 13, 0);
+        assert_eq!(
+            SubtensorModule::get_all_subnet_netuids(),
+            vec![1, 3, 5, 300]
+        );
+
+        SubtensorModule::remove_network(3);
+        assert_eq!(SubtensorModule::get_all_subnet_netuids(), vec![1, 5, 300]);
+        assert!(!SubtensorModule::if_subnet_exist(3));
+
+        // Adding an existing network does not list it twice.
+        SubtensorModule::add_subnet_netuid(5);
+        assert_eq!(SubtensorModule::get_all_subnet_netuids(), vec![1, 5, 300]);
+
+        let infos = SubtensorModule::get_subnets_info();
+        assert_eq!(
+            infos
+                .iter()
+                .map(|info| info.as_ref().map(|info| info.netuid.0))
+                .collect::<Vec<_>>(),
+            vec![Some(1), Some(5), Some(300)]
+        );
+    });
+}
+
+#[test]
+fn test_project_network_burn_cost() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+        let owner: U256 = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        step_block(1);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner)
+        ));
+
+        // The lock doubles on registration, then halves its way back to the minimum.
+        assert_eq!(
+            SubtensorModule::project_network_burn_cost(0),
+            200_000_000_000
+        );
+        assert_eq!(
+            SubtensorModule::project_network_burn_cost(1),
+            150_000_000_000
+        );
+        assert_eq!(
+            SubtensorModule::project_network_burn_cost(2),
+            100_000_000_000
+        );
+        assert_eq!(
+            SubtensorModule::project_network_burn_cost(u64::MAX),
+            100_000_000_000
+        );
+
+        // The projection matches the cost once the blocks have passed.
+        step_block(1);
+        assert_eq!(SubtensorModule::get_network_lock_cost(), 150_000_000_000);
+        assert_eq!(
+            SubtensorModule::project_network_burn_cost(0),
+            150_000_000_000
+        );
+    });
+}
+
+#[test]
+fn test_network_lock_cost_multiplier() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+        let owner: U256 = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+        SubtensorModule::set_network_lock_cost_multiplier(30_000);
+        step_block(1);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner)
+        ));
+
+        // The lock triples on registration, then decays at the same pace as when it doubles.
+        assert_eq!(
+            SubtensorModule::project_network_burn_cost(0),
+            300_000_000_000
+        );
+        assert_eq!(
+            SubtensorModule::project_network_burn_cost(1),
+            250_000_000_000
+        );
+        assert_eq!(
+            SubtensorModule::project_network_burn_cost(u64::MAX),
+            100_000_000_000
+        );
+    });
+}
+
+#[test]
+fn test_root_epoch_over_several_blocks() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        let n: u16 = 6;
+        SubtensorModule::set_max_registrations_per_block(root_netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, n);
+        SubtensorModule::set_max_root_validators(n);
+        for i in 0..n {
+            let hotkey = U256::from(i);
+            let coldkey = U256::from(i + 456);
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000_000);
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                1000 * (u64::from(i) + 1)
+            ));
+        }
+        for i in 1..n {
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(i + 456))
+            ));
+        }
+        for i in 0..n {
+            assert_ok!(SubtensorModule::set_root_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(i + 456)),
+                NetUid::from(root_netuid),
+                U256::from(i),
+                netuids(vec![1 + i % 3, 1 + (i + 1) % 5]),
+                vec![u16::MAX / (i + 1), 100],
+                0,
+            ));
+        }
+        SubtensorModule::set_tempo(root_netuid, 1);
+        let netuids = SubtensorModule::get_all_subnet_netuids();
+
+        // The sparse rows hold the weights of the dense matrix set by each validator.
+        let dense = SubtensorModule::get_root_weights();
+        let sparse = SubtensorModule::get_root_weights_sparse();
+        assert_eq!(sparse.len(), dense.len());
+        for (sparse_row, dense_row) in sparse.iter().zip(&dense) {
+            let mut sparse_row = sparse_row.clone();
+            sparse_row.sort_by_key(|(column, _)| *column);
+            let nonzero: Vec<_> = dense_row
+                .iter()
+                .enumerate()
+                .filter(|(_, weight)| **weight > 0)
+                .map(|(column, weight)| (column as u16, *weight))
+                .collect();
+            assert_eq!(sparse_row, nonzero);
+        }
+
+        // Within the default budget the epoch completes in its block.
+        assert_ok!(SubtensorModule::root_epoch(1_000_000_000));
+        let single_block: Vec<u64> = netuids
+            .iter()
+            .map(|netuid| SubtensorModule::get_emission_value(*netuid))
+            .collect();
+        assert!(single_block.iter().any(|emission| *emission > 0));
+        assert_eq!(
+            SubtensorModule::compute_root_emission_values(
+                SubtensorModule::get_root_weights_sparse()
+            ),
+            Ok(single_block.clone())
+        );
+
+        // With a small budget it continues over the next blocks, whatever their number, and
+        // sets the same emission values once complete.
+        for netuid in &netuids {
+            pallet_subtensor::EmissionValues::<Test>::insert(netuid, 0);
+        }
+        SubtensorModule::set_root_epoch_block_budget(4);
+        assert!(SubtensorModule::root_epoch(1_000_000_000).is_err());
+        assert!(pallet_subtensor::RootEpochInProgress::<Test>::get().is_some());
+        let mut blocks = 1;
+        while SubtensorModule::root_epoch(1_000_000_001).is_err() {
+            blocks += 1;
+            assert!(blocks < 20);
+        }
+        assert!(blocks > 2);
+        assert!(pallet_subtensor::RootEpochInProgress::<Test>::get().is_none());
+        let several_blocks: Vec<u64> = netuids
+            .iter()
+            .map(|netuid| SubtensorModule::get_emission_value(*netuid))
+            .collect();
+        assert_eq!(several_blocks, single_block);
+    });
+}
+
+#[test]
+fn test_get_top_validators_and_root_voters() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        let netuid: u16 = 1;
+        let n: u16 = 3;
+        SubtensorModule::set_max_registrations_per_block(root_netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, n);
+        SubtensorModule::set_max_root_validators(n);
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+
+        // Stakes 1000, 3000 and 3000: the tie is broken by the lower uid.
+        for i in 0..n {
+            let hotkey = U256::from(i);
+            let coldkey = U256::from(i + 456);
+            register_ok_neuron(netuid, hotkey, coldkey, u64::from(i) * 100_000);
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000_000);
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+            ));
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &coldkey,
+                &hotkey,
+                if i == 0 { 1_000 } else { 3_000 },
+            );
+        }
+        pallet_subtensor::ValidatorPermit::<Test>::insert(netuid, vec![true, false, true]);
+        assert_ok!(SubtensorModule::set_root_weights(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(456)),
+            NetUid::from(root_netuid),
+            U256::from(0),
+            netuids(vec![netuid]),
+            vec![1],
+            0,
+        ));
+
+        let top = SubtensorModule::get_top_validators(netuid, 2);
+        let summary: Vec<(U256, u64, bool)> = top
+            .iter()
+            .map(|v| (v.hotkey, v.stake.0, v.permit))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(U256::from(1), 3_000, false), (U256::from(2), 3_000, true)]
+        );
+        assert_eq!(
+            SubtensorModule::get_top_validators(netuid, u16::MAX).len(),
+            3
+        );
+        assert!(SubtensorModule::get_top_validators(netuid + 1, 2).is_empty());
+
+        let voters: Vec<(U256, bool)> = SubtensorModule::get_root_voters()
+            .iter()
+            .map(|v| (v.hotkey, v.permit))
+            .collect();
+        assert_eq!(
+            voters,
+            vec![
+                (U256::from(1), false),
+                (U256::from(2), false),
+                (U256::from(0), true)
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_root_weights_commit_reveal() {
+    new_test_ext(1).execute_with(|| {
+        use sp_runtime::traits::{BlakeTwo256, Hash};
+
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000_000);
+        assert_ok!(SubtensorModule::root_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+        ));
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+        ));
+
+        SubtensorModule::set_commit_reveal_weights_interval(root_netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(root_netuid, true);
+
+        // Plaintext root weights are refused while commit-reveal is on.
+        let uids: Vec<u16> = vec![1];
+        let values: Vec<u16> = vec![10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4];
+        assert_err!(
+            SubtensorModule::set_root_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                NetUid::from(root_netuid),
+                hotkey,
+                netuids(uids.clone()),
+                values.clone(),
+                0,
+            ),
+            Error::<Test>::CommitRevealEnabled
+        );
+
+        let commit_hash: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            root_netuid,
+            uids.clone(),
+            values.clone(),
+            salt.clone(),
+            0u64,
+        ));
+        assert_ok!(SubtensorModule::commit_weights(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            root_netuid,
+            commit_hash
+        ));
+        // A commit alone leaves the root weights untouched.
+        assert!(pallet_subtensor::Weights::<Test>::get(root_netuid, 0).is_empty());
+
+        step_block(5);
+        assert_ok!(SubtensorModule::reveal_weights(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            root_netuid,
+            uids,
+            values,
+            salt,
+            0,
+        ));
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(root_netuid, 0).entries(),
+            vec![(1, u16::MAX)]
+        );
+    });
+}
+
+#[test]
+fn test_network_rate_limit() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_min_lock(0);
+        SubtensorModule::set_network_rate_limit(0);
+        let owner = U256::from(0);
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 1_000_000_000_000_000);
+
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner)
+        ));
+        SubtensorModule::set_network_rate_limit(10);
+        assert_err!(
+            SubtensorModule::register_network(<<Test as Config>::RuntimeOrigin>::signed(owner)),
+            Error::<Test>::NetworkTxRateLimitExceeded
+        );
+
+        // Networks can be registered again once the limit has passed.
+        run_to_block(11);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner)
+        ));
+    });
+}
+
+#[test]
+fn test_dissolve_network_clears_neuron_storage() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 30;
+        let hotkey = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        let owner_coldkey = SubtensorModule::get_subnet_owner(netuid);
+        register_ok_neuron(netuid, hotkey, owner_coldkey, 3);
+        assert_ok!(SubtensorModule::set_commitment(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![1, 2, 3]
+        ));
+        pallet_subtensor::Axons::<Test>::insert(
+            netuid,
+            hotkey,
+            pallet_subtensor::AxonInfo::default(),
+        );
+        pallet_subtensor::WeightCommits::<Test>::insert(netuid, hotkey, (H256::zero(), 1));
+        pallet_subtensor::PendingEmission::<Test>::insert(netuid, 1_000);
+
+        assert_ok!(SubtensorModule::dissolve_network(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid
+        ));
+
+        // A network registered under the same netuid starts from a clean slate.
+        assert_eq!(SubtensorModule::get_commitment(netuid, &hotkey), None);
+        assert!(!pallet_subtensor::Axons::<Test>::contains_key(
+            netuid, hotkey
+        ));
+        assert!(!pallet_subtensor::WeightCommits::<Test>::contains_key(
+            netuid, hotkey
+        ));
+        assert!(!pallet_subtensor::BlockAtRegistration::<Test>::contains_key(netuid, 0));
+        assert_eq!(pallet_subtensor::PendingEmission::<Test>::get(netuid), 0);
+    });
+}
+
+#[test]
+fn test_netuid_quarantine() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_immunity_period(0);
+        SubtensorModule::set_network_min_lock(1_000);
+        SubtensorModule::set_network_rate_limit(0);
+        SubtensorModule::set_lock_reduction_interval(1);
+        SubtensorModule::set_max_subnets(1);
+
+        let owners: Vec<U256> = (1..=3).map(U256::from).collect();
+        for owner in &owners {
+            SubtensorModule::add_balance_to_coldkey_account(owner, 10_000_000);
+        }
+        let register = |owner: &U256| {
+            SubtensorModule::register_network(<<Test as Config>::RuntimeOrigin>::signed(*owner))
+        };
+
+        // Without a quarantine period the pruned netuid goes straight to the new network.
+        assert_ok!(register(&owners[0]));
+        step_block(2);
+        assert_ok!(register(&owners[1]));
+        assert_eq!(pallet_subtensor::SubnetOwner::<Test>::get(1), owners[1]);
+        assert_eq!(SubtensorModule::get_netuid_tombstone(1), None);
+
+        // With one, the pruned netuid is held back and the new network takes the next free one.
+        SubtensorModule::set_netuid_quarantine_period(100);
+        step_block(2);
+        assert_ok!(register(&owners[2]));
+        let removed_at = SubtensorModule::get_current_block_as_u64();
+        assert!(!SubtensorModule::if_subnet_exist(1));
+        assert_eq!(pallet_subtensor::SubnetOwner::<Test>::get(2), owners[2]);
+        assert_eq!(
+            SubtensorModule::get_netuid_tombstone(1),
+            Some((removed_at, owners[1]))
+        );
+        assert!(SubtensorModule::is_netuid_quarantined(1));
+        assert_eq!(SubtensorModule::get_next_available_netuid(), Some(3));
+        assert_err!(
+            SubtensorModule::do_reserve_netuid(1, 0, 10),
+            Error::<Test>::NetuidQuarantined
+        );
+
+        // Once the period is over the netuid can be assigned again.
+        run_to_block(removed_at + 100);
+        assert!(!SubtensorModule::is_netuid_quarantined(1));
+        assert_eq!(SubtensorModule::get_next_available_netuid(), Some(1));
+    });
+}
+
+#[test]
+fn test_root_epoch_drops_removed_subnet() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        let n: u16 = 3;
+        SubtensorModule::set_max_registrations_per_block(root_netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, n);
+        SubtensorModule::set_max_root_validators(n);
+        for i in 0..n {
+            let hotkey = U256::from(i);
+            let coldkey = U256::from(i + 456);
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000_000);
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                1000 * (u64::from(i) + 1)
+            ));
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+            ));
+        }
+        for i in 0..n {
+            assert_ok!(SubtensorModule::set_root_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(i + 456)),
+                NetUid::from(root_netuid),
+                U256::from(i),
+                netuids(vec![1, 2, 3]),
+                vec![100, 200, 300],
+                0,
+            ));
+        }
+        SubtensorModule::set_tempo(root_netuid, 1);
+
+        // The subnet is removed while the root epoch is spread over several blocks.
+        SubtensorModule::set_root_epoch_block_budget(2);
+        assert!(SubtensorModule::root_epoch(1_000_000_000).is_err());
+        assert!(pallet_subtensor::RootEpochInProgress::<Test>::get().is_some());
+        SubtensorModule::remove_network(2);
+        assert!(!pallet_subtensor::RootEpochInProgress::<Test>::get()
+            .unwrap()
+            .netuids
+            .contains(&2));
+        let mut blocks = 1;
+        while SubtensorModule::root_epoch(1_000_000_001).is_err() {
+            blocks += 1;
+            assert!(blocks < 20);
+        }
+
+        // It gets no emission and the remaining subnets share the block emission.
+        assert_eq!(SubtensorModule::get_emission_value(2), 0);
+        assert!(SubtensorModule::get_emission_value(1) > 0);
+        assert!(SubtensorModule::get_emission_value(3) > 0);
+
+        // Root weights can no longer be set on it.
+        assert_err!(
+            SubtensorModule::set_root_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(456)),
+                NetUid::from(root_netuid),
+                U256::from(0),
+                netuids(vec![1, 2]),
+                vec![100, 200],
+                0,
+            ),
+            Error::<Test>::UidVecContainInvalidOne
+        );
+    });
+}
+
+#[test]
+fn test_emission_history_keeps_last_root_epochs() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        assert!(SubtensorModule::get_emission_history(netuid).is_empty());
+
+        for emission in 0..66u64 {
+            assert_ok!(SubtensorModule::set_emission_values(
+                &[netuid],
+                vec![emission]
+            ));
+            SubtensorModule::record_emission_history();
+        }
+
+        // The oldest values are dropped once the history is full.
+        let history = SubtensorModule::get_emission_history(netuid);
+        assert_eq!(
+            history.len(),
+            pallet_subtensor::EMISSION_HISTORY_LENGTH as usize
+        );
+        assert_eq!(history[0], 2);
+        assert_eq!(history[63], 65);
+
+        SubtensorModule::remove_network(netuid);
+        assert!(SubtensorModule::get_emission_history(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_emission_proofs_against_root_epoch_commitment() {
+    new_test_ext(1).execute_with(|| {
+        let netuids: Vec<u16> = vec![1, 2, 3];
+        for netuid in &netuids {
+            add_network(*netuid, 10, 0);
+        }
+        assert_ok!(SubtensorModule::set_emission_values(
+            &netuids,
+            vec![100, 200, 300]
+        ));
+        SubtensorModule::record_root_epoch_commitment(7);
+
+        let (block, root) = SubtensorModule::get_root_epoch_commitment();
+        assert_eq!(block, 7);
+        for (netuid, emission) in [(1, 100), (2, 200), (3, 300)] {
+            let proof = SubtensorModule::get_emission_proof(netuid).expect("subnet exists");
+            assert_eq!(proof.emission.0, emission);
+            assert_eq!(proof.root, root);
+            assert!(proof.verify());
+
+            // A forged emission does not fold to the root.
+            let mut forged = proof.clone();
+            forged.emission = (emission + 1).into();
+            assert!(!forged.verify());
+        }
+        assert!(SubtensorModule::get_emission_proof(4).is_none());
+
+        // Emission set since the commitment cannot be proven until the next root epoch.
+        assert_ok!(SubtensorModule::set_emission_values(&[2], vec![250]));
+        assert!(SubtensorModule::get_emission_proof(2).is_none());
+        SubtensorModule::record_root_epoch_commitment(8);
+        assert!(SubtensorModule::get_emission_proof(2).is_some_and(|proof| proof.verify()));
+    });
+}
+
+#[cfg(any(feature = "test-utils", feature = "runtime-benchmarks"))]
+#[test]
+fn test_subnet_config_snapshot_restores_into_fresh_externalities() {
+    use codec::Decode;
+    use pallet_subtensor::subnet_config_snapshot::SubnetConfigSnapshot;
+
+    let netuid: u16 = 1;
+    let encoded: Vec<u8> = new_test_ext(1).execute_with(|| {
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_kappa(netuid, 100);
+        SubtensorModule::set_difficulty(netuid, 12_345);
+        SubtensorModule::set_max_allowed_uids(netuid, 64);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        SubtensorModule::set_registrations_this_interval(netuid, 3);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, U256::from(7));
+        assert_eq!(SubtensorModule::capture_subnet_config(netuid + 1), None);
+        SubtensorModule::capture_subnet_config(netuid)
+            .unwrap()
+            .encode()
+    });
+
+    new_test_ext(1).execute_with(|| {
+        let snapshot = SubnetConfigSnapshot::<Test>::decode(&mut &encoded[..]).unwrap();
+        SubtensorModule::restore_subnet_config(snapshot.clone());
+        assert!(SubtensorModule::if_subnet_exist(netuid));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 13);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 100);
+        assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 12_345);
+        assert_eq!(SubtensorModule::get_max_allowed_uids(netuid), 64);
+        assert!(SubtensorModule::get_commit_reveal_weights_enabled(netuid));
+        assert_eq!(SubtensorModule::get_registrations_this_interval(netuid), 3);
+        assert_eq!(
+            pallet_subtensor::SubnetOwner::<Test>::get(netuid),
+            U256::from(7)
+        );
+        assert_eq!(
+            SubtensorModule::capture_subnet_config(netuid),
+            Some(snapshot)
+        );
+    });
+}
+
+#[test]
+fn test_identities() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(10);
+        let new_coldkey = U256::from(11);
+        let other = U256::from(12);
+        let deposit = SubtensorModule::get_identity_deposit();
+        let identity = pallet_subtensor::ChainIdentity {
+            name: "validator".into(),
+            url: "https://example.com".into(),
+            ..Default::default()
+        };
+        add_network(1, 0, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(1, coldkey);
+
+        assert_err!(
+            SubtensorModule::set_identity(RuntimeOrigin::signed(coldkey), identity.clone()),
+            Error::<Test>::NotEnoughBalanceForIdentityDeposit
+        );
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, deposit * 3);
+        assert_err!(
+            SubtensorModule::set_identity(
+                RuntimeOrigin::signed(coldkey),
+                pallet_subtensor::ChainIdentity {
+                    name: vec![b'a'; 257],
+                    ..Default::default()
+                }
+            ),
+            Error::<Test>::InvalidIdentity
+        );
+
+        // Updating the identity keeps the deposit taken when it was first set.
+        assert_ok!(SubtensorModule::set_identity(
+            RuntimeOrigin::signed(coldkey),
+            identity.clone()
+        ));
+        assert_ok!(SubtensorModule::set_identity(
+            RuntimeOrigin::signed(coldkey),
+            identity.clone()
+        ));
+        assert_eq!(
+            SubtensorModule::get_identity(&coldkey),
+            Some(identity.clone())
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), deposit * 2);
+
+        assert_err!(
+            SubtensorModule::set_subnet_identity(RuntimeOrigin::signed(other), 1, identity.clone()),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert_ok!(SubtensorModule::set_subnet_identity(
+            RuntimeOrigin::signed(coldkey),
+            1,
+            identity.clone()
+        ));
+        assert_eq!(
+            SubtensorModule::get_subnet_identity(1),
+            Some(identity.clone())
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), deposit);
+
+        // The identity and the deposits follow the coldkey through a swap.
+        assert_ok!(SubtensorModule::perform_swap_coldkey(
+            &coldkey,
+            &new_coldkey
+        ));
+        assert_eq!(SubtensorModule::get_identity(&coldkey), None);
+        assert_eq!(
+            SubtensorModule::get_identity(&new_coldkey),
+            Some(identity.clone())
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), deposit);
+
+        // An empty identity clears it and refunds the deposit.
+        assert_ok!(SubtensorModule::set_identity(
+            RuntimeOrigin::signed(new_coldkey),
+            Default::default()
+        ));
+        assert_eq!(SubtensorModule::get_identity(&new_coldkey), None);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&new_coldkey),
+            deposit * 2
+        );
+
+        // Removing the subnet refunds the owner that set its identity.
+        SubtensorModule::remove_network(1);
+        assert_eq!(SubtensorModule::get_subnet_identity(1), None);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&new_coldkey),
+            deposit * 3
+        );
+    });
+}
+
+#[test]
+fn test_burned_root_register() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        SubtensorModule::set_max_root_validators(1);
+        SubtensorModule::set_max_registrations_per_block(root_netuid, 1000);
+        SubtensorModule::set_max_burn_registrations_per_block(root_netuid, 1000);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, 1000);
+        SubtensorModule::set_immunity_period(root_netuid, 10);
+        SubtensorModule::set_burn(root_netuid, 1_000);
+
+        let incumbent = U256::from(1);
+        let newcomer_hotkey = U256::from(2);
+        let newcomer_coldkey = U256::from(3);
+        SubtensorModule::add_balance_to_coldkey_account(&incumbent, 10_000);
+        assert_ok!(SubtensorModule::burned_root_register(
+            <<Test as Config>::RuntimeOrigin>::signed(incumbent),
+            incumbent
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&incumbent), 9_000);
+        assert_eq!(SubtensorModule::get_rao_recycled(root_netuid), 1_000);
+        assert!(SubtensorModule::hotkey_is_delegate(&incumbent));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&incumbent, &incumbent, 5_000);
+
+        assert_err!(
+            SubtensorModule::burned_root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(newcomer_coldkey),
+                newcomer_hotkey
+            ),
+            Error::<Test>::RootValidatorInImmunity
+        );
+
+        // Past immunity the incumbent is replaced whatever its stake, once the burn is paid.
+        System::set_block_number(11);
+        assert_err!(
+            SubtensorModule::burned_root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(newcomer_coldkey),
+                newcomer_hotkey
+            ),
+            Error::<Test>::NotEnoughBalanceToStake
+        );
+        SubtensorModule::add_balance_to_coldkey_account(&newcomer_coldkey, 1_000);
+        assert_ok!(SubtensorModule::burned_root_register(
+            <<Test as Config>::RuntimeOrigin>::signed(newcomer_coldkey),
+            newcomer_hotkey
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&newcomer_coldkey), 0);
+        assert_eq!(SubtensorModule::get_rao_recycled(root_netuid), 2_000);
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(root_netuid, &newcomer_hotkey),
+            Ok(0)
+        );
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(root_netuid, &incumbent).is_err());
+        assert_eq!(
+            SubtensorModule::get_burn_registrations_this_interval(root_netuid),
+            2
+        );
+    });
+}
+
+#[test]
+fn test_root_weights_safe_mode() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        SubtensorModule::set_weights_set_rate_limit(root_netuid, 0);
+        SubtensorModule::set_max_registrations_per_block(root_netuid, 2);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, 2);
+        for netuid in 1..=3 {
+            add_network(netuid, 1, 0);
+        }
+
+        // A large validator and a small one.
+        let large_hotkey = U256::from(1);
+        let large_coldkey = U256::from(11);
+        let small_hotkey = U256::from(2);
+        let small_coldkey = U256::from(12);
+        for (hotkey, coldkey, stake) in [
+            (large_hotkey, large_coldkey, 1_000_000),
+            (small_hotkey, small_coldkey, 1_000),
+        ] {
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000);
+            assert_ok!(SubtensorModule::root_register(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                stake
+            ));
+        }
+        let set_root_weights = |coldkey: U256, hotkey: U256, values: Vec<u16>| {
+            SubtensorModule::set_root_weights(
+                RuntimeOrigin::signed(coldkey),
+                NetUid::from(root_netuid),
+                hotkey,
+                netuids(vec![1, 2, 3]),
+                values,
+                0,
+            )
+        };
+
+        // Degenerate weights are accepted while safe mode is off.
+        assert_ok!(set_root_weights(large_coldkey, large_hotkey, vec![0, 0, 0]));
+        assert_ok!(set_root_weights(large_coldkey, large_hotkey, vec![1, 0, 0]));
+
+        SubtensorModule::set_root_weights_safe_mode(true, 10_000, 2);
+        assert_err!(
+            set_root_weights(small_coldkey, small_hotkey, vec![0, 0, 0]),
+            Error::<Test>::DegenerateRootWeights
+        );
+        assert_err!(
+            set_root_weights(large_coldkey, large_hotkey, vec![0, 0, 0]),
+            Error::<Test>::DegenerateRootWeights
+        );
+
+        // Only validators above the stake threshold must spread their weights.
+        assert_ok!(set_root_weights(small_coldkey, small_hotkey, vec![1, 0, 0]));
+        assert_err!(
+            set_root_weights(large_coldkey, large_hotkey, vec![1, 0, 0]),
+            Error::<Test>::RootWeightsSpreadTooLow
+        );
+        assert_ok!(set_root_weights(large_coldkey, large_hotkey, vec![1, 1, 0]));
+
+        SubtensorModule::set_root_weights_safe_mode(true, 10_000, 3);
+        assert_err!(
+            set_root_weights(large_coldkey, large_hotkey, vec![1, 1, 0]),
+            Error::<Test>::RootWeightsSpreadTooLow
+        );
+        assert_ok!(set_root_weights(large_coldkey, large_hotkey, vec![1, 1, 1]));
+    });
+}
+
+#[test]
+fn test_root_stake_cap() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        SubtensorModule::set_max_registrations_per_block(root_netuid, 2);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, 2);
+        add_network(1, 1, 0);
+        add_network(2, 1, 0);
+
+        // A whale weighting subnet 1 and a smaller validator weighting subnet 2.
+        for (i, stake) in [(0_u16, 9_000_u64), (1, 1_000)] {
+            let hotkey = U256::from(i);
+            let coldkey = U256::from(i + 456);
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000);
+            assert_ok!(SubtensorModule::root_register(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                stake
+            ));
+            assert_ok!(SubtensorModule::set_root_weights(
+                RuntimeOrigin::signed(coldkey),
+                NetUid::from(root_netuid),
+                hotkey,
+                netuids(vec![i + 1]),
+                vec![u16::MAX],
+                0,
+            ));
+        }
+        let emission = || {
+            let values = SubtensorModule::compute_root_emission_values(
+                SubtensorModule::get_root_weights_sparse(),
+            )
+            .unwrap();
+            let netuids = SubtensorModule::get_all_subnet_netuids();
+            let of = |netuid: u16| {
+                netuids
+                    .iter()
+                    .position(|n| *n == netuid)
+                    .and_then(|i| values.get(i).copied())
+                    .unwrap()
+            };
+            (of(1), of(2))
+        };
+
+        let (whale_subnet, other_subnet) = emission();
+        assert!(whale_subnet > other_subnet);
+
+        // Capped just below half of the stake, both validators rank with the same share.
+        SubtensorModule::set_root_stake_cap(u16::MAX / 2);
+        let (whale_subnet, other_subnet) = emission();
+        assert!(whale_subnet.abs_diff(other_subnet) <= 1);
+
+        // The root epoch applies the cap as the computation above does.
+        SubtensorModule::set_tempo(root_netuid, 1);
+        assert_ok!(SubtensorModule::root_epoch(1_000_000_000));
+        assert_eq!(
+            (
+                SubtensorModule::get_emission_value(1),
+                SubtensorModule::get_emission_value(2)
+            ),
+            (whale_subnet, other_subnet)
+        );
+    });
+}
+
+#[test]
+fn test_migrate_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let old_netuid: u16 = 1;
+        let new_netuid: u16 = 7;
+        let (hotkey1, hotkey2, coldkey) = (U256::from(1), U256::from(2), U256::from(3));
+        add_network(old_netuid, 13, 0);
+        register_ok_neuron(old_netuid, hotkey1, coldkey, 0);
+        register_ok_neuron(old_netuid, hotkey2, coldkey, 100_000);
+        SubtensorModule::set_max_allowed_validators(old_netuid, 3);
+        take_subtensor_hook_calls();
+
+        assert_err!(
+            SubtensorModule::do_start_subnet_migration(0, new_netuid),
+            Error::<Test>::CannotMigrateRootNetwork
+        );
+        assert_err!(
+            SubtensorModule::do_start_subnet_migration(old_netuid, old_netuid),
+            Error::<Test>::NetuidAlreadyInUse
+        );
+        assert_ok!(SubtensorModule::do_start_subnet_migration(
+            old_netuid, new_netuid
+        ));
+        assert_err!(
+            SubtensorModule::do_start_subnet_migration(old_netuid, new_netuid),
+            Error::<Test>::SubnetMigrationInProgress
+        );
+        // The subnet is offline and neither netuid is handed out while it moves.
+        assert!(!SubtensorModule::if_subnet_exist(old_netuid));
+        assert!(!SubtensorModule::if_subnet_exist(new_netuid));
+        assert_eq!(SubtensorModule::get_next_available_netuid(), Some(2));
+
+        // It still counts toward the subnet limit, and neither accrues emission nor runs
+        // epochs while its state is split between the two netuids.
+        assert_eq!(SubtensorModule::get_num_subnets(), 1);
+        pallet_subtensor::EmissionValues::<Test>::insert(old_netuid, 1_000);
+        for block in 0..=14 {
+            SubtensorModule::generate_emission(block);
+        }
+        assert_eq!(SubtensorModule::get_pending_emission(old_netuid), 0);
+        assert_eq!(SubtensorModule::get_blocks_since_last_step(old_netuid), 0);
+
+        // Just enough weight for the first key.
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        SubtensorModule::advance_subnet_migration(db_weight.reads_writes(3, 5));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetMigrationProgressed(old_netuid, new_netuid, 0, 1),
+        ));
+        assert!(!SubtensorModule::if_subnet_exist(new_netuid));
+
+        SubtensorModule::advance_subnet_migration(frame_support::weights::Weight::MAX);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetMigrated(old_netuid, new_netuid),
+        ));
+        assert_eq!(SubtensorModule::get_subnet_migration(), None);
+        assert!(!SubtensorModule::if_subnet_exist(old_netuid));
+        assert!(SubtensorModule::if_subnet_exist(new_netuid));
+        assert_eq!(SubtensorModule::get_subnetwork_n(new_netuid), 2);
+        assert_eq!(SubtensorModule::get_subnetwork_n(old_netuid), 0);
+        assert_eq!(SubtensorModule::get_max_allowed_validators(new_netuid), 3);
+        assert_eq!(
+            SubtensorModule::get_hotkey_for_net_and_uid(new_netuid, 1),
+            Ok(hotkey2)
+        );
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(new_netuid, &hotkey1),
+            Ok(0)
+        );
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(old_netuid, &hotkey1).is_err());
+        assert_eq!(
+            SubtensorModule::get_registered_networks_for_hotkey(&hotkey1),
+            vec![new_netuid]
+        );
+        assert!(SubtensorModule::get_netuid_tombstone(old_netuid).is_some());
+        assert_eq!(
+            take_subtensor_hook_calls(),
+            vec![SubtensorHookCall::NetworkMigrated(old_netuid, new_netuid)]
+        );
+    });
+}
+
+#[test]
+fn test_remove_network_strips_root_weights() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000_000);
+        assert_ok!(SubtensorModule::root_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+        ));
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            1_000
+        ));
+        add_network(1, 10, 0);
+        add_network(2, 10, 0);
+        assert_ok!(SubtensorModule::set_root_weights(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            NetUid::from(root_netuid),
+            hotkey,
+            netuids(vec![1, 2]),
+            vec![1, 1],
+            0,
+        ));
+        SubtensorModule::set_emission_values(&[1], vec![1_000]).unwrap();
+
+        SubtensorModule::remove_network(1);
+        assert_eq!(SubtensorModule::get_subnet_emission_value(1), 0);
+
+        // A network registered under the removed netuid gets no weight from the old row.
+        add_network(1, 10, 0);
+        let rows = SubtensorModule::get_root_weights_sparse();
+        assert_eq!(rows[0].len(), 1);
+        assert_eq!(
+            SubtensorModule::get_all_subnet_netuids()[rows[0][0].0 as usize],
+            2
+        );
+    });
+}
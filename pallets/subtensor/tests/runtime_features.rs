@@ -0,0 +1,35 @@
+#![allow(clippy::unwrap_used)]
+
+mod mock;
+use codec::{Decode, Encode};
+use mock::*;
+use pallet_subtensor::runtime_features::{feature, RuntimeFeatures, RUNTIME_API_VERSION};
+
+#[test]
+fn test_get_runtime_features() {
+    new_test_ext(1).execute_with(|| {
+        let features = SubtensorModule::get_runtime_features();
+        let (major, minor, patch) = RUNTIME_API_VERSION;
+        assert_eq!(
+            (features.major.0, features.minor.0, features.patch.0),
+            (major, minor, patch)
+        );
+        assert!(features.supports(feature::COMMIT_REVEAL_WEIGHTS | feature::NEURON_INFO_LITE));
+        assert!(features.supports(feature::ALL));
+        assert!(!features.supports(1 << 127));
+
+        // Queries which moved between runtime APIs leave their bits unset.
+        assert!(!features.supports(feature::STORAGE_STATS));
+        assert!(!features.supports(feature::VALIDATOR_SET_EXPORT));
+
+        // Default builds compile in every optional subsystem.
+        assert_eq!(feature::COMPILED_OUT, 0);
+        assert!(features.supports(feature::AXON_TLS | feature::IDENTITIES));
+
+        // Clients decode the runtime API response back into the same struct.
+        assert_eq!(
+            RuntimeFeatures::decode(&mut features.encode().as_slice()).unwrap(),
+            features
+        );
+    });
+}
@@ -801,10 +801,69 @@ fn test_adjust_senate_events() {
         assert!(
             System::events().contains(&record(RuntimeEvent::SubtensorModule(
                 SubtensorEvent::SenateAdjusted {
-                    old_member: None,
+                    old_member: Some(hotkey_account_id),
                     new_member: replacement_hotkey_account_id
                 }
             )))
         );
     });
 }
+
+#[test]
+fn test_root_register_takes_seat_of_lowest_stake_member() {
+    new_test_ext().execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+
+        let netuid: u16 = 1;
+        let burn_cost = 1000;
+        let coldkey_account_id = U256::from(667);
+        let root_netuid = SubtensorModule::get_root_netuid();
+        let max_senate_size: u16 = SenateMaxMembers::get() as u16;
+
+        SubtensorModule::set_burn(netuid, burn_cost);
+        add_network(netuid, 13, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey_account_id, 10_000_000);
+        SubtensorModule::set_max_registrations_per_block(netuid, max_senate_size + 2);
+        SubtensorModule::set_target_registrations_per_interval(netuid, max_senate_size + 2);
+        SubtensorModule::set_max_registrations_per_block(root_netuid, max_senate_size + 2);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, max_senate_size + 2);
+
+        let register_with_stake = |hotkey: U256, stake: u64| {
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                netuid,
+                hotkey
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey,
+                stake
+            ));
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey
+            ));
+        };
+
+        // The genesis members are not root validators, so root validators take their seats
+        // once the Senate is full.
+        for i in 0..max_senate_size {
+            register_with_stake(U256::from(100 + i), 10_000 + u64::from(i));
+        }
+        assert_eq!(Senate::members().len(), max_senate_size as usize);
+        for member in 1..=5 {
+            assert!(!Senate::is_member(&U256::from(member)));
+        }
+
+        // A root validator with more stake than the lowest member takes its seat.
+        register_with_stake(U256::from(200), 10_005);
+        assert!(Senate::is_member(&U256::from(200)));
+        assert!(!Senate::is_member(&U256::from(100)));
+
+        // One with less stake than every member stays out of the Senate.
+        register_with_stake(U256::from(201), 5_000);
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(root_netuid, &U256::from(201)).is_ok());
+        assert!(!Senate::is_member(&U256::from(201)));
+        assert_eq!(Senate::members().len(), max_senate_size as usize);
+    });
+}
@@ -550,3 +550,553 @@ fn test_serving_is_invalid_ipv6_address() {
         ));
     });
 }
+
+#[test]
+fn test_serving_axon_multi() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(66), 0);
+        let serve = |index: u8, port: u16| {
+            SubtensorModule::serve_axon_multi(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                index,
+                2,
+                1676056785,
+                port,
+                4,
+                0,
+                0,
+                0,
+            )
+        };
+
+        // Replicas can only be added after the primary axon.
+        assert_eq!(serve(1, 200), Err(Error::<Test>::InvalidAxonIndex.into()));
+        assert_ok!(serve(0, 100));
+        // Indices must not leave a gap.
+        assert_eq!(serve(2, 200), Err(Error::<Test>::InvalidAxonIndex.into()));
+        for index in 1..8 {
+            assert_ok!(serve(index, 100 + u16::from(index)));
+        }
+        assert_eq!(serve(8, 200), Err(Error::<Test>::InvalidAxonIndex.into()));
+
+        // Existing entries are updated in place, the primary one through serve_axon too.
+        assert_ok!(serve(3, 300));
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            netuid,
+            2,
+            1676056785,
+            400,
+            4,
+            0,
+            0,
+            0
+        ));
+
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        let ports: Vec<u16> = SubtensorModule::get_axons(netuid, uid)
+            .iter()
+            .map(|axon| axon.port)
+            .collect();
+        assert_eq!(ports, vec![400, 101, 102, 300, 104, 105, 106, 107]);
+        assert_eq!(SubtensorModule::get_axon_info(netuid, &hotkey).port, 400);
+        assert!(SubtensorModule::get_axons(netuid, uid + 1).is_empty());
+    });
+}
+
+#[test]
+fn test_stale_axons_left_out_of_get_axons() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(66), 0);
+        SubtensorModule::set_serving_rate_limit(netuid, 0);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        let serve = |index: u8, port: u16| {
+            SubtensorModule::serve_axon_multi(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                index,
+                2,
+                1676056785,
+                port,
+                4,
+                0,
+                0,
+                0,
+            )
+        };
+        let ports = || -> Vec<u16> {
+            SubtensorModule::get_axons(netuid, uid)
+                .iter()
+                .map(|axon| axon.port)
+                .collect()
+        };
+
+        assert_ok!(serve(0, 100));
+        assert_ok!(serve(1, 101));
+        SubtensorModule::set_axon_ttl_blocks(netuid, 10);
+        step_block(5);
+        assert_ok!(serve(1, 101));
+        assert_eq!(ports(), vec![100, 101]);
+
+        // Only the axon served again within the TTL is left.
+        step_block(5);
+        assert_eq!(ports(), vec![101]);
+        assert_eq!(
+            SubtensorModule::get_axons_for_hotkey(netuid, &hotkey).len(),
+            2
+        );
+
+        // Serving again makes the axon active, and a zero TTL disables expiry.
+        assert_ok!(serve(0, 100));
+        assert_eq!(ports(), vec![100, 101]);
+        step_block(20);
+        assert!(ports().is_empty());
+        SubtensorModule::set_axon_ttl_blocks(netuid, 0);
+        assert_eq!(ports(), vec![100, 101]);
+    });
+}
+
+#[test]
+fn test_serving_axon_multi_rate_limited_per_entry() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(66), 0);
+        SubtensorModule::set_serving_rate_limit(netuid, 2);
+        let serve = |index: u8| {
+            SubtensorModule::serve_axon_multi(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                index,
+                2,
+                1676056785,
+                128,
+                4,
+                0,
+                0,
+                0,
+            )
+        };
+
+        assert_ok!(serve(0));
+        // A new replica has never been served, so it is not limited by the primary axon.
+        assert_ok!(serve(1));
+        assert_eq!(
+            serve(1),
+            Err(Error::<Test>::ServingRateLimitExceeded.into())
+        );
+        run_to_block(3);
+        assert_ok!(serve(1));
+    });
+}
+
+#[test]
+fn test_get_neurons_by_ip() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey1 = U256::from(1);
+        let hotkey2 = U256::from(2);
+        let netuid: u16 = 1;
+        let ip_a: u128 = 1676056785;
+        let ip_b: u128 = 1676056786;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey1, U256::from(66), 0);
+        register_ok_neuron(netuid, hotkey2, U256::from(67), 100000);
+        let serve = |hotkey: U256, index: u8, ip: u128| {
+            SubtensorModule::serve_axon_multi(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                index,
+                2,
+                ip,
+                128,
+                4,
+                0,
+                0,
+                0,
+            )
+        };
+        let neurons_by_ip = |ip: u128| {
+            let mut neurons = SubtensorModule::get_neurons_by_ip(ip);
+            neurons.sort();
+            neurons
+        };
+        let uid1 = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey1).unwrap();
+        let uid2 = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey2).unwrap();
+
+        assert_ok!(serve(hotkey1, 0, ip_a));
+        assert_ok!(serve(hotkey1, 1, ip_b));
+        assert_ok!(serve(hotkey2, 0, ip_a));
+        assert_eq!(
+            neurons_by_ip(ip_a),
+            vec![(netuid, uid1.min(uid2)), (netuid, uid1.max(uid2))]
+        );
+        assert_eq!(neurons_by_ip(ip_b), vec![(netuid, uid1)]);
+
+        // Moving the primary axon off an ip drops the neuron from its index.
+        assert_ok!(serve(hotkey1, 0, ip_b));
+        assert_eq!(neurons_by_ip(ip_a), vec![(netuid, uid2)]);
+        assert_eq!(neurons_by_ip(ip_b), vec![(netuid, uid1)]);
+        assert!(SubtensorModule::get_neurons_by_ip(1676056787).is_empty());
+    });
+}
+
+#[test]
+fn test_set_commitment() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        let commit = |commitment: Vec<u8>| {
+            SubtensorModule::set_commitment(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                commitment,
+            )
+        };
+
+        assert_eq!(
+            SubtensorModule::set_commitment(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid + 1,
+                vec![1]
+            ),
+            Err(Error::<Test>::SubNetworkDoesNotExist.into())
+        );
+        assert_eq!(
+            commit(vec![1]),
+            Err(Error::<Test>::HotKeyNotRegisteredInSubNet.into())
+        );
+        register_ok_neuron(netuid, hotkey, U256::from(66), 0);
+
+        // The commitment must fit in the subnet's limit.
+        SubtensorModule::set_max_commitment_size(netuid, 4);
+        assert_eq!(
+            commit(vec![0; 5]),
+            Err(Error::<Test>::CommitmentTooLarge.into())
+        );
+        assert_ok!(commit(vec![1, 2, 3, 4]));
+        assert_eq!(
+            SubtensorModule::get_commitment(netuid, &hotkey),
+            Some(vec![1, 2, 3, 4])
+        );
+
+        // Commitments are rate limited per hotkey and subnet.
+        SubtensorModule::set_commitment_rate_limit(netuid, 10);
+        assert_eq!(
+            commit(vec![5]),
+            Err(Error::<Test>::CommitmentRateLimitExceeded.into())
+        );
+        run_to_block(11);
+        assert_ok!(commit(vec![5]));
+        assert_eq!(
+            SubtensorModule::get_commitment(netuid, &hotkey),
+            Some(vec![5])
+        );
+
+        // An empty commitment clears it.
+        SubtensorModule::set_commitment_rate_limit(netuid, 0);
+        assert_ok!(commit(Vec::new()));
+        assert_eq!(SubtensorModule::get_commitment(netuid, &hotkey), None);
+    });
+}
+
+#[test]
+fn test_associate_evm_key() {
+    new_test_ext(1).execute_with(|| {
+        use sp_core::{ecdsa, Pair, H160};
+
+        let hotkey = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(66), 0);
+        run_to_block(20);
+
+        let evm_key = ecdsa::Pair::from_seed(&[7u8; 32]);
+        let sign = |block_number: u64| {
+            let digest = SubtensorModule::evm_key_association_digest(netuid, &hotkey, block_number);
+            let mut signature = [0u8; 65];
+            signature.copy_from_slice(evm_key.sign_prehashed(&digest).as_ref());
+            signature
+        };
+        // The address is the last 20 bytes of the keccak-256 of the uncompressed public key.
+        let digest = SubtensorModule::evm_key_association_digest(netuid, &hotkey, 20);
+        let public = sp_io::crypto::secp256k1_ecdsa_recover(&sign(20), &digest).unwrap();
+        let evm_address = H160::from_slice(
+            sp_io::hashing::keccak_256(&public)
+                .get(12..)
+                .unwrap_or_default(),
+        );
+        let associate = |address: H160, block_number: u64, signature: [u8; 65]| {
+            SubtensorModule::associate_evm_key(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                address,
+                block_number,
+                signature,
+            )
+        };
+
+        // The signature must recover to the address being associated.
+        assert_eq!(
+            associate(H160::repeat_byte(1), 20, sign(20)),
+            Err(Error::<Test>::InvalidEvmKeySignature.into())
+        );
+        // Proofs naming a block in the future or too long ago are refused.
+        assert_eq!(
+            associate(evm_address, 21, sign(21)),
+            Err(Error::<Test>::EvmKeySignatureExpired.into())
+        );
+        run_to_block(20 + pallet_subtensor::EVM_KEY_SIGNATURE_VALIDITY + 1);
+        assert_eq!(
+            associate(evm_address, 20, sign(20)),
+            Err(Error::<Test>::EvmKeySignatureExpired.into())
+        );
+
+        let block = SubtensorModule::get_current_block_as_u64();
+        assert_ok!(associate(evm_address, block, sign(block)));
+        assert_eq!(
+            SubtensorModule::get_associated_evm_address(netuid, &hotkey),
+            Some(evm_address)
+        );
+        assert_eq!(
+            SubtensorModule::get_evm_key_associations(netuid),
+            vec![(hotkey, evm_address, block)]
+        );
+
+        // The association does not survive the hotkey's deregistration.
+        SubtensorModule::replace_neuron(netuid, 0, &U256::from(2), block);
+        assert_eq!(
+            SubtensorModule::get_associated_evm_address(netuid, &hotkey),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_attest_axon_reachability() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_axon_reachability_half_life(10);
+        // Uids 0 and 1 hold permits, uid 2 holds most of the stake without one.
+        for (uid, stake, permit) in [(0u16, 300u64, true), (1, 100, true), (2, 600, false)] {
+            let hotkey = U256::from(uid);
+            SubtensorModule::append_neuron(netuid, &hotkey, 0);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(100 + uid),
+                &hotkey,
+                stake,
+            );
+            SubtensorModule::set_validator_permit_for_uid(netuid, uid, permit);
+        }
+
+        let attest = |hotkey: u16, uid: u16, reachable: bool| {
+            SubtensorModule::attest_axon(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(hotkey)),
+                netuid,
+                uid,
+                reachable,
+            )
+        };
+        assert_eq!(
+            attest(2, 0, true),
+            Err(Error::<Test>::NeuronNoValidatorPermit.into())
+        );
+        assert_eq!(
+            attest(0, 3, true),
+            Err(Error::<Test>::AttestedUidNotFound.into())
+        );
+        assert_eq!(SubtensorModule::get_axon_reachability(netuid, 2), 0);
+
+        // The score is the share of the attesting stake that reached the axon.
+        assert_ok!(attest(0, 2, true));
+        assert_ok!(attest(1, 2, false));
+        assert_eq!(SubtensorModule::get_axon_reachability(netuid, 2), 49151);
+
+        // A validator attesting again replaces its previous attestation.
+        assert_ok!(attest(1, 2, true));
+        assert_eq!(SubtensorModule::get_axon_attestations(netuid, 2).len(), 2);
+        assert_eq!(SubtensorModule::get_axon_reachability(netuid, 2), u16::MAX);
+
+        // Reachable attestations decay until attested again.
+        System::set_block_number(11);
+        assert_eq!(SubtensorModule::get_axon_reachability(netuid, 2), 32767);
+        assert_ok!(attest(0, 2, true));
+        assert_eq!(SubtensorModule::get_axon_reachability(netuid, 2), 57343);
+
+        // Attestations of validators losing their permit no longer count.
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, false);
+        assert_eq!(SubtensorModule::get_axon_reachability(netuid, 2), u16::MAX);
+    });
+}
+
+#[test]
+fn test_remove_network_drops_axon_ip_index() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(66);
+        let ip: u128 = 1676056785;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            netuid,
+            2,
+            ip,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        assert!(pallet_subtensor::AxonIpIndex::<Test>::get(ip, (netuid, hotkey)).is_some());
+
+        SubtensorModule::remove_network(netuid);
+        assert!(!SubtensorModule::has_axon_info(netuid, &hotkey));
+        assert!(pallet_subtensor::AxonIpIndex::<Test>::get(ip, (netuid, hotkey)).is_none());
+    });
+}
+
+#[test]
+fn test_clear_neuron() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(66);
+        let ip: u128 = 1676056785;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::set_serving_rate_limit(netuid, 2);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            netuid,
+            2,
+            ip,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        assert_ok!(SubtensorModule::serve_prometheus(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+            netuid,
+            2,
+            ip,
+            128,
+            4
+        ));
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            0,
+            pallet_subtensor::WeightsRow::from(vec![(0, u16::MAX)]),
+        );
+
+        // Only the owning coldkey clears the neuron.
+        assert_eq!(
+            SubtensorModule::clear_neuron(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                hotkey
+            ),
+            Err(Error::<Test>::NonAssociatedColdKey.into())
+        );
+        assert_ok!(SubtensorModule::clear_neuron(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+        assert!(!SubtensorModule::has_axon_info(netuid, &hotkey));
+        assert!(!SubtensorModule::has_prometheus_info(netuid, &hotkey));
+        assert!(pallet_subtensor::Weights::<Test>::get(netuid, 0).is_empty());
+        assert!(pallet_subtensor::AxonIpIndex::<Test>::get(ip, (netuid, hotkey)).is_none());
+        // The neuron keeps its uid.
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey),
+            Ok(0)
+        );
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NeuronCleared(netuid, hotkey),
+        ));
+
+        // Clearing again waits for the serving rate limit.
+        assert_eq!(
+            SubtensorModule::clear_neuron(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey
+            ),
+            Err(Error::<Test>::ServingRateLimitExceeded.into())
+        );
+        run_to_block(3);
+        assert_ok!(SubtensorModule::clear_neuron(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+    });
+}
+
+#[test]
+fn test_serve_axon_tls() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(66);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        let serve = |certificate: Vec<u8>| {
+            SubtensorModule::serve_axon_tls(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey),
+                netuid,
+                2,
+                1676056785,
+                128,
+                4,
+                0,
+                0,
+                0,
+                certificate,
+            )
+        };
+
+        assert_eq!(serve(vec![]), Err(Error::<Test>::InvalidCertificate.into()));
+        assert_eq!(
+            serve(vec![
+                1;
+                pallet_subtensor::MAX_NEURON_CERTIFICATE_BYTES as usize
+                    + 1
+            ]),
+            Err(Error::<Test>::InvalidCertificate.into())
+        );
+        assert!(!SubtensorModule::has_axon_info(netuid, &hotkey));
+
+        assert_ok!(serve(vec![7; 32]));
+        assert_eq!(SubtensorModule::get_axon_info(netuid, &hotkey).port, 128);
+        assert_eq!(
+            SubtensorModule::get_neuron_certificate(netuid, 0),
+            Some(vec![7; 32])
+        );
+        assert_eq!(SubtensorModule::get_neuron_certificate(netuid, 1), None);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NeuronCertificateSet(netuid, hotkey),
+        ));
+
+        // Clearing the neuron drops its certificate along with its axon.
+        assert_ok!(SubtensorModule::clear_neuron(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+        assert_eq!(SubtensorModule::get_neuron_certificate(netuid, 0), None);
+    });
+}
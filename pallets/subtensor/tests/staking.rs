@@ -1,6 +1,7 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::arithmetic_side_effects)]
 
+use codec::Encode;
 use frame_support::pallet_prelude::{
     InvalidTransaction, TransactionValidity, TransactionValidityError,
 };
@@ -13,9 +14,10 @@ use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays
 use frame_support::sp_runtime::DispatchError;
 use mock::*;
 use pallet_balances::Call as BalancesCall;
+use pallet_subtensor::stake_info::{PendingUnstakeKind, SubnetEmissionRate};
 use pallet_subtensor::*;
 use sp_core::{H256, U256};
-use sp_runtime::traits::SignedExtension;
+use sp_runtime::traits::{Dispatchable, SignedExtension};
 
 /***********************************************************
     staking::add_stake() tests
@@ -1200,6 +1202,68 @@ fn test_delegate_stake_division_by_zero_check() {
     });
 }
 
+#[test]
+fn test_total_hotkey_stake_matches_stake() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkeys = [U256::from(1), U256::from(2)];
+        let owner = U256::from(3);
+        let nominator = U256::from(4);
+        // The ground truth the cached TotalHotkeyStake must equal.
+        let summed_stake = |hotkey: &U256| -> u64 {
+            pallet_subtensor::Stake::<Test>::iter_prefix_values(hotkey).sum()
+        };
+        let assert_consistent = || {
+            for hotkey in hotkeys.iter() {
+                assert_eq!(
+                    SubtensorModule::get_total_stake_for_hotkey(hotkey),
+                    summed_stake(hotkey)
+                );
+            }
+        };
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_target_stakes_per_interval(10);
+        for hotkey in hotkeys.iter() {
+            register_ok_neuron(netuid, *hotkey, owner, 0);
+        }
+        assert_ok!(SubtensorModule::become_delegate(
+            RuntimeOrigin::signed(owner),
+            hotkeys[0]
+        ));
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 100_000);
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 100_000);
+
+        for hotkey in hotkeys.iter() {
+            assert_ok!(SubtensorModule::add_stake(
+                RuntimeOrigin::signed(owner),
+                *hotkey,
+                10_000
+            ));
+        }
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkeys[0],
+            20_000
+        ));
+        assert_consistent();
+
+        // Emission through a delegate and a non-delegate.
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkeys[0], 1_000, 3_000);
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkeys[1], 1_000, 3_000);
+        assert_consistent();
+
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkeys[0],
+            5_000
+        ));
+        assert_consistent();
+
+        step_block(3);
+        assert_consistent();
+    });
+}
+
 #[test]
 #[cfg(not(tarpaulin))]
 fn test_full_with_delegating() {
@@ -2455,10 +2519,123 @@ fn test_faucet_ok() {
     });
 }
 
+#[test]
+fn test_faucet_rate_limit() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(123560);
+        let faucet_work = |coldkey: U256, start_nonce: u64| {
+            let block_number = SubtensorModule::get_current_block_as_u64();
+            let mut nonce: u64 = start_nonce;
+            let mut work: H256 = SubtensorModule::create_seal_hash(block_number, nonce, &coldkey);
+            while !SubtensorModule::hash_meets_difficulty(&work, U256::from(1_000_000)) {
+                nonce += 1;
+                work = SubtensorModule::create_seal_hash(block_number, nonce, &coldkey);
+            }
+            (block_number, nonce, SubtensorModule::hash_to_vec(work))
+        };
+        SubtensorModule::set_faucet_rate_limit(5);
+
+        let (block_number, nonce, work) = faucet_work(coldkey, 0);
+        assert_ok!(SubtensorModule::do_faucet(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            block_number,
+            nonce,
+            work
+        ));
+        let balance = SubtensorModule::get_coldkey_balance(&coldkey);
+
+        // The coldkey cannot use the faucet again before FaucetRateLimit blocks passed.
+        let (block_number, nonce, work) = faucet_work(coldkey, nonce + 1);
+        assert_noop!(
+            SubtensorModule::do_faucet(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                block_number,
+                nonce,
+                work
+            ),
+            Error::<Test>::FaucetRateLimitExceeded
+        );
+
+        // Other coldkeys are not limited by it.
+        let other_coldkey = U256::from(123561);
+        let (block_number, nonce, work) = faucet_work(other_coldkey, 0);
+        assert_ok!(SubtensorModule::do_faucet(
+            <<Test as Config>::RuntimeOrigin>::signed(other_coldkey),
+            block_number,
+            nonce,
+            work
+        ));
+
+        step_block(5);
+        let (block_number, nonce, work) = faucet_work(coldkey, 0);
+        assert_ok!(SubtensorModule::do_faucet(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            block_number,
+            nonce,
+            work
+        ));
+        assert!(SubtensorModule::get_coldkey_balance(&coldkey) > balance);
+    });
+}
+
 /// This test ensures that the clear_small_nominations function works as expected.
 /// It creates a network with two hotkeys and two coldkeys, and then registers a nominator account for each hotkey.
 /// When we call set_nominator_min_required_stake, it should clear all small nominations that are below the minimum required stake.
 /// Run this test using: cargo test --package pallet-subtensor --test staking test_clear_small_nominations
+#[test]
+fn test_clear_small_nominations_extrinsic() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let (small, large) = (U256::from(3), U256::from(4));
+        let caller = U256::from(5);
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 5);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&small, &hotkey, 5);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&large, &hotkey, 50);
+        SubtensorModule::set_nominator_min_required_stake(10);
+
+        assert_noop!(
+            SubtensorModule::clear_small_nominations(
+                <<Test as Config>::RuntimeOrigin>::signed(caller),
+                vec![
+                    (hotkey, small);
+                    pallet_subtensor::MAX_NOMINATIONS_CLEARED_PER_CALL as usize + 1
+                ]
+            ),
+            Error::<Test>::TooManyNominationsToClear
+        );
+
+        // Only the small nomination is cleared, the owner's own stake stays.
+        assert_ok!(SubtensorModule::clear_small_nominations(
+            <<Test as Config>::RuntimeOrigin>::signed(caller),
+            vec![
+                (hotkey, owner),
+                (hotkey, small),
+                (hotkey, large),
+                (hotkey, caller)
+            ]
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&owner, &hotkey),
+            5
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&small, &hotkey),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&large, &hotkey),
+            50
+        );
+        assert_eq!(Balances::free_balance(small), 5);
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 55);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SmallNominationCleared(hotkey, small, 5),
+        ));
+    });
+}
+
 #[test]
 fn test_clear_small_nominations() {
     new_test_ext(0).execute_with(|| {
@@ -2547,7 +2724,7 @@ fn test_clear_small_nominations() {
 
         // Run clear all small nominations when min stake is zero (noop)
         SubtensorModule::set_nominator_min_required_stake(0);
-        SubtensorModule::clear_small_nominations();
+        SubtensorModule::clear_all_small_nominations();
         assert_eq!(
             SubtensorModule::get_stake_for_coldkey_and_hotkey(&cold1, &hot1),
             1
@@ -2576,7 +2753,7 @@ fn test_clear_small_nominations() {
         SubtensorModule::set_nominator_min_required_stake(10);
 
         // Run clear all small nominations (removes delegations under 10)
-        SubtensorModule::clear_small_nominations();
+        SubtensorModule::clear_all_small_nominations();
         assert_eq!(
             SubtensorModule::get_stake_for_coldkey_and_hotkey(&cold1, &hot1),
             1
@@ -3083,6 +3260,48 @@ fn test_delegate_take_can_not_be_increased_beyond_limit() {
     });
 }
 
+// Verify delegate take can not be increased to a value still below MinTake
+#[test]
+fn test_delegate_take_can_not_be_increased_below_min_take() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey0, 100000);
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+
+        let old_min_take = SubtensorModule::get_min_take();
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            old_min_take
+        ));
+
+        // Governance raises the floor above the current take.
+        let new_min_take = old_min_take + 1_000;
+        SubtensorModule::set_min_delegate_take(new_min_take);
+        step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+
+        assert_eq!(
+            SubtensorModule::do_increase_take(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0,
+                new_min_take - 1
+            ),
+            Err(Error::<Test>::DelegateTakeTooLow.into())
+        );
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), old_min_take);
+
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            new_min_take
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), new_min_take);
+    });
+}
+
 // Test rate-limiting on increase_take
 #[test]
 fn test_rate_limits_enforced_on_increase_take() {
@@ -3789,6 +4008,126 @@ fn test_concurrent_arbitrated_coldkey_swaps() {
     });
 }
 
+#[test]
+fn test_schedule_swap_coldkey_with_arbitration() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let new_coldkey = U256::from(3);
+        let other_coldkey = U256::from(4);
+        let netuid: u16 = 1;
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::set_arbitration_period(5);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 2 * swap_cost + 1_000);
+
+        // The swap is charged and waits for the arbitration period.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            new_coldkey
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            swap_cost + 1_000
+        );
+        assert_eq!(
+            SubtensorModule::get_scheduled_coldkey_swap(&coldkey),
+            (vec![new_coldkey], 6)
+        );
+        assert_err!(
+            SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                new_coldkey
+            ),
+            Error::<Test>::DuplicateColdkey
+        );
+
+        // A second destination disputes the swap, holding it past the period.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            other_coldkey
+        ));
+        run_to_block(6);
+        assert_eq!(
+            SubtensorModule::get_scheduled_coldkey_swap(&coldkey),
+            (vec![new_coldkey, other_coldkey], u64::MAX)
+        );
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey),
+            coldkey
+        );
+
+        // Governance settles the dispute.
+        assert_noop!(
+            SubtensorModule::arbitrate_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                coldkey,
+                Some(new_coldkey)
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::arbitrate_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                coldkey,
+                Some(U256::from(5))
+            ),
+            Error::<Test>::InvalidColdkeySwapDestination
+        );
+        assert_ok!(SubtensorModule::arbitrate_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            coldkey,
+            Some(new_coldkey)
+        ));
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey),
+            new_coldkey
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), 1_000);
+        assert_eq!(
+            SubtensorModule::get_scheduled_coldkey_swap(&coldkey),
+            (vec![], 0)
+        );
+        assert_noop!(
+            SubtensorModule::arbitrate_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                coldkey,
+                None
+            ),
+            Error::<Test>::NoColdkeySwapScheduled
+        );
+    });
+}
+
+#[test]
+fn test_cancel_scheduled_swap_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let new_coldkey = U256::from(3);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::set_arbitration_period(5);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, swap_cost + 1_000);
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            new_coldkey
+        ));
+        assert_ok!(SubtensorModule::arbitrate_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            coldkey,
+            None
+        ));
+        assert!(ColdkeysToSwapAtBlock::<Test>::get(6).is_empty());
+
+        // Nothing is swapped once the period passes.
+        run_to_block(10);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 1_000);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), 0);
+        assert!(!SubtensorModule::coldkey_in_arbitration(&coldkey));
+    });
+}
+
 // #[test]
 // fn test_get_remaining_arbitration_period() {
 //     new_test_ext(1).execute_with(|| {
@@ -4453,6 +4792,113 @@ fn test_get_total_delegated_stake_exclude_owner_stake() {
     });
 }
 
+#[test]
+fn test_delegate_indexes_track_stake_changes() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = 1u16;
+        let delegate_coldkey = U256::from(1);
+        let delegate_hotkey = U256::from(2);
+        let delegator1 = U256::from(3);
+        let delegator2 = U256::from(4);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, delegate_hotkey, delegate_coldkey, 0);
+        assert_ok!(SubtensorModule::become_delegate(
+            RuntimeOrigin::signed(delegate_coldkey),
+            delegate_hotkey
+        ));
+        assert_eq!(
+            SubtensorModule::get_delegate_nominator_count(&delegate_hotkey),
+            0
+        );
+
+        // Owner stake counts as a nominator but not as delegated stake.
+        SubtensorModule::add_balance_to_coldkey_account(&delegate_coldkey, 10_000);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(delegate_coldkey),
+            delegate_hotkey,
+            1_000
+        ));
+        SubtensorModule::add_balance_to_coldkey_account(&delegator1, 10_000);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(delegator1),
+            delegate_hotkey,
+            2_000
+        ));
+        SubtensorModule::add_balance_to_coldkey_account(&delegator2, 10_000);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(delegator2),
+            delegate_hotkey,
+            3_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_delegate_nominator_count(&delegate_hotkey),
+            3
+        );
+        assert_eq!(
+            SubtensorModule::get_total_hotkey_delegated_stake(&delegate_hotkey),
+            5_000
+        );
+
+        // Partial and full unstakes.
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(delegator1),
+            delegate_hotkey,
+            500
+        ));
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(delegator2),
+            delegate_hotkey,
+            3_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_delegate_nominator_count(&delegate_hotkey),
+            2
+        );
+        assert_eq!(
+            SubtensorModule::get_total_hotkey_delegated_stake(&delegate_hotkey),
+            1_500
+        );
+
+        // The indexes agree with a full scan of the stake map.
+        let expected_count = Stake::<Test>::iter_prefix(delegate_hotkey)
+            .filter(|(_, stake)| *stake > 0)
+            .count() as u32;
+        let expected_delegated: u64 = Stake::<Test>::iter_prefix(delegate_hotkey)
+            .filter(|(coldkey, _)| *coldkey != delegate_coldkey)
+            .map(|(_, stake)| stake)
+            .sum();
+        assert_eq!(
+            SubtensorModule::get_delegate_nominator_count(&delegate_hotkey),
+            expected_count
+        );
+        assert_eq!(
+            SubtensorModule::get_total_hotkey_delegated_stake(&delegate_hotkey),
+            expected_delegated
+        );
+
+        // The delegated view only lists delegates the account has stake on.
+        let delegated = SubtensorModule::get_delegated(delegator1.encode());
+        assert_eq!(delegated.len(), 1);
+        assert!(SubtensorModule::get_delegated(delegator2.encode()).is_empty());
+
+        // Swapping the owner coldkey moves the owner stake without counting it as delegated.
+        let new_delegate_coldkey = U256::from(5);
+        assert_ok!(SubtensorModule::perform_swap_coldkey(
+            &delegate_coldkey,
+            &new_delegate_coldkey
+        ));
+        assert_eq!(
+            SubtensorModule::get_delegate_nominator_count(&delegate_hotkey),
+            2
+        );
+        assert_eq!(
+            SubtensorModule::get_total_hotkey_delegated_stake(&delegate_hotkey),
+            1_500
+        );
+    });
+}
+
 #[test]
 fn test_do_schedule_coldkey_swap_subnet_owner_skips_min_balance() {
     new_test_ext(1).execute_with(|| {
@@ -4719,3 +5165,1643 @@ fn test_do_schedule_coldkey_swap_regular_user_passes_min_balance() {
         );
     });
 }
+
+#[test]
+fn test_stake_added_event_indexed_by_hotkey_subnets() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(1, 13, 0);
+        add_network(2, 13, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+        register_ok_neuron(2, hotkey, coldkey, 100);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            1_000
+        ));
+
+        let record = System::events()
+            .into_iter()
+            .find(|record| {
+                record.event
+                    == RuntimeEvent::SubtensorModule(pallet_subtensor::Event::StakeAdded(
+                        hotkey, 1_000,
+                    ))
+            })
+            .unwrap();
+        // One topic for each subnet the hotkey is registered on.
+        assert_eq!(record.topics.len(), 2);
+        assert!(record
+            .topics
+            .contains(&SubtensorModule::subnet_event_topic(1)));
+        assert!(record
+            .topics
+            .contains(&SubtensorModule::subnet_event_topic(2)));
+    });
+}
+
+#[test]
+fn test_stake_all_minus_fees() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(1, 13, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+        SubtensorModule::set_fee_reserve(1_000);
+
+        // Nothing is left to stake once the reserve is kept back.
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000);
+        assert_noop!(
+            SubtensorModule::stake_all_minus_fees(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey
+            ),
+            Error::<Test>::NotEnoughBalanceToStake
+        );
+
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 9_000);
+        assert_ok!(SubtensorModule::stake_all_minus_fees(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            9_000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 1_000);
+    });
+}
+
+#[test]
+fn test_add_stake_keeps_fee_reserve() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(1, 13, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+        SubtensorModule::set_fee_reserve(1_000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+
+        assert_ok!(SubtensorModule::set_keep_fee_reserve(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            true
+        ));
+        assert_noop!(
+            SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                9_500
+            ),
+            Error::<Test>::FeeReserveNotKept
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            9_000
+        ));
+
+        // Without the setting the reserve can be staked as well.
+        assert_ok!(SubtensorModule::set_keep_fee_reserve(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            false
+        ));
+        assert!(!KeepFeeReserve::<Test>::contains_key(coldkey));
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            500
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 500);
+    });
+}
+
+#[test]
+fn test_coldkey_emission_summary() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let hotkey1 = U256::from(2);
+        let coldkey0 = U256::from(3);
+        let coldkey1 = U256::from(4);
+        SubtensorModule::create_account_if_non_existent(&coldkey0, &hotkey0);
+        SubtensorModule::create_account_if_non_existent(&coldkey1, &hotkey1);
+        Delegates::<Test>::insert(hotkey0, u16::MAX / 2);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey0, &hotkey0, 100);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey1, &hotkey0, 100);
+
+        // Through a delegate, the owner gets the incentive and the take, and every
+        // nominator gets dividends.
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkey0, 200, 1_000);
+        let owner_emission = SubtensorModule::get_coldkey_emission(&coldkey0);
+        let nominator_emission = SubtensorModule::get_coldkey_emission(&coldkey1);
+        assert_eq!(owner_emission.incentive, 200);
+        assert_eq!(
+            owner_emission.delegate_take,
+            SubtensorModule::calculate_delegate_proportional_take(&hotkey0, 1_000)
+        );
+        assert!(owner_emission.delegate_take > 0);
+        assert_eq!(nominator_emission.incentive, 0);
+        assert_eq!(
+            nominator_emission.dividends,
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey1, &hotkey0) - 100
+        );
+        assert_eq!(
+            owner_emission.dividends + owner_emission.delegate_take + nominator_emission.dividends,
+            1_000
+        );
+
+        // Through a plain hotkey, everything goes to its owner.
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkey1, 50, 70);
+        let emission = SubtensorModule::get_coldkey_emission(&coldkey1);
+        assert_eq!(emission.incentive, 50);
+        assert_eq!(emission.dividends, nominator_emission.dividends + 70);
+        assert_eq!(emission.delegate_take, 0);
+
+        assert_eq!(
+            SubtensorModule::get_coldkey_emission_summary(coldkey1.encode()),
+            Some(emission)
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_emission_summary(vec![0u8; 31]),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_banned_hotkey_stake_wind_down() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let nominators: Vec<U256> = (3..7).map(U256::from).collect();
+        add_network(netuid, 1, 0);
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        Delegates::<Test>::insert(hotkey, u16::MAX / 2);
+        for coldkey in nominators.iter().chain([owner].iter()) {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(coldkey, &hotkey, 1_000);
+        }
+        SubtensorModule::set_stake_wind_down_chunk_size(2);
+        SubtensorModule::set_target_stakes_per_interval(0);
+
+        assert_ok!(SubtensorModule::do_ban_hotkey(&hotkey));
+        assert_eq!(
+            SubtensorModule::do_ban_hotkey(&hotkey).map_err(DispatchError::from),
+            Err(Error::<Test>::HotkeyAlreadyBanned.into())
+        );
+
+        // The hotkey can neither receive stake nor register.
+        SubtensorModule::add_balance_to_coldkey_account(&owner, 10_000);
+        assert_err!(
+            SubtensorModule::add_stake(RuntimeOrigin::signed(owner), hotkey, 1_000),
+            Error::<Test>::HotkeyIsBanned
+        );
+        assert_err!(
+            SubtensorModule::burned_register(RuntimeOrigin::signed(owner), netuid, hotkey),
+            Error::<Test>::HotkeyIsBanned
+        );
+
+        // The stake can be removed without the unstake rate limit.
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(owner),
+            hotkey,
+            400
+        ));
+
+        // The rest is returned in chunks.
+        let staked_coldkeys = || {
+            nominators
+                .iter()
+                .chain([owner].iter())
+                .filter(|coldkey| {
+                    SubtensorModule::get_stake_for_coldkey_and_hotkey(coldkey, &hotkey) > 0
+                })
+                .count()
+        };
+        assert_eq!(staked_coldkeys(), 5);
+        step_block(1);
+        assert_eq!(staked_coldkeys(), 3);
+        step_block(1);
+        assert_eq!(staked_coldkeys(), 1);
+        assert_eq!(HotkeysWindingDown::<Test>::get(), vec![hotkey]);
+        step_block(1);
+        assert_eq!(staked_coldkeys(), 0);
+        assert!(HotkeysWindingDown::<Test>::get().is_empty());
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 0);
+        for coldkey in nominators.iter() {
+            assert_eq!(SubtensorModule::get_coldkey_balance(coldkey), 1_000);
+        }
+        assert_eq!(SubtensorModule::get_coldkey_balance(&owner), 11_000);
+        assert!(SubtensorModule::is_hotkey_banned(&hotkey));
+    });
+}
+
+#[test]
+fn test_banned_hotkey_is_deregistered_and_unbanned() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let other = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        register_ok_neuron(netuid, other, coldkey, 100_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        SubtensorModule::set_stake_wind_down_chunk_size(0);
+
+        // The ban frees the uid of the hotkey, so it earns no more emission, and leaves its
+        // stake to the wind down.
+        assert_ok!(SubtensorModule::do_ban_hotkey(&hotkey));
+        assert!(!SubtensorModule::is_hotkey_registered_on_any_network(
+            &hotkey
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 1);
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &other),
+            Ok(0)
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 1_000);
+
+        // A banned hotkey can not be swapped from or to.
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000);
+        assert_err!(
+            SubtensorModule::do_swap_hotkey(
+                RuntimeOrigin::signed(coldkey),
+                &hotkey,
+                &U256::from(4)
+            ),
+            Error::<Test>::HotkeyIsBanned
+        );
+        assert_err!(
+            SubtensorModule::do_swap_hotkey(RuntimeOrigin::signed(coldkey), &other, &hotkey),
+            Error::<Test>::HotkeyIsBanned
+        );
+
+        // Unbanning stops the wind down and keeps the stake on the hotkey.
+        assert_ok!(SubtensorModule::do_unban_hotkey(&hotkey));
+        assert_eq!(
+            SubtensorModule::do_unban_hotkey(&hotkey).map_err(DispatchError::from),
+            Err(Error::<Test>::HotkeyNotBanned.into())
+        );
+        assert!(!SubtensorModule::is_hotkey_banned(&hotkey));
+        assert!(HotkeysWindingDown::<Test>::get().is_empty());
+        SubtensorModule::set_stake_wind_down_chunk_size(10);
+        step_block(1);
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 1_000);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::HotkeyUnbanned(hotkey),
+        ));
+    });
+}
+
+#[test]
+fn test_get_stake_distribution() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let delegate = U256::from(1);
+        let miner = U256::from(2);
+        let owner = U256::from(3);
+        let nominator = U256::from(4);
+        add_network(netuid, tempo, 0);
+        register_ok_neuron(netuid, delegate, owner, 0);
+        register_ok_neuron(netuid, miner, nominator, 100_000);
+        Delegates::<Test>::insert(delegate, u16::MAX / 2);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &delegate, 500);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &delegate, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &miner, 300);
+        Emission::<Test>::insert(netuid, vec![26, 13]);
+
+        let distribution = SubtensorModule::get_stake_distribution(nominator.encode());
+        assert_eq!(distribution.len(), 2);
+        let on_delegate = distribution.iter().find(|d| d.hotkey == delegate).unwrap();
+        assert_eq!(on_delegate.stake, 1_000.into());
+        assert_eq!(on_delegate.total_hotkey_stake, 1_500.into());
+        assert_eq!(on_delegate.take, (u16::MAX / 2).into());
+        assert_eq!(
+            on_delegate.subnets,
+            vec![SubnetEmissionRate {
+                netuid: netuid.into(),
+                uid: 0.into(),
+                emission: 26.into(),
+                emission_per_day: (26 * (7200 / 13)).into(),
+            }]
+        );
+        let on_miner = distribution.iter().find(|d| d.hotkey == miner).unwrap();
+        assert_eq!(on_miner.stake, 300.into());
+        assert_eq!(on_miner.take, 0.into());
+        assert_eq!(on_miner.subnets.len(), 1);
+
+        assert!(SubtensorModule::get_stake_distribution(vec![0u8; 31]).is_empty());
+    });
+}
+
+#[test]
+fn test_stake_fee_accounting() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533453);
+        let coldkey = U256::from(55453);
+        let treasury = U256::from(77);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        SubtensorModule::set_target_stakes_per_interval(10);
+
+        // 100 RAO plus 0.5%, nothing below 1_000 RAO.
+        SubtensorModule::set_stake_fee(100, 50);
+        SubtensorModule::set_stake_fee_dust_threshold(1_000);
+        assert_eq!(SubtensorModule::get_stake_fee(999), 0);
+        assert_eq!(SubtensorModule::get_stake_fee(1_000), 105);
+        assert_eq!(SubtensorModule::get_stake_fee(10_000), 150);
+
+        // Without a treasury, the fee on staking is recycled out of the issuance.
+        let issuance = TotalIssuance::<Test>::get();
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            10_000
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 990_000);
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 9_850);
+        assert_eq!(SubtensorModule::get_total_stake(), 9_850);
+        assert_eq!(TotalIssuance::<Test>::get(), issuance - 150);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::StakeFeeCharged(coldkey, 150),
+        ));
+
+        // With a treasury, the fee on unstaking is paid to it and stays in the issuance.
+        SubtensorModule::set_stake_fee_treasury(Some(treasury));
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            4_000
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 993_880);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&treasury), 120);
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 5_850);
+        assert_eq!(SubtensorModule::get_total_stake(), 5_850);
+        assert_eq!(TotalIssuance::<Test>::get(), issuance - 150);
+
+        // Dust is unstaked without a fee.
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            500
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 994_380);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&treasury), 120);
+        assert_eq!(SubtensorModule::get_total_stake_fees(), 270);
+    });
+}
+
+#[test]
+fn test_stake_threshold_alerts() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533453);
+        let coldkey = U256::from(55453);
+        let delegator = U256::from(66);
+        let netuid: u16 = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+        SubtensorModule::set_target_stakes_per_interval(10);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            10_000
+        ));
+
+        assert_err!(
+            SubtensorModule::notify_if_stake_below(
+                <<Test as Config>::RuntimeOrigin>::signed(delegator),
+                U256::from(999),
+                5_000
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+        assert_ok!(SubtensorModule::notify_if_stake_below(
+            <<Test as Config>::RuntimeOrigin>::signed(delegator),
+            hotkey,
+            5_000
+        ));
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_stake_threshold_subscription(&hotkey, &delegator),
+            Some(5_000)
+        );
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            6_000
+        ));
+        step_block(1);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::StakeThresholdBreached(delegator, hotkey, 4_000, 5_000),
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_threshold_subscription(&hotkey, &delegator),
+            None
+        );
+
+        // A coldkey holds a bounded number of subscriptions, cancelling frees one.
+        for i in 0..pallet_subtensor::MAX_STAKE_SUBSCRIPTIONS_PER_COLDKEY {
+            let watched = U256::from(1_000 + i);
+            SubtensorModule::create_account_if_non_existent(&coldkey, &watched);
+            assert_ok!(SubtensorModule::notify_if_stake_below(
+                <<Test as Config>::RuntimeOrigin>::signed(delegator),
+                watched,
+                1
+            ));
+        }
+        assert_err!(
+            SubtensorModule::notify_if_stake_below(
+                <<Test as Config>::RuntimeOrigin>::signed(delegator),
+                hotkey,
+                1
+            ),
+            Error::<Test>::TooManyStakeSubscriptions
+        );
+        assert_ok!(SubtensorModule::notify_if_stake_below(
+            <<Test as Config>::RuntimeOrigin>::signed(delegator),
+            U256::from(1_000),
+            0
+        ));
+        assert_ok!(SubtensorModule::notify_if_stake_below(
+            <<Test as Config>::RuntimeOrigin>::signed(delegator),
+            hotkey,
+            1
+        ));
+    });
+}
+
+#[test]
+fn test_stake_threshold_checks_are_bounded_per_block() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533453);
+        let coldkey = U256::from(55453);
+        let netuid: u16 = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 10_000);
+
+        let subscribers: Vec<U256> = (0..pallet_subtensor::MAX_STAKE_ALERT_CHECKS_PER_BLOCK + 1)
+            .map(|i| U256::from(10_000 + i))
+            .collect();
+        for subscriber in subscribers.iter() {
+            assert_ok!(SubtensorModule::notify_if_stake_below(
+                <<Test as Config>::RuntimeOrigin>::signed(*subscriber),
+                hotkey,
+                5_000
+            ));
+        }
+        step_block(2);
+        assert!(!pallet_subtensor::StakeAlertHotkeys::<Test>::contains_key(
+            hotkey
+        ));
+
+        // Only hotkeys whose stake fell are checked, a bounded number of subscriptions per block.
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 6_000);
+        step_block(1);
+        let remaining = subscribers
+            .iter()
+            .filter(|subscriber| {
+                SubtensorModule::get_stake_threshold_subscription(&hotkey, subscriber).is_some()
+            })
+            .count();
+        assert_eq!(remaining, 1);
+        assert!(pallet_subtensor::StakeAlertCursor::<Test>::get().is_some());
+
+        step_block(1);
+        for subscriber in subscribers.iter() {
+            assert_eq!(
+                SubtensorModule::get_stake_threshold_subscription(&hotkey, subscriber),
+                None
+            );
+        }
+        assert!(pallet_subtensor::StakeAlertCursor::<Test>::get().is_none());
+        assert!(!pallet_subtensor::StakeAlertHotkeys::<Test>::contains_key(
+            hotkey
+        ));
+    });
+}
+
+#[test]
+fn test_get_pending_unstakes() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let banned = U256::from(1);
+        let hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let others: Vec<U256> = (4..7).map(U256::from).collect();
+        add_network(netuid, 1, 0);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &banned);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        for staker in others.iter().chain([coldkey].iter()) {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(staker, &banned, 1_000);
+        }
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        SubtensorModule::set_target_stakes_per_interval(1);
+        assert!(SubtensorModule::get_pending_unstakes(coldkey.encode()).is_empty());
+
+        // The stake interval on the hotkey is used up by a single stake.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            500
+        ));
+        let pending = SubtensorModule::get_pending_unstakes(coldkey.encode());
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].hotkey, hotkey);
+        assert_eq!(pending[0].kind, PendingUnstakeKind::StakeRateLimited);
+        assert_eq!(
+            pending[0].stake,
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey).into()
+        );
+        assert!(pending[0].blocks_remaining > 0.into());
+
+        // Stake on a banned hotkey is pending until wound down, at most two stakes per block.
+        SubtensorModule::set_stake_wind_down_chunk_size(2);
+        assert_ok!(SubtensorModule::do_ban_hotkey(&banned));
+        let pending = SubtensorModule::get_pending_unstakes(coldkey.encode());
+        let wind_down = pending
+            .iter()
+            .find(|p| p.kind == PendingUnstakeKind::BannedHotkeyWindDown)
+            .unwrap();
+        assert_eq!(wind_down.hotkey, banned);
+        assert_eq!(wind_down.stake, 1_000.into());
+        let blocks: u64 = wind_down.blocks_remaining.into();
+        assert!((1..=2).contains(&blocks));
+        step_block(blocks as u16);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &banned),
+            0
+        );
+        assert!(SubtensorModule::get_pending_unstakes(coldkey.encode())
+            .iter()
+            .all(|p| p.kind != PendingUnstakeKind::BannedHotkeyWindDown));
+
+        assert!(SubtensorModule::get_pending_unstakes(vec![0u8; 31]).is_empty());
+    });
+}
+
+#[test]
+fn test_get_coldkey_value() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 1, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 3_000);
+
+        let value = SubtensorModule::get_coldkey_value(coldkey.encode()).unwrap();
+        assert_eq!(value.free_balance, 10_000.into());
+        assert_eq!(value.total_staked, 3_000.into());
+        assert_eq!(value.winding_down, 0.into());
+        assert_eq!(value.held, 0.into());
+        assert_eq!(value.total, 13_000.into());
+
+        // The lock of an owned subnet is held for the coldkey.
+        SubnetOwner::<Test>::insert(netuid, coldkey);
+        SubtensorModule::set_subnet_locked_balance(netuid, 500);
+        let value = SubtensorModule::get_coldkey_value(coldkey.encode()).unwrap();
+        assert_eq!(value.held, 500.into());
+        assert_eq!(value.total, 13_500.into());
+
+        assert!(SubtensorModule::get_coldkey_value(vec![0u8; 31]).is_none());
+    });
+}
+
+#[test]
+fn test_get_stake_info_for_coldkeys() {
+    new_test_ext(1).execute_with(|| {
+        let hotkeys = [U256::from(1), U256::from(2)];
+        let coldkey = U256::from(3);
+        let other_coldkey = U256::from(4);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkeys[0], 100);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkeys[1], 200);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&other_coldkey, &hotkeys[0], 300);
+
+        let stake_info = SubtensorModule::get_stake_info_for_coldkey(coldkey.encode());
+        assert_eq!(stake_info.len(), 2);
+        assert_eq!(stake_info.encode(), {
+            let mut expected = Vec::new();
+            for (hotkey, stake) in [(hotkeys[0], 100u64), (hotkeys[1], 200u64)] {
+                expected.push((hotkey, coldkey, codec::Compact(stake)));
+            }
+            expected.encode()
+        });
+
+        let batched = SubtensorModule::get_stake_info_for_coldkeys(vec![
+            coldkey.encode(),
+            vec![0u8; 31],
+            other_coldkey.encode(),
+        ]);
+        assert_eq!(batched.len(), 2);
+        assert_eq!(batched[0].0, coldkey);
+        assert_eq!(batched[0].1, stake_info);
+        assert_eq!(batched[1].0, other_coldkey);
+        assert_eq!(batched[1].1.len(), 1);
+
+        assert!(SubtensorModule::get_stake_info_for_coldkey(vec![0u8; 31]).is_empty());
+    });
+}
+
+#[test]
+fn test_get_delegate_registrations() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(1, 1, 0);
+        add_network(2, 1, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+        register_ok_neuron(2, hotkey, coldkey, 100_000);
+        SubtensorModule::set_validator_permit_for_uid(2, 0, true);
+        Emission::<Test>::insert(2, vec![26]);
+
+        let registrations = SubtensorModule::get_delegate_registrations(&hotkey);
+        assert_eq!(registrations.len(), 2);
+        let on_first = registrations.iter().find(|r| r.netuid.0 == 1).unwrap();
+        assert!(!on_first.validator_permit);
+        assert_eq!(on_first.emission.0, 0);
+        let on_second = registrations.iter().find(|r| r.netuid.0 == 2).unwrap();
+        assert!(on_second.validator_permit);
+        assert_eq!(on_second.rank.0, SubtensorModule::get_rank_for_uid(2, 0));
+        assert_eq!(on_second.emission.0, 26);
+
+        assert!(SubtensorModule::get_delegate_registrations(&U256::from(3)).is_empty());
+    });
+}
+
+#[test]
+fn test_staking_hotkeys_paged() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkeys: Vec<U256> = (100..170).map(U256::from).collect();
+        for hotkey in hotkeys.iter() {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, hotkey, 100);
+        }
+
+        assert_eq!(SubtensorModule::get_staking_hotkey_page_count(&coldkey), 2);
+        assert_eq!(
+            SubtensorModule::get_staking_hotkeys_page(&coldkey, 0),
+            hotkeys.get(..64).unwrap().to_vec()
+        );
+        assert_eq!(
+            SubtensorModule::get_staking_hotkeys_page(&coldkey, 1),
+            hotkeys.get(64..).unwrap().to_vec()
+        );
+        assert_eq!(SubtensorModule::get_all_staked_hotkeys(&coldkey), hotkeys);
+
+        // The last hotkey takes the place of the removed one.
+        SubtensorModule::empty_stake_on_coldkey_hotkey_account(&coldkey, hotkeys.first().unwrap());
+        let page = SubtensorModule::get_staking_hotkeys_page(&coldkey, 0);
+        assert_eq!(page.len(), 64);
+        assert_eq!(page.first(), hotkeys.last());
+        assert_eq!(
+            SubtensorModule::get_staking_hotkeys_page(&coldkey, 1).len(),
+            5
+        );
+
+        // Emptying the last page drops it.
+        for hotkey in hotkeys.get(64..69).unwrap() {
+            SubtensorModule::empty_stake_on_coldkey_hotkey_account(&coldkey, hotkey);
+        }
+        assert_eq!(SubtensorModule::get_staking_hotkey_page_count(&coldkey), 1);
+        assert_eq!(SubtensorModule::get_all_staked_hotkeys(&coldkey).len(), 64);
+        assert!(SubtensorModule::coldkey_has_associated_hotkeys(&coldkey));
+    });
+}
+
+#[test]
+fn test_delegate_take_changes_within_bounds() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        System::set_block_number(1);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        let min_take = SubtensorModule::get_min_delegate_take();
+        let max_take = SubtensorModule::get_max_delegate_take();
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+
+        assert_ok!(SubtensorModule::do_become_delegate(
+            origin.clone(),
+            hotkey,
+            min_take + 1
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey), min_take + 1);
+
+        // Decreases are bounded by the minimum take and not rate limited.
+        assert_err!(
+            SubtensorModule::do_decrease_take(origin.clone(), hotkey, min_take - 1),
+            Error::<Test>::DelegateTakeTooLow
+        );
+        assert_ok!(SubtensorModule::do_decrease_take(
+            origin.clone(),
+            hotkey,
+            min_take
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey), min_take);
+
+        // Increases are bounded by the maximum take and rate limited.
+        assert_err!(
+            SubtensorModule::do_increase_take(origin.clone(), hotkey, max_take + 1),
+            Error::<Test>::DelegateTakeTooHigh
+        );
+        assert_err!(
+            SubtensorModule::do_increase_take(origin.clone(), hotkey, max_take),
+            Error::<Test>::DelegateTxRateLimitExceeded
+        );
+        System::set_block_number(1 + SubtensorModule::get_tx_delegate_take_rate_limit() + 1);
+        assert_ok!(SubtensorModule::do_increase_take(
+            origin.clone(),
+            hotkey,
+            max_take
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey), max_take);
+        assert_err!(
+            SubtensorModule::do_increase_take(origin, hotkey, max_take),
+            Error::<Test>::DelegateTakeTooLow
+        );
+    });
+}
+
+#[test]
+fn test_emission_honors_per_delegate_take() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let nominator = U256::from(10);
+        add_network(netuid, 0, 0);
+        let min_take = SubtensorModule::get_min_delegate_take();
+        let max_take = SubtensorModule::get_max_delegate_take();
+
+        // Two delegates with the same stakes, one at the minimum take and one at the maximum.
+        let mut nominator_gains = Vec::new();
+        for (hotkey, coldkey, take) in [
+            (U256::from(1), U256::from(2), min_take),
+            (U256::from(3), U256::from(4), max_take),
+        ] {
+            register_ok_neuron(netuid, hotkey, coldkey, 124124);
+            assert_ok!(SubtensorModule::do_become_delegate(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                take
+            ));
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1_000);
+
+            let delegate_take =
+                SubtensorModule::calculate_delegate_proportional_take(&hotkey, 1_000_000);
+            SubtensorModule::emit_inflation_through_hotkey_account(&hotkey, 0, 1_000_000);
+            let nominator_gain =
+                SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey) - 1_000;
+            assert_eq!(nominator_gain, (1_000_000 - delegate_take) / 2);
+            assert_eq!(
+                SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+                1_000 + 1_000_000 - nominator_gain
+            );
+            nominator_gains.push(nominator_gain);
+        }
+        assert!(nominator_gains[0] > nominator_gains[1]);
+    });
+}
+
+#[test]
+fn test_max_take_per_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let nominator = U256::from(10);
+        add_network(netuid, 0, 0);
+        add_network(2, 0, 0);
+        let min_take = SubtensorModule::get_min_delegate_take();
+        let max_take = SubtensorModule::get_max_delegate_take();
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            max_take
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1_000);
+
+        SubtensorModule::set_max_take_per_subnet(netuid, min_take);
+        assert_eq!(
+            SubtensorModule::get_delegate_take_on_subnet(&hotkey, netuid),
+            min_take
+        );
+        assert_eq!(
+            SubtensorModule::get_delegate_take_on_subnet(&hotkey, 2),
+            max_take
+        );
+
+        // Emission earned on the capped subnet is paid at its cap, even with a hotkey
+        // emission tempo set.
+        SubtensorModule::set_hotkey_emission_tempo(10);
+        assert_eq!(
+            SubtensorModule::accrue_hotkey_emission(&hotkey, netuid, 0, 1_000_000),
+            1_000_000
+        );
+        let capped_gain =
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey) - 1_000;
+        let capped_take = 1_000_000 * u64::from(min_take) / u64::from(u16::MAX);
+        assert!(capped_gain.abs_diff(1_000_000 - capped_take) <= 1);
+
+        // Emission earned elsewhere accrues and is paid at the take of the delegate.
+        assert_eq!(
+            SubtensorModule::accrue_hotkey_emission(&hotkey, 2, 0, 1_000_000),
+            0
+        );
+
+        // A cap at the maximum delegate take lifts it.
+        SubtensorModule::set_max_take_per_subnet(netuid, max_take);
+        assert_eq!(SubtensorModule::get_max_take_per_subnet(netuid), None);
+    });
+}
+
+#[test]
+fn test_set_children_attributes_stake_after_cooldown() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        let child = U256::from(3);
+        add_network(netuid, 13, 0);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &parent);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &parent, 1000);
+        SubtensorModule::set_pending_child_key_cooldown(5);
+
+        // Only the owner of the hotkey sets valid children.
+        assert_noop!(
+            SubtensorModule::set_children(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(9)),
+                parent,
+                netuid,
+                vec![(u64::MAX / 2, child)]
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_noop!(
+            SubtensorModule::set_children(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                parent,
+                netuid,
+                vec![(u64::MAX / 2, parent)]
+            ),
+            Error::<Test>::InvalidChild
+        );
+        assert_noop!(
+            SubtensorModule::set_children(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                parent,
+                netuid,
+                vec![(1, child), (1, child)]
+            ),
+            Error::<Test>::DuplicateChild
+        );
+        assert_noop!(
+            SubtensorModule::set_children(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                parent,
+                netuid,
+                vec![(u64::MAX, child), (1, U256::from(4))]
+            ),
+            Error::<Test>::ChildProportionsOverflow
+        );
+
+        // The children only take stake once the cooldown passes.
+        assert_ok!(SubtensorModule::set_children(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX / 2, child)]
+        ));
+        assert_eq!(
+            SubtensorModule::get_pending_child_keys(&parent, netuid),
+            Some((vec![(u64::MAX / 2, child)], 6))
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&parent, netuid),
+            1000
+        );
+        step_block(5);
+        assert_eq!(
+            SubtensorModule::get_pending_child_keys(&parent, netuid),
+            None
+        );
+        assert_eq!(
+            SubtensorModule::get_child_keys(&parent, netuid),
+            vec![(u64::MAX / 2, child)]
+        );
+        assert_eq!(
+            SubtensorModule::get_parent_keys(&child, netuid),
+            vec![(u64::MAX / 2, parent)]
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&child, netuid),
+            499
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&parent, netuid),
+            501
+        );
+        // Other subnets keep the stake on the parent.
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&child, netuid + 1),
+            0
+        );
+
+        // Revoking the children returns the stake to the parent after the cooldown.
+        assert_ok!(SubtensorModule::set_children(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            parent,
+            netuid,
+            vec![]
+        ));
+        step_block(5);
+        assert!(SubtensorModule::get_child_keys(&parent, netuid).is_empty());
+        assert!(SubtensorModule::get_parent_keys(&child, netuid).is_empty());
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&parent, netuid),
+            1000
+        );
+    });
+}
+
+#[test]
+fn test_set_children_applies_only_the_latest_schedule() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let parent = U256::from(2);
+        add_network(netuid, 13, 0);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &parent);
+        SubtensorModule::set_pending_child_key_cooldown(2);
+
+        assert_ok!(SubtensorModule::set_children(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            parent,
+            netuid,
+            vec![(1, U256::from(3))]
+        ));
+        step_block(1);
+        // Setting the children again restarts the cooldown, the first schedule is skipped.
+        assert_ok!(SubtensorModule::set_children(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            parent,
+            netuid,
+            vec![(2, U256::from(4))]
+        ));
+        step_block(1);
+        assert!(SubtensorModule::get_child_keys(&parent, netuid).is_empty());
+        assert_eq!(
+            SubtensorModule::get_pending_child_keys(&parent, netuid),
+            Some((vec![(2, U256::from(4))], 4))
+        );
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_child_keys(&parent, netuid),
+            vec![(2, U256::from(4))]
+        );
+        assert!(SubtensorModule::get_parent_keys(&U256::from(3), netuid).is_empty());
+        assert_eq!(
+            SubtensorModule::get_pending_child_keys(&parent, netuid),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_set_children_too_many_parents() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let child = U256::from(1);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_pending_child_key_cooldown(0);
+
+        for i in 0..pallet_subtensor::MAX_PARENTS as u64 {
+            let (coldkey, parent) = (U256::from(1_000 + i), U256::from(2_000 + i));
+            SubtensorModule::create_account_if_non_existent(&coldkey, &parent);
+            assert_ok!(SubtensorModule::set_children(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                parent,
+                netuid,
+                vec![(1, child)]
+            ));
+        }
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_parent_keys(&child, netuid).len(),
+            pallet_subtensor::MAX_PARENTS as usize
+        );
+
+        // A new parent is refused, a current one may still change its proportion.
+        let (coldkey, parent) = (U256::from(3_000), U256::from(4_000));
+        SubtensorModule::create_account_if_non_existent(&coldkey, &parent);
+        assert_noop!(
+            SubtensorModule::set_children(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                parent,
+                netuid,
+                vec![(1, child)]
+            ),
+            Error::<Test>::TooManyParents
+        );
+        assert_ok!(SubtensorModule::set_children(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(1_000)),
+            U256::from(2_000),
+            netuid,
+            vec![(2, child)]
+        ));
+    });
+}
+
+#[test]
+fn test_vote_delegate_take_cap() {
+    new_test_ext(1).execute_with(|| {
+        let owner = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator1 = U256::from(3);
+        let nominator2 = U256::from(4);
+        let outsider = U256::from(5);
+        let netuid: u16 = 1;
+        let root_netuid = SubtensorModule::get_root_netuid();
+        let max_take = SubtensorModule::get_max_delegate_take();
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        SubtensorModule::set_tempo(root_netuid, 1);
+        SubtensorModule::set_take_cap_duration(4);
+
+        assert_noop!(
+            SubtensorModule::vote_delegate_take_cap(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator1),
+                hotkey,
+                Some(8_000)
+            ),
+            Error::<Test>::HotKeyNotDelegate
+        );
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            max_take
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator1, &hotkey, 600);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator2, &hotkey, 400);
+
+        assert_noop!(
+            SubtensorModule::vote_delegate_take_cap(
+                <<Test as Config>::RuntimeOrigin>::signed(outsider),
+                hotkey,
+                Some(8_000)
+            ),
+            Error::<Test>::NoStakeOnDelegate
+        );
+        assert_noop!(
+            SubtensorModule::vote_delegate_take_cap(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator1),
+                hotkey,
+                Some(SubtensorModule::get_min_delegate_take() - 1)
+            ),
+            Error::<Test>::DelegateTakeTooLow
+        );
+        assert_noop!(
+            SubtensorModule::vote_delegate_take_cap(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator1),
+                hotkey,
+                Some(max_take + 1)
+            ),
+            Error::<Test>::DelegateTakeTooHigh
+        );
+
+        // Two fifths of the stake is short of the threshold.
+        assert_ok!(SubtensorModule::vote_delegate_take_cap(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator2),
+            hotkey,
+            Some(6_000)
+        ));
+        run_to_block(3);
+        assert_eq!(SubtensorModule::get_delegate_take_cap(&hotkey), None);
+        assert_eq!(
+            SubtensorModule::get_effective_delegate_take(&hotkey),
+            max_take
+        );
+
+        // Together the nominators back the higher of the two caps.
+        assert_ok!(SubtensorModule::vote_delegate_take_cap(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator1),
+            hotkey,
+            Some(8_000)
+        ));
+        run_to_block(5);
+        assert_eq!(
+            SubtensorModule::get_delegate_take_cap(&hotkey),
+            Some((8_000, 9))
+        );
+        assert_eq!(SubtensorModule::get_effective_delegate_take(&hotkey), 8_000);
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey), max_take);
+        assert_eq!(
+            SubtensorModule::get_take_cap_vote(&hotkey, &nominator1),
+            None
+        );
+        assert_eq!(
+            SubtensorModule::get_take_cap_vote(&hotkey, &nominator2),
+            None
+        );
+
+        // The cap lapses after its duration.
+        run_to_block(9);
+        assert_eq!(SubtensorModule::get_delegate_take_cap(&hotkey), None);
+        assert_eq!(
+            SubtensorModule::get_effective_delegate_take(&hotkey),
+            max_take
+        );
+    });
+}
+
+#[test]
+fn test_withdraw_delegate_take_cap_vote() {
+    new_test_ext(1).execute_with(|| {
+        let owner = U256::from(1);
+        let hotkey = U256::from(2);
+        let nominator = U256::from(3);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            SubtensorModule::get_max_delegate_take()
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 100);
+
+        assert_ok!(SubtensorModule::vote_delegate_take_cap(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            Some(8_000)
+        ));
+        assert_eq!(
+            SubtensorModule::get_take_cap_vote(&hotkey, &nominator),
+            Some(8_000)
+        );
+        assert_ok!(SubtensorModule::vote_delegate_take_cap(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            None
+        ));
+        assert_eq!(
+            SubtensorModule::get_take_cap_vote(&hotkey, &nominator),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_take_cap_tally_is_bounded_per_block() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(1);
+        SubtensorModule::set_tempo(SubtensorModule::get_root_netuid(), 1);
+        let delegates: Vec<U256> = (0..pallet_subtensor::MAX_DELEGATES_CHECKED_PER_BLOCK + 1)
+            .map(|i| U256::from(1_000 + i))
+            .collect();
+        for hotkey in delegates.iter() {
+            Delegates::<Test>::insert(hotkey, SubtensorModule::get_max_delegate_take());
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, hotkey, 100);
+            assert_ok!(SubtensorModule::vote_delegate_take_cap(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                *hotkey,
+                Some(8_000)
+            ));
+        }
+        let capped = || {
+            delegates
+                .iter()
+                .filter(|hotkey| SubtensorModule::get_delegate_take_cap(hotkey).is_some())
+                .count()
+        };
+
+        // Nothing is tallied between the root tempo boundaries.
+        SubtensorModule::tally_delegate_take_caps(2);
+        assert_eq!(capped(), 0);
+
+        // A pass starts at the boundary and resumes in the following blocks.
+        SubtensorModule::tally_delegate_take_caps(1);
+        assert_eq!(
+            capped(),
+            pallet_subtensor::MAX_DELEGATES_CHECKED_PER_BLOCK as usize
+        );
+        assert!(pallet_subtensor::TakeCapTallyCursor::<Test>::get().is_some());
+        SubtensorModule::tally_delegate_take_caps(2);
+        assert_eq!(capped(), delegates.len());
+        assert!(pallet_subtensor::TakeCapTallyCursor::<Test>::get().is_none());
+    });
+}
+
+#[test]
+fn test_delegate_underperformance() {
+    new_test_ext(1).execute_with(|| {
+        let owner = U256::from(1);
+        let hotkey = U256::from(2);
+        let netuid: u16 = 1;
+        let max_take = SubtensorModule::get_max_delegate_take();
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner, 0);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey,
+            max_take
+        ));
+        SubtensorModule::set_tempo(SubtensorModule::get_root_netuid(), 1);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        let set_trust = |trust: u16| {
+            let mut trusts = vec![0; usize::from(uid) + 1];
+            trusts[usize::from(uid)] = trust;
+            pallet_subtensor::ValidatorTrust::<Test>::insert(netuid, trusts);
+        };
+        assert_eq!(
+            SubtensorModule::get_delegate_validator_trust(&hotkey),
+            Some(0)
+        );
+
+        // Off by default.
+        SubtensorModule::check_delegate_underperformance(1);
+        assert!(!SubtensorModule::is_delegate_take_reduced(&hotkey));
+
+        // The take is reduced after two root tempos in a row below the threshold.
+        SubtensorModule::set_delegate_underperformance(30_000, 2, 1_000);
+        SubtensorModule::check_delegate_underperformance(1);
+        set_trust(40_000);
+        SubtensorModule::check_delegate_underperformance(3);
+        set_trust(10_000);
+        SubtensorModule::check_delegate_underperformance(5);
+        assert!(!SubtensorModule::is_delegate_take_reduced(&hotkey));
+        SubtensorModule::check_delegate_underperformance(7);
+        assert!(SubtensorModule::is_delegate_take_reduced(&hotkey));
+        assert_eq!(SubtensorModule::get_effective_delegate_take(&hotkey), 1_000);
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey), max_take);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::DelegateTakeReduced(hotkey, 1_000),
+        ));
+
+        // It is restored after as many tempos back at the threshold.
+        set_trust(30_000);
+        SubtensorModule::check_delegate_underperformance(9);
+        assert!(SubtensorModule::is_delegate_take_reduced(&hotkey));
+        SubtensorModule::check_delegate_underperformance(11);
+        assert!(!SubtensorModule::is_delegate_take_reduced(&hotkey));
+        assert_eq!(
+            SubtensorModule::get_effective_delegate_take(&hotkey),
+            max_take
+        );
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::DelegateTakeRestored(hotkey),
+        ));
+    });
+}
+
+#[test]
+fn test_delegate_take_transaction_type_rate_limit() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        System::set_block_number(1);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        let min_take = SubtensorModule::get_min_take();
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            origin.clone(),
+            hotkey,
+            u16::MAX / 10
+        ));
+
+        // No limit is set, so the take changes freely.
+        assert_ok!(SubtensorModule::do_decrease_take(
+            origin.clone(),
+            hotkey,
+            min_take + 3
+        ));
+        assert_ok!(SubtensorModule::do_decrease_take(
+            origin.clone(),
+            hotkey,
+            min_take + 2
+        ));
+
+        SubtensorModule::set_transaction_type_rate_limit(TransactionType::SetDelegateTake, 10);
+        assert_err!(
+            SubtensorModule::do_decrease_take(origin.clone(), hotkey, min_take + 1),
+            Error::<Test>::TransactionTypeRateLimitExceeded
+        );
+        // The limit of one transaction type leaves the others alone.
+        assert!(SubtensorModule::passes_rate_limit(
+            &coldkey,
+            TransactionType::RegisterNetwork
+        ));
+
+        step_block(10);
+        assert_ok!(SubtensorModule::do_decrease_take(
+            origin.clone(),
+            hotkey,
+            min_take + 1
+        ));
+        assert_eq!(
+            SubtensorModule::get_last_transaction_type_block(
+                &coldkey,
+                TransactionType::SetDelegateTake
+            ),
+            Some(11)
+        );
+    });
+}
+
+#[test]
+fn test_add_stake_locked() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let nominator = U256::from(10);
+        System::set_block_number(1);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            SubtensorModule::get_min_take()
+        ));
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 100_000);
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(nominator);
+
+        for lock_blocks in [0, MAX_STAKE_LOCK_BLOCKS + 1] {
+            assert_err!(
+                SubtensorModule::add_stake_locked(origin.clone(), hotkey, 10_000, lock_blocks),
+                Error::<Test>::InvalidStakeLockDuration
+            );
+        }
+
+        assert_ok!(SubtensorModule::add_stake_locked(
+            origin.clone(),
+            hotkey,
+            10_000,
+            100
+        ));
+        let stake = SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey);
+        assert_eq!(
+            SubtensorModule::get_locked_stake(&hotkey, &nominator),
+            stake
+        );
+        assert_eq!(
+            StakeLocks::<Test>::get(hotkey, nominator),
+            Some(StakeLock {
+                amount: stake,
+                locked_at: 1,
+                unlock_block: 101,
+            })
+        );
+        assert_err!(
+            SubtensorModule::remove_stake(origin.clone(), hotkey, 1),
+            Error::<Test>::StakeIsLocked
+        );
+
+        // The lock ends in the block_step of its unlock block.
+        step_block(100);
+        assert_eq!(StakeLocks::<Test>::get(hotkey, nominator), None);
+        assert_eq!(SubtensorModule::get_locked_stake(&hotkey, &nominator), 0);
+        assert_ok!(SubtensorModule::remove_stake(origin, hotkey, stake));
+    });
+}
+
+#[test]
+fn test_locked_stake_survives_deregistration() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        System::set_block_number(1);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 30_000);
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+        assert_ok!(SubtensorModule::add_stake_locked(
+            origin.clone(),
+            hotkey,
+            10_000,
+            MAX_STAKE_LOCK_BLOCKS
+        ));
+        assert_ok!(SubtensorModule::add_stake(origin.clone(), hotkey, 10_000));
+        let locked = SubtensorModule::get_locked_stake(&hotkey, &coldkey);
+        let stake = SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+
+        // The owner can't unregister the hotkey to get its locked stake back.
+        assert_err!(
+            SubtensorModule::unregister(origin, netuid, hotkey),
+            Error::<Test>::StakeIsLocked
+        );
+
+        // Pruned, the hotkey keeps the locked stake. The rest pays the stake fee and waits out
+        // the unstake cooldown.
+        SubtensorModule::set_stake_fee(0, 100);
+        SubtensorModule::set_unstake_cooldown(10);
+        let balance = Balances::free_balance(coldkey);
+        SubtensorModule::unstake_all_coldkeys_from_hotkey_account(&hotkey);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            locked
+        );
+        assert_eq!(SubtensorModule::get_locked_stake(&hotkey, &coldkey), locked);
+        let unlocked = stake - locked;
+        assert_eq!(
+            SubtensorModule::get_pending_withdrawal_total(&coldkey),
+            unlocked - SubtensorModule::get_stake_fee(unlocked)
+        );
+        assert_eq!(Balances::free_balance(coldkey), balance);
+    });
+}
+
+#[test]
+fn test_locked_stake_boosts_dividends() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let locked_nominator = U256::from(10);
+        let nominator = U256::from(11);
+        System::set_block_number(1);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            SubtensorModule::get_min_take()
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &locked_nominator,
+            &hotkey,
+            1_000,
+        );
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1_000);
+
+        SubtensorModule::set_stake_lock_boosts(vec![(100, 15_000), (1_000, 20_000)]);
+        assert_eq!(SubtensorModule::get_stake_lock_boost(99), 10_000);
+        assert_eq!(SubtensorModule::get_stake_lock_boost(100), 15_000);
+        assert_eq!(SubtensorModule::get_stake_lock_boost(5_000), 20_000);
+
+        StakeLocks::<Test>::insert(
+            hotkey,
+            locked_nominator,
+            StakeLock {
+                amount: 1_000,
+                locked_at: 1,
+                unlock_block: 1_001,
+            },
+        );
+        assert_eq!(
+            SubtensorModule::get_dividend_weighted_stake(&hotkey, &locked_nominator, 1_000),
+            2_000
+        );
+
+        // The locked stake earns twice the dividends of the same unlocked stake.
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkey, 0, 1_000_000);
+        let locked_gain =
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&locked_nominator, &hotkey) - 1_000;
+        let gain = SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey) - 1_000;
+        assert!(gain > 0);
+        assert!(locked_gain.abs_diff(2 * gain) <= 1);
+    });
+}
+
+#[test]
+fn test_remove_stake_waits_out_unstake_cooldown() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        System::set_block_number(1);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 124124);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 10_000);
+        SubtensorModule::set_unstake_cooldown(10);
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+
+        // The removed stake is held instead of credited.
+        assert_ok!(SubtensorModule::remove_stake(origin.clone(), hotkey, 4_000));
+        let credited = 4_000 - SubtensorModule::get_stake_fee(4_000);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before
+        );
+        assert_eq!(
+            PendingWithdrawals::<Test>::get(coldkey),
+            vec![(hotkey, credited, 11)]
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_held_balance(&coldkey),
+            credited
+        );
+        assert_err!(
+            SubtensorModule::claim_unstaked(origin.clone()),
+            Error::<Test>::NothingToClaim
+        );
+
+        step_block(10);
+        assert_ok!(SubtensorModule::claim_unstaked(origin.clone()));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before + credited
+        );
+        assert!(PendingWithdrawals::<Test>::get(coldkey).is_empty());
+
+        // Without a cooldown the stake is credited at once.
+        SubtensorModule::set_unstake_cooldown(0);
+        step_block(1);
+        assert_ok!(SubtensorModule::remove_stake(origin, hotkey, 1_000));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before + credited + 1_000 - SubtensorModule::get_stake_fee(1_000)
+        );
+    });
+}
+
+#[test]
+fn test_add_and_remove_stake_multiple() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey0 = U256::from(2);
+        let hotkey1 = U256::from(3);
+        System::set_block_number(1);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey, 0);
+        register_ok_neuron(netuid, hotkey1, coldkey, 100);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+
+        // The whole batch is checked against the balance before any stake is added.
+        assert_err!(
+            SubtensorModule::add_stake_multiple(
+                origin.clone(),
+                vec![(hotkey0, 6_000), (hotkey1, 6_000)]
+            ),
+            Error::<Test>::NotEnoughBalanceToStake
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_coldkey(&coldkey), 0);
+        assert_err!(
+            SubtensorModule::add_stake_multiple(
+                origin.clone(),
+                vec![(hotkey0, 1); MAX_STAKE_BATCH_SIZE as usize + 1]
+            ),
+            Error::<Test>::StakeBatchTooLarge
+        );
+
+        // Entries for the same hotkey are added up into one stake.
+        System::reset_events();
+        assert_ok!(SubtensorModule::add_stake_multiple(
+            origin.clone(),
+            vec![(hotkey0, 1_000), (hotkey1, 2_000), (hotkey0, 500)]
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey0),
+            1_500
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey1),
+            2_000
+        );
+        let stakes_added = System::events()
+            .iter()
+            .filter(|record| {
+                matches!(
+                    record.event,
+                    RuntimeEvent::SubtensorModule(pallet_subtensor::Event::StakeAdded(..))
+                )
+            })
+            .count();
+        assert_eq!(stakes_added, 2);
+
+        // An entry that fails fails the whole batch.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::remove_stake_multiple {
+            stakes: vec![(hotkey0, 1_000), (hotkey1, 3_000)],
+        });
+        assert_eq!(
+            call.dispatch(origin.clone()).map_err(|e| e.error),
+            Err(Error::<Test>::NotEnoughStakeToWithdraw.into())
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey0),
+            1_500
+        );
+
+        assert_ok!(SubtensorModule::remove_stake_multiple(
+            origin,
+            vec![(hotkey0, 1_000), (hotkey1, 2_000)]
+        ));
+        assert_eq!(SubtensorModule::get_total_stake_for_coldkey(&coldkey), 500);
+    });
+}
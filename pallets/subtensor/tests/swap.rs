@@ -1271,6 +1271,61 @@ fn test_swap_stake_for_coldkey() {
     });
 }
 
+#[test]
+fn test_swap_pending_withdrawals_for_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        SubtensorModule::set_unstake_cooldown(10);
+
+        // Stake removed from the same hotkey in the same block joins one withdrawal.
+        SubtensorModule::pay_out_or_schedule_withdrawal(&old_coldkey, &hotkey, 100);
+        SubtensorModule::pay_out_or_schedule_withdrawal(&old_coldkey, &hotkey, 50);
+        assert_eq!(
+            PendingWithdrawals::<Test>::get(old_coldkey),
+            vec![(hotkey, 150, 11)]
+        );
+
+        // A withdrawal of the old coldkey merges into the one of the new coldkey from the same
+        // hotkey unlocking at the same block.
+        let full: Vec<(U256, u64, u64)> = (0..MAX_PENDING_WITHDRAWALS as u64)
+            .map(|unlock_block| (hotkey, 1, 100 + unlock_block))
+            .collect();
+        let mut pending = full.clone();
+        pending[0].2 = 11;
+        PendingWithdrawals::<Test>::insert(new_coldkey, pending);
+        let mut weight = Weight::zero();
+        SubtensorModule::swap_pending_withdrawals_for_coldkey(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight,
+        );
+        let swapped = PendingWithdrawals::<Test>::get(new_coldkey);
+        assert_eq!(swapped.len(), MAX_PENDING_WITHDRAWALS);
+        assert_eq!(swapped[0], (hotkey, 151, 11));
+        assert!(PendingWithdrawals::<Test>::get(old_coldkey).is_empty());
+
+        // A full queue never holds up the swap, the earliest withdrawal is folded into the
+        // next one to unlock instead.
+        SubtensorModule::pay_out_or_schedule_withdrawal(&old_coldkey, &hotkey, 50);
+        PendingWithdrawals::<Test>::insert(new_coldkey, full);
+        SubtensorModule::swap_pending_withdrawals_for_coldkey(
+            &old_coldkey,
+            &new_coldkey,
+            &mut weight,
+        );
+        let swapped = PendingWithdrawals::<Test>::get(new_coldkey);
+        assert_eq!(swapped.len(), MAX_PENDING_WITHDRAWALS);
+        assert_eq!(swapped[0], (hotkey, 51, 100));
+        assert_eq!(
+            SubtensorModule::get_pending_withdrawal_total(&new_coldkey),
+            50 + 16
+        );
+        assert!(PendingWithdrawals::<Test>::get(old_coldkey).is_empty());
+    });
+}
+
 #[test]
 fn test_swap_total_hotkey_coldkey_stakes_this_interval_for_coldkey() {
     new_test_ext(1).execute_with(|| {
@@ -1736,3 +1791,256 @@ fn test_swap_senate_member() {
         assert_eq!(weight, expected_weight);
     });
 }
+
+#[test]
+fn test_rotate_subnet_owner_key() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let new_owner = U256::from(2);
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_subnet_locked_balance(netuid, 1_000);
+        SubnetOwnerHotkey::<Test>::insert(netuid, U256::from(3));
+        PendingSubnetOwner::<Test>::insert(netuid, U256::from(4));
+        let payload = SubtensorModule::subnet_owner_rotation_payload(netuid, &owner);
+
+        // Only the owner coldkey may rotate.
+        assert_noop!(
+            SubtensorModule::rotate_subnet_owner_key(
+                RuntimeOrigin::signed(new_owner),
+                netuid,
+                new_owner,
+                TestKeySignature(new_owner, payload.clone())
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        // The signature must be the new coldkey's over the payload of this subnet and owner.
+        assert_noop!(
+            SubtensorModule::rotate_subnet_owner_key(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                new_owner,
+                TestKeySignature(owner, payload.clone())
+            ),
+            Error::<Test>::InvalidOwnerKeySignature
+        );
+        assert_noop!(
+            SubtensorModule::rotate_subnet_owner_key(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                new_owner,
+                TestKeySignature(
+                    new_owner,
+                    SubtensorModule::subnet_owner_rotation_payload(netuid + 1, &owner)
+                )
+            ),
+            Error::<Test>::InvalidOwnerKeySignature
+        );
+        assert_noop!(
+            SubtensorModule::rotate_subnet_owner_key(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                owner,
+                TestKeySignature(owner, payload.clone())
+            ),
+            Error::<Test>::SameColdkey
+        );
+
+        assert_ok!(SubtensorModule::rotate_subnet_owner_key(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            new_owner,
+            TestKeySignature(new_owner, payload.clone())
+        ));
+        assert_eq!(SubtensorModule::get_subnet_owner(netuid), new_owner);
+        assert_eq!(SubtensorModule::get_subnet_locked_balance(netuid), 1_000);
+        assert!(!SubnetOwnerHotkey::<Test>::contains_key(netuid));
+        assert!(!PendingSubnetOwner::<Test>::contains_key(netuid));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(Event::SubnetOwnerKeyRotated(
+            netuid, owner, new_owner,
+        )));
+
+        // Once the subnet came back to the owner, the first proof of the new coldkey is stale.
+        let back = SubtensorModule::subnet_owner_rotation_payload(netuid, &new_owner);
+        assert_ok!(SubtensorModule::rotate_subnet_owner_key(
+            RuntimeOrigin::signed(new_owner),
+            netuid,
+            owner,
+            TestKeySignature(owner, back)
+        ));
+        assert_noop!(
+            SubtensorModule::rotate_subnet_owner_key(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                new_owner,
+                TestKeySignature(new_owner, payload)
+            ),
+            Error::<Test>::InvalidOwnerKeySignature
+        );
+        assert_eq!(SubtensorModule::get_subnet_owner(netuid), owner);
+    });
+}
+
+#[test]
+fn test_swap_hotkey_moves_child_keys() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let child = U256::from(4);
+        let parent = U256::from(5);
+        let parent_coldkey = U256::from(6);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, old_hotkey, coldkey, 0);
+        SubtensorModule::create_account_if_non_existent(&parent_coldkey, &parent);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000);
+        SubtensorModule::set_pending_child_key_cooldown(0);
+
+        // The old hotkey is both a parent and a child on the subnet.
+        assert_ok!(SubtensorModule::set_children(
+            RuntimeOrigin::signed(coldkey),
+            old_hotkey,
+            netuid,
+            vec![(u64::MAX / 2, child)]
+        ));
+        assert_ok!(SubtensorModule::set_children(
+            RuntimeOrigin::signed(parent_coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX / 4, old_hotkey)]
+        ));
+        // Without a cooldown, the children apply at the next block.
+        SubtensorModule::apply_pending_child_keys(2);
+
+        assert_ok!(SubtensorModule::do_swap_hotkey(
+            RuntimeOrigin::signed(coldkey),
+            &old_hotkey,
+            &new_hotkey
+        ));
+
+        assert!(SubtensorModule::get_child_keys(&old_hotkey, netuid).is_empty());
+        assert!(SubtensorModule::get_parent_keys(&old_hotkey, netuid).is_empty());
+        assert_eq!(
+            SubtensorModule::get_child_keys(&new_hotkey, netuid),
+            vec![(u64::MAX / 2, child)]
+        );
+        assert_eq!(
+            SubtensorModule::get_parent_keys(&child, netuid),
+            vec![(u64::MAX / 2, new_hotkey)]
+        );
+        assert_eq!(
+            SubtensorModule::get_parent_keys(&new_hotkey, netuid),
+            vec![(u64::MAX / 4, parent)]
+        );
+        assert_eq!(
+            SubtensorModule::get_child_keys(&parent, netuid),
+            vec![(u64::MAX / 4, new_hotkey)]
+        );
+    });
+}
+
+#[test]
+fn test_transfer_subnet_ownership() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let new_owner = U256::from(2);
+        let operator = U256::from(3);
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        pallet_subtensor::SubnetOwnerHotkey::<Test>::insert(netuid, operator);
+        SubtensorModule::set_subnet_locked_balance(netuid, 1_000);
+
+        // Only the owner coldkey may propose a new owner, other than itself.
+        assert_noop!(
+            SubtensorModule::transfer_subnet_ownership(
+                RuntimeOrigin::signed(new_owner),
+                netuid,
+                new_owner
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert_noop!(
+            SubtensorModule::transfer_subnet_ownership(RuntimeOrigin::signed(owner), netuid, owner),
+            Error::<Test>::SameColdkey
+        );
+        assert_ok!(SubtensorModule::transfer_subnet_ownership(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            new_owner
+        ));
+        assert_eq!(
+            SubtensorModule::get_pending_subnet_owner(netuid),
+            Some(new_owner)
+        );
+        assert_eq!(SubnetOwner::<Test>::get(netuid), owner);
+
+        // Only the pending owner may accept.
+        assert_noop!(
+            SubtensorModule::accept_subnet_ownership(RuntimeOrigin::signed(operator), netuid),
+            Error::<Test>::NotPendingSubnetOwner
+        );
+        assert_ok!(SubtensorModule::accept_subnet_ownership(
+            RuntimeOrigin::signed(new_owner),
+            netuid
+        ));
+        assert_eq!(SubnetOwner::<Test>::get(netuid), new_owner);
+        assert_eq!(SubtensorModule::get_pending_subnet_owner(netuid), None);
+        assert_eq!(
+            pallet_subtensor::SubnetOwnerHotkey::<Test>::get(netuid),
+            None
+        );
+        assert_eq!(SubtensorModule::get_subnet_locked_balance(netuid), 1_000);
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::SubnetOwnershipTransferred(netuid, owner, new_owner),
+        ));
+
+        // The transfer cannot be accepted twice.
+        assert_noop!(
+            SubtensorModule::accept_subnet_ownership(RuntimeOrigin::signed(new_owner), netuid),
+            Error::<Test>::NotPendingSubnetOwner
+        );
+    });
+}
+
+#[test]
+fn test_swap_pending_hotkey_emission_adds_to_new_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let mut weight = Weight::zero();
+        SubtensorModule::create_account_if_non_existent(&coldkey, &old_hotkey);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &new_hotkey);
+        SubtensorModule::set_hotkey_emission_tempo(10);
+
+        // Both hotkeys have emission pending.
+        SubtensorModule::accrue_hotkey_emission(&old_hotkey, 1, 100, 200);
+        SubtensorModule::accrue_hotkey_emission(&new_hotkey, 1, 10, 20);
+
+        SubtensorModule::swap_pending_hotkey_emission(&old_hotkey, &new_hotkey, &mut weight);
+
+        assert_eq!(
+            SubtensorModule::get_pending_hotkey_emission(&old_hotkey),
+            (0, 0)
+        );
+        assert_eq!(
+            SubtensorModule::get_pending_hotkey_emission(&new_hotkey),
+            (110, 220)
+        );
+        // The new hotkey keeps its own payout block.
+        let payout_blocks: Vec<u64> = HotkeyEmissionPayouts::<Test>::iter()
+            .filter(|(_, hotkey, _)| *hotkey == new_hotkey)
+            .map(|(block, _, _)| block)
+            .collect();
+        assert_eq!(payout_blocks.len(), 1);
+
+        SubtensorModule::drain_hotkey_emission(*payout_blocks.first().unwrap());
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&new_hotkey),
+            330
+        );
+    });
+}
@@ -3,6 +3,7 @@
 use crate::mock::*;
 use frame_support::assert_ok;
 use frame_system::Config;
+use pallet_subtensor::{BlockAtRegistration, Keys, EVICTION_LOG_LENGTH};
 use sp_core::U256;
 
 mod mock;
@@ -371,3 +372,100 @@ fn test_replace_neuron_multiple_subnets_unstake_all() {
         );
     });
 }
+
+#[test]
+fn test_uid_ordered_iteration() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+
+        // Storage iterates uid 256 before uid 1.
+        for uid in [300u16, 1, 256, 0, 2] {
+            Keys::<Test>::insert(netuid, uid, U256::from(uid));
+            let stake = if uid == 1 || uid == 256 { 5 } else { 10 };
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(1_000 + uid),
+                &U256::from(uid),
+                stake,
+            );
+        }
+        let uids: Vec<u16> = SubtensorModule::get_uid_hotkey_pairs(netuid)
+            .into_iter()
+            .map(|(uid, _)| uid)
+            .collect();
+        assert_eq!(uids, vec![0, 1, 2, 256, 300]);
+
+        // Equal lowest stakes resolve to the lowest uid, whatever the storage order.
+        assert_eq!(SubtensorModule::get_lowest_stake_uid(netuid), Some((1, 5)));
+        assert_eq!(SubtensorModule::get_lowest_stake_uid(netuid + 1), None);
+
+        add_network(300, 1, 0);
+        add_network(2, 1, 0);
+        assert_eq!(SubtensorModule::get_all_subnet_netuids(), vec![1, 2, 300]);
+    });
+}
+
+#[test]
+fn test_lowest_stake_uid_tie_break() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        for uid in 0..4u16 {
+            Keys::<Test>::insert(netuid, uid, U256::from(uid));
+        }
+        let set = |stakes: [u64; 4], registered_at: [u64; 4]| {
+            for (uid, (stake, block)) in (0..4u16).zip(stakes.into_iter().zip(registered_at)) {
+                let hotkey = U256::from(uid);
+                let current = SubtensorModule::get_total_stake_for_hotkey(&hotkey);
+                SubtensorModule::decrease_stake_on_coldkey_hotkey_account(
+                    &hotkey, &hotkey, current,
+                );
+                SubtensorModule::increase_stake_on_coldkey_hotkey_account(&hotkey, &hotkey, stake);
+                BlockAtRegistration::<Test>::insert(netuid, uid, block);
+            }
+        };
+
+        // The lowest stake is replaced whatever its age.
+        set([10, 0, 10, 10], [1, 9, 1, 1]);
+        assert_eq!(SubtensorModule::get_lowest_stake_uid(netuid), Some((1, 0)));
+
+        // Tied stakes replace the oldest registration.
+        set([0, 0, 0, 0], [4, 3, 1, 2]);
+        assert_eq!(SubtensorModule::get_lowest_stake_uid(netuid), Some((2, 0)));
+        set([5, 0, 0, 5], [1, 7, 6, 1]);
+        assert_eq!(SubtensorModule::get_lowest_stake_uid(netuid), Some((2, 0)));
+
+        // Registrations in the same block replace the lowest uid.
+        set([0, 0, 0, 0], [3, 2, 2, 2]);
+        assert_eq!(SubtensorModule::get_lowest_stake_uid(netuid), Some((1, 0)));
+    });
+}
+
+#[test]
+fn test_replace_neuron_eviction_log() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::append_neuron(netuid, &U256::from(0), 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(10),
+            &U256::from(0),
+            1_000,
+        );
+
+        // The evicted stake is logged before the old hotkey is unstaked.
+        SubtensorModule::replace_neuron(netuid, 0, &U256::from(1), 5);
+        assert_eq!(
+            SubtensorModule::get_eviction_log(netuid),
+            vec![(5, U256::from(0), 1_000, U256::from(1))]
+        );
+
+        // Only the latest EVICTION_LOG_LENGTH evictions are kept.
+        for block in 0..EVICTION_LOG_LENGTH as u64 {
+            SubtensorModule::replace_neuron(netuid, 0, &U256::from(block + 2), block + 6);
+        }
+        let log = SubtensorModule::get_eviction_log(netuid);
+        assert_eq!(log.len(), EVICTION_LOG_LENGTH as usize);
+        assert_eq!(log[0], (6, U256::from(1), 0, U256::from(2)));
+    });
+}
@@ -1,4 +1,4 @@
-#![allow(clippy::indexing_slicing)]
+#![allow(clippy::indexing_slicing, clippy::unwrap_used)]
 
 mod mock;
 use frame_support::{
@@ -7,10 +7,10 @@ use frame_support::{
     pallet_prelude::{InvalidTransaction, TransactionValidityError},
 };
 use mock::*;
-use pallet_subtensor::{Error, Owner};
-use sp_core::{H256, U256};
+use pallet_subtensor::{subnet_features, Error, NetUid, Owner};
+use sp_core::{Get, H256, U256};
 use sp_runtime::{
-    traits::{BlakeTwo256, DispatchInfoOf, Hash, SignedExtension},
+    traits::{BlakeTwo256, DispatchInfoOf, Dispatchable, Hash, SignedExtension},
     DispatchError,
 };
 use substrate_fixed::types::I32F32;
@@ -44,13 +44,13 @@ fn test_set_weights_dispatch_info_ok() {
 #[cfg(not(tarpaulin))]
 fn test_set_rootweights_dispatch_info_ok() {
     new_test_ext(0).execute_with(|| {
-        let dests = vec![1, 1];
+        let dests = vec![NetUid::from(1), NetUid::from(1)];
         let weights = vec![1, 1];
         let netuid: u16 = 1;
         let version_key: u64 = 0;
         let hotkey: U256 = U256::from(1); // Add the hotkey field
         let call = RuntimeCall::SubtensorModule(SubtensorCall::set_root_weights {
-            netuid,
+            netuid: NetUid::from(netuid),
             dests,
             weights,
             version_key,
@@ -69,7 +69,7 @@ fn test_set_rootweights_validate() {
     // correctly filters this transaction.
 
     new_test_ext(0).execute_with(|| {
-        let dests = vec![1, 1];
+        let dests = vec![NetUid::from(1), NetUid::from(1)];
         let weights = vec![1, 1];
         let netuid: u16 = 1;
         let version_key: u64 = 0;
@@ -80,7 +80,7 @@ fn test_set_rootweights_validate() {
         let who = coldkey; // The coldkey signs this transaction
 
         let call = RuntimeCall::SubtensorModule(SubtensorCall::set_root_weights {
-            netuid,
+            netuid: NetUid::from(netuid),
             dests,
             weights,
             version_key,
@@ -337,6 +337,49 @@ fn test_reveal_weights_validate() {
     });
 }
 
+#[test]
+fn test_signed_extension_rejects_missing_subnet() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let who = U256::from(1);
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = pallet_subtensor::SubtensorSignedExtension::<Test>::new();
+        let set_weights = |netuid: u16| {
+            RuntimeCall::SubtensorModule(SubtensorCall::set_weights {
+                netuid,
+                dests: vec![0],
+                weights: vec![1],
+                version_key: 0,
+            })
+        };
+        let batch = RuntimeCall::SubtensorModule(SubtensorCall::batch_set_weights {
+            batch: vec![(netuid, vec![0], vec![1], 0), (2, vec![0], vec![1], 0)],
+        });
+
+        assert_err!(
+            extension.validate(&who, &set_weights(netuid), &info, 10),
+            TransactionValidityError::Invalid(InvalidTransaction::Call)
+        );
+        assert_err!(
+            extension.validate(&who, &batch, &info, 10),
+            TransactionValidityError::Invalid(InvalidTransaction::Call)
+        );
+
+        add_network(netuid, 13, 0);
+        assert_ok!(extension.validate(&who, &set_weights(netuid), &info, 10));
+        // A batch is kept while one of its subnets exists.
+        assert_ok!(extension.validate(&who, &batch, &info, 10));
+
+        // Calls in flight for a pruned subnet are dropped.
+        SubtensorModule::remove_network(netuid);
+        assert_err!(
+            extension.validate(&who, &set_weights(netuid), &info, 10),
+            TransactionValidityError::Invalid(InvalidTransaction::Call)
+        );
+    });
+}
+
 #[test]
 fn test_set_weights_is_root_error() {
     new_test_ext(0).execute_with(|| {
@@ -814,6 +857,53 @@ fn test_set_weights_err_invalid_uid() {
     });
 }
 
+// Tests that weights on uids past the size of a shrunk subnet are rejected, and the stored
+// ones ignored by the epoch.
+#[test]
+fn test_set_weights_uid_beyond_subnetwork_n() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        for uid in 0..3 {
+            register_ok_neuron(netuid, U256::from(uid), U256::from(uid + 10), uid * 10_000);
+        }
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(U256::from(0)),
+            netuid,
+            vec![0, 1, 2],
+            vec![1, 1, 1],
+            0
+        ));
+
+        // The subnet shrinks to two uids, leaving the key and weights of uid 2 behind.
+        SubtensorModule::set_max_allowed_uids(netuid, 2);
+        pallet_subtensor::SubnetworkN::<Test>::insert(netuid, 2);
+        assert!(SubtensorModule::is_uid_exist_on_network(netuid, 2));
+        assert_eq!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(U256::from(0)),
+                netuid,
+                vec![0, 2],
+                vec![1, 1],
+                0
+            ),
+            Err(Error::<Test>::UidVecContainInvalidOne.into())
+        );
+
+        let weights = SubtensorModule::get_weights_sparse(netuid);
+        assert_eq!(weights.len(), 2);
+        assert!(weights[0].iter().all(|(uid, _)| *uid < 2));
+        assert_eq!(SubtensorModule::get_weights(netuid)[0].len(), 2);
+        assert_eq!(SubtensorModule::epoch_dense(netuid, 1_000_000_000).len(), 2);
+        SubtensorModule::epoch(netuid, None);
+    });
+}
+
 // Tests that set weights fails if you don't pass enough values.
 #[test]
 fn test_set_weight_not_enough_values() {
@@ -951,6 +1041,108 @@ fn test_set_weights_sum_larger_than_u16_max() {
     });
 }
 
+// Test that subnets with SUM_NORMALIZED_WEIGHTS store weight rows normalized to sum to u16::MAX.
+#[test]
+fn test_set_weights_sum_normalized_storage() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey_account_id = U256::from(55);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 65555);
+        register_ok_neuron(netuid, U256::from(2), U256::from(2), 75555);
+
+        let neuron_uid: u16 =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey_account_id)
+                .expect("Not registered.");
+        SubtensorModule::set_validator_permit_for_uid(netuid, neuron_uid, true);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+
+        // By default rows are max-upscaled.
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey_account_id),
+            netuid,
+            vec![1, 2],
+            vec![1, 2],
+            0
+        ));
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, neuron_uid).entries(),
+            vec![(1, 32768), (2, 65535)]
+        );
+
+        // With the feature enabled the same row sums to u16::MAX, rounding down.
+        SubtensorModule::set_subnet_feature(netuid, subnet_features::SUM_NORMALIZED_WEIGHTS, true);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey_account_id),
+            netuid,
+            vec![1, 2],
+            vec![1, 2],
+            0
+        ));
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, neuron_uid).entries(),
+            vec![(1, 21845), (2, 43690)]
+        );
+    });
+}
+
+#[test]
+fn test_switching_sum_normalized_weights_rescales_stored_rows() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey_account_id = U256::from(55);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 65555);
+        register_ok_neuron(netuid, U256::from(2), U256::from(2), 75555);
+
+        let neuron_uid: u16 =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey_account_id)
+                .expect("Not registered.");
+        SubtensorModule::set_validator_permit_for_uid(netuid, neuron_uid, true);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey_account_id),
+            netuid,
+            vec![1, 2],
+            vec![1, 2],
+            0
+        ));
+
+        // The row set before the switch is still max-upscaled, so the epoch row-normalizes
+        // until the sweep has rescaled it.
+        SubtensorModule::set_subnet_feature(netuid, subnet_features::SUM_NORMALIZED_WEIGHTS, true);
+        assert!(pallet_subtensor::WeightsRescalePending::<Test>::contains_key(netuid));
+        assert_eq!(SubtensorModule::get_weights_sweep_cursor(netuid), Some(0));
+        assert!(!SubtensorModule::weights_sum_normalized(netuid));
+
+        SubtensorModule::sweep_nonconforming_weights();
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, neuron_uid).entries(),
+            vec![(1, 21845), (2, 43690)]
+        );
+        assert!(!pallet_subtensor::WeightsRescalePending::<Test>::contains_key(netuid));
+        assert!(SubtensorModule::weights_sum_normalized(netuid));
+
+        // Switching back max-upscales the row again.
+        SubtensorModule::set_subnet_feature(netuid, subnet_features::SUM_NORMALIZED_WEIGHTS, false);
+        assert!(pallet_subtensor::WeightsRescalePending::<Test>::contains_key(netuid));
+        SubtensorModule::sweep_nonconforming_weights();
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, neuron_uid).entries(),
+            vec![(1, 32768), (2, 65535)]
+        );
+        assert!(!pallet_subtensor::WeightsRescalePending::<Test>::contains_key(netuid));
+    });
+}
+
 /// Check _truthy_ path for self weight
 #[test]
 fn test_check_length_allows_singleton() {
@@ -1363,7 +1555,7 @@ fn test_commit_reveal_weights_ok() {
 }
 
 #[test]
-fn test_commit_reveal_interval() {
+fn test_weights_metadata_hash() {
     new_test_ext(1).execute_with(|| {
         let netuid: u16 = 1;
         let uids: Vec<u16> = vec![0, 1];
@@ -1371,7 +1563,9 @@ fn test_commit_reveal_interval() {
         let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
         let version_key: u64 = 0;
         let hotkey: U256 = U256::from(1);
+        let metadata_hash = H256::repeat_byte(7);
 
+        // The commit covers the metadata hash, appended after the version key.
         let commit_hash: H256 = BlakeTwo256::hash_of(&(
             hotkey,
             netuid,
@@ -1379,69 +1573,38 @@ fn test_commit_reveal_interval() {
             weight_values.clone(),
             salt.clone(),
             version_key,
+            metadata_hash,
         ));
 
         add_network(netuid, 0, 0);
         register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
-        register_ok_neuron(netuid, U256::from(1), U256::from(2), 100000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
         SubtensorModule::set_weights_set_rate_limit(netuid, 5);
         SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
         SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
-
-        SubtensorModule::set_commit_reveal_weights_interval(netuid, 100);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
         SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
-        System::set_block_number(0);
 
-        assert_ok!(SubtensorModule::commit_weights(
-            RuntimeOrigin::signed(hotkey),
-            netuid,
-            commit_hash
-        ));
-        assert_err!(
-            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash),
-            Error::<Test>::WeightsCommitNotAllowed
-        );
-        assert_err!(
-            SubtensorModule::reveal_weights(
-                RuntimeOrigin::signed(hotkey),
-                netuid,
-                uids.clone(),
-                weight_values.clone(),
-                salt.clone(),
-                version_key,
-            ),
-            Error::<Test>::InvalidRevealCommitTempo
-        );
-        step_block(99);
-        assert_err!(
-            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash),
-            Error::<Test>::WeightsCommitNotAllowed
-        );
         assert_err!(
-            SubtensorModule::reveal_weights(
+            SubtensorModule::set_weights_with_metadata(
                 RuntimeOrigin::signed(hotkey),
                 netuid,
                 uids.clone(),
                 weight_values.clone(),
-                salt.clone(),
                 version_key,
+                metadata_hash,
             ),
-            Error::<Test>::InvalidRevealCommitTempo
+            Error::<Test>::CommitRevealEnabled
         );
-        step_block(1);
-        assert_ok!(SubtensorModule::reveal_weights(
-            RuntimeOrigin::signed(hotkey),
-            netuid,
-            uids.clone(),
-            weight_values.clone(),
-            salt.clone(),
-            version_key,
-        ));
+
         assert_ok!(SubtensorModule::commit_weights(
             RuntimeOrigin::signed(hotkey),
             netuid,
             commit_hash
         ));
+        step_block(5);
+
+        // Revealing without the metadata hash does not match the commit.
         assert_err!(
             SubtensorModule::reveal_weights(
                 RuntimeOrigin::signed(hotkey),
@@ -1451,82 +1614,52 @@ fn test_commit_reveal_interval() {
                 salt.clone(),
                 version_key,
             ),
-            Error::<Test>::InvalidRevealCommitTempo
+            Error::<Test>::InvalidRevealCommitHashNotMatch
         );
-        step_block(100);
-        assert_ok!(SubtensorModule::reveal_weights(
+        assert_ok!(SubtensorModule::reveal_weights_with_metadata(
             RuntimeOrigin::signed(hotkey),
             netuid,
             uids.clone(),
             weight_values.clone(),
-            salt.clone(),
+            salt,
             version_key,
+            metadata_hash,
         ));
-
-        // Testing that if you miss the next tempo you cannot reveal it.
-        assert_ok!(SubtensorModule::commit_weights(
-            RuntimeOrigin::signed(hotkey),
-            netuid,
-            commit_hash
-        ));
-        step_block(205);
-        assert_err!(
-            SubtensorModule::reveal_weights(
-                RuntimeOrigin::signed(hotkey),
-                netuid,
-                uids.clone(),
-                weight_values.clone(),
-                salt.clone(),
-                version_key,
-            ),
-            Error::<Test>::InvalidRevealCommitTempo
+        assert_eq!(
+            SubtensorModule::get_weights_metadata_hash(netuid, 1),
+            Some(metadata_hash)
         );
 
-        // Testing when you commit but do not reveal until later intervals
-        assert_ok!(SubtensorModule::commit_weights(
+        // Weights set without a metadata hash clear the stored one.
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+        step_block(5);
+        assert_ok!(SubtensorModule::set_weights(
             RuntimeOrigin::signed(hotkey),
             netuid,
-            commit_hash
-        ));
-        step_block(425);
-        let commit_hash_2: H256 = BlakeTwo256::hash_of(&(
-            hotkey,
-            netuid,
             uids.clone(),
             weight_values.clone(),
-            salt.clone(),
-            version_key + 1,
+            version_key,
         ));
-        assert_ok!(SubtensorModule::commit_weights(
+        assert_eq!(SubtensorModule::get_weights_metadata_hash(netuid, 1), None);
+
+        step_block(5);
+        assert_ok!(SubtensorModule::set_weights_with_metadata(
             RuntimeOrigin::signed(hotkey),
             netuid,
-            commit_hash_2
+            uids,
+            weight_values,
+            version_key,
+            H256::repeat_byte(8),
         ));
-        step_block(100);
-        assert_err!(
-            SubtensorModule::reveal_weights(
-                RuntimeOrigin::signed(hotkey),
-                netuid,
-                uids.clone(),
-                weight_values.clone(),
-                salt.clone(),
-                version_key,
-            ),
-            Error::<Test>::InvalidRevealCommitHashNotMatch
+        assert_eq!(
+            SubtensorModule::get_weights_metadata_hash(netuid, 1),
+            Some(H256::repeat_byte(8))
         );
-        assert_ok!(SubtensorModule::reveal_weights(
-            RuntimeOrigin::signed(hotkey),
-            netuid,
-            uids.clone(),
-            weight_values.clone(),
-            salt.clone(),
-            version_key + 1,
-        ));
     });
 }
 
 #[test]
-fn test_commit_reveal_hash() {
+fn test_weights_key_commit_reveal() {
     new_test_ext(1).execute_with(|| {
         let netuid: u16 = 1;
         let uids: Vec<u16> = vec![0, 1];
@@ -1534,17 +1667,9 @@ fn test_commit_reveal_hash() {
         let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
         let version_key: u64 = 0;
         let hotkey: U256 = U256::from(1);
+        let weights_key: U256 = U256::from(99);
 
-        add_network(netuid, 0, 0);
-        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
-        register_ok_neuron(netuid, U256::from(1), U256::from(2), 100000);
-        SubtensorModule::set_weights_set_rate_limit(netuid, 5);
-        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
-        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
-
-        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
-        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
-
+        // The commit is made for the hotkey, whichever key signs it.
         let commit_hash: H256 = BlakeTwo256::hash_of(&(
             hotkey,
             netuid,
@@ -1554,22 +1679,297 @@ fn test_commit_reveal_hash() {
             version_key,
         ));
 
-        assert_ok!(SubtensorModule::commit_weights(
-            RuntimeOrigin::signed(hotkey),
-            netuid,
-            commit_hash
-        ));
-
-        step_block(5);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 5);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
 
         assert_err!(
-            SubtensorModule::reveal_weights(
-                RuntimeOrigin::signed(hotkey),
+            SubtensorModule::authorize_weights_key(
+                RuntimeOrigin::signed(weights_key),
                 netuid,
-                vec![0, 2],
-                weight_values.clone(),
-                salt.clone(),
-                version_key
+                hotkey
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+        assert_err!(
+            SubtensorModule::authorize_weights_key(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                U256::from(3)
+            ),
+            Error::<Test>::WeightsKeyIsRegistered
+        );
+        assert_ok!(SubtensorModule::authorize_weights_key(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            weights_key
+        ));
+        assert_err!(
+            SubtensorModule::authorize_weights_key(
+                RuntimeOrigin::signed(U256::from(3)),
+                netuid,
+                weights_key
+            ),
+            Error::<Test>::WeightsKeyInUse
+        );
+        assert_eq!(
+            SubtensorModule::get_weights_hotkey(netuid, weights_key),
+            hotkey
+        );
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(weights_key),
+            netuid,
+            commit_hash
+        ));
+        step_block(5);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(weights_key),
+            netuid,
+            uids,
+            weight_values,
+            salt,
+            version_key,
+        ));
+        assert!(!SubtensorModule::get_weights_sparse(netuid)[1].is_empty());
+
+        // Once revoked, the key acts for itself again.
+        assert_ok!(SubtensorModule::authorize_weights_key(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            hotkey
+        ));
+        assert_eq!(
+            SubtensorModule::get_weights_hotkey(netuid, weights_key),
+            weights_key
+        );
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(weights_key),
+                netuid,
+                vec![0, 1],
+                vec![10, 10],
+                version_key
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+    });
+}
+
+#[test]
+fn test_commit_reveal_interval() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let version_key: u64 = 0;
+        let hotkey: U256 = U256::from(1);
+
+        let commit_hash: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            version_key,
+        ));
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 100000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 5);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 100);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        System::set_block_number(0);
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        assert_err!(
+            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash),
+            Error::<Test>::WeightsCommitNotAllowed
+        );
+        assert_err!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                salt.clone(),
+                version_key,
+            ),
+            Error::<Test>::InvalidRevealCommitTempo
+        );
+        step_block(99);
+        assert_err!(
+            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash),
+            Error::<Test>::WeightsCommitNotAllowed
+        );
+        assert_err!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                salt.clone(),
+                version_key,
+            ),
+            Error::<Test>::InvalidRevealCommitTempo
+        );
+        step_block(1);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            version_key,
+        ));
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        assert_err!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                salt.clone(),
+                version_key,
+            ),
+            Error::<Test>::InvalidRevealCommitTempo
+        );
+        step_block(100);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            version_key,
+        ));
+
+        // Testing that if you miss the next tempo you cannot reveal it.
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        step_block(205);
+        assert_err!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                salt.clone(),
+                version_key,
+            ),
+            Error::<Test>::InvalidRevealCommitTempo
+        );
+
+        // Testing when you commit but do not reveal until later intervals
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        step_block(425);
+        let commit_hash_2: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            version_key + 1,
+        ));
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash_2
+        ));
+        step_block(100);
+        assert_err!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                salt.clone(),
+                version_key,
+            ),
+            Error::<Test>::InvalidRevealCommitHashNotMatch
+        );
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            version_key + 1,
+        ));
+    });
+}
+
+#[test]
+fn test_commit_reveal_hash() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let version_key: u64 = 0;
+        let hotkey: U256 = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 100000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 5);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        let commit_hash: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            version_key,
+        ));
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+
+        step_block(5);
+
+        assert_err!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![0, 2],
+                weight_values.clone(),
+                salt.clone(),
+                version_key
             ),
             Error::<Test>::InvalidRevealCommitHashNotMatch
         );
@@ -1710,6 +2110,88 @@ fn test_commit_reveal_disabled_or_enabled() {
     });
 }
 
+#[test]
+fn test_subnet_feature_flags_owner_toggle() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        let other = U256::from(11);
+
+        add_network(netuid, 0, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_eq!(SubtensorModule::get_subnet_feature_flags(netuid), 0);
+
+        // Only the owner or root may toggle flags.
+        assert_err!(
+            SubtensorModule::set_subnet_feature_flags(
+                RuntimeOrigin::signed(other),
+                netuid,
+                subnet_features::COMMIT_REVEAL_WEIGHTS,
+                true
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // Empty and unknown flags are rejected.
+        assert_err!(
+            SubtensorModule::set_subnet_feature_flags(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                0,
+                true
+            ),
+            Error::<Test>::InvalidSubnetFeatureFlags
+        );
+        assert_err!(
+            SubtensorModule::set_subnet_feature_flags(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                1 << 31,
+                true
+            ),
+            Error::<Test>::InvalidSubnetFeatureFlags
+        );
+        assert_err!(
+            SubtensorModule::set_subnet_feature_flags(
+                RuntimeOrigin::root(),
+                netuid + 1,
+                subnet_features::LIQUID_ALPHA,
+                true
+            ),
+            Error::<Test>::SubNetworkDoesNotExist
+        );
+
+        // Several flags can be set at once, and cleared one by one.
+        assert_ok!(SubtensorModule::set_subnet_feature_flags(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            subnet_features::COMMIT_REVEAL_WEIGHTS | subnet_features::LIQUID_ALPHA,
+            true
+        ));
+        assert!(SubtensorModule::get_commit_reveal_weights_enabled(netuid));
+        assert!(SubtensorModule::get_liquid_alpha_enabled(netuid));
+
+        assert_ok!(SubtensorModule::set_subnet_feature_flags(
+            RuntimeOrigin::root(),
+            netuid,
+            subnet_features::LIQUID_ALPHA,
+            false
+        ));
+        assert!(SubtensorModule::get_commit_reveal_weights_enabled(netuid));
+        assert!(!SubtensorModule::get_liquid_alpha_enabled(netuid));
+        assert_eq!(
+            SubtensorModule::get_subnet_feature_flags(netuid),
+            subnet_features::COMMIT_REVEAL_WEIGHTS
+        );
+
+        // The flag gates the commit-reveal path.
+        assert_err!(
+            SubtensorModule::set_weights(RuntimeOrigin::signed(other), netuid, vec![0], vec![1], 0),
+            Error::<Test>::CommitRevealEnabled
+        );
+    });
+}
+
 #[test]
 fn test_toggle_commit_reveal_weights_and_set_weights() {
     new_test_ext(1).execute_with(|| {
@@ -1877,3 +2359,489 @@ fn commit_reveal_set_weights(
 
     Ok(())
 }
+
+#[test]
+fn test_sweep_nonconforming_weights() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 0, 0);
+        for uid in 0..4u64 {
+            SubtensorModule::append_neuron(netuid, &U256::from(uid), 0);
+        }
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            0,
+            pallet_subtensor::WeightsRow::from(vec![(1, 1), (2, 1), (3, 1)]),
+        );
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            1,
+            pallet_subtensor::WeightsRow::from(vec![(0, 1)]),
+        );
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            2,
+            pallet_subtensor::WeightsRow::from(vec![(2, 1)]),
+        );
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            3,
+            pallet_subtensor::WeightsRow::from(vec![(0, 1), (1, 1)]),
+        );
+        SubtensorModule::set_weights_sweep_chunk_size(2);
+
+        // Lowering the minimum leaves the rows alone.
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        assert_eq!(SubtensorModule::get_weights_sweep_cursor(netuid), None);
+
+        SubtensorModule::set_min_allowed_weights(netuid, 3);
+        assert_eq!(SubtensorModule::get_weights_sweep_cursor(netuid), Some(0));
+
+        step_block(1);
+        assert_eq!(SubtensorModule::get_weights_sweep_cursor(netuid), Some(2));
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, 0)
+                .entries()
+                .len(),
+            3
+        );
+        assert!(pallet_subtensor::Weights::<Test>::get(netuid, 1).is_empty());
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NonconformingWeightsCleared(netuid, 1),
+        ));
+
+        // A self weight still conforms, a row of two weights does not.
+        step_block(1);
+        assert_eq!(SubtensorModule::get_weights_sweep_cursor(netuid), None);
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(netuid, 2).entries(),
+            vec![(2, 1)]
+        );
+        assert!(pallet_subtensor::Weights::<Test>::get(netuid, 3).is_empty());
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::WeightsSweepCompleted(netuid),
+        ));
+    });
+}
+
+#[test]
+fn test_weights_precision() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 0, 0);
+        for uid in 0..2u64 {
+            SubtensorModule::append_neuron(netuid, &U256::from(uid), 0);
+        }
+        let entries: Vec<(u16, u16)> = vec![(0, 0), (1, 1_000), (2, 30_000), (3, u16::MAX)];
+
+        // Weights round to the nearest of 256 buckets, stored in a byte each.
+        let row = pallet_subtensor::WeightsRow::quantized(&entries, 8);
+        assert_eq!(row.precision(), 8);
+        assert_eq!(
+            row.entries(),
+            vec![(0, 0), (1, 1_028), (2, 30_069), (3, u16::MAX)]
+        );
+        assert!(
+            codec::Encode::encoded_size(&row)
+                < codec::Encode::encoded_size(&pallet_subtensor::WeightsRow::from_entries(
+                    &entries
+                ))
+        );
+        assert_eq!(
+            pallet_subtensor::WeightsRow::quantized(&row.entries(), 8),
+            row
+        );
+
+        // Changing the precision rewrites the existing rows.
+        pallet_subtensor::Weights::<Test>::insert(
+            netuid,
+            0,
+            pallet_subtensor::WeightsRow::from(vec![(0, 1_000), (1, u16::MAX)]),
+        );
+        SubtensorModule::set_weights_precision(netuid, 8);
+        assert_eq!(SubtensorModule::get_weights_sweep_cursor(netuid), Some(0));
+        step_block(1);
+        assert_eq!(SubtensorModule::get_weights_sweep_cursor(netuid), None);
+        let row = pallet_subtensor::Weights::<Test>::get(netuid, 0);
+        assert_eq!(row.precision(), 8);
+        assert_eq!(row.entries(), vec![(0, 1_028), (1, u16::MAX)]);
+
+        // New rows are stored with the precision of the subnet.
+        assert_eq!(
+            SubtensorModule::weights_row(netuid, vec![(1, 30_000)]).precision(),
+            8
+        );
+    });
+}
+
+#[test]
+fn test_undecodable_weights_row() {
+    use codec::{Decode, Encode};
+
+    // An unknown format and a row cut short both read as empty, but are told apart from it.
+    for bytes in [vec![9u8], vec![0u8, 8]] {
+        let row = pallet_subtensor::WeightsRow::decode(&mut &bytes.encode()[..]).unwrap();
+        assert!(!row.is_empty());
+        assert!(row.try_entries().is_err());
+        assert!(row.entries().is_empty());
+    }
+    assert!(pallet_subtensor::WeightsRow::default()
+        .try_entries()
+        .unwrap()
+        .is_empty());
+
+    // Rows longer than a bound the encoding allows don't decode at all.
+    let oversized = vec![0u8; pallet_subtensor::weights_row::MAX_WEIGHTS_ROW_BYTES as usize + 1];
+    assert!(pallet_subtensor::WeightsRow::decode(&mut &oversized.encode()[..]).is_err());
+}
+
+// Test ensures that weights cannot be set on a subnet while weight setting is paused, and
+// can be again once the pause expires.
+#[test]
+fn test_set_weights_paused() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(55);
+        let coldkey = U256::from(66);
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 2143124);
+
+        SubtensorModule::set_max_weights_pause_duration(20);
+        assert!(SubtensorModule::can_pause_weights(netuid));
+        SubtensorModule::set_weights_paused(netuid, true);
+        assert_eq!(SubtensorModule::get_weights_paused_until(netuid), Some(20));
+        assert!(!SubtensorModule::can_pause_weights(netuid));
+
+        assert_eq!(
+            commit_reveal_set_weights(hotkey, netuid, vec![0], vec![1], salt.clone(), 0),
+            Err(Error::<Test>::WeightsPaused.into())
+        );
+
+        // The pause lifts by itself.
+        step_block(15);
+        assert_eq!(SubtensorModule::get_weights_paused_until(netuid), None);
+        assert_ok!(commit_reveal_set_weights(
+            hotkey,
+            netuid,
+            vec![0],
+            vec![1],
+            salt,
+            0
+        ));
+        assert!(!SubtensorModule::can_pause_weights(netuid));
+    });
+}
+
+// Test ensures that subnets requiring target epochs only accept weights set for their next
+// epoch, and that their epochs mask the weights set for another one.
+#[test]
+fn test_set_weights_for_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let hotkey: U256 = U256::from(1);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_target_epoch_weights(netuid, true);
+
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                0
+            ),
+            Error::<Test>::WeightsTargetEpochRequired
+        );
+        let epoch = SubtensorModule::get_epoch_index(netuid);
+        assert_err!(
+            SubtensorModule::set_weights_for_epoch(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                0,
+                epoch + 1
+            ),
+            Error::<Test>::InvalidWeightsTargetEpoch
+        );
+        assert_ok!(SubtensorModule::set_weights_for_epoch(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            0,
+            epoch
+        ));
+        assert_eq!(
+            SubtensorModule::get_weights_target_epoch(netuid, 1),
+            Some(epoch)
+        );
+        assert_eq!(
+            SubtensorModule::get_stale_weights(netuid),
+            vec![true, false]
+        );
+
+        // Once the epoch ran, the weights set for it are masked from the next one and late
+        // weights for it are rejected.
+        for _ in 0..10 {
+            if SubtensorModule::get_epoch_index(netuid) > epoch {
+                break;
+            }
+            step_block(1);
+        }
+        assert_eq!(SubtensorModule::get_epoch_index(netuid), epoch + 1);
+        assert_eq!(
+            SubtensorModule::load_epoch_input(netuid).stale_weights,
+            vec![true, true]
+        );
+        assert_err!(
+            SubtensorModule::set_weights_for_epoch(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                0,
+                epoch
+            ),
+            Error::<Test>::InvalidWeightsTargetEpoch
+        );
+        assert_ok!(SubtensorModule::set_weights_for_epoch(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids,
+            weight_values,
+            0,
+            epoch + 1
+        ));
+        assert_eq!(
+            SubtensorModule::get_stale_weights(netuid),
+            vec![true, false]
+        );
+    });
+}
+
+#[test]
+fn test_batch_set_weights() {
+    new_test_ext(1).execute_with(|| {
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let hotkey: U256 = U256::from(1);
+        for netuid in [1, 2] {
+            add_network(netuid, 1, 0);
+            register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+            register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+            SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+            SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+            SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        }
+
+        assert_err!(
+            SubtensorModule::batch_set_weights(
+                RuntimeOrigin::signed(hotkey),
+                vec![
+                    (1, uids.clone(), weight_values.clone(), 0);
+                    pallet_subtensor::MAX_WEIGHTS_BATCH_SIZE as usize + 1
+                ]
+            ),
+            Error::<Test>::WeightsBatchTooLarge
+        );
+
+        // Subnet 2 was set within its rate limit, the entry for subnet 1 still goes through.
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            2,
+            uids.clone(),
+            weight_values.clone(),
+            0
+        ));
+        SubtensorModule::set_weights_set_rate_limit(2, 100);
+        assert_ok!(SubtensorModule::batch_set_weights(
+            RuntimeOrigin::signed(hotkey),
+            vec![
+                (1, uids.clone(), weight_values.clone(), 0),
+                (2, uids.clone(), vec![5, 10], 0),
+            ]
+        ));
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(1, 1).entries(),
+            vec![(0, 65535), (1, 65535)]
+        );
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(2, 1).entries(),
+            vec![(0, 65535), (1, 65535)]
+        );
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::WeightsBatchEntryFailed(
+                2,
+                Error::<Test>::SettingWeightsTooFast.into(),
+            ),
+        ));
+    });
+}
+
+#[test]
+fn test_call_metrics() {
+    new_test_ext(1).execute_with(|| {
+        let who = U256::from(1);
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let dispatch = |identity: pallet_subtensor::ChainIdentity| {
+            let call = RuntimeCall::SubtensorModule(SubtensorCall::set_identity { identity });
+            let extension = pallet_subtensor::SubtensorSignedExtension::<Test>::new();
+            let pre = extension.pre_dispatch(&who, &call, &info, 10).unwrap();
+            let result = call
+                .dispatch(RuntimeOrigin::signed(who))
+                .map(|_| ())
+                .map_err(|e| e.error);
+            assert_ok!(
+                pallet_subtensor::SubtensorSignedExtension::<Test>::post_dispatch(
+                    Some(pre),
+                    &info,
+                    &Default::default(),
+                    10,
+                    &result
+                )
+            );
+        };
+        SubtensorModule::add_balance_to_coldkey_account(
+            &who,
+            SubtensorModule::get_identity_deposit() + 1,
+        );
+
+        dispatch(pallet_subtensor::ChainIdentity {
+            name: vec![b'a'; 257],
+            ..Default::default()
+        });
+        dispatch(pallet_subtensor::ChainIdentity {
+            name: vec![b'a'; 257],
+            ..Default::default()
+        });
+        dispatch(pallet_subtensor::ChainIdentity {
+            name: "validator".into(),
+            ..Default::default()
+        });
+
+        let report = SubtensorModule::get_call_metrics();
+        assert_eq!(report.calls.len(), 1);
+        let metrics = &report.calls[0];
+        assert_eq!(metrics.call_index.0, 108);
+        assert_eq!(metrics.call_name, b"set_identity".to_vec());
+        assert_eq!(metrics.calls.0, 3);
+        assert_eq!(metrics.failures.0, 2);
+        assert_eq!(
+            metrics.failures_by_error,
+            vec![(
+                Error::<Test>::InvalidIdentity.error_code().into(),
+                2u64.into()
+            )]
+        );
+
+        // The counter writes of each dispatch are added to the block weight.
+        let block_weight = System::block_weight().get(DispatchClass::Normal).ref_time();
+        dispatch(pallet_subtensor::ChainIdentity {
+            name: vec![b'a'; 257],
+            ..Default::default()
+        });
+        assert_eq!(
+            System::block_weight().get(DispatchClass::Normal).ref_time(),
+            block_weight
+                + <Test as frame_system::Config>::DbWeight::get()
+                    .reads_writes(2, 2)
+                    .ref_time()
+        );
+
+        // Errors raised outside the pallet are counted under code 0.
+        SubtensorModule::record_call_metrics(108, &Err(DispatchError::BadOrigin));
+        assert_eq!(
+            SubtensorModule::get_call_metrics().calls[0].failures_by_error[0],
+            (0u16.into(), 1u64.into())
+        );
+
+        // The counters are cleared once the era has run its length.
+        SubtensorModule::roll_call_metrics_era(7199);
+        assert_eq!(SubtensorModule::get_call_metrics().calls.len(), 1);
+        SubtensorModule::roll_call_metrics_era(7200);
+        let report = SubtensorModule::get_call_metrics();
+        assert!(report.calls.is_empty());
+        assert_eq!(report.era_start.0, 7200);
+    });
+}
+
+#[test]
+fn test_purge_my_weights() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey: U256 = U256::from(1);
+        for netuid in [1, 2, 3] {
+            add_network(netuid, 1, 0);
+            register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        }
+        for netuid in [1, 2] {
+            register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+            pallet_subtensor::Weights::<Test>::insert(
+                netuid,
+                1,
+                pallet_subtensor::WeightsRow::from(vec![(0, 65535)]),
+            );
+            pallet_subtensor::WeightsTargetEpoch::<Test>::insert(netuid, 1, 7);
+        }
+        pallet_subtensor::Weights::<Test>::insert(
+            3,
+            0,
+            pallet_subtensor::WeightsRow::from(vec![(0, 65535)]),
+        );
+
+        assert_err!(
+            SubtensorModule::purge_my_weights(
+                RuntimeOrigin::signed(hotkey),
+                vec![1; pallet_subtensor::MAX_WEIGHTS_BATCH_SIZE as usize + 1]
+            ),
+            Error::<Test>::WeightsBatchTooLarge
+        );
+
+        // Subnet 3 is skipped, the hotkey is not registered there.
+        System::reset_events();
+        assert_ok!(SubtensorModule::purge_my_weights(
+            RuntimeOrigin::signed(hotkey),
+            vec![1, 3]
+        ));
+        assert!(pallet_subtensor::Weights::<Test>::get(1, 1).is_empty());
+        assert_eq!(SubtensorModule::get_weights_target_epoch(1, 1), None);
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(2, 1).entries(),
+            vec![(0, 65535)]
+        );
+        assert_eq!(
+            pallet_subtensor::Weights::<Test>::get(3, 0).entries(),
+            vec![(0, 65535)]
+        );
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::WeightsPurged(1, 1, hotkey),
+        ));
+
+        // A subnet already purged emits no event.
+        System::reset_events();
+        assert_ok!(SubtensorModule::purge_my_weights(
+            RuntimeOrigin::signed(hotkey),
+            vec![1, 2]
+        ));
+        assert!(pallet_subtensor::Weights::<Test>::get(2, 1).is_empty());
+        let purged = System::events()
+            .iter()
+            .filter(|record| {
+                matches!(
+                    record.event,
+                    RuntimeEvent::SubtensorModule(pallet_subtensor::Event::WeightsPurged(..))
+                )
+            })
+            .count();
+        assert_eq!(purged, 1);
+    });
+}
@@ -313,8 +313,12 @@ impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
                 | RuntimeCall::Timestamp(_)
                 | RuntimeCall::SubtensorModule(
                     pallet_subtensor::Call::set_weights { .. }
+                        | pallet_subtensor::Call::set_weights_for_epoch { .. }
                         | pallet_subtensor::Call::set_root_weights { .. }
                         | pallet_subtensor::Call::serve_axon { .. }
+                        | pallet_subtensor::Call::serve_axon_multi { .. }
+                        | pallet_subtensor::Call::serve_axon_tls { .. }
+                        | pallet_subtensor::Call::set_commitment { .. }
                 )
                 | RuntimeCall::Commitments(pallet_commitments::Call::set_commitment { .. })
         )
@@ -611,6 +615,8 @@ pub enum ProxyType {
     Governance, // Both above governance
     Staking,
     Registration,
+    SubnetOwner, // Hyperparameter calls a subnet owner can make
+    SenateVoting,
 }
 impl Default for ProxyType {
     fn default() -> Self {
@@ -626,9 +632,26 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 c,
                 RuntimeCall::Balances(..)
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::stake_all_minus_fees { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake_locked { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::add_stake_multiple { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::remove_stake_multiple { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_register_with_role { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_root_register { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unregister { .. })
             ),
             ProxyType::Owner => matches!(c, RuntimeCall::AdminUtils(..)),
             ProxyType::NonCritical => !matches!(
@@ -636,6 +659,13 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::dissolve_network { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_register_with_role { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_root_register { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unregister { .. })
                     | RuntimeCall::Triumvirate(..)
             ),
             ProxyType::Triumvirate => matches!(
@@ -652,12 +682,83 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             ProxyType::Staking => matches!(
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::stake_all_minus_fees { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake_locked { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::add_stake_multiple { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::remove_stake_multiple { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::claim_unstaked { .. })
             ),
             ProxyType::Registration => matches!(
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_register_with_role { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::register { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unregister { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_root_register { .. }
+                    )
+            ),
+            ProxyType::SubnetOwner => matches!(
+                c,
+                RuntimeCall::AdminUtils(
+                    pallet_admin_utils::Call::sudo_set_serving_rate_limit { .. }
+                        | pallet_admin_utils::Call::sudo_set_min_difficulty { .. }
+                        | pallet_admin_utils::Call::sudo_set_max_difficulty { .. }
+                        | pallet_admin_utils::Call::sudo_set_weights_version_key { .. }
+                        | pallet_admin_utils::Call::sudo_set_weights_set_rate_limit { .. }
+                        | pallet_admin_utils::Call::sudo_set_adjustment_interval { .. }
+                        | pallet_admin_utils::Call::sudo_set_adjustment_alpha { .. }
+                        | pallet_admin_utils::Call::sudo_set_max_weight_limit { .. }
+                        | pallet_admin_utils::Call::sudo_set_immunity_period { .. }
+                        | pallet_admin_utils::Call::sudo_set_min_allowed_weights { .. }
+                        | pallet_admin_utils::Call::sudo_set_kappa { .. }
+                        | pallet_admin_utils::Call::sudo_set_max_stake_weight_fraction { .. }
+                        | pallet_admin_utils::Call::sudo_set_rho { .. }
+                        | pallet_admin_utils::Call::sudo_set_activity_cutoff { .. }
+                        | pallet_admin_utils::Call::sudo_set_network_registration_allowed { .. }
+                        | pallet_admin_utils::Call::sudo_set_network_pow_registration_allowed {
+                            ..
+                        }
+                        | pallet_admin_utils::Call::sudo_set_target_registrations_per_interval {
+                            ..
+                        }
+                        | pallet_admin_utils::Call::sudo_set_min_burn { .. }
+                        | pallet_admin_utils::Call::sudo_set_max_burn { .. }
+                        | pallet_admin_utils::Call::sudo_set_difficulty { .. }
+                        | pallet_admin_utils::Call::sudo_set_bonds_moving_average { .. }
+                        | pallet_admin_utils::Call::sudo_set_max_registrations_per_block { .. }
+                        | pallet_admin_utils::Call::sudo_set_tempo { .. }
+                        | pallet_admin_utils::Call::sudo_set_commit_reveal_weights_interval { .. }
+                        | pallet_admin_utils::Call::sudo_set_commit_reveal_weights_enabled { .. }
+                        | pallet_admin_utils::Call::sudo_set_liquid_alpha_enabled { .. }
+                        | pallet_admin_utils::Call::sudo_set_alpha_values { .. }
+                        | pallet_admin_utils::Call::sudo_set_max_commitment_size { .. }
+                        | pallet_admin_utils::Call::sudo_set_commitment_rate_limit { .. }
+                        | pallet_admin_utils::Call::sudo_set_axon_ttl_blocks { .. }
+                        | pallet_admin_utils::Call::sudo_schedule_max_allowed_uids_growth { .. }
+                        | pallet_admin_utils::Call::sudo_cancel_max_allowed_uids_growth { .. }
+                        | pallet_admin_utils::Call::sudo_set_max_miner_uids { .. }
+                        | pallet_admin_utils::Call::sudo_set_bootstrap_incentive_floor { .. }
+                        | pallet_admin_utils::Call::sudo_set_weights_paused { .. }
+                        | pallet_admin_utils::Call::sudo_set_min_stake_to_register { .. }
+                        | pallet_admin_utils::Call::sudo_reset_bonds { .. }
+                        | pallet_admin_utils::Call::sudo_set_max_take_per_subnet { .. }
+                        | pallet_admin_utils::Call::sudo_set_weights_precision { .. }
+                )
+            ),
+            ProxyType::SenateVoting => matches!(
+                c,
+                RuntimeCall::SubtensorModule(pallet_subtensor::Call::vote { .. })
             ),
         }
     }
@@ -668,6 +769,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             (_, ProxyType::Any) => false,
             (ProxyType::NonTransfer, _) => true,
             (ProxyType::Governance, ProxyType::Triumvirate | ProxyType::Senate) => true,
+            (ProxyType::Owner, ProxyType::SubnetOwner) => true,
             _ => false,
         }
     }
@@ -881,6 +983,17 @@ parameter_types! {
     pub const InitialAlphaLow: u16 = 45875; // Represents 0.7 as per the production default
     pub const InitialLiquidAlphaOn: bool = false; // Default value for LiquidAlphaOn
     pub const SubtensorInitialBaseDifficulty: u64 = 10_000_000; // Base difficulty
+    pub const SubtensorInitialRootTempo: u16 = 100;
+    pub const SubtensorInitialRootMaxUids: u16 = 64;
+    pub const SubtensorNewNetworkTempo: u16 = 360;
+    pub const SubtensorNewNetworkMaxAllowedUids: u16 = 256;
+    pub const SubtensorNewNetworkMaxAllowedValidators: u16 = 64;
+    pub const SubtensorNewNetworkMinAllowedWeights: u16 = 1;
+    pub const SubtensorNewNetworkAdjustmentInterval: u16 = 360;
+    pub const SubtensorNewNetworkTargetRegistrationsPerInterval: u16 = 1;
+    pub const SubtensorNewNetworkAdjustmentAlpha: u64 = 17_893_341_751_498_265_066; // 0.97
+    pub const SubtensorNewNetworkImmunityPeriod: u16 = 5000;
+    pub const SubtensorNewNetworkMinBurn: u64 = 1;
 }
 
 impl pallet_subtensor::Config for Runtime {
@@ -890,6 +1003,10 @@ impl pallet_subtensor::Config for Runtime {
     type CouncilOrigin = EnsureMajoritySenate;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type OnSubtensorEvent = ();
+    type KeySignature = Signature;
+    type KeySigner = <Signature as Verify>::Signer;
+    type WeightInfo = pallet_subtensor::weight_info::SubstrateWeight<Runtime>;
 
     type InitialRho = SubtensorInitialRho;
     type InitialKappa = SubtensorInitialKappa;
@@ -937,6 +1054,18 @@ impl pallet_subtensor::Config for Runtime {
     type AlphaLow = InitialAlphaLow;
     type LiquidAlphaOn = InitialLiquidAlphaOn;
     type InitialBaseDifficulty = SubtensorInitialBaseDifficulty;
+    type InitialRootTempo = SubtensorInitialRootTempo;
+    type InitialRootMaxUids = SubtensorInitialRootMaxUids;
+    type NewNetworkTempo = SubtensorNewNetworkTempo;
+    type NewNetworkMaxAllowedUids = SubtensorNewNetworkMaxAllowedUids;
+    type NewNetworkMaxAllowedValidators = SubtensorNewNetworkMaxAllowedValidators;
+    type NewNetworkMinAllowedWeights = SubtensorNewNetworkMinAllowedWeights;
+    type NewNetworkAdjustmentInterval = SubtensorNewNetworkAdjustmentInterval;
+    type NewNetworkTargetRegistrationsPerInterval =
+        SubtensorNewNetworkTargetRegistrationsPerInterval;
+    type NewNetworkAdjustmentAlpha = SubtensorNewNetworkAdjustmentAlpha;
+    type NewNetworkImmunityPeriod = SubtensorNewNetworkImmunityPeriod;
+    type NewNetworkMinBurn = SubtensorNewNetworkMinBurn;
 }
 
 use sp_runtime::BoundedVec;
@@ -1081,8 +1210,24 @@ impl
         SubtensorModule::set_network_min_lock(net_min_lock);
     }
 
-    fn set_subnet_limit(limit: u16) {
-        SubtensorModule::set_max_subnets(limit);
+    fn set_subnet_limit(limit: u16) -> u64 {
+        SubtensorModule::schedule_max_subnets(limit)
+    }
+
+    fn set_subnet_limit_change_delay(delay: u64) {
+        SubtensorModule::set_subnet_limit_change_delay(delay);
+    }
+
+    fn set_max_maintenance_duration(duration: u64) {
+        SubtensorModule::set_max_maintenance_duration(duration);
+    }
+
+    fn set_max_commitment_size(netuid: u16, max_commitment_size: u32) {
+        SubtensorModule::set_max_commitment_size(netuid, max_commitment_size);
+    }
+
+    fn set_commitment_rate_limit(netuid: u16, commitment_rate_limit: u64) {
+        SubtensorModule::set_commitment_rate_limit(netuid, commitment_rate_limit);
     }
 
     fn set_lock_reduction_interval(interval: u64) {
@@ -1105,6 +1250,14 @@ impl
         SubtensorModule::set_max_registrations_per_block(netuid, max_registrations_per_block);
     }
 
+    fn set_max_pow_registrations_per_block(netuid: u16, max_registrations_per_block: u16) {
+        SubtensorModule::set_max_pow_registrations_per_block(netuid, max_registrations_per_block);
+    }
+
+    fn set_max_burn_registrations_per_block(netuid: u16, max_registrations_per_block: u16) {
+        SubtensorModule::set_max_burn_registrations_per_block(netuid, max_registrations_per_block);
+    }
+
     fn set_adjustment_alpha(netuid: u16, adjustment_alpha: u64) {
         SubtensorModule::set_adjustment_alpha(netuid, adjustment_alpha);
     }
@@ -1128,6 +1281,14 @@ impl
         SubtensorModule::set_activity_cutoff(netuid, activity_cutoff);
     }
 
+    fn get_min_activity_cutoff() -> u16 {
+        SubtensorModule::get_min_activity_cutoff()
+    }
+
+    fn set_min_activity_cutoff(min_activity_cutoff: u16) {
+        SubtensorModule::set_min_activity_cutoff(min_activity_cutoff);
+    }
+
     fn ensure_subnet_owner_or_root(o: RuntimeOrigin, netuid: u16) -> Result<(), DispatchError> {
         SubtensorModule::ensure_subnet_owner_or_root(o, netuid)
     }
@@ -1140,10 +1301,22 @@ impl
         SubtensorModule::set_kappa(netuid, kappa);
     }
 
+    fn set_max_stake_weight_fraction(netuid: u16, fraction: u16) {
+        SubtensorModule::set_max_stake_weight_fraction(netuid, fraction);
+    }
+
     fn set_max_allowed_uids(netuid: u16, max_allowed: u16) {
         SubtensorModule::set_max_allowed_uids(netuid, max_allowed);
     }
 
+    fn set_max_root_validators(max_validators: u16) {
+        SubtensorModule::set_max_root_validators(max_validators);
+    }
+
+    fn set_fee_reserve(fee_reserve: u64) {
+        SubtensorModule::set_fee_reserve(fee_reserve);
+    }
+
     fn set_min_allowed_weights(netuid: u16, min_allowed_weights: u16) {
         SubtensorModule::set_min_allowed_weights(netuid, min_allowed_weights);
     }
@@ -1189,7 +1362,7 @@ impl
     }
 
     fn clear_small_nominations() {
-        SubtensorModule::clear_small_nominations();
+        SubtensorModule::clear_all_small_nominations();
     }
 
     fn set_nominator_min_required_stake(min_stake: u64) {
@@ -1224,6 +1397,252 @@ impl
     ) -> Result<(), DispatchError> {
         SubtensorModule::do_set_alpha_values(origin, netuid, alpha_low, alpha_high)
     }
+
+    fn reserve_netuid(netuid: u16, min_bid: u64, duration: u64) -> Result<(), DispatchError> {
+        SubtensorModule::do_reserve_netuid(netuid, min_bid, duration)
+    }
+
+    fn release_netuid(netuid: u16) -> Result<(), DispatchError> {
+        SubtensorModule::do_release_netuid(netuid)
+    }
+
+    fn ban_hotkey(hotkey: &AccountId) -> Result<(), DispatchError> {
+        SubtensorModule::do_ban_hotkey(hotkey)
+    }
+
+    fn unban_hotkey(hotkey: &AccountId) -> Result<(), DispatchError> {
+        SubtensorModule::do_unban_hotkey(hotkey)
+    }
+
+    fn set_stake_wind_down_chunk_size(chunk_size: u16) {
+        SubtensorModule::set_stake_wind_down_chunk_size(chunk_size);
+    }
+
+    fn set_axon_ttl_blocks(netuid: u16, axon_ttl_blocks: u64) {
+        SubtensorModule::set_axon_ttl_blocks(netuid, axon_ttl_blocks);
+    }
+
+    fn set_utilization_floor(utilization_floor: u16) {
+        SubtensorModule::set_utilization_floor(utilization_floor);
+    }
+
+    fn set_low_utilization_burn(low_utilization_burn: u16) {
+        SubtensorModule::set_low_utilization_burn(low_utilization_burn);
+    }
+
+    fn schedule_max_allowed_uids_growth(
+        netuid: u16,
+        step: u16,
+        interval: u64,
+        target: u16,
+    ) -> Result<(), DispatchError> {
+        SubtensorModule::do_schedule_max_allowed_uids_growth(netuid, step, interval, target)
+    }
+
+    fn cancel_max_allowed_uids_growth(netuid: u16) -> Result<(), DispatchError> {
+        SubtensorModule::do_cancel_max_allowed_uids_growth(netuid)
+    }
+
+    fn prune_orphaned_storage(limit: u32) -> u32 {
+        SubtensorModule::do_prune_orphaned_storage(limit)
+    }
+
+    fn set_hotkey_emission_tempo(hotkey_emission_tempo: u64) {
+        SubtensorModule::set_hotkey_emission_tempo(hotkey_emission_tempo);
+    }
+
+    fn set_epoch_pause_threshold(threshold: u16) {
+        SubtensorModule::set_epoch_pause_threshold(threshold);
+    }
+
+    fn set_epoch_pause_duration(duration: u64) {
+        SubtensorModule::set_epoch_pause_duration(duration);
+    }
+
+    fn set_stake_fee(flat: u64, bps: u16) {
+        SubtensorModule::set_stake_fee(flat, bps);
+    }
+
+    fn set_stake_fee_dust_threshold(threshold: u64) {
+        SubtensorModule::set_stake_fee_dust_threshold(threshold);
+    }
+
+    fn set_stake_fee_treasury(treasury: Option<AccountId>) {
+        SubtensorModule::set_stake_fee_treasury(treasury);
+    }
+
+    fn set_weights_sweep_chunk_size(chunk_size: u16) {
+        SubtensorModule::set_weights_sweep_chunk_size(chunk_size);
+    }
+
+    fn set_max_miner_uids(netuid: u16, max_miner_uids: u16) {
+        SubtensorModule::set_max_miner_uids(netuid, max_miner_uids);
+    }
+
+    fn set_root_epoch_block_budget(budget: u32) {
+        SubtensorModule::set_root_epoch_block_budget(budget);
+    }
+
+    fn set_bootstrap_incentive_floor(netuid: u16, floor: u16) {
+        SubtensorModule::set_bootstrap_incentive_floor(netuid, floor);
+    }
+
+    fn can_pause_weights(netuid: u16) -> bool {
+        SubtensorModule::can_pause_weights(netuid)
+    }
+
+    fn set_weights_paused(netuid: u16, paused: bool) {
+        SubtensorModule::set_weights_paused(netuid, paused);
+    }
+
+    fn set_max_weights_pause_duration(duration: u64) {
+        SubtensorModule::set_max_weights_pause_duration(duration);
+    }
+
+    fn get_owner_hyperparam_bounds(
+        netuid: u16,
+        param: pallet_subtensor::OwnerHyperparam,
+    ) -> (u16, u16) {
+        SubtensorModule::get_owner_hyperparam_bounds(netuid, param)
+    }
+
+    fn passes_owner_hyperparam_rate_limit(
+        netuid: u16,
+        param: pallet_subtensor::OwnerHyperparam,
+    ) -> bool {
+        SubtensorModule::passes_owner_hyperparam_rate_limit(netuid, param)
+    }
+
+    fn set_last_owner_hyperparam_update(netuid: u16, param: pallet_subtensor::OwnerHyperparam) {
+        SubtensorModule::set_last_owner_hyperparam_update(netuid, param);
+    }
+
+    fn set_owner_hyperparam_rate_limit(rate_limit: u64) {
+        SubtensorModule::set_owner_hyperparam_rate_limit(rate_limit);
+    }
+
+    fn set_max_immunity_period(max_immunity_period: u16) {
+        SubtensorModule::set_max_immunity_period(max_immunity_period);
+    }
+
+    fn set_network_lock_cost_multiplier(multiplier: u32) {
+        SubtensorModule::set_network_lock_cost_multiplier(multiplier);
+    }
+
+    fn set_referendum_duration(duration: u64) {
+        SubtensorModule::set_referendum_duration(duration);
+    }
+
+    fn set_referendum_threshold(threshold: u16) {
+        SubtensorModule::set_referendum_threshold(threshold);
+    }
+
+    fn set_netuid_quarantine_period(period: u64) {
+        SubtensorModule::set_netuid_quarantine_period(period);
+    }
+
+    fn set_axon_reachability_half_life(half_life: u64) {
+        SubtensorModule::set_axon_reachability_half_life(half_life);
+    }
+
+    fn set_min_stake_to_register(netuid: u16, min_stake: u64) {
+        SubtensorModule::set_min_stake_to_register(netuid, min_stake);
+    }
+
+    fn get_max_min_stake_to_register() -> u64 {
+        SubtensorModule::get_max_min_stake_to_register()
+    }
+
+    fn set_max_min_stake_to_register(max_min_stake: u64) {
+        SubtensorModule::set_max_min_stake_to_register(max_min_stake);
+    }
+
+    fn set_pending_child_key_cooldown(cooldown: u64) {
+        SubtensorModule::set_pending_child_key_cooldown(cooldown);
+    }
+
+    fn schedule_bonds_reset(netuid: u16) {
+        SubtensorModule::schedule_bonds_reset(netuid);
+    }
+
+    fn set_bonds_reset_chunk_size(chunk_size: u16) {
+        SubtensorModule::set_bonds_reset_chunk_size(chunk_size);
+    }
+
+    fn set_arbitration_period(period: u64) {
+        SubtensorModule::set_arbitration_period(period);
+    }
+
+    fn set_take_cap_threshold(threshold: u16) {
+        SubtensorModule::set_take_cap_threshold(threshold);
+    }
+
+    fn set_take_cap_duration(duration: u64) {
+        SubtensorModule::set_take_cap_duration(duration);
+    }
+
+    fn set_identity_deposit(deposit: u64) {
+        SubtensorModule::set_identity_deposit(deposit);
+    }
+
+    fn set_call_metrics_era_length(era_length: u64) {
+        SubtensorModule::set_call_metrics_era_length(era_length);
+    }
+
+    fn set_faucet_rate_limit(faucet_rate_limit: u64) {
+        SubtensorModule::set_faucet_rate_limit(faucet_rate_limit);
+    }
+
+    fn set_root_weights_safe_mode(enabled: bool, stake_threshold: u64, min_spread: u16) {
+        SubtensorModule::set_root_weights_safe_mode(enabled, stake_threshold, min_spread);
+    }
+
+    fn set_root_stake_cap(cap: u16) {
+        SubtensorModule::set_root_stake_cap(cap);
+    }
+
+    fn set_unregister_refund(window: u64, share: u16) {
+        SubtensorModule::set_unregister_refund(window, share);
+    }
+
+    fn set_emission_multiplier(netuid: u16, multiplier: u32) {
+        SubtensorModule::set_emission_multiplier(netuid, multiplier);
+    }
+
+    fn get_min_delegate_take() -> u16 {
+        SubtensorModule::get_min_delegate_take()
+    }
+
+    fn set_max_take_per_subnet(netuid: u16, max_take: u16) {
+        SubtensorModule::set_max_take_per_subnet(netuid, max_take);
+    }
+
+    fn set_transaction_type_rate_limit(
+        tx_type: pallet_subtensor::TransactionType,
+        rate_limit: u64,
+    ) {
+        SubtensorModule::set_transaction_type_rate_limit(tx_type, rate_limit);
+    }
+
+    fn set_stake_lock_boosts(boosts: Vec<(u64, u32)>) {
+        SubtensorModule::set_stake_lock_boosts(boosts);
+    }
+
+    fn set_unstake_cooldown(cooldown: u64) {
+        SubtensorModule::set_unstake_cooldown(cooldown);
+    }
+
+    fn migrate_subnet(old_netuid: u16, new_netuid: u16) -> Result<(), DispatchError> {
+        SubtensorModule::do_start_subnet_migration(old_netuid, new_netuid)
+    }
+
+    fn set_weights_precision(netuid: u16, precision: u8) {
+        SubtensorModule::set_weights_precision(netuid, precision);
+    }
+
+    fn set_delegate_underperformance(trust_threshold: u16, tempos: u16, take: u16) {
+        SubtensorModule::set_delegate_underperformance(trust_threshold, tempos, take);
+    }
 }
 
 impl pallet_admin_utils::Config for Runtime {
@@ -1585,6 +2004,16 @@ impl_runtime_apis! {
             let result = SubtensorModule::get_delegated(delegatee_account_vec);
             result.encode()
         }
+
+        fn get_top_validators(netuid: u16, k: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_top_validators(netuid, k);
+            result.encode()
+        }
+
+        fn get_root_voters() -> Vec<u8> {
+            let result = SubtensorModule::get_root_voters();
+            result.encode()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi<Block> for Runtime {
@@ -1617,6 +2046,31 @@ impl_runtime_apis! {
                 vec![]
             }
         }
+
+        fn get_axons(netuid: u16, uid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_axons(netuid, uid);
+            result.encode()
+        }
+
+        fn get_neurons_by_ip(ip: u128) -> Vec<u8> {
+            let result = SubtensorModule::get_neurons_by_ip(ip);
+            result.encode()
+        }
+
+        fn get_neuron_certificate(netuid: u16, uid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_neuron_certificate(netuid, uid);
+            result.encode()
+        }
+
+        fn get_neurons_paginated(netuid: u16, start_uid: u16, limit: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_neurons_paginated(netuid, start_uid, limit);
+            result.encode()
+        }
+
+        fn get_neurons_lite_paginated(netuid: u16, start_uid: u16, limit: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_neurons_lite_paginated(netuid, start_uid, limit);
+            result.encode()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi<Block> for Runtime {
@@ -1635,6 +2089,21 @@ impl_runtime_apis! {
             result.encode()
         }
 
+        fn get_subnet_info_v2(netuid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_subnet_info_v2(netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get SubnetInfoV2");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_subnets_info_v2() -> Vec<u8> {
+            let result = SubtensorModule::get_subnets_info_v2();
+            result.encode()
+        }
+
         fn get_subnet_hyperparams(netuid: u16) -> Vec<u8> {
             let _result = SubtensorModule::get_subnet_hyperparams(netuid);
             if _result.is_some() {
@@ -1644,6 +2113,110 @@ impl_runtime_apis! {
                 vec![]
             }
         }
+
+        fn get_weights_sparse(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_subnet_weights_sparse(netuid);
+            result.encode()
+        }
+
+        fn get_bonds_sparse(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_subnet_bonds_sparse(netuid);
+            result.encode()
+        }
+
+        fn get_subnet_recycle_info(netuid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_subnet_recycle_info(netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get SubnetRecycleInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn dump_subnet_state(netuid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::dump_subnet_state(netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get SubnetState");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_epoch_snapshots(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_epoch_snapshots(netuid);
+            result.encode()
+        }
+
+        fn get_eviction_log(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_eviction_log(netuid);
+            result.encode()
+        }
+
+        fn get_all_subnet_netuids() -> Vec<u8> {
+            let result = SubtensorModule::get_all_subnet_netuids();
+            result.encode()
+        }
+
+        fn get_evm_key_associations(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_evm_key_associations(netuid);
+            result.encode()
+        }
+
+        fn get_root_weights() -> Vec<u8> {
+            let result = SubtensorModule::get_root_weights_dense();
+            result.encode()
+        }
+
+        fn get_root_emission_info() -> Vec<u8> {
+            let result = SubtensorModule::get_root_emission_info();
+            result.encode()
+        }
+
+        fn simulate_root_epoch() -> Vec<u8> {
+            let result = SubtensorModule::simulate_root_epoch();
+            result.encode()
+        }
+
+        fn get_pow_stats(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_pow_stats(netuid);
+            result.encode()
+        }
+
+        fn get_emission_proof(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_emission_proof(netuid);
+            result.encode()
+        }
+
+        fn get_adjustment_info(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_adjustment_info(netuid);
+            result.encode()
+        }
+
+        fn get_all_epoch_schedules() -> Vec<u8> {
+            let result = SubtensorModule::get_all_epoch_schedules();
+            result.encode()
+        }
+
+        fn project_network_burn_cost(blocks_ahead: u64) -> u64 {
+            SubtensorModule::project_network_burn_cost(blocks_ahead)
+        }
+
+        fn simulate_root_weight_change( hotkey_account_vec: Vec<u8>, uids: Vec<u16>, values: Vec<u16> ) -> Vec<u8> {
+            let result = SubtensorModule::simulate_root_weight_change( hotkey_account_vec, uids, values );
+            result.encode()
+        }
+
+        fn get_storage_stats() -> Vec<u8> {
+            let result = SubtensorModule::get_storage_stats();
+            result.encode()
+        }
+
+        fn get_call_metrics() -> Vec<u8> {
+            let result = SubtensorModule::get_call_metrics();
+            result.encode()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block> for Runtime {
@@ -1656,6 +2229,63 @@ impl_runtime_apis! {
             let result = SubtensorModule::get_stake_info_for_coldkeys( coldkey_account_vecs );
             result.encode()
         }
+
+        fn get_coldkey_emission_summary( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_coldkey_emission_summary( coldkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get ColdkeyEmission");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_stake_distribution( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_stake_distribution( coldkey_account_vec );
+            result.encode()
+        }
+
+        fn get_stake_fee( amount: u64 ) -> u64 {
+            SubtensorModule::get_stake_fee( amount )
+        }
+
+        fn get_pending_unstakes( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_pending_unstakes( coldkey_account_vec );
+            result.encode()
+        }
+
+        fn get_coldkey_value( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_coldkey_value( coldkey_account_vec );
+            result.encode()
+        }
+
+        fn get_weights_min_stake() -> u64 {
+            SubtensorModule::get_weights_min_stake()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::RateLimitInfoRuntimeApi<Block> for Runtime {
+        fn get_rate_limits( hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_rate_limits( hotkey_account_vec );
+            result.encode()
+        }
+
+        fn get_next_weight_set_block( netuid: u16, hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_next_weight_set_block( netuid, hotkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get next weight set block");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::RuntimeFeaturesRuntimeApi<Block> for Runtime {
+        fn get_runtime_features() -> Vec<u8> {
+            let result = SubtensorModule::get_runtime_features();
+            result.encode()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
@@ -67,6 +67,14 @@ fn call_owner_util() -> RuntimeCall {
     })
 }
 
+// owner hyperparameter call
+fn call_owner_hyperparam() -> RuntimeCall {
+    RuntimeCall::AdminUtils(pallet_admin_utils::Call::sudo_set_tempo {
+        netuid: 1,
+        tempo: 100,
+    })
+}
+
 // critical call for Subtensor
 fn call_propose() -> RuntimeCall {
     let proposal = call_remark();
@@ -100,6 +108,16 @@ fn call_senate() -> RuntimeCall {
     })
 }
 
+// senate vote call
+fn call_senate_vote() -> RuntimeCall {
+    RuntimeCall::SubtensorModule(pallet_subtensor::Call::vote {
+        hotkey: AccountId::from(ACCOUNT),
+        proposal: Default::default(),
+        index: 0,
+        approve: true,
+    })
+}
+
 // staking call
 fn call_add_stake() -> RuntimeCall {
     let amount_staked = 100;
@@ -171,16 +189,20 @@ fn test_proxy_pallet() {
         ProxyType::Governance,
         ProxyType::Staking,
         ProxyType::Registration,
+        ProxyType::SubnetOwner,
+        ProxyType::SenateVoting,
     ];
 
     let calls = [
         call_transfer,
         call_remark,
         call_owner_util,
+        call_owner_hyperparam,
         call_propose,
         call_root_register,
         call_triumvirate,
         call_senate,
+        call_senate_vote,
         call_add_stake,
         call_register,
     ];